@@ -7,9 +7,11 @@
 //! Includes virtual/scratch buffer identity helpers so non-file buffers can
 //! share the same icon + label presentation pipeline.
 
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use devicons::FileIcon;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Generic plain-file fallback icon used when the icon database has no match.
 pub const GENERIC_FILE_ICON: &str = "󰈔";
@@ -34,6 +36,42 @@ pub const OVERLAY_PREVIEW_ICON: &str = "󰈈";
 /// Generic overlay virtual-buffer icon.
 pub const OVERLAY_ICON: &str = "󰏌";
 
+/// ASCII file tag, used by [`IconStyle::Ascii`] for fonts without Nerd Font glyphs.
+pub const FILE_ICON_ASCII: &str = "[f]";
+/// ASCII directory tag.
+pub const DIRECTORY_ICON_ASCII: &str = "[d]";
+/// ASCII scratch-buffer tag.
+pub const SCRATCH_ICON_ASCII: &str = "[s]";
+/// ASCII command palette tag.
+pub const COMMAND_PALETTE_ICON_ASCII: &str = "[cmd]";
+/// ASCII file picker tag.
+pub const FILE_PICKER_ICON_ASCII: &str = "[fp]";
+/// ASCII search tag.
+pub const SEARCH_ICON_ASCII: &str = "[/]";
+/// ASCII rename tag.
+pub const RENAME_ICON_ASCII: &str = "[mv]";
+/// ASCII workspace search tag.
+pub const WORKSPACE_SEARCH_ICON_ASCII: &str = "[grep]";
+/// ASCII overlay list-pane tag.
+pub const OVERLAY_LIST_ICON_ASCII: &str = "[ls]";
+/// ASCII overlay preview-pane tag.
+pub const OVERLAY_PREVIEW_ICON_ASCII: &str = "[pv]";
+/// ASCII generic overlay tag.
+pub const OVERLAY_ICON_ASCII: &str = "[?]";
+
+/// Unicode modified-buffer badge.
+pub const MODIFIED_BADGE: &str = "●";
+/// Unicode readonly-buffer badge.
+pub const READONLY_BADGE: &str = "";
+/// Unicode new-file badge.
+pub const NEW_FILE_BADGE: &str = "✦";
+/// ASCII modified-buffer badge.
+pub const MODIFIED_BADGE_ASCII: &str = "+";
+/// ASCII readonly-buffer badge.
+pub const READONLY_BADGE_ASCII: &str = "[RO]";
+/// ASCII new-file badge.
+pub const NEW_FILE_BADGE_ASCII: &str = "[New]";
+
 /// Semantic kind of file-system entry.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FileKind {
@@ -55,6 +93,70 @@ pub enum VirtualBufferKind {
 	OverlayCustom(String),
 }
 
+/// Glyph set for file and buffer icons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconStyle {
+	/// Nerd Font glyphs, with per-filetype icons from the devicons table.
+	#[default]
+	NerdFont,
+	/// Short bracketed ASCII tags such as `[f]` and `[cmd]`.
+	Ascii,
+	/// No icons; every icon resolves to an empty string.
+	None,
+}
+
+impl IconStyle {
+	/// Parses an `icons` option value (`nerd-font`, `ascii`, or `none`).
+	pub fn from_option(value: &str) -> Option<Self> {
+		match value {
+			"nerd-font" => Some(Self::NerdFont),
+			"ascii" => Some(Self::Ascii),
+			"none" => Some(Self::None),
+			_ => None,
+		}
+	}
+}
+
+/// Icon slot resolved through [`icon_glyph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Icon {
+	File,
+	Directory,
+	Scratch,
+	CommandPalette,
+	FilePicker,
+	Search,
+	Rename,
+	WorkspaceSearch,
+	OverlayList,
+	OverlayPreview,
+	Overlay,
+}
+
+/// Maps an icon slot to its glyph in `style`; the one table every icon
+/// lookup goes through. Nerd Font file icons are refined per filetype by
+/// [`file_icon_for_path`].
+fn icon_glyph(icon: Icon, style: IconStyle) -> &'static str {
+	let (nerd_font, ascii) = match icon {
+		Icon::File => (GENERIC_FILE_ICON, FILE_ICON_ASCII),
+		Icon::Directory => (DIRECTORY_ICON, DIRECTORY_ICON_ASCII),
+		Icon::Scratch => (SCRATCH_ICON, SCRATCH_ICON_ASCII),
+		Icon::CommandPalette => (COMMAND_PALETTE_ICON, COMMAND_PALETTE_ICON_ASCII),
+		Icon::FilePicker => (FILE_PICKER_ICON, FILE_PICKER_ICON_ASCII),
+		Icon::Search => (SEARCH_ICON, SEARCH_ICON_ASCII),
+		Icon::Rename => (RENAME_ICON, RENAME_ICON_ASCII),
+		Icon::WorkspaceSearch => (WORKSPACE_SEARCH_ICON, WORKSPACE_SEARCH_ICON_ASCII),
+		Icon::OverlayList => (OVERLAY_LIST_ICON, OVERLAY_LIST_ICON_ASCII),
+		Icon::OverlayPreview => (OVERLAY_PREVIEW_ICON, OVERLAY_PREVIEW_ICON_ASCII),
+		Icon::Overlay => (OVERLAY_ICON, OVERLAY_ICON_ASCII),
+	};
+	match style {
+		IconStyle::NerdFont => nerd_font,
+		IconStyle::Ascii => ascii,
+		IconStyle::None => "",
+	}
+}
+
 /// Label formatting mode for file entries.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FileDisplayMode {
@@ -67,6 +169,13 @@ pub enum FileDisplayMode {
 	RelativeToWorkingDir,
 	/// Display an absolute path when possible.
 	Absolute,
+	/// Relative to `working_dir` (or `~`-abbreviated), with every component
+	/// but the last `keep_last` cut to its first character, fish-style:
+	/// `~/.c/x/config.kdl`. At least the file name is always kept.
+	Shortened { keep_last: usize },
+	/// Relative to `working_dir` (or `~`-abbreviated), with the middle
+	/// replaced by `…` so the label fits `max_width` cells.
+	TruncateMiddle { max_width: usize },
 }
 
 /// Rendering context for file-label formatting.
@@ -74,6 +183,8 @@ pub enum FileDisplayMode {
 pub struct FileDisplayContext<'a> {
 	pub mode: FileDisplayMode,
 	pub working_dir: Option<&'a Path>,
+	/// Also used for buffer icons through [`BufferDisplayContext::file`].
+	pub icons: IconStyle,
 }
 
 impl Default for FileDisplayContext<'_> {
@@ -81,6 +192,40 @@ impl Default for FileDisplayContext<'_> {
 		Self {
 			mode: FileDisplayMode::AsProvided,
 			working_dir: None,
+			icons: IconStyle::NerdFont,
+		}
+	}
+}
+
+/// Glyph set for buffer state badges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeStyle {
+	/// `●`, a lock glyph and `✦`.
+	#[default]
+	Unicode,
+	/// `+`, `[RO]` and `[New]`, for terminals without symbol fonts.
+	Ascii,
+}
+
+impl BadgeStyle {
+	fn modified(self) -> &'static str {
+		match self {
+			Self::Unicode => MODIFIED_BADGE,
+			Self::Ascii => MODIFIED_BADGE_ASCII,
+		}
+	}
+
+	fn readonly(self) -> &'static str {
+		match self {
+			Self::Unicode => READONLY_BADGE,
+			Self::Ascii => READONLY_BADGE_ASCII,
+		}
+	}
+
+	fn new_file(self) -> &'static str {
+		match self {
+			Self::Unicode => NEW_FILE_BADGE,
+			Self::Ascii => NEW_FILE_BADGE_ASCII,
 		}
 	}
 }
@@ -89,6 +234,18 @@ impl Default for FileDisplayContext<'_> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct BufferDisplayContext<'a> {
 	pub file: FileDisplayContext<'a>,
+	pub badges: BadgeStyle,
+}
+
+/// Buffer state rendered as badges after the label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferFlags {
+	/// Unsaved changes.
+	pub modified: bool,
+	/// Editing is disabled.
+	pub readonly: bool,
+	/// The file does not exist on disk yet.
+	pub new_file: bool,
 }
 
 /// Input item for file presentation.
@@ -132,6 +289,8 @@ pub enum BufferIdentity<'a> {
 pub struct BufferItem<'a> {
 	pub identity: BufferIdentity<'a>,
 	pub label_override: Option<&'a str>,
+	/// Ignored for virtual buffers.
+	pub flags: BufferFlags,
 }
 
 impl<'a> BufferItem<'a> {
@@ -139,6 +298,7 @@ impl<'a> BufferItem<'a> {
 		Self {
 			identity: BufferIdentity::File { path, kind: FileKind::File },
 			label_override: None,
+			flags: BufferFlags::default(),
 		}
 	}
 
@@ -146,6 +306,7 @@ impl<'a> BufferItem<'a> {
 		Self {
 			identity: BufferIdentity::Scratch,
 			label_override: None,
+			flags: BufferFlags::default(),
 		}
 	}
 
@@ -153,6 +314,7 @@ impl<'a> BufferItem<'a> {
 		Self {
 			identity: BufferIdentity::Virtual(kind),
 			label_override: None,
+			flags: BufferFlags::default(),
 		}
 	}
 
@@ -167,6 +329,11 @@ impl<'a> BufferItem<'a> {
 		self.label_override = Some(label);
 		self
 	}
+
+	pub fn with_flags(mut self, flags: BufferFlags) -> Self {
+		self.flags = flags;
+		self
+	}
 }
 
 /// Resolved icon + label payload for UI surfaces.
@@ -191,15 +358,33 @@ impl FilePresentation {
 }
 
 /// Resolved icon + label payload for buffer UI surfaces.
+///
+/// The label ends with the state badges, space-separated; [`Self::badges`]
+/// returns them on their own for surfaces that style them separately.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BufferPresentation {
 	icon: String,
 	label: String,
+	badges: Vec<&'static str>,
 }
 
 impl BufferPresentation {
 	pub fn new(icon: String, label: String) -> Self {
-		Self { icon, label }
+		Self {
+			icon,
+			label,
+			badges: Vec::new(),
+		}
+	}
+
+	/// Appends `badges` to the label and records them.
+	fn with_badges(mut self, badges: Vec<&'static str>) -> Self {
+		for badge in &badges {
+			self.label.push(' ');
+			self.label.push_str(badge);
+		}
+		self.badges = badges;
+		self
 	}
 
 	pub fn icon(&self) -> &str {
@@ -209,18 +394,25 @@ impl BufferPresentation {
 	pub fn label(&self) -> &str {
 		&self.label
 	}
+
+	/// Returns the state badges ending the label, in display order.
+	pub fn badges(&self) -> &[&'static str] {
+		&self.badges
+	}
 }
 
 /// Resolves icon + label in one call for a file item.
 pub fn present_file(item: FileItem<'_>, context: FileDisplayContext<'_>) -> FilePresentation {
-	let icon = file_icon_for_path(item.path, item.kind);
+	let icon = file_icon_for_path(item.path, item.kind, context.icons);
 	let label = format_file_label(item.path, item.label_override, context);
 	FilePresentation::new(icon, label)
 }
 
-/// Resolves icon + label in one call for any buffer identity.
+/// Resolves icon + label in one call for any buffer identity, with badges
+/// for the item's [`BufferFlags`] unless it is a virtual buffer.
 pub fn present_buffer(item: BufferItem<'_>, context: BufferDisplayContext<'_>) -> BufferPresentation {
-	match item.identity {
+	let badges = buffer_badges(item.flags, context.badges);
+	let presentation = match item.identity {
 		BufferIdentity::File { path, kind } => {
 			let mut file = FileItem::new(path).with_kind(kind);
 			if let Some(label_override) = item.label_override {
@@ -230,52 +422,68 @@ pub fn present_buffer(item: BufferItem<'_>, context: BufferDisplayContext<'_>) -
 			BufferPresentation::new(presentation.icon().to_string(), presentation.label().to_string())
 		}
 		BufferIdentity::Scratch => BufferPresentation::new(
-			SCRATCH_ICON.to_string(),
+			icon_glyph(Icon::Scratch, context.file.icons).to_string(),
 			item.label_override
 				.map(std::borrow::ToOwned::to_owned)
 				.unwrap_or_else(|| "[scratch]".to_string()),
 		),
 		BufferIdentity::Virtual(kind) => {
-			let (icon, label) = virtual_identity(kind, item.label_override);
-			BufferPresentation::new(icon, label)
+			let (icon, label) = virtual_identity(kind, item.label_override, context.file.icons);
+			return BufferPresentation::new(icon, label);
 		}
-	}
+	};
+	presentation.with_badges(badges)
 }
 
-fn virtual_identity(kind: VirtualBufferKind, label_override: Option<&str>) -> (String, String) {
-	match kind {
-		VirtualBufferKind::CommandPalette => (COMMAND_PALETTE_ICON.to_string(), "[Command Palette]".to_string()),
-		VirtualBufferKind::FilePicker => (FILE_PICKER_ICON.to_string(), "[File Picker]".to_string()),
-		VirtualBufferKind::Search => (SEARCH_ICON.to_string(), "[Search]".to_string()),
-		VirtualBufferKind::Rename => (RENAME_ICON.to_string(), "[Rename]".to_string()),
-		VirtualBufferKind::WorkspaceSearch => (WORKSPACE_SEARCH_ICON.to_string(), "[Workspace Search]".to_string()),
+fn buffer_badges(flags: BufferFlags, style: BadgeStyle) -> Vec<&'static str> {
+	[
+		(flags.modified, style.modified()),
+		(flags.new_file, style.new_file()),
+		(flags.readonly, style.readonly()),
+	]
+	.into_iter()
+	.filter_map(|(set, badge)| set.then_some(badge))
+	.collect()
+}
+
+fn virtual_identity(kind: VirtualBufferKind, label_override: Option<&str>, icons: IconStyle) -> (String, String) {
+	let (icon, label) = match kind {
+		VirtualBufferKind::CommandPalette => (Icon::CommandPalette, "[Command Palette]".to_string()),
+		VirtualBufferKind::FilePicker => (Icon::FilePicker, "[File Picker]".to_string()),
+		VirtualBufferKind::Search => (Icon::Search, "[Search]".to_string()),
+		VirtualBufferKind::Rename => (Icon::Rename, "[Rename]".to_string()),
+		VirtualBufferKind::WorkspaceSearch => (Icon::WorkspaceSearch, "[Workspace Search]".to_string()),
 		VirtualBufferKind::OverlayList => (
-			OVERLAY_LIST_ICON.to_string(),
+			Icon::OverlayList,
 			label_override.map(|label| format!("[{label} List]")).unwrap_or_else(|| "[List]".to_string()),
 		),
 		VirtualBufferKind::OverlayPreview => (
-			OVERLAY_PREVIEW_ICON.to_string(),
+			Icon::OverlayPreview,
 			label_override
 				.map(|label| format!("[{label} Preview]"))
 				.unwrap_or_else(|| "[Preview]".to_string()),
 		),
 		VirtualBufferKind::OverlayCustom(name) => (
-			OVERLAY_ICON.to_string(),
+			Icon::Overlay,
 			label_override
 				.map(std::borrow::ToOwned::to_owned)
 				.unwrap_or_else(|| format!("[Overlay: {name}]")),
 		),
-	}
+	};
+	(icon_glyph(icon, icons).to_string(), label)
 }
 
-/// Resolves the icon glyph for a file path.
-pub fn file_icon_for_path(path: &Path, kind: FileKind) -> String {
-	match kind {
-		FileKind::Directory => DIRECTORY_ICON.to_string(),
-		FileKind::File => {
+/// Resolves the icon glyph for a file path in `style`.
+///
+/// Only [`IconStyle::NerdFont`] consults the devicons table.
+pub fn file_icon_for_path(path: &Path, kind: FileKind, style: IconStyle) -> String {
+	match (kind, style) {
+		(FileKind::File, IconStyle::NerdFont) => {
 			let icon = FileIcon::from(path).icon;
 			if icon == '*' { GENERIC_FILE_ICON.to_string() } else { icon.to_string() }
 		}
+		(FileKind::File, style) => icon_glyph(Icon::File, style).to_string(),
+		(FileKind::Directory, style) => icon_glyph(Icon::Directory, style).to_string(),
 	}
 }
 
@@ -300,6 +508,8 @@ pub fn format_file_label(path: &Path, label_override: Option<&str>, context: Fil
 				path.display().to_string()
 			}
 		}
+		FileDisplayMode::Shortened { keep_last } => shorten_components(&display_base(path, context.working_dir), keep_last),
+		FileDisplayMode::TruncateMiddle { max_width } => truncate_middle(&display_base(path, context.working_dir), max_width),
 		FileDisplayMode::Absolute => {
 			if path.is_absolute() {
 				path.display().to_string()
@@ -314,25 +524,108 @@ pub fn format_file_label(path: &Path, label_override: Option<&str>, context: Fil
 	}
 }
 
+/// Returns `path` with a leading `home` replaced by `~`, or `None` when
+/// `path` is not inside `home`.
+pub fn abbreviate_home(path: &Path, home: &Path) -> Option<PathBuf> {
+	let rest = path.strip_prefix(home).ok()?;
+	let mut abbreviated = PathBuf::from("~");
+	if !rest.as_os_str().is_empty() {
+		abbreviated.push(rest);
+	}
+	Some(abbreviated)
+}
+
+fn home_dir() -> Option<PathBuf> {
+	std::env::var_os("HOME")
+		.or_else(|| std::env::var_os("USERPROFILE"))
+		.filter(|home| !home.is_empty())
+		.map(PathBuf::from)
+}
+
+/// Relativizes `path` to `working_dir`, falling back to `~` abbreviation for
+/// absolute paths outside it.
+fn display_base(path: &Path, working_dir: Option<&Path>) -> PathBuf {
+	if !path.is_absolute() {
+		return path.to_path_buf();
+	}
+	if let Some(rel) = working_dir.and_then(|dir| path.strip_prefix(dir).ok())
+		&& !rel.as_os_str().is_empty()
+	{
+		return rel.to_path_buf();
+	}
+	home_dir().and_then(|home| abbreviate_home(path, &home)).unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Cuts every component of `path` but the last `keep_last` to its first
+/// grapheme, keeping the leading `.` of hidden names.
+fn shorten_components(path: &Path, keep_last: usize) -> String {
+	let components: Vec<Component<'_>> = path.components().collect();
+	let keep_from = components.len().saturating_sub(keep_last.max(1));
+	let mut out = PathBuf::new();
+	for (idx, component) in components.iter().enumerate() {
+		match component {
+			Component::Normal(name) if idx < keep_from => {
+				let name = name.to_string_lossy();
+				let hidden = usize::from(name.starts_with('.') && name.len() > 1);
+				out.push(name.graphemes(true).take(1 + hidden).collect::<String>());
+			}
+			component => out.push(component),
+		}
+	}
+	out.display().to_string()
+}
+
+/// Replaces the middle of `path` with `…` so it fits `max_width` cells,
+/// cutting only at grapheme boundaries and favoring the tail on odd budgets.
+fn truncate_middle(path: &Path, max_width: usize) -> String {
+	let text = path.display().to_string();
+	if text.width() <= max_width {
+		return text;
+	}
+	let Some(budget) = max_width.checked_sub(1) else {
+		return String::new();
+	};
+
+	let graphemes: Vec<&str> = text.graphemes(true).collect();
+	let (mut head, mut tail) = (0, graphemes.len());
+	let mut head_width = 0;
+	let mut tail_width = 0;
+	let head_budget = budget / 2;
+	while let Some(width) = graphemes.get(head).map(|g| g.width())
+		&& head_width + width <= head_budget
+	{
+		head_width += width;
+		head += 1;
+	}
+	while tail > head
+		&& let Some(width) = graphemes.get(tail - 1).map(|g| g.width())
+		&& head_width + tail_width + width <= budget
+	{
+		tail_width += width;
+		tail -= 1;
+	}
+	format!("{}…{}", graphemes[..head].concat(), graphemes[tail..].concat())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
 	#[test]
 	fn file_icon_uses_generic_fallback_for_unknown_extension() {
-		let icon = file_icon_for_path(Path::new("notes.some_unknown_ext_xeno"), FileKind::File);
+		let icon = file_icon_for_path(Path::new("notes.some_unknown_ext_xeno"), FileKind::File, IconStyle::NerdFont);
 		assert_eq!(icon, GENERIC_FILE_ICON);
 	}
 
 	#[test]
 	fn file_icon_uses_directory_icon_for_directory_kind() {
-		let icon = file_icon_for_path(Path::new("src"), FileKind::Directory);
+		let icon = file_icon_for_path(Path::new("src"), FileKind::Directory, IconStyle::NerdFont);
 		assert_eq!(icon, DIRECTORY_ICON);
 	}
 
 	#[test]
 	fn file_icon_uses_devicon_for_known_filetypes() {
-		let icon = file_icon_for_path(Path::new("Cargo.toml"), FileKind::File);
+		let icon = file_icon_for_path(Path::new("Cargo.toml"), FileKind::File, IconStyle::NerdFont);
 		assert_ne!(icon, GENERIC_FILE_ICON);
 		assert_ne!(icon, "*");
 	}
@@ -345,6 +638,7 @@ mod tests {
 			FileDisplayContext {
 				mode: FileDisplayMode::AsProvided,
 				working_dir: None,
+				..Default::default()
 			},
 		);
 		assert_eq!(label, "../alias-name.txt");
@@ -358,6 +652,7 @@ mod tests {
 			FileDisplayContext {
 				mode: FileDisplayMode::RelativeToWorkingDir,
 				working_dir: Some(Path::new("/tmp/xeno")),
+				..Default::default()
 			},
 		);
 		assert_eq!(label, "src/main.rs");
@@ -414,4 +709,150 @@ mod tests {
 		assert_eq!(presentation.label(), "[Workspace Search List]");
 		assert_eq!(presentation.icon(), OVERLAY_LIST_ICON);
 	}
+
+	#[test]
+	fn present_buffer_appends_badges_in_order() {
+		let all = BufferFlags {
+			modified: true,
+			readonly: true,
+			new_file: true,
+		};
+		let cases = [
+			(BufferFlags::default(), BadgeStyle::Unicode, "main.rs", &[][..]),
+			(
+				BufferFlags {
+					modified: true,
+					..Default::default()
+				},
+				BadgeStyle::Unicode,
+				"main.rs ●",
+				&[MODIFIED_BADGE][..],
+			),
+			(
+				BufferFlags {
+					readonly: true,
+					..Default::default()
+				},
+				BadgeStyle::Ascii,
+				"main.rs [RO]",
+				&[READONLY_BADGE_ASCII][..],
+			),
+			(
+				all,
+				BadgeStyle::Ascii,
+				"main.rs + [New] [RO]",
+				&[MODIFIED_BADGE_ASCII, NEW_FILE_BADGE_ASCII, READONLY_BADGE_ASCII][..],
+			),
+			(all, BadgeStyle::Unicode, "main.rs ● ✦ ", &[MODIFIED_BADGE, NEW_FILE_BADGE, READONLY_BADGE][..]),
+		];
+		for (flags, badges, label, expected) in cases {
+			let context = BufferDisplayContext { badges, ..Default::default() };
+			let presentation = present_buffer(BufferItem::file(Path::new("main.rs")).with_flags(flags), context);
+			assert_eq!(presentation.label(), label);
+			assert_eq!(presentation.badges(), expected);
+		}
+	}
+
+	#[test]
+	fn badges_follow_label_override_and_skip_virtual_buffers() {
+		let flags = BufferFlags {
+			modified: true,
+			readonly: true,
+			new_file: false,
+		};
+		let context = BufferDisplayContext {
+			badges: BadgeStyle::Ascii,
+			..Default::default()
+		};
+
+		let file = present_buffer(
+			BufferItem::file(Path::new("/tmp/real.rs")).with_label_override("alias.rs").with_flags(flags),
+			context,
+		);
+		assert_eq!(file.label(), "alias.rs + [RO]");
+
+		let scratch = present_buffer(BufferItem::scratch().with_flags(flags), context);
+		assert_eq!(scratch.label(), "[scratch] + [RO]");
+
+		let palette = present_buffer(BufferItem::virtual_buffer(VirtualBufferKind::CommandPalette).with_flags(flags), context);
+		assert_eq!(palette.label(), "[Command Palette]");
+		assert!(palette.badges().is_empty());
+	}
+
+	#[test]
+	fn icon_styles_cover_files_directories_and_virtual_buffers() {
+		let context = |icons| BufferDisplayContext {
+			file: FileDisplayContext { icons, ..Default::default() },
+			..Default::default()
+		};
+		let file = |icons| present_buffer(BufferItem::file(Path::new("Cargo.toml")), context(icons)).icon().to_string();
+		let dir = |icons| {
+			present_buffer(BufferItem::file(Path::new("src")).with_file_kind(FileKind::Directory), context(icons))
+				.icon()
+				.to_string()
+		};
+		let palette = |icons| present_buffer(BufferItem::virtual_buffer(VirtualBufferKind::CommandPalette), context(icons));
+
+		assert_ne!(file(IconStyle::NerdFont), GENERIC_FILE_ICON, "devicons refine nerd-font file icons");
+		assert_eq!(dir(IconStyle::NerdFont), DIRECTORY_ICON);
+		assert_eq!(palette(IconStyle::NerdFont).icon(), COMMAND_PALETTE_ICON);
+
+		assert_eq!(file(IconStyle::Ascii), "[f]");
+		assert_eq!(dir(IconStyle::Ascii), "[d]");
+		assert_eq!(palette(IconStyle::Ascii).icon(), "[cmd]");
+
+		assert_eq!(file(IconStyle::None), "");
+		assert_eq!(dir(IconStyle::None), "");
+		assert_eq!(palette(IconStyle::None).icon(), "");
+		assert_eq!(palette(IconStyle::None).label(), "[Command Palette]");
+	}
+
+	#[test]
+	fn abbreviate_home_substitutes_tilde_only_inside_home() {
+		let home = Path::new("/home/xeno");
+		assert_eq!(
+			abbreviate_home(Path::new("/home/xeno/.config/xeno/config.kdl"), home),
+			Some(PathBuf::from("~/.config/xeno/config.kdl"))
+		);
+		assert_eq!(abbreviate_home(home, home), Some(PathBuf::from("~")));
+		assert_eq!(abbreviate_home(Path::new("/home/xenophon/notes.md"), home), None);
+	}
+
+	#[test]
+	fn shortened_mode_keeps_first_grapheme_of_unicode_components() {
+		let context = FileDisplayContext {
+			mode: FileDisplayMode::Shortened { keep_last: 1 },
+			working_dir: Some(Path::new("/work")),
+			..Default::default()
+		};
+		let label = format_file_label(Path::new("/work/.config/e\u{301}tudes/日本/config.kdl"), None, context);
+		assert_eq!(label, ".c/e\u{301}/日/config.kdl");
+
+		let context = FileDisplayContext {
+			mode: FileDisplayMode::Shortened { keep_last: 2 },
+			..context
+		};
+		assert_eq!(format_file_label(Path::new("src/render/buffer/mod.rs"), None, context), "s/r/buffer/mod.rs");
+		assert_eq!(shorten_components(Path::new("/usr/lib/libc.so"), 0), "/u/l/libc.so");
+	}
+
+	#[test]
+	fn truncate_middle_fits_width_without_splitting_wide_graphemes() {
+		let context = |max_width| FileDisplayContext {
+			mode: FileDisplayMode::TruncateMiddle { max_width },
+			working_dir: Some(Path::new("/work")),
+			..Default::default()
+		};
+		let path = Path::new("/work/src/日本語/main.rs");
+
+		assert_eq!(format_file_label(path, None, context(18)), "src/日本語/main.rs", "exact fit is kept");
+		assert_eq!(format_file_label(path, None, context(17)), "src/日本…/main.rs");
+		assert_eq!(format_file_label(path, None, context(14)), "src/日…main.rs");
+		assert_eq!(format_file_label(path, None, context(15)), "src/日…/main.rs");
+		assert_eq!(format_file_label(path, None, context(1)), "…");
+		assert_eq!(format_file_label(path, None, context(0)), "");
+		for max_width in 0..=18 {
+			assert!(format_file_label(path, None, context(max_width)).width() <= max_width);
+		}
+	}
 }
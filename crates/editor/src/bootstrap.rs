@@ -213,10 +213,12 @@ fn create_bootstrap_theme(colors: &BootstrapColors) -> &'static Theme {
 			id: "bootstrap",
 			name: "bootstrap",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: -1000,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		variant: colors.variant.into(),
 		colors: ThemeColors {
@@ -32,6 +32,35 @@ fn col_to_char_offset(segment: &WrapSegment, text_col: usize, tab_width: usize)
 	(segment.start_offset + last_i, false)
 }
 
+/// Returns the screen width of `ch` drawn at display column `col`.
+fn char_width(ch: char, col: usize, tab_width: usize) -> usize {
+	if ch == '\t' {
+		let tab_width = tab_width.max(1);
+		tab_width - col % tab_width
+	} else {
+		1
+	}
+}
+
+/// Returns the display column of character `char_col` in `line`.
+fn display_col(line: &str, char_col: usize, tab_width: usize) -> usize {
+	line.chars().take(char_col).fold(0, |col, ch| col + char_width(ch, col, tab_width))
+}
+
+/// Returns the character of `line` drawn at `display_col`, or the line length
+/// if the line ends before it.
+fn char_col_at(line: &str, display_col: usize, tab_width: usize) -> usize {
+	let mut col = 0;
+	for (i, ch) in line.chars().enumerate() {
+		let width = char_width(ch, col, tab_width);
+		if display_col < col + width {
+			return i;
+		}
+		col += width;
+	}
+	line.chars().count()
+}
+
 impl Buffer {
 	/// Moves cursors vertically, accounting for line wrapping.
 	///
@@ -55,6 +84,7 @@ impl Buffer {
 			self.compute_column_in_line(primary.head)
 		});
 		self.goal_column = Some(goal_col);
+		self.add_cursor_column = None;
 
 		let mut new_ranges = Vec::with_capacity(ranges.len());
 
@@ -79,6 +109,54 @@ impl Buffer {
 		self.cursor = self.selection.primary().head;
 	}
 
+	/// Adds a cursor `count` lines below (Forward) or above (Backward) the
+	/// outermost selection and makes it primary.
+	///
+	/// The new cursor lands in the primary cursor's display column, with tabs
+	/// expanded to `tab_width`. Shorter lines clamp it to their end; the column
+	/// is remembered so later adds return to it on longer lines.
+	///
+	/// Returns false if the target line is outside the document.
+	pub fn add_cursor_vertical(&mut self, direction: MoveDir, count: usize, tab_width: usize) -> bool {
+		self.ensure_valid_selection();
+		let count = count.max(1);
+		let primary_head = self.selection.primary().head;
+		let heads: Vec<usize> = self.selection.ranges().iter().map(|range| range.head).collect();
+
+		let target = self.with_doc(|doc| {
+			let content = doc.content();
+			let lines = heads.iter().map(|&head| content.char_to_line(head));
+			let target_line = match direction {
+				MoveDir::Forward => lines.max()?.checked_add(count).filter(|&line| line < visible_line_count(content.slice(..)))?,
+				MoveDir::Backward => lines.min()?.checked_sub(count)?,
+			};
+
+			let goal = self.add_cursor_column.unwrap_or_else(|| {
+				let line = content.char_to_line(primary_head);
+				let text: String = content.line(line).into();
+				display_col(&text, primary_head - content.line_to_char(line), tab_width)
+			});
+
+			let text: String = content.line(target_line).into();
+			let has_newline = text.ends_with('\n');
+			let text = text.trim_end_matches('\n');
+			let len = text.chars().count();
+			let max_col = if has_newline { len } else { len.saturating_sub(1) };
+			Some((goal, content.line_to_char(target_line) + char_col_at(text, goal, tab_width).min(max_col)))
+		});
+
+		let Some((goal, pos)) = target else {
+			return false;
+		};
+		self.add_cursor_column = Some(goal);
+		self.selection.push(Range::point(pos));
+		if let Some(index) = self.selection.ranges().iter().position(|range| range.head == pos) {
+			self.selection.set_primary(index);
+		}
+		self.cursor = self.selection.primary().head;
+		true
+	}
+
 	/// Computes the column position of a cursor within its line.
 	fn compute_column_in_line(&self, cursor: usize) -> usize {
 		self.with_doc(|doc| {
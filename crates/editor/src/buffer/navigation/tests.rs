@@ -82,3 +82,62 @@ fn goal_column_preserved_moving_up() {
 	assert_eq!(buffer.cursor, 15); // restored to col 15
 	assert_eq!(buffer.goal_column, Some(15));
 }
+
+fn heads(buffer: &Buffer) -> Vec<usize> {
+	buffer.selection.ranges().iter().map(|range| range.head).collect()
+}
+
+#[test]
+fn add_cursor_below_clamps_on_short_lines_and_restores_column() {
+	// Lines: "hello world" / "hi" / "" / "longer line"
+	let mut buffer = make_buffer("hello world\nhi\n\nlonger line");
+	buffer.cursor = 8;
+	buffer.selection = xeno_primitives::Selection::point(8);
+
+	// "hi" clamps to its newline
+	assert!(buffer.add_cursor_vertical(MoveDir::Forward, 1, 4));
+	assert_eq!(heads(&buffer), [8, 14]);
+	assert_eq!(buffer.cursor, 14);
+	assert_eq!(buffer.add_cursor_column, Some(8));
+
+	// The empty line clamps to its start
+	assert!(buffer.add_cursor_vertical(MoveDir::Forward, 1, 4));
+	assert_eq!(heads(&buffer), [8, 14, 15]);
+
+	// The long line restores column 8
+	assert!(buffer.add_cursor_vertical(MoveDir::Forward, 1, 4));
+	assert_eq!(heads(&buffer), [8, 14, 15, 24]);
+	assert_eq!(buffer.selection.primary().head, 24);
+
+	assert!(!buffer.add_cursor_vertical(MoveDir::Forward, 1, 4));
+	assert_eq!(heads(&buffer), [8, 14, 15, 24]);
+}
+
+#[test]
+fn add_cursor_above_keeps_display_column_across_tabs() {
+	// Lines: "abcdefgh" / "\tx"
+	for (tab_width, expected) in [(4, 4), (2, 2)] {
+		let mut buffer = make_buffer("abcdefgh\n\tx");
+		buffer.cursor = 10;
+		buffer.selection = xeno_primitives::Selection::point(10);
+
+		assert!(buffer.add_cursor_vertical(MoveDir::Backward, 1, tab_width));
+		assert_eq!(heads(&buffer), [expected, 10]);
+		assert_eq!(buffer.cursor, expected);
+
+		assert!(!buffer.add_cursor_vertical(MoveDir::Backward, 1, tab_width));
+	}
+}
+
+#[test]
+fn add_cursor_column_reset_by_set_selection() {
+	let mut buffer = make_buffer("hello\nworld");
+	buffer.cursor = 2;
+	buffer.selection = xeno_primitives::Selection::point(2);
+
+	assert!(buffer.add_cursor_vertical(MoveDir::Forward, 1, 4));
+	assert_eq!(buffer.add_cursor_column, Some(2));
+
+	buffer.set_selection(xeno_primitives::Selection::point(0));
+	assert_eq!(buffer.add_cursor_column, None);
+}
@@ -134,6 +134,8 @@ pub struct Buffer {
 	readonly_override: Option<bool>,
 	/// Remembered column for vertical navigation (j/k) stability.
 	pub(in crate::buffer) goal_column: Option<usize>,
+	/// Remembered display column (tabs expanded) for adding cursors vertically.
+	pub(in crate::buffer) add_cursor_column: Option<usize>,
 }
 
 impl Buffer {
@@ -155,6 +157,7 @@ impl Buffer {
 			local_options: OptionStore::new(),
 			readonly_override: None,
 			goal_column: None,
+			add_cursor_column: None,
 		}
 	}
 
@@ -183,6 +186,7 @@ impl Buffer {
 			local_options: self.local_options.clone(),
 			readonly_override: None,
 			goal_column: None,
+			add_cursor_column: None,
 		}
 	}
 
@@ -373,18 +377,20 @@ impl Buffer {
 			.expect("option type mismatch with registered default")
 	}
 
-	/// Sets cursor position and resets goal column.
+	/// Sets cursor position and resets goal columns.
 	#[inline]
 	pub fn set_cursor(&mut self, pos: CharIdx) {
 		self.cursor = pos;
 		self.goal_column = None;
+		self.add_cursor_column = None;
 	}
 
-	/// Sets selection and resets goal column.
+	/// Sets selection and resets goal columns.
 	#[inline]
 	pub fn set_selection(&mut self, sel: Selection) {
 		self.selection = sel;
 		self.goal_column = None;
+		self.add_cursor_column = None;
 	}
 
 	/// Syncs cursor to the selection head without resetting goal column.
@@ -393,12 +399,13 @@ impl Buffer {
 		self.cursor = self.selection.primary().head;
 	}
 
-	/// Sets both cursor and selection, resetting goal column.
+	/// Sets both cursor and selection, resetting goal columns.
 	#[inline]
 	pub fn set_cursor_and_selection(&mut self, pos: CharIdx, sel: Selection) {
 		self.cursor = pos;
 		self.selection = sel;
 		self.goal_column = None;
+		self.add_cursor_column = None;
 	}
 
 	/// Maintains the horizontal position (goal column) during vertical movement.
@@ -39,9 +39,17 @@ impl Editor {
 		active.session.virtual_identity_for_buffer(view_id).cloned()
 	}
 
-	/// Resolves icon + label presentation for a view buffer.
+	/// Resolves icon + label presentation for a view buffer, with icons from
+	/// the `icons` option.
 	pub fn buffer_presentation(&self, view_id: ViewId) -> xeno_buffer_display::BufferPresentation {
-		let context = xeno_buffer_display::BufferDisplayContext::default();
+		let icons = self.option(xeno_registry::options::option_keys::ICONS);
+		let context = xeno_buffer_display::BufferDisplayContext {
+			file: xeno_buffer_display::FileDisplayContext {
+				icons: xeno_buffer_display::IconStyle::from_option(&icons).unwrap_or_default(),
+				..Default::default()
+			},
+			..Default::default()
+		};
 
 		match self.resolve_buffer_identity(view_id) {
 			ResolvedBufferIdentity::File(path) => xeno_buffer_display::present_buffer(xeno_buffer_display::BufferItem::file(path.as_path()), context),
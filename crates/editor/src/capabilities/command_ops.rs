@@ -4,10 +4,10 @@ use xeno_primitives::BoxFutureLocal;
 use xeno_registry::HookEventData;
 use xeno_registry::actions::editor_ctx::OverlayRequest;
 use xeno_registry::actions::{EditorCapabilities, FileOpsAccess, NotificationAccess, ThemeAccess};
-use xeno_registry::commands::{CommandEditorOps, CommandError};
-use xeno_registry::hooks::{HookContext, emit_sync_with as emit_hook_sync_with};
+use xeno_registry::commands::{BackgroundTask, CommandEditorOps, CommandError, ConfirmationResult, TaskId, TaskInfo};
+use xeno_registry::hooks::{HookContext, emit as emit_hook};
 use xeno_registry::notifications::Notification;
-use xeno_registry::options::{OptionScope, find};
+use xeno_registry::options::{OptionError, OptionScope, find};
 
 use crate::capabilities::provider::EditorCaps;
 use crate::runtime::work_queue::RuntimeWorkSource;
@@ -47,18 +47,15 @@ impl CommandEditorOps for EditorCaps<'_> {
 
 	fn set_option(&mut self, key: &str, value: &str) -> Result<(), CommandError> {
 		let opt_value = super::parse_option_value(key, value)?;
-		let _ = self.ed.state.config.config.global_options.set_by_key(&xeno_registry::OPTIONS, key, opt_value);
-
-		if let Some(def) = find(key) {
-			let resolved_key = def.name_str();
-			emit_hook_sync_with(
-				&HookContext::new(HookEventData::OptionChanged {
-					key: resolved_key,
-					scope: "global",
-				}),
-				&mut self.ed.state.integration.work_scheduler,
-			);
-		}
+		self.ed
+			.state
+			.config
+			.config
+			.global_options
+			.set_by_key(&xeno_registry::OPTIONS, key, opt_value)
+			.map_err(super::option_error)?;
+		self.ed.state.runtime.effects.request_redraw();
+		self.ed.drain_option_hooks();
 		Ok(())
 	}
 
@@ -79,19 +76,21 @@ impl CommandEditorOps for EditorCaps<'_> {
 		}
 
 		let opt_value = super::parse_option_value(key, value)?;
-		let _ = self.ed.buffer_mut().local_options.set_by_key(&xeno_registry::OPTIONS, key, opt_value);
-
-		let resolved_key = def.name_str();
-		emit_hook_sync_with(
-			&HookContext::new(HookEventData::OptionChanged {
-				key: resolved_key,
-				scope: "buffer",
-			}),
-			&mut self.ed.state.integration.work_scheduler,
-		);
+		let option_hooks = self.ed.state.config.config.option_hooks.clone();
+		let local_options = &mut self.ed.buffer_mut().local_options;
+		local_options.emit_hooks("buffer", option_hooks);
+		local_options.set_by_key(&xeno_registry::OPTIONS, key, opt_value).map_err(super::option_error)?;
+		self.ed.state.runtime.effects.request_redraw();
+		self.ed.drain_option_hooks();
 		Ok(())
 	}
 
+	fn describe_option(&self, key: &str) -> Result<String, CommandError> {
+		let opt = find(key).ok_or_else(|| super::option_error(OptionError::UnknownOption(key.to_string())))?;
+		let (value, origin) = self.ed.resolve_option_origin(self.ed.focused_view(), &opt);
+		Ok(format!("{value} ({origin})"))
+	}
+
 	fn open_info_popup(&mut self, content: &str, _file_type: Option<&str>) {
 		self.ed.state.runtime.effects.overlay_request(OverlayRequest::ShowInfoPopup {
 			title: None,
@@ -103,6 +102,10 @@ impl CommandEditorOps for EditorCaps<'_> {
 		// TODO: Add CloseInfoPopups to OverlayRequest if needed
 	}
 
+	fn recent_commands(&self, limit: usize) -> Vec<String> {
+		self.ed.state.telemetry.command_history.iter_rev().take(limit).map(str::to_string).collect()
+	}
+
 	fn insert_snippet_body(&mut self, body: &str) -> bool {
 		self.ed.insert_snippet_body(body)
 	}
@@ -130,4 +133,44 @@ impl CommandEditorOps for EditorCaps<'_> {
 	fn queue_invocation(&mut self, request: xeno_registry::actions::DeferredInvocationRequest) {
 		self.ed.enqueue_runtime_invocation_request(request, RuntimeWorkSource::CommandOps);
 	}
+
+	/// Accepts a prompt the user already granted through the confirm overlay.
+	///
+	/// Otherwise, unless a [`HookEvent::ConfirmRequest`] hook cancels, records
+	/// the prompt and declines. The command future holds the editor borrow, so
+	/// the invocation path opens the yes/no overlay once the command returns
+	/// and re-dispatches the command if the user accepts.
+	///
+	/// [`HookEvent::ConfirmRequest`]: xeno_registry::HookEvent::ConfirmRequest
+	fn confirm(&mut self, prompt: &str) -> BoxFutureLocal<'_, ConfirmationResult> {
+		let prompt = prompt.to_string();
+		Box::pin(async move {
+			if self.ed.state.ui.confirmation.granted.take_if(|granted| *granted == prompt).is_some() {
+				return ConfirmationResult::Accept;
+			}
+			let result = emit_hook(&HookContext::new(HookEventData::ConfirmRequest { prompt: &prompt })).await;
+			if !result.is_cancel() {
+				self.ed.state.ui.confirmation.pending = Some(prompt);
+			}
+			ConfirmationResult::Decline
+		})
+	}
+
+	fn confirmation_pending(&self) -> bool {
+		self.ed.state.ui.confirmation.pending.is_some()
+	}
+
+	fn spawn_background(&mut self, name: String, task: BackgroundTask) -> TaskId {
+		let (id, future) = self.ed.state.integration.background_tasks.start(name, task);
+		xeno_worker::spawn(xeno_worker::TaskClass::Background, future);
+		id
+	}
+
+	fn background_tasks(&self) -> Vec<TaskInfo> {
+		self.ed.state.integration.background_tasks.list()
+	}
+
+	fn cancel_background(&mut self, id: TaskId) -> bool {
+		self.ed.state.integration.background_tasks.cancel(id)
+	}
 }
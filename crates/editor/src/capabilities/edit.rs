@@ -7,11 +7,7 @@ impl EditAccess for EditorCaps<'_> {
 		self.ed.execute_edit_op(op.clone());
 	}
 
-	fn paste(&mut self, before: bool) {
-		if before {
-			self.ed.paste_before();
-		} else {
-			self.ed.paste_after();
-		}
+	fn paste(&mut self, before: bool, count: usize, register: Option<char>) {
+		self.ed.paste_register(before, count, register);
 	}
 }
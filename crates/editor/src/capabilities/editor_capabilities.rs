@@ -70,4 +70,8 @@ impl EditorCapabilities for EditorCaps<'_> {
 	fn is_readonly(&self) -> bool {
 		self.ed.buffer().is_readonly()
 	}
+
+	fn repeat_last_action(&mut self) -> xeno_registry::actions::HandleOutcome {
+		self.ed.repeat_last_action()
+	}
 }
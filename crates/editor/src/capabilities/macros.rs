@@ -1,21 +1,25 @@
-use xeno_registry::actions::MacroAccess;
+use xeno_registry::actions::{HandleOutcome, MacroAccess};
 
 use crate::capabilities::provider::EditorCaps;
 
 impl MacroAccess for EditorCaps<'_> {
-	fn record(&mut self) {
-		self.ed.state.core.editor.workspace.macro_state.start_recording('q');
+	fn record(&mut self, register: char) {
+		self.ed.state.core.editor.workspace.macro_state.start_recording(register);
 	}
 
 	fn stop_recording(&mut self) {
 		self.ed.state.core.editor.workspace.macro_state.stop_recording();
 	}
 
-	fn play(&mut self) {
-		// TODO: Requires event loop integration
+	fn play(&mut self, register: char, count: usize) -> HandleOutcome {
+		self.ed.replay_macro(register, count)
 	}
 
 	fn is_recording(&self) -> bool {
 		self.ed.state.core.editor.workspace.macro_state.is_recording()
 	}
+
+	fn last_register(&self) -> Option<char> {
+		self.ed.state.core.editor.workspace.macro_state.last_register()
+	}
 }
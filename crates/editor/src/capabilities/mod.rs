@@ -35,16 +35,19 @@ pub mod viewport;
 mod invariants;
 
 use xeno_registry::commands::CommandError;
-use xeno_registry::options::{OptionValue, parse};
+use xeno_registry::options::{OptionError, OptionValue, parse};
 
 /// Parses a string value into an [`OptionValue`] based on the option's declared type.
 ///
 /// Uses centralized validation from the options registry, including type checking
 /// and any custom validators defined on the option.
 pub(crate) fn parse_option_value(key: &str, value: &str) -> Result<OptionValue, CommandError> {
-	use xeno_registry::options::OptionError;
+	parse::parse_value(key, value).map_err(option_error)
+}
 
-	parse::parse_value(key, value).map_err(|e| match e {
+/// Maps an option parse or validation failure to a user-facing command error.
+pub(crate) fn option_error(e: OptionError) -> CommandError {
+	match e {
 		OptionError::UnknownOption(key) => {
 			let suggestion = parse::suggest_option(&key);
 			match suggestion {
@@ -56,5 +59,5 @@ pub(crate) fn parse_option_value(key: &str, value: &str) -> Result<OptionValue,
 		OptionError::TypeMismatch { option, expected, got } => {
 			CommandError::InvalidArgument(format!("type mismatch for {option}: expected {expected:?}, got {got}"))
 		}
-	})
+	}
 }
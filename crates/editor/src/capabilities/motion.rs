@@ -11,4 +11,11 @@ impl MotionAccess for EditorCaps<'_> {
 		self.ed.snippet_session_on_cursor_moved(view);
 		self.ed.state.runtime.effects.push_layer_event(LayerEvent::CursorMoved { view });
 	}
+
+	fn add_cursor_vertical(&mut self, direction: Direction, count: usize) {
+		let view = self.ed.focused_view();
+		self.ed.add_cursor_vertical(direction, count);
+		self.ed.snippet_session_on_cursor_moved(view);
+		self.ed.state.runtime.effects.push_layer_event(LayerEvent::CursorMoved { view });
+	}
 }
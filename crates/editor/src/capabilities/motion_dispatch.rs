@@ -1,7 +1,7 @@
 use tracing::warn;
 use xeno_primitives::{Mode, Selection};
 use xeno_registry::actions::{MotionDispatchAccess, MotionKind, MotionRequest};
-use xeno_registry::motions;
+use xeno_registry::motions::{self, Capability, SyntaxTree};
 
 use crate::capabilities::provider::EditorCaps;
 
@@ -18,13 +18,23 @@ impl MotionDispatchAccess for EditorCaps<'_> {
 
 		let MotionRequest { count, extend, kind, .. } = *req;
 
-		let new_ranges = self.ed.buffer().with_doc(|doc| {
+		let buffer = self.ed.buffer();
+		let tree = match handler.capability() {
+			Capability::Text => None,
+			Capability::Syntax => self
+				.ed
+				.syntax_manager()
+				.syntax_for_doc(buffer.document_id())
+				.map(|syntax| syntax as &dyn SyntaxTree),
+		};
+
+		let new_ranges = buffer.with_doc(|doc| {
 			let text = doc.content().slice(..);
 			selection
 				.ranges()
 				.iter()
 				.map(|range| {
-					let mut target = handler(text, *range, count, extend);
+					let mut target = handler.apply(text, tree, *range, count, extend);
 
 					if is_normal {
 						target.head = xeno_primitives::clamp_to_cell(target.head, text);
@@ -12,4 +12,9 @@ impl NotificationAccess for EditorCaps<'_> {
 		self.ed.clear_all_notifications();
 		self.ed.state.runtime.effects.request_redraw();
 	}
+
+	fn toggle_do_not_disturb(&mut self) {
+		self.ed.toggle_do_not_disturb();
+		self.ed.state.runtime.effects.request_redraw();
+	}
 }
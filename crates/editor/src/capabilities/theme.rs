@@ -1,3 +1,4 @@
+use xeno_primitives::ViewId;
 use xeno_registry::actions::ThemeAccess;
 use xeno_registry::commands::CommandError;
 
@@ -8,4 +9,12 @@ impl ThemeAccess for EditorCaps<'_> {
 	fn set_theme(&mut self, name: &str) -> Result<(), CommandError> {
 		Editor::set_theme(self.ed, name)
 	}
+
+	fn set_buffer_theme(&mut self, buffer: ViewId, name: &str) -> Result<(), CommandError> {
+		Editor::set_buffer_theme(self.ed, buffer, name)
+	}
+
+	fn clear_buffer_theme(&mut self, buffer: ViewId) {
+		Editor::clear_buffer_theme(self.ed, buffer);
+	}
 }
@@ -18,4 +18,16 @@ impl UndoAccess for EditorCaps<'_> {
 	fn can_redo(&self) -> bool {
 		self.ed.state.core.editor.undo_manager.can_redo()
 	}
+
+	fn begin_batch(&mut self) {
+		self.ed.begin_undo_batch();
+	}
+
+	fn end_batch(&mut self) -> bool {
+		self.ed.end_undo_batch()
+	}
+
+	fn batch_rejected(&self) -> bool {
+		self.ed.state.core.editor.undo_manager.batch_rejected()
+	}
 }
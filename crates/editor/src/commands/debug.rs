@@ -28,6 +28,15 @@ editor_command!(
 	handler: cmd_registry
 );
 
+editor_command!(
+	undo_history,
+	{
+		keys: &["undo-history", "undolist"],
+		description: "List undo steps, newest first"
+	},
+	handler: cmd_undo_history
+);
+
 editor_command!(
 	files,
 	{
@@ -44,6 +53,24 @@ fn cmd_files<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Re
 	})
 }
 
+fn cmd_undo_history<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let groups = ctx.editor.state.core.editor.undo_manager.undo_groups();
+		let mut content = String::from("# Undo History\n");
+		if groups.is_empty() {
+			content.push_str("\nNothing to undo\n");
+		}
+		for (depth, group) in groups.iter().rev().enumerate() {
+			match &group.description {
+				Some(description) => content.push_str(&format!("\n{}. {description}", depth + 1)),
+				None => content.push_str(&format!("\n{}. {:?}", depth + 1, group.origin)),
+			}
+		}
+		crate::Editor::open_info_popup(ctx.editor, content, Some("markdown"), PopupAnchor::Center);
+		Ok(CommandOutcome::Ok)
+	})
+}
+
 fn cmd_stats<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
 		let stats = ctx.editor.stats_snapshot();
@@ -0,0 +1,81 @@
+//! Line-oriented editing commands that honor ex-style ranges (`:%d`, `:10,20>`).
+//!
+//! Without a range both commands act on the cursor line. The range only
+//! constrains the selection; the edit itself is the same [`edit_op`] the
+//! matching action compiles.
+
+use xeno_primitives::{BoxFutureLocal, Range, Selection};
+use xeno_registry::actions::edit_op;
+use xeno_registry::commands::LineAddr;
+
+use super::{CommandError, CommandOutcome, CommandRange, EditorCommandContext, flags};
+use crate::Editor;
+use crate::editor_command;
+
+editor_command!(
+	delete_lines,
+	{
+		keys: &["delete-lines", "d"],
+		description: "Delete lines in a range (default: cursor line)",
+		mutates_buffer: true,
+		flags: flags::ACCEPTS_RANGE
+	},
+	handler: cmd_delete_lines
+);
+
+editor_command!(
+	indent,
+	{
+		keys: &[">"],
+		description: "Indent lines in a range (default: cursor line)",
+		mutates_buffer: true,
+		flags: flags::ACCEPTS_RANGE
+	},
+	handler: cmd_indent
+);
+
+fn cmd_delete_lines<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let (first, last) = resolve_lines(ctx.editor, ctx.range)?;
+		let (start, end) = ctx.editor.buffer().with_doc(|doc| {
+			let text = doc.content();
+			let end = if last + 1 < text.len_lines() {
+				text.line_to_char(last + 1)
+			} else {
+				text.len_chars()
+			};
+			(text.line_to_char(first), end)
+		});
+		if start == end {
+			return Ok(CommandOutcome::Ok);
+		}
+		ctx.editor
+			.buffer_mut()
+			.set_cursor_and_selection(start, Selection::from_vec(vec![Range::from_exclusive(start, end)], 0));
+		ctx.editor.execute_edit_op(edit_op::delete(true));
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+fn cmd_indent<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let (first, last) = resolve_lines(ctx.editor, ctx.range)?;
+		let starts: Vec<Range> = ctx
+			.editor
+			.buffer()
+			.with_doc(|doc| (first..=last).map(|line| Range::point(doc.content().line_to_char(line))).collect());
+		ctx.editor.buffer_mut().set_cursor_and_selection(starts[0].head, Selection::from_vec(starts, 0));
+		ctx.editor.execute_edit_op(edit_op::indent());
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+/// Resolves `range` (default: the cursor line) to inclusive 0-based lines.
+fn resolve_lines(editor: &Editor, range: Option<CommandRange>) -> Result<(usize, usize), CommandError> {
+	let range = range.unwrap_or(CommandRange::line(LineAddr::Current));
+	let buffer = editor.buffer();
+	buffer.with_doc(|doc| {
+		let text = doc.content();
+		range.resolve(text.char_to_line(buffer.cursor), text.len_lines())
+	})
+}
@@ -16,16 +16,18 @@
 
 mod config;
 mod debug;
+mod lines;
 #[cfg(feature = "lsp")]
 mod lsp;
 mod nu;
+mod selection;
 
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use xeno_primitives::BoxFutureLocal;
 pub use xeno_registry::RegistrySource;
-pub use xeno_registry::commands::{CommandError, CommandOutcome};
+pub use xeno_registry::commands::{CommandError, CommandOutcome, CommandRange, flags};
 
 use crate::Editor;
 
@@ -41,6 +43,9 @@ pub struct EditorCommandContext<'a> {
 	pub editor: &'a mut Editor,
 	/// Command arguments (space-separated tokens after command name).
 	pub args: &'a [&'a str],
+	/// Line range prefixed to the command name, only for commands that set
+	/// [`flags::ACCEPTS_RANGE`].
+	pub range: Option<CommandRange>,
 }
 
 /// Function signature for async editor-direct command handlers.
@@ -64,6 +69,15 @@ pub struct EditorCommandDef {
 	pub priority: i16,
 	/// Where this command was registered from.
 	pub source: RegistrySource,
+	/// Behavior flags (see [`flags`]).
+	pub flags: u32,
+}
+
+impl EditorCommandDef {
+	/// Returns whether the command honors a line range prefix.
+	pub fn accepts_range(&self) -> bool {
+		self.flags & flags::ACCEPTS_RANGE != 0
+	}
 }
 
 /// O(1) editor command lookup index by name and keys.
@@ -98,6 +112,7 @@ macro_rules! editor_command {
 		description: $desc:expr
 		$(, mutates_buffer: $mutates:expr)?
 		$(, priority: $priority:expr)?
+		$(, flags: $flags:expr)?
 		$(,)?
 	}, handler: $handler:expr) => {
 		paste::paste! {
@@ -112,6 +127,7 @@ macro_rules! editor_command {
 					handler: $handler,
 					priority: $crate::__editor_cmd_opt!($({$priority})?, 0),
 					source: $crate::commands::RegistrySource::Crate(env!("CARGO_PKG_NAME")),
+					flags: $crate::__editor_cmd_opt!($({$flags})?, $crate::commands::flags::NONE),
 				};
 
 			inventory::submit! { $crate::commands::EditorCommandReg(&[<EDITOR_CMD_ $name>]) }
@@ -65,9 +65,13 @@ fn cmd_nu_run<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, R
 }
 
 async fn reload_runtime_from_dir(editor: &mut Editor, config_dir: PathBuf) -> Result<PathBuf, CommandError> {
-	let loaded = xeno_worker::spawn_blocking(xeno_worker::TaskClass::CpuBlocking, move || crate::nu::NuRuntime::load(&config_dir))
-		.await
-		.map_err(|error| CommandError::Failed(format!("failed to join Nu runtime load task: {error}")))?;
+	let current = editor.nu_runtime().filter(|runtime| runtime.config_dir() == config_dir).cloned();
+	let loaded = xeno_worker::spawn_blocking(xeno_worker::TaskClass::CpuBlocking, move || match current {
+		Some(runtime) => runtime.reload(),
+		None => crate::nu::NuRuntime::load(&config_dir),
+	})
+	.await
+	.map_err(|error| CommandError::Failed(format!("failed to join Nu runtime load task: {error}")))?;
 
 	match loaded {
 		Ok(runtime) => {
@@ -79,6 +79,7 @@ async fn nu_run_command_injects_ctx() {
 		let mut ctx = EditorCommandContext {
 			editor: &mut editor,
 			args: &args,
+			range: None,
 		};
 		cmd_nu_run(&mut ctx).await
 	}
@@ -165,6 +166,24 @@ async fn nu_reload_rejects_external_script_and_keeps_existing_runtime() {
 	assert_eq!(kept_runtime.script_path(), initial_script);
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn nu_reload_picks_up_script_changes_through_existing_runtime() {
+	let temp = tempfile::tempdir().expect("temp dir should exist");
+	write_script(temp.path(), "export def go [] { null }");
+
+	let mut editor = Editor::new_scratch();
+	editor.set_nu_runtime(Some(crate::nu::NuRuntime::load(temp.path()).expect("initial runtime should load")));
+
+	reload_runtime_from_dir(&mut editor, temp.path().to_path_buf()).await.expect("unchanged reload");
+	assert!(editor.nu_runtime().expect("runtime").find_export("go").is_some());
+
+	write_script(temp.path(), "export def stop [] { null }");
+	reload_runtime_from_dir(&mut editor, temp.path().to_path_buf()).await.expect("changed reload");
+	let runtime = editor.nu_runtime().expect("runtime");
+	assert!(runtime.find_export("go").is_none());
+	assert!(runtime.find_export("stop").is_some());
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn action_post_hook_dispatches_once_with_recursion_guard() {
 	assert!(xeno_registry::find_action("move_right").is_some(), "expected move_right action to exist");
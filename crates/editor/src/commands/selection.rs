@@ -0,0 +1,26 @@
+//! Selection commands that take text arguments.
+
+use xeno_primitives::BoxFutureLocal;
+
+use super::{CommandError, CommandOutcome, EditorCommandContext};
+use crate::editor_command;
+
+editor_command!(
+	split_regex,
+	{
+		keys: &["split-regex"],
+		description: "Split selections on a regex"
+	},
+	handler: cmd_split_regex
+);
+
+fn cmd_split_regex<'a>(ctx: &'a mut EditorCommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let pattern = ctx.args.join(" ");
+		if pattern.is_empty() {
+			return Err(CommandError::MissingArgument("pattern"));
+		}
+		ctx.editor.split_selections_on_regex(&pattern);
+		Ok(CommandOutcome::Ok)
+	})
+}
@@ -12,6 +12,7 @@
 #[cfg(test)]
 mod tests;
 
+use std::borrow::Cow;
 use std::path::PathBuf;
 
 use tracing::trace;
@@ -60,6 +61,10 @@ pub struct Document {
 	language_id: Option<xeno_language::LanguageId>,
 	/// Monotonic document version, incremented on every transaction.
 	version: u64,
+	/// Content as last loaded or saved, for change signs.
+	diff_base: Rope,
+	/// Document version at which [`Self::diff_base`] was captured.
+	diff_base_version: u64,
 }
 
 /// Static snapshot of a document's core state at a specific version.
@@ -84,15 +89,18 @@ impl Document {
 	pub fn new(content: String, path: Option<PathBuf>) -> Self {
 		let mut undo_backend = UndoBackend::default();
 		undo_backend.set_modified(false);
+		let content = Rope::from(content.as_str());
 		Self {
 			id: DocumentId::next(),
-			content: Rope::from(content.as_str()),
+			diff_base: content.clone(),
+			content,
 			path,
 			readonly: false,
 			undo_backend,
 			file_type: None,
 			language_id: None,
 			version: 0,
+			diff_base_version: 0,
 		}
 	}
 
@@ -112,12 +120,17 @@ impl Document {
 	}
 
 	/// Initializes syntax highlighting metadata based on the file path.
+	///
+	/// Files whose path matches no language fall back to a shebang or modeline
+	/// on the first line.
 	pub fn init_syntax(&mut self, language_loader: &LanguageLoader) {
 		self.file_type = None;
 		self.language_id = None;
 
 		if let Some(ref p) = self.path
-			&& let Some(lang_id) = language_loader.language_for_path(p)
+			&& let Some(lang_id) = language_loader
+				.language_for_path(p)
+				.or_else(|| language_loader.language_for_first_line(&Cow::<str>::from(self.content.line(0))))
 		{
 			let lang_data = language_loader.get(lang_id);
 			self.file_type = lang_data.map(|l| l.name().to_string());
@@ -266,6 +279,7 @@ impl Document {
 		self.undo_backend.clear_active_group_owner();
 		self.version = self.version.checked_add(1).expect("document version overflow");
 		self.undo_backend.set_modified(false);
+		self.mark_diff_base();
 	}
 
 	/// Replaces the document content from a synchronization snapshot.
@@ -278,6 +292,23 @@ impl Document {
 		self.undo_backend.clear_active_group_owner();
 		self.undo_backend.set_modified(false);
 		self.version = self.version.checked_add(1).expect("document version overflow");
+		self.mark_diff_base();
+	}
+
+	/// Returns the content as last loaded or saved.
+	pub fn diff_base(&self) -> &Rope {
+		&self.diff_base
+	}
+
+	/// Returns the document version at which the diff base was captured.
+	pub fn diff_base_version(&self) -> u64 {
+		self.diff_base_version
+	}
+
+	/// Records the current content as the diff base, e.g. after a save.
+	pub fn mark_diff_base(&mut self) {
+		self.diff_base = self.content.clone();
+		self.diff_base_version = self.version;
 	}
 
 	/// Returns the associated file path.
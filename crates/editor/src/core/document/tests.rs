@@ -282,6 +282,31 @@ fn reset_content_clears_undo_history() {
 	assert_eq!(doc.redo_len(), 0);
 }
 
+#[test]
+fn diff_base_tracks_loaded_and_saved_content() {
+	let mut doc = Document::new("hello".into(), None);
+
+	let tx = Transaction::change(
+		doc.content().slice(..),
+		[Change {
+			start: 0,
+			end: 0,
+			replacement: Some("X".into()),
+		}],
+	);
+	doc.commit(make_commit(tx), None).unwrap();
+	assert_eq!(doc.diff_base().to_string(), "hello", "edits keep the base");
+	assert_eq!(doc.diff_base_version(), 0);
+
+	doc.mark_diff_base();
+	assert_eq!(doc.diff_base().to_string(), "Xhello");
+	assert_eq!(doc.diff_base_version(), doc.version());
+
+	doc.reset_content("reloaded");
+	assert_eq!(doc.diff_base().to_string(), "reloaded");
+	assert_eq!(doc.diff_base_version(), doc.version());
+}
+
 #[test]
 fn undo_redo_to_clean_state() {
 	let mut doc = Document::new("hello".into(), None);
@@ -317,3 +342,20 @@ fn identity_commit_does_not_bump_version_or_modified() {
 	assert_eq!(doc.version(), version_before);
 	assert!(!doc.is_modified());
 }
+
+#[test]
+fn init_syntax_falls_back_to_first_line() {
+	let loader = xeno_language::LanguageLoader::from_embedded();
+
+	let mut script = Document::new("#!/usr/bin/env python3\nprint(1)\n".into(), Some("bin/deploy".into()));
+	script.init_syntax(&loader);
+	assert_eq!(script.file_type(), Some("python"));
+
+	let mut modeline = Document::new("# vim: set ft=ruby:\n".into(), Some("Rakefile.local".into()));
+	modeline.init_syntax(&loader);
+	assert_eq!(modeline.file_type(), Some("ruby"));
+
+	let mut by_path = Document::new("#!/bin/bash\n".into(), Some("main.rs".into()));
+	by_path.init_syntax(&loader);
+	assert_eq!(by_path.file_type(), Some("rust"), "path detection takes precedence");
+}
@@ -4,7 +4,7 @@ use tracing::{trace, trace_span};
 use xeno_primitives::{Mode, Selection};
 use xeno_registry::HookEventData;
 use xeno_registry::actions::editor_ctx::*;
-use xeno_registry::actions::{ActionEffects, AppEffect, EditEffect, Effect, ScreenPosition, ScrollAmount, UiEffect, ViewEffect};
+use xeno_registry::actions::{ActionEffects, ActionResult, AppEffect, EditEffect, Effect, ScreenPosition, ScrollAmount, UiEffect, ViewEffect};
 use xeno_registry::hooks::{HookContext, emit_sync as emit_hook_sync};
 use xeno_registry::notifications::keys;
 
@@ -45,6 +45,37 @@ pub fn apply_effects(effects: &ActionEffects, ctx: &mut xeno_registry::actions::
 	outcome
 }
 
+/// Applies an action result, descending into batches.
+///
+/// Elements of an [`ActionResult::Batch`] are dispatched in order inside one
+/// undo batch, so their edits undo together. The first element with a
+/// rejected edit ends the batch: later elements are skipped, the batch's edits
+/// are rolled back and the cursor, selection, mode and registers return to
+/// their pre-batch state. A quit requested by a dispatched element is returned
+/// after the batch closes, unless the batch was rolled back.
+pub fn dispatch_result(result: &ActionResult, ctx: &mut xeno_registry::actions::editor_ctx::EditorContext, extend: bool) -> HandleOutcome {
+	match result {
+		ActionResult::Effects(effects) => apply_effects(effects, ctx, extend),
+		ActionResult::Batch(results) => {
+			ctx.undo().begin_batch();
+			let mut outcome = HandleOutcome::Handled;
+			for result in results {
+				if matches!(dispatch_result(result, ctx, extend), HandleOutcome::Quit) {
+					outcome = HandleOutcome::Quit;
+				}
+				if ctx.undo().batch_rejected() {
+					break;
+				}
+			}
+			if !ctx.undo().end_batch() {
+				trace!(len = results.len(), "action batch rolled back");
+				return HandleOutcome::Handled;
+			}
+			outcome
+		}
+	}
+}
+
 fn effect_kind(effect: &Effect) -> (&'static str, bool) {
 	match effect {
 		Effect::View(_) => ("view", false),
@@ -98,6 +129,10 @@ fn apply_view_effect(effect: &ViewEffect, ctx: &mut xeno_registry::actions::edit
 			ctx.motion().move_visual_vertical(*direction, *count, *move_extend);
 		}
 
+		ViewEffect::AddCursorVertical { direction, count } => {
+			ctx.motion().add_cursor_vertical(*direction, *count);
+		}
+
 		ViewEffect::Search { direction, add_selection } => {
 			ctx.search().search(*direction, *add_selection, extend);
 		}
@@ -123,8 +158,8 @@ fn apply_edit_effect(effect: &EditEffect, ctx: &mut xeno_registry::actions::edit
 			ctx.edit().execute_edit_op(op);
 		}
 
-		EditEffect::Paste { before } => {
-			ctx.edit().paste(*before);
+		EditEffect::Paste { before, count, register } => {
+			ctx.edit().paste(*before, *count, *register);
 		}
 	}
 }
@@ -153,6 +188,10 @@ fn apply_ui_effect(effect: &UiEffect, ctx: &mut xeno_registry::actions::editor_c
 		}
 
 		UiEffect::ForceRedraw => {}
+
+		UiEffect::ToggleDoNotDisturb => {
+			ctx.toggle_do_not_disturb();
+		}
 	}
 }
 
@@ -203,6 +242,31 @@ fn apply_app_effect(effect: &AppEffect, ctx: &mut xeno_registry::actions::editor
 		AppEffect::QueueInvocation(request) => {
 			ctx.deferred_invocations().queue_invocation(request.clone());
 		}
+
+		AppEffect::RepeatLastAction => {
+			if ctx.repeat_last_action() == HandleOutcome::Quit {
+				return Some(HandleOutcome::Quit);
+			}
+		}
+
+		AppEffect::ToggleMacroRecording { register } => {
+			let macros = ctx.macro_ops();
+			if macros.is_recording() {
+				macros.stop_recording();
+			} else {
+				macros.record(*register);
+			}
+		}
+
+		AppEffect::ReplayMacro { register, count } => {
+			let Some(register) = register.or_else(|| ctx.macro_ops().last_register()) else {
+				ctx.emit(keys::NO_LAST_MACRO);
+				return None;
+			};
+			if ctx.macro_ops().play(register, *count) == HandleOutcome::Quit {
+				return Some(HandleOutcome::Quit);
+			}
+		}
 	}
 
 	None
@@ -91,6 +91,8 @@ impl NotificationAccess for MockEditor {
 	fn clear_notifications(&mut self) {
 		self.notifications.clear();
 	}
+
+	fn toggle_do_not_disturb(&mut self) {}
 }
 
 impl DeferredInvocationAccess for MockEditor {
@@ -30,6 +30,7 @@
 //! * Must apply mixed view/ui/app effects in strict `ActionEffects` sequence order.
 //! * Must route capability-emitted side effects through sink flush paths before they become visible.
 //! * Must route action result effects through `apply_effects` at invocation/runtime boundaries.
+//! * Must dispatch `ActionResult::Batch` elements in order inside one undo batch (`dispatch_result`).
 //! * Must produce compiler errors (exhaustive match) when new effect variants are added.
 //!
 //! # Data flow
@@ -72,7 +73,7 @@
 
 mod core;
 
-pub use core::apply_effects;
+pub use core::{apply_effects, dispatch_result};
 
 pub use xeno_registry::actions::editor_ctx::*;
 
@@ -1,8 +1,11 @@
 //! Behavior-lock tests for the effect interpreter.
 
+use std::collections::HashMap;
+
 use xeno_primitives::{CharIdx, Mode, Selection};
-use xeno_registry::actions::editor_ctx::{CursorAccess, EditorCapabilities, ModeAccess, NotificationAccess, SelectionAccess};
-use xeno_registry::actions::{ActionEffects, AppEffect, UiEffect, ViewEffect};
+use xeno_registry::actions::edit_op::{EditOp, PreEffect};
+use xeno_registry::actions::editor_ctx::{CursorAccess, EditAccess, EditorCapabilities, ModeAccess, NotificationAccess, SelectionAccess};
+use xeno_registry::actions::{ActionArgs, ActionContext, ActionEffects, ActionResult, AppEffect, UiEffect, ViewEffect, find_action};
 use xeno_registry::notifications::Notification;
 
 use crate::editor_ctx::apply_effects;
@@ -13,6 +16,10 @@ struct MockEditor {
 	mode: Mode,
 	notifications: Vec<Notification>,
 	effect_log: Vec<String>,
+	/// Text the mock yanks, standing in for the selected text.
+	selected_text: String,
+	registers: HashMap<Option<char>, String>,
+	pasted: String,
 }
 
 impl MockEditor {
@@ -23,6 +30,9 @@ impl MockEditor {
 			mode: Mode::Normal,
 			notifications: Vec::new(),
 			effect_log: Vec::new(),
+			selected_text: String::new(),
+			registers: HashMap::new(),
+			pasted: String::new(),
 		}
 	}
 }
@@ -81,6 +91,22 @@ impl NotificationAccess for MockEditor {
 	fn clear_notifications(&mut self) {
 		self.notifications.clear();
 	}
+
+	fn toggle_do_not_disturb(&mut self) {}
+}
+
+impl EditAccess for MockEditor {
+	fn execute_edit_op(&mut self, op: &EditOp) {
+		if op.pre.contains(&PreEffect::Yank) {
+			self.registers.insert(op.register, self.selected_text.clone());
+		}
+	}
+
+	fn paste(&mut self, _before: bool, count: usize, register: Option<char>) {
+		if let Some(text) = self.registers.get(&register) {
+			self.pasted.push_str(&text.repeat(count));
+		}
+	}
 }
 
 impl EditorCapabilities for MockEditor {
@@ -91,7 +117,7 @@ impl EditorCapabilities for MockEditor {
 		unimplemented!("test mock")
 	}
 	fn edit(&mut self) -> &mut dyn xeno_registry::actions::editor_ctx::EditAccess {
-		unimplemented!("test mock")
+		self
 	}
 	fn motion(&mut self) -> &mut dyn xeno_registry::actions::editor_ctx::MotionAccess {
 		unimplemented!("test mock")
@@ -324,3 +350,50 @@ fn set_selection_emits_cursor_then_selection() {
 	assert_eq!(editor.effect_log[0], "set_cursor:15");
 	assert_eq!(editor.effect_log[1], "set_selection:15");
 }
+
+/// Runs the registered handler of `action` against the mock.
+fn run_action(editor: &mut MockEditor, action: &str, count: usize, register: Option<char>) {
+	let action = find_action(action).expect("action registered");
+	let text = ropey::Rope::new();
+	let selection = editor.selection.clone();
+	let result = (action.handler)(&ActionContext {
+		text: text.slice(..),
+		syntax: None,
+		cursor: editor.cursor,
+		selection: &selection,
+		count,
+		extend: false,
+		register,
+		args: ActionArgs::default(),
+	});
+	let ActionResult::Effects(effects) = result else {
+		panic!("editing actions return effects");
+	};
+	let mut ctx = xeno_registry::actions::editor_ctx::EditorContext::new(editor);
+	apply_effects(&effects, &mut ctx, false);
+}
+
+#[test]
+fn paste_count_pastes_register_count_times() {
+	let mut editor = MockEditor::new();
+	editor.selected_text = "ab".to_string();
+
+	run_action(&mut editor, "yank", 1, None);
+	run_action(&mut editor, "paste_after", 3, None);
+
+	assert_eq!(editor.pasted, "ababab");
+}
+
+#[test]
+fn named_register_round_trips_yank_and_paste() {
+	let mut editor = MockEditor::new();
+	editor.selected_text = "first".to_string();
+	run_action(&mut editor, "yank", 1, Some('a'));
+	editor.selected_text = "second".to_string();
+	run_action(&mut editor, "yank", 1, None);
+
+	run_action(&mut editor, "paste_after", 1, Some('a'));
+	assert_eq!(editor.pasted, "first");
+	run_action(&mut editor, "paste_before", 1, None);
+	assert_eq!(editor.pasted, "firstsecond");
+}
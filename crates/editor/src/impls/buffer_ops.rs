@@ -238,6 +238,7 @@ impl Editor {
 	/// [`RenderCache`]: crate::render::cache::RenderCache
 	pub(crate) fn finalize_buffer_removal(&mut self, id: ViewId) {
 		let removed = self.state.core.editor.buffers.remove_buffer_raw(id);
+		self.state.config.config.theme_overrides.clear(id);
 		if let Some(buffer) = removed {
 			self.finalize_document_if_orphaned(buffer.document_id());
 		}
@@ -0,0 +1,42 @@
+use xeno_registry::commands::CommandHistory;
+
+use super::Editor;
+
+/// History file name under the state directory.
+const HISTORY_FILE: &str = "command_history";
+
+impl Editor {
+	/// Loads persistent command-line history and enables appending to it.
+	///
+	/// Reads `$XDG_STATE_HOME/xeno/command_history` and compacts it to the
+	/// in-memory ring. Call once at startup; editors that never call this keep
+	/// history in memory only.
+	pub fn load_command_history(&mut self) {
+		let Some(path) = crate::paths::get_state_dir().map(|dir| dir.join(HISTORY_FILE)) else {
+			return;
+		};
+		match CommandHistory::load(&path, CommandHistory::DEFAULT_CAPACITY) {
+			Ok(history) => {
+				if let Err(error) = history.save(&path) {
+					tracing::warn!(path = %path.display(), %error, "failed to compact command history");
+				}
+				self.state.telemetry.command_history = history;
+			}
+			Err(error) => tracing::warn!(path = %path.display(), %error, "failed to load command history"),
+		}
+		self.state.telemetry.command_history_path = Some(path);
+	}
+
+	/// Records a committed command line, appending it to the history file when loaded.
+	pub(crate) fn push_command_history(&mut self, line: &str) {
+		let telemetry = &mut self.state.telemetry;
+		if !telemetry.command_history.push(line) {
+			return;
+		}
+		if let Some(path) = telemetry.command_history_path.as_ref()
+			&& let Err(error) = CommandHistory::append(path, line)
+		{
+			tracing::warn!(path = %path.display(), %error, "failed to append command history");
+		}
+	}
+}
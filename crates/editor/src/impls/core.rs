@@ -11,6 +11,7 @@
 //!
 //! [`Editor`]: super::Editor
 
+use super::history::BatchSnapshot;
 use crate::buffer::{Buffer, ViewId};
 use crate::types::{UndoManager, Workspace};
 use crate::view_manager::ViewManager;
@@ -27,7 +28,8 @@ use crate::view_manager::ViewManager;
 /// EditorCore
 /// ├── buffers: ViewManager       // Text buffer storage and focus tracking
 /// ├── workspace: Workspace       // Session state (registers, jumps, macros)
-/// └── undo_manager: UndoManager  // Undo/redo grouping stacks
+/// ├── undo_manager: UndoManager  // Undo/redo grouping stacks
+/// └── batch_snapshot             // Pre-batch state for rollback
 /// ```
 pub struct EditorCore {
 	/// Buffer and document storage.
@@ -46,6 +48,12 @@ pub struct EditorCore {
 	/// (cursor, selection, scroll) for all affected buffers at the time of
 	/// the edit. Document state is stored separately in each document's history.
 	pub undo_manager: UndoManager,
+
+	/// State captured when the outermost undo batch opened.
+	///
+	/// Restored when the batch is rolled back, so a rejected batch leaves no
+	/// cursor, selection, mode or register changes behind.
+	pub batch_snapshot: Option<BatchSnapshot>,
 }
 
 impl EditorCore {
@@ -55,6 +63,7 @@ impl EditorCore {
 			buffers,
 			workspace,
 			undo_manager,
+			batch_snapshot: None,
 		}
 	}
 
@@ -64,15 +64,11 @@ impl<'a> EditExecutor<'a> {
 		self.editor.execute_edit_op(op.clone());
 	}
 
-	/// Pastes from the yank register.
+	/// Pastes from the default yank register.
 	///
 	/// If `before` is true, pastes before the cursor; otherwise after.
 	pub fn paste(&mut self, before: bool) {
-		if before {
-			self.editor.paste_before();
-		} else {
-			self.editor.paste_after();
-		}
+		self.editor.paste_register(before, 1, None);
 	}
 
 	/// Moves the cursor visually (handling wrapped lines).
@@ -25,19 +25,32 @@ impl Editor {
 		}
 
 		for pre in &plan.op.pre {
-			self.apply_pre_effect(pre);
+			self.apply_pre_effect(pre, plan.op.register);
 		}
 
-		if !self.apply_selection_op(&plan.op.selection) {
-			return;
-		}
-
-		let original_cursor = self.buffer().cursor;
-
-		if let Some((tx, new_selection)) = self.build_transform_transaction(&plan) {
-			let buffer_id = self.focused_view();
-			self.apply_edit(buffer_id, &tx, Some(new_selection), plan.undo_policy, EditOrigin::Internal("edit_op"));
+		let undo_len = self.state.core.editor.undo_manager.undo_len();
+		let mut original_cursor = None;
+		for pass in 0..plan.passes() {
+			if !self.apply_selection_op(&plan.op.selection) {
+				break;
+			}
+			original_cursor.get_or_insert(self.buffer().cursor);
+
+			if let Some((tx, new_selection)) = self.build_transform_transaction(&plan) {
+				let buffer_id = self.focused_view();
+				self.apply_edit(
+					buffer_id,
+					&tx,
+					Some(new_selection),
+					plan.undo_policy_for_pass(pass),
+					EditOrigin::Internal("edit_op"),
+				);
+			}
 		}
+		self.state.core.editor.undo_manager.describe_groups_since(undo_len, &plan.op.explain());
+		let Some(original_cursor) = original_cursor else {
+			return;
+		};
 
 		for post in &plan.op.post {
 			self.apply_post_effect(post, original_cursor);
@@ -6,10 +6,10 @@ use super::super::Editor;
 
 impl Editor {
 	/// Applies a pre-effect before the main transformation.
-	pub(super) fn apply_pre_effect(&mut self, effect: &PreEffect) {
+	pub(super) fn apply_pre_effect(&mut self, effect: &PreEffect, register: Option<char>) {
 		match effect {
 			PreEffect::Yank => {
-				self.yank_selection();
+				self.yank_selection(register);
 			}
 		}
 	}
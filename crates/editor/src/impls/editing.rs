@@ -12,8 +12,11 @@ use crate::buffer::ViewId;
 
 impl Editor {
 	/// Returns true if the current buffer permits mutations.
+	///
+	/// A refusal inside an undo batch rejects the batch.
 	pub(crate) fn guard_readonly(&mut self) -> bool {
 		if self.buffer().is_readonly() {
+			self.state.core.editor.undo_manager.reject_batch();
 			self.notify(keys::BUFFER_READONLY);
 			return false;
 		}
@@ -52,6 +55,7 @@ impl Editor {
 			lsp: &mut integration.lsp,
 		};
 
+		let undo = editor.undo_manager.batch_policy(undo);
 		let res = editor.undo_manager.with_edit(&mut host, buffer_id, origin, |host: &mut EditorUndoHost| {
 			host.apply_transaction_inner(buffer_id, tx, new_selection, undo)
 		});
@@ -136,58 +140,35 @@ impl Editor {
 		}
 	}
 
-	/// Copies the current selection to the yank register.
-	pub fn yank_selection(&mut self) {
+	/// Copies the current selection to `register` (the default register if `None`).
+	pub fn yank_selection(&mut self, register: Option<char>) {
 		if let Some(yank) = self.buffer_mut().yank_selection() {
 			let count = yank.total_chars;
-			self.state.core.editor.workspace.registers.yank = yank;
+			self.state.core.editor.workspace.registers.store(register, yank);
 			self.notify(keys::yanked_chars(count));
 		}
 	}
 
-	/// Pastes the yank register content after the cursor.
-	pub fn paste_after(&mut self) {
-		if self.state.core.editor.workspace.registers.yank.is_empty() {
-			return;
-		}
-
-		if !self.guard_readonly() {
-			return;
-		}
-
-		let buffer_id = self.focused_view();
-		let yank = self.state.core.editor.workspace.registers.yank.joined();
-
-		let Some((tx, new_selection)) = ({
-			let buffer = self.state.core.editor.buffers.get_buffer_mut(buffer_id).expect("focused buffer must exist");
-			buffer.prepare_paste_after(&yank)
-		}) else {
+	/// Pastes `count` copies of `register` before or after the cursor as one edit.
+	pub fn paste_register(&mut self, before: bool, count: usize, register: Option<char>) {
+		let Some(yank) = self.state.core.editor.workspace.registers.get(register).filter(|yank| !yank.is_empty()) else {
 			return;
 		};
-
-		let applied = self.apply_edit(buffer_id, &tx, Some(new_selection), UndoPolicy::Record, EditOrigin::Internal("paste"));
-
-		if !applied {
-			self.notify(keys::BUFFER_READONLY);
-		}
-	}
-
-	/// Pastes the yank register content before the cursor.
-	pub fn paste_before(&mut self) {
-		if self.state.core.editor.workspace.registers.yank.is_empty() {
-			return;
-		}
+		let text = yank.joined().repeat(count.max(1));
 
 		if !self.guard_readonly() {
 			return;
 		}
 
 		let buffer_id = self.focused_view();
-		let yank = self.state.core.editor.workspace.registers.yank.joined();
 
 		let Some((tx, new_selection)) = ({
 			let buffer = self.state.core.editor.buffers.get_buffer_mut(buffer_id).expect("focused buffer must exist");
-			buffer.prepare_paste_before(&yank)
+			if before {
+				buffer.prepare_paste_before(&text)
+			} else {
+				buffer.prepare_paste_after(&text)
+			}
 		}) else {
 			return;
 		};
@@ -9,7 +9,7 @@ use tracing::warn;
 use xeno_primitives::BoxFutureLocal;
 use xeno_registry::HookEventData;
 use xeno_registry::commands::CommandError;
-use xeno_registry::hooks::{HookContext, emit as emit_hook};
+use xeno_registry::hooks::{HookContext, emit as emit_hook, emit_with_outcome as emit_hook_with_outcome};
 
 use super::Editor;
 
@@ -23,7 +23,8 @@ impl Editor {
 	///
 	/// Delegates the atomic write to [`crate::io::save_buffer_to_disk`],
 	/// wrapping it with hooks, LSP notifications, and post-save state
-	/// updates (modified flag, user notification).
+	/// updates (modified flag, user notification). A `BufferWritePre` hook
+	/// that cancels aborts the write with its reason.
 	pub fn save(&mut self) -> BoxFutureLocal<'_, Result<(), CommandError>> {
 		Box::pin(async move {
 			let path_owned = match &self.buffer().path() {
@@ -36,11 +37,17 @@ impl Editor {
 			// Snapshot content for hooks before save.
 			let rope = self.buffer().with_doc(|doc| doc.content().clone());
 
-			emit_hook(&HookContext::new(HookEventData::BufferWritePre {
+			let outcome = emit_hook_with_outcome(&HookContext::new(HookEventData::BufferWritePre {
 				path: &path_owned,
 				text: rope.slice(..),
 			}))
 			.await;
+			if let Some(hook) = &outcome.cancelled_by {
+				return Err(CommandError::Failed(match outcome.reason() {
+					Some(reason) => format!("write cancelled by hook '{hook}': {reason}"),
+					None => format!("write cancelled by hook '{hook}'"),
+				}));
+			}
 
 			#[cfg(feature = "lsp")]
 			if let Err(e) = self.state.integration.lsp.on_buffer_will_save(self.buffer()).await {
@@ -50,7 +57,7 @@ impl Editor {
 			if let Some(parent) = path_owned.parent()
 				&& !parent.as_os_str().is_empty()
 			{
-				tokio::fs::create_dir_all(parent).await.map_err(|e| CommandError::Io(e.to_string()))?;
+				tokio::fs::create_dir_all(parent).await?;
 			}
 
 			let buffer_id = self.focused_view();
@@ -63,6 +70,7 @@ impl Editor {
 			crate::io::save_buffer_to_disk(buffer).await.map_err(|e| CommandError::Io(e.to_string()))?;
 
 			let _ = self.buffer_mut().set_modified(false);
+			self.buffer().with_doc_mut(|doc| doc.mark_diff_base());
 			self.show_notification(xeno_registry::notifications::keys::file_saved(&path_owned));
 
 			#[cfg(feature = "lsp")]
@@ -180,7 +188,7 @@ impl Editor {
 			if let Some(parent) = new_path.parent()
 				&& !parent.as_os_str().is_empty()
 			{
-				tokio::fs::create_dir_all(parent).await.map_err(|e| CommandError::Io(e.to_string()))?;
+				tokio::fs::create_dir_all(parent).await?;
 			}
 
 			// Perform the actual filesystem rename.
@@ -193,7 +201,7 @@ impl Editor {
 						new_path.display()
 					)));
 				}
-				Err(e) => return Err(CommandError::Io(e.to_string())),
+				Err(e) => return Err(CommandError::from_io(e)),
 			}
 
 			// Update buffer path.
@@ -312,7 +320,7 @@ impl Editor {
 			if let Some(parent) = path.parent()
 				&& !parent.as_os_str().is_empty()
 			{
-				tokio::fs::create_dir_all(parent).await.map_err(|e| CommandError::Io(e.to_string()))?;
+				tokio::fs::create_dir_all(parent).await?;
 			}
 			// Use create_new to avoid TOCTOU clobber if file appeared between exists() check and here.
 			match tokio::fs::OpenOptions::new().write(true).create_new(true).open(&path).await {
@@ -322,7 +330,7 @@ impl Editor {
 					let _ = self.open_file(path).await.map_err(|e| CommandError::Failed(e.to_string()))?;
 					return Ok(());
 				}
-				Err(e) => return Err(CommandError::Io(e.to_string())),
+				Err(e) => return Err(CommandError::from_io(e)),
 			}
 
 			// Open the file in the editor (triggers didOpen via LSP tracking).
@@ -402,7 +410,7 @@ impl Editor {
 			};
 
 			// Delete the file from disk.
-			tokio::fs::remove_file(&path).await.map_err(|e| CommandError::Io(e.to_string()))?;
+			tokio::fs::remove_file(&path).await?;
 
 			// Close LSP document explicitly (didClose) BEFORE didDeleteFiles.
 			#[cfg(feature = "lsp")]
@@ -539,7 +547,7 @@ impl Editor {
 				}
 			}
 
-			tokio::fs::create_dir_all(&path).await.map_err(|e| CommandError::Io(e.to_string()))?;
+			tokio::fs::create_dir_all(&path).await?;
 
 			// Broadcast didCreateFiles to interested clients.
 			#[cfg(feature = "lsp")]
@@ -623,7 +631,7 @@ impl Editor {
 				if e.raw_os_error() == Some(39) || e.raw_os_error() == Some(66) {
 					CommandError::Failed(format!("Directory not empty: {}", path.display()))
 				} else {
-					CommandError::Io(e.to_string())
+					CommandError::from_io(e)
 				}
 			})?;
 
@@ -14,10 +14,23 @@
 //!
 //! [`UndoManager`]: crate::types::UndoManager
 
+use xeno_primitives::Mode;
+
 use super::undo_host::EditorUndoHost;
-use crate::buffer::Buffer;
+use crate::buffer::{Buffer, ViewId};
 use crate::impls::Editor;
-use crate::types::ViewSnapshot;
+use crate::overlay::LayerEvent;
+use crate::types::{Registers, ViewSnapshot};
+
+/// Focused view, mode and register state captured when an undo batch opens.
+///
+/// See [`Editor::begin_undo_batch`].
+pub struct BatchSnapshot {
+	view: ViewId,
+	view_state: ViewSnapshot,
+	mode: Mode,
+	registers: Registers,
+}
 
 impl Buffer {
 	/// Creates a snapshot of this buffer's view state.
@@ -86,6 +99,80 @@ impl Editor {
 		};
 		editor.undo_manager.redo(&mut host);
 	}
+
+	/// Opens an undo batch so following edits share one undo step.
+	///
+	/// The outermost call also snapshots the focused view, mode and registers
+	/// so [`Self::end_undo_batch`] can restore them on rollback.
+	pub fn begin_undo_batch(&mut self) {
+		if !self.state.core.editor.undo_manager.batch_open() {
+			let buffer = self.buffer();
+			let snapshot = BatchSnapshot {
+				view: self.focused_view(),
+				view_state: buffer.snapshot_view(),
+				mode: buffer.mode(),
+				registers: self.state.core.editor.workspace.registers.clone(),
+			};
+			self.state.core.editor.batch_snapshot = Some(snapshot);
+		}
+		self.state.core.editor.undo_manager.begin_batch();
+	}
+
+	/// Closes an undo batch, rolling it back if one of its edits was rejected.
+	///
+	/// A rollback reverts the batch's edits and restores the state captured by
+	/// [`Self::begin_undo_batch`]. Returns false when the batch was rolled back.
+	pub fn end_undo_batch(&mut self) -> bool {
+		let applied = self.close_undo_batch();
+		if self.state.core.editor.undo_manager.batch_open() {
+			return applied;
+		}
+		let snapshot = self.state.core.editor.batch_snapshot.take();
+		if !applied && let Some(snapshot) = snapshot {
+			self.restore_batch_snapshot(snapshot);
+		}
+		applied
+	}
+
+	fn restore_batch_snapshot(&mut self, snapshot: BatchSnapshot) {
+		self.state.core.editor.workspace.registers = snapshot.registers;
+		let Some(buffer) = self.state.core.editor.buffers.get_buffer_mut(snapshot.view) else {
+			return;
+		};
+		buffer.restore_view(&snapshot.view_state);
+		let mode_changed = buffer.mode() != snapshot.mode;
+		buffer.input.set_mode(snapshot.mode.clone());
+		let effects = &mut self.state.runtime.effects;
+		effects.push_layer_event(LayerEvent::CursorMoved { view: snapshot.view });
+		if mode_changed {
+			effects.push_layer_event(LayerEvent::ModeChanged {
+				view: snapshot.view,
+				mode: snapshot.mode,
+			});
+		}
+	}
+
+	fn close_undo_batch(&mut self) -> bool {
+		let focused_view = self.focused_view();
+
+		let state = &mut self.state;
+		let core = &mut state.core;
+		let editor = &mut core.editor;
+		let frame = &mut core.frame;
+		let integration = &mut state.integration;
+		let ui = &mut state.ui;
+		let mut host = EditorUndoHost {
+			buffers: &mut editor.buffers,
+			focused_view,
+			config: &state.config.config,
+			frame,
+			notifications: &mut ui.notifications,
+			syntax_manager: &mut integration.syntax_manager,
+			#[cfg(feature = "lsp")]
+			lsp: &mut integration.lsp,
+		};
+		editor.undo_manager.end_batch(&mut host)
+	}
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@
 
 use proptest::prelude::*;
 use xeno_primitives::{Change, CharIdx, EditOrigin, Mode, Selection, Transaction, UndoPolicy};
+use xeno_registry::actions::{ActionEffects, ActionResult, edit_op};
 
 use super::Editor;
 use crate::buffer::ViewId;
@@ -313,6 +314,67 @@ async fn record_policy_breaks_merge_group() {
 	assert_eq!(editor.state.core.editor.undo_manager.undo_len(), 2, "Record policy should create new group");
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn action_batch_applies_all_edits_as_one_undo_step() {
+	let mut editor = test_editor("hello");
+	set_cursor(&mut editor, 0);
+	let batch = ActionResult::Batch(vec![
+		ActionEffects::edit_op(edit_op::add_line_below()).into(),
+		ActionEffects::edit_op(edit_op::add_line_above()).into(),
+	]);
+
+	assert!(!editor.apply_action_result("history_test_batch", batch, false));
+
+	let content = editor.buffer().with_doc(|doc| doc.content().to_string());
+	assert_eq!(content.matches('\n').count(), 2, "both batch edits applied: {content:?}");
+	assert_eq!(editor.state.core.editor.undo_manager.undo_len(), 1, "batch records a single undo group");
+
+	editor.undo();
+	assert_eq!(editor.buffer().with_doc(|doc| doc.content().to_string()), "hello");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn action_batch_stops_at_rejected_edit_and_restores_pre_batch_state() {
+	let mut editor = test_editor("hello");
+	set_cursor(&mut editor, 0);
+	editor.buffer_mut().set_readonly(true);
+	let batch = ActionResult::Batch(vec![
+		ActionEffects::cursor(CharIdx::from(3)).into(),
+		ActionEffects::edit_op(edit_op::yank()).into(),
+		ActionEffects::edit_op(edit_op::add_line_below()).into(),
+		ActionEffects::mode(Mode::Insert).into(),
+	]);
+
+	assert!(!editor.apply_action_result("history_test_batch", batch, false));
+
+	assert_eq!(editor.buffer().with_doc(|doc| doc.content().to_string()), "hello");
+	assert_eq!(get_cursor(&editor, editor.focused_view()), 0, "cursor change before the rejection is reverted");
+	assert!(
+		editor.state.core.editor.workspace.registers.yank.is_empty(),
+		"yank before the rejection is reverted"
+	);
+	assert_eq!(editor.mode(), Mode::Normal, "elements after the rejection are skipped");
+	assert_eq!(editor.state.core.editor.undo_manager.undo_len(), 0);
+	assert!(!editor.state.core.editor.undo_manager.batch_rejected(), "the batch is closed");
+	assert!(editor.state.core.editor.batch_snapshot.is_none(), "the snapshot is released");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn applied_action_batch_keeps_its_view_changes() {
+	let mut editor = test_editor("hello");
+	set_cursor(&mut editor, 0);
+	let batch = ActionResult::Batch(vec![
+		ActionEffects::cursor(CharIdx::from(3)).into(),
+		ActionEffects::edit_op(edit_op::yank()).into(),
+	]);
+
+	assert!(!editor.apply_action_result("history_test_batch", batch, false));
+
+	assert_eq!(get_cursor(&editor, editor.focused_view()), 3);
+	assert!(!editor.state.core.editor.workspace.registers.yank.is_empty());
+	assert!(editor.state.core.editor.batch_snapshot.is_none());
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn sibling_selection_sync_after_apply() {
 	let mut editor = test_editor("abcd");
@@ -489,3 +551,28 @@ async fn terminal_paste_is_its_own_undo_step() {
 
 	assert_eq!(content, "A");
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn edit_op_undo_groups_carry_explanations() {
+	let mut editor = test_editor("hello");
+	set_cursor(&mut editor, 5);
+	editor.execute_edit_op(edit_op::delete_back());
+	apply_test_edit(&mut editor, "!", 0);
+
+	let descriptions: Vec<_> = editor
+		.state
+		.core
+		.editor
+		.undo_manager
+		.undo_groups()
+		.iter()
+		.map(|group| group.description.as_deref())
+		.collect();
+	assert_eq!(descriptions, [Some("Delete char before cursor"), None]);
+
+	editor.undo();
+	editor.undo();
+	editor.redo();
+	let group = editor.state.core.editor.undo_manager.last_undo_group().expect("redone group");
+	assert_eq!(group.description.as_deref(), Some("Delete char before cursor"));
+}
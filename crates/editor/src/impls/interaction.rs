@@ -45,6 +45,16 @@ impl Editor {
 		result
 	}
 
+	/// Asks the user to confirm `prompt`, running `request` if they accept.
+	pub(crate) fn open_confirm(&mut self, prompt: String, request: xeno_registry::actions::DeferredInvocationRequest) -> bool {
+		let ctl = controllers::ConfirmOverlay::new(prompt, request);
+		let mut interaction = self.state.ui.overlay_system.take_interaction();
+		let result = interaction.open(self, Box::new(ctl));
+		self.state.ui.overlay_system.restore_interaction(interaction);
+		self.flush_effects();
+		result
+	}
+
 	pub fn open_file_picker(&mut self) -> bool {
 		let ctl = controllers::FilePickerOverlay::new(None);
 		let mut interaction = self.state.ui.overlay_system.take_interaction();
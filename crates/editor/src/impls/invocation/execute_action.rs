@@ -1,14 +1,15 @@
 use tracing::{trace, trace_span};
 use xeno_registry::actions::editor_ctx::HandleOutcome;
-use xeno_registry::actions::{ActionArgs, ActionContext, ActionResult, find_action};
+use xeno_registry::actions::{ActionArgs, ActionContext, ActionResult, RecordedInvocation, find_action};
 use xeno_registry::hooks::{HookContext, emit_sync_with as emit_hook_sync_with};
+use xeno_registry::motions::SyntaxTree;
 use xeno_registry::{HookEventData, RegistryEntry};
 
-use crate::editor_ctx::apply_effects;
+use crate::editor_ctx::dispatch_result;
 use crate::impls::Editor;
 use crate::impls::invocation::kernel::InvocationKernel;
 use crate::impls::invocation::policy_gate::InvocationGateInput;
-use crate::types::{InvocationOutcome, InvocationPolicy, InvocationTarget};
+use crate::types::{InvocationOutcome, InvocationPolicy, InvocationStatus, InvocationTarget, MacroState};
 
 impl Editor {
 	pub(crate) fn run_action_invocation(
@@ -54,36 +55,155 @@ impl Editor {
 			(buffer.with_doc(|doc| doc.content().clone()), buffer.cursor, buffer.selection.clone())
 		};
 
-		let ctx = ActionContext {
-			text: content.slice(..),
-			cursor,
-			selection: &selection,
-			count,
-			extend,
-			register,
-			args: ActionArgs { char: char_arg, string: None },
-		};
+		// Checked before the handler runs so the action that starts or stops a
+		// recording is not captured into it.
+		let was_recording = kernel.editor().state.core.editor.workspace.macro_state.is_recording();
 
-		let result = (action.handler)(&ctx);
+		let args = ActionArgs { char: char_arg, string: None };
+		let result = {
+			let editor = &*kernel.editor();
+			let syntax = editor
+				.syntax_manager()
+				.syntax_for_doc(editor.buffer().document_id())
+				.map(|syntax| syntax as &dyn SyntaxTree);
+			let ctx = ActionContext {
+				text: content.slice(..),
+				syntax,
+				cursor,
+				selection: &selection,
+				count,
+				extend,
+				register,
+				args: args.clone(),
+			};
+			(action.handler)(&ctx)
+		};
 		trace!(result = ?result, "Action completed");
 
 		let outcome = if kernel.editor().apply_action_result(&action_id_str, result, extend) {
 			InvocationOutcome::quit(InvocationTarget::Action)
 		} else {
+			let invocation = RecordedInvocation {
+				id: action.dense_id(),
+				count,
+				extend,
+				register,
+				args,
+			};
+			let workspace = &mut kernel.editor().state.core.editor.workspace;
+			if was_recording {
+				workspace.macro_state.record_invocation(invocation.clone());
+			}
+			if action.repeatable {
+				workspace.last_action = Some(invocation);
+			}
 			InvocationOutcome::ok(InvocationTarget::Action)
 		};
 
 		kernel.flush_effects_and_return(outcome)
 	}
 
+	/// Records input that bypasses action dispatch into the active macro.
+	///
+	/// Typed text and leaving insert mode are handled by the input state
+	/// machine, so they are captured here as the equivalent `name` action to
+	/// keep replays faithful. A no-op unless a macro is recording.
+	pub(crate) fn record_input_invocation(&mut self, name: &str, char_arg: Option<char>) {
+		let macro_state = &mut self.state.core.editor.workspace.macro_state;
+		if !macro_state.is_recording() {
+			return;
+		}
+		let Some(action) = find_action(name) else {
+			return;
+		};
+		macro_state.record_invocation(RecordedInvocation {
+			id: action.dense_id(),
+			count: 1,
+			extend: false,
+			register: None,
+			args: ActionArgs { char: char_arg, string: None },
+		});
+	}
+
+	/// Replays the last action recorded by [`Self::run_action_invocation`].
+	///
+	/// Uses the original count, extend flag, register, and pending-action input.
+	/// A no-op when nothing repeatable has run yet.
+	pub(crate) fn repeat_last_action(&mut self) -> HandleOutcome {
+		let Some(last) = self.state.core.editor.workspace.last_action.clone() else {
+			return HandleOutcome::Handled;
+		};
+		let Some(action) = xeno_registry::ACTIONS.get_by_id(last.id) else {
+			return HandleOutcome::Handled;
+		};
+		let name = action.id_str().to_string();
+
+		if !self.state.core.editor.workspace.macro_state.begin_replay() {
+			self.show_notification(xeno_registry::notifications::keys::macro_recursion_limit(MacroState::MAX_REPLAY_DEPTH));
+			return HandleOutcome::Handled;
+		}
+		let outcome = self.run_action_invocation(&name, last.count, last.extend, last.register, last.args.char, InvocationPolicy::enforcing());
+		self.state.core.editor.workspace.macro_state.end_replay();
+		match outcome.status {
+			InvocationStatus::Quit | InvocationStatus::ForceQuit => HandleOutcome::Quit,
+			_ => HandleOutcome::Handled,
+		}
+	}
+
+	/// Replays the macro recorded in `register` `count` times.
+	///
+	/// Each recorded invocation runs with its original count, extend flag,
+	/// register, and pending-action input. Replays nested deeper than
+	/// [`MacroState::MAX_REPLAY_DEPTH`] (a macro that replays itself) abort the
+	/// whole replay with a notification.
+	pub(crate) fn replay_macro(&mut self, register: char, count: usize) -> HandleOutcome {
+		let macro_state = &mut self.state.core.editor.workspace.macro_state;
+		let Some(invocations) = macro_state.get(register).map(<[_]>::to_vec) else {
+			self.show_notification(xeno_registry::notifications::keys::macro_empty(register));
+			return HandleOutcome::Handled;
+		};
+		macro_state.set_last_register(register);
+		if !macro_state.begin_replay() {
+			self.show_notification(xeno_registry::notifications::keys::macro_recursion_limit(MacroState::MAX_REPLAY_DEPTH));
+			return HandleOutcome::Handled;
+		}
+
+		let mut result = HandleOutcome::Handled;
+		'replay: for _ in 0..count.max(1) {
+			for invocation in &invocations {
+				let Some(action) = xeno_registry::ACTIONS.get_by_id(invocation.id) else {
+					continue;
+				};
+				let name = action.id_str().to_string();
+				let outcome = self.run_action_invocation(
+					&name,
+					invocation.count,
+					invocation.extend,
+					invocation.register,
+					invocation.args.char,
+					InvocationPolicy::enforcing(),
+				);
+				if matches!(outcome.status, InvocationStatus::Quit | InvocationStatus::ForceQuit) {
+					result = HandleOutcome::Quit;
+					break 'replay;
+				}
+				if self.state.core.editor.workspace.macro_state.replay_aborted() {
+					break 'replay;
+				}
+			}
+		}
+
+		self.state.core.editor.workspace.macro_state.end_replay();
+		result
+	}
+
 	/// Dispatches an action result to handlers and emits post-action hook.
 	pub(crate) fn apply_action_result(&mut self, action_id: &str, result: ActionResult, extend: bool) -> bool {
 		let (should_quit, result_variant) = {
 			let mut caps = self.caps();
 			let mut ctx = xeno_registry::actions::EditorContext::new(&mut caps);
 			let result_variant = result.variant_name();
-			let ActionResult::Effects(effects) = result;
-			let should_quit = matches!(apply_effects(&effects, &mut ctx, extend), HandleOutcome::Quit);
+			let should_quit = matches!(dispatch_result(&result, &mut ctx, extend), HandleOutcome::Quit);
 			(should_quit, result_variant)
 		};
 
@@ -1,6 +1,7 @@
 use xeno_invocation::CommandRoute;
 use xeno_registry::RegistryEntry;
-use xeno_registry::commands::{CommandContext, find_command};
+use xeno_registry::actions::DeferredInvocationRequest;
+use xeno_registry::commands::{CommandContext, CommandError, CommandRange, find_command, split_range};
 
 use crate::commands::{EditorCommandContext, find_editor_command};
 use crate::impls::Editor;
@@ -8,6 +9,9 @@ use crate::impls::invocation::kernel::InvocationKernel;
 use crate::impls::invocation::policy_gate::InvocationGateInput;
 use crate::types::{InvocationOutcome, InvocationPolicy, InvocationTarget};
 
+/// Error for a range prefixed to a command without [`flags::ACCEPTS_RANGE`](xeno_registry::commands::flags::ACCEPTS_RANGE).
+const RANGE_REJECTED: CommandError = CommandError::Unsupported("command does not accept a range");
+
 enum ResolvedCommandTarget {
 	Editor(&'static crate::commands::EditorCommandDef),
 	Registry(xeno_registry::commands::CommandRef),
@@ -62,12 +66,28 @@ impl Editor {
 		route: CommandRoute,
 		policy: InvocationPolicy,
 	) -> (InvocationOutcome, CommandRoute) {
+		let invoked = name;
+		let (range, name) = match split_range(name) {
+			Ok(split) => split,
+			Err(error) => {
+				let mut kernel = InvocationKernel::new(self, policy);
+				let result = kernel.map_command_result(InvocationTarget::Command, Err(error));
+				return (kernel.flush_effects_and_return(result), route);
+			}
+		};
 		let CommandResolution { resolved_route, target } = resolve_command_target(name, route);
 		let outcome = match target {
-			ResolvedCommandTarget::Editor(editor_cmd) => self.execute_editor_command(name, args, editor_cmd, policy).await,
-			ResolvedCommandTarget::Registry(command_def) => self.execute_registry_command(name, args, command_def, policy).await,
+			ResolvedCommandTarget::Editor(editor_cmd) => self.execute_editor_command(name, args, range, editor_cmd, policy).await,
+			ResolvedCommandTarget::Registry(command_def) => self.execute_registry_command(name, args, range, command_def, policy).await,
 			ResolvedCommandTarget::Missing => InvocationOutcome::not_found(InvocationTarget::Command, format!("command:{name}")),
 		};
+		self.state.ui.confirmation.granted = None;
+		if let Some(prompt) = self.state.ui.confirmation.pending.take() {
+			let request = DeferredInvocationRequest::command(invoked.to_string(), args.to_vec());
+			if !self.open_confirm(prompt, request) {
+				tracing::warn!(command = invoked, "confirmation prompt could not open");
+			}
+		}
 		(outcome, resolved_route)
 	}
 
@@ -75,6 +95,7 @@ impl Editor {
 		&mut self,
 		_name: &str,
 		args: &[String],
+		range: Option<CommandRange>,
 		command_def: xeno_registry::commands::CommandRef,
 		policy: InvocationPolicy,
 	) -> InvocationOutcome {
@@ -83,15 +104,28 @@ impl Editor {
 		if let Some(result) = kernel.deny_if_policy_blocks(gate_input) {
 			return result;
 		}
+		if range.is_some() && !command_def.accepts_range() {
+			let result = kernel.map_command_result(InvocationTarget::Command, Err(RANGE_REJECTED));
+			return kernel.flush_effects_and_return(result);
+		}
 
 		let args_refs: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+		let parsed = match command_def.parse_args(&args_refs) {
+			Ok(parsed) => parsed,
+			Err(error) => {
+				let result = kernel.map_command_result(InvocationTarget::Command, Err(error));
+				return kernel.flush_effects_and_return(result);
+			}
+		};
 		let outcome = {
 			let mut caps = kernel.editor().caps();
 			let mut ctx = CommandContext {
 				editor: &mut caps,
 				args: &args_refs,
+				parsed: &parsed,
 				count: 1,
 				register: None,
+				range,
 				user_data: command_def.user_data,
 			};
 
@@ -106,6 +140,7 @@ impl Editor {
 		&mut self,
 		_name: &str,
 		args: &[String],
+		range: Option<CommandRange>,
 		editor_cmd: &'static crate::commands::EditorCommandDef,
 		policy: InvocationPolicy,
 	) -> InvocationOutcome {
@@ -114,12 +149,17 @@ impl Editor {
 		if let Some(result) = kernel.deny_if_policy_blocks(gate_input) {
 			return result;
 		}
+		if range.is_some() && !editor_cmd.accepts_range() {
+			let result = kernel.map_command_result(InvocationTarget::Command, Err(RANGE_REJECTED));
+			return kernel.flush_effects_and_return(result);
+		}
 
 		let args_refs: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
 		let outcome = {
 			let mut ctx = EditorCommandContext {
 				editor: kernel.editor(),
 				args: &args_refs,
+				range,
 			};
 
 			(editor_cmd.handler)(&mut ctx).await
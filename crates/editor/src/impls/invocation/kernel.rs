@@ -45,6 +45,7 @@ impl<'a> InvocationKernel<'a> {
 			CommandOutcome::Ok => InvocationOutcome::ok(target),
 			CommandOutcome::Quit => InvocationOutcome::quit(target),
 			CommandOutcome::ForceQuit => InvocationOutcome::force_quit(target),
+			outcome @ CommandOutcome::Pipeline(_) => self.map_command_outcome(outcome.collapse(), target),
 		}
 	}
 
@@ -102,3 +102,268 @@ async fn action_count_usize_max_clamped_at_engine_boundary() {
 		"action handler should be invoked exactly once with clamped count"
 	);
 }
+
+#[tokio::test]
+async fn repeat_last_replays_previous_action() {
+	INVOCATION_TEST_ACTION_COUNT.with(|c| c.set(0));
+
+	let mut editor = Editor::new_scratch();
+	let result = editor.invoke_action("invocation_test_action", 3, false, None, None).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+	let result = editor.invoke_action("repeat_last", 1, false, None, None).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+
+	assert_eq!(INVOCATION_TEST_ACTION_COUNT.with(|c| c.get()), 2);
+	let last = editor
+		.state
+		.core
+		.editor
+		.workspace
+		.last_action
+		.as_ref()
+		.expect("repeat_last keeps the replayed action");
+	assert_eq!(last.count, 3, "replay reuses the original count");
+}
+
+#[tokio::test]
+async fn repeat_last_after_delete_deletes_again() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("abcdef");
+	let len = |editor: &Editor| editor.buffer().with_doc(|doc| doc.content().len_chars());
+
+	let before = len(&editor);
+	editor.invoke_action("delete", 1, false, None, None).await;
+	let after_delete = len(&editor);
+	editor.invoke_action("repeat_last", 1, false, None, None).await;
+	let after_repeat = len(&editor);
+
+	assert!(after_delete < before, "delete should remove text");
+	assert_eq!(
+		before - after_delete,
+		after_delete - after_repeat,
+		"repeat_last should delete the same amount again"
+	);
+}
+
+#[tokio::test]
+async fn repeat_last_skips_motions_between_edit_and_repeat() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("abcdef");
+	let len = |editor: &Editor| editor.buffer().with_doc(|doc| doc.content().len_chars());
+
+	let before = len(&editor);
+	editor.invoke_action("delete", 1, false, None, None).await;
+	let after_delete = len(&editor);
+	editor.invoke_action("move_right", 1, false, None, None).await;
+	editor.invoke_action("repeat_last", 1, false, None, None).await;
+	let after_repeat = len(&editor);
+
+	assert_eq!(
+		before - after_delete,
+		after_delete - after_repeat,
+		"the motion must not replace the recorded delete"
+	);
+	let last = editor.state.core.editor.workspace.last_action.as_ref().expect("delete is recorded");
+	assert!(xeno_registry::ACTIONS.get_by_id(last.id).is_some_and(|action| action.name_str() == "delete"));
+}
+
+#[tokio::test]
+async fn repeat_last_skips_mode_switches_between_edit_and_repeat() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("abcdef");
+
+	editor.invoke_action("delete", 1, false, None, None).await;
+	editor.invoke_action("enter_insert", 1, false, None, None).await;
+	editor.invoke_action("enter_normal", 1, false, None, None).await;
+
+	let last = editor.state.core.editor.workspace.last_action.as_ref().expect("delete is recorded");
+	assert!(
+		xeno_registry::ACTIONS.get_by_id(last.id).is_some_and(|action| action.name_str() == "delete"),
+		"leaving insert mode must not replace the recorded delete"
+	);
+}
+
+#[tokio::test]
+async fn move_right_replaces_selection_unless_extending() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("abcdef");
+
+	editor.invoke_action("move_right", 1, false, None, None).await;
+	let moved = editor.buffer().selection.primary();
+	assert_eq!(moved.anchor, moved.head, "plain motion collapses the selection");
+
+	editor.invoke_action("move_right", 2, true, None, None).await;
+	let extended = editor.buffer().selection.primary();
+	assert_eq!(extended.anchor, moved.head, "extend keeps the anchor");
+	assert_eq!(extended.head, moved.head + 2, "extend moves the head");
+}
+
+#[tokio::test]
+async fn replay_macro_runs_recorded_edits_count_times() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("abc");
+	let lines = |editor: &Editor| editor.buffer().with_doc(|doc| doc.content().len_lines());
+
+	editor.invoke_action("record_macro", 1, false, Some('a'), None).await;
+	assert_eq!(editor.state.core.editor.workspace.macro_state.recording_register(), Some('a'));
+	editor.invoke_action("add_line_below", 1, false, None, None).await;
+	editor.invoke_action("add_line_below", 1, false, None, None).await;
+	editor.invoke_action("record_macro", 1, false, None, None).await;
+
+	let macro_state = &editor.state.core.editor.workspace.macro_state;
+	assert!(!macro_state.is_recording());
+	assert_eq!(macro_state.get('a').map(<[_]>::len), Some(2), "toggles are not recorded");
+
+	let before = lines(&editor);
+	let result = editor.invoke_action("replay_macro", 2, false, Some('a'), None).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+	assert_eq!(lines(&editor) - before, 4, "two recorded edits replayed twice");
+
+	editor.invoke_action("replay_last_macro", 1, false, None, None).await;
+	assert_eq!(lines(&editor) - before, 6);
+}
+
+#[tokio::test]
+async fn replay_macro_reproduces_typed_text() {
+	let mut editor = Editor::new_scratch();
+	let text = |editor: &Editor| editor.buffer().with_doc(|doc| doc.content().to_string());
+
+	editor.invoke_action("record_macro", 1, false, Some('t'), None).await;
+	editor.invoke_action("insert_mode", 1, false, None, None).await;
+	for c in "foo".chars() {
+		editor.handle_key(Key::new(KeyCode::Char(c))).await;
+	}
+	editor.handle_key(Key::new(KeyCode::Esc)).await;
+	editor.invoke_action("record_macro", 1, false, None, None).await;
+	assert_eq!(editor.mode(), Mode::Normal);
+	let recorded = text(&editor);
+	assert!(recorded.contains("foo"), "typed text lands in the buffer, got {recorded:?}");
+
+	editor.invoke_action("replay_macro", 1, false, Some('t'), None).await;
+	assert_eq!(text(&editor).matches("foo").count(), 2, "replay types the recorded text again");
+	assert_eq!(editor.mode(), Mode::Normal, "replay leaves insert mode like the recording did");
+}
+
+#[tokio::test]
+async fn self_replaying_macro_stops_at_depth_limit() {
+	let mut editor = Editor::new_scratch();
+	editor.invoke_action("record_macro", 1, false, Some('r'), None).await;
+	editor.invoke_action("replay_macro", 1, false, Some('r'), None).await;
+	editor.invoke_action("record_macro", 1, false, None, None).await;
+	editor.state.ui.notifications.take_pending();
+
+	let result = editor.invoke_action("replay_macro", 1, false, Some('r'), None).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+
+	let limit = xeno_registry::notifications::keys::macro_recursion_limit(crate::types::MacroState::MAX_REPLAY_DEPTH);
+	let notifications = editor.state.ui.notifications.take_pending();
+	assert_eq!(notifications.iter().filter(|n| n.id == limit.id).count(), 1, "limit is reported once");
+	assert!(
+		!editor.state.core.editor.workspace.macro_state.replay_aborted(),
+		"abort clears once the stack unwinds"
+	);
+}
+
+#[tokio::test]
+async fn range_rejected_for_command_without_accepts_range() {
+	let mut editor = Editor::new_scratch();
+	let result = editor
+		.run_invocation(
+			Invocation::command("%invocation_test_command_fail".to_string(), vec![]),
+			InvocationPolicy::enforcing(),
+		)
+		.await;
+
+	assert!(matches!(result.status, InvocationStatus::CommandError));
+	assert!(
+		result.detail_text().is_some_and(|msg| msg.contains("does not accept a range")),
+		"range should be rejected before the handler runs, got: {result:?}"
+	);
+}
+
+#[tokio::test]
+async fn delete_lines_honors_range() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("a\nb\nc\nd\n");
+	let text = |editor: &Editor| editor.buffer().with_doc(|doc| doc.content().to_string());
+
+	let result = editor.run_invocation(Invocation::command("2,3d", vec![]), InvocationPolicy::enforcing()).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+	assert_eq!(text(&editor), "a\nd\n");
+
+	let result = editor.run_invocation(Invocation::command("9d", vec![]), InvocationPolicy::enforcing()).await;
+	assert!(matches!(result.status, InvocationStatus::CommandError), "out-of-bounds range is an error");
+	assert_eq!(text(&editor), "a\nd\n");
+}
+
+#[tokio::test]
+async fn indent_honors_whole_buffer_range() {
+	let mut editor = Editor::new_scratch();
+	editor.buffer_mut().reset_content("a\nb");
+
+	let result = editor.run_invocation(Invocation::command("%>", vec![]), InvocationPolicy::enforcing()).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+	assert_eq!(editor.buffer().with_doc(|doc| doc.content().to_string()), "    a\n    b");
+}
+
+#[tokio::test]
+async fn toggle_notifications_suppresses_popups_and_shows_statusline_indicator() {
+	let mut editor = Editor::new_scratch();
+	editor.handle_window_resize(120, 30);
+	editor.take_notification_render_items();
+	let shows_dnd = |editor: &Editor| editor.statusline_render_plan().iter().any(|segment| segment.text == " DND ");
+
+	let result = editor.invoke_action("toggle_notifications", 1, false, None, None).await;
+	assert!(matches!(result.status, InvocationStatus::Ok));
+	assert!(editor.do_not_disturb());
+	assert!(shows_dnd(&editor));
+
+	editor.notify(xeno_registry::notifications::keys::error("suppressed"));
+	assert!(editor.take_notification_render_items().is_empty());
+	assert!(
+		editor.notification_history().any(|n| n.message == "suppressed"),
+		"suppressed popups are still recorded"
+	);
+
+	editor.invoke_action("toggle_notifications", 1, false, None, None).await;
+	assert!(!editor.do_not_disturb());
+	assert!(!shows_dnd(&editor));
+	editor.notify(xeno_registry::notifications::keys::error("shown"));
+	assert_eq!(editor.take_notification_render_items().len(), 1);
+}
+
+/// Runs `:force_quit` on a modified buffer, answers the confirm overlay, and drains the re-dispatch.
+async fn answer_force_quit_prompt(answer: &str) -> (Editor, bool) {
+	let mut editor = Editor::new_scratch();
+	editor.handle_window_resize(80, 24);
+	editor.buffer_mut().set_modified(true);
+
+	let result = editor
+		.run_invocation(Invocation::command("force_quit", vec![]), InvocationPolicy::enforcing())
+		.await;
+	assert!(matches!(result.status, InvocationStatus::Ok), "quit waits for the answer, got: {result:?}");
+	let active = editor.state.ui.overlay_system.interaction().active().expect("confirm overlay should open");
+	assert_eq!(active.controller.name(), "Confirm");
+	let input = active.session.input;
+
+	editor.state.core.editor.buffers.get_buffer_mut(input).unwrap().reset_content(answer);
+	editor.interaction_commit().await;
+	assert!(!editor.state.ui.overlay_system.interaction().is_open());
+	let report = editor.drain_runtime_work_report(usize::MAX).await;
+	(editor, report.should_quit)
+}
+
+#[tokio::test]
+async fn force_quit_prompt_declined_keeps_editor_open() {
+	let (editor, quit) = answer_force_quit_prompt("n").await;
+	assert!(!quit);
+	assert!(editor.state.ui.confirmation.pending.is_none());
+	assert!(editor.state.ui.confirmation.granted.is_none());
+}
+
+#[tokio::test]
+async fn force_quit_prompt_accepted_reruns_command() {
+	let (editor, quit) = answer_force_quit_prompt("y").await;
+	assert!(quit);
+	assert!(editor.state.ui.confirmation.granted.is_none(), "the re-run consumes the grant");
+}
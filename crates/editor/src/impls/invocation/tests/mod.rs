@@ -5,7 +5,7 @@ use xeno_primitives::{BoxFutureLocal, Key, KeyCode, Mode};
 use xeno_registry::CommandError;
 use xeno_registry::actions::{ActionEffects, ActionResult};
 use xeno_registry::commands::{CommandContext, CommandOutcome};
-use xeno_registry::hooks::{HookAction, HookContext, HookDef, HookHandler, HookMutability, HookPriority};
+use xeno_registry::hooks::{HookAction, HookContext, HookDef, HookHandler, HookMutability, HookPhase, HookPriority, HookSchedule};
 
 use super::*;
 use crate::types::{InvocationOutcome, InvocationStatus, InvocationTarget};
@@ -32,14 +32,18 @@ static ACTION_INVOCATION_TEST: xeno_registry::actions::ActionDef = xeno_registry
 		id: "xeno-editor::invocation_test_action",
 		name: "invocation_test_action",
 		keys: &[],
+		categories: &[],
 		description: "Invocation test action",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	short_desc: "Invocation test action",
 	handler: handler_invocation_test_action,
 	bindings: &[],
+	repeatable: true,
+	keyboard_icon: None,
 };
 
 static ACTION_INVOCATION_TEST_ALT: xeno_registry::actions::ActionDef = xeno_registry::actions::ActionDef {
@@ -47,14 +51,18 @@ static ACTION_INVOCATION_TEST_ALT: xeno_registry::actions::ActionDef = xeno_regi
 		id: "xeno-editor::invocation_test_action_alt",
 		name: "invocation_test_action_alt",
 		keys: &[],
+		categories: &[],
 		description: "Invocation test action alt",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	short_desc: "Invocation test action alt",
 	handler: handler_invocation_test_action_alt,
 	bindings: &[],
+	repeatable: true,
+	keyboard_icon: None,
 };
 
 fn handler_invocation_edit_action(_ctx: &xeno_registry::actions::ActionContext) -> ActionResult {
@@ -66,14 +74,18 @@ static ACTION_INVOCATION_EDIT: xeno_registry::actions::ActionDef = xeno_registry
 		id: "xeno-editor::invocation_edit_action",
 		name: "invocation_edit_action",
 		keys: &[],
+		categories: &[],
 		description: "Invocation edit action",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: true,
+		deprecated: None,
 	},
 	short_desc: "Invocation edit action",
 	handler: handler_invocation_edit_action,
 	bindings: &[],
+	repeatable: true,
+	keyboard_icon: None,
 };
 
 fn hook_handler_action_pre(ctx: &HookContext) -> HookAction {
@@ -88,15 +100,21 @@ static HOOK_ACTION_PRE: HookDef = HookDef {
 		id: "xeno-editor::invocation_test_action_pre",
 		name: "invocation_test_action_pre",
 		keys: &[],
+		categories: &[],
 		description: "Count action pre hooks",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	event: xeno_registry::HookEvent::ActionPre,
 	mutability: HookMutability::Immutable,
 	execution_priority: HookPriority::Interactive,
 	handler: HookHandler::Immutable(hook_handler_action_pre),
+	filter: None,
+	schedule: HookSchedule::Always,
+	phase: HookPhase::Main,
+	tags: &[],
 };
 
 fn hook_handler_action_post(ctx: &HookContext) -> HookAction {
@@ -111,15 +129,21 @@ static HOOK_ACTION_POST: HookDef = HookDef {
 		id: "xeno-editor::invocation_test_action_post",
 		name: "invocation_test_action_post",
 		keys: &[],
+		categories: &[],
 		description: "Count action post hooks",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	event: xeno_registry::HookEvent::ActionPost,
 	mutability: HookMutability::Immutable,
 	execution_priority: HookPriority::Interactive,
 	handler: HookHandler::Immutable(hook_handler_action_post),
+	filter: None,
+	schedule: HookSchedule::Always,
+	phase: HookPhase::Main,
+	tags: &[],
 };
 
 fn invocation_test_command_fail<'a>(_ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
@@ -131,13 +155,16 @@ static CMD_TEST_FAIL: xeno_registry::commands::CommandDef = xeno_registry::comma
 		id: "xeno-editor::invocation_test_command_fail",
 		name: "invocation_test_command_fail",
 		keys: &[],
+		categories: &[],
 		description: "Invocation test command failure",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	handler: invocation_test_command_fail,
 	user_data: None,
+	flags: xeno_registry::commands::flags::NONE,
 };
 
 fn register_invocation_test_defs(db: &mut xeno_registry::RegistryDbBuilder) -> Result<(), xeno_registry::RegistryError> {
@@ -145,8 +172,8 @@ fn register_invocation_test_defs(db: &mut xeno_registry::RegistryDbBuilder) -> R
 	db.push_domain::<xeno_registry::actions::Actions>(xeno_registry::actions::def::ActionInput::Static(ACTION_INVOCATION_TEST_ALT.clone()));
 	db.push_domain::<xeno_registry::actions::Actions>(xeno_registry::actions::def::ActionInput::Static(ACTION_INVOCATION_EDIT.clone()));
 	db.push_domain::<xeno_registry::commands::Commands>(xeno_registry::commands::def::CommandInput::Static(CMD_TEST_FAIL.clone()));
-	db.push_domain::<xeno_registry::hooks::Hooks>(xeno_registry::hooks::HookInput::Static(HOOK_ACTION_PRE));
-	db.push_domain::<xeno_registry::hooks::Hooks>(xeno_registry::hooks::HookInput::Static(HOOK_ACTION_POST));
+	db.push_domain::<xeno_registry::hooks::Hooks>(xeno_registry::hooks::HookInput::Static(HOOK_ACTION_PRE.clone()));
+	db.push_domain::<xeno_registry::hooks::Hooks>(xeno_registry::hooks::HookInput::Static(HOOK_ACTION_POST.clone()));
 	Ok(())
 }
 
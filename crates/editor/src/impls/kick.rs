@@ -136,6 +136,9 @@ impl Editor {
 /// 1. Data-directory themes (`~/.local/share/xeno/themes/`)
 /// 2. Config-directory themes (`~/.config/xeno/themes/`)
 ///
+/// Themes with an `inherits` field are resolved against their parent after
+/// deduplication, so a config-directory theme can extend a data-directory one.
+///
 /// Returns the deduped theme list and any parse errors. Registration happens
 /// on the editor thread after token validation.
 async fn load_themes_blocking(
@@ -144,7 +147,7 @@ async fn load_themes_blocking(
 ) -> (Vec<xeno_registry::themes::LinkedThemeDef>, Vec<(String, String)>) {
 	xeno_worker::spawn_blocking(xeno_worker::TaskClass::IoBlocking, move || {
 		let mut errors = Vec::new();
-		let mut all_themes: Vec<xeno_registry::themes::LinkedThemeDef> = Vec::new();
+		let mut all_themes: Vec<xeno_registry::themes::RuntimeTheme> = Vec::new();
 
 		if let Some(ref dir) = data_themes_dir {
 			collect_dir_themes(dir, &mut all_themes, &mut errors);
@@ -157,18 +160,25 @@ async fn load_themes_blocking(
 		// Deduplicate by canonical ID (xeno-registry::<name>)
 		let mut deduped = std::collections::BTreeMap::new();
 		for theme in all_themes {
-			deduped.insert(theme.meta.id.clone(), theme);
+			deduped.insert(theme.meta().id.clone(), theme);
 		}
 
-		(deduped.into_values().collect(), errors)
+		let (themes, inherit_errors) = xeno_registry::themes::resolve_theme_inheritance(deduped.into_values().collect());
+		errors.extend(inherit_errors.into_iter().map(|e| (e.theme().to_string(), e.to_string())));
+		(themes, errors)
 	})
 	.await
 	.unwrap_or_else(|_| (Vec::new(), Vec::new()))
 }
 
 /// Loads themes from `dir` into the accumulator vectors, logging on failure.
-fn collect_dir_themes(dir: &std::path::Path, themes: &mut Vec<xeno_registry::themes::LinkedThemeDef>, errors: &mut Vec<(String, String)>) {
-	use xeno_registry::config::nuon::parse_theme_standalone_str as parse_nuon_theme;
+///
+/// Accepts native `.nuon` themes and Helix `.toml` themes; Helix keys without
+/// a xeno equivalent are logged and skipped.
+fn collect_dir_themes(dir: &std::path::Path, themes: &mut Vec<xeno_registry::themes::RuntimeTheme>, errors: &mut Vec<(String, String)>) {
+	use xeno_registry::config::helix::parse_helix_theme_str;
+	use xeno_registry::config::nuon::parse_runtime_theme_str as parse_nuon_theme;
+	use xeno_registry::themes::RuntimeTheme;
 
 	if !dir.exists() {
 		return;
@@ -185,7 +195,7 @@ fn collect_dir_themes(dir: &std::path::Path, themes: &mut Vec<xeno_registry::the
 	let mut files: Vec<std::path::PathBuf> = entries
 		.flatten()
 		.map(|entry| entry.path())
-		.filter(|path| path.extension().is_some_and(|ext| ext == "nuon"))
+		.filter(|path| path.extension().is_some_and(|ext| ext == "nuon" || ext == "toml"))
 		.collect();
 
 	files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
@@ -201,6 +211,20 @@ fn collect_dir_themes(dir: &std::path::Path, themes: &mut Vec<xeno_registry::the
 			}
 		};
 
+		if path.extension().is_some_and(|ext| ext == "toml") {
+			let name = path.file_stem().unwrap_or_default().to_string_lossy();
+			match parse_helix_theme_str(&name, &content) {
+				Ok(helix) => {
+					for warning in &helix.warnings {
+						tracing::warn!(file = %filename, %warning, "unsupported Helix theme key");
+					}
+					themes.push(RuntimeTheme::Complete(Box::new(helix.theme)));
+				}
+				Err(e) => errors.push((filename, e.to_string())),
+			}
+			continue;
+		}
+
 		match parse_nuon_theme(&content) {
 			Ok(theme) => themes.push(theme),
 			Err(e) => errors.push((filename, e.to_string())),
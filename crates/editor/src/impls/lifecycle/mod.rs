@@ -39,7 +39,8 @@ impl Editor {
 		self.state.ui.ui = ui;
 	}
 
-	/// Runs the main editor tick: dirty buffer hooks, LSP sync, and animations.
+	/// Runs the main editor tick: dirty buffer hooks, LSP sync, key sequence timeouts,
+	/// background task completions, and animations.
 	///
 	/// Also drains completed background syntax parses from the [`xeno_syntax::SyntaxManager`]
 	/// and requests a redraw if any results were installed.
@@ -64,6 +65,9 @@ impl Editor {
 		#[cfg(feature = "lsp")]
 		self.queue_lsp_resyncs_from_documents();
 
+		self.resolve_key_sequence_timeout();
+		self.report_finished_background_tasks();
+
 		// Emit BufferChange hooks for all modified buffers
 		let dirty_ids: Vec<_> = self.state.core.frame.dirty_buffers.drain().collect();
 		let scratch_path = PathBuf::from("[scratch]");
@@ -105,6 +109,7 @@ impl Editor {
 		self.tick_document_highlights();
 
 		emit_hook_sync_with(&HookContext::new(HookEventData::EditorTick), &mut self.state.integration.work_scheduler);
+		self.drain_option_hooks();
 
 		self.flush_effects();
 	}
@@ -173,6 +173,20 @@ impl Editor {
 		}
 	}
 
+	/// Posts a notification for each command background task that stopped since the last tick.
+	pub(super) fn report_finished_background_tasks(&mut self) {
+		use xeno_registry::commands::tasks::TaskStatus;
+		use xeno_registry::notifications::keys;
+
+		for task in self.state.integration.background_tasks.drain_finished() {
+			match task.status {
+				TaskStatus::Completed => self.notify(keys::task_completed(&task.name)),
+				TaskStatus::Failed(error) => self.notify(keys::task_failed(&task.name, error)),
+				TaskStatus::Cancelled => self.notify(keys::task_cancelled(&task.name)),
+			}
+		}
+	}
+
 	/// Queues full LSP syncs for documents flagged by the LSP state manager.
 	#[cfg(feature = "lsp")]
 	pub(super) fn queue_lsp_resyncs_from_documents(&mut self) {
@@ -28,12 +28,36 @@ impl Editor {
 		self.show_notification(notification.into());
 	}
 
+	/// Emits a typed notification in group `key`, replacing the group's
+	/// notification if one is still on screen instead of stacking a new one.
+	pub fn notify_grouped(&mut self, key: impl Into<String>, notification: impl Into<Notification>) {
+		self.show_notification(notification.into().with_group_key(key));
+	}
+
 	/// Shows a typed notification (internal).
 	pub fn show_notification(&mut self, notification: Notification) {
 		push_notification(&mut self.state.ui.notifications, notification);
 		self.state.core.frame.needs_redraw = true;
 	}
 
+	/// Returns recorded notifications, oldest first, including those that were
+	/// filtered out or suppressed by do-not-disturb.
+	pub fn notification_history(&self) -> impl ExactSizeIterator<Item = &Notification> {
+		self.state.ui.notifications.history()
+	}
+
+	/// Flips do-not-disturb, which suppresses every notification popup, and
+	/// returns whether it is now on.
+	pub fn toggle_do_not_disturb(&mut self) -> bool {
+		self.state.core.frame.needs_redraw = true;
+		self.state.ui.notifications.toggle_do_not_disturb()
+	}
+
+	/// Returns whether do-not-disturb is on.
+	pub fn do_not_disturb(&self) -> bool {
+		self.state.ui.notifications.do_not_disturb()
+	}
+
 	/// Clears all visible notifications.
 	pub fn clear_all_notifications(&mut self) {
 		self.state.ui.notifications.clear();
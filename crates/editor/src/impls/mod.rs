@@ -8,12 +8,15 @@
 //! * `file_ops` - File save/load (implements [`xeno_registry::actions::FileOpsAccess`])
 //! * `focus` - View focus and navigation
 //! * `lifecycle` - Tick, startup, and render updates
+//! * `selection_ops` - Adding cursors and splitting selections
 //! * `splits` - Split view management
 //! * `theming` - Theme and syntax highlighting
 //!
 
 /// Buffer creation operations.
 mod buffer_ops;
+/// Persistent command-line history.
+mod command_history;
 /// Core editing state.
 mod core;
 /// Centralized edit executor.
@@ -44,6 +47,8 @@ mod navigation;
 mod options;
 /// Search state and operations.
 mod search;
+/// Multi-cursor selection operations.
+mod selection_ops;
 /// Split view operations.
 mod splits;
 /// Editor construction and top-level integration accessors.
@@ -141,6 +146,7 @@ fn log_registry_summary_once() {
 /// * `focus_next_view` / `focus_prev_view` - Cycle through views
 pub(crate) struct EffectiveKeymapCache {
 	pub(crate) catalog_version: u64,
+	pub(crate) actions_generation: u64,
 	pub(crate) overrides_hash: u64,
 	pub(crate) preset_ptr: usize,
 	pub(crate) index: Arc<KeymapSnapshot>,
@@ -208,6 +214,8 @@ pub(crate) struct IntegrationStateBundle {
 	pub(crate) syntax_manager: xeno_syntax::SyntaxManager,
 	/// Unified async work scheduler (hooks, LSP, indexing, watchers).
 	pub(crate) work_scheduler: WorkScheduler,
+	/// Background tasks spawned by commands, listed by `:tasks`.
+	pub(crate) background_tasks: xeno_registry::commands::TaskRegistry,
 	/// Background filesystem indexing and picker state.
 	pub(crate) filesystem: crate::filesystem::FsService,
 }
@@ -219,6 +227,8 @@ pub(crate) struct UiStateBundle {
 	pub(crate) overlay_system: OverlaySystem,
 	/// Notification system.
 	pub(crate) notifications: crate::notifications::NotificationCenter,
+	/// Command confirmation prompts awaiting an answer or a re-dispatch.
+	pub(crate) confirmation: crate::overlay::controllers::ConfirmationState,
 	/// Render cache for efficient viewport rendering.
 	pub(crate) render_cache: crate::render::cache::RenderCache,
 	/// Inlay hint cache for LSP inlay hints.
@@ -301,6 +311,10 @@ pub(crate) struct TelemetryStateBundle {
 	pub(crate) metrics: std::sync::Arc<crate::metrics::EditorMetrics>,
 	/// Command usage tracking for command palette ranking.
 	pub(crate) command_usage: crate::completion::CommandPaletteUsage,
+	/// Committed command lines, oldest first.
+	pub(crate) command_history: xeno_registry::commands::CommandHistory,
+	/// History file appended to on commit; `None` until [`Editor::load_command_history`].
+	pub(crate) command_history_path: Option<std::path::PathBuf>,
 }
 
 pub(crate) struct EditorState {
@@ -61,11 +61,14 @@ impl Editor {
 
 	/// Handles mouse scroll events.
 	///
-	/// Resolves `scroll-lines` and `tab-width` options and delegates to Buffer.
+	/// Resolves `scroll-lines`, `scroll-multiplier`, and `tab-width` options and
+	/// delegates to Buffer. The multiplied distance rounds to at least one line.
 	pub(crate) fn handle_mouse_scroll(&mut self, direction: ScrollDirection, count: usize) {
 		let scroll_lines = (self.option(keys::SCROLL_LINES) as usize).max(1);
+		let multiplier = self.option(keys::SCROLL_MULTIPLIER);
+		let lines = (((count * scroll_lines) as f64 * multiplier).round() as usize).max(1);
 		let tab_width = self.tab_width();
-		self.buffer_mut().handle_mouse_scroll(direction, count * scroll_lines, tab_width);
+		self.buffer_mut().handle_mouse_scroll(direction, lines, tab_width);
 		self.state.core.frame.needs_redraw = true;
 	}
 
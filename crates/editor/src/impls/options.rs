@@ -3,9 +3,11 @@
 //! Provides a single source of truth for resolving option values through the
 //! layered configuration system.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use xeno_registry::options::{FromOptionValue, OptionKey, OptionResolver, OptionStore, OptionValue, TypedOptionKey};
+use xeno_registry::hooks::SharedHookScheduler;
+
+use xeno_registry::options::{FiletypeOptions, FromOptionValue, OptionKey, OptionOrigin, OptionResolver, OptionStore, OptionValue, TypedOptionKey};
 
 use super::Editor;
 use crate::buffer::ViewId;
@@ -31,9 +33,13 @@ impl Editor {
 	///
 	/// Resolution order (highest priority first):
 	/// 1. Buffer-local override (set via `:setlocal`)
-	/// 2. Language-specific config (from `language "rust" { }` block)
-	/// 3. Global config (from `options { }` block)
-	/// 4. Compile-time default (from `#[derive_option]` macro)
+	/// 2. Filetype-specific config (from `filetype "markdown" { }` block)
+	/// 3. Language-specific config (from `language "rust" { }` block)
+	/// 4. Global config (from `options { }` block)
+	/// 5. Compile-time default (from `#[derive_option]` macro)
+	///
+	/// A buffer's filetype is its detected language name, falling back to the
+	/// file extension for paths without a matching language.
 	///
 	/// # Panics
 	///
@@ -54,11 +60,26 @@ impl Editor {
 
 	/// Resolves an option for a specific buffer using a resolved reference.
 	pub fn resolve_option_ref(&self, buffer_id: ViewId, opt: &xeno_registry::options::OptionsRef) -> OptionValue {
+		self.option_resolver(buffer_id).resolve(opt)
+	}
+
+	/// Resolves an option for a specific buffer along with the layer and config
+	/// location that supplied it, for `:set key?`.
+	pub fn resolve_option_origin(&self, buffer_id: ViewId, opt: &xeno_registry::options::OptionsRef) -> (OptionValue, OptionOrigin) {
+		self.option_resolver(buffer_id).resolve_with_origin(opt)
+	}
+
+	/// Builds the resolver over every option layer that applies to a buffer.
+	fn option_resolver(&self, buffer_id: ViewId) -> OptionResolver<'_> {
 		let buffer = self.state.core.editor.buffers.get_buffer(buffer_id).expect("buffer must exist");
 
-		let language_store = buffer.file_type().and_then(|ft| self.state.config.config.language_options.get(&ft));
+		let config = &self.state.config.config;
+		let language = buffer.file_type();
+		let filetype = language.clone().or_else(|| buffer.path()?.extension()?.to_str().map(str::to_string));
+		let language_store = language.and_then(|ft| config.language_options.get(&ft));
+		let filetype_store = filetype.and_then(|ft| config.filetype_options.get(&ft));
 
-		Self::resolve_with_stores(&buffer.local_options, language_store, &self.state.config.config.global_options, opt)
+		Self::resolver_with_stores(&buffer.local_options, filetype_store, language_store, &config.global_options)
 	}
 
 	/// Resolves a typed option for a specific buffer.
@@ -100,14 +121,21 @@ impl Editor {
 	/// Replaces editor key/option configuration with a loaded user config.
 	///
 	/// This is used by startup and reload flows to keep config merge/apply
-	/// behavior consistent across runtimes.
+	/// behavior consistent across runtimes. Global, language and filetype
+	/// options are each committed to their existing store in one transaction,
+	/// so watchers and subscribers stay attached and each store fires an
+	/// `OptionChanged` hook for every option whose value the new config changes.
+	/// Stores for languages or filetypes the new config drops are emptied, not
+	/// removed.
 	pub fn apply_loaded_config(&mut self, mut config: Option<xeno_registry::config::Config>) {
 		let mut key_overrides = None;
 		let mut preset_name = None;
 		let mut global_options = OptionStore::new();
 		let mut language_options = HashMap::<String, OptionStore>::new();
+		let mut filetype_options = FiletypeOptions::new();
 
 		let mut nu_config = None;
+		let mut text_objects = Vec::new();
 		if let Some(mut loaded) = config.take() {
 			if let Some(ref mut km) = loaded.keymap {
 				key_overrides = km.keys.take();
@@ -119,34 +147,156 @@ impl Editor {
 			for lang_config in loaded.languages {
 				language_options.entry(lang_config.name).or_default().merge(&lang_config.options);
 			}
+			for filetype_config in loaded.filetypes {
+				filetype_options.merge(&filetype_config.name, &filetype_config.options);
+			}
+			text_objects = loaded.text_objects;
 		}
 
+		for id in self.config_mut().text_objects.drain(..) {
+			xeno_registry::textobj::unregister(&id);
+		}
+		let text_object_ids = text_objects
+			.into_iter()
+			.map(|def| {
+				let id = def.id.clone();
+				xeno_registry::textobj::register_runtime(def);
+				id
+			})
+			.collect();
+
 		self.set_key_overrides(key_overrides);
 		self.set_keymap_preset(preset_name.unwrap_or_else(|| xeno_registry::keymaps::DEFAULT_PRESET.to_string()));
 		let editor_config = self.config_mut();
-		editor_config.global_options = global_options;
-		editor_config.language_options = language_options;
+		let hooks = editor_config.option_hooks.clone();
+		let empty = OptionStore::new();
+		reload_option_store(&mut editor_config.global_options, &global_options, "global", &hooks);
+
+		for (name, store) in &mut editor_config.language_options {
+			reload_option_store(store, language_options.get(name).unwrap_or(&empty), "language", &hooks);
+		}
+		for (name, loaded) in &language_options {
+			if !editor_config.language_options.contains_key(name) {
+				let store = editor_config.language_options.entry(name.clone()).or_default();
+				reload_option_store(store, loaded, "language", &hooks);
+			}
+		}
+
+		let filetypes: HashSet<String> = editor_config
+			.filetype_options
+			.names()
+			.chain(filetype_options.names())
+			.map(str::to_string)
+			.collect();
+		for name in filetypes {
+			let loaded = filetype_options.get(&name).unwrap_or(&empty);
+			reload_option_store(editor_config.filetype_options.get_or_insert(&name), loaded, "filetype", &hooks);
+		}
+
 		editor_config.nu = nu_config;
+		editor_config.text_objects = text_object_ids;
+		self.drain_option_hooks();
+	}
+
+	/// Moves async `OptionChanged` hook futures raised by option store writes
+	/// into the work scheduler.
+	pub(crate) fn drain_option_hooks(&mut self) {
+		self.state.config.config.option_hooks.drain_into(&mut self.state.integration.work_scheduler);
 	}
 
-	/// Internal helper that performs resolution given the stores directly.
+	/// Internal helper that builds a resolver given the stores directly.
 	///
 	/// This avoids borrowing issues when the buffer is already borrowed.
-	fn resolve_with_stores(
-		buffer_options: &OptionStore,
-		language_options: Option<&OptionStore>,
-		global_options: &OptionStore,
-		opt: &xeno_registry::options::OptionsRef,
-	) -> OptionValue {
-		let resolver = if let Some(lang_store) = language_options {
-			OptionResolver::new()
-				.with_buffer(buffer_options)
-				.with_language(lang_store)
-				.with_global(global_options)
-		} else {
-			OptionResolver::new().with_buffer(buffer_options).with_global(global_options)
+	fn resolver_with_stores<'a>(
+		buffer_options: &'a OptionStore,
+		filetype_options: Option<&'a OptionStore>,
+		language_options: Option<&'a OptionStore>,
+		global_options: &'a OptionStore,
+	) -> OptionResolver<'a> {
+		let mut resolver = OptionResolver::new().with_buffer(buffer_options).with_global(global_options);
+		if let Some(filetype_store) = filetype_options {
+			resolver = resolver.with_filetype(filetype_store);
+		}
+		if let Some(lang_store) = language_options {
+			resolver = resolver.with_language(lang_store);
+		}
+		resolver
+	}
+}
+
+/// Commits `loaded` as the new contents of `store` in one transaction, with
+/// `OptionChanged` hooks reported under `scope`.
+fn reload_option_store(store: &mut OptionStore, loaded: &OptionStore, scope: &'static str, hooks: &SharedHookScheduler) {
+	store.emit_hooks(scope, hooks.clone());
+	let mut reload = store.transaction();
+	reload.replace_with(loaded);
+	if let Err(errors) = reload.commit() {
+		for error in errors {
+			tracing::warn!(scope, error = %error, "rejected option from loaded config");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use xeno_registry::options::{OptionStore, OptionValue, option_keys as keys};
+
+	use crate::Editor;
+
+	fn config_with_tab_width(width: i64) -> xeno_registry::config::Config {
+		let mut options = OptionStore::new();
+		options.set_by_key(&xeno_registry::OPTIONS, "tab-width", OptionValue::Int(width)).unwrap();
+		xeno_registry::config::Config { options, ..Default::default() }
+	}
+
+	#[tokio::test]
+	async fn config_reload_keeps_global_option_watchers() {
+		let mut editor = Editor::new_scratch();
+		let mut tab_width = editor.config().global_options.watch(keys::TAB_WIDTH);
+
+		editor.apply_loaded_config(Some(config_with_tab_width(2)));
+		assert!(tab_width.has_changed());
+		assert_eq!(tab_width.get_and_update(), 2);
+
+		editor.apply_loaded_config(Some(config_with_tab_width(2)));
+		assert!(!tab_width.has_changed(), "reloading the same value changes nothing");
+
+		editor.apply_loaded_config(None);
+		assert_eq!(tab_width.get_and_update(), 4, "options missing from the new config fall back to defaults");
+	}
+
+	#[tokio::test]
+	async fn config_reload_reports_language_and_filetype_changes() {
+		let with_overrides = |width: i64| {
+			let config = config_with_tab_width(width);
+			xeno_registry::config::Config {
+				languages: vec![xeno_registry::config::LanguageConfig {
+					name: "rust".to_string(),
+					options: config.options.clone(),
+				}],
+				filetypes: vec![xeno_registry::config::FiletypeConfig {
+					name: "markdown".to_string(),
+					options: config.options,
+				}],
+				..Default::default()
+			}
 		};
+		let mut editor = Editor::new_scratch();
+		editor.apply_loaded_config(Some(with_overrides(2)));
+		let config = editor.config_mut();
+		let mut rust = config.language_options.get_mut("rust").expect("rust store").subscribe();
+		let mut markdown = config.filetype_options.get_or_insert("markdown").subscribe();
+
+		editor.apply_loaded_config(Some(with_overrides(8)));
+		let change = rust.try_recv().expect("language store reports the reload");
+		assert_eq!(
+			(change.key.as_str(), change.old, change.new),
+			("tab-width", OptionValue::Int(2), OptionValue::Int(8))
+		);
+		assert_eq!(markdown.try_recv().expect("filetype store reports the reload").new, OptionValue::Int(8));
 
-		resolver.resolve(opt)
+		editor.apply_loaded_config(None);
+		assert_eq!(rust.try_recv().expect("dropped language block resets").new, OptionValue::Int(4));
+		assert!(editor.config().language_options["rust"].is_empty());
 	}
 }
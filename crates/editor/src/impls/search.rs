@@ -146,50 +146,6 @@ impl Editor {
 		false
 	}
 
-	/// Splits the selection at regex matches, keeping the non-matching parts.
-	#[allow(dead_code, reason = "regex split will be re-enabled via picker UI")]
-	pub(crate) fn split_regex(&mut self, pattern: &str) -> bool {
-		let primary = self.buffer().selection.primary();
-		let from = primary.from();
-		let to = primary.to();
-		if from >= to {
-			self.notify(keys::NO_SELECTION_TO_SPLIT);
-			return false;
-		}
-
-		let search_result = self.buffer().with_doc(|doc| movement::find_all_matches(doc.content().slice(from..to), pattern));
-		match search_result {
-			Ok(matches) if !matches.is_empty() => {
-				let mut new_ranges: Vec<xeno_primitives::Range> = Vec::new();
-				let mut last_end = from;
-				for m in matches {
-					let match_start = from + m.min();
-					if match_start > last_end {
-						new_ranges.push(xeno_primitives::Range::from_exclusive(last_end, match_start));
-					}
-					last_end = from + m.to();
-				}
-				if last_end < to {
-					new_ranges.push(xeno_primitives::Range::from_exclusive(last_end, to));
-				}
-				if !new_ranges.is_empty() {
-					let count = new_ranges.len();
-					self.buffer_mut().set_selection(Selection::from_vec(new_ranges, 0));
-					self.notify(keys::splits_count(count));
-				} else {
-					self.notify(keys::SPLIT_NO_RANGES);
-				}
-			}
-			Ok(_) => {
-				self.notify(keys::NO_MATCHES_TO_SPLIT);
-			}
-			Err(e) => {
-				self.notify(keys::regex_error(&e.to_string()));
-			}
-		}
-		false
-	}
-
 	/// Keeps only selections that match (or don't match) the pattern.
 	#[allow(dead_code, reason = "keep-matching filter will be re-enabled via picker UI")]
 	pub(crate) fn keep_matching(&mut self, pattern: &str, invert: bool) -> bool {
@@ -0,0 +1,44 @@
+//! Multi-cursor selection operations.
+
+use xeno_input::movement;
+use xeno_primitives::Direction as MoveDir;
+use xeno_registry::notifications::keys;
+
+use super::Editor;
+
+impl Editor {
+	/// Adds a cursor `count` lines below or above the outermost selection.
+	///
+	/// Resolves the `tab-width` option and delegates to Buffer.
+	pub fn add_cursor_vertical(&mut self, direction: MoveDir, count: usize) {
+		let tab_width = self.tab_width();
+		self.buffer_mut().add_cursor_vertical(direction, count, tab_width);
+	}
+
+	/// Splits every selection at matches of `pattern`, keeping the text
+	/// between matches.
+	///
+	/// Selections without a match are kept whole.
+	pub(crate) fn split_selections_on_regex(&mut self, pattern: &str) {
+		let selection = self.buffer().selection.clone();
+		let result = self
+			.buffer()
+			.with_doc(|doc| movement::split_on_matches(doc.content().slice(..), &selection, pattern));
+		match result {
+			Ok(Some(split)) if split == selection => {
+				self.notify(keys::NO_MATCHES_TO_SPLIT);
+			}
+			Ok(Some(split)) => {
+				let count = split.len();
+				self.buffer_mut().set_cursor_and_selection(split.primary().head, split);
+				self.notify(keys::splits_count(count));
+			}
+			Ok(None) => {
+				self.notify(keys::SPLIT_NO_RANGES);
+			}
+			Err(e) => {
+				self.notify(keys::regex_error(&e.to_string()));
+			}
+		}
+	}
+}
@@ -168,6 +168,7 @@ impl Editor {
 				..Default::default()
 			}),
 			work_scheduler,
+			background_tasks: xeno_registry::commands::TaskRegistry::new(),
 			filesystem: crate::filesystem::FsService::new_with_runtime(),
 		}
 	}
@@ -177,6 +178,7 @@ impl Editor {
 			ui: UiManager::new(),
 			overlay_system: OverlaySystem::default(),
 			notifications: crate::notifications::NotificationCenter::new(),
+			confirmation: crate::overlay::controllers::ConfirmationState::default(),
 			render_cache: crate::render::cache::RenderCache::new(),
 			#[cfg(feature = "lsp")]
 			inlay_hint_cache: crate::lsp::inlay_hints::InlayHintCache::new(),
@@ -233,6 +235,8 @@ impl Editor {
 		TelemetryStateBundle {
 			metrics: std::sync::Arc::new(crate::metrics::EditorMetrics::new()),
 			command_usage: crate::completion::CommandPaletteUsage::default(),
+			command_history: xeno_registry::commands::CommandHistory::default(),
+			command_history_path: None,
 		}
 	}
 
@@ -520,7 +524,7 @@ impl Editor {
 		self.state.integration.nu.ensure_executor()
 	}
 
-	/// Returns the effective keymap for the current catalog version, preset, and overrides.
+	/// Returns the effective keymap for the current catalog version, actions generation, preset, and overrides.
 	pub fn effective_keymap(&self) -> Arc<KeymapSnapshot> {
 		let catalog_version = xeno_registry::CATALOG.version_hash();
 		let snap = xeno_registry::ACTIONS.snapshot();
@@ -531,6 +535,7 @@ impl Editor {
 			let cache = self.state.config.keymap_cache.lock();
 			if let Some(cache) = cache.as_ref()
 				&& cache.catalog_version == catalog_version
+				&& cache.actions_generation == snap.generation
 				&& cache.overrides_hash == overrides_hash
 				&& cache.preset_ptr == preset_ptr
 			{
@@ -546,6 +551,7 @@ impl Editor {
 		let mut cache = self.state.config.keymap_cache.lock();
 		*cache = Some(EffectiveKeymapCache {
 			catalog_version,
+			actions_generation: snap.generation,
 			overrides_hash,
 			preset_ptr,
 			index: Arc::clone(&index),
@@ -553,9 +559,12 @@ impl Editor {
 		index
 	}
 
-	#[inline]
+	/// Takes pending notifications for display, filtered by the
+	/// `notifications-min-level` option and do-not-disturb at call time.
 	pub fn take_notification_render_items(&mut self) -> Vec<crate::notifications::NotificationRenderItem> {
-		self.state.ui.notifications.take_pending_render_items()
+		let min_level = self.option(xeno_registry::options::option_keys::NOTIFICATIONS_MIN_LEVEL);
+		let min_severity = xeno_registry::notifications::Level::min_severity(&min_level);
+		self.state.ui.notifications.take_pending_render_items(min_severity)
 	}
 
 	#[inline]
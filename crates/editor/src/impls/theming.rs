@@ -2,8 +2,9 @@
 //!
 //! Theme management and highlight span collection.
 
+use xeno_primitives::ViewId;
 use xeno_registry::commands::CommandError;
-use xeno_registry::themes::SyntaxStyles;
+use xeno_registry::themes::{SyntaxStyles, Theme};
 
 use super::Editor;
 
@@ -47,27 +48,38 @@ impl Editor {
 
 	/// Sets the editor's color theme by name.
 	pub fn set_theme(&mut self, theme_name: &str) -> Result<(), CommandError> {
-		if let Some(theme_ref) = xeno_registry::themes::get_theme(theme_name) {
-			// Leak the name for RegistryMetaStatic since themes are rarely changed
-			let name: &'static str = Box::leak(theme_name.to_string().into_boxed_str());
-			self.state.config.config.theme = xeno_registry::themes::Theme {
-				meta: xeno_registry::RegistryMetaStatic::minimal(name, name, ""),
-				variant: theme_ref.variant,
-				colors: theme_ref.colors,
-			};
-			// Increment theme epoch to invalidate highlight cache
-			let new_epoch = self.state.ui.render_cache.theme_epoch.wrapping_add(1);
-			self.state.ui.render_cache.set_theme_epoch(new_epoch);
-			Ok(())
-		} else {
-			let mut err = format!("Theme not found: {}", theme_name);
-			if let Some(suggestion) = xeno_registry::themes::suggest_theme(theme_name) {
-				err.push_str(&format!(". Did you mean '{}'?", suggestion));
-			}
-			Err(CommandError::Failed(err))
+		self.state.config.config.theme = lookup_theme(theme_name)?;
+		self.bump_theme_epoch();
+		Ok(())
+	}
+
+	/// Overrides the theme of `buffer` by name, leaving the editor theme unchanged.
+	pub fn set_buffer_theme(&mut self, buffer: ViewId, theme_name: &str) -> Result<(), CommandError> {
+		let theme = lookup_theme(theme_name)?;
+		self.state.config.config.theme_overrides.set(buffer, theme);
+		self.bump_theme_epoch();
+		Ok(())
+	}
+
+	/// Removes the theme override of `buffer`, if any.
+	pub fn clear_buffer_theme(&mut self, buffer: ViewId) {
+		if self.state.config.config.theme_overrides.clear(buffer).is_some() {
+			self.bump_theme_epoch();
 		}
 	}
 
+	/// Returns the theme `buffer` renders with: its override, else the editor theme.
+	pub fn effective_theme(&self, buffer: ViewId) -> &Theme {
+		let config = &self.state.config.config;
+		config.theme_overrides.resolve(buffer, &config.theme)
+	}
+
+	/// Increments the theme epoch to invalidate the highlight cache.
+	fn bump_theme_epoch(&mut self) {
+		let new_epoch = self.state.ui.render_cache.theme_epoch.wrapping_add(1);
+		self.state.ui.render_cache.set_theme_epoch(new_epoch);
+	}
+
 	/// Collects syntax highlight spans for the visible area of the buffer.
 	pub fn collect_highlight_spans(&self, area: crate::geometry::Rect) -> Vec<(xeno_language::HighlightSpan, xeno_primitives::Style)> {
 		let buffer = self.buffer();
@@ -127,3 +139,21 @@ impl Editor {
 		None
 	}
 }
+
+/// Looks up a registered theme by name, suggesting a close match when missing.
+fn lookup_theme(theme_name: &str) -> Result<Theme, CommandError> {
+	let Some(theme_ref) = xeno_registry::themes::get_theme(theme_name) else {
+		let mut err = format!("Theme not found: {}", theme_name);
+		if let Some(suggestion) = xeno_registry::themes::suggest_theme(theme_name) {
+			err.push_str(&format!(". Did you mean '{}'?", suggestion));
+		}
+		return Err(CommandError::Failed(err));
+	};
+	// Leak the name for RegistryMetaStatic since themes are rarely changed
+	let name: &'static str = Box::leak(theme_name.to_string().into_boxed_str());
+	Ok(Theme {
+		meta: xeno_registry::RegistryMetaStatic::minimal(name, name, ""),
+		variant: theme_ref.variant,
+		colors: theme_ref.colors,
+	})
+}
@@ -2,10 +2,14 @@
 
 mod ops;
 
+use std::time::Duration;
+
 use xeno_input::KeyResult;
 use xeno_primitives::{Key, Mode};
+use xeno_registry::options::option_keys as keys;
 
 use crate::Editor;
+use crate::runtime::work_queue::{RuntimeWorkSource, WorkExecutionPolicy, WorkScope};
 
 impl Editor {
 	/// Processes a key event, routing to UI or input state machine.
@@ -37,6 +41,30 @@ impl Editor {
 		quit
 	}
 
+	/// Commits a pending ambiguous key sequence once `keyseq-timeout` has elapsed.
+	///
+	/// Runs from [`Editor::tick`], so the committed binding is queued as runtime
+	/// work under the same enforcing policy as direct key dispatch.
+	pub(crate) fn resolve_key_sequence_timeout(&mut self) {
+		if self.buffer().input.pending_key_count() == 0 {
+			return;
+		}
+		let timeout = Duration::from_millis(self.option(keys::KEYSEQ_TIMEOUT).max(0) as u64);
+		let keymap = self.effective_keymap();
+		let Some(result) = self.buffer_mut().input.resolve_key_timeout(&keymap, timeout) else {
+			return;
+		};
+		if let KeyResult::Dispatch(dispatch) = result {
+			self.enqueue_runtime_invocation(
+				dispatch.invocation,
+				RuntimeWorkSource::KeySequenceTimeout,
+				WorkExecutionPolicy::EnforcingNuPipeline,
+				WorkScope::Global,
+			);
+		}
+		self.state.core.frame.needs_redraw = true;
+	}
+
 	/// Handles a key event when in active editing mode.
 	pub(crate) async fn handle_key_active(&mut self, key: Key) -> bool {
 		use xeno_registry::HookEventData;
@@ -113,6 +141,9 @@ impl Editor {
 				if leaving_insert {
 					self.cancel_snippet_session();
 					self.buffer_mut().clear_undo_group();
+					if matches!(old_mode, Mode::Insert) {
+						self.record_input_invocation("normal_mode", None);
+					}
 				}
 				#[cfg(feature = "lsp")]
 				{
@@ -127,6 +158,7 @@ impl Editor {
 				if !self.snippet_replace_mode_insert(&text) {
 					self.insert_text(&text);
 				}
+				self.record_input_invocation("insert_char", Some(c));
 				#[cfg(feature = "lsp")]
 				{
 					inserted_char = Some(c);
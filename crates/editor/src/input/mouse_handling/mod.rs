@@ -1,6 +1,7 @@
 //! Mouse event handling.
 //!
-//! Processing mouse input for text selection and separator dragging.
+//! Processing mouse input for text selection, separator dragging, and
+//! statusline segment clicks.
 
 mod context;
 mod effects;
@@ -8,9 +9,10 @@ mod routing;
 
 use routing::decide_mouse_route;
 use xeno_input::KeyResult;
-use xeno_primitives::MouseEvent;
+use xeno_primitives::{MouseButton, MouseEvent};
 
 use crate::impls::{Editor, FocusTarget};
+use crate::types::InvocationPolicy;
 
 impl Editor {
 	/// Processes a mouse event, returning true if the event triggered a quit.
@@ -20,6 +22,17 @@ impl Editor {
 
 		// Main area excludes status line (1 row)
 		let main_height = height.saturating_sub(1);
+
+		if let MouseEvent::Press {
+			button: MouseButton::Left,
+			row,
+			col,
+			..
+		} = mouse && row == main_height
+			&& let Some(action) = crate::ui::statusline_action_at(self, col)
+		{
+			return self.apply_input_invocation_request(action.to_invocation(), InvocationPolicy::enforcing()).await;
+		}
 		let main_area = crate::geometry::Rect {
 			x: 0,
 			y: 0,
@@ -44,3 +44,15 @@ async fn click_outside_modal_closes_overlay() {
 
 	assert!(!editor.state.ui.overlay_system.interaction().is_open());
 }
+
+#[tokio::test]
+async fn statusline_file_segment_click_opens_file_picker() {
+	let mut editor = Editor::new_scratch();
+	editor.handle_window_resize(100, 40);
+
+	let _ = editor.handle_mouse(mouse_down(60, 39)).await;
+	assert!(!editor.state.ui.overlay_system.interaction().is_open(), "spacer gap has no action");
+
+	let _ = editor.handle_mouse(mouse_down(1, 39)).await;
+	assert_eq!(editor.overlay_kind(), Some(crate::overlay::OverlayControllerKind::FilePicker));
+}
@@ -99,7 +99,7 @@ pub(crate) mod window;
 pub use bootstrap::init as bootstrap_init;
 pub use buffer::{Buffer, HistoryResult, ViewId};
 pub(crate) use completion::CompletionState;
-pub use editor_ctx::{EditorCapabilities, EditorContext, EditorOps, HandleOutcome, apply_effects};
+pub use editor_ctx::{EditorCapabilities, EditorContext, EditorOps, HandleOutcome, apply_effects, dispatch_result};
 pub use impls::{Editor, FocusReason, FocusTarget, FrontendFramePlan, PanelId};
 #[cfg(feature = "lsp")]
 pub use lsp::LspDiagnosticsEvent;
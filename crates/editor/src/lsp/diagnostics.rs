@@ -7,8 +7,8 @@ use crate::render::{DiagnosticLineMap, DiagnosticRangeMap, DiagnosticSpan};
 
 /// Builds a diagnostic line map from LSP diagnostics.
 ///
-/// Converts LSP severity to gutter severity and keeps only the highest
-/// severity per line.
+/// Converts LSP severity to gutter severity and counts the diagnostics
+/// starting on each line by severity.
 pub fn build_diagnostic_line_map(diagnostics: &[Diagnostic]) -> DiagnosticLineMap {
 	let mut map = DiagnosticLineMap::new();
 
@@ -21,7 +21,7 @@ pub fn build_diagnostic_line_map(diagnostics: &[Diagnostic]) -> DiagnosticLineMa
 			DiagnosticSeverity::Info => 2,
 			DiagnosticSeverity::Hint => 1,
 		};
-		map.entry(line).and_modify(|e| *e = (*e).max(severity)).or_insert(severity);
+		map.entry(line).or_default().add(severity);
 	}
 
 	map
@@ -339,6 +339,7 @@ impl Editor {
 			affected_docs,
 			view_snapshots: all_view_snapshots,
 			origin: EditOrigin::Lsp,
+			description: Some("Apply LSP workspace edit".to_string()),
 		});
 	}
 
@@ -236,6 +236,7 @@ impl EditorMetrics {
 			RuntimeWorkSource::CommandOps => "command_ops",
 			RuntimeWorkSource::NuHookDispatch => "nu_hook_dispatch",
 			RuntimeWorkSource::NuScheduledMacro => "nu_scheduled_macro",
+			RuntimeWorkSource::KeySequenceTimeout => "key_sequence_timeout",
 		});
 		let kind_label = match kind {
 			RuntimeWorkKindTag::Invocation => "invocation",
@@ -8,10 +8,21 @@
 use std::collections::VecDeque;
 use std::time::Duration;
 
-use xeno_registry::notifications::Notification;
+use xeno_registry::notifications::{Notification, NotificationQueue};
+
+/// Number of notifications kept in [`NotificationCenter::history`].
+const HISTORY_LIMIT: usize = 100;
+
+/// Number of notifications waiting for the frontend before the oldest is dropped.
+const PENDING_LIMIT: usize = 100;
 
 pub(crate) struct NotificationCenter {
-	pending: VecDeque<Notification>,
+	/// Notifications waiting for the frontend; repeats within a second are dropped.
+	pending: NotificationQueue,
+	/// Every pushed notification, displayed or not, oldest first.
+	history: VecDeque<Notification>,
+	/// Do-not-disturb: suppresses every popup while set.
+	do_not_disturb: bool,
 	clear_epoch: u64,
 }
 
@@ -38,6 +49,8 @@ pub struct NotificationRenderItem {
 	pub message: String,
 	pub level: NotificationRenderLevel,
 	pub auto_dismiss: NotificationRenderAutoDismiss,
+	/// Group whose on-screen notification this item replaces.
+	pub group_key: Option<String>,
 }
 
 impl From<xeno_registry::notifications::Level> for NotificationRenderLevel {
@@ -69,6 +82,7 @@ impl From<Notification> for NotificationRenderItem {
 			message: notification.message,
 			level: level.into(),
 			auto_dismiss: auto_dismiss.into(),
+			group_key: notification.group_key,
 		}
 	}
 }
@@ -82,7 +96,9 @@ impl Default for NotificationCenter {
 impl NotificationCenter {
 	pub(crate) fn new() -> Self {
 		Self {
-			pending: VecDeque::new(),
+			pending: NotificationQueue::new(PENDING_LIMIT).with_dedup(true),
+			history: VecDeque::new(),
+			do_not_disturb: false,
 			clear_epoch: 0,
 		}
 	}
@@ -92,16 +108,56 @@ impl NotificationCenter {
 		self.clear_epoch = self.clear_epoch.wrapping_add(1);
 	}
 
-	pub(crate) fn push(&mut self, notification: Notification) {
-		self.pending.push_back(notification);
+	/// Queues `notification`, replacing a pending notification of the same group.
+	///
+	/// Unresolved notifications take their level from the registry first, and
+	/// every notification is recorded in [`Self::history`], including repeats
+	/// the queue drops.
+	pub(crate) fn push(&mut self, mut notification: Notification) {
+		if notification.level.is_none() {
+			notification.resolve(*xeno_registry::CATALOG);
+		}
+		if self.history.len() == HISTORY_LIMIT {
+			self.history.pop_front();
+		}
+		self.history.push_back(notification.clone());
+		self.pending.push(notification);
 	}
 
 	pub(crate) fn take_pending(&mut self) -> Vec<Notification> {
-		self.pending.drain(..).collect()
+		self.pending.drain()
+	}
+
+	/// Takes the pending notifications to display, dropping those below
+	/// `min_severity` (see [`Level::min_severity`]) and all of them while
+	/// do-not-disturb is on.
+	///
+	/// [`Level::min_severity`]: xeno_registry::notifications::Level::min_severity
+	pub(crate) fn take_pending_render_items(&mut self, min_severity: Option<u8>) -> Vec<NotificationRenderItem> {
+		let pending = self.take_pending();
+		let Some(min_severity) = min_severity.filter(|_| !self.do_not_disturb) else {
+			return Vec::new();
+		};
+		pending
+			.into_iter()
+			.filter(|notification| notification.level().severity() >= min_severity)
+			.map(NotificationRenderItem::from)
+			.collect()
+	}
+
+	/// Returns recorded notifications, oldest first, including suppressed ones.
+	pub(crate) fn history(&self) -> impl ExactSizeIterator<Item = &Notification> {
+		self.history.iter()
+	}
+
+	/// Flips do-not-disturb and returns whether it is now on.
+	pub(crate) fn toggle_do_not_disturb(&mut self) -> bool {
+		self.do_not_disturb = !self.do_not_disturb;
+		self.do_not_disturb
 	}
 
-	pub(crate) fn take_pending_render_items(&mut self) -> Vec<NotificationRenderItem> {
-		self.take_pending().into_iter().map(NotificationRenderItem::from).collect()
+	pub(crate) fn do_not_disturb(&self) -> bool {
+		self.do_not_disturb
 	}
 
 	pub(crate) fn clear_epoch(&self) -> u64 {
@@ -12,9 +12,104 @@ fn take_pending_render_items_maps_level_and_auto_dismiss() {
 		"warning",
 	));
 
-	let items = center.take_pending_render_items();
+	let items = center.take_pending_render_items(Some(0));
 	assert_eq!(items.len(), 1);
 	assert_eq!(items[0].message, "warning");
 	assert_eq!(items[0].level, NotificationRenderLevel::Warn);
 	assert_eq!(items[0].auto_dismiss, NotificationRenderAutoDismiss::After(Duration::from_secs(2)));
 }
+
+#[test]
+fn push_replaces_pending_notification_of_the_same_group() {
+	let mut center = NotificationCenter::new();
+	center.push(Notification::new_pending("test.progress", "indexing 10%").with_group_key("lsp"));
+	center.push(Notification::new_pending("test.other", "saved"));
+	center.push(Notification::new_pending("test.progress", "indexing 50%").with_group_key("lsp"));
+	center.push(Notification::new_pending("test.progress", "building").with_group_key("cargo"));
+
+	let pending: Vec<_> = center.take_pending().into_iter().map(|n| (n.message, n.group_key)).collect();
+	assert_eq!(
+		pending,
+		[
+			("indexing 50%".to_string(), Some("lsp".to_string())),
+			("saved".to_string(), None),
+			("building".to_string(), Some("cargo".to_string())),
+		]
+	);
+}
+
+#[test]
+fn push_drops_repeats_but_keeps_them_in_history() {
+	let mut center = NotificationCenter::new();
+	center.push(Notification::new_pending("test.saved", "saved"));
+	center.push(Notification::new_pending("test.saved", "saved"));
+
+	assert_eq!(center.take_pending().len(), 1);
+	assert_eq!(center.history().count(), 2);
+}
+
+fn leveled(level: xeno_registry::notifications::Level, message: &str) -> Notification {
+	Notification::new("test.leveled", level, xeno_registry::notifications::AutoDismiss::DEFAULT, message)
+}
+
+#[test]
+fn take_pending_render_items_drops_levels_below_the_threshold() {
+	use xeno_registry::notifications::Level;
+
+	let mut center = NotificationCenter::new();
+	for (level, message) in [
+		(Level::Debug, "hint"),
+		(Level::Success, "done"),
+		(Level::Warn, "careful"),
+		(Level::Error, "broken"),
+	] {
+		center.push(leveled(level, message));
+	}
+
+	let shown: Vec<_> = center
+		.take_pending_render_items(Level::min_severity("warning"))
+		.into_iter()
+		.map(|item| item.message)
+		.collect();
+	assert_eq!(shown, ["careful", "broken"]);
+	assert_eq!(center.history().count(), 4, "filtered notifications stay in history");
+
+	center.push(leveled(Level::Error, "broken again"));
+	assert!(center.take_pending_render_items(Level::min_severity("off")).is_empty());
+	assert_eq!(center.history().count(), 5);
+}
+
+#[test]
+fn do_not_disturb_suppresses_every_level_until_toggled_off() {
+	use xeno_registry::notifications::Level;
+
+	let mut center = NotificationCenter::new();
+	assert!(center.toggle_do_not_disturb());
+	center.push(leveled(Level::Error, "hidden"));
+	assert!(center.take_pending_render_items(Level::min_severity("hint")).is_empty());
+
+	assert!(!center.toggle_do_not_disturb());
+	center.push(leveled(Level::Error, "shown"));
+	let items = center.take_pending_render_items(Level::min_severity("hint"));
+	assert_eq!(items.len(), 1);
+	assert_eq!(items[0].message, "shown");
+	assert_eq!(center.history().map(|n| n.message.as_str()).collect::<Vec<_>>(), ["hidden", "shown"]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn min_level_option_applies_at_display_time() {
+	use xeno_registry::notifications::Level;
+	use xeno_registry::options::OptionValue;
+
+	let mut editor = crate::Editor::new_scratch();
+	editor.take_notification_render_items();
+	editor.show_notification(leveled(Level::Info, "queued before the change"));
+	editor
+		.config_mut()
+		.global_options
+		.set_by_key(&xeno_registry::OPTIONS, "notifications-min-level", OptionValue::String("error".into()))
+		.expect("valid level");
+
+	assert!(editor.take_notification_render_items().is_empty(), "already queued info is filtered too");
+	assert!(editor.notification_history().any(|n| n.message == "queued before the change"));
+}
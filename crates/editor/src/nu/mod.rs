@@ -60,11 +60,28 @@ impl NuRuntime {
 		})
 	}
 
+	/// Reload `xeno.nu` from this runtime's config directory, reusing the
+	/// current parse when the script is unchanged.
+	pub fn reload(&self) -> Result<Self, String> {
+		let program = self.program.reload_macro_from_dir(&self.config_dir).map_err(|error| error.to_string())?;
+		let script_path = program.script_path().to_path_buf();
+		Ok(Self {
+			config_dir: self.config_dir.clone(),
+			script_path,
+			program,
+		})
+	}
+
 	/// Returns the loaded script path.
 	pub fn script_path(&self) -> &Path {
 		&self.script_path
 	}
 
+	/// Returns the config directory the script was loaded from.
+	pub fn config_dir(&self) -> &Path {
+		&self.config_dir
+	}
+
 	/// Run a function in `xeno.nu` and return its raw Nu value.
 	pub fn run(&self, fn_name: &str, args: &[String]) -> Result<Value, String> {
 		self.run_internal(fn_name, args, &[]).map_err(map_run_error)
@@ -83,8 +83,8 @@ impl OverlayController for CommandPaletteOverlay {
 		if !input.trim().is_empty() {
 			let mut chars: Vec<char> = input.chars().collect();
 			let mut tokens = Self::tokenize(&chars);
-			if let Some(name_tok) = tokens.first() {
-				let typed_name: String = chars[name_tok.content_start..name_tok.content_end].iter().collect();
+			if !tokens.is_empty() {
+				let (mut range, mut name_index, typed_name) = Self::split_command_range(&chars, &tokens);
 				let cursor = Self::char_count(&input);
 				let token = Self::token_context(&input, cursor);
 				let selected_item = Self::selected_completion_item(ctx);
@@ -95,8 +95,9 @@ impl OverlayController for CommandPaletteOverlay {
 					input = session.input_text(ctx).trim_end_matches('\n').to_string();
 					chars = input.chars().collect();
 					tokens = Self::tokenize(&chars);
-					if let Some(updated_name_tok) = tokens.first() {
-						let updated_typed_name: String = chars[updated_name_tok.content_start..updated_name_tok.content_end].iter().collect();
+					if !tokens.is_empty() {
+						let updated_typed_name;
+						(range, name_index, updated_typed_name) = Self::split_command_range(&chars, &tokens);
 						let updated_token = Self::token_context(&input, Self::char_count(&input));
 						let updated_selected = Self::selected_completion_item(ctx);
 						command_name = Self::resolve_command_name_for_commit(&updated_typed_name, updated_token.token_index, updated_selected.as_ref());
@@ -105,16 +106,19 @@ impl OverlayController for CommandPaletteOverlay {
 
 				let args: Vec<String> = tokens
 					.iter()
-					.skip(1)
+					.skip(name_index + 1)
 					.map(|tok| chars[tok.content_start..tok.content_end].iter().collect())
 					.collect();
+				let invoked_name = |name: &str| range.map_or_else(|| name.to_string(), |range| format!("{range}{name}"));
 
 				if let Some(cmd) = crate::commands::find_editor_command(&command_name) {
-					ctx.queue_invocation(xeno_registry::actions::DeferredInvocationRequest::editor_command(cmd.name.to_string(), args));
+					ctx.queue_invocation(xeno_registry::actions::DeferredInvocationRequest::editor_command(invoked_name(cmd.name), args));
 					ctx.record_command_usage(cmd.name);
+					ctx.record_command_line(input.trim());
 				} else if let Some(cmd) = xeno_registry::commands::find_command(&command_name) {
-					ctx.queue_invocation(xeno_registry::actions::DeferredInvocationRequest::command(cmd.name_str().to_string(), args));
+					ctx.queue_invocation(xeno_registry::actions::DeferredInvocationRequest::command(invoked_name(cmd.name_str()), args));
 					ctx.record_command_usage(cmd.name_str());
+					ctx.record_command_line(input.trim());
 				} else {
 					ctx.notify(keys::unknown_command(&command_name));
 				}
@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use std::pin::Pin;
 
 use xeno_primitives::{Key, KeyCode, Selection};
-use xeno_registry::commands::{COMMANDS, PaletteArgKind, PaletteCommitPolicy};
+use xeno_registry::commands::{COMMANDS, CommandRange, PaletteArgKind, PaletteCommitPolicy, split_range};
 use xeno_registry::notifications::keys;
 use xeno_registry::options::{OPTIONS, OptionType, OptionValue, option_keys as opt_keys};
 use xeno_registry::snippets::SNIPPETS;
@@ -62,7 +62,7 @@ impl CommandArgCompletion {
 			PaletteArgKind::OptionValue => Self::OptionValue,
 			PaletteArgKind::BufferRef => Self::Buffer,
 			PaletteArgKind::CommandName => Self::CommandName,
-			PaletteArgKind::FreeText => Self::FreeText,
+			PaletteArgKind::Int | PaletteArgKind::FreeText => Self::FreeText,
 		}
 	}
 
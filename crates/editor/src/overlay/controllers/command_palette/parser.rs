@@ -31,6 +31,21 @@ impl CommandPaletteOverlay {
 		crate::overlay::picker_engine::parser::tokenize(chars)
 	}
 
+	/// Splits an ex range (`%`, `10,20`, ...) off the command word.
+	///
+	/// Returns the range, the index of the token naming the command, and the
+	/// name. A bare range token (`10,20 indent`) takes its name from the next
+	/// token; malformed ranges are left in the name so the lookup fails.
+	pub(super) fn split_command_range(chars: &[char], tokens: &[Tok]) -> (Option<CommandRange>, usize, String) {
+		let text = |tok: &Tok| chars[tok.content_start..tok.content_end].iter().collect::<String>();
+		let word = tokens.first().map(text).unwrap_or_default();
+		match split_range(&word) {
+			Ok((Some(range), "")) if tokens.len() > 1 => (Some(range), 1, text(&tokens[1])),
+			Ok((Some(range), rest)) => (Some(range), 0, rest.to_string()),
+			_ => (None, 0, word),
+		}
+	}
+
 	pub(super) fn token_context(input: &str, cursor: usize) -> TokenCtx {
 		let chars: Vec<char> = input.chars().collect();
 		let len = chars.len();
@@ -1,5 +1,11 @@
 use super::*;
 
+/// Right-column badge marking deprecated commands.
+const DEPRECATED_BADGE: &str = "⚠ deprecated";
+
+/// Maximum history matches offered below command completions.
+const MAX_HISTORY_ITEMS: usize = 10;
+
 impl CommandPaletteOverlay {
 	pub(super) fn build_command_items(query: &str, usage: &crate::completion::CommandUsageSnapshot) -> Vec<CompletionItem> {
 		let query = query.trim();
@@ -41,11 +47,19 @@ impl CommandPaletteOverlay {
 					return None;
 				}
 
-				let right = cmd
-					.keys_resolved()
-					.iter()
-					.find(|alias| **alias != name && alias.len() <= 8)
-					.map(|alias| alias.to_string());
+				let deprecated = cmd.deprecated_resolved();
+				let right = if deprecated.is_some() {
+					Some(DEPRECATED_BADGE.to_string())
+				} else {
+					cmd.keys_resolved()
+						.iter()
+						.find(|alias| **alias != name && alias.len() <= 8)
+						.map(|alias| alias.to_string())
+				};
+				let detail = match deprecated {
+					Some(deprecated) => Self::deprecated_detail(description, deprecated),
+					None => description.to_string(),
+				};
 
 				let count = usage.count(name);
 				let frequency_bonus = if count == 0 { 0 } else { (31 - (count + 1).leading_zeros()) as i32 * 40 };
@@ -61,7 +75,7 @@ impl CommandPaletteOverlay {
 					CompletionItem {
 						label: name.to_string(),
 						insert_text: name.to_string(),
-						detail: Some(description.to_string()),
+						detail: Some(detail),
 						filter_text: None,
 						kind: CompletionKind::Command,
 						match_indices,
@@ -431,28 +445,40 @@ impl CommandPaletteOverlay {
 		scored.into_iter().map(|(_, item)| item).collect()
 	}
 
+	/// Appends the deprecation version and replacement hint to a command description.
+	pub(super) fn deprecated_detail(description: &str, deprecated: xeno_registry::Deprecation<&str>) -> String {
+		match deprecated.replacement {
+			Some(replacement) => format!("{description} (deprecated since {}; use '{replacement}')", deprecated.since),
+			None => format!("{description} (deprecated since {})", deprecated.since),
+		}
+	}
+
 	pub(super) fn build_option_value_items(query: &str, option_key: Option<&str>) -> Vec<CompletionItem> {
-		let values: Vec<&str> = if let Some(key) = option_key
+		const BOOL_VALUES: [&str; 4] = ["true", "false", "on", "off"];
+
+		let values: Vec<String> = if let Some(key) = option_key
 			&& let Some(opt) = xeno_registry::options::find(key)
 		{
 			match opt.value_type {
-				OptionType::Bool => vec!["true", "false", "on", "off"],
-				OptionType::Int => Vec::new(),
-				OptionType::String => Vec::new(),
+				OptionType::Bool => BOOL_VALUES.map(String::from).to_vec(),
+				OptionType::Int | OptionType::Float => Vec::new(),
+				OptionType::String => xeno_registry::options::choices(&opt)
+					.map(|choices| choices.into_iter().map(String::from).collect())
+					.unwrap_or_default(),
 			}
 		} else {
-			vec!["true", "false", "on", "off"]
+			BOOL_VALUES.map(String::from).to_vec()
 		};
 
 		let query = query.trim();
 		let mut scored = Vec::new();
 		for value in values {
-			if let Some((score, _, indices)) = crate::completion::frizbee_match(query, value) {
+			if let Some((score, _, indices)) = crate::completion::frizbee_match(query, &value) {
 				scored.push((
 					score as i32,
 					CompletionItem {
-						label: value.to_string(),
-						insert_text: value.to_string(),
+						label: value.clone(),
+						insert_text: value,
 						detail: Some("value".to_string()),
 						filter_text: None,
 						kind: CompletionKind::Command,
@@ -468,6 +494,31 @@ impl CommandPaletteOverlay {
 		scored.into_iter().map(|(_, item)| item).collect()
 	}
 
+	/// Builds full command lines from history that extend a typed prefix.
+	///
+	/// Listed after command matches so history never displaces the
+	/// auto-selected command.
+	pub(super) fn build_history_items(query: &str, history: &xeno_registry::commands::CommandHistory) -> Vec<CompletionItem> {
+		if query.trim().is_empty() {
+			return Vec::new();
+		}
+		let prefix_len = query.chars().count();
+		history
+			.search_prefix(query)
+			.take(MAX_HISTORY_ITEMS)
+			.map(|entry| CompletionItem {
+				label: entry.to_string(),
+				insert_text: entry.to_string(),
+				detail: None,
+				filter_text: None,
+				kind: CompletionKind::Command,
+				match_indices: Some((0..prefix_len).collect()),
+				right: Some("history".to_string()),
+				file: None,
+			})
+			.collect()
+	}
+
 	pub(super) fn build_items_for_token(
 		&mut self,
 		token: &TokenCtx,
@@ -477,7 +528,9 @@ impl CommandPaletteOverlay {
 	) -> Vec<CompletionItem> {
 		if token.token_index == 0 {
 			let mut provider = FnPickerProvider::new(|query: &str| Self::build_command_items(query, usage));
-			return provider.candidates(&token.query);
+			let mut items = provider.candidates(&token.query);
+			items.extend(Self::build_history_items(&token.query, ctx.command_history()));
+			return items;
 		}
 
 		match Self::command_arg_completion(&token.cmd, token.token_index) {
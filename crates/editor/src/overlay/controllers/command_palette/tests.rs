@@ -149,3 +149,64 @@ fn commit_applies_selected_theme_argument_when_missing() {
 		Some(&selected)
 	));
 }
+
+#[test]
+fn option_value_items_offer_declared_choices() {
+	use xeno_registry::options::{OPTIONS, OptionDef, OptionDefault, OptionInput, OptionScope, OptionType, OptionValue, RegistryMetaStatic, RegistrySource};
+
+	static CHOICE_OPTION: OptionDef = OptionDef {
+		meta: RegistryMetaStatic {
+			id: "xeno-editor::palette_test_line_numbers",
+			name: "palette_test_line_numbers",
+			keys: &[],
+			categories: &[],
+			description: "choice option for palette completion tests",
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		key: "palette-test-line-numbers",
+		value_type: OptionType::String,
+		default: OptionDefault::Value(OptionValue::String(String::new())),
+		scope: OptionScope::Buffer,
+		validator: None,
+		choices: Some(&["absolute", "relative", "none"]),
+	};
+	OPTIONS.replace(std::sync::Arc::new(OptionInput::Static(CHOICE_OPTION.clone())));
+
+	let labels = |query: &str, key: &str| {
+		CommandPaletteOverlay::build_option_value_items(query, Some(key))
+			.into_iter()
+			.map(|item| item.label)
+			.collect::<Vec<_>>()
+	};
+
+	let mut all = labels("", "palette-test-line-numbers");
+	all.sort();
+	assert_eq!(all, ["absolute", "none", "relative"]);
+	assert_eq!(labels("rel", "palette-test-line-numbers").first().map(String::as_str), Some("relative"));
+	assert!(labels("", "theme").is_empty(), "free-form string options offer no values");
+}
+
+#[test]
+fn deprecated_detail_names_version_and_replacement() {
+	let with_replacement = xeno_registry::Deprecation::new("0.4.0", Some("write"));
+	assert_eq!(
+		CommandPaletteOverlay::deprecated_detail("Save buffer", with_replacement),
+		"Save buffer (deprecated since 0.4.0; use 'write')"
+	);
+	let removed = xeno_registry::Deprecation::new("0.5.0", None);
+	assert_eq!(CommandPaletteOverlay::deprecated_detail("Old", removed), "Old (deprecated since 0.5.0)");
+}
+
+#[test]
+fn history_items_extend_typed_prefix() {
+	let mut history = xeno_registry::commands::CommandHistory::default();
+	history.push("set wrap");
+	history.push("write");
+	let items = CommandPaletteOverlay::build_history_items("se", &history);
+	assert_eq!(items.iter().map(|item| item.label.as_str()).collect::<Vec<_>>(), ["set wrap"]);
+	assert_eq!(items[0].right.as_deref(), Some("history"));
+	assert!(CommandPaletteOverlay::build_history_items("", &history).is_empty());
+}
@@ -0,0 +1,96 @@
+//! Yes/no prompt for commands that ask [`CommandContext::confirm`].
+//!
+//! A command future holds the editor borrow, so it cannot wait for keystrokes.
+//! Instead the first run declines and records the prompt; the invocation path
+//! then opens this overlay, and answering `y` grants the prompt and re-queues
+//! the same command, whose second `confirm` call accepts.
+//!
+//! [`CommandContext::confirm`]: xeno_registry::commands::CommandContext::confirm
+
+use std::future::Future;
+use std::pin::Pin;
+
+use xeno_registry::actions::DeferredInvocationRequest;
+
+use crate::overlay::{CloseReason, OverlayContext, OverlayController, OverlaySession, OverlayUiSpec, RectPolicy};
+use crate::window::GutterSelector;
+
+/// Confirmation prompts in flight between a command run and its re-dispatch.
+#[derive(Debug, Default)]
+pub struct ConfirmationState {
+	/// Prompt a command declined on while waiting for the user's answer.
+	pub pending: Option<String>,
+	/// Prompt the user accepted; the next matching `confirm` call consumes it.
+	pub granted: Option<String>,
+}
+
+pub struct ConfirmOverlay {
+	prompt: String,
+	request: Option<DeferredInvocationRequest>,
+}
+
+impl ConfirmOverlay {
+	pub fn new(prompt: String, request: DeferredInvocationRequest) -> Self {
+		Self {
+			prompt,
+			request: Some(request),
+		}
+	}
+}
+
+/// Returns whether `answer` accepts the prompt; anything but `y`/`yes` declines.
+fn is_accept(answer: &str) -> bool {
+	matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+impl OverlayController for ConfirmOverlay {
+	fn name(&self) -> &'static str {
+		"Confirm"
+	}
+
+	fn ui_spec(&self, _ctx: &dyn OverlayContext) -> OverlayUiSpec {
+		OverlayUiSpec {
+			title: Some(format!("{} [y/N]", self.prompt)),
+			gutter: GutterSelector::Prompt('?'),
+			rect: RectPolicy::TopCenter {
+				width_percent: 100,
+				max_width: u16::MAX,
+				min_width: 1,
+				y_frac: (0, 1),
+				height: 1,
+			},
+			style: crate::overlay::docked_prompt_style(),
+			windows: vec![],
+		}
+	}
+
+	fn on_open(&mut self, _ctx: &mut dyn OverlayContext, _session: &mut OverlaySession) {}
+
+	fn on_input_changed(&mut self, _ctx: &mut dyn OverlayContext, _session: &mut OverlaySession, _text: &str) {}
+
+	fn on_commit<'a>(&'a mut self, ctx: &'a mut dyn OverlayContext, session: &'a mut OverlaySession) -> Pin<Box<dyn Future<Output = ()> + 'a>> {
+		if is_accept(&session.input_text(ctx))
+			&& let Some(request) = self.request.take()
+		{
+			ctx.grant_confirmation(self.prompt.clone());
+			ctx.queue_invocation(request);
+		}
+		Box::pin(async {})
+	}
+
+	fn on_close(&mut self, _ctx: &mut dyn OverlayContext, _session: &mut OverlaySession, _reason: CloseReason) {}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::is_accept;
+
+	#[test]
+	fn only_yes_answers_accept() {
+		assert!(is_accept("y"));
+		assert!(is_accept(" Yes\n"));
+		assert!(!is_accept(""));
+		assert!(!is_accept("n"));
+		assert!(!is_accept("yep"));
+	}
+}
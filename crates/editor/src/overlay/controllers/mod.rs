@@ -1,9 +1,10 @@
 //! Built-in overlay controllers and layers.
 //!
-//! Collects modal interaction controllers (command palette, file picker,
-//! search, rename, workspace search) and passive overlay layers.
+//! Collects modal interaction controllers (command palette, confirm, file
+//! picker, search, rename, workspace search) and passive overlay layers.
 
 pub mod command_palette;
+pub mod confirm;
 pub mod file_picker;
 pub mod info_popup;
 pub mod rename;
@@ -11,6 +12,7 @@ pub mod search;
 pub mod workspace_search;
 
 pub use command_palette::CommandPaletteOverlay;
+pub use confirm::{ConfirmOverlay, ConfirmationState};
 pub use file_picker::FilePickerOverlay;
 pub use info_popup::InfoPopupLayer;
 pub use rename::RenameOverlay;
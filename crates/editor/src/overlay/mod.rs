@@ -249,6 +249,8 @@ pub trait OverlayContext {
 	fn request_redraw(&mut self);
 	/// Queues a deferred invocation request.
 	fn queue_invocation(&mut self, request: xeno_registry::actions::DeferredInvocationRequest);
+	/// Lets the next command `confirm` call with `prompt` accept without asking.
+	fn grant_confirmation(&mut self, prompt: String);
 	/// Returns the async message sender for background results.
 	#[cfg(feature = "lsp")]
 	fn msg_tx(&self) -> crate::msg::MsgSender;
@@ -264,6 +266,10 @@ pub trait OverlayContext {
 	fn record_command_usage(&mut self, canonical: &str);
 	/// Returns a snapshot of command usage state.
 	fn command_usage_snapshot(&self) -> crate::completion::CommandUsageSnapshot;
+	/// Records a committed command line in the command history.
+	fn record_command_line(&mut self, line: &str);
+	/// Returns the command-line history.
+	fn command_history(&self) -> &xeno_registry::commands::CommandHistory;
 	/// Returns filesystem indexing/search service state.
 	fn filesystem(&self) -> &crate::filesystem::FsService;
 	/// Returns mutable filesystem indexing/search service state.
@@ -366,6 +372,10 @@ impl OverlayContext for crate::Editor {
 		self.enqueue_runtime_invocation_request(request, crate::runtime::work_queue::RuntimeWorkSource::Overlay);
 	}
 
+	fn grant_confirmation(&mut self, prompt: String) {
+		self.state.ui.confirmation.granted = Some(prompt);
+	}
+
 	fn finalize_buffer_removal(&mut self, view: ViewId) {
 		self.finalize_buffer_removal(view);
 	}
@@ -396,6 +406,14 @@ impl OverlayContext for crate::Editor {
 		self.state.telemetry.command_usage.snapshot()
 	}
 
+	fn record_command_line(&mut self, line: &str) {
+		self.push_command_history(line);
+	}
+
+	fn command_history(&self) -> &xeno_registry::commands::CommandHistory {
+		&self.state.telemetry.command_history
+	}
+
 	fn filesystem(&self) -> &crate::filesystem::FsService {
 		&self.state.integration.filesystem
 	}
@@ -18,6 +18,14 @@ pub fn get_data_dir() -> Option<PathBuf> {
 	dirs::data_dir().map(|p| p.join(APP_DIR))
 }
 
+/// Returns the platform-specific state directory for xeno.
+///
+/// Uses `$XDG_STATE_HOME/xeno` (~/.local/state/xeno on Linux), falling back to
+/// the data directory on platforms without a state directory.
+pub fn get_state_dir() -> Option<PathBuf> {
+	dirs::state_dir().or_else(dirs::data_dir).map(|p| p.join(APP_DIR))
+}
+
 /// Returns the platform-specific cache directory for xeno.
 ///
 /// Uses XDG base directories: `$XDG_CACHE_HOME/xeno` (~/.cache/xeno on Linux).
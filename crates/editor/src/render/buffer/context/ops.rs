@@ -2,7 +2,7 @@ use ropey::Rope;
 use tracing::trace;
 use xeno_language::{HighlightSpan, LanguageId};
 use xeno_primitives::{Mode, Modifier, Style, UnderlineStyle, visible_line_count};
-use xeno_registry::gutter::GutterAnnotations;
+use xeno_registry::gutter::{GutterAnnotations, LineDiff, compute_line_diff};
 
 use super::super::diff::{DiffLineNumbers, compute_diff_line_numbers, diff_line_bg};
 use super::super::gutter::GutterLayout;
@@ -247,19 +247,22 @@ impl<'a> BufferRenderContext<'a> {
 	/// Orchestrates the full rendering pipeline for a single buffer viewport.
 	pub fn render_buffer_with_gutter(&self, p: RenderBufferParams<'_>) -> RenderResult {
 		// Snapshot document state. This is the only place we touch the document lock.
-		let (doc_id, doc_content, doc_version, total_lines, language_id, file_type, path) = p.buffer.with_doc(|doc: &Document| {
-			let content = doc.content().clone();
-			let total_lines = content.len_lines();
-			(
-				doc.id,
-				content,
-				doc.version(),
-				total_lines,
-				doc.language_id(),
-				doc.file_type().map(String::from),
-				doc.path().cloned(),
-			)
-		});
+		let (doc_id, doc_content, doc_version, total_lines, language_id, file_type, path, diff_base, diff_base_version) =
+			p.buffer.with_doc(|doc: &Document| {
+				let content = doc.content().clone();
+				let total_lines = content.len_lines();
+				(
+					doc.id,
+					content,
+					doc.version(),
+					total_lines,
+					doc.language_id(),
+					doc.file_type().map(String::from),
+					doc.path().cloned(),
+					doc.diff_base().clone(),
+					doc.diff_base_version(),
+				)
+			});
 
 		let is_diff_file = file_type.as_deref().is_some_and(|ft| ft == "diff");
 
@@ -305,6 +308,12 @@ impl<'a> BufferRenderContext<'a> {
 			None
 		};
 
+		let line_diff: Option<std::sync::Arc<LineDiff>> = layout.gutter_layout.has_column("git_signs").then(|| {
+			p.cache
+				.line_diff
+				.get_or_build(doc_id, doc_version, diff_base_version, || compute_line_diff(&diff_base, &doc_content))
+		});
+
 		let mode_color = self.mode_color(p.buffer.mode());
 		let base_bg = self.theme.colors.ui.bg;
 
@@ -343,11 +352,14 @@ impl<'a> BufferRenderContext<'a> {
 			let line_idx = line.as_ref().map(|l: &LineSlice| l.line_idx).unwrap_or(total_lines);
 
 			let diff_nums = diff_line_numbers.as_ref().and_then(|nums| nums.get(line_idx));
+			let diagnostics = self.diagnostics.and_then(|d| d.get(&line_idx).copied()).unwrap_or_default();
 			let line_annotations = GutterAnnotations {
-				diagnostic_severity: self.diagnostics.and_then(|d| d.get(&line_idx).copied()).unwrap_or(0),
+				diagnostic_severity: diagnostics.highest_severity(),
+				diagnostics,
 				sign: None,
 				diff_old_line: diff_nums.and_then(|dn: &DiffLineNumbers| dn.old),
 				diff_new_line: diff_nums.and_then(|dn: &DiffLineNumbers| dn.new),
+				diff: line_diff.as_deref(),
 			};
 
 			let line_diff_bg = if is_diff_file {
@@ -8,15 +8,17 @@
 
 use std::collections::HashMap;
 
-/// Map from line number (0-indexed) to diagnostic severity (gutter format).
+use xeno_registry::gutter::DiagnosticCounts;
+
+/// Map from line number (0-indexed) to the diagnostics on that line, counted by severity.
 ///
-/// Severity values match `GutterAnnotations::diagnostic_severity`:
+/// Severity values use the gutter format of `GutterAnnotations::diagnostic_severity`:
 /// * 4 = Error
 /// * 3 = Warning
 /// * 2 = Information
 /// * 1 = Hint
 /// * 0 = None
-pub type DiagnosticLineMap = HashMap<usize, u8>;
+pub type DiagnosticLineMap = HashMap<usize, DiagnosticCounts>;
 
 /// A diagnostic span covering a character range within a single line.
 #[derive(Debug, Clone, Copy)]
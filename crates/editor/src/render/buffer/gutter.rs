@@ -115,6 +115,11 @@ impl GutterLayout {
 		}
 	}
 
+	/// Returns `true` if the layout renders the registry gutter `name`.
+	pub fn has_column(&self, name: &str) -> bool {
+		matches!(&self.kind, GutterLayoutKind::Columns(columns) if columns.iter().any(|(_, def)| def.name_str() == name))
+	}
+
 	fn column_total_width(width: u16) -> u16 {
 		if width > 0 { width + 1 } else { 0 }
 	}
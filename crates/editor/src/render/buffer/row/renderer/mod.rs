@@ -56,7 +56,7 @@ pub struct RowRenderInput<'a> {
 	pub highlight: &'a HighlightIndex,
 	pub overlays: &'a OverlayIndex,
 
-	pub line_annotations: GutterAnnotations,
+	pub line_annotations: GutterAnnotations<'a>,
 }
 
 pub struct TextRowRenderer;
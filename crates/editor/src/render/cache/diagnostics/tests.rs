@@ -1,9 +1,15 @@
+use xeno_registry::gutter::DiagnosticCounts;
+
 use super::*;
 
+fn counts(severity: u8) -> DiagnosticCounts {
+	[severity].into_iter().collect()
+}
+
 fn build_test_maps() -> (DiagnosticLineMap, DiagnosticRangeMap) {
 	let mut line_map = DiagnosticLineMap::new();
-	line_map.insert(0, 4); // Error on line 0
-	line_map.insert(5, 3); // Warning on line 5
+	line_map.insert(0, counts(4)); // Error on line 0
+	line_map.insert(5, counts(3)); // Warning on line 5
 
 	let mut range_map = DiagnosticRangeMap::new();
 	range_map.insert(
@@ -26,12 +32,12 @@ fn test_cache_get_or_build_caches_entry() {
 
 	// First call should build
 	let entry1 = cache.get_or_build(doc_id, epoch, build_test_maps);
-	assert_eq!(entry1.line_map.get(&0), Some(&4));
+	assert_eq!(entry1.line_map.get(&0).map(DiagnosticCounts::highest_severity), Some(4));
 	assert_eq!(cache.len(), 1);
 
 	// Second call should return cached entry
 	let entry2 = cache.get_or_build(doc_id, epoch, || panic!("should not be called"));
-	assert_eq!(entry2.line_map.get(&0), Some(&4));
+	assert_eq!(entry2.line_map.get(&0).map(DiagnosticCounts::highest_severity), Some(4));
 	assert_eq!(cache.len(), 1);
 }
 
@@ -42,15 +48,15 @@ fn test_cache_different_epochs_create_separate_entries() {
 
 	// Build for epoch 1
 	let entry1 = cache.get_or_build(doc_id, 1, build_test_maps);
-	assert_eq!(entry1.line_map.get(&0), Some(&4));
+	assert_eq!(entry1.line_map.get(&0).map(DiagnosticCounts::highest_severity), Some(4));
 
 	// Build for epoch 2 (different maps)
 	let entry2 = cache.get_or_build(doc_id, 2, || {
 		let mut line_map = DiagnosticLineMap::new();
-		line_map.insert(10, 2); // Different line
+		line_map.insert(10, counts(2)); // Different line
 		(line_map, DiagnosticRangeMap::new())
 	});
-	assert_eq!(entry2.line_map.get(&10), Some(&2));
+	assert_eq!(entry2.line_map.get(&10).map(DiagnosticCounts::highest_severity), Some(2));
 	assert!(entry2.line_map.get(&0).is_none());
 
 	// Should have 2 entries
@@ -129,7 +135,7 @@ fn test_cache_get_returns_some_for_existing() {
 
 	let entry = cache.get(DocumentId(1), 42);
 	assert!(entry.is_some());
-	assert_eq!(entry.unwrap().line_map.get(&0), Some(&4));
+	assert_eq!(entry.unwrap().line_map.get(&0).map(DiagnosticCounts::highest_severity), Some(4));
 }
 
 #[test]
@@ -0,0 +1,74 @@
+//! Cache for change-sign line diffs.
+//!
+//! Stores per-document diffs against the diff base keyed by
+//! `(DocumentId, doc_version, diff_base_version)` so the diff is only
+//! recomputed after an edit, load or save.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use xeno_primitives::DocumentId;
+use xeno_registry::gutter::LineDiff;
+
+/// Cache key for line diffs.
+pub type LineDiffCacheKey = (DocumentId, u64, u64);
+
+/// Cache for line diffs against each document's diff base.
+#[derive(Debug)]
+pub struct LineDiffCache {
+	entries: HashMap<LineDiffCacheKey, Arc<LineDiff>>,
+	max_entries: usize,
+}
+
+impl LineDiffCache {
+	/// Default maximum number of cached document-version entries.
+	pub const DEFAULT_MAX_ENTRIES: usize = 16;
+
+	/// Creates a new empty cache with the default capacity.
+	pub fn new() -> Self {
+		Self {
+			entries: HashMap::new(),
+			max_entries: Self::DEFAULT_MAX_ENTRIES,
+		}
+	}
+
+	/// Returns a cached diff, or builds and stores it if missing.
+	pub fn get_or_build<F>(&mut self, doc_id: DocumentId, doc_version: u64, base_version: u64, build_fn: F) -> Arc<LineDiff>
+	where
+		F: FnOnce() -> LineDiff,
+	{
+		let key = (doc_id, doc_version, base_version);
+		if let Some(diff) = self.entries.get(&key) {
+			return Arc::clone(diff);
+		}
+
+		// Older versions of the same document are never asked for again.
+		self.entries.retain(|(id, _, _), _| *id != doc_id);
+		self.enforce_capacity();
+		let diff = Arc::new(build_fn());
+		self.entries.insert(key, Arc::clone(&diff));
+		diff
+	}
+
+	/// Invalidates all entries for a document.
+	pub fn invalidate_document(&mut self, doc_id: DocumentId) {
+		self.entries.retain(|(id, _, _), _| *id != doc_id);
+	}
+
+	fn enforce_capacity(&mut self) {
+		if self.entries.len() >= self.max_entries
+			&& let Some(key) = self.entries.keys().next().copied()
+		{
+			self.entries.remove(&key);
+		}
+	}
+}
+
+impl Default for LineDiffCache {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests;
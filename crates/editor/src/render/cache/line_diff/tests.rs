@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use xeno_primitives::{DocumentId, Rope};
+use xeno_registry::gutter::{LineChange, LineDiff, compute_line_diff};
+
+use crate::render::cache::line_diff::LineDiffCache;
+
+#[test]
+fn get_or_build_reuses_diff_until_version_or_base_changes() {
+	let mut cache = LineDiffCache::new();
+	let doc_id = DocumentId(1);
+	let (old, new) = (Rope::from("a\n"), Rope::from("a\nb\n"));
+
+	let first = cache.get_or_build(doc_id, 3, 0, || compute_line_diff(&old, &new));
+	let second = cache.get_or_build(doc_id, 3, 0, || panic!("should not rebuild"));
+	assert!(Arc::ptr_eq(&first, &second));
+	assert_eq!(first.get(1), Some(LineChange::Added));
+
+	let saved = cache.get_or_build(doc_id, 3, 3, LineDiff::default);
+	assert!(saved.is_empty(), "saving moves the base and rebuilds");
+}
+
+#[test]
+fn invalidate_document_drops_its_entries() {
+	let mut cache = LineDiffCache::new();
+	cache.get_or_build(DocumentId(1), 1, 0, LineDiff::default);
+	cache.get_or_build(DocumentId(2), 1, 0, LineDiff::default);
+
+	cache.invalidate_document(DocumentId(1));
+	let mut rebuilt = false;
+	cache.get_or_build(DocumentId(1), 1, 0, || {
+		rebuilt = true;
+		LineDiff::default()
+	});
+	assert!(rebuilt);
+	cache.get_or_build(DocumentId(2), 1, 0, || panic!("other documents stay cached"));
+}
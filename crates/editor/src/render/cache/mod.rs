@@ -4,14 +4,17 @@
 //! * Line wrapping results (per document, per wrap configuration)
 //! * Syntax highlighting spans (tiled caching)
 //! * Diagnostics maps (line_map, range_map keyed by epoch)
+//! * Change-sign line diffs against each document's diff base
 //! * Future: layout calculations
 
 mod diagnostics;
 mod diff;
+mod line_diff;
 mod wrap;
 
 use diagnostics::DiagnosticsCache;
 use diff::DiffLineNumbersCache;
+use line_diff::LineDiffCache;
 use wrap::WrapBuckets;
 use xeno_primitives::DocumentId;
 pub(crate) use xeno_syntax::HighlightSpanQuery;
@@ -32,6 +35,8 @@ pub struct RenderCache {
 	pub diagnostics: DiagnosticsCache,
 	/// Diff line-number mappings keyed by document and version.
 	pub diff_line_numbers: DiffLineNumbersCache,
+	/// Line diffs against the diff base, keyed by document and both versions.
+	pub line_diff: LineDiffCache,
 	/// Theme epoch for cache invalidation.
 	pub theme_epoch: u64,
 }
@@ -50,6 +55,7 @@ impl RenderCache {
 		self.highlight.invalidate_document(doc_id);
 		self.diagnostics.invalidate_document(doc_id);
 		self.diff_line_numbers.invalidate_document(doc_id);
+		self.line_diff.invalidate_document(doc_id);
 	}
 
 	/// Updates the theme epoch, invalidating the highlight cache if changed.
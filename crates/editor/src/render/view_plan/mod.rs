@@ -72,7 +72,7 @@ impl Editor {
 
 		let buffer = self.get_buffer(view)?;
 		let buffer_ctx = BufferRenderContext {
-			theme: &render_ctx.theme,
+			theme: self.config().theme_overrides.resolve(view, &render_ctx.theme),
 			language_loader: &self.config().language_loader,
 			syntax_manager: self.syntax_manager(),
 			diagnostics: render_ctx.lsp.diagnostics_for(view),
@@ -59,14 +59,18 @@ static ACTION_RUNTIME_EDIT: xeno_registry::actions::ActionDef = xeno_registry::a
 		id: "xeno-editor::runtime_invariant_edit_action",
 		name: "runtime_invariant_edit_action",
 		keys: &[],
+		categories: &[],
 		description: "Runtime invariant test action requiring Edit capability",
 		priority: 0,
 		source: xeno_registry::RegistrySource::Crate("xeno-editor"),
 		mutates_buffer: true,
+		deprecated: None,
 	},
 	short_desc: "Runtime invariant edit action",
 	handler: handler_runtime_edit_action,
 	bindings: &[],
+	repeatable: true,
+	keyboard_icon: None,
 };
 
 fn register_runtime_invariant_action_defs(db: &mut xeno_registry::RegistryDbBuilder) -> Result<(), xeno_registry::RegistryError> {
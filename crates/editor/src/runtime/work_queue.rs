@@ -26,6 +26,8 @@ pub enum RuntimeWorkSource {
 	NuHookDispatch,
 	/// Invocation produced by Nu scheduled macro timers.
 	NuScheduledMacro,
+	/// Exact binding committed after an ambiguous key sequence timed out.
+	KeySequenceTimeout,
 }
 
 /// Deferred invocation execution policy.
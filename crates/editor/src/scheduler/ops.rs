@@ -404,6 +404,17 @@ impl HookScheduler for WorkScheduler {
 			doc_id: None,
 		});
 	}
+
+	fn schedule_after(&mut self, delay: Duration, fut: HookBoxFuture, priority: HookPriority) {
+		HookScheduler::schedule(
+			self,
+			Box::pin(async move {
+				tokio::time::sleep(delay).await;
+				fut.await
+			}),
+			priority,
+		);
+	}
 }
 
 /// Budget for draining scheduled work completions.
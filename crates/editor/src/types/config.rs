@@ -4,8 +4,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use xeno_language::LanguageLoader;
-use xeno_registry::options::OptionStore;
-use xeno_registry::themes::Theme;
+use xeno_registry::hooks::SharedHookScheduler;
+use xeno_registry::options::{FiletypeOptions, OptionStore};
+use xeno_registry::themes::{Theme, ThemeOverrides};
 
 /// Editor configuration.
 ///
@@ -14,14 +15,23 @@ use xeno_registry::themes::Theme;
 pub struct Config {
 	/// Current theme.
 	pub theme: Theme,
+	/// Per-buffer themes that take precedence over [`Self::theme`].
+	pub theme_overrides: ThemeOverrides,
 	/// Language configuration loader (Arc-wrapped for background task cloning).
 	pub language_loader: Arc<LanguageLoader>,
 	/// Global user configuration options.
 	pub global_options: OptionStore,
+	/// Async `OptionChanged` hook futures raised by option store writes,
+	/// drained into the work scheduler.
+	pub option_hooks: SharedHookScheduler,
 	/// Per-language option overrides.
 	pub language_options: HashMap<String, OptionStore>,
+	/// Per-filetype option overrides, resolved ahead of language overrides.
+	pub filetype_options: FiletypeOptions,
 	/// Nu scripting configuration (decode limits, etc.).
 	pub nu: Option<xeno_registry::config::NuConfig>,
+	/// IDs of text objects registered from the loaded config, dropped on reload.
+	pub text_objects: Vec<String>,
 }
 
 impl Config {
@@ -37,12 +47,19 @@ impl Config {
 	/// [`ThemeMsg::ThemesReady`]: crate::msg::ThemeMsg::ThemesReady
 	pub fn new(language_loader: LanguageLoader) -> Self {
 		let bootstrap_theme = crate::bootstrap::get().unwrap_or(&xeno_registry::themes::DEFAULT_THEME);
+		let option_hooks = SharedHookScheduler::new();
+		let mut global_options = OptionStore::new();
+		global_options.emit_hooks("global", option_hooks.clone());
 		Self {
 			theme: *bootstrap_theme,
+			theme_overrides: ThemeOverrides::new(),
 			language_loader: Arc::new(language_loader),
-			global_options: OptionStore::new(),
+			global_options,
+			option_hooks,
 			language_options: HashMap::new(),
+			filetype_options: FiletypeOptions::new(),
 			nu: None,
+			text_objects: Vec::new(),
 		}
 	}
 }
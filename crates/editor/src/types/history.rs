@@ -51,4 +51,6 @@ pub struct EditorUndoGroup {
 	pub view_snapshots: HashMap<ViewId, ViewSnapshot>,
 	/// Origin of this edit group.
 	pub origin: EditOrigin,
+	/// Human-readable summary of the edit, shown by `:undo-history`.
+	pub description: Option<String>,
 }
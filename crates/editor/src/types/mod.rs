@@ -28,4 +28,4 @@ pub(crate) use invocation::adapters::{PipelineDisposition, PipelineLogContext, c
 pub use invocation::{Invocation, InvocationOutcome, InvocationPolicy, InvocationStatus, InvocationTarget};
 pub use undo_manager::{UndoHost, UndoManager};
 pub use viewport::Viewport;
-pub use workspace::{JumpLocation, Workspace, Yank};
+pub use workspace::{JumpLocation, MacroState, Registers, Workspace, Yank};
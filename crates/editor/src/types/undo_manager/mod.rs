@@ -23,7 +23,7 @@
 use std::collections::HashMap;
 
 use tracing::trace;
-use xeno_primitives::{CommitResult, EditOrigin};
+use xeno_primitives::{CommitResult, EditOrigin, UndoPolicy};
 
 use super::{EditorUndoGroup, ViewSnapshot};
 use crate::buffer::{DocumentId, ViewId};
@@ -43,10 +43,25 @@ pub struct UndoManager {
 	undo_stack: Vec<EditorUndoGroup>,
 	/// Editor-level redo grouping stack.
 	redo_stack: Vec<EditorUndoGroup>,
+	/// Open edit batch, see [`Self::begin_batch`].
+	batch: Option<UndoBatch>,
 	#[cfg(test)]
 	pub finalize_calls: usize,
 }
 
+/// Bookkeeping for an open [`UndoManager::begin_batch`] scope.
+#[derive(Debug)]
+struct UndoBatch {
+	/// Nesting depth; the batch closes when it returns to zero.
+	depth: usize,
+	/// Undo stack length when the batch opened.
+	base: usize,
+	/// A batch edit created an undo step that later edits merge into.
+	recorded: bool,
+	/// A batch edit was rejected.
+	rejected: bool,
+}
+
 /// Pre-edit state captured by [`UndoManager::prepare_edit`].
 ///
 /// Holds all information needed to finalize an edit, including affected documents
@@ -136,6 +151,19 @@ impl UndoManager {
 		self.redo_stack.last()
 	}
 
+	/// Returns the undo groups, oldest first.
+	pub fn undo_groups(&self) -> &[EditorUndoGroup] {
+		&self.undo_stack
+	}
+
+	/// Sets `description` on every undo group pushed after the stack held
+	/// `since` groups.
+	pub fn describe_groups_since(&mut self, since: usize, description: &str) {
+		for group in self.undo_stack.iter_mut().skip(since) {
+			group.description = Some(description.to_string());
+		}
+	}
+
 	/// Pushes an undo group directly and clears the redo stack.
 	///
 	/// For use by subsystems (e.g., LSP workspace edits) that manage their
@@ -185,6 +213,11 @@ impl UndoManager {
 			self.finalize_calls += 1;
 		}
 
+		if let Some(batch) = &mut self.batch {
+			batch.recorded |= result.undo_recorded;
+			batch.rejected |= !result.applied;
+		}
+
 		if result.applied {
 			self.redo_stack.clear();
 
@@ -193,11 +226,90 @@ impl UndoManager {
 					affected_docs: prep.affected_docs,
 					view_snapshots: prep.pre_views,
 					origin: prep.origin,
+					description: None,
 				});
 			}
 		}
 	}
 
+	/// Opens an edit batch; nested calls only deepen the current one.
+	///
+	/// While a batch is open, [`Self::batch_policy`] turns recorded edits into
+	/// one undo step, so the whole batch is undone at once.
+	pub fn begin_batch(&mut self) {
+		match &mut self.batch {
+			Some(batch) => batch.depth += 1,
+			None => {
+				self.batch = Some(UndoBatch {
+					depth: 1,
+					base: self.undo_stack.len(),
+					recorded: false,
+					rejected: false,
+				});
+			}
+		}
+	}
+
+	/// Marks the open batch as rejected, for edits refused before they reach
+	/// [`Self::with_edit`] (e.g. by a readonly guard). No-op outside a batch.
+	pub fn reject_batch(&mut self) {
+		if let Some(batch) = &mut self.batch {
+			batch.rejected = true;
+		}
+	}
+
+	/// Returns true while a batch opened by [`Self::begin_batch`] is open.
+	pub fn batch_open(&self) -> bool {
+		self.batch.is_some()
+	}
+
+	/// Returns true when an edit in the open batch has been rejected.
+	pub fn batch_rejected(&self) -> bool {
+		self.batch.as_ref().is_some_and(|batch| batch.rejected)
+	}
+
+	/// Returns the undo policy to use for an edit requested with `undo`.
+	///
+	/// Outside a batch this is `undo` itself. Inside one, the first recorded
+	/// edit opens a fresh group and later edits merge into it.
+	pub fn batch_policy(&self, undo: UndoPolicy) -> UndoPolicy {
+		match &self.batch {
+			None => undo,
+			Some(_) if undo == UndoPolicy::NoUndo => undo,
+			Some(batch) if batch.recorded => UndoPolicy::MergeWithCurrentGroup,
+			Some(_) => UndoPolicy::Boundary,
+		}
+	}
+
+	/// Closes one level of the batch opened by [`Self::begin_batch`].
+	///
+	/// When the outermost level closes after a rejected edit, the undo groups
+	/// pushed by the batch are reverted without touching the redo stack or
+	/// notifying, and false is returned.
+	pub fn end_batch(&mut self, host: &mut impl UndoHost) -> bool {
+		let Some(batch) = self.batch.as_mut() else {
+			return true;
+		};
+		batch.depth -= 1;
+		if batch.depth > 0 {
+			return true;
+		}
+		let (base, rejected) = (batch.base, batch.rejected);
+		self.batch = None;
+		if !rejected {
+			return true;
+		}
+
+		let base = base.min(self.undo_stack.len());
+		for group in self.undo_stack.split_off(base).into_iter().rev() {
+			if host.undo_documents(&group.affected_docs) {
+				host.restore_view_snapshots(&group.view_snapshots);
+			}
+		}
+		trace!(target: "xeno_undo_trace", undo_depth = self.undo_stack.len(), "undo_manager.batch.rolled_back");
+		false
+	}
+
 	/// Executes a closure as an undoable edit operation.
 	pub fn with_edit<H, F>(&mut self, host: &mut H, buffer_id: ViewId, origin: EditOrigin, apply: F) -> bool
 	where
@@ -256,6 +368,7 @@ impl UndoManager {
 				affected_docs: group.affected_docs,
 				view_snapshots: current_snapshots,
 				origin: group.origin,
+				description: group.description,
 			});
 			host.notify_undo();
 			trace!(
@@ -314,6 +427,7 @@ impl UndoManager {
 				affected_docs: group.affected_docs,
 				view_snapshots: current_snapshots,
 				origin: group.origin,
+				description: group.description,
 			});
 			host.notify_redo();
 			trace!(
@@ -98,3 +98,58 @@ fn with_edit_calls_finalize_on_failure() {
 	assert_eq!(manager.redo_len(), 0);
 	assert_eq!(manager.finalize_calls, 1);
 }
+
+#[test]
+fn batch_policy_opens_then_merges_one_group() {
+	let mut manager = UndoManager::new();
+	let mut host = TestHost::new();
+	let buffer_id = host.buffer_id;
+	assert_eq!(manager.batch_policy(UndoPolicy::Record), UndoPolicy::Record);
+
+	manager.begin_batch();
+	assert_eq!(manager.batch_policy(UndoPolicy::Record), UndoPolicy::Boundary);
+	manager.with_edit(&mut host, buffer_id, EditOrigin::Internal("test"), |_host| CommitResult::stub(0));
+	assert_eq!(manager.batch_policy(UndoPolicy::Record), UndoPolicy::MergeWithCurrentGroup);
+	assert_eq!(manager.batch_policy(UndoPolicy::NoUndo), UndoPolicy::NoUndo);
+	manager.with_edit(&mut host, buffer_id, EditOrigin::Internal("test"), |_host| CommitResult {
+		undo_recorded: false,
+		..CommitResult::stub(1)
+	});
+
+	assert!(manager.end_batch(&mut host));
+	assert_eq!(manager.undo_len(), 1);
+	assert_eq!(manager.batch_policy(UndoPolicy::Record), UndoPolicy::Record);
+}
+
+#[test]
+fn batch_with_rejected_edit_rolls_back() {
+	let mut manager = UndoManager::new();
+	let mut host = TestHost::new();
+	let buffer_id = host.buffer_id;
+	manager.with_edit(&mut host, buffer_id, EditOrigin::Internal("before"), |_host| CommitResult::stub(0));
+
+	manager.begin_batch();
+	manager.begin_batch();
+	manager.with_edit(&mut host, buffer_id, EditOrigin::Internal("test"), |_host| CommitResult::stub(1));
+	assert!(manager.end_batch(&mut host), "inner level does not close the batch");
+	manager.with_edit(&mut host, buffer_id, EditOrigin::Internal("test"), |_host| CommitResult::blocked(2));
+
+	assert!(!manager.end_batch(&mut host));
+	assert_eq!(manager.undo_len(), 1, "only the pre-batch group remains");
+	assert_eq!(manager.redo_len(), 0);
+}
+
+#[test]
+fn reject_batch_marks_only_an_open_batch() {
+	let mut manager = UndoManager::new();
+	let mut host = TestHost::new();
+	manager.reject_batch();
+	assert!(!manager.batch_rejected());
+
+	manager.begin_batch();
+	assert!(!manager.batch_rejected());
+	manager.reject_batch();
+	assert!(manager.batch_rejected());
+	assert!(!manager.end_batch(&mut host));
+	assert!(!manager.batch_rejected());
+}
@@ -2,7 +2,8 @@
 
 use std::collections::{HashMap, VecDeque};
 
-use xeno_primitives::{CharIdx, Key};
+use xeno_primitives::CharIdx;
+use xeno_registry::actions::RecordedInvocation;
 
 use crate::buffer::ViewId;
 
@@ -28,10 +29,31 @@ impl Yank {
 }
 
 /// Named registers for storing yanked text.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Registers {
 	/// Default yank register content.
 	pub yank: Yank,
+	/// Content of registers selected with `"<name>`.
+	pub named: HashMap<char, Yank>,
+}
+
+impl Registers {
+	/// Returns the content of `register`, or of the default register when `None`.
+	pub fn get(&self, register: Option<char>) -> Option<&Yank> {
+		match register {
+			None => Some(&self.yank),
+			Some(name) => self.named.get(&name),
+		}
+	}
+
+	/// Stores `yank` in `register`. As in Vim, a named yank also fills the
+	/// default register.
+	pub fn store(&mut self, register: Option<char>, yank: Yank) {
+		if let Some(name) = register {
+			self.named.insert(name, yank.clone());
+		}
+		self.yank = yank;
+	}
 }
 
 /// A saved position in the jump list.
@@ -91,48 +113,59 @@ impl JumpList {
 }
 
 /// State for macro recording and playback.
+///
+/// Macros hold resolved action invocations rather than keys, so replay is
+/// unaffected by keymap changes made after recording.
 #[derive(Default)]
 pub struct MacroState {
 	recording_register: Option<char>,
-	recording_keys: Vec<Key>,
-	macros: HashMap<char, Vec<Key>>,
+	recording: Vec<RecordedInvocation>,
+	macros: HashMap<char, Vec<RecordedInvocation>>,
 	last_register: Option<char>,
+	replay_depth: usize,
+	replay_aborted: bool,
 }
 
 impl MacroState {
+	/// Maximum nesting of replays, e.g. a macro that replays itself.
+	pub const MAX_REPLAY_DEPTH: usize = 8;
+
 	/// Starts recording a macro into the given register.
 	pub fn start_recording(&mut self, register: char) {
 		if self.recording_register.is_some() {
 			self.stop_recording();
 		}
 		self.recording_register = Some(register);
-		self.recording_keys.clear();
+		self.recording.clear();
 	}
 
 	/// Stops recording and saves the macro to its register.
 	pub fn stop_recording(&mut self) {
 		if let Some(register) = self.recording_register.take() {
-			let keys = std::mem::take(&mut self.recording_keys);
-			if !keys.is_empty() {
-				self.macros.insert(register, keys);
+			let invocations = std::mem::take(&mut self.recording);
+			if !invocations.is_empty() {
+				self.macros.insert(register, invocations);
 				self.last_register = Some(register);
 			}
 		}
 	}
 
-	/// Records a key event if currently recording.
-	pub fn record_key(&mut self, key: Key) {
-		if self.recording_register.is_some() {
-			self.recording_keys.push(key);
+	/// Records an invocation if currently recording.
+	///
+	/// Invocations issued by a replay are skipped; the replaying invocation
+	/// itself is what gets recorded.
+	pub fn record_invocation(&mut self, invocation: RecordedInvocation) {
+		if self.recording_register.is_some() && self.replay_depth == 0 {
+			self.recording.push(invocation);
 		}
 	}
 
 	/// Returns the macro for a register, if any.
-	pub fn get(&self, register: char) -> Option<&[Key]> {
+	pub fn get(&self, register: char) -> Option<&[RecordedInvocation]> {
 		self.macros.get(&register).map(|v| v.as_slice())
 	}
 
-	/// Returns the last used macro register.
+	/// Returns the last recorded or replayed macro register.
 	pub fn last_register(&self) -> Option<char> {
 		self.last_register
 	}
@@ -146,6 +179,38 @@ impl MacroState {
 	pub fn recording_register(&self) -> Option<char> {
 		self.recording_register
 	}
+
+	/// Marks `register` as the most recently replayed macro.
+	pub fn set_last_register(&mut self, register: char) {
+		self.last_register = Some(register);
+	}
+
+	/// Enters a replay: a macro playback or a dot-repeat.
+	///
+	/// Returns `false` without entering if replays are already nested
+	/// [`Self::MAX_REPLAY_DEPTH`] deep; the whole replay stack is then aborted.
+	pub fn begin_replay(&mut self) -> bool {
+		if self.replay_depth >= Self::MAX_REPLAY_DEPTH {
+			self.replay_aborted = true;
+			return false;
+		}
+		self.replay_depth += 1;
+		true
+	}
+
+	/// Leaves the replay entered by the matching [`Self::begin_replay`].
+	pub fn end_replay(&mut self) {
+		self.replay_depth = self.replay_depth.saturating_sub(1);
+		if self.replay_depth == 0 {
+			self.replay_aborted = false;
+		}
+	}
+
+	/// Returns true if a nested replay hit the depth limit and the replays in
+	/// progress should stop.
+	pub fn replay_aborted(&self) -> bool {
+		self.replay_aborted
+	}
 }
 
 /// Per-session key-value store for Nu script state persistence.
@@ -191,7 +256,7 @@ impl NuState {
 /// Editing session state.
 ///
 /// Groups workspace-level state that persists across buffer switches:
-/// registers, jump list, macros, and Nu script state.
+/// registers, jump list, macros, last repeatable action, and Nu script state.
 #[derive(Default)]
 pub struct Workspace {
 	/// Named registers (yank buffer, etc.).
@@ -200,6 +265,8 @@ pub struct Workspace {
 	pub jump_list: JumpList,
 	/// Macro recording and playback state.
 	pub macro_state: MacroState,
+	/// Most recent repeatable action, replayed by `repeat_last`.
+	pub last_action: Option<xeno_registry::actions::LastAction>,
 	/// Per-session Nu script state store.
 	pub nu_state: NuState,
 }
@@ -33,6 +33,14 @@ pub(crate) fn statusline_render_plan(editor: &crate::Editor) -> Vec<StatuslineRe
 	statusline::render_plan(editor)
 }
 
+/// Returns the click action of the statusline segment at `column`.
+///
+/// The plan is rebuilt from current state rather than kept from the last frame;
+/// planning is deterministic, so it matches what is (or is about to be) drawn.
+pub(crate) fn statusline_action_at(editor: &crate::Editor, column: u16) -> Option<xeno_registry::statusline::SegmentAction> {
+	statusline::action_at(&statusline::render_plan(editor), column).cloned()
+}
+
 pub(crate) fn statusline_segment_style(editor: &crate::Editor, style: StatuslineRenderStyle) -> xeno_primitives::Style {
 	statusline::segment_style(editor, style)
 }
@@ -5,7 +5,7 @@
 
 use unicode_width::UnicodeWidthStr;
 use xeno_primitives::Style;
-use xeno_registry::statusline::{SegmentPosition, SegmentStyle, StatuslineContext, render_position};
+use xeno_registry::statusline::{SegmentAction, SegmentPosition, SegmentStyle, StatuslineContext, render_position};
 
 use crate::Editor;
 
@@ -16,6 +16,7 @@ pub const STATUSLINE_ROWS: u16 = 1;
 pub struct StatuslineRenderSegment {
 	pub(crate) text: String,
 	pub(crate) style: StatuslineRenderStyle,
+	pub(crate) on_click: Option<SegmentAction>,
 }
 
 impl StatuslineRenderSegment {
@@ -56,7 +57,7 @@ impl From<SegmentStyle> for StatuslineRenderStyle {
 
 /// Resolves frontend-neutral color style for a statusline render segment.
 pub fn segment_style(editor: &Editor, style: StatuslineRenderStyle) -> Style {
-	let colors = &editor.config().theme.colors;
+	let colors = &editor.effective_theme(editor.focused_view()).colors;
 	let mode_style = colors.mode_style(&editor.mode());
 
 	match style {
@@ -86,8 +87,26 @@ fn overlay_label(editor: &Editor) -> Option<&'static str> {
 	})
 }
 
-fn make_segment(text: String, style: SegmentStyle) -> StatuslineRenderSegment {
-	StatuslineRenderSegment { text, style: style.into() }
+fn make_segment(text: String, style: SegmentStyle, on_click: Option<SegmentAction>) -> StatuslineRenderSegment {
+	StatuslineRenderSegment {
+		text,
+		style: style.into(),
+		on_click,
+	}
+}
+
+/// Returns the click action of the plan segment covering `column`.
+pub fn action_at(plan: &[StatuslineRenderSegment], column: u16) -> Option<&SegmentAction> {
+	let column = usize::from(column);
+	let mut start = 0;
+	for segment in plan {
+		let end = start + segment_width(segment);
+		if column < end {
+			return segment.on_click.as_ref();
+		}
+		start = end;
+	}
+	None
 }
 
 /// Builds data-only statusline content with shared width/alignment policy.
@@ -116,7 +135,7 @@ pub fn render_plan(editor: &Editor) -> Vec<StatuslineRenderSegment> {
 
 	let (sync_role_str, sync_status_str): (Option<&str>, Option<&str>) = (None, None);
 
-	let ctx = StatuslineContext {
+	let mut ctx = StatuslineContext {
 		mode_name,
 		path: path_str.as_deref(),
 		file_icon: file_icon.as_str(),
@@ -132,13 +151,18 @@ pub fn render_plan(editor: &Editor) -> Vec<StatuslineRenderSegment> {
 		buffer_count,
 		sync_role: sync_role_str,
 		sync_status: sync_status_str,
+		recording_macro: editor.state.core.editor.workspace.macro_state.recording_register(),
+		do_not_disturb: editor.do_not_disturb(),
+		total_width: editor.viewport().width.unwrap_or(u16::MAX),
+		consumed_width: 0,
 	};
 
 	let mut mode_segments = Vec::new();
 	let mut body_segments = Vec::new();
 	for position in [SegmentPosition::Left, SegmentPosition::Center, SegmentPosition::Right] {
-		for segment in render_position(position, &ctx) {
-			let target = make_segment(segment.text, segment.style);
+		for segment in render_position(&ctx, position, ctx.remaining_width()) {
+			ctx.consumed_width = ctx.consumed_width.saturating_add(segment.width);
+			let target = make_segment(segment.text, segment.style, segment.on_click);
 			if matches!(target.style, StatuslineRenderStyle::Mode) {
 				mode_segments.push(target);
 			} else {
@@ -164,6 +188,7 @@ pub fn render_plan(editor: &Editor) -> Vec<StatuslineRenderSegment> {
 			plan.push(StatuslineRenderSegment {
 				text: tag,
 				style: StatuslineRenderStyle::Dim,
+				on_click: None,
 			});
 			current_width += tag_width;
 		}
@@ -174,6 +199,7 @@ pub fn render_plan(editor: &Editor) -> Vec<StatuslineRenderSegment> {
 		plan.push(StatuslineRenderSegment {
 			text: " ".repeat(viewport_width.saturating_sub(current_width + mode_width)),
 			style: StatuslineRenderStyle::Normal,
+			on_click: None,
 		});
 	}
 
@@ -139,6 +139,7 @@ impl IcedEditorApp {
 		editor.kick_theme_load();
 		editor.kick_lsp_catalog_load();
 		editor.apply_loaded_config(user_config);
+		editor.load_command_history();
 
 		if let Some(theme_name) = startup.theme {
 			editor.set_configured_theme_name(theme_name);
@@ -45,16 +45,19 @@ fn map_notification_to_toast(colors: ThemeColors, notification: NotificationRend
 	};
 	let notif_style: Style = colors.notification_style(semantic).into();
 	let accent = notif_style.fg.unwrap_or_default();
-	Toast::new(notification.message)
-		.anchor(Anchor::TopRight)
-		.style(notif_style)
-		.border_style(Style::default().fg(accent))
-		.icon(ToastIcon::new(icon_glyph).style(Style::default().fg(accent)))
-		.animation(notif::Animation::Fade)
-		.auto_dismiss(match auto_dismiss {
-			NotificationRenderAutoDismiss::Never => notif::AutoDismiss::Never,
-			NotificationRenderAutoDismiss::After(d) => notif::AutoDismiss::After(d),
-		})
+	Toast {
+		group_key: notification.group_key,
+		..Toast::new(notification.message)
+	}
+	.anchor(Anchor::TopRight)
+	.style(notif_style)
+	.border_style(Style::default().fg(accent))
+	.icon(ToastIcon::new(icon_glyph).style(Style::default().fg(accent)))
+	.animation(notif::Animation::Fade)
+	.auto_dismiss(match auto_dismiss {
+		NotificationRenderAutoDismiss::Never => notif::AutoDismiss::Never,
+		NotificationRenderAutoDismiss::After(d) => notif::AutoDismiss::After(d),
+	})
 }
 
 pub fn render(ed: &mut Editor, state: &mut FrontendNotifications, doc_area: xeno_tui::layout::Rect, buffer: &mut xeno_tui::buffer::Buffer) {
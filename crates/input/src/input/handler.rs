@@ -1,5 +1,7 @@
 //! Input handler managing key processing and mode state.
 
+use std::time::{Duration, Instant};
+
 use tracing::debug;
 use xeno_keymap_core::parser::Node;
 use xeno_primitives::{Key, KeyCode, MouseButton, MouseEvent};
@@ -23,6 +25,9 @@ pub struct InputHandler {
 	pub(crate) extend: bool,
 	pub(crate) last_search: Option<(String, bool)>,
 	pub(crate) key_sequence: Vec<Node>,
+	pub(crate) pending_text: String,
+	/// When the pending key sequence last grew; drives ambiguous-match timeouts.
+	pub(crate) pending_since: Option<Instant>,
 }
 
 impl Default for InputHandler {
@@ -41,6 +46,8 @@ impl InputHandler {
 			extend: false,
 			last_search: None,
 			key_sequence: Vec::new(),
+			pending_text: String::new(),
+			pending_since: None,
 		}
 	}
 
@@ -59,6 +66,7 @@ impl InputHandler {
 				PendingKind::FindChar { .. } | PendingKind::FindCharReverse { .. } => "FIND",
 				PendingKind::ReplaceChar => "REPLACE",
 				PendingKind::Object(_) => "OBJECT",
+				PendingKind::SplitRegex => "SPLIT",
 			},
 		}
 	}
@@ -83,9 +91,15 @@ impl InputHandler {
 		if matches!(mode, Mode::Normal) {
 			self.reset_params();
 		}
+		self.pending_text.clear();
 		self.mode = mode;
 	}
 
+	/// Returns the text typed so far for a line-entry pending action.
+	pub fn pending_text(&self) -> &str {
+		&self.pending_text
+	}
+
 	/// Stores the last search pattern and direction for `n`/`N` repeat.
 	pub fn set_last_search(&mut self, pattern: String, reverse: bool) {
 		self.last_search = Some((pattern, reverse));
@@ -132,6 +146,7 @@ impl InputHandler {
 		self.register = None;
 		self.extend = false;
 		self.key_sequence.clear();
+		self.pending_since = None;
 	}
 
 	/// Returns the number of keys accumulated in the pending sequence.
@@ -257,13 +272,15 @@ impl InputHandler {
 				}
 				self.consume_binding(entry)
 			}
-			LookupOutcome::Pending { sticky } => {
-				if let Some(entry) = sticky {
-					debug!(action = entry.name(), keys = self.key_sequence.len(), "Pending with sticky action");
-				}
-				KeyResult::Pending {
-					keys_so_far: self.key_sequence.len(),
-				}
+			LookupOutcome::Pending => self.pending_sequence(),
+			LookupOutcome::AmbiguousMatch { exact, longer_candidates } => {
+				debug!(
+					action = exact.name(),
+					keys = self.key_sequence.len(),
+					longer_candidates,
+					"Ambiguous key sequence awaiting timeout"
+				);
+				self.pending_sequence()
 			}
 			LookupOutcome::None => {
 				if binding_mode != BindingMode::Normal {
@@ -275,6 +292,32 @@ impl InputHandler {
 		}
 	}
 
+	/// Marks the key sequence as pending more keys.
+	pub(crate) fn pending_sequence(&mut self) -> KeyResult {
+		self.pending_since = Some(Instant::now());
+		KeyResult::Pending {
+			keys_so_far: self.key_sequence.len(),
+		}
+	}
+
+	/// Commits a pending ambiguous key sequence once `timeout` has passed since its last key.
+	///
+	/// Returns `None` if no sequence is pending, the timeout is still running, or
+	/// the pending sequence is not itself bound.
+	pub fn resolve_key_timeout(&mut self, registry: &KeymapSnapshot, timeout: Duration) -> Option<KeyResult> {
+		let binding_mode = match self.mode {
+			Mode::Normal => BindingMode::Normal,
+			Mode::Insert => BindingMode::Insert,
+			Mode::PendingAction(_) => return None,
+		};
+		let since = self.pending_since?;
+		if self.key_sequence.is_empty() {
+			return None;
+		}
+		let entry = registry.resolve_pending(binding_mode, &self.key_sequence, since.elapsed(), timeout)?;
+		Some(self.consume_binding(entry))
+	}
+
 	/// Translates a mouse event into a [`KeyResult`].
 	pub fn handle_mouse(&mut self, event: MouseEvent) -> KeyResult {
 		match event {
@@ -51,9 +51,7 @@ impl InputHandler {
 
 		match result {
 			LookupOutcome::Match(entry) => self.consume_binding(entry),
-			LookupOutcome::Pending { .. } => KeyResult::Pending {
-				keys_so_far: self.key_sequence.len(),
-			},
+			LookupOutcome::Pending | LookupOutcome::AmbiguousMatch { .. } => self.pending_sequence(),
 			LookupOutcome::None => {
 				// Unknown prefix — clear pending and consume (don't insert garbage).
 				self.key_sequence.clear();
@@ -3,6 +3,9 @@ use xeno_primitives::{Key, KeyCode, ObjectSelectionKind, PendingKind};
 use super::InputHandler;
 use super::types::{KeyDispatch, KeyResult, Mode};
 
+/// Editor command that receives the pattern entered for [`PendingKind::SplitRegex`].
+const SPLIT_REGEX_COMMAND: &str = "split_regex";
+
 impl InputHandler {
 	/// Handles key input for pending actions (character find, text objects, etc.).
 	pub(crate) fn handle_pending_action_key(&mut self, key: Key, pending: PendingKind) -> KeyResult {
@@ -25,6 +28,7 @@ impl InputHandler {
 				ObjectSelectionKind::ToStart => "select_object_to_start",
 				ObjectSelectionKind::ToEnd => "select_object_to_end",
 			},
+			PendingKind::SplitRegex => return self.handle_pending_text_key(key, SPLIT_REGEX_COMMAND),
 		};
 
 		match key.code {
@@ -51,4 +55,38 @@ impl InputHandler {
 			_ => KeyResult::Consumed,
 		}
 	}
+
+	/// Accumulates a line of text for a pending action, dispatching `command`
+	/// with the text as its argument on Enter.
+	fn handle_pending_text_key(&mut self, key: Key, command: &str) -> KeyResult {
+		match key.code {
+			KeyCode::Char(ch) => {
+				self.pending_text.push(ch);
+				KeyResult::Consumed
+			}
+			KeyCode::Space => {
+				self.pending_text.push(' ');
+				KeyResult::Consumed
+			}
+			KeyCode::Backspace => {
+				self.pending_text.pop();
+				KeyResult::Consumed
+			}
+			KeyCode::Enter if !self.pending_text.is_empty() => {
+				let text = std::mem::take(&mut self.pending_text);
+				self.mode = Mode::Normal;
+				self.reset_params();
+				KeyResult::Dispatch(KeyDispatch {
+					invocation: xeno_registry::Invocation::editor_command(command, vec![text]),
+				})
+			}
+			KeyCode::Enter | KeyCode::Esc => {
+				self.pending_text.clear();
+				self.mode = Mode::Normal;
+				self.reset_params();
+				KeyResult::ModeChange(Mode::Normal)
+			}
+			_ => KeyResult::Consumed,
+		}
+	}
 }
@@ -109,6 +109,39 @@ fn invocation_spec_multi_key_pending_then_match() {
 	assert_eq!(h.pending_key_count(), 0);
 }
 
+#[test]
+fn ambiguous_sequence_commits_exact_binding_after_timeout() {
+	use std::collections::HashMap;
+	use std::time::Duration;
+
+	use xeno_registry::KeymapSnapshot;
+	use xeno_registry::config::UnresolvedKeys;
+	use xeno_registry::keymaps::KeymapBehavior;
+
+	let actions = xeno_registry::ACTIONS.snapshot();
+	let mut normal = HashMap::new();
+	normal.insert("g".to_string(), Some(xeno_registry::Invocation::editor_command("stats", vec![])));
+	let mut modes = HashMap::new();
+	modes.insert("normal".to_string(), normal);
+	let keymap = KeymapSnapshot::build_with_overrides(&actions, Some(&UnresolvedKeys { modes }));
+
+	let mut h = InputHandler::new();
+	assert!(h.resolve_key_timeout(&keymap, Duration::ZERO).is_none(), "nothing pending");
+
+	let result = h.handle_key_with_registry(Key::char('g'), &keymap, KeymapBehavior::default());
+	assert!(matches!(result, super::types::KeyResult::Pending { keys_so_far: 1 }), "got {result:?}");
+	assert!(h.resolve_key_timeout(&keymap, Duration::from_secs(60)).is_none(), "timeout still running");
+
+	match h.resolve_key_timeout(&keymap, Duration::ZERO) {
+		Some(super::types::KeyResult::Dispatch(super::types::KeyDispatch { invocation })) => {
+			assert!(matches!(invocation, xeno_registry::Invocation::Command(cmd) if cmd.name == "stats"));
+		}
+		other => panic!("expected the exact 'g' binding to dispatch, got {other:?}"),
+	}
+	assert_eq!(h.pending_key_count(), 0);
+	assert!(h.resolve_key_timeout(&keymap, Duration::ZERO).is_none(), "sequence already committed");
+}
+
 #[test]
 fn insert_multikey_prefix_dispatches() {
 	use xeno_registry::{KeymapSnapshot, keymaps};
@@ -183,6 +216,42 @@ fn action_count_overflow_clamped_to_max() {
 	}
 }
 
+#[test]
+fn split_regex_pending_collects_pattern_until_enter() {
+	use xeno_primitives::{KeyCode, PendingKind};
+
+	let mut h = InputHandler::new();
+	h.set_mode(super::types::Mode::PendingAction(PendingKind::SplitRegex));
+	assert_eq!(h.mode_name(), "SPLIT");
+
+	for ch in ", x".chars() {
+		assert!(matches!(h.handle_key(Key::char(ch)), super::types::KeyResult::Consumed));
+	}
+	h.handle_key(Key::new(KeyCode::Backspace));
+	assert_eq!(h.pending_text(), ", ");
+
+	match h.handle_key(Key::new(KeyCode::Enter)) {
+		super::types::KeyResult::Dispatch(super::types::KeyDispatch { invocation }) => {
+			assert_eq!(invocation, xeno_registry::Invocation::editor_command("split_regex", vec![", ".to_string()]));
+		}
+		other => panic!("expected Dispatch, got {other:?}"),
+	}
+	assert!(matches!(h.mode(), super::types::Mode::Normal));
+	assert_eq!(h.pending_text(), "");
+}
+
+#[test]
+fn split_regex_pending_enter_without_pattern_cancels() {
+	use xeno_primitives::{KeyCode, PendingKind};
+
+	let mut h = InputHandler::new();
+	h.set_mode(super::types::Mode::PendingAction(PendingKind::SplitRegex));
+	assert!(matches!(
+		h.handle_key(Key::new(KeyCode::Enter)),
+		super::types::KeyResult::ModeChange(super::types::Mode::Normal)
+	));
+}
+
 /// Golden table: `key_to_node(Key)` must produce the same `Node` as `parse(keymap_string)`.
 ///
 /// This ensures the runtime key representation and the keymap parser agree
@@ -8,7 +8,7 @@ mod word;
 pub use find::{find_char_backward, find_char_forward};
 pub use objects::{select_surround_object, select_word_object};
 use ropey::RopeSlice;
-pub use search::{escape_pattern, find_all_matches, find_next, find_next_re, find_prev, find_prev_re, matches_pattern};
+pub use search::{escape_pattern, find_all_matches, find_next, find_next_re, find_prev, find_prev_re, matches_pattern, split_on_matches};
 pub use word::{move_to_next_word_end, move_to_next_word_start, move_to_prev_word_start};
 use xeno_primitives::{CharIdx, Direction, Range, max_cursor_pos, next_grapheme_boundary, prev_grapheme_boundary, visible_line_count};
 
@@ -3,7 +3,7 @@
 use regex::Regex;
 pub use regex::escape as escape_pattern;
 use ropey::RopeSlice;
-use xeno_primitives::{CharIdx, Range};
+use xeno_primitives::{CharIdx, Range, Selection};
 
 /// Returns whether `text` matches `pattern` as a regex.
 pub fn matches_pattern(text: &str, pattern: &str) -> Result<bool, regex::Error> {
@@ -26,6 +26,47 @@ pub fn find_all_matches(text: RopeSlice, pattern: &str) -> Result<Vec<Range>, re
 	Ok(matches)
 }
 
+/// Splits every range of `selection` at matches of `pattern`, keeping the
+/// text between matches.
+///
+/// Ranges without a match are kept as they are. The primary becomes the first
+/// piece of the old primary range. Returns `None` if matches cover every range.
+pub fn split_on_matches(text: RopeSlice, selection: &Selection, pattern: &str) -> Result<Option<Selection>, regex::Error> {
+	let re = Regex::new(pattern)?;
+	let len = text.len_chars();
+	let mut pieces = Vec::new();
+	let mut primary_index = 0;
+
+	for (index, range) in selection.ranges().iter().enumerate() {
+		if index == selection.primary_index() {
+			primary_index = pieces.len();
+		}
+		let (from, to) = (range.from().min(len), range.to().min(len));
+		let range_text: String = text.slice(from..to).chars().collect();
+		let mut matched = false;
+		let mut last_end = from;
+		for m in re.find_iter(&range_text).filter(|m| !m.is_empty()) {
+			matched = true;
+			let start = from + byte_to_char_offset(&range_text, m.start());
+			if start > last_end {
+				pieces.push(Range::from_exclusive(last_end, start));
+			}
+			last_end = from + byte_to_char_offset(&range_text, m.end());
+		}
+		if !matched {
+			pieces.push(*range);
+		} else if last_end < to {
+			pieces.push(Range::from_exclusive(last_end, to));
+		}
+	}
+
+	if pieces.is_empty() {
+		return Ok(None);
+	}
+	let primary_index = primary_index.min(pieces.len() - 1);
+	Ok(Some(Selection::from_vec(pieces, primary_index)))
+}
+
 /// Finds the next regex match of `pattern` after `pos`, with document wraparound.
 pub fn find_next(text: RopeSlice, pattern: &str, pos: CharIdx) -> Result<Option<Range>, regex::Error> {
 	let re = Regex::new(pattern)?;
@@ -1,4 +1,5 @@
 use ropey::Rope;
+use xeno_primitives::Selection;
 
 use super::*;
 
@@ -45,3 +46,33 @@ fn test_find_all_matches() {
 	assert_eq!(matches[1].min(), 8);
 	assert_eq!(matches[2].min(), 16);
 }
+
+#[test]
+fn split_on_matches_splits_each_range_and_keeps_unmatched_ones() {
+	// "a,b,c" (0..5) / "def" (6..9) / ",x," (10..13)
+	let text = Rope::from("a,b,c\ndef\n,x,");
+	let selection = Selection::from_vec(vec![Range::from_exclusive(0, 5), Range::from_exclusive(6, 9), Range::from_exclusive(10, 13)], 1);
+
+	let split = split_on_matches(text.slice(..), &selection, ",").unwrap().unwrap();
+	let pieces: Vec<(usize, usize)> = split.ranges().iter().map(|r| (r.from(), r.to())).collect();
+	assert_eq!(pieces, [(0, 1), (2, 3), (4, 5), (6, 9), (11, 12)]);
+	assert_eq!(split.primary(), Range::from_exclusive(6, 9));
+}
+
+#[test]
+fn split_on_matches_without_matches_keeps_selection() {
+	let text = Rope::from("hello world");
+	let selection = Selection::from_vec(vec![Range::new(4, 0), Range::from_exclusive(6, 11)], 0);
+
+	let split = split_on_matches(text.slice(..), &selection, ",").unwrap().unwrap();
+	assert_eq!(split, selection);
+}
+
+#[test]
+fn split_on_matches_drops_fully_matched_ranges() {
+	let text = Rope::from("   ");
+	let selection = Selection::single(0, 2);
+
+	assert_eq!(split_on_matches(text.slice(..), &selection, r"\s+").unwrap(), None);
+	assert!(split_on_matches(text.slice(..), &selection, "(").is_err());
+}
@@ -52,6 +52,8 @@ pub struct NuCallLimits {
 	pub max_arg_len: usize,
 	/// Max Value nodes traversed in env validation.
 	pub max_env_nodes: usize,
+	/// Max list/record nesting depth of an env value.
+	pub max_value_depth: usize,
 	/// Max byte length per env string (keys + leaf values).
 	pub max_env_string_len: usize,
 	/// Max number of env vars passed to a call.
@@ -63,6 +65,7 @@ pub const DEFAULT_CALL_LIMITS: NuCallLimits = NuCallLimits {
 	max_args: schema::DEFAULT_LIMITS.max_args,
 	max_arg_len: schema::DEFAULT_LIMITS.max_string_len,
 	max_env_nodes: 5_000,
+	max_value_depth: 64,
 	max_env_string_len: schema::DEFAULT_LIMITS.max_string_len,
 	max_env_vars: 64,
 };
@@ -4,7 +4,7 @@
 //! * Key patterns: single keys (`a`), combinations (`ctrl-b`), sequences (`g g`)
 //! * Key groups: `@upper`, `@lower`, `@alpha`, `@alnum`, `@digit`, `@any`
 
-pub use matcher::{ContinuationEntry, ContinuationKind, MatchResult, Matcher};
+pub use matcher::{ContinuationEntry, ContinuationKind, MatchResult, Matcher, ShadowedBinding};
 pub use xeno_keymap_parser as parser;
 
 mod matcher;
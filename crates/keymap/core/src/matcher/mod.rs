@@ -83,6 +83,20 @@ pub struct ContinuationEntry<'a, T> {
 	pub kind: ContinuationKind,
 }
 
+/// A binding reachable only by passing through a shorter binding's sequence.
+///
+/// Dispatch sees `shadowing` complete at `prefix` while `shadowed` still needs
+/// more keys, so `shadowing` fires only after a timeout or non-matching key.
+#[derive(Debug, Clone)]
+pub struct ShadowedBinding<'a, T> {
+	/// Key sequence of the shorter, shadowing binding.
+	pub prefix: Vec<Node>,
+	/// Value bound at `prefix`.
+	pub shadowing: &'a T,
+	/// Value bound somewhere below `prefix`.
+	pub shadowed: &'a T,
+}
+
 /// A prefix tree node for storing key bindings.
 #[derive(Debug)]
 struct Trie<T> {
@@ -217,6 +231,66 @@ impl<T> Matcher<T> {
 
 		exact.chain(groups).collect()
 	}
+
+	/// Returns the number of bindings strictly longer than `prefix` that extend it.
+	pub fn count_below(&self, prefix: &[Node]) -> usize {
+		let Some(trie) = navigate_to(&self.root, prefix, 0) else {
+			return 0;
+		};
+		let mut below = Vec::new();
+		for (_, child) in children(trie) {
+			collect_values(child, &mut below);
+		}
+		below.len()
+	}
+
+	/// Returns every stored value, in no particular order.
+	pub fn values(&self) -> Vec<&T> {
+		let mut out = Vec::new();
+		collect_values(&self.root, &mut out);
+		out
+	}
+
+	/// Returns every binding whose sequence passes through another binding.
+	///
+	/// Walks the trie once; each node that both holds a value and has children
+	/// yields one entry per value beneath it.
+	pub fn shadowed_bindings(&self) -> Vec<ShadowedBinding<'_, T>> {
+		let mut out = Vec::new();
+		let mut path = Vec::new();
+		collect_shadowed(&self.root, &mut path, &mut out);
+		out
+	}
+}
+
+fn children<T>(node: &Trie<T>) -> impl Iterator<Item = (&Node, &Trie<T>)> {
+	node.exact.iter().chain(node.groups.iter().map(|(key, child)| (key, child)))
+}
+
+fn collect_shadowed<'a, T>(node: &'a Trie<T>, path: &mut Vec<Node>, out: &mut Vec<ShadowedBinding<'a, T>>) {
+	if let Some(shadowing) = node.value.as_ref() {
+		let mut below = Vec::new();
+		for (_, child) in children(node) {
+			collect_values(child, &mut below);
+		}
+		out.extend(below.into_iter().map(|shadowed| ShadowedBinding {
+			prefix: path.clone(),
+			shadowing,
+			shadowed,
+		}));
+	}
+	for (key, child) in children(node) {
+		path.push(key.clone());
+		collect_shadowed(child, path, out);
+		path.pop();
+	}
+}
+
+fn collect_values<'a, T>(node: &'a Trie<T>, out: &mut Vec<&'a T>) {
+	out.extend(node.value.as_ref());
+	for (_, child) in children(node) {
+		collect_values(child, out);
+	}
 }
 
 /// Navigates to the trie node at the given prefix, or None if prefix doesn't exist.
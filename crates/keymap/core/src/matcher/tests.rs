@@ -88,6 +88,33 @@ fn lookup_partial_with_value() {
 	}
 }
 
+#[test]
+fn count_below_counts_longer_bindings() {
+	let mut matcher = Matcher::new();
+	matcher.add(parse_seq("g").unwrap(), 1);
+	matcher.add(parse_seq("g g").unwrap(), 2);
+	matcher.add(parse_seq("g d").unwrap(), 3);
+	matcher.add(parse_seq("g w x").unwrap(), 4);
+
+	assert_eq!(matcher.count_below(&parse_seq("g").unwrap()), 3);
+	assert_eq!(matcher.count_below(&parse_seq("g w").unwrap()), 1);
+	assert_eq!(matcher.count_below(&parse_seq("g g").unwrap()), 0);
+	assert_eq!(matcher.count_below(&parse_seq("z").unwrap()), 0);
+}
+
+#[test]
+fn values_walks_exact_and_group_nodes() {
+	let mut matcher = Matcher::new();
+	matcher.add(parse_seq("g").unwrap(), 1);
+	matcher.add(parse_seq("g g").unwrap(), 2);
+	matcher.add(parse_seq("m @any").unwrap(), 3);
+	matcher.add(parse_seq("x").unwrap(), 4);
+
+	let mut values: Vec<_> = matcher.values().into_iter().copied().collect();
+	values.sort_unstable();
+	assert_eq!(values, [1, 2, 3, 4]);
+}
+
 #[test]
 fn lookup_none() {
 	let mut matcher = Matcher::new();
@@ -144,3 +171,27 @@ fn continuations_with_kind_branch_with_sticky() {
 	assert_eq!(g_cont.kind, ContinuationKind::Branch, "'g' is a branch (has 'g g' child)");
 	assert_eq!(g_cont.value, Some(&1), "'g' has sticky value");
 }
+
+#[test]
+fn shadowed_bindings_pair_prefix_with_descendants() {
+	let mut matcher = Matcher::new();
+	matcher.add(parse_seq("g").unwrap(), "goto");
+	matcher.add(parse_seq("g d").unwrap(), "definition");
+	matcher.add(parse_seq("g g").unwrap(), "top");
+	matcher.add(parse_seq("x").unwrap(), "line");
+
+	let mut shadowed = matcher.shadowed_bindings();
+	shadowed.sort_by_key(|s| *s.shadowed);
+	assert_eq!(shadowed.len(), 2);
+	assert!(shadowed.iter().all(|s| *s.shadowing == "goto" && s.prefix == parse_seq("g").unwrap()));
+	assert_eq!(*shadowed[0].shadowed, "definition");
+	assert_eq!(*shadowed[1].shadowed, "top");
+}
+
+#[test]
+fn shadowed_bindings_empty_without_sticky_prefixes() {
+	let mut matcher = Matcher::new();
+	matcher.add(parse_seq("g d").unwrap(), 1);
+	matcher.add(parse_seq("g g").unwrap(), 2);
+	assert!(matcher.shadowed_bindings().is_empty());
+}
@@ -7,6 +7,7 @@ use xeno_tree_house::LanguageConfig as TreeHouseConfig;
 
 use crate::language::LanguageData;
 use crate::lsp_config::LanguageLspInfo;
+use crate::query::TextObjectQuery;
 
 /// Global language database, initialized on first access.
 static LANG_DB: OnceLock<Arc<LanguageDb>> = OnceLock::new();
@@ -22,6 +23,7 @@ pub fn language_db() -> &'static Arc<LanguageDb> {
 /// runtime-loaded syntax configurations.
 pub struct LanguageDb {
 	configs: Vec<OnceLock<Option<Arc<TreeHouseConfig>>>>,
+	textobjects: Vec<OnceLock<Option<TextObjectQuery>>>,
 }
 
 impl LanguageDb {
@@ -34,10 +36,12 @@ impl LanguageDb {
 	pub fn from_embedded() -> Self {
 		let len = LANGUAGES.len();
 		let mut configs = Vec::with_capacity(len);
+		let mut textobjects = Vec::with_capacity(len);
 		for _ in 0..len {
 			configs.push(OnceLock::new());
+			textobjects.push(OnceLock::new());
 		}
-		Self { configs }
+		Self { configs, textobjects }
 	}
 
 	/// Returns language data by index.
@@ -105,6 +109,19 @@ impl LanguageDb {
 		.map(|arc: &Arc<TreeHouseConfig>| arc.as_ref())
 	}
 
+	/// Returns the text object query for a language ID, compiling it on first use.
+	///
+	/// `None` when the language has no `textobjects.scm` or its grammar fails to load.
+	pub fn get_textobject_query(&self, id: LanguageId) -> Option<&TextObjectQuery> {
+		let lock = self.textobjects.get(id.as_u32() as usize)?;
+		lock.get_or_init(|| {
+			let entry = LANGUAGES.get_by_id(id)?;
+			let grammar = self.get_config(id)?.grammar;
+			crate::language::load_textobject_query(&entry, grammar)
+		})
+		.as_ref()
+	}
+
 	/// Returns LSP configuration for a language.
 	pub fn lsp_info(&self, language: &str) -> Option<LanguageLspInfo> {
 		let entry = LANGUAGES.get(language)?;
@@ -4,9 +4,10 @@ use tracing::warn;
 use xeno_registry::languages::query::LanguageRef;
 use xeno_registry::themes::SyntaxStyles;
 use xeno_tree_house::LanguageConfig as TreeHouseConfig;
+use xeno_tree_house::tree_sitter::Grammar;
 
 use crate::grammar::load_grammar_or_build;
-use crate::query::read_query;
+use crate::query::{TextObjectQuery, read_query};
 use crate::syntax::{ViewportRepair, ViewportRepairRule};
 
 /// Language data wrapper over a registry entry.
@@ -194,3 +195,13 @@ pub(crate) fn load_syntax_config(entry: &LanguageRef) -> Option<TreeHouseConfig>
 		}
 	}
 }
+
+pub(crate) fn load_textobject_query(entry: &LanguageRef, grammar: Grammar) -> Option<TextObjectQuery> {
+	let source = read_query(entry.name_str(), "textobjects.scm");
+	if source.trim().is_empty() {
+		return None;
+	}
+	TextObjectQuery::new(grammar, &source)
+		.map_err(|e| warn!(language = entry.name_str(), error = %e, "Failed to compile text object query"))
+		.ok()
+}
@@ -87,6 +87,25 @@ impl LanguageLoader {
 		})
 	}
 
+	/// Finds a language from an Emacs or Vim modeline.
+	///
+	/// Recognizes `-*- mode: python -*-`, `-*- python -*-`, and
+	/// `vim: set ft=python:` / `vim: filetype=python` forms. The mode name is
+	/// matched against language names, then against shebang interpreters so
+	/// that e.g. `sh` resolves like `#!/bin/sh`.
+	pub fn language_for_modeline(&self, line: &str) -> Option<Language> {
+		let mode = modeline_mode(line)?.to_ascii_lowercase();
+		LANGUAGES
+			.get(&mode)
+			.or_else(|| LANGUAGES.language_for_shebang(&mode))
+			.map(|r: LanguageRef| r.dense_id().to_xeno_tree_house())
+	}
+
+	/// Finds a language from a file's first line: a shebang, else a modeline.
+	pub fn language_for_first_line(&self, first_line: &str) -> Option<Language> {
+		self.language_for_shebang(first_line).or_else(|| self.language_for_modeline(first_line))
+	}
+
 	/// Finds a language by matching text against injection regexes.
 	fn language_for_injection_match(&self, text: &str) -> Option<Language> {
 		LANGUAGES.snapshot_guard().iter_refs().find_map(|l: LanguageRef| {
@@ -121,6 +140,46 @@ impl LanguageLoader {
 	}
 }
 
+/// Markers that open a Vim modeline.
+const VIM_MODELINE_MARKERS: [&str; 3] = ["vim:", "vi:", "ex:"];
+
+/// Characters that may directly precede a Vim modeline marker, e.g. `#vim:`.
+const COMMENT_LEADERS: &[char] = &['#', '/', '*', ';', '-', '%', '"', '!'];
+
+/// Returns the text after the first Vim modeline marker in `line`.
+///
+/// Like Vim, a marker only counts at the start of the line or after
+/// whitespace, here also after a comment leader; `navi:` and `levi:` do not
+/// open a modeline.
+fn vim_modeline_options(line: &str) -> Option<&str> {
+	line.char_indices().find_map(|(i, _)| {
+		let at_boundary = line[..i].chars().next_back().is_none_or(|c| c.is_whitespace() || COMMENT_LEADERS.contains(&c));
+		if !at_boundary {
+			return None;
+		}
+		VIM_MODELINE_MARKERS.iter().find_map(|marker| line[i..].strip_prefix(marker))
+	})
+}
+
+/// Extracts the mode name from an Emacs (`-*- ... -*-`) or Vim (`vim:`) modeline.
+fn modeline_mode(line: &str) -> Option<&str> {
+	if let Some((_, rest)) = line.split_once("-*-") {
+		let body = rest.split_once("-*-").map_or(rest, |(body, _)| body).trim();
+		if !body.contains(':') {
+			return Some(body).filter(|mode| !mode.is_empty());
+		}
+		return body.split(';').find_map(|var| {
+			let (key, value) = var.split_once(':')?;
+			key.trim().eq_ignore_ascii_case("mode").then(|| value.trim()).filter(|mode| !mode.is_empty())
+		});
+	}
+
+	vim_modeline_options(line)?
+		.split(|c: char| c.is_whitespace() || c == ':')
+		.filter_map(|opt| opt.strip_prefix("ft=").or_else(|| opt.strip_prefix("filetype=")))
+		.find(|mode| !mode.is_empty())
+}
+
 /// A view of a [`LanguageLoader`] with a specific injection policy.
 pub struct LoaderView<'a> {
 	base: &'a LanguageLoader,
@@ -63,3 +63,50 @@ fn from_embedded_uses_global_db() {
 	let data = loader.get(rust).unwrap();
 	assert!(data.extensions.contains(&"rs".to_string()));
 }
+
+#[test]
+fn shebang_detection_for_common_interpreters() {
+	let loader = LanguageLoader::from_embedded();
+	let name = |line: &str| {
+		loader
+			.language_for_shebang(line)
+			.and_then(|lang| loader.get(lang))
+			.map(|data| data.name().to_string())
+	};
+
+	for (line, expected) in [
+		("#!/usr/bin/env python3", "python"),
+		("#!/bin/bash", "bash"),
+		("#!/usr/bin/env node", "javascript"),
+		("#!/usr/bin/ruby", "ruby"),
+		("#!/usr/bin/perl -w", "perl"),
+		("#!/usr/bin/env lua", "lua"),
+	] {
+		assert_eq!(name(line).as_deref(), Some(expected), "{line}");
+	}
+}
+
+#[test]
+fn modeline_detection() {
+	let loader = LanguageLoader::from_embedded();
+	let python = loader.language_for_name("python");
+	let bash = loader.language_for_name("bash");
+	assert!(python.is_some() && bash.is_some());
+
+	assert_eq!(loader.language_for_modeline("# -*- mode: python -*-"), python);
+	assert_eq!(loader.language_for_modeline("# -*- coding: utf-8; Mode: Python -*-"), python);
+	assert_eq!(loader.language_for_modeline("# -*- python -*-"), python);
+	assert_eq!(loader.language_for_modeline("# vim: set ft=python:"), python);
+	assert_eq!(loader.language_for_modeline("# vim: filetype=sh"), bash);
+	assert_eq!(loader.language_for_modeline("# -*- coding: utf-8 -*-"), None);
+	assert_eq!(loader.language_for_modeline("plain text"), None);
+	assert_eq!(loader.language_for_modeline("// ex: ft=python"), python);
+	assert_eq!(loader.language_for_modeline("#vim: ft=python"), python);
+	assert_eq!(loader.language_for_modeline("vi: ft=python"), python);
+	assert_eq!(loader.language_for_modeline("navi: ft=python"), None);
+	assert_eq!(loader.language_for_modeline("# levi: ft=python"), None);
+	assert_eq!(loader.language_for_modeline("let regex: ft=python"), None);
+
+	assert_eq!(loader.language_for_first_line("#!/bin/bash"), bash);
+	assert_eq!(loader.language_for_first_line("# -*- mode: python -*-"), python);
+}
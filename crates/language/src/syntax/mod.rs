@@ -17,6 +17,7 @@ use crate::highlight::Highlighter;
 use crate::loader::LanguageLoader;
 
 mod edit_generation;
+mod motion_tree;
 mod viewport_repair;
 
 use edit_generation::generate_edits;
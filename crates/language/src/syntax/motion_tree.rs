@@ -0,0 +1,72 @@
+//! Syntax tree access for syntax-aware motions and text objects.
+
+use ropey::RopeSlice;
+use xeno_registry::LANGUAGES;
+use xeno_registry::motions::{SyntaxNode, SyntaxTree};
+use xeno_tree_house::tree_sitter::Node;
+
+use super::Syntax;
+use crate::db::language_db;
+use crate::ids::TreeHouseLanguageExt;
+
+fn span(node: &Node<'_>) -> SyntaxNode {
+	SyntaxNode {
+		start: node.start_byte(),
+		end: node.end_byte(),
+	}
+}
+
+impl Syntax {
+	/// Named nodes spanning exactly `node`, innermost first.
+	fn same_span_nodes(&self, node: SyntaxNode) -> impl Iterator<Item = Node<'_>> {
+		let innermost = self.named_descendant_for_byte_range(node.start, node.end).filter(|n| span(n) == node);
+		std::iter::successors(innermost, move |n| n.parent().filter(|p| span(p) == node))
+	}
+
+	/// Runs the text object query of the innermost layer covering `start..end`.
+	fn layer_captures(&self, text: RopeSlice<'_>, capture: &str, start: u32, end: u32) -> Option<Vec<SyntaxNode>> {
+		let layer = self.layer(self.layer_for_byte_range(start, end));
+		let language = layer.language.to_registry(LANGUAGES.len() as u32)?;
+		let query = language_db().get_textobject_query(language)?;
+		let root = layer.tree()?.root_node();
+		let captures = query.capture_nodes(capture, &root, text)?;
+		Some(
+			captures
+				.map(|node| SyntaxNode {
+					start: node.start_byte() as u32,
+					end: node.end_byte() as u32,
+				})
+				.filter(|node| node.start <= start && end <= node.end)
+				.collect(),
+		)
+	}
+}
+
+impl SyntaxTree for Syntax {
+	fn named_node_at(&self, start: u32, end: u32) -> Option<SyntaxNode> {
+		self.named_descendant_for_byte_range(start, end).map(|n| span(&n))
+	}
+
+	fn parent(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		let mut current = self.named_descendant_for_byte_range(node.start, node.end)?;
+		while let Some(parent) = current.parent() {
+			if parent.is_named() && span(&parent) != node {
+				return Some(span(&parent));
+			}
+			current = parent;
+		}
+		None
+	}
+
+	fn next_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		self.same_span_nodes(node).find_map(|n| n.next_named_sibling()).map(|n| span(&n))
+	}
+
+	fn prev_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		self.same_span_nodes(node).find_map(|n| n.prev_named_sibling()).map(|n| span(&n))
+	}
+
+	fn captures_containing(&self, text: RopeSlice<'_>, capture: &str, start: u32, end: u32) -> Vec<SyntaxNode> {
+		self.layer_captures(text, capture, start, end).unwrap_or_default()
+	}
+}
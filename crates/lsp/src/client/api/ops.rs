@@ -548,6 +548,22 @@ impl ClientHandle {
 		Ok(result)
 	}
 
+	/// Request the changes to a document's semantic tokens since `previous_result_id`.
+	pub async fn semantic_tokens_full_delta(&self, uri: Uri, previous_result_id: String) -> Result<Option<lsp_types::SemanticTokensFullDeltaResult>> {
+		if !self.supports_semantic_tokens_delta() {
+			return Ok(None);
+		}
+		let result = self
+			.request::<lsp_types::request::SemanticTokensFullDeltaRequest>(lsp_types::SemanticTokensDeltaParams {
+				text_document: lsp_types::TextDocumentIdentifier { uri },
+				previous_result_id,
+				work_done_progress_params: Default::default(),
+				partial_result_params: Default::default(),
+			})
+			.await?;
+		Ok(result)
+	}
+
 	/// Request semantic tokens for a range within a document.
 	pub async fn semantic_tokens_range(&self, uri: Uri, range: lsp_types::Range) -> Result<Option<lsp_types::SemanticTokensRangeResult>> {
 		if !self.supports_semantic_tokens_range() {
@@ -135,7 +135,7 @@ pub fn client_capabilities(enable_snippets: bool) -> ClientCapabilities {
 			semantic_tokens: Some(lsp_types::SemanticTokensClientCapabilities {
 				dynamic_registration: Some(false),
 				requests: lsp_types::SemanticTokensClientCapabilitiesRequests {
-					full: Some(lsp_types::SemanticTokensFullOptions::Bool(true)),
+					full: Some(lsp_types::SemanticTokensFullOptions::Delta { delta: Some(true) }),
 					range: Some(true),
 					..Default::default()
 				},
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use serde_json::Value;
 pub use xeno_lsp_framework::OffsetEncoding;
@@ -45,6 +46,55 @@ impl std::fmt::Display for LanguageServerId {
 	}
 }
 
+/// Timeout and retry budget for requests to a language server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestPolicy {
+	/// Time to wait for a response before cancelling the request.
+	pub timeout: Duration,
+	/// Extra attempts after a timeout; only honored for idempotent read
+	/// requests (see [`RequestPolicy::is_retryable`]).
+	pub retries: u8,
+}
+
+impl RequestPolicy {
+	/// Creates a policy with `timeout` and no retries.
+	pub const fn new(timeout: Duration) -> Self {
+		Self { timeout, retries: 0 }
+	}
+
+	/// Sets the number of retries after a timeout.
+	pub const fn retries(mut self, retries: u8) -> Self {
+		self.retries = retries;
+		self
+	}
+
+	/// Returns whether `method` is a read request that is safe to resend.
+	///
+	/// Edits, formatting and completion are never retried: a late response
+	/// to the first attempt could still be applied.
+	pub fn is_retryable(method: &str) -> bool {
+		matches!(
+			method,
+			"textDocument/hover"
+				| "textDocument/documentSymbol"
+				| "textDocument/definition"
+				| "textDocument/declaration"
+				| "textDocument/typeDefinition"
+				| "textDocument/implementation"
+				| "textDocument/references"
+				| "textDocument/documentHighlight"
+				| "textDocument/foldingRange"
+				| "workspace/symbol"
+		)
+	}
+}
+
+impl Default for RequestPolicy {
+	fn default() -> Self {
+		Self::new(Duration::from_secs(30))
+	}
+}
+
 /// Configuration for starting a language server.
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
@@ -60,6 +110,8 @@ pub struct ServerConfig {
 	pub root_path: PathBuf,
 	/// Request timeout in seconds.
 	pub timeout_secs: u64,
+	/// Policies overriding `timeout_secs` for specific request methods.
+	pub request_policies: HashMap<String, RequestPolicy>,
 	/// Optional server-specific configuration.
 	pub config: Option<Value>,
 }
@@ -74,6 +126,7 @@ impl ServerConfig {
 			env: HashMap::new(),
 			root_path: root_path.into(),
 			timeout_secs: 30,
+			request_policies: HashMap::new(),
 			config: None,
 		}
 	}
@@ -96,6 +149,21 @@ impl ServerConfig {
 		self
 	}
 
+	/// Override the request policy for `method` (e.g. `textDocument/hover`).
+	pub fn request_policy(mut self, method: impl Into<String>, policy: RequestPolicy) -> Self {
+		self.request_policies.insert(method.into(), policy);
+		self
+	}
+
+	/// Returns the policy for requests of `method`: its override, or
+	/// `timeout_secs` without retries.
+	pub fn policy_for(&self, method: &str) -> RequestPolicy {
+		self.request_policies
+			.get(method)
+			.copied()
+			.unwrap_or_else(|| RequestPolicy::new(Duration::from_secs(self.timeout_secs)))
+	}
+
 	/// Set server-specific configuration.
 	pub fn config(mut self, config: Value) -> Self {
 		self.config = Some(config);
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use lsp_types::PositionEncodingKind;
 
 use super::*;
@@ -23,3 +25,16 @@ fn test_server_config_builder() {
 	assert_eq!(config.timeout_secs, 60);
 	assert!(config.config.is_some());
 }
+
+#[test]
+fn test_request_policy_overrides_per_method() {
+	let hover = RequestPolicy::new(Duration::from_millis(500)).retries(2);
+	let config = ServerConfig::new(LanguageServerId::new(0, 1), "rust-analyzer", "/project")
+		.timeout(10)
+		.request_policy("textDocument/hover", hover);
+
+	assert_eq!(config.policy_for("textDocument/hover"), hover);
+	assert_eq!(config.policy_for("textDocument/completion"), RequestPolicy::new(Duration::from_secs(10)));
+	assert!(RequestPolicy::is_retryable("textDocument/documentSymbol"));
+	assert!(!RequestPolicy::is_retryable("textDocument/rename"));
+}
@@ -1,5 +1,6 @@
 //! Public handle to an LSP language server.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -10,7 +11,7 @@ use lsp_types::request::Request;
 use lsp_types::{ServerCapabilities, Uri};
 use tokio::sync::{Notify, OnceCell};
 
-use super::config::{LanguageServerId, OffsetEncoding};
+use super::config::{LanguageServerId, OffsetEncoding, RequestPolicy, ServerConfig};
 use super::transport::LspTransport;
 use crate::{AnyNotification, AnyRequest, RequestId, Result};
 
@@ -32,8 +33,10 @@ pub struct ClientHandle {
 	pub(super) root_uri: Option<Uri>,
 	/// Notification channel for initialization completion.
 	pub(super) initialize_notify: Arc<Notify>,
-	/// Per-request timeout.
-	pub(super) timeout: Duration,
+	/// Timeout and retries for methods without an override.
+	pub(super) request_policy: RequestPolicy,
+	/// Per-method overrides of `request_policy`.
+	pub(super) method_policies: Arc<HashMap<String, RequestPolicy>>,
 	/// Underlying transport.
 	pub(super) transport: Arc<dyn LspTransport>,
 	/// Whether the server has completed initialization.
@@ -65,13 +68,26 @@ impl ClientHandle {
 			root_path,
 			root_uri,
 			initialize_notify: Arc::new(Notify::new()),
-			timeout: Duration::from_secs(30),
+			request_policy: RequestPolicy::default(),
+			method_policies: Arc::default(),
 			transport,
 			is_ready: Arc::new(AtomicBool::new(false)),
 			next_request_id: Arc::new(AtomicU64::new(1)),
 		}
 	}
 
+	/// Applies the request timeout and per-method policies of `config`.
+	pub fn with_request_policies(mut self, config: &ServerConfig) -> Self {
+		self.request_policy = RequestPolicy::new(Duration::from_secs(config.timeout_secs));
+		self.method_policies = Arc::new(config.request_policies.clone());
+		self
+	}
+
+	/// Returns the policy applied to requests of `method`.
+	pub fn request_policy(&self, method: &str) -> RequestPolicy {
+		self.method_policies.get(method).copied().unwrap_or(self.request_policy)
+	}
+
 	/// Get the client's unique identifier.
 	pub fn id(&self) -> LanguageServerId {
 		self.id
@@ -247,6 +263,27 @@ impl ClientHandle {
 		})
 	}
 
+	/// Check if the server supports semantic tokens deltas (`full/delta`).
+	pub fn supports_semantic_tokens_delta(&self) -> bool {
+		self.capabilities().is_some_and(|c| {
+			matches!(
+				&c.semantic_tokens_provider,
+				Some(
+					lsp_types::SemanticTokensServerCapabilities::SemanticTokensOptions(lsp_types::SemanticTokensOptions {
+						full: Some(lsp_types::SemanticTokensFullOptions::Delta { delta: Some(true) }),
+						..
+					}) | lsp_types::SemanticTokensServerCapabilities::SemanticTokensRegistrationOptions(lsp_types::SemanticTokensRegistrationOptions {
+						semantic_tokens_options: lsp_types::SemanticTokensOptions {
+							full: Some(lsp_types::SemanticTokensFullOptions::Delta { delta: Some(true) }),
+							..
+						},
+						..
+					})
+				)
+			)
+		})
+	}
+
 	/// Check if the server supports semantic tokens (range).
 	pub fn supports_semantic_tokens_range(&self) -> bool {
 		self.capabilities().is_some_and(|c| {
@@ -421,23 +458,52 @@ impl ClientHandle {
 	/// A unique monotonic request ID is automatically generated and assigned to the outgoing
 	/// request. This ID is used by the underlying transport to correlate the response.
 	///
+	/// The request is bounded by the method's [`RequestPolicy`]: on timeout it is cancelled
+	/// with `$/cancelRequest` and, for idempotent reads, resent up to `retries` times
+	/// under a fresh ID.
+	///
 	/// # Errors
 	/// Returns an error if the transport fails to send the request, if the request times out,
 	/// or if the server returns an LSP error response.
 	pub async fn request<R: Request>(&self, params: R::Params) -> Result<R::Result> {
-		let id_num = self.next_request_id.fetch_add(1, Ordering::Relaxed);
-		let req = AnyRequest::new(
-			RequestId::Number(id_num as i32),
-			R::METHOD,
-			serde_json::to_value(params).expect("Failed to serialize"),
-		);
-		let resp = self.transport.request(self.id, req, Some(self.timeout)).await?;
+		let params = serde_json::to_value(params).expect("Failed to serialize");
+		let policy = self.request_policy(R::METHOD);
+		let retries = if RequestPolicy::is_retryable(R::METHOD) { policy.retries } else { 0 };
+
+		let mut attempt = 0;
+		let resp = loop {
+			match self.request_once(R::METHOD, params.clone(), policy.timeout).await {
+				Err(crate::Error::RequestTimeout(_)) if attempt < retries => {
+					attempt += 1;
+					tracing::debug!(server_id = %self.id, method = R::METHOD, attempt, "lsp.request.retry_after_timeout");
+				}
+				result => break result?,
+			}
+		};
 		match resp.error {
 			None => Ok(serde_json::from_value(resp.result.unwrap_or_default())?),
 			Some(err) => Err(crate::Error::Response(err)),
 		}
 	}
 
+	/// Sends one attempt of a request, cancelling it if no response arrives within `timeout`.
+	async fn request_once(&self, method: &str, params: serde_json::Value, timeout: Duration) -> Result<crate::AnyResponse> {
+		let id_num = self.next_request_id.fetch_add(1, Ordering::Relaxed) as i32;
+		let req = AnyRequest::new(RequestId::Number(id_num), method, params);
+		match tokio::time::timeout(timeout, self.transport.request(self.id, req, Some(timeout))).await {
+			Ok(Err(crate::Error::RequestTimeout(_))) | Err(_) => {
+				let cancel = lsp_types::CancelParams {
+					id: lsp_types::NumberOrString::Number(id_num),
+				};
+				if let Err(error) = self.notify::<lsp_types::notification::Cancel>(cancel).await {
+					tracing::debug!(server_id = %self.id, method, %error, "lsp.request.cancel_failed");
+				}
+				Err(crate::Error::RequestTimeout(method.to_string()))
+			}
+			Ok(result) => result,
+		}
+	}
+
 	/// Send a notification to the language server.
 	pub async fn notify<N: Notification>(&self, params: N::Params) -> Result<()> {
 		let notif = AnyNotification::new(N::METHOD, serde_json::to_value(params).expect("Failed to serialize"));
@@ -620,4 +686,141 @@ mod tests {
 		let js_uri: Uri = "file:///project/src/main.js".parse().unwrap();
 		assert!(!matches_file_operation_filters(root, &js_uri, &filters, FileOperationTarget::File));
 	}
+
+	mod request_policy {
+		use std::collections::VecDeque;
+
+		use async_trait::async_trait;
+		use lsp_types::request::{DocumentSymbolRequest, Formatting};
+		use parking_lot::Mutex;
+		use tokio::sync::{mpsc, oneshot};
+
+		use super::*;
+		use crate::client::transport::{StartedServer, TransportEvent};
+		use crate::{AnyResponse, JsonValue, ResponseError};
+
+		/// Mock server that answers each request after the next queued delay.
+		#[derive(Default)]
+		struct DelayedTransport {
+			delays: Mutex<VecDeque<Duration>>,
+			requests: Mutex<Vec<(RequestId, String)>>,
+			notifications: Mutex<Vec<AnyNotification>>,
+		}
+
+		#[async_trait]
+		impl LspTransport for DelayedTransport {
+			fn subscribe_events(&self) -> Result<mpsc::UnboundedReceiver<TransportEvent>> {
+				Ok(mpsc::unbounded_channel().1)
+			}
+
+			async fn start(&self, cfg: ServerConfig) -> Result<StartedServer> {
+				Ok(StartedServer { id: cfg.id })
+			}
+
+			async fn notify(&self, _server: LanguageServerId, notif: AnyNotification) -> Result<()> {
+				self.notifications.lock().push(notif);
+				Ok(())
+			}
+
+			async fn notify_with_barrier(&self, server: LanguageServerId, notif: AnyNotification) -> Result<oneshot::Receiver<Result<()>>> {
+				let (tx, rx) = oneshot::channel();
+				let _ = tx.send(self.notify(server, notif).await);
+				Ok(rx)
+			}
+
+			async fn request(&self, _server: LanguageServerId, req: AnyRequest, _timeout: Option<Duration>) -> Result<AnyResponse> {
+				self.requests.lock().push((req.id.clone(), req.method));
+				let delay = self.delays.lock().pop_front().unwrap_or_default();
+				tokio::time::sleep(delay).await;
+				Ok(AnyResponse::new_ok(req.id, JsonValue::Null))
+			}
+
+			async fn reply(&self, _server: LanguageServerId, _id: RequestId, _resp: std::result::Result<JsonValue, ResponseError>) -> Result<()> {
+				Ok(())
+			}
+
+			async fn stop(&self, _server: LanguageServerId) -> Result<()> {
+				Ok(())
+			}
+		}
+
+		fn client(transport: &Arc<DelayedTransport>, config: ServerConfig) -> ClientHandle {
+			ClientHandle::new(config.id, "mock".into(), PathBuf::from("/project"), transport.clone()).with_request_policies(&config)
+		}
+
+		fn document() -> lsp_types::TextDocumentIdentifier {
+			lsp_types::TextDocumentIdentifier::new("file:///project/main.rs".parse().unwrap())
+		}
+
+		fn cancelled_ids(transport: &DelayedTransport) -> Vec<JsonValue> {
+			let notifications = transport.notifications.lock();
+			notifications
+				.iter()
+				.filter(|notif| notif.method == "$/cancelRequest")
+				.map(|notif| notif.params["id"].clone())
+				.collect()
+		}
+
+		#[tokio::test(start_paused = true)]
+		async fn timed_out_reads_are_cancelled_and_retried() {
+			let transport = Arc::new(DelayedTransport::default());
+			transport.delays.lock().extend([Duration::from_secs(5), Duration::from_secs(5)]);
+			let policy = RequestPolicy::new(Duration::from_millis(100)).retries(2);
+			let config = ServerConfig::new(LanguageServerId::new(0, 0), "mock", "/project").request_policy("textDocument/documentSymbol", policy);
+
+			let symbols = client(&transport, config)
+				.request::<DocumentSymbolRequest>(lsp_types::DocumentSymbolParams {
+					text_document: document(),
+					work_done_progress_params: Default::default(),
+					partial_result_params: Default::default(),
+				})
+				.await
+				.expect("third attempt answers in time");
+
+			assert!(symbols.is_none());
+			let ids: Vec<_> = transport.requests.lock().iter().map(|(id, _)| id.clone()).collect();
+			assert_eq!(ids, [RequestId::Number(1), RequestId::Number(2), RequestId::Number(3)]);
+			assert_eq!(cancelled_ids(&transport), [JsonValue::from(1), JsonValue::from(2)]);
+		}
+
+		#[tokio::test(start_paused = true)]
+		async fn timed_out_edits_are_cancelled_without_retry() {
+			let transport = Arc::new(DelayedTransport::default());
+			transport.delays.lock().extend([Duration::from_secs(5); 4]);
+			let config = ServerConfig::new(LanguageServerId::new(0, 0), "mock", "/project")
+				.timeout(1)
+				.request_policy("textDocument/formatting", RequestPolicy::new(Duration::from_millis(100)).retries(3));
+
+			let error = client(&transport, config)
+				.request::<Formatting>(lsp_types::DocumentFormattingParams {
+					text_document: document(),
+					options: Default::default(),
+					work_done_progress_params: Default::default(),
+				})
+				.await
+				.expect_err("server never answers in time");
+
+			assert!(matches!(error, crate::Error::RequestTimeout(ref method) if method == "textDocument/formatting"));
+			assert_eq!(transport.requests.lock().len(), 1);
+			assert_eq!(cancelled_ids(&transport), [JsonValue::from(1)]);
+		}
+
+		#[tokio::test(start_paused = true)]
+		async fn methods_without_override_use_the_server_timeout() {
+			let transport = Arc::new(DelayedTransport::default());
+			transport.delays.lock().push_back(Duration::from_millis(1500));
+			let config = ServerConfig::new(LanguageServerId::new(0, 0), "mock", "/project").timeout(2);
+
+			let result = client(&transport, config)
+				.request::<DocumentSymbolRequest>(lsp_types::DocumentSymbolParams {
+					text_document: document(),
+					work_done_progress_params: Default::default(),
+					partial_result_params: Default::default(),
+				})
+				.await;
+
+			assert!(result.is_ok(), "1.5s response fits the 2s server timeout");
+			assert!(cancelled_ids(&transport).is_empty());
+		}
+	}
 }
@@ -26,7 +26,9 @@ pub use xeno_lsp_framework::{
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 pub mod client;
 #[cfg(feature = "client")]
-pub use client::{ClientHandle, LanguageServerId, LocalTransport, LogLevel, LspEventHandler, NoOpEventHandler, ServerConfig, ServerState, SharedEventHandler};
+pub use client::{
+	ClientHandle, LanguageServerId, LocalTransport, LogLevel, LspEventHandler, NoOpEventHandler, RequestPolicy, ServerConfig, ServerState, SharedEventHandler,
+};
 #[cfg(feature = "position")]
 pub use xeno_lsp_framework::{
 	IncrementalResult, char_range_to_lsp_range, char_to_lsp_position, compute_lsp_changes, lsp_position_to_char, lsp_range_to_char_range,
@@ -56,4 +58,6 @@ pub use sync::{BarrierMode, ChangeDispatch, ChangePayload, ChangeRequest, Docume
 pub mod session;
 
 #[cfg(all(feature = "client", feature = "position"))]
-pub use session::{CompletionController, CompletionRequest, CompletionTrigger, LspRuntime, LspSession, RuntimeStartError};
+pub use session::{
+	CompletionController, CompletionRequest, CompletionTrigger, DecodedToken, LspRuntime, LspSession, RuntimeStartError, SemanticTokensController,
+};
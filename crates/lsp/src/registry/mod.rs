@@ -317,7 +317,7 @@ impl Registry {
 			.env(config.env.iter().map(|(k, v)| (k.clone(), v.clone())))
 			.timeout(config.timeout_secs);
 
-		let started_res = self.transport.start(server_config).await;
+		let started_res = self.transport.start(server_config.clone()).await;
 
 		let final_res = match started_res {
 			Ok(started) => {
@@ -335,7 +335,8 @@ impl Registry {
 							Some(started.id),
 						)
 					} else {
-						let handle = ClientHandle::new(started.id, config.command.clone(), root_path.clone(), self.transport.clone());
+						let handle = ClientHandle::new(started.id, config.command.clone(), root_path.clone(), self.transport.clone())
+							.with_request_policies(&server_config);
 
 						state.server_meta.insert(
 							started.id,
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
@@ -7,6 +9,7 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
 use super::*;
+use crate::session::{DecodedToken, SemanticTokensController};
 
 /// Errors returned when starting [`LspRuntime`].
 #[derive(Debug, thiserror::Error)]
@@ -162,6 +165,7 @@ pub struct LspSession {
 	sync: DocumentSync,
 	diagnostics_receiver: Option<DiagnosticsEventReceiver>,
 	transport: Arc<dyn LspTransport>,
+	semantic_tokens: HashMap<PathBuf, SemanticTokensController>,
 }
 
 impl LspSession {
@@ -174,6 +178,7 @@ impl LspSession {
 				sync,
 				diagnostics_receiver: Some(diagnostics_receiver),
 				transport,
+				semantic_tokens: HashMap::new(),
 			},
 			runtime,
 		)
@@ -189,6 +194,7 @@ impl LspSession {
 				sync,
 				diagnostics_receiver: None,
 				transport,
+				semantic_tokens: HashMap::new(),
 			},
 			runtime,
 		)
@@ -242,6 +248,40 @@ impl LspSession {
 		self.sync.documents()
 	}
 
+	/// Returns the last semantic tokens fetched for `path`.
+	pub fn semantic_tokens_for_file(&self, path: &Path) -> Option<Vec<DecodedToken<'_>>> {
+		self.semantic_tokens.get(path).map(SemanticTokensController::tokens)
+	}
+
+	/// Fetches semantic tokens for `path` from its running `language` server.
+	///
+	/// Requests a delta against the previous tokens when the server supports it.
+	/// Returns `false` if no server is running for the file, the server has no
+	/// semantic tokens provider, or it returned nothing.
+	pub async fn refresh_semantic_tokens(&mut self, language: &str, path: &Path) -> crate::Result<bool> {
+		let Some(client) = self.registry().get(language, path) else {
+			return Ok(false);
+		};
+		let (Some(legend), Some(uri)) = (client.semantic_token_legend(), crate::uri_from_path(path)) else {
+			self.semantic_tokens.remove(path);
+			return Ok(false);
+		};
+		let controller = self
+			.semantic_tokens
+			.entry(path.to_path_buf())
+			.or_insert_with(|| SemanticTokensController::new(legend.clone()));
+		if controller.legend() != legend {
+			// Restarted server with a different legend; earlier tokens are meaningless.
+			*controller = SemanticTokensController::new(legend.clone());
+		}
+		controller.refresh(&client, uri).await
+	}
+
+	/// Drops the semantic tokens held for `path`, e.g. when its document closes.
+	pub fn clear_semantic_tokens(&mut self, path: &Path) {
+		self.semantic_tokens.remove(path);
+	}
+
 	/// Shutdown all language servers.
 	pub async fn shutdown_all(&self) {
 		let ids = self.sync.registry().shutdown_all();
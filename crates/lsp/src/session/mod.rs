@@ -1,11 +1,13 @@
 //! LSP session orchestration surface.
 //!
-//! Groups the session manager, completion controller wiring, and handlers for
-//! server-initiated requests.
+//! Groups the session manager, completion and semantic tokens controllers, and
+//! handlers for server-initiated requests.
 
 mod completion;
 pub(crate) mod manager;
+mod semantic_tokens;
 pub mod server_requests;
 
 pub use completion::{CompletionController, CompletionRequest, CompletionTrigger};
 pub use manager::{LspRuntime, LspSession, RuntimeStartError};
+pub use semantic_tokens::{DecodedToken, SemanticTokensController};
@@ -0,0 +1,293 @@
+use lsp_types::{SemanticToken, SemanticTokensEdit, SemanticTokensFullDeltaResult, SemanticTokensLegend, SemanticTokensResult, Uri};
+
+use crate::ClientHandle;
+
+/// Number of `u32` values encoding one token.
+const TOKEN_LEN: usize = 5;
+
+/// A semantic token with an absolute position and names resolved from the legend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedToken<'a> {
+	/// Zero-based line.
+	pub line: u32,
+	/// Start character on the line, in the negotiated position encoding.
+	pub character: u32,
+	/// Token length, in the negotiated position encoding.
+	pub length: u32,
+	/// Token type name from the legend.
+	pub token_type: &'a str,
+	/// Modifier names from the legend, in legend order.
+	pub modifiers: Vec<&'a str>,
+}
+
+/// Semantic tokens of one document from one server.
+///
+/// Holds the server's legend and the last token array in the relative 5-tuple
+/// encoding. Once the server has returned a result ID, refreshes request
+/// `textDocument/semanticTokens/full/delta` if the server supports it, and
+/// `textDocument/semanticTokens/full` otherwise.
+#[derive(Debug, Clone)]
+pub struct SemanticTokensController {
+	legend: SemanticTokensLegend,
+	result_id: Option<String>,
+	data: Vec<u32>,
+}
+
+impl SemanticTokensController {
+	/// Creates a controller decoding with `legend`, holding no tokens.
+	pub fn new(legend: SemanticTokensLegend) -> Self {
+		Self {
+			legend,
+			result_id: None,
+			data: Vec::new(),
+		}
+	}
+
+	/// Creates a controller for the legend in the server's `semanticTokensProvider`.
+	///
+	/// Returns `None` if the server does not provide semantic tokens.
+	pub fn for_client(client: &ClientHandle) -> Option<Self> {
+		client.semantic_token_legend().cloned().map(Self::new)
+	}
+
+	/// Returns the legend tokens are decoded with.
+	pub fn legend(&self) -> &SemanticTokensLegend {
+		&self.legend
+	}
+
+	/// Returns the result ID of the last response, used as the delta base.
+	pub fn result_id(&self) -> Option<&str> {
+		self.result_id.as_deref()
+	}
+
+	/// Returns the current token array in the relative 5-tuple encoding.
+	pub fn data(&self) -> &[u32] {
+		&self.data
+	}
+
+	/// Decodes the current token array.
+	pub fn tokens(&self) -> Vec<DecodedToken<'_>> {
+		self.decode(&self.data)
+	}
+
+	/// Requests the document's tokens, as a delta against the last result when possible.
+	///
+	/// Returns `false` if the server returned nothing, keeping the previous tokens.
+	pub async fn refresh(&mut self, client: &ClientHandle, uri: Uri) -> crate::Result<bool> {
+		if let Some(previous) = self.result_id.clone().filter(|_| client.supports_semantic_tokens_delta()) {
+			let Some(result) = client.semantic_tokens_full_delta(uri, previous).await? else {
+				return Ok(false);
+			};
+			self.apply_delta_result(result);
+			return Ok(true);
+		}
+
+		match client.semantic_tokens_full(uri).await? {
+			Some(SemanticTokensResult::Tokens(tokens)) => self.replace(tokens.result_id, &tokens.data),
+			Some(SemanticTokensResult::Partial(partial)) => self.replace(None, &partial.data),
+			None => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	/// Applies a `textDocument/semanticTokens/full/delta` response.
+	///
+	/// The server may answer with a full token array instead of edits.
+	pub fn apply_delta_result(&mut self, result: SemanticTokensFullDeltaResult) {
+		match result {
+			SemanticTokensFullDeltaResult::Tokens(tokens) => self.replace(tokens.result_id, &tokens.data),
+			SemanticTokensFullDeltaResult::TokensDelta(delta) => {
+				apply_edits(&mut self.data, &delta.edits);
+				self.result_id = delta.result_id;
+			}
+			SemanticTokensFullDeltaResult::PartialTokensDelta { edits } => apply_edits(&mut self.data, &edits),
+		}
+	}
+
+	fn replace(&mut self, result_id: Option<String>, tokens: &[SemanticToken]) {
+		self.result_id = result_id;
+		self.data = tokens.iter().flat_map(encode_token).collect();
+	}
+
+	/// Decodes a token array in the relative 5-tuple encoding
+	/// `[deltaLine, deltaStart, length, tokenType, tokenModifiers]`.
+	///
+	/// Positions accumulate across every token; tokens whose type is not in the
+	/// legend are skipped, and a trailing partial tuple is ignored.
+	pub fn decode(&self, encoded: &[u32]) -> Vec<DecodedToken<'_>> {
+		let mut line = 0u32;
+		let mut character = 0u32;
+		encoded
+			.chunks_exact(TOKEN_LEN)
+			.filter_map(|chunk| {
+				let &[delta_line, delta_start, length, token_type, modifier_bits] = chunk else {
+					return None;
+				};
+				if delta_line > 0 {
+					line = line.saturating_add(delta_line);
+					character = delta_start;
+				} else {
+					character = character.saturating_add(delta_start);
+				}
+
+				let token_type = self.legend.token_types.get(token_type as usize)?.as_str();
+				let modifiers = self
+					.legend
+					.token_modifiers
+					.iter()
+					.take(u32::BITS as usize)
+					.enumerate()
+					.filter(|(bit, _)| modifier_bits & (1 << bit) != 0)
+					.map(|(_, modifier)| modifier.as_str())
+					.collect();
+				Some(DecodedToken {
+					line,
+					character,
+					length,
+					token_type,
+					modifiers,
+				})
+			})
+			.collect()
+	}
+}
+
+/// Applies semantic token delta `edits` to a token array.
+///
+/// Edit offsets index the array as it was before any edit, so edits are
+/// applied from the back. Offsets past the end are clamped.
+fn apply_edits(data: &mut Vec<u32>, edits: &[SemanticTokensEdit]) {
+	let mut edits: Vec<&SemanticTokensEdit> = edits.iter().collect();
+	edits.sort_by_key(|edit| std::cmp::Reverse(edit.start));
+	for edit in edits {
+		let start = (edit.start as usize).min(data.len());
+		let end = start.saturating_add(edit.delete_count as usize).min(data.len());
+		data.splice(start..end, edit.data.iter().flatten().flat_map(encode_token));
+	}
+}
+
+fn encode_token(token: &SemanticToken) -> [u32; TOKEN_LEN] {
+	[
+		token.delta_line,
+		token.delta_start,
+		token.length,
+		token.token_type,
+		token.token_modifiers_bitset,
+	]
+}
+
+#[cfg(test)]
+mod tests {
+	use lsp_types::{SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensDelta};
+
+	use super::*;
+
+	fn controller() -> SemanticTokensController {
+		SemanticTokensController::new(SemanticTokensLegend {
+			token_types: vec![SemanticTokenType::KEYWORD, SemanticTokenType::FUNCTION, SemanticTokenType::VARIABLE],
+			token_modifiers: vec![SemanticTokenModifier::DECLARATION, SemanticTokenModifier::READONLY],
+		})
+	}
+
+	fn token(delta_line: u32, delta_start: u32, length: u32, token_type: u32, token_modifiers_bitset: u32) -> SemanticToken {
+		SemanticToken {
+			delta_line,
+			delta_start,
+			length,
+			token_type,
+			token_modifiers_bitset,
+		}
+	}
+
+	fn positions<'a>(tokens: &[DecodedToken<'a>]) -> Vec<(u32, u32, &'a str)> {
+		tokens.iter().map(|t| (t.line, t.character, t.token_type)).collect()
+	}
+
+	#[test]
+	fn decode_accumulates_positions_and_resolves_legend() {
+		let ctl = controller();
+		// fn foo() {}
+		//   let x
+		let encoded = [0, 0, 2, 0, 0, 0, 3, 3, 1, 0b01, 1, 2, 3, 0, 0, 0, 4, 1, 2, 0b11];
+		let tokens = ctl.decode(&encoded);
+
+		assert_eq!(
+			positions(&tokens),
+			vec![(0, 0, "keyword"), (0, 3, "function"), (1, 2, "keyword"), (1, 6, "variable")]
+		);
+		assert_eq!(tokens[1].modifiers, vec!["declaration"]);
+		assert_eq!(tokens[3].modifiers, vec!["declaration", "readonly"]);
+		assert_eq!(tokens[3].length, 1);
+	}
+
+	#[test]
+	fn decode_skips_unknown_types_but_keeps_their_offsets() {
+		let ctl = controller();
+		let encoded = [2, 4, 1, 9, 0, 0, 3, 2, 0, 0, 7];
+		assert_eq!(positions(&ctl.decode(&encoded)), vec![(2, 7, "keyword")], "partial tuple is ignored");
+	}
+
+	#[test]
+	fn delta_edits_apply_against_the_original_array() {
+		let mut data: Vec<u32> = (0..15).collect();
+		let edits = [
+			SemanticTokensEdit {
+				start: 10,
+				delete_count: 5,
+				data: None,
+			},
+			SemanticTokensEdit {
+				start: 0,
+				delete_count: 5,
+				data: Some(vec![token(9, 9, 9, 9, 9), token(8, 8, 8, 8, 8)]),
+			},
+		];
+		apply_edits(&mut data, &edits);
+		assert_eq!(data, vec![9, 9, 9, 9, 9, 8, 8, 8, 8, 8, 5, 6, 7, 8, 9]);
+
+		let mut data = vec![1, 2, 3, 4, 5];
+		apply_edits(
+			&mut data,
+			&[SemanticTokensEdit {
+				start: 40,
+				delete_count: 10,
+				data: Some(vec![token(0, 1, 1, 0, 0)]),
+			}],
+		);
+		assert_eq!(data, vec![1, 2, 3, 4, 5, 0, 1, 1, 0, 0], "out-of-range edits append");
+	}
+
+	#[test]
+	fn delta_result_updates_tokens_and_result_id() {
+		let mut ctl = controller();
+		ctl.apply_delta_result(SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+			result_id: Some("1".into()),
+			data: vec![token(0, 0, 2, 0, 0), token(0, 3, 3, 1, 0)],
+		}));
+		assert_eq!(ctl.result_id(), Some("1"));
+
+		// Rename `foo` to `foobar` and retype it as a variable.
+		ctl.apply_delta_result(SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+			result_id: Some("2".into()),
+			edits: vec![SemanticTokensEdit {
+				start: 5,
+				delete_count: 5,
+				data: Some(vec![token(0, 3, 6, 2, 0)]),
+			}],
+		}));
+		assert_eq!(ctl.result_id(), Some("2"));
+		let tokens = ctl.tokens();
+		assert_eq!(positions(&tokens), vec![(0, 0, "keyword"), (0, 3, "variable")]);
+		assert_eq!(tokens[1].length, 6);
+
+		ctl.apply_delta_result(SemanticTokensFullDeltaResult::PartialTokensDelta {
+			edits: vec![SemanticTokensEdit {
+				start: 10,
+				delete_count: 0,
+				data: Some(vec![token(1, 0, 2, 0, 0)]),
+			}],
+		});
+		assert_eq!(ctl.result_id(), Some("2"), "partial deltas keep the result ID");
+		assert_eq!(positions(&ctl.tokens()).last(), Some(&(1, 0, "keyword")));
+	}
+}
@@ -4,6 +4,8 @@
 //! * `HookEvent` enum
 //! * `HookEventData<'a>` enum with borrowed payloads
 //! * `OwnedHookContext` enum with owned payloads
+//! * `HookEventData::path` / `HookEventData::file_type` accessors for hook filters
+//! * `OwnedHookContext::with_event_data` for re-running sync handlers on owned payloads
 //! * `__hook_extract!` macro for sync parameter extraction
 //! * `__async_hook_extract!` macro for async parameter extraction
 
@@ -111,6 +113,19 @@ fn owned_type(ty: &Ident) -> TokenStream2 {
 }
 
 /// Generates the conversion expression for borrowed -> owned.
+/// Borrows an owned payload field back as its `HookEventData` type.
+///
+/// `RopeSlice` fields are rebuilt into a local `Rope` binding first (see [`define_events`]).
+fn borrowed_value(ty: &Ident, field: &Ident) -> TokenStream2 {
+	match ty.to_string().as_str() {
+		"Path" => quote! { #field.as_path() },
+		"RopeSlice" => quote! { #field.slice(..) },
+		"OptionStr" => quote! { #field.as_deref() },
+		"Str" => quote! { #field.as_str() },
+		_ => quote! { #field.clone() },
+	}
+}
+
 fn owned_value(ty: &Ident, field: &Ident) -> TokenStream2 {
 	let ty_str = ty.to_string();
 	match ty_str.as_str() {
@@ -267,6 +282,60 @@ pub fn define_events(input: TokenStream) -> TokenStream {
 		})
 		.collect();
 
+	// Generate OwnedHookContext::with_event_data() match arms
+	let with_event_data_arms: Vec<_> = events
+		.iter()
+		.map(|e| {
+			let name = &e.name;
+			if e.fields.is_empty() {
+				return quote! { OwnedHookContext::#name => f(HookEventData::#name) };
+			}
+			let field_names: Vec<_> = e.fields.iter().map(|f| &f.name).collect();
+			let ropes: Vec<_> = e
+				.fields
+				.iter()
+				.filter(|f| f.ty == "RopeSlice")
+				.map(|f| {
+					let fname = &f.name;
+					quote! { let #fname = ::xeno_primitives::Rope::from_str(#fname); }
+				})
+				.collect();
+			let borrows: Vec<_> = e
+				.fields
+				.iter()
+				.map(|f| {
+					let fname = &f.name;
+					let conv = borrowed_value(&f.ty, fname);
+					quote! { #fname: #conv }
+				})
+				.collect();
+			quote! {
+				OwnedHookContext::#name { #(#field_names),* } => {
+					#(#ropes)*
+					f(HookEventData::#name { #(#borrows),* })
+				}
+			}
+		})
+		.collect();
+
+	// Generate HookEventData::path() / file_type() match arms for events carrying those fields
+	let path_arms: Vec<_> = events
+		.iter()
+		.filter(|e| e.fields.iter().any(|f| f.name == "path" && f.ty == "Path"))
+		.map(|e| {
+			let name = &e.name;
+			quote! { HookEventData::#name { path, .. } => Some(*path) }
+		})
+		.collect();
+	let file_type_arms: Vec<_> = events
+		.iter()
+		.filter(|e| e.fields.iter().any(|f| f.name == "file_type" && f.ty == "OptionStr"))
+		.map(|e| {
+			let name = &e.name;
+			quote! { HookEventData::#name { file_type, .. } => *file_type }
+		})
+		.collect();
+
 	// Generate __hook_extract! macro arms
 	// Use $crate:: which resolves to the invoking crate
 	let hook_extract_arms: Vec<_> = events
@@ -351,6 +420,22 @@ pub fn define_events(input: TokenStream) -> TokenStream {
 				}
 			}
 
+			/// Returns the buffer path carried by this event, if any.
+			pub fn path(&self) -> Option<&'a ::std::path::Path> {
+				match self {
+					#(#path_arms,)*
+					_ => None,
+				}
+			}
+
+			/// Returns the buffer file type carried by this event, if any.
+			pub fn file_type(&self) -> Option<&'a str> {
+				match self {
+					#(#file_type_arms,)*
+					_ => None,
+				}
+			}
+
 			/// Creates an owned version of this event data for use in async hooks.
 			pub fn to_owned(&self) -> OwnedHookContext {
 				OwnedHookContext::from(self)
@@ -378,6 +463,15 @@ pub fn define_events(input: TokenStream) -> TokenStream {
 					#(#owned_event_arms),*
 				}
 			}
+
+			/// Calls `f` with this payload borrowed as [`HookEventData`].
+			///
+			/// Text payloads are rebuilt into a temporary rope for the duration of the call.
+			pub fn with_event_data<R>(&self, f: impl FnOnce(HookEventData<'_>) -> R) -> R {
+				match self {
+					#(#with_event_data_arms),*
+				}
+			}
 		}
 
 		/// Extracts event parameters in sync hook handlers.
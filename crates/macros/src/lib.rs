@@ -82,10 +82,13 @@ pub fn define_events(input: TokenStream) -> TokenStream {
 /// * `key = "key-name"` - Required: configuration key
 /// * `scope = global | buffer` - Required: Option scope
 /// * `priority = N` - Optional: Sort priority (default 0)
+/// * `validate = name` - Optional: validator from `xeno_registry::options::validators`
+/// * `choices = ["a", "b"]` - Optional: allowed values for a string option
 ///
 /// # Supported Types
 ///
 /// * `i64` → `OptionType::Int`
+/// * `f64` → `OptionType::Float`
 /// * `bool` → `OptionType::Bool`
 /// * `String` → `OptionType::String`
 /// * `&'static str` → `OptionType::String` (converted to owned)
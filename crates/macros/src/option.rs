@@ -25,7 +25,8 @@ use syn::{Expr, Item, Lit, Meta, parse_macro_input};
 /// * `TAB_WIDTH` constant as `TypedOptionKey<i64>`
 ///
 /// The `validate` attribute is optional and references a validator function
-/// from `xeno_registry::options::validators`.
+/// from `xeno_registry::options::validators`. String options may also declare
+/// `choices = ["absolute", "relative", "none"]` to restrict accepted values.
 pub fn derive_option(input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as Item);
 
@@ -43,6 +44,7 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 	let mut scope: Option<syn::Ident> = None;
 	let mut priority: Option<i16> = None;
 	let mut validator: Option<syn::Ident> = None;
+	let mut choices: Option<Vec<syn::LitStr>> = None;
 
 	if let Err(e) = option_attr.parse_nested_meta(|meta| {
 		if meta.path.is_ident("key") {
@@ -65,6 +67,18 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 			let ident: syn::Ident = meta.value()?.parse()?;
 			validator = Some(ident);
 			Ok(())
+		} else if meta.path.is_ident("choices") {
+			let array: syn::ExprArray = meta.value()?.parse()?;
+			let lits = array
+				.elems
+				.iter()
+				.map(|elem| match elem {
+					Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) => Ok(lit.clone()),
+					other => Err(syn::Error::new_spanned(other, "choices must be string literals")),
+				})
+				.collect::<syn::Result<Vec<_>>>()?;
+			choices = Some(lits);
+			Ok(())
 		} else {
 			Err(meta.error("unknown option attribute"))
 		}
@@ -101,15 +115,25 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 	let (option_type, value_wrapper, key_type): (_, _, syn::Type) = match ty_str.as_str() {
 		"i64" => (format_ident!("Int"), format_ident!("Int"), syn::parse_quote!(i64)),
 		"bool" => (format_ident!("Bool"), format_ident!("Bool"), syn::parse_quote!(bool)),
+		"f64" => (format_ident!("Float"), format_ident!("Float"), syn::parse_quote!(f64)),
 		"String" => (format_ident!("String"), format_ident!("String"), syn::parse_quote!(String)),
 		"& 'static str" | "&'static str" => (format_ident!("String"), format_ident!("String"), syn::parse_quote!(String)),
 		_ => {
-			return syn::Error::new_spanned(ty, format!("unsupported option type: {ty_str}. Supported: i64, bool, String, &'static str"))
-				.to_compile_error()
-				.into();
+			return syn::Error::new_spanned(
+				ty,
+				format!("unsupported option type: {ty_str}. Supported: i64, f64, bool, String, &'static str"),
+			)
+			.to_compile_error()
+			.into();
 		}
 	};
 
+	if choices.is_some() && option_type != "String" {
+		return syn::Error::new_spanned(option_attr, "'choices' requires a String or &'static str option")
+			.to_compile_error()
+			.into();
+	}
+
 	let default_expr = &item.expr;
 	let default_value = if ty_str.contains("str") {
 		quote! { (#default_expr).to_string() }
@@ -149,6 +173,11 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 		None => quote! { None },
 	};
 
+	let choices_expr = match choices {
+		Some(choices) => quote! { Some(&[#(#choices),*]) },
+		None => quote! { None },
+	};
+
 	let expanded = quote! {
 		#[allow(non_upper_case_globals)]
 		pub(crate) static #internal_static: ::xeno_registry::options::OptionDef =
@@ -157,10 +186,12 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 			id: ::core::concat!(::core::env!("CARGO_PKG_NAME"), "::", ::core::stringify!(#name)),
 			name: ::core::stringify!(#name),
 			keys: &[],
+			categories: &[],
 			description: #description,
 			priority: #priority,
 			source: ::xeno_registry::options::RegistrySource::Crate(::core::env!("CARGO_PKG_NAME")),
 			mutates_buffer: false,
+			deprecated: None,
 			flags: 0,
 		},
 		key: #key,
@@ -168,6 +199,7 @@ pub fn derive_option(input: TokenStream) -> TokenStream {
 		default: ::xeno_registry::options::OptionDefault::#value_wrapper(|| #default_value),
 		scope: ::xeno_registry::options::OptionScope::#scope_variant,
 		validator: #validator_expr,
+		choices: #choices_expr,
 	};
 
 		::inventory::submit! { ::xeno_registry::options::builtins::OptionReg(&#internal_static) }
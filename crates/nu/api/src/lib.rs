@@ -8,7 +8,7 @@ use std::fmt;
 
 pub use xeno_nu_data::{NuRecord, NuSpan, NuType, NuValue, Record, Span, Value};
 pub use xeno_nu_runtime::host::{BufferMeta, HostError, LineColRange, TextChunk, XenoNuHost};
-pub use xeno_nu_runtime::{CallValidationError, CompileError, ExecError, ExportId, NuProgram, ProgramPolicy};
+pub use xeno_nu_runtime::{CallValidationError, CompileError, ExecError, ExportId, ExportStream, NuCommandFn, NuProgram, ProgramPolicy, StableExportId};
 
 /// Error emitted while parsing NUON source.
 #[derive(Debug, Clone)]
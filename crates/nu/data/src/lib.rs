@@ -5,6 +5,7 @@
 //! effects/config parsing and provides explicit conversions to/from the
 //! vendored Nu value types.
 
+use std::collections::HashMap;
 use std::fmt;
 
 use indexmap::IndexMap;
@@ -90,6 +91,35 @@ impl Record {
 	pub fn iter(&self) -> RecordIter<'_> {
 		RecordIter { inner: self.inner.iter() }
 	}
+
+	/// Converts into a [`HashMap`], mapping each value through `map_fn`.
+	///
+	/// Keys are already unique, so the only failure is the first `map_fn`
+	/// error in field order. Field order itself is lost.
+	pub fn try_into_hashmap<V, E, F>(self, map_fn: F) -> Result<HashMap<String, V>, E>
+	where
+		F: Fn(Value) -> Result<V, E>,
+	{
+		self.inner.into_iter().map(|(key, value)| Ok((key, map_fn(value)?))).collect()
+	}
+
+	/// Builds a record from a [`HashMap`], ordering fields by key so the
+	/// result is deterministic.
+	///
+	/// Panics like [`push`](Self::push) if two keys convert to the same string.
+	pub fn from_hashmap<K, V>(map: HashMap<K, V>) -> Self
+	where
+		K: Into<String>,
+		V: Into<Value>,
+	{
+		let mut fields: Vec<(String, Value)> = map.into_iter().map(|(key, value)| (key.into(), value.into())).collect();
+		fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+		let mut record = Self::with_capacity(fields.len());
+		for (key, value) in fields {
+			record.push(key, value);
+		}
+		record
+	}
 }
 
 pub struct RecordIter<'a> {
@@ -259,6 +289,60 @@ impl Value {
 			other => Err(other),
 		}
 	}
+
+	/// Merges `overlay` into `base` according to `strategy`.
+	///
+	/// Both inputs must be [`Value::Record`]; anything else is rejected with a
+	/// [`ValueTypeError`] naming the offending type. The result keeps `base`'s
+	/// span and field order, with fields only present in `overlay` appended in
+	/// overlay order.
+	pub fn merge_records(base: Value, overlay: Value, strategy: MergeStrategy) -> Result<Value, ValueTypeError> {
+		let span = base.span();
+		let base = base.into_record().map_err(|other| ValueTypeError::new("record", other.get_type()))?;
+		let overlay = overlay.into_record().map_err(|other| ValueTypeError::new("record", other.get_type()))?;
+		Ok(Value::record(merge_record_fields(base, overlay, strategy), span))
+	}
+}
+
+/// Conflict resolution for [`Value::merge_records`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeStrategy {
+	/// Overlay fields replace base fields wholesale (shallow merge).
+	Override,
+	/// Nested records are merged recursively; other conflicts take the overlay value.
+	DeepMerge,
+	/// Like [`DeepMerge`](Self::DeepMerge), but list fields present on both
+	/// sides are concatenated (base first) instead of replaced.
+	Append,
+}
+
+fn merge_record_fields(mut base: Record, overlay: Record, strategy: MergeStrategy) -> Record {
+	for (key, incoming) in overlay.inner {
+		match base.inner.get_mut(&key) {
+			Some(slot) => {
+				let existing = std::mem::replace(slot, Value::nothing(Span::unknown()));
+				*slot = merge_field(existing, incoming, strategy);
+			}
+			None => {
+				base.inner.insert(key, incoming);
+			}
+		}
+	}
+	base
+}
+
+fn merge_field(existing: Value, incoming: Value, strategy: MergeStrategy) -> Value {
+	match (existing, incoming, strategy) {
+		(_, incoming, MergeStrategy::Override) => incoming,
+		(Value::Record { val: base, internal_span }, Value::Record { val: overlay, .. }, strategy) => {
+			Value::record(merge_record_fields(base, overlay, strategy), internal_span)
+		}
+		(Value::List { mut vals, internal_span }, Value::List { vals: more, .. }, MergeStrategy::Append) => {
+			vals.extend(more);
+			Value::list(vals, internal_span)
+		}
+		(_, incoming, _) => incoming,
+	}
 }
 
 /// Coarse value type used for diagnostics.
@@ -404,3 +488,6 @@ impl From<Value> for xeno_nu_protocol::Value {
 pub type NuValue = Value;
 pub type NuRecord = Record;
 pub type NuSpan = Span;
+
+#[cfg(test)]
+mod tests;
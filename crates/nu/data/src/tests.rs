@@ -0,0 +1,155 @@
+use super::*;
+
+fn record(fields: Vec<(&str, Value)>) -> Value {
+	let mut rec = Record::new();
+	for (key, value) in fields {
+		rec.push(key, value);
+	}
+	Value::test_record(rec)
+}
+
+fn field<'a>(value: &'a Value, path: &[&str]) -> &'a Value {
+	path.iter().fold(value, |v, key| {
+		v.as_record().expect("record").get(key).unwrap_or_else(|| panic!("missing field '{key}'"))
+	})
+}
+
+#[test]
+fn merge_override_replaces_nested_records_wholesale() {
+	let base = record(vec![
+		("a", Value::test_int(1)),
+		("nested", record(vec![("x", Value::test_int(1)), ("y", Value::test_int(2))])),
+	]);
+	let overlay = record(vec![("nested", record(vec![("x", Value::test_int(10))])), ("b", Value::test_int(2))]);
+
+	let merged = Value::merge_records(base, overlay, MergeStrategy::Override).unwrap();
+	let rec = merged.as_record().unwrap();
+	assert_eq!(rec.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>(), ["a", "nested", "b"]);
+	assert_eq!(field(&merged, &["nested", "x"]).as_int().unwrap(), 10);
+	assert!(field(&merged, &["nested"]).as_record().unwrap().get("y").is_none());
+}
+
+#[test]
+fn merge_deep_resolves_innermost_conflict_across_three_levels() {
+	let base = record(vec![(
+		"l1",
+		record(vec![
+			("keep1", Value::test_string("base")),
+			(
+				"l2",
+				record(vec![
+					("keep2", Value::test_int(2)),
+					("l3", record(vec![("keep3", Value::test_bool(true)), ("conflict", Value::test_string("base"))])),
+				]),
+			),
+		]),
+	)]);
+	let overlay = record(vec![(
+		"l1",
+		record(vec![(
+			"l2",
+			record(vec![(
+				"l3",
+				record(vec![("conflict", Value::test_string("overlay")), ("added", Value::test_int(3))]),
+			)]),
+		)]),
+	)]);
+
+	let merged = Value::merge_records(base, overlay, MergeStrategy::DeepMerge).unwrap();
+	assert_eq!(field(&merged, &["l1", "keep1"]).as_str().unwrap(), "base");
+	assert_eq!(field(&merged, &["l1", "l2", "keep2"]).as_int().unwrap(), 2);
+	assert!(field(&merged, &["l1", "l2", "l3", "keep3"]).as_bool().unwrap());
+	assert_eq!(field(&merged, &["l1", "l2", "l3", "conflict"]).as_str().unwrap(), "overlay");
+	assert_eq!(field(&merged, &["l1", "l2", "l3", "added"]).as_int().unwrap(), 3);
+}
+
+#[test]
+fn merge_deep_replaces_lists_and_mismatched_types() {
+	let base = record(vec![
+		("list", Value::test_list(vec![Value::test_int(1)])),
+		("shape", record(vec![("x", Value::test_int(1))])),
+	]);
+	let overlay = record(vec![
+		("list", Value::test_list(vec![Value::test_int(2)])),
+		("shape", Value::test_string("flat")),
+	]);
+
+	let merged = Value::merge_records(base, overlay, MergeStrategy::DeepMerge).unwrap();
+	assert_eq!(field(&merged, &["list"]).as_list().unwrap(), [Value::test_int(2)]);
+	assert_eq!(field(&merged, &["shape"]).as_str().unwrap(), "flat");
+}
+
+#[test]
+fn merge_append_concatenates_lists_including_nested() {
+	let base = record(vec![
+		("hooks", Value::test_list(vec![Value::test_string("a")])),
+		("nested", record(vec![("items", Value::test_list(vec![Value::test_int(1)]))])),
+		("name", Value::test_string("base")),
+	]);
+	let overlay = record(vec![
+		("hooks", Value::test_list(vec![Value::test_string("b"), Value::test_string("c")])),
+		("nested", record(vec![("items", Value::test_list(vec![Value::test_int(2)]))])),
+		("name", Value::test_string("overlay")),
+	]);
+
+	let merged = Value::merge_records(base, overlay, MergeStrategy::Append).unwrap();
+	assert_eq!(
+		field(&merged, &["hooks"]).as_list().unwrap(),
+		[Value::test_string("a"), Value::test_string("b"), Value::test_string("c")]
+	);
+	assert_eq!(
+		field(&merged, &["nested", "items"]).as_list().unwrap(),
+		[Value::test_int(1), Value::test_int(2)]
+	);
+	assert_eq!(field(&merged, &["name"]).as_str().unwrap(), "overlay");
+}
+
+#[test]
+fn merge_rejects_non_record_inputs() {
+	let rec = record(vec![("a", Value::test_int(1))]);
+
+	let err = Value::merge_records(Value::test_int(1), rec.clone(), MergeStrategy::Override).unwrap_err();
+	assert_eq!(err, ValueTypeError::new("record", NuType::Int));
+
+	let err = Value::merge_records(rec, Value::test_list(vec![]), MergeStrategy::DeepMerge).unwrap_err();
+	assert_eq!(err.to_string(), "expected record, got list");
+}
+
+#[test]
+fn from_hashmap_orders_fields_by_key() {
+	let map = HashMap::from([("zeta", Value::test_int(3)), ("alpha", Value::test_int(1)), ("mid", Value::test_int(2))]);
+	let record = Record::from_hashmap(map);
+	let keys: Vec<&str> = record.iter().map(|(key, _)| key.as_str()).collect();
+	assert_eq!(keys, ["alpha", "mid", "zeta"]);
+
+	let back = record.try_into_hashmap(|value| value.as_int()).expect("all ints");
+	assert_eq!(back, HashMap::from([("alpha".to_string(), 1), ("mid".to_string(), 2), ("zeta".to_string(), 3)]));
+}
+
+#[test]
+fn try_into_hashmap_reports_first_mapping_error() {
+	let mut record = Record::new();
+	record.push("a", Value::test_int(1));
+	record.push("b", Value::test_string("two"));
+	record.push("c", Value::test_bool(true));
+
+	let err = record.try_into_hashmap(|value| value.as_int()).expect_err("non-int fields");
+	assert_eq!(err, ValueTypeError::new("int", NuType::String));
+}
+
+#[test]
+fn hashmap_conversions_handle_empty_maps() {
+	let record = Record::from_hashmap(HashMap::<String, Value>::new());
+	assert!(record.is_empty());
+	let map = record.try_into_hashmap(Ok::<_, ConversionError>).expect("empty");
+	assert!(map.is_empty());
+}
+
+#[test]
+fn duplicate_keys_are_rejected_before_hashmap_conversion() {
+	let mut nu = xeno_nu_protocol::Record::new();
+	nu.push("key", xeno_nu_protocol::Value::test_int(1));
+	nu.push("key", xeno_nu_protocol::Value::test_int(2));
+
+	assert_eq!(Record::try_from(nu), Err(ConversionError::DuplicateKey("key".to_string())));
+}
@@ -4,6 +4,15 @@
 //! * compilation (`NuProgram::compile_*`) under an explicit policy
 //! * execution (`NuProgram::call_export*`, `NuProgram::execute_root`)
 //!
+//! [`NuProgram::call_export_stream`] is the non-collecting variant of
+//! `call_export` for exports that return large lists.
+//!
+//! Two export handle flavours exist. [`ExportId`] wraps the raw decl id and is
+//! only meaningful for the program that produced it; persist it with
+//! [`ExportId::to_stable_string`] instead. [`StableExportId`] hashes
+//! the export name and signature, survives recompilation, and is re-resolved
+//! through the name table on every call.
+//!
 //! The facade wraps vendored Nu internals used for `xeno.nu` and `config.nu`
 //! while enforcing the sandboxed evaluation environment.
 #![allow(clippy::result_large_err, reason = "ShellError is intentionally rich and shared across Nu runtime APIs")]
@@ -20,13 +29,20 @@ use std::sync::Arc;
 use xeno_nu_data::Value;
 use xeno_nu_protocol::ast::Block;
 use xeno_nu_protocol::engine::EngineState;
-use xeno_nu_protocol::{DeclId, Value as ProtocolValue};
+use xeno_nu_protocol::{DeclId, PipelineIterator, Value as ProtocolValue};
 
 const SCRIPT_FILE_NAME: &str = "xeno.nu";
 
 /// Hard limit on script/source size to prevent DoS via pathological input.
 const MAX_SCRIPT_BYTES: usize = 512 * 1024;
 
+/// Host-side handler for a command injected with [`NuProgram::add_command`]
+/// or [`NuProgram::compile_source_with_commands`].
+///
+/// Receives the positional arguments and the caller's environment; an `Err`
+/// message surfaces as a Nu runtime error.
+pub type NuCommandFn = fn(args: Vec<Value>, env: &HashMap<String, Value>) -> Result<Value, String>;
+
 /// Stable identifier for a compiled Nu export declaration.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ExportId(usize);
@@ -40,6 +56,20 @@ impl ExportId {
 		self.0
 	}
 
+	/// Returns a string naming this export that can be persisted across reloads.
+	///
+	/// The string is the export's name, so it is only meaningful to programs
+	/// that still export a function by that name. Recompiling identical source
+	/// always yields the same string, even though the raw decl id may change.
+	/// Returns `None` when `self` is not an export of `program`.
+	pub fn to_stable_string(self, program: &NuProgram) -> Option<String> {
+		let decl_id = self.to_decl_id();
+		program
+			.export_decls
+			.contains(&decl_id)
+			.then(|| program.engine_state.get_decl(decl_id).name().to_string())
+	}
+
 	fn from_decl_id(decl_id: DeclId) -> Self {
 		Self(decl_id.get())
 	}
@@ -55,6 +85,44 @@ impl fmt::Debug for ExportId {
 	}
 }
 
+/// Recompilation-stable handle for a Nu export.
+///
+/// The hash covers the export name plus its rendered signature (positionals,
+/// rest, flags) using FNV-1a, so it is deterministic across processes. Calls via
+/// [`NuProgram::call_stable`] map through the program's name table, so decl
+/// renumbering after a recompile does not invalidate cached handles. A changed
+/// signature yields a different hash and is reported as a missing export.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StableExportId {
+	hash: u64,
+	name: Arc<str>,
+}
+
+impl StableExportId {
+	/// Returns the name+signature hash.
+	pub fn hash(&self) -> u64 {
+		self.hash
+	}
+
+	/// Returns the export name this handle was resolved from.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+}
+
+/// Export names that differ between two compilations, from [`NuProgram::diff_exports`].
+///
+/// Each list is sorted by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportDiff {
+	/// Exports only the newer program defines.
+	pub added: Vec<String>,
+	/// Exports only the older program defines.
+	pub removed: Vec<String>,
+	/// Exports both programs define, whether or not their signatures match.
+	pub unchanged: Vec<String>,
+}
+
 /// Compilation policy describing allowed top-level constructs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProgramPolicy {
@@ -103,6 +171,7 @@ pub enum CallValidationError {
 	EnvKeyTooLong { len: usize, max: usize },
 	EnvValueTooComplex { nodes: usize, max: usize },
 	EnvStringTooLong { len: usize, max: usize },
+	RecursiveValue { depth: usize, max: usize },
 }
 
 impl fmt::Display for CallValidationError {
@@ -114,6 +183,7 @@ impl fmt::Display for CallValidationError {
 			Self::EnvKeyTooLong { len, max } => write!(f, "Nu call error: env key length {len} exceeds limit of {max}"),
 			Self::EnvValueTooComplex { nodes, max } => write!(f, "Nu call error: env value traversal ({nodes} nodes) exceeds limit of {max}"),
 			Self::EnvStringTooLong { len, max } => write!(f, "Nu call error: env string length {len} exceeds limit of {max}"),
+			Self::RecursiveValue { depth, max } => write!(f, "Nu call error: env value nesting depth {depth} exceeds limit of {max}"),
 		}
 	}
 }
@@ -150,7 +220,13 @@ pub struct NuProgram {
 	export_decls: Arc<HashSet<DeclId>>,
 	/// Export name → DeclId lookup for `resolve_export`.
 	export_names: Arc<HashMap<String, DeclId>>,
+	/// Export name → name+signature hash for `resolve_stable`/`call_stable`.
+	export_hashes: Arc<HashMap<String, u64>>,
 	root_block: Option<Arc<Block>>,
+	/// Parse of the source this program was compiled from, reused by [`Self::reload_macro_from_dir`].
+	parse_cache: Option<sandbox::ParseCache>,
+	/// Host commands registered before parsing, re-registered on reload.
+	host_commands: Arc<[(&'static str, NuCommandFn)]>,
 }
 
 impl fmt::Debug for NuProgram {
@@ -166,18 +242,27 @@ impl fmt::Debug for NuProgram {
 impl NuProgram {
 	/// Compile `xeno.nu` from a config directory with macro-module policy.
 	pub fn compile_macro_from_dir(config_dir: &Path) -> Result<Self, CompileError> {
-		let script_path = config_dir.join(SCRIPT_FILE_NAME);
-		let metadata = std::fs::metadata(&script_path).map_err(|error| CompileError::Io(format!("failed to read {}: {error}", script_path.display())))?;
-		if metadata.len() as usize > MAX_SCRIPT_BYTES {
-			return Err(CompileError::Parse(format!("Nu runtime error: script exceeds {} byte limit", MAX_SCRIPT_BYTES)));
-		}
-
-		let script_src =
-			std::fs::read_to_string(&script_path).map_err(|error| CompileError::Io(format!("failed to read {}: {error}", script_path.display())))?;
-
+		let (script_path, script_src) = read_macro_script(config_dir)?;
 		Self::compile_source(config_dir, &script_path, &script_src, ProgramPolicy::ModuleWrapped)
 	}
 
+	/// Recompile `xeno.nu` from a config directory, reusing this program's
+	/// parse when the script and its parse inputs are unchanged.
+	///
+	/// Host commands of this program, including ones added with
+	/// [`Self::add_command`], are registered before the script is reparsed.
+	pub fn reload_macro_from_dir(&self, config_dir: &Path) -> Result<Self, CompileError> {
+		let (script_path, script_src) = read_macro_script(config_dir)?;
+		Self::compile_source_opt(
+			Some(config_dir),
+			&script_path,
+			&script_src,
+			ProgramPolicy::ModuleWrapped,
+			Some(self),
+			&self.host_commands,
+		)
+	}
+
 	/// Compile a macro module source blob as if it were `xeno.nu`.
 	pub fn compile_macro_source(config_dir: &Path, script_path: &Path, script_src: &str) -> Result<Self, CompileError> {
 		Self::compile_source(config_dir, script_path, script_src, ProgramPolicy::ModuleWrapped)
@@ -187,22 +272,62 @@ impl NuProgram {
 	pub fn compile_config_script(fname: &str, source: &str, config_root: Option<&Path>) -> Result<Self, CompileError> {
 		let script_path = PathBuf::from(fname);
 		let root = config_root.map(Path::to_path_buf);
-		Self::compile_source_opt(root.as_deref(), &script_path, source, ProgramPolicy::ConfigScript)
+		Self::compile_source_opt(root.as_deref(), &script_path, source, ProgramPolicy::ConfigScript, None, &[])
 	}
 
 	/// Compile source using an explicit policy.
 	pub fn compile_source(config_dir: &Path, script_path: &Path, source: &str, policy: ProgramPolicy) -> Result<Self, CompileError> {
-		Self::compile_source_opt(Some(config_dir), script_path, source, policy)
+		Self::compile_source_opt(Some(config_dir), script_path, source, policy, None, &[])
+	}
+
+	/// Like [`Self::compile_source`], but registers `commands` as host commands
+	/// before parsing so `source` can call them.
+	///
+	/// Fails like [`Self::add_command`] if a name is reserved or taken.
+	pub fn compile_source_with_commands(
+		config_dir: &Path,
+		script_path: &Path,
+		source: &str,
+		policy: ProgramPolicy,
+		commands: &[(&'static str, NuCommandFn)],
+	) -> Result<Self, CompileError> {
+		Self::compile_source_opt(Some(config_dir), script_path, source, policy, None, commands)
 	}
 
-	fn compile_source_opt(config_dir: Option<&Path>, script_path: &Path, source: &str, policy: ProgramPolicy) -> Result<Self, CompileError> {
+	/// Compiles `source` after registering `host_commands`, reusing the engine
+	/// state and parse of `previous` when its parse cache matches the source
+	/// and parse inputs.
+	fn compile_source_opt(
+		config_dir: Option<&Path>,
+		script_path: &Path,
+		source: &str,
+		policy: ProgramPolicy,
+		previous: Option<&Self>,
+		host_commands: &[(&'static str, NuCommandFn)],
+	) -> Result<Self, CompileError> {
 		if source.len() > MAX_SCRIPT_BYTES {
 			return Err(CompileError::Parse(format!("Nu runtime error: script exceeds {} byte limit", MAX_SCRIPT_BYTES)));
 		}
 
-		let mut engine_state = sandbox::create_engine_state(config_dir).map_err(CompileError::Parse)?;
 		let fname = script_path.to_string_lossy().to_string();
-		let parsed = sandbox::parse_and_validate_with_policy(&mut engine_state, &fname, source, config_dir, policy.parse_policy())
+		let parse_policy = policy.parse_policy();
+		let reusable = previous.filter(|previous| {
+			previous
+				.parse_cache
+				.as_ref()
+				.is_some_and(|cache| cache.matches(&fname, source, config_dir, parse_policy))
+		});
+		let (mut engine_state, mut parse_cache) = match reusable {
+			Some(previous) => (EngineState::clone(&previous.engine_state), previous.parse_cache.clone()),
+			None => {
+				let mut engine_state = sandbox::create_engine_state(config_dir).map_err(CompileError::Parse)?;
+				for &(name, handler) in host_commands {
+					sandbox::register_host_command(&mut engine_state, name, handler).map_err(CompileError::Parse)?;
+				}
+				(engine_state, None)
+			}
+		};
+		let parsed = sandbox::parse_and_validate_incremental(&mut engine_state, &fname, source, config_dir, parse_policy, &mut parse_cache)
 			.map_err(|e| CompileError::Parse(add_prelude_removal_hint(&e)))?;
 
 		let root_block = (policy == ProgramPolicy::ConfigScript).then_some(parsed.block.clone());
@@ -216,6 +341,10 @@ impl NuProgram {
 				(name, id)
 			})
 			.collect();
+		let export_hash_map: HashMap<String, u64> = export_name_map
+			.iter()
+			.map(|(name, &id)| (name.clone(), stable_export_hash(name, &engine_state.get_decl(id).signature())))
+			.collect();
 
 		Ok(Self {
 			policy,
@@ -224,7 +353,10 @@ impl NuProgram {
 			engine_state: Arc::new(engine_state),
 			export_decls: Arc::new(export_decl_set),
 			export_names: Arc::new(export_name_map),
+			export_hashes: Arc::new(export_hash_map),
 			root_block,
+			parse_cache,
+			host_commands: host_commands.into(),
 		})
 	}
 
@@ -246,6 +378,45 @@ impl NuProgram {
 		self.export_names.get(name).map(|&id| ExportId::from_decl_id(id))
 	}
 
+	/// Resolve an export from a string produced by [`ExportId::to_stable_string`].
+	pub fn resolve_stable_string(&self, stable: &str) -> Option<ExportId> {
+		self.resolve_export(stable)
+	}
+
+	/// Resolve a recompilation-stable handle for an export by name.
+	///
+	/// Only returns explicitly exported definitions, like [`Self::resolve_export`].
+	pub fn resolve_stable(&self, name: &str) -> Option<StableExportId> {
+		self.export_hashes.get(name).map(|&hash| StableExportId { hash, name: name.into() })
+	}
+
+	/// Call an export through a stable handle.
+	///
+	/// The handle is mapped through this program's name table at call time.
+	/// Returns [`ExecError::MissingExport`] naming the original export when it
+	/// no longer exists or its signature changed since the handle was resolved.
+	pub fn call_stable(
+		&self,
+		export: &StableExportId,
+		args: &[String],
+		env: &[(&str, Value)],
+		host: Option<&(dyn host::XenoNuHost + 'static)>,
+	) -> Result<Value, ExecError> {
+		let resolved = self.export_names.get(export.name()).copied();
+		let current_hash = self.export_hashes.get(export.name()).copied();
+		match (resolved, current_hash) {
+			(Some(decl_id), Some(hash)) if hash == export.hash => self.call_export(ExportId::from_decl_id(decl_id), args, env, host),
+			(Some(_), Some(_)) => Err(ExecError::MissingExport(format!(
+				"Nu runtime error: function '{}' changed signature since it was resolved",
+				export.name()
+			))),
+			_ => Err(ExecError::MissingExport(format!(
+				"Nu runtime error: function '{}' is not defined in xeno.nu",
+				export.name()
+			))),
+		}
+	}
+
 	/// Call a pre-resolved export.
 	pub fn call_export(
 		&self,
@@ -282,6 +453,18 @@ impl NuProgram {
 		Value::try_from(value).map_err(|error| ExecError::Runtime(format!("Nu runtime error: {error}")))
 	}
 
+	/// Call a pre-resolved export without collecting its output.
+	///
+	/// List results are yielded item by item as the returned [`ExportStream`]
+	/// is advanced; a scalar result yields a single item. No host is installed,
+	/// so host-backed commands fail when evaluated lazily.
+	pub fn call_export_stream(&self, export: ExportId, args: &[String], env: &[(&str, Value)]) -> Result<ExportStream, ExecError> {
+		let decl_id = self.checked_decl_id(export)?;
+		let env = env.iter().map(|(key, value)| (*key, ProtocolValue::from(value.clone()))).collect::<Vec<_>>();
+		let data = sandbox::call_function_stream(&self.engine_state, decl_id, args, &env).map_err(map_sandbox_err)?;
+		Ok(ExportStream { inner: data.into_iter() })
+	}
+
 	/// Resolve and call an export by name.
 	pub fn call_export_name(
 		&self,
@@ -307,6 +490,36 @@ impl NuProgram {
 		Value::try_from(value).map_err(|error| ExecError::Runtime(format!("Nu runtime error: {error}")))
 	}
 
+	/// Registers a host-side command callable as `name` from Nu source.
+	///
+	/// Nu resolves command names at parse time, so the command is visible to
+	/// source parsed after registration (via [`Self::eval_source`] or
+	/// [`Self::reload_macro_from_dir`]), not to blocks already compiled into
+	/// this program; pass commands the program's own source calls to
+	/// [`Self::compile_source_with_commands`] instead. Fails if `name` is in
+	/// the reserved `xeno` namespace or already names a command.
+	pub fn add_command(&mut self, name: &'static str, handler: NuCommandFn) -> Result<(), CompileError> {
+		sandbox::register_host_command(Arc::make_mut(&mut self.engine_state), name, handler).map_err(CompileError::Parse)?;
+		self.host_commands = self.host_commands.iter().copied().chain([(name, handler)]).collect();
+		Ok(())
+	}
+
+	/// Parses and evaluates a script snippet against this program's engine state.
+	///
+	/// The snippet is validated with the config-script sandbox policy and sees
+	/// host commands added via [`Self::add_command`]. It does not modify the
+	/// program.
+	pub fn eval_source(&self, fname: &str, source: &str) -> Result<Value, ExecError> {
+		if source.len() > MAX_SCRIPT_BYTES {
+			return Err(ExecError::Runtime(format!("Nu runtime error: script exceeds {} byte limit", MAX_SCRIPT_BYTES)));
+		}
+		let mut engine_state = EngineState::clone(&self.engine_state);
+		let parsed = sandbox::parse_and_validate_with_policy(&mut engine_state, fname, source, self.config_dir.as_deref(), sandbox::ParsePolicy::Script)
+			.map_err(ExecError::Runtime)?;
+		let value = sandbox::evaluate_block(&engine_state, parsed.block.as_ref()).map_err(ExecError::Runtime)?;
+		Value::try_from(value).map_err(|error| ExecError::Runtime(format!("Nu runtime error: {error}")))
+	}
+
 	/// Returns all exported definitions, sorted by name.
 	pub fn exports(&self) -> Vec<(String, ExportId)> {
 		let mut out: Vec<_> = self.export_names.iter().map(|(name, &id)| (name.clone(), ExportId::from_decl_id(id))).collect();
@@ -314,6 +527,33 @@ impl NuProgram {
 		out
 	}
 
+	/// Compares exports by name against `other`, a newer compilation such as
+	/// the result of reloading `xeno.nu`.
+	///
+	/// A rename shows up as one removed and one added export. Signature
+	/// changes are not detected; use [`StableExportId`] for that.
+	pub fn diff_exports(&self, other: &NuProgram) -> ExportDiff {
+		let mut diff = ExportDiff::default();
+		for name in self.export_names.keys() {
+			if other.export_names.contains_key(name) {
+				diff.unchanged.push(name.clone());
+			} else {
+				diff.removed.push(name.clone());
+			}
+		}
+		diff.added = other
+			.export_names
+			.keys()
+			.filter(|name| !self.export_names.contains_key(*name))
+			.cloned()
+			.collect();
+
+		diff.added.sort();
+		diff.removed.sort();
+		diff.unchanged.sort();
+		diff
+	}
+
 	fn checked_decl_id(&self, export: ExportId) -> Result<DeclId, ExecError> {
 		let decl_id = export.to_decl_id();
 		if !self.export_decls.contains(&decl_id) {
@@ -323,6 +563,76 @@ impl NuProgram {
 	}
 }
 
+/// Lazy output of [`NuProgram::call_export_stream`].
+///
+/// Item errors (including Nu error values) are yielded as [`ExecError::Runtime`]
+/// without ending the stream. Dropping the stream drains and discards any
+/// remaining items so the underlying pipeline runs to completion.
+pub struct ExportStream {
+	inner: PipelineIterator,
+}
+
+impl fmt::Debug for ExportStream {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ExportStream").finish_non_exhaustive()
+	}
+}
+
+impl Iterator for ExportStream {
+	type Item = Result<Value, ExecError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = match self.inner.next()? {
+			ProtocolValue::Error { error, .. } => return Some(Err(ExecError::Runtime(format!("Nu runtime error: {error}")))),
+			value => value,
+		};
+		Some(Value::try_from(value).map_err(|error| ExecError::Runtime(format!("Nu runtime error: {error}"))))
+	}
+}
+
+impl Drop for ExportStream {
+	fn drop(&mut self) {
+		self.inner.by_ref().for_each(drop);
+	}
+}
+
+/// Reads `xeno.nu` from `config_dir`, enforcing the script size limit.
+fn read_macro_script(config_dir: &Path) -> Result<(PathBuf, String), CompileError> {
+	let script_path = config_dir.join(SCRIPT_FILE_NAME);
+	let metadata = std::fs::metadata(&script_path).map_err(|error| CompileError::Io(format!("failed to read {}: {error}", script_path.display())))?;
+	if metadata.len() as usize > MAX_SCRIPT_BYTES {
+		return Err(CompileError::Parse(format!("Nu runtime error: script exceeds {} byte limit", MAX_SCRIPT_BYTES)));
+	}
+
+	let script_src = std::fs::read_to_string(&script_path).map_err(|error| CompileError::Io(format!("failed to read {}: {error}", script_path.display())))?;
+	Ok((script_path, script_src))
+}
+
+/// FNV-1a hash over export name and rendered signature.
+fn stable_export_hash(name: &str, signature: &xeno_nu_protocol::Signature) -> u64 {
+	let mut rendered = String::new();
+	rendered.push_str(name);
+	rendered.push('\0');
+	rendered.push_str(&signature.call_signature());
+	for flag in &signature.named {
+		rendered.push('\0');
+		rendered.push_str(&flag.long);
+	}
+
+	fnv1a(rendered.as_bytes())
+}
+
+/// FNV-1a hash of `bytes`.
+///
+/// Deliberately avoids `std::hash` so values stay stable across toolchains and
+/// process restarts.
+fn fnv1a(bytes: &[u8]) -> u64 {
+	const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+	const PRIME: u64 = 0x0100_0000_01b3;
+
+	bytes.iter().fold(OFFSET, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(PRIME))
+}
+
 fn map_sandbox_err(err: sandbox::SandboxCallError) -> ExecError {
 	match err {
 		sandbox::SandboxCallError::Validation(v) => ExecError::CallValidation(v),
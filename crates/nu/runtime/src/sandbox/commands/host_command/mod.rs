@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use xeno_nu_data::Value as DataValue;
+use xeno_nu_engine::CallExt;
+use xeno_nu_protocol::engine::{Call, Command, EngineState, Stack};
+use xeno_nu_protocol::{Category, PipelineData, ShellError, Signature, SyntaxShape, Type, Value};
+
+use super::err;
+use crate::NuCommandFn;
+
+/// Command backed by a host-side function registered via
+/// [`crate::NuProgram::add_command`].
+#[derive(Clone)]
+pub struct HostCommand {
+	pub name: &'static str,
+	pub handler: NuCommandFn,
+}
+
+impl Command for HostCommand {
+	fn name(&self) -> &str {
+		self.name
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::build(self.name)
+			.input_output_types(vec![(Type::Any, Type::Any)])
+			.rest("args", SyntaxShape::Any, "Arguments passed to the host handler")
+			.category(Category::Custom("host".into()))
+	}
+
+	fn description(&self) -> &str {
+		"Host-provided command"
+	}
+
+	fn run(&self, engine_state: &EngineState, stack: &mut Stack, call: &Call, _input: PipelineData) -> Result<PipelineData, ShellError> {
+		let span = call.head;
+		let args = call
+			.rest::<Value>(engine_state, stack, 0)?
+			.into_iter()
+			.map(|value| DataValue::try_from(value).map_err(|error| err(span, format!("{}: unsupported argument", self.name), error.to_string())))
+			.collect::<Result<Vec<_>, _>>()?;
+		// Env values of types the data model cannot carry (closures etc.) are
+		// not visible to host handlers.
+		let env: HashMap<String, DataValue> = stack
+			.get_env_vars(engine_state)
+			.into_iter()
+			.filter_map(|(key, value)| DataValue::try_from(value).ok().map(|value| (key, value)))
+			.collect();
+
+		let value = (self.handler)(args, &env).map_err(|msg| err(span, format!("{} failed", self.name), msg))?;
+		Ok(PipelineData::Value(Value::from(value), None))
+	}
+}
@@ -1,5 +1,6 @@
 //! Native Nu command declarations for typed effect constructors and builtins.
 
+mod host_command;
 mod xeno_assert;
 mod xeno_buffer_get;
 mod xeno_buffer_text;
@@ -8,9 +9,11 @@ mod xeno_ctx;
 mod xeno_effect;
 mod xeno_effects_normalize;
 mod xeno_is_effect;
+mod xeno_keybind;
 mod xeno_log;
 mod xeno_selection_get;
 
+pub(crate) use host_command::HostCommand;
 use xeno_nu_protocol::engine::StateWorkingSet;
 use xeno_nu_protocol::{ShellError, Span};
 
@@ -45,5 +48,6 @@ pub fn register_all(working_set: &mut StateWorkingSet<'_>) {
 	working_set.add_decl(Box::new(xeno_effect::XenoEffectCommand));
 	working_set.add_decl(Box::new(xeno_effects_normalize::XenoEffectsNormalizeCommand));
 	working_set.add_decl(Box::new(xeno_is_effect::XenoIsEffectCommand));
+	working_set.add_decl(Box::new(xeno_keybind::XenoKeybindCommand));
 	working_set.add_decl(Box::new(xeno_selection_get::XenoSelectionGetCommand));
 }
@@ -56,7 +56,7 @@ impl Command for XenoEffectsNormalizeCommand {
 	}
 }
 
-fn encode_effect(effect: NuEffect, span: xeno_nu_protocol::Span) -> Value {
+pub(super) fn encode_effect(effect: NuEffect, span: xeno_nu_protocol::Span) -> Value {
 	match effect {
 		NuEffect::Dispatch(invocation) => {
 			let mut rec = Record::new();
@@ -0,0 +1,73 @@
+use xeno_invocation::nu::NuEffect;
+use xeno_nu_data::Value as DataValue;
+use xeno_nu_engine::CallExt;
+use xeno_nu_protocol::engine::{Call, Command, EngineState, Stack};
+use xeno_nu_protocol::{Category, PipelineData, Record, ShellError, Signature, SyntaxShape, Type, Value};
+
+use super::xeno_effects_normalize::encode_effect;
+use super::{err, err_help};
+
+/// Mode used when `--mode` is omitted.
+const DEFAULT_MODE: &str = "normal";
+
+#[derive(Clone)]
+pub struct XenoKeybindCommand;
+
+impl Command for XenoKeybindCommand {
+	fn name(&self) -> &str {
+		"xeno keybind"
+	}
+
+	fn signature(&self) -> Signature {
+		Signature::build("xeno keybind")
+			.input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+			.required("key", SyntaxShape::String, "Key sequence, e.g. 'ctrl-s' or 'g r'")
+			.required("invocation", SyntaxShape::Any, "Dispatch effect record or envelope")
+			.named("mode", SyntaxShape::String, "Keymap mode (default: normal)", None)
+			.category(Category::Custom("xeno".into()))
+	}
+
+	fn description(&self) -> &str {
+		"Build a validated keybinding record { mode, key, invocation }."
+	}
+
+	fn search_terms(&self) -> Vec<&str> {
+		vec!["keymap", "bind", "key"]
+	}
+
+	fn run(&self, engine_state: &EngineState, stack: &mut Stack, call: &Call, _input: PipelineData) -> Result<PipelineData, ShellError> {
+		let span = call.head;
+		let key: String = call.req(engine_state, stack, 0)?;
+		let invocation: Value = call.req(engine_state, stack, 1)?;
+		let mode: Option<String> = call.get_flag(engine_state, stack, "mode")?;
+		let mode = mode.unwrap_or_else(|| DEFAULT_MODE.to_string());
+
+		if mode.is_empty() {
+			return Err(err(span, "xeno keybind: --mode must not be empty", "empty mode"));
+		}
+		if key.trim().is_empty() {
+			return Err(err(span, "xeno keybind: key must not be empty", "empty key"));
+		}
+		if let Err(e) = xeno_keymap_parser::parse_seq(&key) {
+			return Err(err_help(
+				span,
+				format!("xeno keybind: invalid key '{key}': {e}"),
+				format!("invalid key '{key}'"),
+				"keys use '-' between modifiers and spaces between sequence steps, e.g. 'ctrl-s' or 'g r'",
+			));
+		}
+
+		let field_path = format!("keys.{mode}.{key}");
+		let data = DataValue::try_from(invocation).map_err(|e| err(span, format!("xeno keybind: {e}"), "unsupported Nu value type for invocation"))?;
+		let decoded = xeno_invocation::nu::decode_single_dispatch_effect(&data, &field_path).map_err(|msg| err(span, format!("xeno keybind: {msg}"), msg))?;
+
+		let mut rec = Record::new();
+		rec.push("mode", Value::string(mode, span));
+		rec.push("key", Value::string(key, span));
+		rec.push("invocation", encode_effect(NuEffect::Dispatch(decoded), span));
+		Ok(PipelineData::Value(Value::record(rec, span), None))
+	}
+}
+
+#[cfg(test)]
+mod tests;
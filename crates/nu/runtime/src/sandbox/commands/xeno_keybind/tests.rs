@@ -0,0 +1,57 @@
+use crate::sandbox::{ParsePolicy, create_engine_state, evaluate_block, find_decl, parse_and_validate, parse_and_validate_with_policy};
+
+fn eval(source: &str) -> Result<xeno_nu_protocol::Value, String> {
+	let mut engine_state = create_engine_state(None).expect("engine state");
+	let parsed = parse_and_validate(&mut engine_state, "<test>", source, None).expect("should parse");
+	evaluate_block(&engine_state, parsed.block.as_ref())
+}
+
+#[test]
+fn xeno_keybind_returns_canonical_record() {
+	let value = eval(r#"xeno keybind --mode insert "ctrl-s" (xeno effect dispatch command write)"#).expect("should evaluate");
+	let rec = value.as_record().expect("should be record");
+	let fields: Vec<&str> = rec.columns().map(String::as_str).collect();
+	assert_eq!(fields, ["mode", "key", "invocation"]);
+	assert_eq!(rec.get("mode").unwrap().as_str().unwrap(), "insert");
+	assert_eq!(rec.get("key").unwrap().as_str().unwrap(), "ctrl-s");
+	let inv = rec.get("invocation").unwrap().as_record().expect("invocation should be a bare effect record");
+	assert_eq!(inv.get("type").unwrap().as_str().unwrap(), "dispatch");
+	assert_eq!(inv.get("kind").unwrap().as_str().unwrap(), "command");
+	assert_eq!(inv.get("name").unwrap().as_str().unwrap(), "write");
+}
+
+#[test]
+fn xeno_keybind_defaults_to_normal_mode() {
+	let value = eval(r#"xeno keybind "g r" {type: "dispatch", kind: "action", name: "move_right"}"#).expect("should evaluate");
+	let rec = value.as_record().expect("should be record");
+	assert_eq!(rec.get("mode").unwrap().as_str().unwrap(), "normal");
+	assert_eq!(rec.get("key").unwrap().as_str().unwrap(), "g r");
+}
+
+#[test]
+fn xeno_keybind_rejects_invalid_key() {
+	let err = eval(r#"xeno keybind "ctrl+s" (xeno effect dispatch command write)"#).expect_err("invalid key should be rejected");
+	assert!(err.contains("ctrl+s"), "got: {err}");
+	assert!(err.contains("Parse error"), "got: {err}");
+}
+
+#[test]
+fn xeno_keybind_rejects_misspelled_invocation_field() {
+	let err = eval(r#"xeno keybind "ctrl-s" {type: "dispatch", kinde: "action", name: "write"}"#).expect_err("typo should be rejected");
+	assert!(err.contains("keys.normal.ctrl-s"), "got: {err}");
+}
+
+#[test]
+fn module_only_rejects_shadowing_xeno_keybind() {
+	let mut engine_state = create_engine_state(None).expect("engine state");
+	let source = r#"export def "xeno keybind" [] { null }"#;
+	let err = parse_and_validate_with_policy(&mut engine_state, "<test>", source, None, ParsePolicy::ModuleWrapped)
+		.expect_err("shadowing 'xeno keybind' should be rejected");
+	assert!(err.contains("reserved") && err.contains("xeno keybind"), "got: {err}");
+}
+
+#[test]
+fn create_engine_state_registers_xeno_keybind_command() {
+	let engine_state = create_engine_state(None).expect("engine state should be created");
+	assert!(find_decl(&engine_state, "xeno keybind").is_some(), "xeno keybind command should be registered");
+}
@@ -10,8 +10,8 @@
 //!   Safe stdlib commands are registered from `xeno-nu-safe-commands`.
 //! * **AST-level scan** (`scan.rs`) — rejects external commands (`^cmd`),
 //!   pipeline redirection, glob expansion, filepath/directory literals,
-//!   range expressions (unbounded iteration), and defense-in-depth
-//!   `source`/`source-env` rejection.
+//!   range expressions (unbounded iteration), `$env`/`$nu` assignment, and
+//!   defense-in-depth `source`/`source-env` rejection.
 //! * **Module root confinement** — all parser-resolved module files must
 //!   canonically remain under the provided config root directory.
 //!
@@ -20,7 +20,9 @@
 //! Function calls are subject to hard limits from
 //! [`xeno_invocation::nu::DEFAULT_CALL_LIMITS`] to prevent resource exhaustion.
 //! Limits are derived from [`xeno_invocation::schema::DEFAULT_LIMITS`] where
-//! applicable (args, string lengths).
+//! applicable (args, string lengths). Env values are also capped in
+//! list/record nesting depth so deeply nested trees cannot overflow the stack
+//! during conversion.
 //!
 //! # Recursion limit
 //!
@@ -46,6 +48,7 @@
 //! `xeno effect` (typed effect constructor),
 //! `xeno effects normalize` (bulk validate/normalize typed effects),
 //! `xeno is-effect` (predicate: true if input decodes as a single effect),
+//! `xeno keybind` (validated `{ mode, key, invocation }` keybinding record),
 //! `xeno log` (pass-through pipeline logger)
 //!
 //! Caveats:
@@ -67,7 +70,7 @@
 pub(crate) mod commands;
 mod scan;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 pub(crate) use scan::ensure_sandboxed;
@@ -158,7 +161,7 @@ fn create_xeno_lang_context() -> Result<EngineState, String> {
 }
 
 /// Result of parsing and validating a Nu script.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct ParseResult {
 	pub block: Arc<Block>,
 	/// Decl IDs of exported definitions (from module export table).
@@ -250,6 +253,106 @@ pub(crate) fn parse_and_validate_with_policy(
 	Ok(ParseResult { block, export_decl_ids })
 }
 
+/// Last successful parse of a script, keyed by the FNV-1a hash of its source
+/// together with the other inputs that affect the result.
+///
+/// The cached result refers to decls merged into one engine state, so a cache
+/// must only be reused with the engine state it was filled against.
+#[derive(Debug, Clone)]
+pub(crate) struct ParseCache {
+	pub source_hash: u64,
+	pub fname: String,
+	pub config_root: Option<PathBuf>,
+	pub policy: ParsePolicy,
+	/// Files the parse resolved through `use`/`source`; see [`ImportedFile`].
+	pub imports: Vec<ImportedFile>,
+	pub parse_result: ParseResult,
+}
+
+/// A file a cached parse read through `use`/`source`, with the FNV-1a hash of
+/// the contents the parser saw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImportedFile {
+	pub path: PathBuf,
+	pub hash: u64,
+}
+
+impl ImportedFile {
+	/// Returns whether the file still holds the contents it was parsed from.
+	fn is_unchanged(&self) -> bool {
+		std::fs::read(&self.path).is_ok_and(|contents| crate::fnv1a(&contents) == self.hash)
+	}
+}
+
+impl ParseCache {
+	/// Returns whether this cache holds the parse of `source` under the same
+	/// inputs, with every imported file unchanged on disk.
+	pub(crate) fn matches(&self, fname: &str, source: &str, config_root: Option<&Path>, policy: ParsePolicy) -> bool {
+		self.source_hash == crate::fnv1a(source.as_bytes())
+			&& self.fname == fname
+			&& self.config_root.as_deref() == config_root
+			&& self.policy == policy
+			&& self.imports.iter().all(ImportedFile::is_unchanged)
+	}
+}
+
+/// Like [`parse_and_validate_with_policy`], but returns the cached result
+/// without reparsing when the source, its imports and parse inputs are
+/// unchanged since the last successful parse.
+///
+/// Anything else is parsed and merged in full and replaces the cache; a
+/// failed parse leaves the cache untouched.
+pub(crate) fn parse_and_validate_incremental(
+	engine_state: &mut EngineState,
+	fname: &str,
+	source: &str,
+	config_root: Option<&Path>,
+	policy: ParsePolicy,
+	cache: &mut Option<ParseCache>,
+) -> Result<ParseResult, String> {
+	parse_cached(fname, source, config_root, policy, cache, || {
+		let known_files = engine_state.files().len();
+		let parsed = parse_and_validate_with_policy(engine_state, fname, source, config_root, policy)?;
+		Ok((parsed, imported_files(engine_state, known_files, fname)))
+	})
+}
+
+/// Returns the real files merged into `engine_state` after its first `known_files`, except `fname`.
+fn imported_files(engine_state: &EngineState, known_files: usize, fname: &str) -> Vec<ImportedFile> {
+	engine_state
+		.files()
+		.skip(known_files)
+		.filter(|file| &*file.name != fname && !scan::is_virtual_filename(&file.name))
+		.map(|file| ImportedFile {
+			path: PathBuf::from(&*file.name),
+			hash: crate::fnv1a(&file.content),
+		})
+		.collect()
+}
+
+fn parse_cached(
+	fname: &str,
+	source: &str,
+	config_root: Option<&Path>,
+	policy: ParsePolicy,
+	cache: &mut Option<ParseCache>,
+	parse: impl FnOnce() -> Result<(ParseResult, Vec<ImportedFile>), String>,
+) -> Result<ParseResult, String> {
+	if let Some(cached) = cache.as_ref().filter(|cached| cached.matches(fname, source, config_root, policy)) {
+		return Ok(cached.parse_result.clone());
+	}
+	let (parse_result, imports) = parse()?;
+	*cache = Some(ParseCache {
+		source_hash: crate::fnv1a(source.as_bytes()),
+		fname: fname.to_string(),
+		config_root: config_root.map(Path::to_path_buf),
+		policy,
+		imports,
+		parse_result: parse_result.clone(),
+	});
+	Ok(parse_result)
+}
+
 fn is_reserved_xeno_name(name: &str) -> bool {
 	name == "xeno" || name.starts_with("xeno ")
 }
@@ -266,6 +369,24 @@ fn check_reserved_names(working_set: &StateWorkingSet<'_>, script_decl_ids: &[De
 	Ok(())
 }
 
+/// Registers a host-side command into the engine state.
+///
+/// Names in the reserved `xeno` namespace and names of existing decls are
+/// rejected so host commands cannot shadow builtins.
+pub(crate) fn register_host_command(engine_state: &mut EngineState, name: &'static str, handler: crate::NuCommandFn) -> Result<(), String> {
+	if is_reserved_xeno_name(name) {
+		return Err(format!("Nu runtime error: '{name}' is in the reserved 'xeno' command namespace"));
+	}
+	if engine_state.find_decl(name.as_bytes(), &[]).is_some() {
+		return Err(format!("Nu runtime error: command '{name}' is already defined"));
+	}
+
+	let mut working_set = StateWorkingSet::new(engine_state);
+	working_set.add_decl(Box::new(commands::HostCommand { name, handler }));
+	let delta = working_set.render();
+	engine_state.merge_delta(delta).map_err(|error| format!("Nu merge error: {error}"))
+}
+
 /// Evaluates a parsed block and returns the resulting value.
 pub(crate) fn evaluate_block(engine_state: &EngineState, block: &Block) -> Result<Value, String> {
 	let mut stack = Stack::new();
@@ -276,6 +397,20 @@ pub(crate) fn evaluate_block(engine_state: &EngineState, block: &Block) -> Resul
 
 /// Calls an already-registered function by declaration ID.
 pub(crate) fn call_function(engine_state: &EngineState, decl_id: DeclId, args: &[String], env: &[(&str, Value)]) -> Result<Value, SandboxCallError> {
+	call_function_stream(engine_state, decl_id, args, env)?
+		.into_value(Span::unknown())
+		.map_err(|error| SandboxCallError::Runtime(format!("Nu runtime error: {error}")))
+}
+
+/// Like [`call_function`] but returns the uncollected pipeline output.
+///
+/// List streams stay lazy: items are produced as the caller iterates.
+pub(crate) fn call_function_stream(
+	engine_state: &EngineState,
+	decl_id: DeclId,
+	args: &[String],
+	env: &[(&str, Value)],
+) -> Result<PipelineData, SandboxCallError> {
 	validate_call_args(args)?;
 	validate_call_env_borrowed(env)?;
 
@@ -290,10 +425,7 @@ pub(crate) fn call_function(engine_state: &EngineState, decl_id: DeclId, args: &
 		stack.add_env_var((*key).to_string(), value.clone());
 	}
 
-	let result = xeno_nu_engine::eval_call::<WithoutDebug>(engine_state, &mut stack, &call, PipelineData::empty())
-		.map_err(|error| SandboxCallError::Runtime(format!("Nu runtime error: {error}")))?;
-	result
-		.into_value(span)
+	xeno_nu_engine::eval_call::<WithoutDebug>(engine_state, &mut stack, &call, PipelineData::empty())
 		.map_err(|error| SandboxCallError::Runtime(format!("Nu runtime error: {error}")))
 }
 
@@ -375,7 +507,7 @@ fn validate_call_env_borrowed(env: &[(&str, Value)]) -> Result<(), CallValidatio
 				max: DEFAULT_CALL_LIMITS.max_env_string_len,
 			});
 		}
-		count_value_nodes(value, &mut nodes)?;
+		count_value_nodes(value, 1, &mut nodes)?;
 	}
 	Ok(())
 }
@@ -395,12 +527,17 @@ fn validate_call_env_owned(env: &[(String, Value)]) -> Result<(), CallValidation
 				max: DEFAULT_CALL_LIMITS.max_env_string_len,
 			});
 		}
-		count_value_nodes(value, &mut nodes)?;
+		count_value_nodes(value, 1, &mut nodes)?;
 	}
 	Ok(())
 }
 
-fn count_value_nodes(value: &Value, nodes: &mut usize) -> Result<(), CallValidationError> {
+/// Walks `value` (nested at `depth`, 1 for a top-level env value) counting
+/// nodes and checking string lengths and list/record nesting depth.
+///
+/// The depth check runs before descending so hostile trees are rejected
+/// without recursing past [`NuCallLimits::max_value_depth`](xeno_invocation::nu::NuCallLimits::max_value_depth).
+fn count_value_nodes(value: &Value, depth: usize, nodes: &mut usize) -> Result<(), CallValidationError> {
 	*nodes += 1;
 	if *nodes > DEFAULT_CALL_LIMITS.max_env_nodes {
 		return Err(CallValidationError::EnvValueTooComplex {
@@ -417,9 +554,15 @@ fn count_value_nodes(value: &Value, nodes: &mut usize) -> Result<(), CallValidat
 				});
 			}
 		}
+		Value::List { .. } | Value::Record { .. } if depth > DEFAULT_CALL_LIMITS.max_value_depth => {
+			return Err(CallValidationError::RecursiveValue {
+				depth,
+				max: DEFAULT_CALL_LIMITS.max_value_depth,
+			});
+		}
 		Value::List { vals, .. } => {
 			for v in vals {
-				count_value_nodes(v, nodes)?;
+				count_value_nodes(v, depth + 1, nodes)?;
 			}
 		}
 		Value::Record { val, .. } => {
@@ -430,7 +573,7 @@ fn count_value_nodes(value: &Value, nodes: &mut usize) -> Result<(), CallValidat
 						max: DEFAULT_CALL_LIMITS.max_env_string_len,
 					});
 				}
-				count_value_nodes(v, nodes)?;
+				count_value_nodes(v, depth + 1, nodes)?;
 			}
 		}
 		_ => {}
@@ -2,8 +2,8 @@
 //!
 //! Walks the parsed AST and rejects structural constructs that cannot be
 //! prevented by engine context alone: external commands (`^cmd`),
-//! pipeline redirection, glob expansion, and module paths that resolve
-//! outside the config root directory.
+//! pipeline redirection, glob expansion, assignments to `$env`/`$nu`, and
+//! module paths that resolve outside the config root directory.
 //!
 //! Most dangerous commands (loops, overlays, extern signatures) are excluded
 //! at the engine level by [`create_xeno_lang_context`](super::create_xeno_lang_context)
@@ -28,9 +28,9 @@
 use std::collections::HashSet;
 use std::path::Path;
 
-use xeno_nu_protocol::BlockId;
-use xeno_nu_protocol::ast::{Argument, Block, Expr, Expression, ListItem, MatchPattern, Pattern, RecordItem};
+use xeno_nu_protocol::ast::{Argument, Block, Expr, Expression, ListItem, MatchPattern, Operator, Pattern, RecordItem};
 use xeno_nu_protocol::engine::StateWorkingSet;
+use xeno_nu_protocol::{BlockId, ENV_VARIABLE_ID, NU_VARIABLE_ID, VarId};
 
 /// Validates that a parsed working set contains no sandbox violations.
 ///
@@ -129,6 +129,9 @@ fn check_expression(
 		Expr::UnaryNot(expr) => check_expression(working_set, expr, visited, state),
 
 		Expr::BinaryOp(lhs, op, rhs) => {
+			if matches!(op.expr, Expr::Operator(Operator::Assignment(_))) {
+				check_assignment_target(lhs)?;
+			}
 			check_expression(working_set, lhs, visited, state)?;
 			check_expression(working_set, op, visited, state)?;
 			check_expression(working_set, rhs, visited, state)
@@ -296,12 +299,33 @@ fn validate_resolved_module_paths(working_set: &StateWorkingSet<'_>, config_root
 	Ok(())
 }
 
-fn is_virtual_filename(name: &str) -> bool {
+pub(super) fn is_virtual_filename(name: &str) -> bool {
 	name.starts_with('<') && name.ends_with('>')
 }
 
 // --- Defense-in-depth checks ---
 
+/// Rejects assignments whose target is rooted at `$env` or `$nu`.
+///
+/// Environment writes would leak into the host process for subsequent calls;
+/// the parser already treats `$nu` as immutable, so that arm is belt-and-braces.
+fn check_assignment_target(lhs: &Expression) -> Result<(), String> {
+	match assignment_root(lhs) {
+		Some(ENV_VARIABLE_ID) => Err("assigning to $env is disabled".to_string()),
+		Some(NU_VARIABLE_ID) => Err("assigning to $nu is disabled".to_string()),
+		_ => Ok(()),
+	}
+}
+
+/// Returns the variable an assignment target (`$x`, `$x.a.b`) is rooted at.
+fn assignment_root(expr: &Expression) -> Option<VarId> {
+	match &expr.expr {
+		Expr::Var(id) => Some(*id),
+		Expr::FullCellPath(path) => assignment_root(&path.head),
+		_ => None,
+	}
+}
+
 fn is_source_decl(decl_name: &str) -> bool {
 	matches!(decl_name, "source" | "source-env")
 }
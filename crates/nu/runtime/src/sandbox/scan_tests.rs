@@ -80,6 +80,26 @@ fn blocks_redirection() {
 	);
 }
 
+#[test]
+fn blocks_env_assignment() {
+	for source in ["$env.MY_VAR = 'x'", "$env.PATH = []", "def f [] { $env.MY_VAR = 'x' }"] {
+		let err = sandbox_check(source, None).unwrap_err();
+		assert!(err.contains("assigning to $env is disabled"), "{source}: {err}");
+	}
+}
+
+#[test]
+fn blocks_nu_assignment() {
+	let err = sandbox_check("$nu.home-path = 'x'", None).unwrap_err();
+	assert!(err.contains("$nu") || err.contains("parse error"), "{err}");
+}
+
+#[test]
+fn allows_env_read() {
+	sandbox_check("$env.MY_VAR", None).expect("reading $env should pass");
+	sandbox_check("mut x = 1\n$x = 2\n$x", None).expect("assigning a local mut should pass");
+}
+
 #[test]
 fn allows_pure_record() {
 	sandbox_check("{ name: 'test', value: 42 }", None).expect("pure record should pass");
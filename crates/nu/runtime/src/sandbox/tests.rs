@@ -1042,3 +1042,71 @@ fn module_only_rejects_xeno_bare() {
 		.expect_err("bare xeno shadowing should be rejected");
 	assert!(err.contains("reserved") && err.contains("xeno"), "got: {err}");
 }
+
+#[test]
+fn parse_cache_skips_parser_for_unchanged_source() {
+	let mut engine_state = create_engine_state(None).expect("engine state");
+	let source = "export def go [] { 1 }";
+	let mut cache = None;
+	let first = parse_and_validate_incremental(&mut engine_state, "<test>", source, None, ParsePolicy::ModuleWrapped, &mut cache).expect("should parse");
+
+	let mut parses = 0;
+	let second = parse_cached("<test>", source, None, ParsePolicy::ModuleWrapped, &mut cache, || {
+		parses += 1;
+		Err("parser should not run".to_string())
+	})
+	.expect("cache hit");
+	assert_eq!(parses, 0);
+	assert!(Arc::ptr_eq(&first.block, &second.block));
+	assert_eq!(first.export_decl_ids, second.export_decl_ids);
+}
+
+#[test]
+fn parse_cache_reparses_changed_source() {
+	let mut engine_state = create_engine_state(None).expect("engine state");
+	let mut cache = None;
+	let mut parses = 0;
+	let mut parse = |source: &str, cache: &mut Option<ParseCache>| {
+		parse_cached("<test>", source, None, ParsePolicy::ModuleWrapped, cache, || {
+			parses += 1;
+			parse_and_validate_with_policy(&mut engine_state, "<test>", source, None, ParsePolicy::ModuleWrapped).map(|parsed| (parsed, Vec::new()))
+		})
+	};
+
+	let first = parse("export def go [] { 1 }", &mut cache).expect("should parse");
+	parse("export def go [] { 1 }", &mut cache).expect("cache hit");
+	let changed = parse("export def go [] { 2 }\nexport def stop [] { 0 }", &mut cache).expect("should reparse");
+	parse("export def broken [ {", &mut cache).expect_err("parse error");
+	parse("export def go [] { 2 }\nexport def stop [] { 0 }", &mut cache).expect("failed parse keeps the cache");
+	drop(parse);
+
+	assert_eq!(parses, 3);
+	assert!(!Arc::ptr_eq(&first.block, &changed.block));
+	assert_eq!(changed.export_decl_ids.len(), 2);
+	assert!(find_decl(&engine_state, "stop").is_some(), "changed source is merged");
+}
+
+#[test]
+fn parse_cache_misses_when_parse_inputs_change() {
+	let mut engine_state = create_engine_state(None).expect("engine state");
+	let temp = tempfile::tempdir().expect("temp dir should exist");
+	let source = "export def go [] { 1 }";
+	let mut cache = None;
+	parse_and_validate_incremental(&mut engine_state, "<test>", source, None, ParsePolicy::ModuleWrapped, &mut cache).expect("should parse");
+
+	let mut parses = 0;
+	let mut reparse = |fname: &str, config_root: Option<&Path>, policy: ParsePolicy| {
+		let mut cache = cache.clone();
+		let _ = parse_cached(fname, source, config_root, policy, &mut cache, || {
+			parses += 1;
+			Err("reparsed".to_string())
+		});
+	};
+	reparse("<test>", None, ParsePolicy::ModuleWrapped);
+	reparse("<other>", None, ParsePolicy::ModuleWrapped);
+	reparse("<test>", Some(temp.path()), ParsePolicy::ModuleWrapped);
+	reparse("<test>", None, ParsePolicy::Script);
+	drop(reparse);
+
+	assert_eq!(parses, 3, "fname, config root and policy are part of the key");
+}
@@ -41,6 +41,67 @@ fn eval_source_returns_runtime_error_for_eval_failure() {
 	assert!(matches!(err, ExecError::Runtime(_)));
 }
 
+fn get_cursor_pos(args: Vec<Value>, _env: &HashMap<String, Value>) -> Result<Value, String> {
+	let offset = match args.first() {
+		Some(value) => value.as_int().map_err(|error| error.to_string())?,
+		None => 0,
+	};
+	let mut record = xeno_nu_data::Record::new();
+	record.push("line", Value::int(3 + offset, xeno_nu_data::Span::unknown()));
+	record.push("col", Value::int(7, xeno_nu_data::Span::unknown()));
+	Ok(Value::record(record, xeno_nu_data::Span::unknown()))
+}
+
+#[test]
+fn add_command_is_callable_from_script() {
+	let mut program = NuProgram::compile_config_script("config.nu", "null", None).expect("script should compile");
+	program.add_command("get-cursor-pos", get_cursor_pos).expect("host command should register");
+
+	let line = program.eval_source("snippet.nu", "get-cursor-pos | get line").expect("host command should run");
+	assert_eq!(line.as_int().expect("line should be int"), 3);
+	let line = program
+		.eval_source("snippet.nu", "(get-cursor-pos 2).line")
+		.expect("host command should take args");
+	assert_eq!(line.as_int().expect("line should be int"), 5);
+
+	let err = program
+		.eval_source("snippet.nu", "get-cursor-pos nope")
+		.expect_err("handler error should surface");
+	assert!(matches!(err, ExecError::Runtime(_)));
+}
+
+#[test]
+fn host_commands_registered_before_parse_are_callable_from_xeno_nu() {
+	let temp = tempfile::tempdir().expect("temp dir should exist");
+	let script = "export def line [] { get-cursor-pos | get line }";
+	write_script(temp.path(), script);
+	let commands: &[(&'static str, NuCommandFn)] = &[("get-cursor-pos", get_cursor_pos)];
+	let program = NuProgram::compile_source_with_commands(temp.path(), &temp.path().join("xeno.nu"), script, ProgramPolicy::ModuleWrapped, commands)
+		.expect("script calling a host command should compile");
+	let line = |program: &NuProgram| {
+		program
+			.call_export_name("line", &[], &[], None)
+			.expect("call")
+			.as_int()
+			.expect("line should be int")
+	};
+	assert_eq!(line(&program), 3);
+
+	write_script(temp.path(), "export def line [] { (get-cursor-pos 1).line }");
+	let reloaded = program.reload_macro_from_dir(temp.path()).expect("reparse keeps host commands");
+	assert_eq!(line(&reloaded), 4);
+}
+
+#[test]
+fn add_command_rejects_reserved_and_existing_names() {
+	let mut program = NuProgram::compile_config_script("config.nu", "null", None).expect("script should compile");
+	assert!(matches!(program.add_command("xeno cursor", get_cursor_pos), Err(CompileError::Parse(_))));
+	assert!(matches!(program.add_command("if", get_cursor_pos), Err(CompileError::Parse(_))));
+
+	program.add_command("get-cursor-pos", get_cursor_pos).expect("host command should register");
+	assert!(matches!(program.add_command("get-cursor-pos", get_cursor_pos), Err(CompileError::Parse(_))));
+}
+
 #[test]
 fn load_rejects_oversized_script_file() {
 	let temp = tempfile::tempdir().expect("temp dir");
@@ -100,6 +161,28 @@ fn exports_returns_only_exported_names() {
 	assert_eq!(names, vec!["alpha", "beta"], "exports should be sorted and contain only exported defs");
 }
 
+#[test]
+fn diff_exports_reports_renamed_export_as_added_and_removed() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "export def alpha [] { 1 }\nexport def beta [] { 2 }\ndef hidden [] { 3 }");
+	let first = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+
+	write_script(temp.path(), "export def alpha [x] { $x }\nexport def gamma [] { 2 }\ndef hidden [] { 3 }");
+	let second = NuProgram::compile_macro_from_dir(temp.path()).expect("should recompile");
+
+	let diff = first.diff_exports(&second);
+	assert_eq!(diff.added, vec!["gamma"]);
+	assert_eq!(diff.removed, vec!["beta"]);
+	assert_eq!(diff.unchanged, vec!["alpha"], "signature changes are not detected");
+	assert_eq!(
+		second.diff_exports(&second),
+		ExportDiff {
+			unchanged: vec!["alpha".into(), "gamma".into()],
+			..ExportDiff::default()
+		}
+	);
+}
+
 #[test]
 fn module_export_use_explicit() {
 	let temp = tempfile::tempdir().expect("temp dir");
@@ -133,6 +216,124 @@ fn module_private_not_exported() {
 	assert!(program.resolve_export("private").is_none(), "private def inside module must not be exported");
 }
 
+#[test]
+fn stable_export_survives_reordered_recompile() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "export def alpha [] { 1 }\nexport def beta [x] { $\"beta:($x)\" }");
+	let first = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let beta = first.resolve_stable("beta").expect("beta should resolve");
+
+	write_script(
+		temp.path(),
+		"def helper [] { 0 }\nexport def gamma [] { 3 }\nexport def beta [x] { $\"beta:($x)\" }\nexport def alpha [] { 1 }",
+	);
+	let second = NuProgram::compile_macro_from_dir(temp.path()).expect("should recompile");
+
+	assert_eq!(second.resolve_stable("beta"), Some(beta.clone()), "stable id must not depend on decl order");
+	let value = second.call_stable(&beta, &["5".to_string()], &[], None).expect("stable call should dispatch");
+	assert_eq!(value.as_str().expect("value should be string"), "beta:5");
+}
+
+#[test]
+fn stable_export_missing_after_recompile_reports_name() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "export def gone [] { 1 }");
+	let first = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let gone = first.resolve_stable("gone").expect("gone should resolve");
+
+	write_script(temp.path(), "export def other [] { 2 }");
+	let second = NuProgram::compile_macro_from_dir(temp.path()).expect("should recompile");
+
+	let err = second.call_stable(&gone, &[], &[], None).expect_err("removed export should fail");
+	assert!(matches!(&err, ExecError::MissingExport(msg) if msg.contains("'gone'")), "got: {err}");
+}
+
+#[test]
+fn stable_export_rejects_changed_signature() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "export def go [] { 1 }");
+	let first = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let go = first.resolve_stable("go").expect("go should resolve");
+
+	write_script(temp.path(), "export def go [x: int] { $x }");
+	let second = NuProgram::compile_macro_from_dir(temp.path()).expect("should recompile");
+
+	assert_ne!(second.resolve_stable("go").map(|id| id.hash()), Some(go.hash()));
+	let err = second.call_stable(&go, &[], &[], None).expect_err("signature change should fail");
+	assert!(matches!(err, ExecError::MissingExport(_)), "got: {err}");
+}
+
+#[test]
+fn stable_export_rejects_private_defs() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "def hidden [] { 1 }\nexport def visible [] { hidden }");
+	let program = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	assert!(program.resolve_stable("visible").is_some());
+	assert!(program.resolve_stable("hidden").is_none());
+}
+
+#[test]
+fn export_stable_string_survives_recompile_of_identical_source() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "def helper [] { 0 }\nexport def alpha [] { 1 }\nexport def beta [] { 2 }");
+	let first = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let second = NuProgram::compile_macro_from_dir(temp.path()).expect("should recompile");
+
+	for name in ["alpha", "beta"] {
+		let stable = first.resolve_export(name).and_then(|id| id.to_stable_string(&first));
+		assert_eq!(stable.as_deref(), Some(name));
+		let again = second.resolve_export(name).and_then(|id| id.to_stable_string(&second));
+		assert_eq!(again, stable, "identical source must produce identical stable strings");
+
+		let resolved = second.resolve_stable_string(name).expect("stable string should resolve");
+		let value = second.call_export(resolved, &[], &[], None).expect("call should succeed");
+		assert!(value.as_int().is_ok());
+	}
+
+	assert!(second.resolve_stable_string("helper").is_none(), "private defs have no stable string");
+	assert_eq!(ExportId::from_raw(usize::MAX).to_stable_string(&first), None);
+}
+
+#[test]
+fn export_stream_yields_items_lazily() {
+	// Items past the fifth raise an error, so any eager collection fails.
+	let items = (1..=10_000).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(
+		temp.path(),
+		&format!("export def big [] {{ [{items}] | each {{|n| if $n > 5 {{ error make {{ msg: 'materialized' }} }} else {{ $n }} }} }}"),
+	);
+	let program = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let export = program.resolve_export("big").expect("big should resolve");
+
+	let first: Vec<i64> = program
+		.call_export_stream(export, &[], &[])
+		.expect("stream should start")
+		.take(5)
+		.map(|item| item.expect("leading items should succeed").as_int().expect("item should be int"))
+		.collect();
+	assert_eq!(first, [1, 2, 3, 4, 5]);
+
+	let mut stream = program.call_export_stream(export, &[], &[]).expect("stream should start");
+	let sixth = stream.nth(5).expect("stream should have a sixth item");
+	assert!(matches!(sixth, Err(ExecError::Runtime(_))), "sixth item should carry the closure error");
+	drop(stream);
+
+	assert!(program.call_export(export, &[], &[], None).is_err(), "collecting call evaluates every item");
+}
+
+#[test]
+fn export_stream_yields_scalar_once() {
+	let temp = tempfile::tempdir().expect("temp dir");
+	write_script(temp.path(), "export def go [] { 42 }");
+	let program = NuProgram::compile_macro_from_dir(temp.path()).expect("should compile");
+	let export = program.resolve_export("go").expect("go should resolve");
+
+	let items: Vec<_> = program.call_export_stream(export, &[], &[]).expect("stream should start").collect();
+	assert_eq!(items.len(), 1);
+	assert_eq!(items[0].as_ref().expect("item should succeed").as_int().expect("item should be int"), 42);
+}
+
 // --- Step 6: Call input validation tests (at NuProgram API level) ---
 
 fn varargs_program() -> (NuProgram, ExportId) {
@@ -219,6 +420,33 @@ fn call_oversize_env_nodes_rejected() {
 	);
 }
 
+fn nested_record(depth: usize) -> xeno_nu_data::Value {
+	let span = xeno_nu_data::Span::unknown();
+	(0..depth).fold(Value::int(0, span), |inner, _| {
+		let mut record = xeno_nu_data::Record::new();
+		record.push("inner", inner);
+		Value::record(record, span)
+	})
+}
+
+#[test]
+fn call_deeply_nested_env_value_rejected() {
+	let (program, export) = varargs_program();
+	let env = [("data", nested_record(100))];
+	let err = program.call_export(export, &[], &env, None).expect_err("100-deep record should be rejected");
+	assert!(
+		matches!(err, ExecError::CallValidation(CallValidationError::RecursiveValue { depth: 65, max: 64 })),
+		"got: {err}"
+	);
+}
+
+#[test]
+fn call_nested_env_value_within_depth_succeeds() {
+	let (program, export) = varargs_program();
+	let env = [("data", nested_record(63))];
+	program.call_export(export, &[], &env, None).expect("63-deep record should pass validation");
+}
+
 // --- Step 8.2: Host access tests ---
 
 use crate::host::{BufferMeta, HostError, LineColRange, TextChunk, XenoNuHost};
@@ -311,3 +539,39 @@ fn host_buffer_get_without_host_errors() {
 	let err = program.call_export(export, &[], &[], None).expect_err("should fail without host");
 	assert!(matches!(err, ExecError::Runtime(_)));
 }
+
+#[test]
+fn reload_reuses_parse_for_unchanged_script() {
+	let temp = tempfile::tempdir().expect("temp dir should exist");
+	write_script(temp.path(), "export def go [] { 42 }");
+	let program = NuProgram::compile_macro_from_dir(temp.path()).expect("runtime should load");
+	let block = |program: &NuProgram| program.parse_cache.as_ref().expect("parse is cached").parse_result.block.clone();
+
+	let reloaded = program.reload_macro_from_dir(temp.path()).expect("reload should succeed");
+	assert!(Arc::ptr_eq(&block(&program), &block(&reloaded)), "unchanged script reuses the parse");
+	let go = reloaded.resolve_export("go").expect("go should resolve");
+	assert_eq!(reloaded.call_export(go, &[], &[], None).expect("call").as_int().expect("int"), 42);
+
+	write_script(temp.path(), "export def go [] { 7 }");
+	let changed = reloaded.reload_macro_from_dir(temp.path()).expect("reload should succeed");
+	assert!(!Arc::ptr_eq(&block(&reloaded), &block(&changed)), "changed script is reparsed");
+	let go = changed.resolve_export("go").expect("go should resolve");
+	assert_eq!(changed.call_export(go, &[], &[], None).expect("call").as_int().expect("int"), 7);
+}
+
+#[test]
+fn reload_reparses_when_an_imported_module_changes() {
+	let temp = tempfile::tempdir().expect("temp dir should exist");
+	std::fs::write(temp.path().join("helper.nu"), "export def value [] { 1 }").expect("helper.nu should be writable");
+	write_script(temp.path(), "use helper.nu *\nexport def go [] { value }");
+	let program = NuProgram::compile_macro_from_dir(temp.path()).expect("runtime should load");
+	let call_go = |program: &NuProgram| {
+		let go = program.resolve_export("go").expect("go should resolve");
+		program.call_export(go, &[], &[], None).expect("call").as_int().expect("int")
+	};
+	assert_eq!(call_go(&program), 1);
+
+	std::fs::write(temp.path().join("helper.nu"), "export def value [] { 2 }").expect("helper.nu should be writable");
+	let reloaded = program.reload_macro_from_dir(temp.path()).expect("reload should succeed");
+	assert_eq!(call_go(&reloaded), 2, "an edited import invalidates the cached parse");
+}
@@ -93,4 +93,10 @@ pub mod motion_ids {
 	// Diff motions
 	pub const NEXT_HUNK: MotionId = MotionId("xeno-registry::next_hunk");
 	pub const PREV_HUNK: MotionId = MotionId("xeno-registry::prev_hunk");
+
+	// Syntax tree motions
+	pub const NEXT_SIBLING_NODE: MotionId = MotionId("xeno-registry::next_sibling_node");
+	pub const PREV_SIBLING_NODE: MotionId = MotionId("xeno-registry::prev_sibling_node");
+	pub const PARENT_NODE_START: MotionId = MotionId("xeno-registry::parent_node_start");
+	pub const NODE_END: MotionId = MotionId("xeno-registry::node_end");
 }
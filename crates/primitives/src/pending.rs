@@ -31,4 +31,6 @@ pub enum PendingKind {
 	ReplaceChar,
 	/// Select text object (`i`/`a` after operator).
 	Object(ObjectSelectionKind),
+	/// Regex to split selections on (`S` command), entered as a line of text.
+	SplitRegex,
 }
@@ -0,0 +1,105 @@
+#![allow(unused_crate_dependencies)]
+
+use std::hint::black_box;
+use std::sync::Arc;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use xeno_registry::core::index::{BuildCtx, BuildEntry, RegistryMetaRef, StrListRef, StringCollector};
+use xeno_registry::core::{ActionId, RegistryBuilder, RegistryEntry, RegistryIndex, RegistryMeta, RegistrySource, Symbol, SymbolList};
+
+const ENTRIES: usize = 500;
+
+struct BenchEntry {
+	meta: RegistryMeta,
+}
+
+impl RegistryEntry for BenchEntry {
+	fn meta(&self) -> &RegistryMeta {
+		&self.meta
+	}
+}
+
+struct BenchDef {
+	id: String,
+	priority: i16,
+	source: RegistrySource,
+}
+
+impl BuildEntry<BenchEntry> for BenchDef {
+	fn meta_ref(&self) -> RegistryMetaRef<'_> {
+		RegistryMetaRef {
+			id: &self.id,
+			name: &self.id,
+			keys: StrListRef::Static(&[]),
+			categories: StrListRef::Static(&[]),
+			description: "",
+			priority: self.priority,
+			source: self.source,
+			mutates_buffer: false,
+			deprecated: None,
+		}
+	}
+
+	fn short_desc_str(&self) -> &str {
+		""
+	}
+
+	fn collect_payload_strings<'b>(&'b self, _collector: &mut StringCollector<'_, 'b>) {}
+
+	fn build(&self, ctx: &mut dyn BuildCtx, _key_pool: &mut Vec<Symbol>) -> BenchEntry {
+		let id = ctx.intern(&self.id);
+		BenchEntry {
+			meta: RegistryMeta {
+				id,
+				name: id,
+				description: ctx.intern(""),
+				keys: SymbolList::default(),
+				categories: SymbolList::default(),
+				priority: self.priority,
+				source: self.source,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+		}
+	}
+}
+
+fn build_index() -> RegistryIndex<BenchEntry, ActionId> {
+	let mut builder = RegistryBuilder::new("bench");
+	for n in 0..ENTRIES {
+		builder.push(Arc::new(BenchDef {
+			id: format!("bench::entry_{n:03}"),
+			priority: ((n * 7919) % 97) as i16 - 48,
+			source: if n % 5 == 0 { RegistrySource::Runtime } else { RegistrySource::Builtin },
+		}));
+	}
+	builder.build()
+}
+
+fn bench_iter_sorted(c: &mut Criterion) {
+	let index = build_index();
+	let mut group = c.benchmark_group("registry_iter_sorted_500");
+
+	group.bench_function("iter_sorted_by", |b| {
+		b.iter(|| {
+			for entry in index.iter_sorted_by(|a: &BenchEntry, b: &BenchEntry| b.total_order_cmp(a)) {
+				black_box(entry);
+			}
+		});
+	});
+
+	group.bench_function("collect_then_sort", |b| {
+		b.iter(|| {
+			let mut entries: Vec<&BenchEntry> = index.iter().collect();
+			entries.sort_by(|a, b| b.total_order_cmp(a));
+			for entry in entries {
+				black_box(entry);
+			}
+		});
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, bench_iter_sorted);
+criterion_main!(benches);
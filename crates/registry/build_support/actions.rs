@@ -11,6 +11,7 @@ pub fn build(ctx: &BuildCtx) {
 
 	validate_unique(spec.actions.iter().map(|action| action.common.name.as_str()), "action");
 	validate_action_modes(&spec);
+	validate_categories(spec.actions.iter().map(|action| &action.common), "action");
 
 	let bin = postcard::to_stdvec(&spec).expect("failed to serialize actions spec");
 	ctx.write_blob("actions.bin", &bin);
@@ -38,6 +38,8 @@ pub fn build(ctx: &BuildCtx) {
 		}
 	}
 
+	validate_categories(spec.commands.iter().map(|cmd| &cmd.common), "command");
+
 	let bin = postcard::to_stdvec(&spec).expect("failed to serialize commands spec");
 	ctx.write_blob("commands.bin", &bin);
 }
@@ -14,6 +14,7 @@ use walkdir::WalkDir;
 use xeno_nu_data::Value;
 
 use crate::defs_blob_header::{MAGIC, SCHEMA_VERSION};
+use crate::schema::meta::{MetaCommonSpec, categories};
 
 pub struct BuildCtx {
 	pub manifest_dir: PathBuf,
@@ -93,3 +94,18 @@ pub fn validate_unique<'a>(items: impl IntoIterator<Item = &'a str>, domain: &st
 		}
 	}
 }
+
+/// Validates that every `common.categories` entry is a well-known category.
+pub fn validate_categories<'a>(items: impl IntoIterator<Item = &'a MetaCommonSpec>, domain: &str) {
+	for common in items {
+		for category in &common.categories {
+			if !categories::ALL.contains(&category.as_str()) {
+				panic!(
+					"{domain} '{}' has unknown category '{category}' (expected one of {:?})",
+					common.name,
+					categories::ALL
+				);
+			}
+		}
+	}
+}
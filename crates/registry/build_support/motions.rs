@@ -18,6 +18,16 @@ pub fn build(ctx: &BuildCtx) {
 		}
 	}
 
+	for motion in &spec.motions {
+		if let Some(inverse) = &motion.inverse
+			&& !seen.contains(inverse)
+		{
+			panic!("motion '{}' names unknown inverse '{}'", motion.common.name, inverse);
+		}
+	}
+
+	validate_categories(spec.motions.iter().map(|motion| &motion.common), "motion");
+
 	let bin = postcard::to_stdvec(&spec).expect("failed to serialize motions spec");
 	ctx.write_blob("motions.bin", &bin);
 }
@@ -25,6 +25,15 @@ pub fn build(ctx: &BuildCtx) {
 			opt.common.name,
 			opt.scope
 		);
+		if let Some(choices) = &opt.choices {
+			assert!(opt.value_type == "string", "option '{}': choices require value_type 'string'", opt.common.name);
+			assert!(
+				choices.contains(&opt.default),
+				"option '{}': default '{}' is not one of its choices",
+				opt.common.name,
+				opt.default
+			);
+		}
 		if !seen.insert(&opt.common.name) {
 			panic!("duplicate option name: '{}'", opt.common.name);
 		}
@@ -1,6 +1,6 @@
 //! NUON → [`TextObjectsSpec`] compiler.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::build_support::compile::*;
 use crate::schema::textobj::TextObjectsSpec;
@@ -12,10 +12,16 @@ pub fn build(ctx: &BuildCtx) {
 	let spec: TextObjectsSpec = read_nuon_spec(&path);
 
 	let mut seen = HashSet::new();
+	let mut triggers: HashMap<&str, &str> = HashMap::new();
 	for obj in &spec.text_objects {
 		if !seen.insert(&obj.common.name) {
 			panic!("duplicate text object name: '{}'", obj.common.name);
 		}
+		for trigger in std::iter::once(&obj.trigger).chain(&obj.alt_triggers) {
+			if let Some(other) = triggers.insert(trigger.as_str(), obj.common.name.as_str()) {
+				panic!("text object '{}' trigger '{}' is already bound to '{}'", obj.common.name, trigger, other);
+			}
+		}
 	}
 
 	let bin = postcard::to_stdvec(&spec).expect("failed to serialize text_objects spec");
@@ -0,0 +1,321 @@
+//! Helix TOML theme import.
+//!
+//! Converts Helix theme files (a `[palette]` table plus `scope = style` entries)
+//! into [`LinkedThemeDef`]s. A style is either a color string (foreground only)
+//! or a table with `fg`, `bg`, and `modifiers`; colors name a palette entry,
+//! a hex value, or a terminal color.
+//!
+//! Syntax scopes without an exact [`SyntaxStyles`](crate::themes::SyntaxStyles)
+//! slot fall back to their nearest known ancestor (`keyword.control.flow` styles
+//! `keyword.control` unless the theme sets that scope itself). UI colors are read
+//! from these keys, falling back as listed:
+//!
+//! | Xeno field                | Helix keys                              | Fallback            |
+//! |---------------------------|-----------------------------------------|---------------------|
+//! | `ui.bg` / `ui.fg`         | `ui.background` bg / `ui.text` fg       | terminal default    |
+//! | `ui.gutter-fg`            | `ui.linenr` fg                          | `ui.fg`             |
+//! | `ui.cursor-bg` / `-fg`    | `ui.cursor.primary`, `ui.cursor`        | `ui.fg` / `ui.bg`   |
+//! | `ui.cursorline-bg`        | `ui.cursorline.primary`, `ui.cursorline`| `ui.bg`             |
+//! | `ui.selection-bg` / `-fg` | `ui.selection.primary`, `ui.selection`  | cursor bg / `ui.fg` |
+//! | `ui.message-fg`           | `ui.statusline` fg                      | `ui.fg`             |
+//! | `ui.command-input-fg`     | `ui.text.focus` fg                      | `ui.fg`             |
+//! | `mode.normal`             | `ui.statusline.normal`, `ui.statusline` | cursor colors       |
+//! | `mode.insert`             | `ui.statusline.insert`                  | `mode.normal`       |
+//! | `mode.prefix` / `command` | `ui.statusline.select`                  | `mode.normal`       |
+//! | `semantic.*`              | `error`, `warning`, `info`, `hint`, `diff.plus`, `ui.virtual`, `markup.link.url`, `ui.cursor.match` bg, `ui.text.focus` | terminal colors |
+//! | `popup.bg` / `fg`         | `ui.popup`, `ui.menu`                   | `ui.bg` / `ui.fg`   |
+//! | `popup.border` / `title`  | `ui.window` fg / `ui.help` fg           | `ui.gutter-fg` / `popup.fg` |
+//!
+//! Helix files carry no variant, so it is inferred from the background
+//! luminance. Keys that cannot be mapped, bad colors, and unsupported features
+//! (`inherits`, `rainbow`, underline styles) produce [`HelixThemeWarning`]s
+//! instead of failing the import; only malformed TOML is an error.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use toml::{Table, Value};
+use xeno_primitives::{Color, Modifier};
+
+use super::utils::{parse_color, parse_modifier, set_syntax_style};
+use super::{ConfigError, Result};
+use crate::themes::{ColorPair, LinkedThemeDef, ModeColors, PopupColors, SemanticColors, SyntaxStyle, SyntaxStyles, ThemeVariant, UiColors};
+
+/// A Helix theme key that was skipped or only partly imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelixThemeWarning {
+	/// Theme key, e.g. `ui.bufferline` or `palette.red`.
+	pub key: String,
+	pub message: String,
+}
+
+impl fmt::Display for HelixThemeWarning {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.key, self.message)
+	}
+}
+
+/// Result of [`parse_helix_theme_str`].
+pub struct HelixTheme {
+	pub theme: LinkedThemeDef,
+	pub warnings: Vec<HelixThemeWarning>,
+}
+
+/// Parses a Helix TOML theme named `name`, usually the file stem.
+pub fn parse_helix_theme_str(name: &str, input: &str) -> Result<HelixTheme> {
+	let mut table: Table = input.parse().map_err(|e: toml::de::Error| ConfigError::Toml(e.to_string()))?;
+	let mut warnings = Vec::new();
+	let mut warn = |key: &str, message: &str| {
+		warnings.push(HelixThemeWarning {
+			key: key.to_string(),
+			message: message.to_string(),
+		})
+	};
+
+	let mut palette = HashMap::new();
+	if let Some(value) = table.remove("palette") {
+		match value {
+			Value::Table(entries) => {
+				for (key, value) in entries {
+					match value.as_str().map(parse_color) {
+						Some(Ok(color)) => {
+							palette.insert(key, color);
+						}
+						_ => warn(&format!("palette.{key}"), "expected a color string"),
+					}
+				}
+			}
+			_ => warn("palette", "expected a table"),
+		}
+	}
+	for key in ["inherits", "rainbow"] {
+		if table.remove(key).is_some() {
+			warn(key, "not supported; key ignored");
+		}
+	}
+
+	let mut styles = HashMap::new();
+	for (key, value) in &table {
+		collect_styles(key, value, &palette, &mut styles, &mut warn);
+	}
+
+	let mut lookup = Lookup { styles, used: HashSet::new() };
+	let ui = ui_colors(&mut lookup);
+	let normal = lookup.pair(&["ui.statusline.normal", "ui.statusline"], ColorPair::new(ui.cursor_bg, ui.cursor_fg));
+	let prefix = lookup.pair(&["ui.statusline.select"], normal);
+	let mode = ModeColors {
+		normal,
+		insert: lookup.pair(&["ui.statusline.insert"], normal),
+		prefix,
+		command: prefix,
+	};
+	let semantic = SemanticColors {
+		error: lookup.fg(&["error", "diagnostic.error"]).unwrap_or(Color::Red),
+		warning: lookup.fg(&["warning", "diagnostic.warning"]).unwrap_or(Color::Yellow),
+		success: lookup.fg(&["diff.plus"]).unwrap_or(Color::Green),
+		info: lookup.fg(&["info", "diagnostic.info"]).unwrap_or(Color::Blue),
+		hint: lookup.fg(&["hint", "diagnostic.hint"]).unwrap_or(Color::Cyan),
+		dim: lookup.fg(&["ui.virtual", "comment"]).unwrap_or(Color::DarkGray),
+		link: lookup.fg(&["markup.link.url", "markup.link"]).unwrap_or(Color::Blue),
+		match_hl: lookup.bg(&["ui.cursor.match"]).unwrap_or(ui.selection_bg),
+		accent: lookup.fg(&["ui.text.focus"]).unwrap_or(ui.cursor_bg),
+	};
+	let popup_fg = lookup.fg(&["ui.popup", "ui.menu"]).unwrap_or(ui.fg);
+	let popup = PopupColors {
+		bg: lookup.bg(&["ui.popup", "ui.menu"]).unwrap_or(ui.bg),
+		fg: popup_fg,
+		border: lookup.fg(&["ui.window"]).unwrap_or(ui.gutter_fg),
+		title: lookup.fg(&["ui.help"]).unwrap_or(popup_fg),
+	};
+	let syntax = syntax_styles(&lookup, &mut warn);
+
+	let variant = if ui.bg.luminance() > 0.5 { ThemeVariant::Light } else { ThemeVariant::Dark };
+	let theme = LinkedThemeDef {
+		meta: crate::core::LinkedMetaOwned {
+			id: format!("xeno-registry::{name}"),
+			name: name.to_string(),
+			keys: Vec::new(),
+			categories: Vec::new(),
+			short_desc: name.to_string(),
+			description: String::new(),
+			priority: 0,
+			source: crate::core::RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		payload: crate::themes::theme::ThemePayload {
+			variant,
+			colors: crate::themes::ThemeColors {
+				ui,
+				mode,
+				semantic,
+				popup,
+				notification: crate::themes::NotificationColors::INHERITED,
+				syntax,
+			},
+		},
+	};
+	Ok(HelixTheme { theme, warnings })
+}
+
+/// Style keys within a Helix style table.
+const STYLE_FIELDS: &[&str] = &["fg", "bg", "modifiers", "underline"];
+
+/// Flattens `key = value` into scope styles, descending into nested tables that
+/// TOML dotted keys such as `ui.background = { .. }` produce.
+fn collect_styles(key: &str, value: &Value, palette: &HashMap<String, Color>, styles: &mut HashMap<String, SyntaxStyle>, warn: &mut impl FnMut(&str, &str)) {
+	let color = |field: &str, value: &Value, warn: &mut dyn FnMut(&str, &str)| {
+		let color = value.as_str().and_then(|name| palette.get(name).copied().or_else(|| parse_color(name).ok()));
+		if color.is_none() {
+			warn(&format!("{key}.{field}"), "unknown color; field ignored");
+		}
+		color
+	};
+
+	match value {
+		Value::String(_) => {
+			styles.insert(
+				key.to_string(),
+				SyntaxStyle {
+					fg: color("fg", value, warn),
+					..SyntaxStyle::NONE
+				},
+			);
+		}
+		Value::Table(fields) => {
+			let mut style = SyntaxStyle::NONE;
+			for (field, value) in fields {
+				match field.as_str() {
+					"fg" => style.fg = color(field, value, warn),
+					"bg" => style.bg = color(field, value, warn),
+					"modifiers" => style.modifiers = modifiers(key, value, warn),
+					"underline" => warn(&format!("{key}.underline"), "underline styles are not supported"),
+					child => collect_styles(&format!("{key}.{child}"), value, palette, styles, warn),
+				}
+			}
+			if fields.keys().any(|field| STYLE_FIELDS.contains(&field.as_str())) {
+				styles.insert(key.to_string(), style);
+			}
+		}
+		_ => warn(key, "expected a color string or style table"),
+	}
+}
+
+fn modifiers(key: &str, value: &Value, warn: &mut impl FnMut(&str, &str)) -> Modifier {
+	let Some(list) = value.as_array() else {
+		warn(&format!("{key}.modifiers"), "expected a list of modifiers");
+		return Modifier::empty();
+	};
+	let mut modifiers = Modifier::empty();
+	for entry in list {
+		match entry.as_str().map(parse_modifier) {
+			Some(Ok(modifier)) => modifiers |= modifier,
+			_ => warn(&format!("{key}.modifiers"), &format!("unknown modifier {entry}; ignored")),
+		}
+	}
+	modifiers
+}
+
+/// Scope styles with a record of which keys fed a theme field.
+struct Lookup {
+	styles: HashMap<String, SyntaxStyle>,
+	used: HashSet<String>,
+}
+
+impl Lookup {
+	/// Returns the first color that `channel` yields among `keys`, marking every present key as used.
+	fn find(&mut self, keys: &[&str], channel: impl Fn(&SyntaxStyle) -> Option<Color>) -> Option<Color> {
+		let mut found = None;
+		for key in keys {
+			if let Some(style) = self.styles.get(*key) {
+				self.used.insert(key.to_string());
+				found = found.or_else(|| channel(style));
+			}
+		}
+		found
+	}
+
+	fn fg(&mut self, keys: &[&str]) -> Option<Color> {
+		self.find(keys, |style| style.fg)
+	}
+
+	fn bg(&mut self, keys: &[&str]) -> Option<Color> {
+		self.find(keys, |style| style.bg)
+	}
+
+	fn pair(&mut self, keys: &[&str], fallback: ColorPair) -> ColorPair {
+		ColorPair::new(self.bg(keys).unwrap_or(fallback.bg), self.fg(keys).unwrap_or(fallback.fg))
+	}
+}
+
+fn ui_colors(lookup: &mut Lookup) -> UiColors {
+	let bg = lookup.bg(&["ui.background"]).unwrap_or(Color::Reset);
+	let fg = lookup.fg(&["ui.text"]).unwrap_or(Color::Reset);
+	let cursor = ["ui.cursor.primary", "ui.cursor"];
+	let cursor_bg = lookup.bg(&cursor).unwrap_or(fg);
+	let selection = ["ui.selection.primary", "ui.selection"];
+	UiColors {
+		bg,
+		fg,
+		nontext_bg: bg.blend(Color::Black, 0.85),
+		gutter_fg: lookup.fg(&["ui.linenr"]).unwrap_or(fg),
+		cursor_bg,
+		cursor_fg: lookup.fg(&cursor).unwrap_or(bg),
+		cursorline_bg: lookup.bg(&["ui.cursorline.primary", "ui.cursorline"]).unwrap_or(bg),
+		selection_bg: lookup.bg(&selection).unwrap_or(cursor_bg),
+		selection_fg: lookup.fg(&selection).unwrap_or(fg),
+		message_fg: lookup.fg(&["ui.statusline"]).unwrap_or(fg),
+		command_input_fg: lookup.fg(&["ui.text.focus"]).unwrap_or(fg),
+	}
+}
+
+/// Maps every non-UI scope onto its exact or nearest known syntax slot.
+///
+/// Fallbacks are applied before exact matches so an explicitly styled parent
+/// scope always wins over a descendant mapped onto it.
+fn syntax_styles(lookup: &Lookup, warn: &mut impl FnMut(&str, &str)) -> SyntaxStyles {
+	let known = SyntaxStyles::minimal();
+	let mut keys: Vec<_> = lookup.styles.keys().collect();
+	keys.sort();
+
+	let mut exact = Vec::new();
+	let mut fallback = Vec::new();
+	for key in keys {
+		let style = lookup.styles[key];
+		if key.starts_with("ui.") || key.starts_with("diagnostic") {
+			if !lookup.used.contains(key) {
+				warn(key, "no equivalent in xeno themes; ignored");
+			}
+			continue;
+		}
+		let mut scope = key.as_str();
+		loop {
+			if known.get_by_scope(scope).is_some() {
+				if scope == key {
+					exact.push((scope, style))
+				} else {
+					fallback.push((scope, style))
+				}
+				break;
+			}
+			match scope.rfind('.') {
+				Some(idx) => scope = &scope[..idx],
+				None => {
+					if !lookup.used.contains(key) {
+						warn(key, "no matching syntax scope; ignored");
+					}
+					break;
+				}
+			}
+		}
+	}
+
+	let mut styles = SyntaxStyles::minimal();
+	for (scope, style) in fallback.into_iter().chain(exact) {
+		set_syntax_style(&mut styles, scope, style);
+	}
+	styles
+}
+
+#[cfg(test)]
+mod tests;
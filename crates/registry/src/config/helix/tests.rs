@@ -0,0 +1,66 @@
+use xeno_primitives::{Color, Modifier};
+
+use super::*;
+
+const SAMPLE: &str = include_str!("sample.toml");
+
+fn rgb(hex: u32) -> Color {
+	Color::from_u32(hex)
+}
+
+#[test]
+fn sample_theme_maps_palette_ui_and_syntax() {
+	let Ok(HelixTheme { theme, warnings }) = parse_helix_theme_str("gruvbox_helix", SAMPLE) else {
+		panic!("sample theme should parse");
+	};
+	assert_eq!(theme.meta.id, "xeno-registry::gruvbox_helix");
+	let colors = theme.payload.colors;
+	assert_eq!(theme.payload.variant, ThemeVariant::Dark);
+
+	assert_eq!(colors.ui.bg, rgb(0x282828));
+	assert_eq!(colors.ui.fg, rgb(0xfbf1c7));
+	assert_eq!((colors.ui.cursor_bg, colors.ui.cursor_fg), (rgb(0xfe8019), rgb(0x282828)));
+	assert_eq!(colors.ui.selection_bg, rgb(0x504945));
+	assert_eq!(colors.ui.gutter_fg, rgb(0x928374));
+	assert_eq!(colors.ui.cursorline_bg, colors.ui.bg, "missing cursorline falls back to the background");
+
+	assert_eq!(colors.mode.insert.bg, rgb(0xb8bb26));
+	assert_eq!(colors.mode.normal.bg, colors.ui.cursor_bg, "missing statusline modes fall back to the cursor");
+	assert_eq!(colors.popup.bg, rgb(0x3c3836));
+	assert_eq!(colors.popup.border, rgb(0x928374), "dotted TOML keys are flattened");
+	assert_eq!(colors.semantic.error, rgb(0xfb4934));
+	assert_eq!(colors.semantic.success, rgb(0xb8bb26));
+
+	assert_eq!(colors.syntax.keyword.fg, Some(rgb(0xfb4934)));
+	assert_eq!(colors.syntax.keyword.modifiers, Modifier::BOLD);
+	assert_eq!(
+		colors.syntax.keyword_control.fg,
+		Some(rgb(0xfe8019)),
+		"unknown scopes style their nearest ancestor"
+	);
+	assert_eq!(colors.syntax.function_builtin.modifiers, Modifier::ITALIC);
+	assert_eq!(colors.syntax.string.fg, Some(rgb(0xb8bb26)));
+
+	let mut keys: Vec<_> = warnings.iter().map(|warning| warning.key.as_str()).collect();
+	keys.sort();
+	assert_eq!(keys, ["diagnostic.error.underline", "inherits", "tabstop", "type.fg", "ui.bufferline"]);
+}
+
+#[test]
+fn exact_scope_wins_over_descendant_fallback() {
+	let input = r##"
+"keyword.control" = "#00ff00"
+"keyword.control.flow" = "#ff0000"
+"ui.background" = { bg = "#fdf6e3" }
+"##;
+	let Ok(HelixTheme { theme, .. }) = parse_helix_theme_str("light", input) else {
+		panic!("theme should parse");
+	};
+	assert_eq!(theme.payload.colors.syntax.keyword_control.fg, Some(rgb(0x00ff00)));
+	assert_eq!(theme.payload.variant, ThemeVariant::Light);
+}
+
+#[test]
+fn malformed_toml_is_an_error() {
+	assert!(matches!(parse_helix_theme_str("broken", "\"ui.text\" = {"), Err(ConfigError::Toml(_))));
+}
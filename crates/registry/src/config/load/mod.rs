@@ -68,6 +68,8 @@ fn merge_layer(report: &mut ConfigLoadReport, merged: &mut Config, found_any: &m
 			for warning in config.warnings.drain(..) {
 				report.warnings.push((path_buf.clone(), warning));
 			}
+			#[cfg(feature = "options")]
+			config.set_source_file(path);
 			merged.merge(config);
 			*found_any = true;
 		}
@@ -41,6 +41,28 @@ fn load_nuon_layer() {
 	let _ = std::fs::remove_dir_all(dir);
 }
 
+#[cfg(feature = "config-nuon")]
+#[test]
+fn load_records_option_source_file_and_line() {
+	let dir = unique_temp_dir("source");
+	let path = dir.join("config.nuon");
+	write_file(
+		&path,
+		"{\n  languages: [\n    { name: rust, options: { tab-width: 8 } }\n  ]\n  # tab-width: 3\n  options: {\n    theme: \"gruvbox\"\n    tab-width: 2\n  }\n}\n",
+	);
+
+	let config = load_user_config_from_dir(&dir).config.expect("nuon config should load");
+	let tab_width = crate::options::find("tab-width").expect("tab-width option should exist");
+	let source = |line| crate::options::OptionSource {
+		file: Some(path.clone()),
+		line: Some(line),
+	};
+	assert_eq!(config.options.source(tab_width.dense_id()), Some(&source(8)));
+	assert_eq!(config.languages[0].options.source(tab_width.dense_id()), Some(&source(3)));
+
+	let _ = std::fs::remove_dir_all(dir);
+}
+
 #[cfg(all(feature = "config-nuon", feature = "config-nu"))]
 #[test]
 fn load_order_precedence_nuon_nu() {
@@ -1,7 +1,8 @@
 //! Configuration types for Xeno.
 //!
 //! This module provides unified configuration structures that are format-neutral.
-//! NUON and Nu script parsing are available behind `config-nuon` and `config-nu`.
+//! NUON and Nu script parsing are available behind `config-nuon` and `config-nu`;
+//! Helix TOML theme import is behind `config-helix`.
 
 use std::collections::{HashMap, HashSet};
 
@@ -14,6 +15,9 @@ pub mod nuon;
 #[cfg(feature = "config-nu")]
 pub mod nu;
 
+#[cfg(feature = "config-helix")]
+pub mod helix;
+
 #[cfg(feature = "config-nuon")]
 pub mod load;
 
@@ -27,6 +31,18 @@ pub struct LanguageConfig {
 	pub options: crate::options::OptionStore,
 }
 
+/// Configuration for a filetype-specific override.
+///
+/// Filetype names are free-form and need not correspond to a known language.
+#[derive(Debug, Clone)]
+pub struct FiletypeConfig {
+	/// Filetype name (e.g., "markdown", "txt").
+	pub name: String,
+	/// Option overrides for this filetype.
+	#[cfg(feature = "options")]
+	pub options: crate::options::OptionStore,
+}
+
 /// Unresolved keybinding configuration (structured invocations before registry resolution).
 ///
 /// Each binding maps a key sequence to either an invocation (`Some`) or an
@@ -82,7 +98,7 @@ impl KeymapConfig {
 /// but do not prevent the configuration from being loaded.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigWarning {
-	/// An option was used in the wrong scope (e.g., global option in language block).
+	/// An option was used in the wrong scope (e.g., global option in language or filetype block).
 	ScopeMismatch {
 		/// The option's config key.
 		option: String,
@@ -121,6 +137,11 @@ pub enum ConfigError {
 	#[error("Nu runtime error: {0}")]
 	NuRuntime(String),
 
+	/// Error parsing TOML syntax.
+	#[cfg(feature = "config-helix")]
+	#[error("TOML parse error: {0}")]
+	Toml(String),
+
 	/// A required field is missing from the configuration.
 	#[error("missing required field: {0}")]
 	MissingField(String),
@@ -185,6 +206,26 @@ pub enum ConfigError {
 /// Result type for configuration operations.
 pub type Result<T> = std::result::Result<T, ConfigError>;
 
+/// Theme parse failure located in the source text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}: {message}")]
+pub struct ThemeParseError {
+	/// 1-based line of the offending value, or 0 when it could not be located.
+	pub line: u32,
+	pub message: String,
+}
+
+/// Options section parse failure located in the source text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{line}:{column}: {message}")]
+pub struct OptionParseError {
+	/// 1-based line of the offending key, or 0 when it could not be located.
+	pub line: u32,
+	/// 1-based column of the offending key, or 0 when it could not be located.
+	pub column: u32,
+	pub message: String,
+}
+
 /// User-configurable overrides for Nu decode safety budgets.
 ///
 /// Each field, when `Some`, overrides the corresponding default in
@@ -302,7 +343,7 @@ fn default_hook_permissions() -> HashSet<xeno_invocation::nu::NuPermission> {
 
 /// Parsed configuration from a config file.
 ///
-/// May contain any combination of keymap, options, and language settings.
+/// May contain any combination of keymap, options, language, and filetype settings.
 #[derive(Clone, Default)]
 pub struct Config {
 	/// Keymap configuration (preset + key overrides).
@@ -315,6 +356,11 @@ pub struct Config {
 	pub options: crate::options::OptionStore,
 	/// Per-language option overrides.
 	pub languages: Vec<LanguageConfig>,
+	/// Per-filetype option overrides.
+	pub filetypes: Vec<FiletypeConfig>,
+	/// User-defined delimiter-pair text objects, registered at runtime.
+	#[cfg(feature = "textobj")]
+	pub text_objects: Vec<crate::textobj::OwnedTextObjectDef>,
 	/// Non-fatal warnings encountered during parsing.
 	pub warnings: Vec<ConfigWarning>,
 }
@@ -331,7 +377,12 @@ impl std::fmt::Debug for Config {
 		#[cfg(feature = "options")]
 		s.field("options", &self.options);
 
-		s.field("languages", &self.languages).field("warnings", &self.warnings).finish()
+		s.field("languages", &self.languages).field("filetypes", &self.filetypes);
+
+		#[cfg(feature = "textobj")]
+		s.field("text_objects", &self.text_objects);
+
+		s.field("warnings", &self.warnings).finish()
 	}
 }
 
@@ -356,5 +407,21 @@ impl Config {
 		self.options.merge(&other.options);
 
 		self.languages.extend(other.languages);
+		self.filetypes.extend(other.filetypes);
+
+		#[cfg(feature = "textobj")]
+		self.text_objects.extend(other.text_objects);
+	}
+
+	/// Attributes every option value in this config to config file `path`.
+	#[cfg(feature = "options")]
+	pub fn set_source_file(&mut self, path: &std::path::Path) {
+		self.options.set_source_file(path);
+		for language in &mut self.languages {
+			language.options.set_source_file(path);
+		}
+		for filetype in &mut self.filetypes {
+			filetype.options.set_source_file(path);
+		}
 	}
 }
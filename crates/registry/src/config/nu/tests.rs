@@ -116,6 +116,53 @@ fn config_nu_parses_structured_keys_with_builtins() {
 	));
 }
 
+#[test]
+fn config_nu_keybind_records_round_trip_into_keys() {
+	let input = r#"{
+		keymap: {
+			keys: [
+				(xeno keybind "ctrl-s" (xeno effect dispatch command write)),
+				(xeno keybind --mode insert "g r" (xeno effect dispatch editor reload_config)),
+			]
+		}
+	}"#;
+	let config = eval_config_str(input, "config.nu").expect("keybind list should evaluate");
+	let keys = config.keymap.expect("keymap should be parsed").keys.expect("keys should be parsed");
+
+	let ctrl_s = keys.modes["normal"]["ctrl-s"].as_ref().expect("should not be unbind");
+	assert!(matches!(
+		ctrl_s,
+		crate::Invocation::Command(xeno_invocation::CommandInvocation { name, args, .. }) if name == "write" && args.is_empty()
+	));
+
+	let gr = keys.modes["insert"]["g r"].as_ref().expect("should not be unbind");
+	assert!(matches!(
+		gr,
+		crate::Invocation::Command(xeno_invocation::CommandInvocation {
+			name,
+			route: xeno_invocation::CommandRoute::Editor,
+			..
+		}) if name == "reload_config"
+	));
+}
+
+#[test]
+fn config_nu_keybind_rejects_invalid_key_at_eval() {
+	let input = r#"{ keymap: { keys: [(xeno keybind "ctrl+s" (xeno effect dispatch command write))] } }"#;
+	let err = eval_config_str(input, "config.nu").expect_err("invalid key should fail at evaluation");
+	match err {
+		ConfigError::NuRuntime(msg) => assert!(msg.contains("ctrl+s"), "got: {msg}"),
+		other => panic!("expected NuRuntime error, got: {other:?}"),
+	}
+}
+
+#[test]
+fn config_nu_keybind_list_rejects_unknown_fields() {
+	let input = r#"{ keymap: { keys: [{ mode: "normal", key: "x", invocation: "command:write", kinde: "action" }] } }"#;
+	let err = eval_config_str(input, "config.nu").expect_err("unknown field should be rejected");
+	assert!(matches!(err, ConfigError::UnknownField(ref f) if f == "keys[0].kinde"), "got: {err:?}");
+}
+
 #[test]
 fn config_nu_accepts_string_key_target() {
 	let input = r#"{
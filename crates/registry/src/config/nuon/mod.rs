@@ -1,22 +1,31 @@
 //! NUON configuration parsing for Xeno.
 
 use std::collections::HashMap;
+use std::ops::Range;
 
 use xeno_nu_data::{Record, Value};
 
-use super::{Config, ConfigError, ConfigWarning, DecodeBudgetOverrides, KeymapConfig, LanguageConfig, NuConfig, Result, UnresolvedKeys};
-use crate::options::{OptionScope, OptionStore};
+use super::{
+	Config, ConfigError, ConfigWarning, DecodeBudgetOverrides, FiletypeConfig, KeymapConfig, LanguageConfig, NuConfig, OptionParseError, Result,
+	ThemeParseError, UnresolvedKeys,
+};
+use crate::options::{OptionScope, OptionSource, OptionStore};
+use crate::textobj::OwnedTextObjectDef;
 
 /// Parse a NUON string into a [`Config`].
+///
+/// Option values record the line they were found on where it can be located.
 pub fn parse_config_str(input: &str) -> Result<Config> {
 	let value = parse_root_value(input)?;
-	parse_config_value(&value)
+	let mut config = parse_config_value(&value)?;
+	record_option_lines(input, &mut config);
+	Ok(config)
 }
 
 /// Parse a NUON value into a [`Config`].
 pub fn parse_config_value(value: &Value) -> Result<Config> {
 	let root = expect_record(value, "config")?;
-	validate_allowed_fields(root, &["keymap", "options", "languages", "nu"], "config")?;
+	validate_allowed_fields(root, &["keymap", "options", "languages", "filetypes", "nu", "text_objects"], "config")?;
 
 	let mut warnings = Vec::new();
 
@@ -30,62 +39,316 @@ pub fn parse_config_value(value: &Value) -> Result<Config> {
 		OptionStore::default()
 	};
 
-	let mut languages = Vec::new();
-	if let Some(value) = root.get("languages") {
-		for (idx, entry) in expect_list(value, "languages")?.iter().enumerate() {
-			let field = format!("languages[{idx}]");
-			let lang = expect_record(entry, &field)?;
-			validate_allowed_fields(lang, &["name", "options"], &field)?;
-
-			let name_field = format!("{field}.name");
-			let name = lang
-				.get("name")
-				.ok_or_else(|| ConfigError::MissingField(name_field.clone()))
-				.and_then(|v| expect_string(v, &name_field))?
-				.to_string();
-
-			let options = if let Some(v) = lang.get("options") {
-				let parsed = parse_options_with_context(v, ParseContext::Language, &format!("{field}.options"))?;
-				warnings.extend(parsed.warnings);
-				parsed.store
-			} else {
-				OptionStore::default()
-			};
-
-			languages.push(LanguageConfig { name, options });
-		}
-	}
+	let languages = parse_option_blocks(root.get("languages"), "languages", ParseContext::Language, &mut warnings)?
+		.into_iter()
+		.map(|(name, options)| LanguageConfig { name, options })
+		.collect();
+
+	let filetypes = parse_option_blocks(root.get("filetypes"), "filetypes", ParseContext::Filetype, &mut warnings)?
+		.into_iter()
+		.map(|(name, options)| FiletypeConfig { name, options })
+		.collect();
 
 	let nu = root.get("nu").map(parse_nu_config).transpose()?;
 
+	let text_objects = root.get("text_objects").map(parse_text_objects).transpose()?.unwrap_or_default();
+
 	Ok(Config {
 		keymap,
 		nu,
 		options,
 		languages,
+		filetypes,
+		text_objects,
 		warnings,
 	})
 }
 
+/// Parses a list of `{ name, options }` records such as `languages` or `filetypes`.
+fn parse_option_blocks(value: Option<&Value>, parent: &str, context: ParseContext, warnings: &mut Vec<ConfigWarning>) -> Result<Vec<(String, OptionStore)>> {
+	let Some(value) = value else {
+		return Ok(Vec::new());
+	};
+
+	let mut blocks = Vec::new();
+	for (idx, entry) in expect_list(value, parent)?.iter().enumerate() {
+		let field = format!("{parent}[{idx}]");
+		let block = expect_record(entry, &field)?;
+		validate_allowed_fields(block, &["name", "options"], &field)?;
+
+		let name_field = format!("{field}.name");
+		let name = block
+			.get("name")
+			.ok_or_else(|| ConfigError::MissingField(name_field.clone()))
+			.and_then(|v| expect_string(v, &name_field))?
+			.to_string();
+
+		let options = if let Some(v) = block.get("options") {
+			let parsed = parse_options_with_context(v, context, &format!("{field}.options"))?;
+			warnings.extend(parsed.warnings);
+			parsed.store
+		} else {
+			OptionStore::default()
+		};
+
+		blocks.push((name, options));
+	}
+	Ok(blocks)
+}
+
 /// Parse a standalone NUON theme file.
 pub fn parse_theme_standalone_str(input: &str) -> Result<crate::themes::LinkedThemeDef> {
 	let value = parse_root_value(input)?;
 	parse_theme_value(&value)
 }
 
+/// Parse a standalone NUON theme file, locating the first error in `input`.
+///
+/// Value errors (bad colors, modifiers, variants, palette references) report the
+/// line of the offending string; structural errors report the line of the last
+/// key on the field path. Errors that cannot be located, including NUON syntax
+/// errors, report line 0.
+pub fn parse_theme_located(input: &str) -> std::result::Result<crate::themes::LinkedThemeDef, ThemeParseError> {
+	let located = |error: ConfigError, offset: Option<usize>| ThemeParseError {
+		line: offset.map_or(0, |offset| line_at(input, offset)),
+		message: error.to_string(),
+	};
+
+	let value = parse_root_value(input).map_err(|e| located(e, None))?;
+	parse_theme_value(&value).map_err(|e| {
+		let offset = error_offset(input, &e);
+		located(e, offset)
+	})
+}
+
+/// Parses the top-level `options` record of a NUON config into key-value pairs.
+///
+/// Keys are not checked against the option registry and values are not
+/// coerced to an option's type; that is left to the caller. A document without
+/// an `options` record yields no pairs. Values that are not a bool, int, float
+/// or string are rejected, located at their key.
+pub fn parse_options_section(input: &str) -> std::result::Result<Vec<(String, crate::options::OptionValue)>, OptionParseError> {
+	let located = |message: String, offset: Option<usize>| {
+		let (line, column) = offset.map_or((0, 0), |offset| line_column_at(input, offset));
+		OptionParseError { line, column, message }
+	};
+
+	let value = parse_root_value(input).map_err(|e| located(e.to_string(), None))?;
+	let root = expect_record(&value, "config").map_err(|e| located(e.to_string(), None))?;
+	let Some(options) = root.get("options") else {
+		return Ok(Vec::new());
+	};
+	let block = option_blocks(input).global;
+	let record = expect_record(options, "options").map_err(|e| located(e.to_string(), find_token(input, "options:")))?;
+
+	record
+		.iter()
+		.map(|(key, raw)| {
+			value_to_option_value(raw).map(|value| (key.to_string(), value)).ok_or_else(|| {
+				let offset = block
+					.as_ref()
+					.and_then(|range| find_token(&input[range.clone()], &format!("{key}:")).map(|offset| range.start + offset));
+				located(invalid_type(&format!("options.{key}"), "bool, int, float or string", raw).to_string(), offset)
+			})
+		})
+		.collect()
+}
+
+/// Serializes a theme into standalone NUON accepted by [`parse_theme_standalone_str`].
+///
+/// Colors are written literally rather than through a palette, and only syntax
+/// scopes that carry a style are emitted.
+pub fn theme_to_nuon(theme: &crate::themes::LinkedThemeDef) -> String {
+	use std::fmt::Write;
+
+	use crate::config::utils::{format_color, format_modifier};
+	use crate::themes::{SyntaxStyles, ThemeVariant};
+
+	fn section(out: &mut String, name: &str, fields: &[(&str, xeno_primitives::Color)]) {
+		let _ = writeln!(out, "\t{name}: {{");
+		for (field, color) in fields {
+			let _ = writeln!(out, "\t\t{field}: {:?},", format_color(*color));
+		}
+		out.push_str("\t},\n");
+	}
+
+	let colors = &theme.payload.colors;
+	let mut out = String::from("{\n");
+	let _ = writeln!(out, "\tname: {:?},", theme.meta.name);
+	let variant = match theme.payload.variant {
+		ThemeVariant::Dark => "dark",
+		ThemeVariant::Light => "light",
+	};
+	let _ = writeln!(out, "\tvariant: {variant:?},");
+	if !theme.meta.keys.is_empty() {
+		let _ = writeln!(out, "\tkeys: {:?},", theme.meta.keys);
+	}
+
+	let ui = &colors.ui;
+	section(
+		&mut out,
+		"ui",
+		&[
+			("bg", ui.bg),
+			("fg", ui.fg),
+			("nontext-bg", ui.nontext_bg),
+			("gutter-fg", ui.gutter_fg),
+			("cursor-bg", ui.cursor_bg),
+			("cursor-fg", ui.cursor_fg),
+			("cursorline-bg", ui.cursorline_bg),
+			("selection-bg", ui.selection_bg),
+			("selection-fg", ui.selection_fg),
+			("message-fg", ui.message_fg),
+			("command-input-fg", ui.command_input_fg),
+		],
+	);
+	let mode = &colors.mode;
+	section(
+		&mut out,
+		"mode",
+		&[
+			("normal-bg", mode.normal.bg),
+			("normal-fg", mode.normal.fg),
+			("insert-bg", mode.insert.bg),
+			("insert-fg", mode.insert.fg),
+			("prefix-bg", mode.prefix.bg),
+			("prefix-fg", mode.prefix.fg),
+			("command-bg", mode.command.bg),
+			("command-fg", mode.command.fg),
+		],
+	);
+	let semantic = &colors.semantic;
+	section(
+		&mut out,
+		"semantic",
+		&[
+			("error", semantic.error),
+			("warning", semantic.warning),
+			("success", semantic.success),
+			("info", semantic.info),
+			("hint", semantic.hint),
+			("dim", semantic.dim),
+			("link", semantic.link),
+			("match", semantic.match_hl),
+			("accent", semantic.accent),
+		],
+	);
+	let popup = &colors.popup;
+	section(
+		&mut out,
+		"popup",
+		&[("bg", popup.bg), ("fg", popup.fg), ("border", popup.border), ("title", popup.title)],
+	);
+
+	out.push_str("\tsyntax: {\n");
+	for scope in SyntaxStyles::scope_names() {
+		let Some(style) = colors.syntax.get_by_scope(scope) else {
+			continue;
+		};
+		let mut fields = Vec::new();
+		if let Some(fg) = style.fg {
+			fields.push(format!("fg: {:?}", format_color(fg)));
+		}
+		if let Some(bg) = style.bg {
+			fields.push(format!("bg: {:?}", format_color(bg)));
+		}
+		if !style.modifiers.is_empty() {
+			fields.push(format!("mod: {:?}", format_modifier(style.modifiers)));
+		}
+		if !fields.is_empty() {
+			let _ = writeln!(out, "\t\t{scope:?}: {{ {} }},", fields.join(", "));
+		}
+	}
+	out.push_str("\t},\n}\n");
+	out
+}
+
 /// Parse a NUON value into a standalone theme definition.
 pub fn parse_theme_value(value: &Value) -> Result<crate::themes::LinkedThemeDef> {
-	use crate::config::utils::{ParseContext as ColorContext, parse_modifier};
+	use crate::config::utils::parse_modifier;
 	use crate::themes::theme::LinkedThemeDef;
 
 	let root = expect_record(value, "theme")?;
-	validate_allowed_fields(
-		root,
-		&["name", "variant", "keys", "palette", "ui", "mode", "semantic", "popup", "syntax"],
-		"theme",
-	)?;
+	validate_allowed_fields(root, &THEME_FIELDS[1..], "theme")?;
+	let (meta, variant, ctx) = parse_theme_header(root)?;
+
+	let ui = parse_ui_colors(root.get("ui"), &ctx)?;
+	let mode = parse_mode_colors(root.get("mode"), &ctx)?;
+	let semantic = parse_semantic_colors(root.get("semantic"), &ctx)?;
+	let popup = parse_popup_colors(root.get("popup"), &ctx)?;
+	let syntax = parse_syntax_styles(root.get("syntax"), &ctx, parse_modifier)?;
 
-	let mut ctx = ColorContext::default();
+	Ok(LinkedThemeDef {
+		meta,
+		payload: crate::themes::theme::ThemePayload {
+			variant: variant.unwrap_or_default(),
+			colors: crate::themes::ThemeColors {
+				ui,
+				mode,
+				semantic,
+				popup,
+				notification: crate::themes::NotificationColors::INHERITED,
+				syntax,
+			},
+		},
+	})
+}
+
+/// Parse a runtime NUON theme file, which may extend another theme.
+pub fn parse_runtime_theme_str(input: &str) -> Result<crate::themes::RuntimeTheme> {
+	let value = parse_root_value(input)?;
+	parse_runtime_theme_value(&value)
+}
+
+/// Parse a NUON value into a runtime theme.
+///
+/// Without an `inherits` field this is [`parse_theme_value`]. With one, every
+/// section and color is optional, and only the colors given override the
+/// parent once [`resolve_theme_inheritance`](crate::themes::resolve_theme_inheritance)
+/// runs.
+pub fn parse_runtime_theme_value(value: &Value) -> Result<crate::themes::RuntimeTheme> {
+	use crate::config::utils::parse_modifier;
+	use crate::themes::{RuntimeTheme, ThemeColorsPatch, ThemePatch};
+
+	let root = expect_record(value, "theme")?;
+	let Some(inherits) = root.get("inherits") else {
+		return parse_theme_value(value).map(|def| RuntimeTheme::Complete(Box::new(def)));
+	};
+	let inherits = expect_string(inherits, "inherits")?.to_string();
+	validate_allowed_fields(root, THEME_FIELDS, "theme")?;
+	let (meta, variant, ctx) = parse_theme_header(root)?;
+
+	let mut colors = ThemeColorsPatch::default();
+	patch_colors(root, "ui", &ctx, |key, color| colors.ui.set(key, color))?;
+	patch_colors(root, "mode", &ctx, |key, color| colors.mode.set(key, color))?;
+	patch_colors(root, "semantic", &ctx, |key, color| colors.semantic.set(key, color))?;
+	patch_colors(root, "popup", &ctx, |key, color| colors.popup.set(key, color))?;
+	if let Some(node) = root.get("syntax") {
+		for (name, value) in expect_record(node, "syntax")?.iter() {
+			parse_syntax_node(name, value, "", &mut |scope, style| colors.syntax.push((scope, style)), &ctx, parse_modifier)?;
+		}
+	}
+
+	Ok(RuntimeTheme::Patch(Box::new(ThemePatch {
+		meta,
+		inherits,
+		variant,
+		colors,
+	})))
+}
+
+/// Top-level theme fields; `inherits` is only accepted by runtime themes.
+const THEME_FIELDS: &[&str] = &["inherits", "name", "variant", "keys", "palette", "ui", "mode", "semantic", "popup", "syntax"];
+
+/// Parses the fields shared by complete and inheriting themes.
+fn parse_theme_header(
+	root: &Record,
+) -> Result<(
+	crate::core::LinkedMetaOwned,
+	Option<crate::themes::ThemeVariant>,
+	crate::config::utils::ParseContext,
+)> {
+	let mut ctx = crate::config::utils::ParseContext::default();
 	if let Some(value) = root.get("palette") {
 		let palette = expect_record(value, "palette")?;
 		for (name, color) in palette.iter() {
@@ -100,11 +363,7 @@ pub fn parse_theme_value(value: &Value) -> Result<crate::themes::LinkedThemeDef>
 		.and_then(|v| expect_string(v, "name"))?
 		.to_string();
 
-	let variant = root
-		.get("variant")
-		.map(|v| expect_string(v, "variant").and_then(parse_variant))
-		.transpose()?
-		.unwrap_or_default();
+	let variant = root.get("variant").map(|v| expect_string(v, "variant").and_then(parse_variant)).transpose()?;
 
 	let keys = if let Some(v) = root.get("keys") {
 		expect_list(v, "keys")?
@@ -119,39 +378,198 @@ pub fn parse_theme_value(value: &Value) -> Result<crate::themes::LinkedThemeDef>
 		Vec::new()
 	};
 
-	let ui = parse_ui_colors(root.get("ui"), &ctx)?;
-	let mode = parse_mode_colors(root.get("mode"), &ctx)?;
-	let semantic = parse_semantic_colors(root.get("semantic"), &ctx)?;
-	let popup = parse_popup_colors(root.get("popup"), &ctx)?;
-	let syntax = parse_syntax_styles(root.get("syntax"), &ctx, parse_modifier)?;
+	let meta = crate::core::LinkedMetaOwned {
+		id: format!("xeno-registry::{name}"),
+		short_desc: name.clone(),
+		name,
+		keys,
+		categories: Vec::new(),
+		description: String::new(),
+		priority: 0,
+		source: crate::core::RegistrySource::Runtime,
+		mutates_buffer: false,
+		deprecated: None,
+	};
+	Ok((meta, variant, ctx))
+}
 
-	let id = format!("xeno-registry::{name}");
+/// Feeds each color of the optional `section` record to `set`, which rejects unknown keys.
+fn patch_colors(
+	root: &Record,
+	section: &str,
+	ctx: &crate::config::utils::ParseContext,
+	mut set: impl FnMut(&str, xeno_primitives::Color) -> bool,
+) -> Result<()> {
+	let Some(node) = root.get(section) else {
+		return Ok(());
+	};
+	for (key, value) in expect_record(node, section)?.iter() {
+		let field = format!("{section}.{key}");
+		let color = ctx.resolve_color(expect_string(value, &field)?)?;
+		if !set(key, color) {
+			return Err(ConfigError::UnknownField(field));
+		}
+	}
+	Ok(())
+}
 
-	Ok(LinkedThemeDef {
-		meta: crate::core::LinkedMetaOwned {
-			id,
-			short_desc: name.clone(),
-			name,
-			keys,
-			description: String::new(),
-			priority: 0,
-			source: crate::core::RegistrySource::Runtime,
-			mutates_buffer: false,
-		},
-		payload: crate::themes::theme::ThemePayload {
-			variant,
-			colors: crate::themes::ThemeColors {
-				ui,
-				mode,
-				semantic,
-				popup,
-				notification: crate::themes::NotificationColors::INHERITED,
-				syntax,
-			},
-		},
+/// Finds the byte offset in `input` that a theme parse error refers to.
+///
+/// NUON values carry no source spans, so this searches the text for the
+/// offending value or, for structural errors, the last key on the field path.
+fn error_offset(input: &str, error: &ConfigError) -> Option<usize> {
+	match error {
+		ConfigError::InvalidColor(text) | ConfigError::InvalidModifier(text) | ConfigError::InvalidVariant(text) => find_token(input, text),
+		ConfigError::UndefinedPaletteColor(name) => find_token(input, &format!("${name}")),
+		ConfigError::MissingField(path) | ConfigError::UnknownField(path) | ConfigError::InvalidType { field: path, .. } => {
+			let key = path.rsplit('.').next()?;
+			let key = key.split_once('[').map_or(key, |(key, _)| key);
+			find_token(input, &format!("{key}:"))
+		}
+		_ => None,
+	}
+}
+
+/// Returns the offset of the first case-insensitive occurrence of `needle` not
+/// embedded in a longer identifier.
+fn find_token(input: &str, needle: &str) -> Option<usize> {
+	let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'-' || b == b'_';
+	let haystack = input.to_ascii_lowercase();
+	let needle = needle.trim().to_ascii_lowercase();
+	if needle.is_empty() {
+		return None;
+	}
+	let bytes = haystack.as_bytes();
+	haystack.match_indices(&needle).map(|(start, _)| start).find(|&start| {
+		let end = start + needle.len();
+		let open = start == 0 || !is_ident(bytes[start - 1]) || !is_ident(needle.as_bytes()[0]);
+		let close = end == bytes.len() || !is_ident(bytes[end]) || !is_ident(needle.as_bytes()[needle.len() - 1]);
+		open && close
 	})
 }
 
+/// Byte ranges of the option-bearing records in a config source.
+#[derive(Debug, Default, PartialEq)]
+struct OptionBlocks {
+	/// The top-level `options` record.
+	global: Option<Range<usize>>,
+	/// Each `languages` entry, in list order.
+	languages: Vec<Range<usize>>,
+	/// Each `filetypes` entry, in list order.
+	filetypes: Vec<Range<usize>>,
+}
+
+/// Records the source line of every option value in `config`.
+///
+/// NUON values carry no source spans, so each key is searched for within the
+/// text of the block it was parsed from.
+fn record_option_lines(input: &str, config: &mut Config) {
+	let blocks = option_blocks(input);
+	let mut stores: Vec<(&mut OptionStore, Option<&Range<usize>>)> = vec![(&mut config.options, blocks.global.as_ref())];
+	stores.extend(
+		config
+			.languages
+			.iter_mut()
+			.zip(&blocks.languages)
+			.map(|(lang, range)| (&mut lang.options, Some(range))),
+	);
+	stores.extend(
+		config
+			.filetypes
+			.iter_mut()
+			.zip(&blocks.filetypes)
+			.map(|(ft, range)| (&mut ft.options, Some(range))),
+	);
+
+	for (store, range) in stores {
+		let Some(range) = range else {
+			continue;
+		};
+		let located: Vec<_> = store
+			.iter()
+			.filter_map(|(id, value)| {
+				let opt = crate::db::OPTIONS.get_by_id(id)?;
+				let offset = find_token(&input[range.clone()], &format!("{}:", opt.resolve(opt.key)))?;
+				Some((opt, value.clone(), line_at(input, range.start + offset) as usize))
+			})
+			.collect();
+		for (opt, value, line) in located {
+			store.set_with_source(opt, value, OptionSource::at_line(line));
+		}
+	}
+}
+
+/// Scans `input` for the records that [`parse_config_value`] reads options from.
+///
+/// Tracks bracket depth outside strings and comments; top-level keys are the
+/// bare identifiers followed by `:` at depth 1.
+fn option_blocks(input: &str) -> OptionBlocks {
+	let bytes = input.as_bytes();
+	let mut blocks = OptionBlocks::default();
+	let mut depth = 0usize;
+	let mut top_key = "";
+	let mut open = 0;
+	let mut i = 0;
+	while i < bytes.len() {
+		match bytes[i] {
+			quote @ (b'"' | b'\'' | b'`') => {
+				i += 1;
+				while i < bytes.len() && bytes[i] != quote {
+					i += if bytes[i] == b'\\' && quote == b'"' { 2 } else { 1 };
+				}
+			}
+			b'#' => {
+				while i < bytes.len() && bytes[i] != b'\n' {
+					i += 1;
+				}
+			}
+			b'{' | b'[' => {
+				depth += 1;
+				if (depth == 2 && top_key == "options") || (depth == 3 && matches!(top_key, "languages" | "filetypes")) {
+					open = i;
+				}
+			}
+			b'}' | b']' => {
+				match (depth, top_key) {
+					(2, "options") => blocks.global = Some(open..i + 1),
+					(3, "languages") => blocks.languages.push(open..i + 1),
+					(3, "filetypes") => blocks.filetypes.push(open..i + 1),
+					_ => {}
+				}
+				depth = depth.saturating_sub(1);
+			}
+			b if depth == 1 && (b.is_ascii_alphabetic() || b == b'_') => {
+				let start = i;
+				while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'_' | b'-')) {
+					i += 1;
+				}
+				if input[i..].trim_start().starts_with(':') {
+					top_key = &input[start..i];
+				}
+				continue;
+			}
+			_ => {}
+		}
+		i += 1;
+	}
+	blocks
+}
+
+/// Returns the 1-based line containing byte `offset` of `input`.
+fn line_at(input: &str, offset: usize) -> u32 {
+	let offset = offset.min(input.len());
+	let newlines = input.as_bytes()[..offset].iter().filter(|&&b| b == b'\n').count();
+	u32::try_from(newlines + 1).unwrap_or(u32::MAX)
+}
+
+/// Returns the 1-based line and character column of byte `offset` of `input`.
+fn line_column_at(input: &str, offset: usize) -> (u32, u32) {
+	let offset = offset.min(input.len());
+	let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+	let column = input[line_start..offset].chars().count() + 1;
+	(line_at(input, offset), u32::try_from(column).unwrap_or(u32::MAX))
+}
+
 fn parse_root_value(input: &str) -> Result<Value> {
 	xeno_nu_api::parse_nuon(input).map_err(|e| ConfigError::Nuon(e.to_string()))
 }
@@ -219,6 +637,58 @@ fn parse_nu_config(value: &Value) -> Result<NuConfig> {
 	})
 }
 
+/// Parses `text_objects: [{ name, trigger, pair: [open, close] }]` delimiter-pair objects.
+///
+/// Optional fields are `alt_triggers`, `description`, and `priority`.
+fn parse_text_objects(value: &Value) -> Result<Vec<OwnedTextObjectDef>> {
+	let mut defs = Vec::new();
+	for (idx, entry) in expect_list(value, "text_objects")?.iter().enumerate() {
+		let field = format!("text_objects[{idx}]");
+		let record = expect_record(entry, &field)?;
+		validate_allowed_fields(record, &["name", "trigger", "alt_triggers", "pair", "description", "priority"], &field)?;
+
+		let required = |name: &str| {
+			let path = format!("{field}.{name}");
+			record.get(name).ok_or(ConfigError::MissingField(path.clone())).map(|value| (value, path))
+		};
+
+		let (name, path) = required("name")?;
+		let name = expect_string(name, &path)?;
+		let (trigger, path) = required("trigger")?;
+		let trigger = expect_char(trigger, &path)?;
+
+		let (pair, path) = required("pair")?;
+		let [open, close] = expect_list(pair, &path)? else {
+			return Err(ConfigError::Nuon(format!("{path} must list exactly two delimiters")));
+		};
+		let mut def = OwnedTextObjectDef::pair(
+			name,
+			trigger,
+			expect_char(open, &format!("{path}[0]"))?,
+			expect_char(close, &format!("{path}[1]"))?,
+		);
+
+		if let Some(value) = record.get("alt_triggers") {
+			let path = format!("{field}.alt_triggers");
+			def.alt_triggers = expect_list(value, &path)?
+				.iter()
+				.enumerate()
+				.map(|(i, v)| expect_char(v, &format!("{path}[{i}]")))
+				.collect::<Result<_>>()?;
+		}
+		if let Some(value) = record.get("description") {
+			def.description = expect_string(value, &format!("{field}.description"))?.to_string();
+		}
+		if let Some(value) = record.get("priority") {
+			let path = format!("{field}.priority");
+			let n = value.as_int().map_err(|_| invalid_type(&path, "int", value))?;
+			def.priority = i16::try_from(n).map_err(|_| ConfigError::Nuon(format!("{path} out of range: {n}")))?;
+		}
+		defs.push(def);
+	}
+	Ok(defs)
+}
+
 fn parse_decode_budget_overrides(value: &Value, parent: &str) -> Result<DecodeBudgetOverrides> {
 	let allowed = &["max_effects", "max_string_len", "max_args", "max_action_count", "max_nodes"];
 	let record = expect_record(value, parent)?;
@@ -254,6 +724,15 @@ fn expect_string<'a>(value: &'a Value, field: &str) -> Result<&'a str> {
 	value.as_str().map_err(|_| invalid_type(field, "string", value))
 }
 
+fn expect_char(value: &Value, field: &str) -> Result<char> {
+	let s = expect_string(value, field)?;
+	let mut chars = s.chars();
+	match (chars.next(), chars.next()) {
+		(Some(c), None) => Ok(c),
+		_ => Err(ConfigError::Nuon(format!("{field} must be a single character, got '{s}'"))),
+	}
+}
+
 fn invalid_type(field: &str, expected: &'static str, value: &Value) -> ConfigError {
 	ConfigError::InvalidType {
 		field: field.to_string(),
@@ -266,6 +745,7 @@ fn invalid_type(field: &str, expected: &'static str, value: &Value) -> ConfigErr
 enum ParseContext {
 	Global,
 	Language,
+	Filetype,
 }
 
 #[derive(Debug)]
@@ -288,20 +768,29 @@ fn parse_options_with_context(value: &Value, context: ParseContext, field: &str)
 			suggestion: suggest_option(opt_key),
 		})?;
 
-		if context == ParseContext::Language && def.scope == OptionScope::Global {
+		let found_in = match context {
+			ParseContext::Global => None,
+			ParseContext::Language => Some("language block"),
+			ParseContext::Filetype => Some("filetype block"),
+		};
+		if let Some(found_in) = found_in
+			&& def.scope == OptionScope::Global
+		{
 			warnings.push(ConfigWarning::ScopeMismatch {
 				option: opt_key.to_string(),
-				found_in: "language block",
+				found_in,
 				expected: "global options block",
 			});
 			continue;
 		}
 
-		let opt_value = value_to_option_value(raw_value).ok_or_else(|| ConfigError::OptionTypeMismatch {
-			option: opt_key.to_string(),
-			expected: option_type_name(def.value_type),
-			got: option_value_type(raw_value),
-		})?;
+		let opt_value = value_to_option_value(raw_value)
+			.ok_or_else(|| ConfigError::OptionTypeMismatch {
+				option: opt_key.to_string(),
+				expected: option_type_name(def.value_type),
+				got: option_value_type(raw_value),
+			})?
+			.coerce_to(def.value_type);
 
 		if !opt_value.matches_type(def.value_type) {
 			return Err(ConfigError::OptionTypeMismatch {
@@ -329,6 +818,9 @@ fn value_to_option_value(value: &Value) -> Option<crate::options::OptionValue> {
 	if let Ok(v) = value.as_int() {
 		return Some(crate::options::OptionValue::Int(v));
 	}
+	if let Ok(v) = value.as_float() {
+		return Some(crate::options::OptionValue::Float(v));
+	}
 	if let Ok(v) = value.as_str() {
 		return Some(crate::options::OptionValue::String(v.to_string()));
 	}
@@ -340,6 +832,8 @@ fn option_value_type(value: &Value) -> &'static str {
 		"bool"
 	} else if value.as_int().is_ok() {
 		"int"
+	} else if value.as_float().is_ok() {
+		"float"
 	} else if value.as_str().is_ok() {
 		"string"
 	} else {
@@ -351,6 +845,7 @@ fn option_type_name(ty: crate::options::OptionType) -> &'static str {
 	match ty {
 		crate::options::OptionType::Bool => "bool",
 		crate::options::OptionType::Int => "int",
+		crate::options::OptionType::Float => "float",
 		crate::options::OptionType::String => "string",
 	}
 }
@@ -367,6 +862,10 @@ fn parse_keymap_value(value: &Value) -> Result<KeymapConfig> {
 }
 
 fn parse_keys_value(value: &Value) -> Result<UnresolvedKeys> {
+	if let Value::List { vals, .. } = value {
+		return parse_keybind_list(vals);
+	}
+
 	let mut config = UnresolvedKeys::default();
 	let modes = expect_record(value, "keys")?;
 
@@ -385,6 +884,37 @@ fn parse_keys_value(value: &Value) -> Result<UnresolvedKeys> {
 	Ok(config)
 }
 
+/// Parse a list of `{ mode, key, invocation }` records as produced by `xeno keybind`.
+///
+/// Later entries win over earlier ones for the same mode and key.
+fn parse_keybind_list(entries: &[Value]) -> Result<UnresolvedKeys> {
+	let mut config = UnresolvedKeys::default();
+
+	for (idx, entry) in entries.iter().enumerate() {
+		let field = format!("keys[{idx}]");
+		let record = expect_record(entry, &field)?;
+		validate_allowed_fields(record, &["mode", "key", "invocation"], &field)?;
+
+		let required = |name: &str| -> Result<&str> {
+			let path = format!("{field}.{name}");
+			record
+				.get(name)
+				.ok_or_else(|| ConfigError::MissingField(path.clone()))
+				.and_then(|v| expect_string(v, &path))
+		};
+		let mode = required("mode")?;
+		let key = required("key")?;
+
+		let invocation = record
+			.get("invocation")
+			.ok_or_else(|| ConfigError::MissingField(format!("{field}.invocation")))?;
+		let inv = parse_keybinding_value_opt(invocation, &format!("keys.{mode}.{key}"))?;
+		config.modes.entry(mode.to_string()).or_default().insert(key.to_string(), inv);
+	}
+
+	Ok(config)
+}
+
 /// Parse a single keybinding value: `null` for unbind, string spec, record, or custom value.
 fn parse_keybinding_value_opt(value: &Value, field_path: &str) -> Result<Option<xeno_invocation::Invocation>> {
 	if matches!(value, Value::Nothing { .. }) {
@@ -509,16 +1039,24 @@ fn parse_syntax_styles(
 
 	let mut styles = crate::themes::SyntaxStyles::minimal();
 	for (name, value) in record.iter() {
-		parse_syntax_node(name, value, "", &mut styles, ctx, parse_modifier)?;
+		parse_syntax_node(
+			name,
+			value,
+			"",
+			&mut |scope, style| crate::config::utils::set_syntax_style(&mut styles, &scope, style),
+			ctx,
+			parse_modifier,
+		)?;
 	}
 	Ok(styles)
 }
 
+/// Parses the style at `prefix.name` and its nested scopes, passing each styled scope to `sink`.
 fn parse_syntax_node(
 	name: &str,
 	value: &Value,
 	prefix: &str,
-	styles: &mut crate::themes::SyntaxStyles,
+	sink: &mut dyn FnMut(String, crate::themes::SyntaxStyle),
 	ctx: &crate::config::utils::ParseContext,
 	parse_modifier: fn(&str) -> Result<xeno_primitives::Modifier>,
 ) -> Result<()> {
@@ -537,13 +1075,15 @@ fn parse_syntax_node(
 		style.modifiers = parse_modifier(expect_string(v, &format!("{scope}.mod"))?)?;
 	}
 
-	crate::config::utils::set_syntax_style(styles, &scope, style);
+	if style.fg.is_some() || style.bg.is_some() || !style.modifiers.is_empty() {
+		sink(scope.clone(), style);
+	}
 
 	for (child, child_value) in node.iter() {
 		if matches!(child.as_str(), "fg" | "bg" | "mod" | "modifiers") {
 			continue;
 		}
-		parse_syntax_node(child, child_value, &scope, styles, ctx, parse_modifier)?;
+		parse_syntax_node(child, child_value, &scope, sink, ctx, parse_modifier)?;
 	}
 
 	Ok(())
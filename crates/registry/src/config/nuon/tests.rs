@@ -230,3 +230,241 @@ fn parse_keymap_null_unbind() {
 	let binding = normal.get("h").expect("h should be in overrides");
 	assert!(binding.is_none(), "null should produce None (unbind)");
 }
+
+#[test]
+fn parse_config_filetypes_accept_unknown_names_and_warn_on_global_scope() {
+	let input = r#"{
+		filetypes: [
+			{ name: "not-a-real-filetype", options: { tab-width: 2, theme: "monokai" } },
+			{ name: "markdown" },
+		],
+	}"#;
+
+	let config = parse_config_str(input).expect("filetype blocks should parse");
+
+	assert_eq!(config.filetypes.len(), 2);
+	assert_eq!(config.filetypes[0].name, "not-a-real-filetype");
+	assert_eq!(config.filetypes[0].options.len(), 1);
+	assert!(config.filetypes[1].options.is_empty());
+	assert!(matches!(
+		config.warnings.as_slice(),
+		[ConfigWarning::ScopeMismatch {
+			option,
+			found_in: "filetype block",
+			expected: "global options block"
+		}] if option == "theme"
+	));
+}
+
+#[test]
+fn parse_config_float_option_accepts_float_and_int_literals() {
+	let multiplier = |input: &str| {
+		let config = parse_config_str(input).expect("config should parse");
+		let opt = crate::options::OPTIONS.get("scroll-multiplier").expect("builtin option");
+		config.options.get(opt.dense_id()).cloned()
+	};
+
+	assert_eq!(
+		multiplier("{ options: { scroll-multiplier: 1.5 } }"),
+		Some(crate::options::OptionValue::Float(1.5))
+	);
+	assert_eq!(
+		multiplier("{ options: { scroll-multiplier: 2 } }"),
+		Some(crate::options::OptionValue::Float(2.0)),
+		"int literals coerce for float options"
+	);
+	assert_eq!(multiplier("{ options: { scroll-multiplier: 50.0 } }"), None, "out-of-range values are rejected");
+
+	let err = parse_config_str(r#"{ options: { scroll-multiplier: "fast" } }"#).expect_err("strings are not floats");
+	assert!(matches!(
+		err,
+		ConfigError::OptionTypeMismatch {
+			expected: "float",
+			got: "string",
+			..
+		}
+	));
+}
+
+const MINIMAL_THEME: &str = r##"{
+	name: "round-trip",
+	variant: "light",
+	palette: { base: "#fafafa" },
+	ui: {
+		bg: "$base",
+		fg: "black",
+		gutter-fg: "dark-gray",
+		cursor-bg: "#303030",
+		cursor-fg: "white",
+		cursorline-bg: "#eeeeee",
+		selection-bg: "light-blue",
+		selection-fg: "black",
+		message-fg: "#a0522d",
+		command-input-fg: "black",
+	},
+	mode: {
+		normal-bg: "blue", normal-fg: "white",
+		insert-bg: "green", insert-fg: "black",
+		prefix-bg: "magenta", prefix-fg: "white",
+		command-bg: "yellow", command-fg: "black",
+	},
+	semantic: {
+		error: "red", warning: "yellow", success: "green", info: "cyan", hint: "gray",
+		dim: "dark-gray", link: "cyan", match: "green", accent: "42",
+	},
+	popup: { bg: "#f0f0f0", fg: "black", border: "gray", title: "blue" },
+	syntax: {
+		keyword: { fg: "#ff0000", mod: "bold italic", control: { fg: "magenta" } },
+		comment: { fg: "gray", bg: "#101010" },
+	},
+}"##;
+
+#[test]
+fn theme_to_nuon_round_trips_minimal_theme() {
+	let theme = parse_theme_located(MINIMAL_THEME).expect("theme should parse");
+	let serialized = theme_to_nuon(&theme);
+	let reparsed = parse_theme_located(&serialized).expect("serialized theme should parse");
+	assert_eq!(theme_to_nuon(&reparsed), serialized);
+
+	let colors = &reparsed.payload.colors;
+	assert_eq!(reparsed.meta.name, "round-trip");
+	assert!(matches!(reparsed.payload.variant, crate::themes::ThemeVariant::Light));
+	assert_eq!(colors.ui.bg, xeno_primitives::Color::Rgb(0xfa, 0xfa, 0xfa));
+	assert_eq!(colors.ui.message_fg, xeno_primitives::Color::Rgb(0xa0, 0x52, 0x2d));
+	assert_eq!(colors.semantic.accent, xeno_primitives::Color::Indexed(42));
+	assert_eq!(colors.syntax.keyword.fg, Some(xeno_primitives::Color::Rgb(0xff, 0, 0)));
+	assert_eq!(
+		colors.syntax.keyword.modifiers,
+		xeno_primitives::Modifier::BOLD | xeno_primitives::Modifier::ITALIC
+	);
+	assert_eq!(colors.syntax.keyword_control.fg, Some(xeno_primitives::Color::Magenta));
+	assert_eq!(colors.syntax.comment.bg, Some(xeno_primitives::Color::Rgb(0x10, 0x10, 0x10)));
+}
+
+#[test]
+fn parse_theme_located_reports_line_of_bad_hex_color() {
+	let input = MINIMAL_THEME.replace("message-fg: \"#a0522d\"", "message-fg: \"#zz0000\"");
+	let Err(err) = parse_theme_located(&input) else {
+		panic!("bad hex should fail");
+	};
+	assert_eq!(err.line, 14);
+	assert!(err.message.contains("#zz0000"), "{}", err.message);
+
+	let Err(err) = parse_theme_located(&MINIMAL_THEME.replace("\"$base\"", "\"$missing\"")) else {
+		panic!("undefined palette color should fail");
+	};
+	assert_eq!(err.line, 6);
+}
+
+#[test]
+fn runtime_theme_inherits_builtin_and_overrides_ui_bg() {
+	let input = r##"{
+	name: "gruvbox-darker",
+	inherits: "gruvbox",
+	ui: { bg: "#101010" },
+	syntax: { keyword: { control: { fg: "magenta" } } },
+}"##;
+	let Ok(runtime @ crate::themes::RuntimeTheme::Patch(_)) = parse_runtime_theme_str(input) else {
+		panic!("inheriting theme should parse as a patch");
+	};
+	let (themes, errors) = crate::themes::resolve_theme_inheritance(vec![runtime]);
+	assert!(errors.is_empty(), "{errors:?}");
+
+	let gruvbox = crate::themes::get_theme("gruvbox").expect("builtin gruvbox");
+	let colors = &themes[0].payload.colors;
+	assert_eq!(themes[0].meta.id, "xeno-registry::gruvbox-darker");
+	assert_eq!(colors.ui.bg, xeno_primitives::Color::Rgb(0x10, 0x10, 0x10));
+	assert_eq!(colors.ui.fg, gruvbox.colors.ui.fg);
+	assert_eq!(colors.mode.insert.bg, gruvbox.colors.mode.insert.bg);
+	assert_eq!(
+		colors.syntax.keyword.fg, gruvbox.colors.syntax.keyword.fg,
+		"an unstyled parent scope is not cleared"
+	);
+	assert_eq!(colors.syntax.keyword_control.fg, Some(xeno_primitives::Color::Magenta));
+
+	let Err(err) = parse_runtime_theme_str(r#"{ name: "bad", inherits: "gruvbox", ui: { background: "red" } }"#) else {
+		panic!("unknown color key should fail");
+	};
+	assert!(matches!(err, ConfigError::UnknownField(field) if field == "ui.background"));
+}
+
+#[test]
+fn parse_config_text_object_pairs() {
+	let input = r#"
+{
+text_objects: [
+	{ name: "guillemet", trigger: "«", pair: ["«", "»"], alt_triggers: ["»"] },
+	{ name: "latex_math", trigger: "$", pair: ["$", "$"], priority: 10, description: "Inline math" },
+]
+}
+"#;
+
+	let config = parse_config_str(input).expect("config should parse");
+	let guillemet = OwnedTextObjectDef {
+		alt_triggers: vec!['»'],
+		..OwnedTextObjectDef::pair("guillemet", '«', '«', '»')
+	};
+	let math = OwnedTextObjectDef {
+		description: "Inline math".to_string(),
+		priority: 10,
+		..OwnedTextObjectDef::pair("latex_math", '$', '$', '$')
+	};
+	assert_eq!(config.text_objects, [guillemet, math]);
+}
+
+#[test]
+fn parse_config_text_object_rejects_bad_delimiters() {
+	let err = parse_config_str(r#"{ text_objects: [{ name: "x", trigger: "x", pair: ["<<", ">>"] }] }"#).expect_err("multi-char delimiter");
+	assert!(err.to_string().contains("text_objects[0].pair[0] must be a single character"), "{err}");
+
+	let err = parse_config_str(r#"{ text_objects: [{ name: "x", trigger: "x", pair: ["<"] }] }"#).expect_err("single delimiter");
+	assert!(err.to_string().contains("exactly two delimiters"), "{err}");
+
+	let err = parse_config_str(r#"{ text_objects: [{ name: "x", pair: ["<", ">"] }] }"#).expect_err("missing trigger");
+	assert!(matches!(err, ConfigError::MissingField(field) if field == "text_objects[0].trigger"));
+}
+
+#[test]
+fn parse_options_section_converts_scalar_values() {
+	let input = r#"{
+	options: {
+		tab-width: 4,
+		cursorline: true,
+		theme: "gruvbox",
+		not-an-option: 1.5,
+	},
+	text_objects: [],
+}"#;
+	let pairs = parse_options_section(input).expect("options should parse");
+	assert_eq!(
+		pairs,
+		vec![
+			("tab-width".to_string(), crate::options::OptionValue::Int(4)),
+			("cursorline".to_string(), crate::options::OptionValue::Bool(true)),
+			("theme".to_string(), crate::options::OptionValue::String("gruvbox".to_string())),
+			("not-an-option".to_string(), crate::options::OptionValue::Float(1.5)),
+		],
+		"keys are not checked against the registry"
+	);
+	assert_eq!(parse_options_section("{ keymap: {} }"), Ok(Vec::new()));
+}
+
+#[test]
+fn parse_options_section_locates_unsupported_values() {
+	let input = "{\n\toptions: {\n\t\ttab-width: 4,\n\t\trulers: [80, 100],\n\t},\n}";
+	let Err(err) = parse_options_section(input) else {
+		panic!("list value should fail");
+	};
+	assert_eq!((err.line, err.column), (4, 3));
+	assert!(err.message.contains("options.rulers"), "{}", err.message);
+
+	let Err(err) = parse_options_section("{ options: [1, 2] }") else {
+		panic!("options list should fail");
+	};
+	assert_eq!((err.line, err.column), (1, 3));
+
+	let Err(err) = parse_options_section("{ options: ") else {
+		panic!("syntax error should fail");
+	};
+	assert_eq!((err.line, err.column), (0, 0));
+}
@@ -29,7 +29,8 @@ impl ParseContext {
 
 /// Parse a color value from a string.
 ///
-/// Supports hex (`#RGB`, `#RRGGBB`), named colors, and `reset`/`default`.
+/// Supports hex (`#RGB`, `#RRGGBB`), named colors, 256-color palette indices
+/// (`0`-`255`), and `reset`/`default`.
 pub fn parse_color(value: &str) -> Result<Color> {
 	let value = value.trim();
 
@@ -41,6 +42,10 @@ pub fn parse_color(value: &str) -> Result<Color> {
 		return parse_hex_color(hex);
 	}
 
+	if let Ok(index) = value.parse::<u8>() {
+		return Ok(Color::Indexed(index));
+	}
+
 	parse_named_color(value)
 }
 
@@ -92,6 +97,32 @@ fn parse_named_color(name: &str) -> Result<Color> {
 	}
 }
 
+/// Formats a color in the form accepted by [`parse_color`].
+pub fn format_color(color: Color) -> String {
+	let name = match color {
+		Color::Reset => "reset",
+		Color::Black => "black",
+		Color::Red => "red",
+		Color::Green => "green",
+		Color::Yellow => "yellow",
+		Color::Blue => "blue",
+		Color::Magenta => "magenta",
+		Color::Cyan => "cyan",
+		Color::Gray => "gray",
+		Color::DarkGray => "dark-gray",
+		Color::LightRed => "light-red",
+		Color::LightGreen => "light-green",
+		Color::LightYellow => "light-yellow",
+		Color::LightBlue => "light-blue",
+		Color::LightMagenta => "light-magenta",
+		Color::LightCyan => "light-cyan",
+		Color::White => "white",
+		Color::Rgb(r, g, b) => return format!("#{r:02x}{g:02x}{b:02x}"),
+		Color::Indexed(index) => return index.to_string(),
+	};
+	name.to_string()
+}
+
 /// Parse text modifiers from a space-separated string.
 pub fn parse_modifier(value: &str) -> Result<Modifier> {
 	let mut modifiers = Modifier::empty();
@@ -115,6 +146,28 @@ pub fn parse_modifier(value: &str) -> Result<Modifier> {
 	Ok(modifiers)
 }
 
+/// Formats modifiers as the space-separated form accepted by [`parse_modifier`].
+pub fn format_modifier(modifiers: Modifier) -> String {
+	const NAMES: &[(Modifier, &str)] = &[
+		(Modifier::BOLD, "bold"),
+		(Modifier::DIM, "dim"),
+		(Modifier::ITALIC, "italic"),
+		(Modifier::UNDERLINED, "underlined"),
+		(Modifier::SLOW_BLINK, "slow-blink"),
+		(Modifier::RAPID_BLINK, "rapid-blink"),
+		(Modifier::REVERSED, "reversed"),
+		(Modifier::HIDDEN, "hidden"),
+		(Modifier::CROSSED_OUT, "crossed-out"),
+	];
+
+	NAMES
+		.iter()
+		.filter(|(flag, _)| modifiers.contains(*flag))
+		.map(|(_, name)| *name)
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
 /// Sets a syntax style for the given scope name.
 pub(crate) fn set_syntax_style(styles: &mut crate::themes::SyntaxStyles, scope: &str, style: crate::themes::SyntaxStyle) {
 	if style.fg.is_none() && style.bg.is_none() && style.modifiers.is_empty() {
@@ -31,6 +31,40 @@ pub enum CommandError {
 	Other(String),
 }
 
+impl CommandError {
+	/// Wraps a filesystem error as [`CommandError::Io`].
+	pub fn from_io(error: std::io::Error) -> Self {
+		Self::Io(error.to_string())
+	}
+}
+
+impl From<std::io::Error> for CommandError {
+	fn from(error: std::io::Error) -> Self {
+		Self::from_io(error)
+	}
+}
+
+impl From<anyhow::Error> for CommandError {
+	/// Maps to [`CommandError::Other`], keeping the full context chain.
+	fn from(error: anyhow::Error) -> Self {
+		Self::Other(format!("{error:#}"))
+	}
+}
+
+impl From<String> for CommandError {
+	/// Maps to [`CommandError::InvalidArgument`].
+	fn from(message: String) -> Self {
+		Self::InvalidArgument(message)
+	}
+}
+
+impl From<&str> for CommandError {
+	/// Maps to [`CommandError::InvalidArgument`].
+	fn from(message: &str) -> Self {
+		Self::InvalidArgument(message.to_string())
+	}
+}
+
 /// Fatal insertion errors.
 #[derive(Debug, Clone, Error)]
 pub enum InsertFatal {
@@ -58,6 +92,15 @@ pub enum RegistryError {
 	Insert(#[from] InsertFatal),
 	#[error("plugin error: {0}")]
 	Plugin(String),
+	/// Registries depend on each other in a loop; holds the path, first name repeated last.
+	#[error("registry dependency cycle: {}", .0.join(" -> "))]
+	DependencyCycle(Vec<&'static str>),
+	/// A registry depends on a name that was never added.
+	#[error("registry '{registry}' depends on unknown registry '{dependency}'")]
+	UnknownDependency { registry: &'static str, dependency: &'static str },
+	/// The same registry name was added twice.
+	#[error("registry '{0}' added twice")]
+	DuplicateRegistry(&'static str),
 }
 
 /// Result of a successful key insertion.
@@ -70,3 +113,40 @@ pub enum InsertAction {
 	/// Key existed; replaced with new definition (policy chose new).
 	ReplacedExisting,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::CommandError;
+
+	#[test]
+	fn from_io_maps_to_io_variant() {
+		let err = CommandError::from_io(std::io::Error::new(std::io::ErrorKind::NotFound, "missing.txt"));
+		assert_eq!(err, CommandError::Io("missing.txt".to_string()));
+		assert_eq!(err.to_string(), "I/O error: missing.txt");
+	}
+
+	#[test]
+	fn io_error_converts_via_question_mark() {
+		fn open() -> Result<(), CommandError> {
+			Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied"))?;
+			Ok(())
+		}
+		assert_eq!(open(), Err(CommandError::Io("denied".to_string())));
+	}
+
+	#[test]
+	fn anyhow_error_maps_to_other_with_context() {
+		let err: CommandError = anyhow::anyhow!("root cause").context("while saving").into();
+		assert_eq!(err, CommandError::Other("while saving: root cause".to_string()));
+		assert_eq!(err.to_string(), "while saving: root cause");
+	}
+
+	#[test]
+	fn strings_map_to_invalid_argument() {
+		let owned: CommandError = String::from("bad width").into();
+		let borrowed: CommandError = "bad width".into();
+		assert_eq!(owned, CommandError::InvalidArgument("bad width".to_string()));
+		assert_eq!(owned, borrowed);
+		assert_eq!(borrowed.to_string(), "invalid argument: bad width");
+	}
+}
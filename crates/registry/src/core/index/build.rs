@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use super::collision::{Collision, CollisionKind, DuplicatePolicy, Party, cmp_party};
 use super::types::RegistryIndex;
-use crate::{DenseId, FrozenInterner, InternerBuilder, RegistryEntry, RegistrySource, Symbol};
+use crate::{DenseId, Deprecation, FrozenInterner, InternerBuilder, RegistryEntry, RegistrySource, Symbol};
 
 /// Context for interning and looking up strings during entry building.
 pub trait BuildCtx {
@@ -148,10 +148,12 @@ pub struct RegistryMetaRef<'a> {
 	pub id: &'a str,
 	pub name: &'a str,
 	pub keys: StrListRef<'a>,
+	pub categories: StrListRef<'a>,
 	pub description: &'a str,
 	pub priority: i16,
 	pub source: RegistrySource,
 	pub mutates_buffer: bool,
+	pub deprecated: Option<Deprecation<&'a str>>,
 }
 
 /// Trait for converting static or dynamic definitions into symbolized runtime entries.
@@ -174,6 +176,13 @@ pub trait BuildEntry<Out: RegistryEntry> {
 		collector.push(meta.name);
 		collector.push(meta.description);
 		meta.keys.for_each(|k| collector.push(k));
+		meta.categories.for_each(|c| collector.push(c));
+		if let Some(deprecated) = meta.deprecated {
+			collector.push(deprecated.since);
+			if let Some(replacement) = deprecated.replacement {
+				collector.push(replacement);
+			}
+		}
 
 		collector.push(self.short_desc_str());
 
@@ -272,7 +281,7 @@ where
 		}
 
 		// 5. Build Stage B and C maps with collision tracking
-		let (by_name, by_key, key_collisions) = build_stage_maps(self.label, &table, &parties, &key_pool, &by_id);
+		let (by_name, by_key, key_collisions) = build_stage_maps(self.label, &table, &[], &parties, &key_pool, &by_id);
 
 		// 6. Finalize collisions
 		let mut all_collisions = Vec::with_capacity(id_collisions.len() + key_collisions.len());
@@ -313,6 +322,8 @@ where
 			key_pool: Arc::from(key_pool),
 			collisions: Arc::from(all_collisions),
 			parties: Arc::from(parties),
+			policy: self.policy,
+			sorted: Default::default(),
 		}
 	}
 }
@@ -391,24 +402,7 @@ where
 	let mut parties = Vec::with_capacity(sorted_winners.len());
 
 	for entry in sorted_winners {
-		let mut prod_ctx = ProdBuildCtx { interner };
-
-		#[cfg(any(debug_assertions, feature = "registry-contracts"))]
-		let out = {
-			let mut sink = Vec::new();
-			entry.inner.collect_strings_all(&mut sink);
-			let collected = sink.into_iter().collect();
-			let mut ctx = DebugBuildCtx {
-				inner: &mut prod_ctx,
-				collected,
-				used: std::collections::HashSet::default(),
-			};
-			entry.inner.build(&mut ctx, &mut key_pool)
-		};
-
-		#[cfg(not(any(debug_assertions, feature = "registry-contracts")))]
-		let out = entry.inner.build(&mut prod_ctx, &mut key_pool);
-
+		let out = build_one(entry.inner.as_ref(), interner, &mut key_pool);
 		parties.push(Party {
 			def_id: out.meta().id,
 			source: out.meta().source,
@@ -421,6 +415,31 @@ where
 	(table, key_pool, parties)
 }
 
+/// Builds a single entry against an interner that already holds all of its strings.
+pub(crate) fn build_one<In, Out>(def: &In, interner: &FrozenInterner, key_pool: &mut Vec<Symbol>) -> Out
+where
+	In: BuildEntry<Out> + ?Sized,
+	Out: RegistryEntry,
+{
+	let mut prod_ctx = ProdBuildCtx { interner };
+
+	#[cfg(any(debug_assertions, feature = "registry-contracts"))]
+	{
+		let mut sink = Vec::new();
+		def.collect_strings_all(&mut sink);
+		let collected = sink.into_iter().collect();
+		let mut ctx = DebugBuildCtx {
+			inner: &mut prod_ctx,
+			collected,
+			used: std::collections::HashSet::default(),
+		};
+		def.build(&mut ctx, key_pool)
+	}
+
+	#[cfg(not(any(debug_assertions, feature = "registry-contracts")))]
+	def.build(&mut prod_ctx, key_pool)
+}
+
 pub(crate) struct IdCollisionRecord {
 	pub(crate) id_str: String,
 	pub(crate) winner_ordinal: u32,
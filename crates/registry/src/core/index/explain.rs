@@ -0,0 +1,180 @@
+//! Lookup explanations built from recorded collisions.
+//!
+//! # Role
+//!
+//! This module answers "why did this key resolve the way it did?" by combining the
+//! 3-stage lookup result with the collisions recorded for that key at build time.
+//! It is read-only and shared by [`RegistryIndex`] and [`Snapshot`].
+//!
+//! [`RegistryIndex`]: super::RegistryIndex
+//! [`Snapshot`]: super::Snapshot
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+
+use crate::core::{Collision, CollisionKind, DenseId, FrozenInterner, KeyKind, Party, RegistryEntry, Resolution, Symbol};
+
+/// Explanation of how a single lookup key resolved.
+#[derive(Debug)]
+pub struct Explanation<'a, T> {
+	/// Entry the key currently resolves to, if any.
+	pub winner: Option<&'a T>,
+	/// Lookup stage that produced the winner.
+	pub stage: Option<KeyKind>,
+	/// Effective entries that competed for the key and lost.
+	///
+	/// Entries dropped as canonical-ID duplicates are not part of the table and only
+	/// appear in [`Self::reason`].
+	pub losers: Vec<&'a T>,
+	/// Human-readable, one line per decision.
+	pub reason: String,
+}
+
+/// Borrowed view over the lookup state shared by indices and snapshots.
+pub(crate) struct ExplainView<'a, T, Id> {
+	pub table: &'a [Arc<T>],
+	pub by_id: &'a FxHashMap<Symbol, Id>,
+	pub by_name: &'a FxHashMap<Symbol, Id>,
+	pub by_key: &'a FxHashMap<Symbol, Id>,
+	pub interner: &'a FrozenInterner,
+	pub collisions: &'a [Collision],
+}
+
+impl<'a, T, Id> ExplainView<'a, T, Id>
+where
+	T: RegistryEntry,
+	Id: DenseId,
+{
+	/// Explains the resolution of `name`.
+	///
+	/// Returns `None` when `name` neither resolves nor appears in any collision.
+	pub(crate) fn explain(&self, name: &str) -> Option<Explanation<'a, T>> {
+		let sym = self.interner.get(name)?;
+		let resolved = [
+			(KeyKind::Canonical, self.by_id),
+			(KeyKind::PrimaryName, self.by_name),
+			(KeyKind::SecondaryKey, self.by_key),
+		]
+		.into_iter()
+		.find_map(|(stage, map)| map.get(&sym).map(|id| (stage, self.entry(*id))));
+		let related: Vec<&Collision> = self.collisions.iter().filter(|c| c.key == sym && !is_self_block(c)).collect();
+
+		if resolved.is_none() && related.is_empty() {
+			return None;
+		}
+
+		let mut lines = Vec::with_capacity(related.len() + 1);
+		match resolved {
+			Some((stage, entry)) => lines.push(format!(
+				"'{name}' resolves to '{}' via {stage} (priority {}, source {})",
+				self.interner.resolve(entry.id()),
+				entry.priority(),
+				entry.source(),
+			)),
+			None => lines.push(format!("'{name}' does not resolve to any entry")),
+		}
+		if related.is_empty() {
+			lines.push("no competing bindings".to_string());
+		}
+
+		let winner = resolved.map(|(_, entry)| entry);
+		let mut losers: Vec<&'a T> = Vec::new();
+		for collision in related {
+			lines.push(self.describe(collision));
+			if let Some(loser) = self.loser_entry(collision)
+				&& !winner.is_some_and(|w| std::ptr::eq(w, loser))
+				&& !losers.iter().any(|l| std::ptr::eq(*l, loser))
+			{
+				losers.push(loser);
+			}
+		}
+
+		Some(Explanation {
+			winner,
+			stage: resolved.map(|(stage, _)| stage),
+			losers,
+			reason: lines.join("\n"),
+		})
+	}
+
+	fn entry(&self, id: Id) -> &'a T {
+		&self.table[id.as_u32() as usize]
+	}
+
+	fn party_entry(&self, party: &Party) -> Option<&'a T> {
+		self.by_id.get(&party.def_id).map(|id| self.entry(*id))
+	}
+
+	fn loser_entry(&self, collision: &Collision) -> Option<&'a T> {
+		match collision.kind {
+			// Duplicate-id losers never reach the table; their def_id maps to the winner.
+			CollisionKind::DuplicateId { .. } => None,
+			CollisionKind::KeyConflict {
+				existing,
+				incoming,
+				resolution,
+				..
+			} => match resolution {
+				Resolution::KeptExisting => self.party_entry(&incoming),
+				Resolution::ReplacedExisting => self.party_entry(&existing),
+			},
+		}
+	}
+
+	fn describe(&self, collision: &Collision) -> String {
+		match collision.kind {
+			CollisionKind::DuplicateId { winner, loser, policy } => format!(
+				"duplicate id '{}': ordinal {} kept over ordinal {} under {policy:?}: {}",
+				self.interner.resolve(winner.def_id),
+				winner.ordinal,
+				loser.ordinal,
+				why_wins(&winner, &loser),
+			),
+			CollisionKind::KeyConflict {
+				existing_kind,
+				incoming_kind,
+				existing,
+				incoming,
+				resolution,
+			} => {
+				let (winner, loser, winner_kind, loser_kind) = match resolution {
+					Resolution::KeptExisting => (existing, incoming, existing_kind, incoming_kind),
+					Resolution::ReplacedExisting => (incoming, existing, incoming_kind, existing_kind),
+				};
+				let winner_id = self.interner.resolve(winner.def_id);
+				let loser_id = self.interner.resolve(loser.def_id);
+				if winner_kind != loser_kind {
+					format!("'{winner_id}' {winner_kind} blocks '{loser_id}' {loser_kind}: earlier lookup stage wins")
+				} else {
+					format!("'{winner_id}' beat '{loser_id}' for {winner_kind}: {}", why_wins(&winner, &loser))
+				}
+			}
+		}
+	}
+}
+
+/// Returns true for an entry whose name or key is blocked by its own canonical id.
+fn is_self_block(collision: &Collision) -> bool {
+	matches!(collision.kind, CollisionKind::KeyConflict { existing, incoming, .. } if existing.def_id == incoming.def_id)
+}
+
+/// Names the first precedence rule that separates `winner` from `loser`.
+///
+/// Mirrors [`super::precedence::cmp_party`]: priority, then source rank, then ingest ordinal.
+fn why_wins(winner: &Party, loser: &Party) -> String {
+	if winner.priority != loser.priority {
+		return format!("higher priority ({} > {})", winner.priority, loser.priority);
+	}
+	if winner.source.rank() != loser.source.rank() {
+		return format!("same priority {}, higher source rank ({} > {})", winner.priority, winner.source, loser.source);
+	}
+	match winner.ordinal.cmp(&loser.ordinal) {
+		Ordering::Greater => format!(
+			"same priority {} and source {}, later ingest ordinal ({} > {})",
+			winner.priority, winner.source, winner.ordinal, loser.ordinal
+		),
+		_ => format!("same priority {} and source {}, kept by duplicate policy", winner.priority, winner.source),
+	}
+}
@@ -56,10 +56,12 @@ pub(crate) fn test_key_collision_recording() {
 			id: "A",
 			name: "A",
 			keys: &["shared"],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let def_b = TestDef {
@@ -67,10 +69,12 @@ pub(crate) fn test_key_collision_recording() {
 			id: "B",
 			name: "B",
 			keys: &["shared"],
+			categories: &[],
 			description: "",
 			priority: 20,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	builder.push(std::sync::Arc::new(def_a));
@@ -93,10 +97,12 @@ pub(crate) fn test_id_override_eviction() {
 			id: "X",
 			name: "X",
 			keys: &[],
+			categories: &[],
 			description: "low priority",
 			priority: 5,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let high = TestDef {
@@ -104,10 +110,12 @@ pub(crate) fn test_id_override_eviction() {
 			id: "X",
 			name: "X",
 			keys: &[],
+			categories: &[],
 			description: "high priority",
 			priority: 50,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	builder.push(Arc::new(low));
@@ -132,10 +140,12 @@ pub(crate) fn test_canonical_id_ordinal_tiebreaker() {
 			id: "tie",
 			name: "first",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let second = TestDef {
@@ -143,10 +153,12 @@ pub(crate) fn test_canonical_id_ordinal_tiebreaker() {
 			id: "tie",
 			name: "second",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 
@@ -176,10 +188,12 @@ pub(crate) fn test_key_conflict_ordinal_tiebreaker() {
 			id: "Z",
 			name: "Z",
 			keys: &["shared"],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let def_second = TestDef {
@@ -187,10 +201,12 @@ pub(crate) fn test_key_conflict_ordinal_tiebreaker() {
 			id: "A",
 			name: "A",
 			keys: &["shared"],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 
@@ -221,10 +237,12 @@ pub(crate) fn test_id_override_keeps_name_binding_on_tie() {
 			id: "Z",
 			name: "shared",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let def_a_v1 = TestDef {
@@ -232,10 +250,12 @@ pub(crate) fn test_id_override_keeps_name_binding_on_tie() {
 			id: "A",
 			name: "shared",
 			keys: &[],
+			categories: &[],
 			description: "v1",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	let def_a_v2 = TestDef {
@@ -243,10 +263,12 @@ pub(crate) fn test_id_override_keeps_name_binding_on_tie() {
 			id: "A",
 			name: "shared",
 			keys: &[],
+			categories: &[],
 			description: "v2",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 
@@ -20,9 +20,12 @@ use rustc_hash::FxHashMap;
 use crate::core::{Collision, CollisionKind, DenseId, KeyKind, Party, Resolution, Symbol};
 
 /// Builds stage B (by_name) and stage C (by_key) maps with collision tracking.
+///
+/// Entries marked in `removed` are tombstones and get no lookups.
 pub(crate) fn build_stage_maps<Out, Id>(
 	registry_label: &'static str,
 	table: &[Arc<Out>],
+	removed: &[bool],
 	parties: &[Party],
 	key_pool: &[Symbol],
 	by_id: &FxHashMap<Symbol, Id>,
@@ -35,8 +38,10 @@ where
 	let mut by_key = FxHashMap::default();
 	let mut collisions = Vec::new();
 
+	let live = table.iter().enumerate().filter(|&(idx, _)| !removed.get(idx).copied().unwrap_or(false));
+
 	// Stage B: Primary names
-	for (idx, entry) in table.iter().enumerate() {
+	for (idx, entry) in live.clone() {
 		let dense_id = Id::from_u32(super::u32_index(idx, registry_label));
 		let name_sym = entry.name();
 		let party = parties[idx];
@@ -63,7 +68,7 @@ where
 	}
 
 	// Stage C: Secondary keys
-	for (idx, entry) in table.iter().enumerate() {
+	for (idx, entry) in live {
 		let dense_id = Id::from_u32(super::u32_index(idx, registry_label));
 		let party = parties[idx];
 		let meta = entry.meta();
@@ -5,7 +5,7 @@
 //! sorted, and interned consistently across all domains.
 
 use crate::core::index::build::RegistryMetaRef;
-use crate::core::{RegistryMeta, Symbol, SymbolList};
+use crate::core::{Deprecation, RegistryMeta, Symbol, SymbolList};
 
 /// Builds a symbolized [`RegistryMeta`] and interns keys into the pool.
 pub fn build_meta<'a>(
@@ -31,13 +31,33 @@ pub fn build_meta<'a>(
 	let len = (super::u32_index(key_pool.len(), "meta_build") - start) as u16;
 	debug_assert!(key_pool.len() as u32 - start <= u16::MAX as u32);
 
+	// Categories share the pool but occupy their own range so they never enter Stage C.
+	let categories = intern_list(ctx, key_pool, meta_ref.categories.to_vec());
+	let deprecated = meta_ref.deprecated.map(|d| Deprecation {
+		since: ctx.intern(d.since),
+		replacement: d.replacement.map(|r| ctx.intern(r)),
+	});
+
 	RegistryMeta {
 		id: ctx.intern(meta_ref.id),
 		name: ctx.intern(meta_ref.name),
 		description: ctx.intern(meta_ref.description),
 		keys: SymbolList { start, len },
+		categories,
 		priority: meta_ref.priority,
 		source: meta_ref.source,
 		mutates_buffer: meta_ref.mutates_buffer,
+		deprecated,
+	}
+}
+
+fn intern_list(ctx: &mut dyn super::build::BuildCtx, key_pool: &mut Vec<Symbol>, mut items: Vec<&str>) -> SymbolList {
+	let start = super::u32_index(key_pool.len(), "meta_build");
+	items.sort_unstable();
+	items.dedup();
+	for item in items {
+		key_pool.push(ctx.intern(item));
 	}
+	let len = (super::u32_index(key_pool.len(), "meta_build") - start) as u16;
+	SymbolList { start, len }
 }
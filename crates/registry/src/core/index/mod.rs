@@ -37,6 +37,14 @@
 //! 2. Stage B: Primary Name - Friendly display name lookup.
 //! 3. Stage C: Secondary Keys - User secondary keys and domain-specific lookup keys.
 //!
+//! Categories ([`crate::core::RegistryMeta::categories`]) share the key pool but occupy a
+//! separate range and never enter any lookup stage; they are only consulted by
+//! [`RegistryIndex::by_category`].
+//!
+//! Every dropped or blocked binding is recorded as a [`Collision`]; [`RegistryIndex::explain`]
+//! and [`Snapshot::explain`] replay those records for one key to report the winner, the
+//! losers, and the precedence rule that decided each conflict.
+//!
 //! # Key types
 //!
 //! | Type | Role |
@@ -66,7 +74,7 @@
 //!
 //! * Build-time bootstrap from builtins/plugins into immutable [`RegistryIndex`].
 //! * Runtime steady-state reads from latest snapshot.
-//! * Snapshot remains immutable for process lifetime.
+//! * Published snapshots are immutable; runtime writers publish a new generation.
 //!
 //! # Concurrency & ordering
 //!
@@ -90,6 +98,7 @@
 
 mod build;
 mod collision;
+mod explain;
 pub(crate) mod lookup;
 pub(crate) mod meta_build;
 pub mod precedence;
@@ -100,7 +109,8 @@ mod util;
 
 pub use build::{BuildCtx, BuildCtxExt, BuildEntry, RegistryBuilder, RegistryMetaRef, StrListRef, StringCollector};
 pub use collision::{Collision, CollisionKind, DuplicatePolicy, KeyKind, Party, Resolution};
-pub use runtime::{RuntimeEntry, RuntimeRegistry};
+pub use explain::Explanation;
+pub use runtime::{InsertResult, Replacement, RuntimeEntry, RuntimeRegistry};
 pub use snapshot::{RegistryRef, Snapshot, SnapshotGuard};
 pub use types::RegistryIndex;
 pub(crate) use util::u32_index;
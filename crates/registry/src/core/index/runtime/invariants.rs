@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
 use crate::core::index::build::RegistryBuilder;
-use crate::core::index::collision::DuplicatePolicy;
+use crate::core::index::collision::{CollisionKind, DuplicatePolicy};
 use crate::core::index::runtime::RuntimeRegistry;
 use crate::core::index::test_fixtures::{TestDef, TestEntry, make_def};
 use crate::core::symbol::ActionId;
 use crate::core::traits::RegistryEntry;
-use crate::core::{RegistryMetaStatic, RegistrySource};
+use crate::core::{DenseId, InsertAction, RegistryMetaStatic, RegistrySource};
 
 /// Must maintain deterministic iteration order by dense ID (table index).
 ///
@@ -77,10 +77,12 @@ pub(crate) fn test_source_precedence() {
 			id: "cmd",
 			name: "builtin",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}));
 	builder.push(Arc::new(TestDef {
@@ -88,10 +90,12 @@ pub(crate) fn test_source_precedence() {
 			id: "cmd",
 			name: "runtime",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Runtime,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}));
 
@@ -113,10 +117,12 @@ pub(crate) fn test_canonical_id_ordinal_tiebreaker() {
 			id: "tie",
 			name: "first",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}));
 	builder.push(Arc::new(TestDef {
@@ -124,10 +130,12 @@ pub(crate) fn test_canonical_id_ordinal_tiebreaker() {
 			id: "tie",
 			name: "second",
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority: 10,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}));
 
@@ -135,3 +143,107 @@ pub(crate) fn test_canonical_id_ordinal_tiebreaker() {
 	let resolved = registry.get("tie").expect("tie should resolve");
 	assert_eq!(resolved.name_str(), "second");
 }
+
+/// Runtime canonical-ID conflicts must follow the `ByPriority` precedence contract.
+///
+/// * Enforced in: `RuntimeRegistry::replace`, `party_wins`
+/// * Failure symptom: a lower-priority plugin entry shadows a higher-priority one.
+#[cfg_attr(test, test)]
+pub(crate) fn test_runtime_replace_respects_priority() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("cmd", 10)));
+	let registry = RuntimeRegistry::new("test", builder.build());
+	let original = registry.get("cmd").expect("cmd should resolve");
+
+	let higher = registry.replace(Arc::new(make_def("cmd", 20)));
+	assert_eq!(higher.action, InsertAction::ReplacedExisting);
+	assert_eq!(higher.evicted.expect("previous entry is evicted").priority(), 10);
+	let resolved = registry.get("cmd").expect("cmd should still resolve");
+	assert_eq!(resolved.priority(), 20);
+	assert_eq!(resolved.dense_id(), original.dense_id(), "replace keeps the dense ID");
+
+	let lower = registry.replace(Arc::new(make_def("cmd", 5)));
+	assert_eq!(lower.action, InsertAction::KeptExisting);
+	assert_eq!(lower.evicted.expect("rejected entry is returned").priority(), 5);
+	assert_eq!(registry.get("cmd").expect("cmd should still resolve").priority(), 20);
+	assert_eq!(original.priority(), 10, "stale refs keep their snapshot");
+}
+
+/// Runtime canonical-ID conflicts must follow the builder's `DuplicatePolicy`.
+///
+/// * Enforced in: `RuntimeRegistry::replace_locked`
+/// * Failure symptom: a registry built with `FirstWins` or `LastWins` silently resolves runtime conflicts by priority.
+#[cfg_attr(test, test)]
+pub(crate) fn test_runtime_replace_honors_builder_policy() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::with_policy("test", DuplicatePolicy::FirstWins);
+	builder.push(Arc::new(make_def("cmd", 10)));
+	let registry = RuntimeRegistry::new("test", builder.build());
+
+	let higher = registry.replace(Arc::new(make_def("cmd", 20)));
+	assert_eq!(higher.action, InsertAction::KeptExisting);
+	assert_eq!(registry.get("cmd").expect("cmd should still resolve").priority(), 10);
+
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::with_policy("test", DuplicatePolicy::LastWins);
+	builder.push(Arc::new(make_def("cmd", 10)));
+	let registry = RuntimeRegistry::new("test", builder.build());
+
+	let lower = registry.replace(Arc::new(make_def("cmd", 5)));
+	assert_eq!(lower.action, InsertAction::ReplacedExisting);
+	assert_eq!(registry.get("cmd").expect("cmd should still resolve").priority(), 5);
+	let collisions = registry.collisions();
+	let collision = collisions.last().expect("replacement should record a collision");
+	assert!(matches!(
+		collision.kind,
+		CollisionKind::DuplicateId {
+			policy: DuplicatePolicy::LastWins,
+			..
+		}
+	));
+}
+
+/// Every published mutation must bump `Snapshot::generation`.
+///
+/// * Enforced in: `RuntimeRegistry::publish`
+/// * Failure symptom: derived caches (keymaps, action lists) keep serving removed entries.
+#[cfg_attr(test, test)]
+pub(crate) fn test_runtime_mutations_bump_generation() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("alpha", 10)));
+	let registry = RuntimeRegistry::new("test", builder.build());
+	assert_eq!(registry.generation(), 0);
+
+	let inserted = registry.replace(Arc::new(make_def("beta", 10)));
+	assert_eq!(inserted.action, InsertAction::InsertedNew);
+	assert!(inserted.evicted.is_none());
+	assert_eq!(registry.generation(), 1);
+	assert_eq!(registry.len(), 2);
+	let beta = registry.get("beta").expect("beta should resolve after insert");
+
+	registry.replace(Arc::new(make_def("beta", 1)));
+	assert_eq!(registry.generation(), 1, "a kept existing entry must not publish");
+
+	let alpha_id = registry.get("alpha").expect("alpha should resolve").dense_id();
+	let removed = registry.remove(alpha_id).expect("alpha should be removed");
+	assert_eq!(removed.priority(), 10);
+	assert_eq!(registry.generation(), 2);
+	assert!(registry.get("alpha").is_none());
+	assert!(registry.get_by_id(alpha_id).is_none(), "a removed dense ID resolves to nothing");
+	assert_eq!(
+		registry.get("beta").expect("beta should still resolve").dense_id(),
+		beta.dense_id(),
+		"removal never shifts later dense IDs"
+	);
+	assert!(registry.remove(alpha_id).is_none(), "a tombstone cannot be removed twice");
+	assert_eq!(registry.len(), 1);
+	assert_eq!(registry.iter_all().map(|entry| entry.dense_id()).collect::<Vec<_>>(), [beta.dense_id()]);
+
+	assert!(registry.remove(ActionId::from_u32(7)).is_none());
+	assert_eq!(registry.generation(), 2);
+
+	assert!(registry.remove_canonical("beta", |_| false).is_none(), "a rejecting predicate keeps the entry");
+	assert!(registry.remove_canonical("missing", |_| true).is_none());
+	assert_eq!(registry.generation(), 2);
+	assert_eq!(registry.remove_canonical("beta", |_| true).expect("beta should be removed").priority(), 10);
+	assert_eq!(registry.generation(), 3);
+	assert!(registry.is_empty());
+}
@@ -1,44 +1,54 @@
-//! Immutable runtime registry container.
+//! Runtime registry container with copy-on-write snapshots.
 //! Anchor ID: XENO_ANCHOR_REGISTRY_RUNTIME
 //!
 //! # Purpose
 //!
-//! Provide lock-free reads on top of immutable snapshots built at bootstrap.
+//! Provide lock-free reads on top of immutable snapshots built at bootstrap, with
+//! serialized runtime writers for plugin load/unload.
 //!
 //! # Mental model
 //!
 //! * Readers pin an `Arc<Snapshot<...>>` and resolve lookups against that immutable view.
-//! * There are no runtime writers. Publication happens once during bootstrap.
+//! * Writers (`insert`, `replace`, `remove`) clone the current snapshot, rebuild lookup maps, and
+//!   publish the result as the next generation. Published snapshots are never mutated.
 //!
 //! # Key types
 //!
 //! | Type | Meaning | Constraints | Constructed / mutated in |
 //! |---|---|---|---|
-//! | [`crate::core::index::runtime::RuntimeRegistry`] | Immutable runtime registry wrapper | Must only expose read/query APIs | [`crate::core::index::runtime::RuntimeRegistry::new`] |
-//! | [`crate::core::index::snapshot::Snapshot`] | Immutable published state | Must remain immutable after publish | [`crate::core::index::snapshot::Snapshot::from_builtins`] |
+//! | [`crate::core::index::runtime::RuntimeRegistry`] | Runtime registry wrapper | Writers must publish whole snapshots | [`crate::core::index::runtime::RuntimeRegistry::new`], `replace`, `remove` |
+//! | [`crate::core::index::snapshot::Snapshot`] | Immutable published state | Must remain immutable after publish | [`crate::core::index::snapshot::Snapshot::from_builtins`], `RuntimeRegistry::publish` |
 //! | [`crate::core::index::snapshot::RegistryRef`] | Snapshot-pinned entry handle | Must keep source snapshot alive | [`crate::core::index::runtime::RuntimeRegistry::get`] |
 //!
 //! # Invariants
 //!
 //! * Lookup stage precedence must be preserved: ID (`by_id`) then name (`by_name`) then key (`by_key`).
+//! * Runtime canonical-ID conflicts must follow the `ByPriority` precedence contract.
+//! * Every published mutation must bump `Snapshot::generation`.
 //!
 //! # Data flow
 //!
 //! 1. Read path: `get*` loads current snapshot and resolves symbols through staged maps.
+//! 2. Write path: `replace` interns new strings on top of the current interner, builds the entry,
+//!    and `publish` rebuilds stage maps before swapping the snapshot in.
 //!
 //! # Lifecycle
 //!
 //! 1. Startup: `RuntimeRegistry::new` creates a snapshot from builtins.
 //! 2. Steady state: readers use lock-free snapshot loads.
+//! 3. Plugin load/unload: `insert` / `replace` / `remove` publish a new generation.
 //!
 //! # Concurrency & ordering
 //!
-//! * Readers are wait-free (`Arc` clone + immutable data reads).
+//! * Readers are lock-free (`ArcSwap` load + immutable data reads).
+//! * Writers are serialized by an internal mutex; the last publish wins atomically.
 //! * Ordering is deterministic through the build-time precedence contract.
 //!
 //! # Failure modes & recovery
 //!
 //! * Stale refs remain valid because they pin their originating snapshot.
+//! * `remove` leaves a tombstone rather than shifting later dense IDs, so cached IDs never
+//!   alias another entry; derived caches must still rebuild when `generation` changes.
 //!
 //! # Recipes
 //!
@@ -53,7 +63,7 @@ use crate::core::{DenseId, RegistryEntry, Symbol};
 
 mod state;
 
-pub use state::{RuntimeEntry, RuntimeRegistry};
+pub use state::{InsertResult, Replacement, RuntimeEntry, RuntimeRegistry};
 
 #[cfg(test)]
 #[allow(clippy::disallowed_methods)]
@@ -1,27 +1,71 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+use arc_swap::ArcSwap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::*;
+use crate::core::index::build::{BuildEntry, build_one};
+use crate::core::index::collision::{Collision, CollisionKind, DuplicatePolicy, Party};
+use crate::core::index::lookup::build_stage_maps;
+use crate::core::index::precedence::party_wins;
+use crate::core::{FrozenInterner, InsertAction, InternerBuilder};
 
 /// Marker trait for types that can be stored in a runtime registry.
 pub trait RuntimeEntry: RegistryEntry + Send + Sync + 'static {}
 impl<T> RuntimeEntry for T where T: RegistryEntry + Send + Sync + 'static {}
 
-/// Immutable runtime registry view.
+/// Runtime registry view with copy-on-write publication.
 pub struct RuntimeRegistry<T, Id: DenseId>
 where
 	T: RuntimeEntry,
 {
-	pub(super) snap: Arc<Snapshot<T, Id>>,
+	label: &'static str,
+	pub(super) snap: ArcSwap<Snapshot<T, Id>>,
+	/// Policy settling canonical-ID conflicts in [`Self::replace`], taken from the builtins' builder.
+	policy: DuplicatePolicy,
+	/// Serializes writers; readers never take it.
+	write_lock: Mutex<()>,
+	/// Canonical IDs of deprecated entries already reported by [`Self::deprecation_notice`].
+	deprecation_reported: Mutex<FxHashSet<Symbol>>,
+}
+
+/// Outcome of [`RuntimeRegistry::replace`].
+#[derive(Debug)]
+pub struct Replacement<T> {
+	/// How the canonical-ID slot was resolved.
+	pub action: InsertAction,
+	/// The entry that no longer occupies the canonical-ID slot.
+	///
+	/// For [`InsertAction::ReplacedExisting`] this is the previous entry; for
+	/// [`InsertAction::KeptExisting`] it is the freshly built, rejected entry.
+	pub evicted: Option<Arc<T>>,
+}
+
+/// Outcome of [`RuntimeRegistry::insert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertResult<Id> {
+	/// The entry was appended under the returned dense ID.
+	Ok(Id),
+	/// An entry with the same canonical ID already exists; nothing was published.
+	DuplicateId,
+	/// The entry's ID, name, or a key already resolves to another entry; nothing was published.
+	AliasConflict(String),
 }
 
 impl<T, Id: DenseId> RuntimeRegistry<T, Id>
 where
 	T: RuntimeEntry,
 {
-	/// Creates an immutable runtime registry from builtins.
-	pub fn new(_label: &'static str, builtins: RegistryIndex<T, Id>) -> Self {
+	/// Creates a runtime registry from builtins.
+	pub fn new(label: &'static str, builtins: RegistryIndex<T, Id>) -> Self {
 		let snap = Snapshot::from_builtins(&builtins);
-		Self { snap: Arc::new(snap) }
+		Self {
+			label,
+			snap: ArcSwap::from_pointee(snap),
+			policy: builtins.policy,
+			write_lock: Mutex::new(()),
+			deprecation_reported: Mutex::new(FxHashSet::default()),
+		}
 	}
 
 	/// Looks up a definition by ID, name, or secondary key.
@@ -29,7 +73,7 @@ where
 	/// Uses 3-stage fallback: canonical ID → primary name → secondary keys.
 	#[inline]
 	pub fn get(&self, key: &str) -> Option<RegistryRef<T, Id>> {
-		let snap = Arc::clone(&self.snap);
+		let snap = self.snap.load_full();
 		let sym = snap.interner.get(key)?;
 		self.get_sym_with_snap(snap, sym)
 	}
@@ -39,7 +83,7 @@ where
 	/// Uses 3-stage fallback: canonical ID → primary name → secondary keys.
 	#[inline]
 	pub fn get_sym(&self, sym: Symbol) -> Option<RegistryRef<T, Id>> {
-		let snap = Arc::clone(&self.snap);
+		let snap = self.snap.load_full();
 		self.get_sym_with_snap(snap, sym)
 	}
 
@@ -64,14 +108,14 @@ where
 
 	/// Returns a snapshot guard for efficient iteration.
 	pub fn snapshot_guard(&self) -> SnapshotGuard<T, Id> {
-		SnapshotGuard { snap: Arc::clone(&self.snap) }
+		SnapshotGuard { snap: self.snap.load_full() }
 	}
 
 	/// Looks up a definition by its dense ID.
 	#[inline]
 	pub fn get_by_id(&self, id: Id) -> Option<RegistryRef<T, Id>> {
-		let snap = Arc::clone(&self.snap);
-		if (id.as_u32() as usize) < snap.table.len() {
+		let snap = self.snap.load_full();
+		if snap.is_live(id.as_u32() as usize) {
 			Some(RegistryRef { snap, id })
 		} else {
 			None
@@ -80,21 +124,281 @@ where
 
 	/// Returns a snapshot guard for direct interner access.
 	pub fn snapshot(&self) -> Arc<Snapshot<T, Id>> {
-		Arc::clone(&self.snap)
+		self.snap.load_full()
+	}
+
+	/// Returns the generation of the current snapshot.
+	///
+	/// Starts at `0` and increases with every successful [`Self::replace`] or
+	/// [`Self::remove`]. Caches derived from a snapshot compare this to detect staleness.
+	pub fn generation(&self) -> u64 {
+		self.snap.load().generation
 	}
 
 	/// Returns the number of effective definitions.
 	pub fn len(&self) -> usize {
-		self.snap.table.len()
+		self.snap.load().live_len()
 	}
 
 	/// Returns collision diagnostics captured for this domain.
-	pub fn collisions(&self) -> &[crate::core::Collision] {
-		&self.snap.collisions
+	pub fn collisions(&self) -> Arc<[Collision]> {
+		Arc::clone(&self.snap.load().collisions)
 	}
 
 	/// Returns true if the registry contains no definitions.
 	pub fn is_empty(&self) -> bool {
 		self.len() == 0
 	}
+
+	/// Returns the deprecated entries of the current snapshot in dense-ID order.
+	pub fn all_deprecated(&self) -> impl Iterator<Item = RegistryRef<T, Id>> {
+		self.snapshot_guard().iter_refs().filter(|entry| entry.deprecated().is_some())
+	}
+
+	/// Returns a warning for `entry` the first time a deprecated entry is reported.
+	///
+	/// Later calls for the same canonical ID return `None`, so lookup paths can warn
+	/// once per process without flooding logs on every keypress.
+	pub fn deprecation_notice(&self, entry: &RegistryRef<T, Id>) -> Option<String> {
+		let deprecated = entry.deprecated_resolved()?;
+		let mut reported = self.deprecation_reported.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		if !reported.insert(entry.id()) {
+			return None;
+		}
+
+		let mut notice = format!("{} '{}' is deprecated since {}", self.label, entry.name_str(), deprecated.since);
+		if let Some(replacement) = deprecated.replacement {
+			notice.push_str(&format!("; use '{replacement}' instead"));
+		}
+		Some(notice)
+	}
+
+	/// Inserts `def`, or replaces the entry sharing its canonical ID.
+	///
+	/// Canonical-ID conflicts are settled with the [`DuplicatePolicy`] the
+	/// builtins were built with. Under [`DuplicatePolicy::ByPriority`] (priority,
+	/// then source rank, then ingest ordinal) a runtime definition replaces an
+	/// equal-priority builtin but never a higher-priority one. Replacing is the
+	/// point of this call, so [`DuplicatePolicy::Panic`] also resolves by
+	/// priority here. A kept existing entry leaves the snapshot and generation
+	/// untouched.
+	///
+	/// Replacing keeps the entry's dense ID; inserting appends a new one.
+	pub fn replace<In>(&self, def: Arc<In>) -> Replacement<T>
+	where
+		In: BuildEntry<T> + ?Sized,
+	{
+		let _guard = self.write_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let current = self.snap.load_full();
+		self.replace_locked(&current, def.as_ref())
+	}
+
+	/// Inserts `def` only if none of its lookup strings are taken.
+	///
+	/// Unlike [`Self::replace`], this never shadows or evicts an existing entry:
+	/// a taken canonical ID yields [`InsertResult::DuplicateId`], and an ID, name,
+	/// or key that already resolves through any lookup stage yields
+	/// [`InsertResult::AliasConflict`]. Both leave the snapshot and generation untouched.
+	pub fn insert<In>(&self, def: Arc<In>) -> InsertResult<Id>
+	where
+		In: BuildEntry<T> + ?Sized,
+	{
+		let _guard = self.write_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let current = self.snap.load_full();
+		let resolves = |s: &str| {
+			current
+				.interner
+				.get(s)
+				.is_some_and(|sym| current.by_id.contains_key(&sym) || current.by_name.contains_key(&sym) || current.by_key.contains_key(&sym))
+		};
+
+		let meta = def.meta_ref();
+		if current.interner.get(meta.id).is_some_and(|sym| current.by_id.contains_key(&sym)) {
+			return InsertResult::DuplicateId;
+		}
+		let mut lookups = vec![meta.id, meta.name];
+		meta.keys.for_each(|key| lookups.push(key));
+		if let Some(taken) = lookups.into_iter().find(|s| resolves(s)) {
+			return InsertResult::AliasConflict(taken.to_string());
+		}
+
+		self.replace_locked(&current, def.as_ref());
+		InsertResult::Ok(Id::from_u32(crate::core::index::u32_index(current.table.len(), self.label)))
+	}
+
+	/// Returns every entry of the current snapshot: builtins first, then runtime
+	/// insertions in the order they were published.
+	pub fn iter_all(&self) -> impl Iterator<Item = RegistryRef<T, Id>> {
+		self.snapshot_guard().iter_refs()
+	}
+
+	/// Body of [`Self::replace`]; the caller holds the write lock.
+	fn replace_locked<In>(&self, current: &Arc<Snapshot<T, Id>>, def: &In) -> Replacement<T>
+	where
+		In: BuildEntry<T> + ?Sized,
+	{
+		let mut builder = InternerBuilder::from_frozen(&current.interner);
+		let mut strings = Vec::new();
+		def.collect_strings_all(&mut strings);
+		for s in strings {
+			builder.intern(s);
+		}
+		let interner = builder.freeze();
+
+		let mut key_pool = current.key_pool.to_vec();
+		let entry = Arc::new(build_one(def, &interner, &mut key_pool));
+		let party = Party {
+			def_id: entry.id(),
+			source: entry.source(),
+			priority: entry.priority(),
+			ordinal: current.next_ordinal,
+		};
+
+		let mut table = current.table.to_vec();
+		let mut parties = current.parties.to_vec();
+		let mut id_collisions: Vec<Collision> = current
+			.collisions
+			.iter()
+			.filter(|c| matches!(c.kind, CollisionKind::DuplicateId { .. }))
+			.copied()
+			.collect();
+
+		let (action, evicted) = match current.by_id.get(&party.def_id) {
+			Some(&existing_id) => {
+				let idx = existing_id.as_u32() as usize;
+				let existing = parties[idx];
+				let policy = match self.policy {
+					DuplicatePolicy::Panic => DuplicatePolicy::ByPriority,
+					policy => policy,
+				};
+				let wins = match policy {
+					DuplicatePolicy::FirstWins => false,
+					DuplicatePolicy::LastWins => true,
+					DuplicatePolicy::ByPriority | DuplicatePolicy::Panic => party_wins(&party, &existing),
+				};
+				if !wins {
+					return Replacement {
+						action: InsertAction::KeptExisting,
+						evicted: Some(entry),
+					};
+				}
+				id_collisions.push(Collision {
+					registry: self.label,
+					key: party.def_id,
+					kind: CollisionKind::DuplicateId {
+						winner: party,
+						loser: existing,
+						policy,
+					},
+				});
+				parties[idx] = party;
+				let previous = std::mem::replace(&mut table[idx], entry);
+				(InsertAction::ReplacedExisting, Some(previous))
+			}
+			None => {
+				table.push(entry);
+				parties.push(party);
+				(InsertAction::InsertedNew, None)
+			}
+		};
+
+		self.publish(current, table, current.removed.clone(), parties, interner, key_pool, id_collisions);
+		Replacement { action, evicted }
+	}
+
+	/// Removes the entry at dense ID `id`, returning it.
+	///
+	/// The slot becomes a tombstone: lookups and iteration skip it, and dense IDs
+	/// are never reused, so IDs cached elsewhere keep naming the same entry or
+	/// resolve to nothing. Existing [`RegistryRef`]s stay valid because they pin
+	/// their snapshot.
+	pub fn remove(&self, id: Id) -> Option<Arc<T>> {
+		let _guard = self.write_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let current = self.snap.load_full();
+		let idx = id.as_u32() as usize;
+		if !current.is_live(idx) {
+			return None;
+		}
+		Some(self.remove_locked(&current, idx))
+	}
+
+	/// Removes the entry whose canonical ID is `id` if `pred` accepts it, returning it.
+	///
+	/// Resolution and removal happen under the write lock, so a concurrent writer
+	/// cannot remove or replace the entry in between.
+	pub fn remove_canonical(&self, id: &str, pred: impl FnOnce(&T) -> bool) -> Option<Arc<T>> {
+		let _guard = self.write_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let current = self.snap.load_full();
+		let dense = *current.by_id.get(&current.interner.get(id)?)?;
+		let idx = dense.as_u32() as usize;
+		if !pred(&current.table[idx]) {
+			return None;
+		}
+		Some(self.remove_locked(&current, idx))
+	}
+
+	/// Tombstones `table[idx]` and publishes; the caller holds the write lock.
+	fn remove_locked(&self, current: &Snapshot<T, Id>, idx: usize) -> Arc<T> {
+		let removed = Arc::clone(&current.table[idx]);
+		let mut tombstones = current.removed.to_vec();
+		if tombstones.len() <= idx {
+			tombstones.resize(idx + 1, false);
+		}
+		tombstones[idx] = true;
+
+		let id_collisions = current
+			.collisions
+			.iter()
+			.filter(|c| matches!(c.kind, CollisionKind::DuplicateId { .. }) && c.key != removed.id())
+			.copied()
+			.collect();
+
+		self.publish(
+			current,
+			current.table.to_vec(),
+			Arc::from(tombstones),
+			current.parties.to_vec(),
+			current.interner.clone(),
+			current.key_pool.to_vec(),
+			id_collisions,
+		);
+		removed
+	}
+
+	/// Rebuilds lookup maps for the live entries of `table` and atomically
+	/// publishes the next generation.
+	#[allow(clippy::too_many_arguments, reason = "snapshot parts published together")]
+	fn publish(
+		&self,
+		current: &Snapshot<T, Id>,
+		table: Vec<Arc<T>>,
+		removed: Arc<[bool]>,
+		parties: Vec<Party>,
+		interner: FrozenInterner,
+		key_pool: Vec<Symbol>,
+		mut collisions: Vec<Collision>,
+	) {
+		let mut by_id = FxHashMap::default();
+		for (idx, entry) in table.iter().enumerate() {
+			if !removed.get(idx).copied().unwrap_or(false) {
+				by_id.insert(entry.id(), Id::from_u32(crate::core::index::u32_index(idx, self.label)));
+			}
+		}
+		let (by_name, by_key, key_collisions) = build_stage_maps(self.label, &table, &removed, &parties, &key_pool, &by_id);
+		collisions.extend(key_collisions);
+
+		self.snap.store(Arc::new(Snapshot {
+			table: Arc::from(table),
+			by_id: Arc::new(by_id),
+			by_name: Arc::new(by_name),
+			by_key: Arc::new(by_key),
+			interner,
+			key_pool: Arc::from(key_pool),
+			collisions: Arc::from(collisions),
+			parties: Arc::from(parties),
+			next_ordinal: current.next_ordinal.saturating_add(1),
+			generation: current.generation + 1,
+			removed,
+		}));
+	}
 }
@@ -33,6 +33,12 @@ where
 	pub parties: Arc<[Party]>,
 	/// Next ordinal for monotonic runtime assignment.
 	pub next_ordinal: u32,
+	/// Publication counter; `0` for the builtin snapshot, bumped by every runtime mutation.
+	pub generation: u64,
+	/// Tombstones left by runtime removals: `removed[idx]` marks a dense ID whose
+	/// entry is gone. Removed entries keep their table slot so later dense IDs never
+	/// shift; indices past the end of this slice are live.
+	pub(crate) removed: Arc<[bool]>,
 }
 
 impl<T, Id: DenseId> Clone for Snapshot<T, Id>
@@ -50,6 +56,8 @@ where
 			collisions: self.collisions.clone(),
 			parties: self.parties.clone(),
 			next_ordinal: self.next_ordinal,
+			generation: self.generation,
+			removed: self.removed.clone(),
 		}
 	}
 }
@@ -71,8 +79,40 @@ where
 			collisions: b.collisions.clone(),
 			parties: b.parties.clone(),
 			next_ordinal,
+			generation: 0,
+			removed: Arc::from([]),
 		}
 	}
+
+	/// Returns true if dense index `idx` holds an entry that has not been removed.
+	pub fn is_live(&self, idx: usize) -> bool {
+		idx < self.table.len() && !self.removed.get(idx).copied().unwrap_or(false)
+	}
+
+	/// Returns the live entries with their dense indices, in dense-ID order.
+	pub fn entries(&self) -> impl Iterator<Item = (usize, &Arc<T>)> + '_ {
+		self.table.iter().enumerate().filter(|&(idx, _)| self.is_live(idx))
+	}
+
+	/// Returns the number of live entries.
+	pub fn live_len(&self) -> usize {
+		self.table.len() - self.removed.iter().filter(|&&removed| removed).count()
+	}
+
+	/// Explains why `name` resolved the way it did in this snapshot.
+	///
+	/// See [`super::RegistryIndex::explain`].
+	pub fn explain(&self, name: &str) -> Option<super::Explanation<'_, T>> {
+		super::explain::ExplainView {
+			table: &self.table,
+			by_id: &self.by_id,
+			by_name: &self.by_name,
+			by_key: &self.by_key,
+			interner: &self.interner,
+			collisions: &self.collisions,
+		}
+		.explain(name)
+	}
 }
 
 /// Snapshot-pinning guard that provides `&T` access to a registry definition.
@@ -146,6 +186,23 @@ where
 		let end = start + meta.keys.len as usize;
 		self.snap.key_pool[start..end].iter().map(|&sym| self.snap.interner.resolve(sym)).collect()
 	}
+
+	/// Returns resolved category strings.
+	pub fn categories_resolved(&self) -> Vec<&str> {
+		let meta = self.meta();
+		let start = meta.categories.start as usize;
+		let end = start + meta.categories.len as usize;
+		self.snap.key_pool[start..end].iter().map(|&sym| self.snap.interner.resolve(sym)).collect()
+	}
+
+	/// Returns the resolved deprecation notice, if the entry is deprecated.
+	pub fn deprecated_resolved(&self) -> Option<crate::core::Deprecation<&str>> {
+		let deprecated = self.deprecated()?;
+		Some(crate::core::Deprecation {
+			since: self.resolve(deprecated.since),
+			replacement: deprecated.replacement.map(|sym| self.resolve(sym)),
+		})
+	}
 }
 
 impl<T, Id: DenseId> std::ops::Deref for RegistryRef<T, Id>
@@ -171,37 +228,40 @@ impl<T, Id: DenseId> SnapshotGuard<T, Id>
 where
 	T: super::RuntimeEntry,
 {
-	/// Returns an iterator over all entries in the snapshot.
+	/// Returns an iterator over all live entries in the snapshot.
 	pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
-		self.snap.table.iter().map(|arc| arc.as_ref())
+		self.snap.entries().map(|(_, arc)| arc.as_ref())
 	}
 
-	/// Returns an iterator over (Id, &T) pairs.
+	/// Returns an iterator over (Id, &T) pairs of live entries.
 	pub fn iter_items(&self) -> impl Iterator<Item = (Id, &T)> + '_ {
 		self.snap
-			.table
-			.iter()
-			.enumerate()
+			.entries()
 			.map(|(idx, arc)| (Id::from_u32(super::u32_index(idx, "snapshot_iter")), arc.as_ref()))
 	}
 
-	/// Returns an iterator over [`RegistryRef`] handles.
+	/// Returns an iterator over [`RegistryRef`] handles of live entries.
 	pub fn iter_refs(self) -> impl Iterator<Item = RegistryRef<T, Id>> {
 		let snap = self.snap;
-		let len = snap.table.len();
-		(0..len).map(move |idx| RegistryRef {
+		let live = snap.clone();
+		(0..snap.table.len()).filter(move |&idx| live.is_live(idx)).map(move |idx| RegistryRef {
 			snap: snap.clone(),
 			id: Id::from_u32(super::u32_index(idx, "snapshot_iter_refs")),
 		})
 	}
 
-	/// Returns the number of entries.
+	/// Returns the number of live entries.
 	pub fn len(&self) -> usize {
-		self.snap.table.len()
+		self.snap.live_len()
 	}
 
-	/// Returns true if empty.
+	/// Returns true if no entry is live.
 	pub fn is_empty(&self) -> bool {
-		self.snap.table.is_empty()
+		self.len() == 0
+	}
+
+	/// Returns the pinned snapshot.
+	pub fn snapshot(&self) -> &Snapshot<T, Id> {
+		&self.snap
 	}
 }
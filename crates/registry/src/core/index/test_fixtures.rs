@@ -26,10 +26,12 @@ impl BuildEntry<TestEntry> for TestDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 	fn short_desc_str(&self) -> &str {
@@ -49,10 +51,12 @@ pub(crate) fn make_def(id: &'static str, priority: i16) -> TestDef {
 			id,
 			name: id,
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}
 }
@@ -63,10 +67,12 @@ pub(crate) fn make_def_with_name(id: &'static str, name: &'static str, priority:
 			id,
 			name,
 			keys: &[],
+			categories: &[],
 			description: "",
 			priority,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}
 }
@@ -77,10 +83,12 @@ pub(crate) fn make_def_with_keyes(id: &'static str, priority: i16, keys: &'stati
 			id,
 			name: id,
 			keys,
+			categories: &[],
 			description: "",
 			priority,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	}
 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use super::test_fixtures::{TestDef, TestEntry, make_def, make_def_with_keyes, make_def_with_name};
@@ -5,7 +6,7 @@ use crate::core::index::build::RegistryBuilder;
 use crate::core::index::runtime::RuntimeRegistry;
 use crate::core::symbol::{ActionId, DenseId};
 use crate::core::traits::RegistryEntry;
-use crate::core::{CollisionKind, KeyKind, RegistryMetaStatic, RegistrySource, Resolution};
+use crate::core::{CollisionKind, DuplicatePolicy, InsertResult, KeyKind, RegistryMetaStatic, RegistrySource, Resolution};
 
 #[test]
 fn test_noop_snapshot_stability() {
@@ -56,6 +57,97 @@ fn test_snapshot_guard_iteration() {
 	assert_eq!(item_ids, vec![ActionId::from_u32(0), ActionId::from_u32(1), ActionId::from_u32(2)]);
 }
 
+#[test]
+fn test_insert_appends_runtime_entries_after_builtins() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("alpha", 10)));
+	builder.push(Arc::new(make_def_with_keyes("beta", 10, &["b"])));
+	let registry = RuntimeRegistry::new("test", builder.build());
+
+	assert_eq!(registry.insert(Arc::new(make_def("gamma", 10))), InsertResult::Ok(ActionId::from_u32(2)));
+	let ids: Vec<String> = registry.iter_all().map(|entry| entry.id_str().to_string()).collect();
+	assert_eq!(ids, ["alpha", "beta", "gamma"]);
+	assert_eq!(registry.generation(), 1);
+
+	assert_eq!(registry.insert(Arc::new(make_def("alpha", 50))), InsertResult::DuplicateId);
+	assert_eq!(
+		registry.insert(Arc::new(make_def_with_name("delta", "b", 10))),
+		InsertResult::AliasConflict("b".to_string())
+	);
+	assert_eq!(
+		registry.insert(Arc::new(make_def_with_keyes("delta", 10, &["gamma"]))),
+		InsertResult::AliasConflict("gamma".to_string())
+	);
+	assert_eq!(registry.generation(), 1, "rejected inserts must not publish");
+	assert_eq!(registry.get("alpha").expect("alpha should resolve").priority(), 10);
+}
+
+#[test]
+fn test_iter_sorted_by_caches_order_per_comparator() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("A", 20)));
+	builder.push(Arc::new(make_def("B", 10)));
+	builder.push(Arc::new(make_def("C", 30)));
+	let index = builder.build();
+
+	let by_priority = |a: &TestEntry, b: &TestEntry| a.priority().cmp(&b.priority());
+	let ascending: Vec<i16> = index.iter_sorted_by(by_priority).map(|entry| entry.priority()).collect();
+	assert_eq!(ascending, vec![10, 20, 30]);
+	let cached = index.sorted.load_full().expect("sort is cached");
+	let again: Vec<i16> = index.iter_sorted_by(by_priority).map(|entry| entry.priority()).collect();
+	assert_eq!(again, ascending);
+	assert!(
+		Arc::ptr_eq(&cached, &index.sorted.load_full().unwrap()),
+		"same comparator reuses the cached order"
+	);
+
+	let descending: Vec<i16> = index.iter_by_priority().map(|entry| entry.priority()).collect();
+	assert_eq!(descending, vec![30, 20, 10]);
+	assert!(!Arc::ptr_eq(&cached, &index.sorted.load_full().unwrap()), "a new comparator re-sorts");
+
+	let table_order: Vec<i16> = index.iter().map(|entry| entry.priority()).collect();
+	assert_eq!(table_order, vec![20, 10, 30]);
+}
+
+#[test]
+fn test_iter_sorted_by_does_not_cache_fn_pointers() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("A", 20)));
+	builder.push(Arc::new(make_def("B", 10)));
+	builder.push(Arc::new(make_def("C", 30)));
+	let index = builder.build();
+
+	fn ascending(a: &TestEntry, b: &TestEntry) -> Ordering {
+		a.priority().cmp(&b.priority())
+	}
+	fn descending(a: &TestEntry, b: &TestEntry) -> Ordering {
+		b.priority().cmp(&a.priority())
+	}
+	let comparators: [fn(&TestEntry, &TestEntry) -> Ordering; 2] = [ascending, descending];
+
+	let up: Vec<i16> = index.iter_sorted_by(comparators[0]).map(|entry| entry.priority()).collect();
+	let down: Vec<i16> = index.iter_sorted_by(comparators[1]).map(|entry| entry.priority()).collect();
+	assert_eq!(up, vec![10, 20, 30]);
+	assert_eq!(down, vec![30, 20, 10], "fn pointers share a type but not an order");
+	assert!(index.sorted.load_full().is_none(), "fn pointer sorts are not cached");
+}
+
+#[test]
+fn test_iter_by_priority_breaks_ties_by_source_then_id() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("a", 5)));
+	builder.push(Arc::new(make_def("b", 5)));
+	let mut runtime = make_def("c", 5);
+	runtime.meta.source = RegistrySource::Runtime;
+	builder.push(Arc::new(runtime));
+	let index = builder.build();
+
+	let first = index.iter_by_priority().next().expect("non-empty index");
+	assert_eq!(index.interner.resolve(first.id()), "c", "runtime source outranks builtins at equal priority");
+	let ids: Vec<_> = index.iter_by_priority().map(|entry| entry.id()).collect();
+	assert!(ids[1] > ids[2], "equal priority and source fall back to descending ID symbol");
+}
+
 #[test]
 fn test_registry_ref_string_helpers() {
 	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
@@ -64,10 +156,12 @@ fn test_registry_ref_string_helpers() {
 			id: "test::my_action",
 			name: "my_action",
 			keys: &["ma"],
+			categories: &[],
 			description: "A test action",
 			priority: 42,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 	};
 	builder.push(Arc::new(def));
@@ -81,6 +175,85 @@ fn test_registry_ref_string_helpers() {
 	assert_eq!(r.dense_id(), ActionId::from_u32(0));
 }
 
+#[test]
+fn test_categories_filter_without_entering_lookup() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	for (id, categories) in [
+		("left", &["motion"] as &'static [&'static str]),
+		("delete", &["editing"]),
+		("word", &["motion", "selection"]),
+	] {
+		builder.push(Arc::new(TestDef {
+			meta: RegistryMetaStatic {
+				categories,
+				..RegistryMetaStatic::minimal(id, id, "")
+			},
+		}));
+	}
+	let index = builder.build();
+
+	let motion: Vec<&str> = index.by_category("motion").map(|e| index.interner.resolve(e.id())).collect();
+	assert_eq!(motion, vec!["left", "word"]);
+	assert_eq!(index.by_category("selection").count(), 1);
+	assert_eq!(index.by_category("window").count(), 0);
+	assert!(index.get("motion").is_none(), "categories must not resolve as lookup keys");
+
+	let registry = RuntimeRegistry::new("test", index);
+	assert_eq!(
+		registry.get("word").expect("word should resolve").categories_resolved(),
+		vec!["motion", "selection"]
+	);
+}
+
+#[test]
+fn test_deprecated_alias_resolves_and_lists() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(TestDef {
+		meta: RegistryMetaStatic {
+			keys: &["old_alias"],
+			..RegistryMetaStatic::minimal("old_cmd", "old_cmd", "").deprecated("0.4.0", Some("new_cmd"))
+		},
+	}));
+	builder.push(Arc::new(make_def("new_cmd", 0)));
+	let index = builder.build();
+
+	assert!(index.get("old_alias").is_some(), "deprecated entries stay indexed");
+	let ids: Vec<_> = index.deprecated().map(|entry| index.interner.resolve(entry.meta().id)).collect();
+	assert_eq!(ids, ["old_cmd"]);
+
+	let registry = RuntimeRegistry::new("test", index);
+	let old = registry.get("old_alias").expect("deprecated alias should still resolve");
+	assert_eq!(old.name_str(), "old_cmd");
+	let deprecated = old.deprecated_resolved().expect("entry should carry its deprecation");
+	assert_eq!(deprecated.since, "0.4.0");
+	assert_eq!(deprecated.replacement, Some("new_cmd"));
+	assert!(registry.get("new_cmd").expect("replacement should resolve").deprecated().is_none());
+
+	let listed: Vec<_> = registry.all_deprecated().map(|r| r.name_str().to_string()).collect();
+	assert_eq!(listed, ["old_cmd"]);
+}
+
+#[test]
+fn test_deprecation_notice_is_reported_once() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(TestDef {
+		meta: RegistryMetaStatic::minimal("old_cmd", "old_cmd", "").deprecated("0.4.0", Some("new_cmd")),
+	}));
+	builder.push(Arc::new(make_def("new_cmd", 0)));
+	let registry = RuntimeRegistry::new("test", builder.build());
+
+	let old = registry.get("old_cmd").expect("old_cmd should resolve");
+	assert_eq!(
+		registry.deprecation_notice(&old).as_deref(),
+		Some("test 'old_cmd' is deprecated since 0.4.0; use 'new_cmd' instead")
+	);
+	assert_eq!(registry.deprecation_notice(&old), None, "second hit must stay silent");
+	assert_eq!(registry.deprecation_notice(&registry.get("old_cmd").unwrap()), None);
+
+	let current = registry.get("new_cmd").expect("new_cmd should resolve");
+	assert_eq!(registry.deprecation_notice(&current), None);
+}
+
 #[test]
 fn test_stage_blocking_collisions() {
 	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
@@ -106,6 +279,49 @@ fn test_stage_blocking_collisions() {
 	}
 }
 
+#[test]
+fn test_explain_reports_priority_winner_and_loser() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::with_policy("test", DuplicatePolicy::ByPriority);
+	builder.push(Arc::new(make_def_with_name("low", "shared", 5)));
+	builder.push(Arc::new(make_def_with_name("high", "shared", 20)));
+	let index = builder.build();
+
+	let explanation = index.explain("shared").expect("shared should be explained");
+	let id_of = |entry: &TestEntry| index.interner.resolve(entry.id()).to_string();
+	assert_eq!(explanation.winner.map(id_of).as_deref(), Some("high"));
+	assert_eq!(explanation.stage, Some(KeyKind::PrimaryName));
+	assert_eq!(explanation.losers.iter().map(|e| id_of(e)).collect::<Vec<_>>(), vec!["low"]);
+	assert!(explanation.reason.contains("priority (20 > 5)"), "got: {}", explanation.reason);
+
+	let reason = explanation.reason.clone();
+	let registry = RuntimeRegistry::new("test", index);
+	let via_snapshot = registry.snapshot().explain("shared").expect("snapshot should explain shared").reason;
+	assert_eq!(via_snapshot, reason);
+}
+
+#[test]
+fn test_explain_reports_stage_block_and_unknown_names() {
+	let mut builder: RegistryBuilder<TestDef, TestEntry, ActionId> = RegistryBuilder::new("test");
+	builder.push(Arc::new(make_def("A", 10)));
+	builder.push(Arc::new(make_def_with_keyes("B", 20, &["A"])));
+	let index = builder.build();
+
+	let explanation = index.explain("A").expect("A should be explained");
+	assert_eq!(explanation.stage, Some(KeyKind::Canonical));
+	assert!(
+		explanation.reason.contains("'A' canonical blocks 'B' secondary_key"),
+		"got: {}",
+		explanation.reason
+	);
+	assert_eq!(explanation.losers.len(), 1);
+
+	let quiet = index.explain("B").expect("B should be explained");
+	assert!(quiet.losers.is_empty());
+	assert!(quiet.reason.contains("no competing bindings"), "got: {}", quiet.reason);
+
+	assert!(index.explain("missing").is_none());
+}
+
 #[test]
 #[cfg(any(debug_assertions, feature = "registry-contracts"))]
 #[should_panic(expected = "not in collect_strings()")]
@@ -122,10 +338,12 @@ fn test_build_ctx_enforcement() {
 				id: "bad",
 				name: "bad",
 				keys: StrListRef::Static(&[]),
+				categories: StrListRef::Static(&[]),
 				description: "",
 				priority: 0,
 				source: RegistrySource::Builtin,
 				mutates_buffer: false,
+				deprecated: None,
 			}
 		}
 
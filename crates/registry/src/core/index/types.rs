@@ -1,8 +1,12 @@
+use std::any::TypeId;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
+use arc_swap::ArcSwapOption;
 use rustc_hash::FxHashMap;
 
-use crate::core::{Collision, DenseId, FrozenInterner, Party, RegistryEntry, Symbol};
+use super::explain::{ExplainView, Explanation};
+use crate::core::{Collision, DenseId, DuplicatePolicy, FrozenInterner, Party, RegistryEntry, Symbol};
 
 pub(super) type Map<K, V> = FxHashMap<K, V>;
 
@@ -19,6 +23,16 @@ where
 	pub(crate) key_pool: Arc<[Symbol]>,
 	pub(crate) collisions: Arc<[Collision]>,
 	pub(crate) parties: Arc<[Party]>,
+	/// Duplicate policy the index was built with; runtime replacements reuse it.
+	pub(crate) policy: DuplicatePolicy,
+	/// Table order produced by the most recent zero-sized [`Self::iter_sorted_by`] comparator.
+	pub(crate) sorted: ArcSwapOption<SortedOrder>,
+}
+
+/// Permutation of table indices, keyed by comparator type when cached.
+pub(crate) struct SortedOrder {
+	comparator: TypeId,
+	order: Box<[u32]>,
 }
 
 impl<T, Id: DenseId> Clone for RegistryIndex<T, Id>
@@ -35,6 +49,8 @@ where
 			key_pool: self.key_pool.clone(),
 			collisions: self.collisions.clone(),
 			parties: self.parties.clone(),
+			policy: self.policy,
+			sorted: ArcSwapOption::new(self.sorted.load_full()),
 		}
 	}
 }
@@ -77,12 +93,87 @@ where
 		self.table.iter().map(|arc| arc.as_ref())
 	}
 
+	/// Returns an iterator over effective definitions ordered by `compare`.
+	///
+	/// The sort is stable, so ties keep table order. When `F` is zero-sized (a
+	/// non-capturing closure or a function item), its type identifies the
+	/// comparator, so the permutation from the most recent such call is cached
+	/// and keyed by that type. Function pointers and capturing closures can
+	/// share a type while ordering differently, so they always sort afresh and
+	/// leave the cache untouched.
+	pub fn iter_sorted_by<F>(&self, compare: F) -> impl Iterator<Item = &T> + '_
+	where
+		F: Fn(&T, &T) -> Ordering + 'static,
+	{
+		let sort = || {
+			let mut order: Vec<u32> = (0..self.table.len()).map(|idx| super::u32_index(idx, "sorted view")).collect();
+			order.sort_by(|&a, &b| compare(&self.table[a as usize], &self.table[b as usize]));
+			order.into_boxed_slice()
+		};
+		let comparator = TypeId::of::<F>();
+		let sorted = match self.sorted.load_full() {
+			_ if size_of::<F>() != 0 => Arc::new(SortedOrder { comparator, order: sort() }),
+			Some(sorted) if sorted.comparator == comparator => sorted,
+			_ => {
+				let sorted = Arc::new(SortedOrder { comparator, order: sort() });
+				self.sorted.store(Some(Arc::clone(&sorted)));
+				sorted
+			}
+		};
+		(0..sorted.order.len()).map(move |pos| self.table[sorted.order[pos] as usize].as_ref())
+	}
+
+	/// Returns an iterator over effective definitions, highest precedence first.
+	///
+	/// See [`RegistryEntry::total_order_cmp`].
+	pub fn iter_by_priority(&self) -> impl Iterator<Item = &T> + '_ {
+		self.iter_sorted_by(|a: &T, b: &T| b.total_order_cmp(a))
+	}
+
+	/// Returns an iterator over definitions tagged with `category`, in stable order.
+	///
+	/// Unknown categories yield an empty iterator. See [`crate::core::categories`]
+	/// for the well-known names.
+	pub fn by_category<'a>(&'a self, category: &str) -> impl Iterator<Item = &'a T> + 'a {
+		let sym = self.interner.get(category);
+		self.iter().filter(move |entry| {
+			let list = entry.meta().categories;
+			let start = list.start as usize;
+			sym.is_some_and(|sym| self.key_pool[start..start + list.len as usize].contains(&sym))
+		})
+	}
+
+	/// Returns deprecated definitions in stable order.
+	///
+	/// Deprecated entries stay fully indexed, so their names and keys keep
+	/// resolving; this only lists them.
+	pub fn deprecated(&self) -> impl Iterator<Item = &T> + '_ {
+		self.iter().filter(|entry| entry.deprecated().is_some())
+	}
+
 	/// Returns recorded collisions for diagnostics.
 	#[inline]
 	pub fn collisions(&self) -> &[Collision] {
 		&self.collisions
 	}
 
+	/// Explains why `name` resolved the way it did.
+	///
+	/// Reports the winning entry, effective entries that lost the key, and the
+	/// precedence rule (priority, source rank, ingest ordinal, or lookup stage)
+	/// behind each recorded collision. Returns `None` for unknown names.
+	pub fn explain(&self, name: &str) -> Option<Explanation<'_, T>> {
+		ExplainView {
+			table: &self.table,
+			by_id: &self.by_id,
+			by_name: &self.by_name,
+			by_key: &self.by_key,
+			interner: &self.interner,
+			collisions: &self.collisions,
+		}
+		.explain(name)
+	}
+
 	/// Returns the number of effective definitions.
 	#[inline]
 	pub fn len(&self) -> usize {
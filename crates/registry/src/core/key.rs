@@ -8,6 +8,8 @@ pub enum OptionValue {
 	Bool(bool),
 	/// Integer value.
 	Int(i64),
+	/// Floating-point value.
+	Float(f64),
 	/// String value.
 	String(String),
 }
@@ -29,6 +31,14 @@ impl OptionValue {
 		}
 	}
 
+	/// Returns the float value if this is a `Float` variant.
+	pub fn as_float(&self) -> Option<f64> {
+		match self {
+			OptionValue::Float(v) => Some(*v),
+			_ => None,
+		}
+	}
+
 	/// Returns the string value if this is a `String` variant.
 	pub fn as_str(&self) -> Option<&str> {
 		match self {
@@ -41,7 +51,10 @@ impl OptionValue {
 	pub fn matches_type(&self, ty: OptionType) -> bool {
 		matches!(
 			(self, ty),
-			(OptionValue::Bool(_), OptionType::Bool) | (OptionValue::Int(_), OptionType::Int) | (OptionValue::String(_), OptionType::String)
+			(OptionValue::Bool(_), OptionType::Bool)
+				| (OptionValue::Int(_), OptionType::Int)
+				| (OptionValue::Float(_), OptionType::Float)
+				| (OptionValue::String(_), OptionType::String)
 		)
 	}
 
@@ -50,9 +63,33 @@ impl OptionValue {
 		match self {
 			OptionValue::Bool(_) => "bool",
 			OptionValue::Int(_) => "int",
+			OptionValue::Float(_) => "float",
 			OptionValue::String(_) => "string",
 		}
 	}
+
+	/// Converts an `Int` to a `Float` when `ty` is [`OptionType::Float`].
+	///
+	/// Lets config and `:set` accept integer literals such as `2` for float options.
+	/// Other values are returned unchanged.
+	pub fn coerce_to(self, ty: OptionType) -> Self {
+		match (self, ty) {
+			(OptionValue::Int(v), OptionType::Float) => OptionValue::Float(v as f64),
+			(value, _) => value,
+		}
+	}
+}
+
+/// Formats the value as it would be typed after `:set key=`.
+impl core::fmt::Display for OptionValue {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			OptionValue::Bool(v) => write!(f, "{v}"),
+			OptionValue::Int(v) => write!(f, "{v}"),
+			OptionValue::Float(v) => write!(f, "{v}"),
+			OptionValue::String(v) => f.write_str(v),
+		}
+	}
 }
 
 impl From<bool> for OptionValue {
@@ -67,6 +104,12 @@ impl From<i64> for OptionValue {
 	}
 }
 
+impl From<f64> for OptionValue {
+	fn from(v: f64) -> Self {
+		OptionValue::Float(v)
+	}
+}
+
 impl From<String> for OptionValue {
 	fn from(v: String) -> Self {
 		OptionValue::String(v)
@@ -86,6 +129,8 @@ pub enum OptionType {
 	Bool,
 	/// Integer type.
 	Int,
+	/// Floating-point type.
+	Float,
 	/// String type.
 	String,
 }
@@ -102,6 +147,8 @@ pub enum OptionDefault {
 	Bool(fn() -> bool),
 	/// Integer default value factory.
 	Int(fn() -> i64),
+	/// Float default value factory.
+	Float(fn() -> f64),
 	/// String default value factory.
 	String(fn() -> String),
 	/// Static value (for registry-linked definitions).
@@ -113,6 +160,7 @@ impl core::fmt::Debug for OptionDefault {
 		match self {
 			OptionDefault::Bool(_) => f.write_str("OptionDefault::Bool(..)"),
 			OptionDefault::Int(_) => f.write_str("OptionDefault::Int(..)"),
+			OptionDefault::Float(_) => f.write_str("OptionDefault::Float(..)"),
 			OptionDefault::String(_) => f.write_str("OptionDefault::String(..)"),
 			OptionDefault::Value(v) => f.debug_tuple("OptionDefault::Value").field(v).finish(),
 		}
@@ -125,10 +173,12 @@ impl OptionDefault {
 		match self {
 			OptionDefault::Bool(_) => OptionType::Bool,
 			OptionDefault::Int(_) => OptionType::Int,
+			OptionDefault::Float(_) => OptionType::Float,
 			OptionDefault::String(_) => OptionType::String,
 			OptionDefault::Value(v) => match v {
 				OptionValue::Bool(_) => OptionType::Bool,
 				OptionValue::Int(_) => OptionType::Int,
+				OptionValue::Float(_) => OptionType::Float,
 				OptionValue::String(_) => OptionType::String,
 			},
 		}
@@ -139,6 +189,7 @@ impl OptionDefault {
 		match self {
 			OptionDefault::Bool(f) => OptionValue::Bool(f()),
 			OptionDefault::Int(f) => OptionValue::Int(f()),
+			OptionDefault::Float(f) => OptionValue::Float(f()),
 			OptionDefault::String(f) => OptionValue::String(f()),
 			OptionDefault::Value(v) => v.clone(),
 		}
@@ -149,6 +200,7 @@ impl OptionDefault {
 mod sealed {
 	pub trait Sealed {}
 	impl Sealed for i64 {}
+	impl Sealed for f64 {}
 	impl Sealed for bool {}
 	impl Sealed for String {}
 }
@@ -172,6 +224,16 @@ impl FromOptionValue for i64 {
 	}
 }
 
+impl FromOptionValue for f64 {
+	fn from_option(value: &OptionValue) -> Option<Self> {
+		value.as_float()
+	}
+
+	fn option_type() -> OptionType {
+		OptionType::Float
+	}
+}
+
 impl FromOptionValue for bool {
 	fn from_option(value: &OptionValue) -> Option<Self> {
 		value.as_bool()
@@ -1,15 +1,17 @@
 use crate::core::index::{BuildCtx, BuildEntry, RegistryMetaRef, StrListRef};
-use crate::core::{RegistryEntry, RegistryMeta, RegistrySource, Symbol};
+use crate::core::{Deprecation, RegistryEntry, RegistryMeta, RegistrySource, Symbol};
 
 #[derive(Clone)]
 pub struct LinkedMetaOwned {
 	pub id: String,
 	pub name: String,
 	pub keys: Vec<String>,
+	pub categories: Vec<String>,
 	pub description: String,
 	pub priority: i16,
 	pub source: RegistrySource,
 	pub mutates_buffer: bool,
+	pub deprecated: Option<Deprecation<String>>,
 	pub short_desc: String,
 }
 
@@ -45,10 +47,12 @@ where
 			id: &self.meta.id,
 			name: &self.meta.name,
 			keys: StrListRef::Owned(&self.meta.keys),
+			categories: StrListRef::Owned(&self.meta.categories),
 			description: &self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated.as_ref().map(Deprecation::as_deref),
 		}
 	}
 
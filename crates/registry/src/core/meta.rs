@@ -1,3 +1,5 @@
+pub use crate::schema::meta::categories;
+
 use super::symbol::Symbol;
 
 /// Represents where a registry item was defined.
@@ -32,16 +34,47 @@ impl core::fmt::Display for RegistrySource {
 	}
 }
 
+/// Deprecation notice attached to registry metadata.
+///
+/// `S` is `&'static str` for const declarations, borrowed or owned strings while
+/// building, and [`Symbol`] once interned into [`RegistryMeta`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecation<S = &'static str> {
+	/// Release (or date) the item was deprecated in.
+	pub since: S,
+	/// Name of the item that supersedes this one, if any.
+	pub replacement: Option<S>,
+}
+
+impl Deprecation {
+	/// Creates a deprecation notice for const declarations.
+	pub const fn new(since: &'static str, replacement: Option<&'static str>) -> Self {
+		Self { since, replacement }
+	}
+}
+
+impl<S: AsRef<str>> Deprecation<S> {
+	/// Borrows the notice's strings.
+	pub fn as_deref(&self) -> Deprecation<&str> {
+		Deprecation {
+			since: self.since.as_ref(),
+			replacement: self.replacement.as_ref().map(AsRef::as_ref),
+		}
+	}
+}
+
 /// Static metadata for const declarations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RegistryMetaStatic {
 	pub id: &'static str,
 	pub name: &'static str,
 	pub keys: &'static [&'static str],
+	pub categories: &'static [&'static str],
 	pub description: &'static str,
 	pub priority: i16,
 	pub source: RegistrySource,
 	pub mutates_buffer: bool,
+	pub deprecated: Option<Deprecation>,
 }
 
 impl RegistryMetaStatic {
@@ -51,6 +84,7 @@ impl RegistryMetaStatic {
 		id: &'static str,
 		name: &'static str,
 		keys: &'static [&'static str],
+		categories: &'static [&'static str],
 		description: &'static str,
 		priority: i16,
 		source: RegistrySource,
@@ -60,10 +94,12 @@ impl RegistryMetaStatic {
 			id,
 			name,
 			keys,
+			categories,
 			description,
 			priority,
 			source,
 			mutates_buffer,
+			deprecated: None,
 		}
 	}
 
@@ -73,10 +109,20 @@ impl RegistryMetaStatic {
 			id,
 			name,
 			keys: &[],
+			categories: &[],
 			description,
 			priority: 0,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
+		}
+	}
+
+	/// Marks the item deprecated since `since`, optionally naming its replacement.
+	pub const fn deprecated(self, since: &'static str, replacement: Option<&'static str>) -> Self {
+		Self {
+			deprecated: Some(Deprecation::new(since, replacement)),
+			..self
 		}
 	}
 }
@@ -99,10 +145,16 @@ pub struct RegistryMeta {
 	pub description: Symbol,
 	/// Alternative lookup keys (interned index range into key pool).
 	pub keys: SymbolList,
+	/// Grouping categories (interned index range into key pool, not lookup keys).
+	///
+	/// See [`categories`] for the well-known values.
+	pub categories: SymbolList,
 	/// Priority for conflict resolution (higher wins).
 	pub priority: i16,
 	/// Where this item was defined.
 	pub source: RegistrySource,
 	/// Whether this item mutates buffer text (used for readonly gating).
 	pub mutates_buffer: bool,
+	/// Deprecation notice (interned), if the item is deprecated.
+	pub deprecated: Option<Deprecation<Symbol>>,
 }
@@ -0,0 +1,192 @@
+//! Dependency-ordered construction of registries that read each other.
+//!
+//! Each registry is added under a name with a build step and the names it
+//! depends on. [`RegistrySet::build`] runs the steps in topological order,
+//! so a step can rely on its dependencies having written their output into the
+//! shared context. Independent steps keep their insertion order.
+
+use super::error::RegistryError;
+
+/// Build step run against the shared construction context.
+type BuildStep<'a, C> = Box<dyn FnOnce(&mut C) + 'a>;
+
+struct Node<'a, C> {
+	name: &'static str,
+	deps: Vec<&'static str>,
+	build: BuildStep<'a, C>,
+}
+
+/// Orchestrates registry construction in dependency order.
+pub struct RegistrySet<'a, C> {
+	nodes: Vec<Node<'a, C>>,
+}
+
+/// Handle for declaring the dependencies of a just-added registry.
+pub struct RegistrySetEntry<'s, 'a, C> {
+	node: &'s mut Node<'a, C>,
+}
+
+impl<C> RegistrySetEntry<'_, '_, C> {
+	/// Requires `name` to be built before this registry.
+	pub fn depends_on(&mut self, name: &'static str) -> &mut Self {
+		self.node.deps.push(name);
+		self
+	}
+}
+
+impl<C> Default for RegistrySet<'_, C> {
+	fn default() -> Self {
+		Self { nodes: Vec::new() }
+	}
+}
+
+impl<'a, C> RegistrySet<'a, C> {
+	/// Creates an empty set.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a registry build step under `name`.
+	pub fn add<F>(&mut self, name: &'static str, build: F) -> RegistrySetEntry<'_, 'a, C>
+	where
+		F: FnOnce(&mut C) + 'a,
+	{
+		self.nodes.push(Node {
+			name,
+			deps: Vec::new(),
+			build: Box::new(build),
+		});
+		let node = self.nodes.last_mut().expect("node was just pushed");
+		RegistrySetEntry { node }
+	}
+
+	/// Returns registry names in construction order.
+	///
+	/// # Errors
+	///
+	/// Returns [`RegistryError::DuplicateRegistry`] for a name added twice,
+	/// [`RegistryError::UnknownDependency`] for a dependency that was never added,
+	/// and [`RegistryError::DependencyCycle`] with the cycle path otherwise.
+	pub fn order(&self) -> Result<Vec<&'static str>, RegistryError> {
+		self.order_indices().map(|order| order.into_iter().map(|idx| self.nodes[idx].name).collect())
+	}
+
+	/// Runs every build step in dependency order.
+	///
+	/// Nothing is built when the order cannot be resolved.
+	///
+	/// # Errors
+	///
+	/// See [`Self::order`].
+	pub fn build(self, ctx: &mut C) -> Result<(), RegistryError> {
+		let order = self.order_indices()?;
+		let mut steps: Vec<Option<BuildStep<'a, C>>> = self.nodes.into_iter().map(|node| Some(node.build)).collect();
+		for idx in order {
+			let build = steps[idx].take().expect("each registry is ordered once");
+			build(ctx);
+		}
+		Ok(())
+	}
+
+	fn order_indices(&self) -> Result<Vec<usize>, RegistryError> {
+		for (idx, node) in self.nodes.iter().enumerate() {
+			if self.nodes[..idx].iter().any(|prev| prev.name == node.name) {
+				return Err(RegistryError::DuplicateRegistry(node.name));
+			}
+		}
+
+		let mut state = vec![Visit::Pending; self.nodes.len()];
+		let mut stack = Vec::new();
+		let mut order = Vec::with_capacity(self.nodes.len());
+		for idx in 0..self.nodes.len() {
+			self.visit(idx, &mut state, &mut stack, &mut order)?;
+		}
+		Ok(order)
+	}
+
+	/// Depth-first post-order visit; `stack` holds the active path for cycle reporting.
+	fn visit(&self, idx: usize, state: &mut [Visit], stack: &mut Vec<usize>, order: &mut Vec<usize>) -> Result<(), RegistryError> {
+		match state[idx] {
+			Visit::Done => return Ok(()),
+			Visit::Active => {
+				let start = stack.iter().position(|&i| i == idx).expect("active node is on the stack");
+				let mut path: Vec<&'static str> = stack[start..].iter().map(|&i| self.nodes[i].name).collect();
+				path.push(self.nodes[idx].name);
+				return Err(RegistryError::DependencyCycle(path));
+			}
+			Visit::Pending => {}
+		}
+
+		state[idx] = Visit::Active;
+		stack.push(idx);
+		let node = &self.nodes[idx];
+		for &dep in &node.deps {
+			let dep_idx = self.nodes.iter().position(|n| n.name == dep).ok_or(RegistryError::UnknownDependency {
+				registry: node.name,
+				dependency: dep,
+			})?;
+			self.visit(dep_idx, state, stack, order)?;
+		}
+		stack.pop();
+		state[idx] = Visit::Done;
+		order.push(idx);
+		Ok(())
+	}
+}
+
+#[derive(Clone, Copy)]
+enum Visit {
+	Pending,
+	Active,
+	Done,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RegistrySet;
+	use crate::core::RegistryError;
+
+	#[test]
+	fn builds_dependencies_first() {
+		let mut set: RegistrySet<'_, Vec<&'static str>> = RegistrySet::new();
+		set.add("keymap", |built| {
+			assert!(built.contains(&"actions"), "keymap needs actions");
+			built.push("keymap");
+		})
+		.depends_on("actions");
+		set.add("statusline", |built| built.push("statusline")).depends_on("themes");
+		set.add("actions", |built| built.push("actions"));
+		set.add("themes", |built| built.push("themes"));
+
+		assert_eq!(set.order().unwrap(), ["actions", "keymap", "themes", "statusline"]);
+		let mut built = Vec::new();
+		set.build(&mut built).unwrap();
+		assert_eq!(built, ["actions", "keymap", "themes", "statusline"]);
+	}
+
+	#[test]
+	fn two_node_cycle_reports_path() {
+		let mut set: RegistrySet<'_, Vec<&'static str>> = RegistrySet::new();
+		set.add("actions", |built| built.push("actions")).depends_on("keymap");
+		set.add("keymap", |built| built.push("keymap")).depends_on("actions");
+
+		let err = set.order().unwrap_err();
+		assert!(matches!(&err, RegistryError::DependencyCycle(path) if path == &["actions", "keymap", "actions"]));
+		assert_eq!(err.to_string(), "registry dependency cycle: actions -> keymap -> actions");
+
+		let mut built = Vec::new();
+		assert!(set.build(&mut built).is_err());
+		assert!(built.is_empty(), "nothing is built when the order is invalid");
+	}
+
+	#[test]
+	fn unknown_and_duplicate_registries_are_rejected() {
+		let mut set: RegistrySet<'_, ()> = RegistrySet::new();
+		set.add("keymap", |_| {}).depends_on("actions");
+		assert_eq!(set.order().unwrap_err().to_string(), "registry 'keymap' depends on unknown registry 'actions'");
+
+		set.add("actions", |_| {});
+		set.add("actions", |_| {});
+		assert!(matches!(set.order(), Err(RegistryError::DuplicateRegistry("actions"))));
+	}
+}
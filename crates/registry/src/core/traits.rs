@@ -1,4 +1,6 @@
-use super::meta::{RegistryMeta, RegistrySource};
+use std::cmp::Ordering;
+
+use super::meta::{Deprecation, RegistryMeta, RegistrySource};
 use super::symbol::Symbol;
 
 /// Trait for accessing registry metadata from definition types.
@@ -35,6 +37,25 @@ pub trait RegistryEntry {
 	fn mutates_buffer(&self) -> bool {
 		self.meta().mutates_buffer
 	}
+
+	/// Returns the deprecation notice, if this item is deprecated.
+	fn deprecated(&self) -> Option<Deprecation<Symbol>> {
+		self.meta().deprecated
+	}
+
+	/// Total precedence order between entries of one snapshot.
+	///
+	/// Orders by priority, then source rank, then canonical ID symbol;
+	/// [`Ordering::Greater`] means `self` takes precedence.
+	fn total_order_cmp(&self, other: &Self) -> Ordering
+	where
+		Self: Sized,
+	{
+		self.priority()
+			.cmp(&other.priority())
+			.then_with(|| self.source().rank().cmp(&other.source().rank()))
+			.then_with(|| self.id().cmp(&other.id()))
+	}
 }
 
 /// Implements [`RegistryEntry`] for a type with a `meta: RegistryMeta` field.
@@ -4,6 +4,7 @@
 //! same key, allowing users to identify and resolve registration conflicts.
 
 use crate::core::Collision;
+pub use crate::db::NameExplanation;
 
 /// Report containing all detected registry collisions.
 pub struct DiagnosticReport {
@@ -17,3 +18,11 @@ pub fn diagnostics() -> DiagnosticReport {
 		collisions: diagnostics.collisions,
 	}
 }
+
+/// Explains how `name` resolves across all catalog domains.
+///
+/// Backs `:registry explain <name>`-style consumers: each domain that knows the
+/// name reports its winner, losing entries, and the precedence rule applied.
+pub fn explain(name: &str) -> Vec<NameExplanation> {
+	crate::db::get_catalog().explain(name)
+}
@@ -2,19 +2,23 @@
 
 use crate::actions::{ActionEntry, ActionKey};
 use crate::commands::CommandEntry;
-use crate::core::{ActionId, CommandId, LookupKey, MotionId, RegistryRef};
+use crate::core::{ActionId, CommandId, DenseId, LookupKey, MotionId, RegistryRef, RuntimeEntry, RuntimeRegistry};
 use crate::db::{ACTIONS, COMMANDS, MOTIONS, TEXT_OBJECTS, resolve_action_id_typed};
 use crate::motions::MotionEntry;
 use crate::textobj::TextObjectRef;
 
 /// Finds a command definition by ID, name, or key.
+///
+/// Deprecated commands still resolve; the first hit logs a warning naming the replacement.
 pub fn find_command(key: &str) -> Option<RegistryRef<CommandEntry, CommandId>> {
-	COMMANDS.get(key)
+	COMMANDS.get(key).map(|command| warn_if_deprecated(&COMMANDS, command))
 }
 
 /// Finds an action definition by ID, name, or key.
+///
+/// Deprecated actions still resolve; the first hit logs a warning naming the replacement.
 pub fn find_action(key: &str) -> Option<RegistryRef<ActionEntry, ActionId>> {
-	ACTIONS.get(key)
+	ACTIONS.get(key).map(|action| warn_if_deprecated(&ACTIONS, action))
 }
 
 /// Look up an action by typed ActionId.
@@ -64,3 +68,15 @@ pub fn all_motions() -> Vec<RegistryRef<MotionEntry, MotionId>> {
 pub fn all_text_objects() -> Vec<TextObjectRef> {
 	TEXT_OBJECTS.snapshot_guard().iter_refs().collect()
 }
+
+/// Logs a one-time warning when `entry` is deprecated, passing it through unchanged.
+fn warn_if_deprecated<T, Id>(registry: &RuntimeRegistry<T, Id>, entry: RegistryRef<T, Id>) -> RegistryRef<T, Id>
+where
+	T: RuntimeEntry,
+	Id: DenseId,
+{
+	if let Some(notice) = registry.deprecation_notice(&entry) {
+		tracing::warn!("{notice}");
+	}
+	entry
+}
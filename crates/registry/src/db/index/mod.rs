@@ -3,7 +3,7 @@
 pub mod diagnostics;
 pub mod lookups;
 
-pub use diagnostics::{DiagnosticReport, diagnostics};
+pub use diagnostics::{DiagnosticReport, NameExplanation, diagnostics, explain};
 pub use lookups::{
 	all_actions, all_commands, all_motions, all_text_objects, find_action, find_action_by_id, find_command, find_motion, find_text_object_by_trigger,
 	resolve_action_id, resolve_action_key,
@@ -106,12 +106,14 @@ impl<'a> KeymapCompiler<'a> {
 						ordinal: a.ordinal,
 						priority: a.priority,
 						target_desc: &a.target_desc,
+						user_override: false,
 					},
 					CandidatePrecedence {
 						source: b.source,
 						ordinal: b.ordinal,
 						priority: b.priority,
 						target_desc: &b.target_desc,
+						user_override: false,
 					},
 				)
 			});
@@ -177,21 +179,9 @@ fn resolve_binding_target(
 			target_desc: binding.target_desc.to_string(),
 		}),
 		SpecBindingTarget::Action { id, count, extend, register } => {
-			let action_entry = &actions.table[id.as_u32() as usize];
 			let target_desc = canonical_action_id(actions, *id);
 			Some(ResolvedTarget {
-				binding: Some(CompiledBinding::new(
-					CompiledBindingTarget::Action {
-						id: *id,
-						count: (*count).max(1),
-						extend: *extend,
-						register: *register,
-					},
-					Arc::from(actions.interner.resolve(action_entry.name())),
-					Arc::from(actions.interner.resolve(action_entry.description())),
-					Arc::from(actions.interner.resolve(action_entry.short_desc)),
-					parsed_keys.to_vec(),
-				)),
+				binding: Some(action_binding(actions, *id, *count, *extend, *register, parsed_keys)),
 				target_desc,
 			})
 		}
@@ -220,21 +210,9 @@ fn resolve_invocation(
 				return None;
 			};
 
-			let action_entry = &actions.table[action_id.as_u32() as usize];
 			let target_desc = canonical_action_id(actions, action_id);
 			Some(ResolvedTarget {
-				binding: Some(CompiledBinding::new(
-					CompiledBindingTarget::Action {
-						id: action_id,
-						count: (*count).max(1),
-						extend: *extend,
-						register: *register,
-					},
-					Arc::from(actions.interner.resolve(action_entry.name())),
-					Arc::from(actions.interner.resolve(action_entry.description())),
-					Arc::from(actions.interner.resolve(action_entry.short_desc)),
-					parsed_keys.to_vec(),
-				)),
+				binding: Some(action_binding(actions, action_id, *count, *extend, *register, parsed_keys)),
 				target_desc,
 			})
 		}
@@ -275,7 +253,31 @@ fn resolve_invocation(
 	}
 }
 
-fn resolve_action_by_name(actions: &Snapshot<ActionEntry, ActionId>, name: &str) -> Option<ActionId> {
+/// Builds the trie entry for an action target, labelled from the action's metadata.
+pub(super) fn action_binding(
+	actions: &Snapshot<ActionEntry, ActionId>,
+	id: ActionId,
+	count: usize,
+	extend: bool,
+	register: Option<char>,
+	parsed_keys: &[Node],
+) -> CompiledBinding {
+	let action_entry = &actions.table[id.as_u32() as usize];
+	CompiledBinding::new(
+		CompiledBindingTarget::Action {
+			id,
+			count: count.max(1),
+			extend,
+			register,
+		},
+		Arc::from(actions.interner.resolve(action_entry.name())),
+		Arc::from(actions.interner.resolve(action_entry.description())),
+		Arc::from(actions.interner.resolve(action_entry.short_desc)),
+		parsed_keys.to_vec(),
+	)
+}
+
+pub(super) fn resolve_action_by_name(actions: &Snapshot<ActionEntry, ActionId>, name: &str) -> Option<ActionId> {
 	let sym = actions.interner.get(name)?;
 	actions
 		.by_id
@@ -298,7 +300,7 @@ fn build_matchers(slots: &[CompiledSlot]) -> HashMap<BindingMode, Matcher<Compil
 	matchers
 }
 
-fn canonical_action_id(actions: &Snapshot<ActionEntry, ActionId>, action_id: ActionId) -> String {
+pub(super) fn canonical_action_id(actions: &Snapshot<ActionEntry, ActionId>, action_id: ActionId) -> String {
 	let action_entry = &actions.table[action_id.as_u32() as usize];
 	actions.interner.resolve(action_entry.id()).to_string()
 }
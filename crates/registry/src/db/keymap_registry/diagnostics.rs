@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use xeno_keymap_core::parser::Node;
+
 use crate::actions::BindingMode;
 
 /// Classification of a keymap compile problem.
@@ -34,6 +36,50 @@ pub struct KeymapConflict {
 	pub dropped_priority: i16,
 }
 
+/// How severely a [`BindingConflict`] affects dispatch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictSeverity {
+	/// A shorter binding completes on a prefix of a longer one, so the longer one
+	/// only fires if more keys arrive before the shorter one is taken.
+	Shadowing,
+	/// Bindings of different priorities claim the same sequence; only one survives.
+	Collision,
+}
+
+/// One side of a [`BindingConflict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingEntry {
+	/// Full key sequence of the binding.
+	pub keys: Vec<Node>,
+	/// Target description (action name or invocation).
+	pub target: String,
+	/// Source priority, when known; compiled bindings do not retain it.
+	pub priority: Option<i16>,
+}
+
+/// Ambiguous pair of bindings in one mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingConflict {
+	pub mode: BindingMode,
+	/// Sequence both bindings share: the shadowing binding's keys, or the collided slot.
+	pub prefix: Vec<Node>,
+	/// Binding that loses: unreachable without extra keys, or dropped from the slot.
+	pub shadowed: BindingEntry,
+	/// Binding that wins at `prefix`.
+	pub shadowing: BindingEntry,
+}
+
+impl BindingConflict {
+	/// Returns [`ConflictSeverity::Collision`] when both entries bind the same sequence.
+	pub fn severity(&self) -> ConflictSeverity {
+		if self.shadowed.keys == self.shadowing.keys {
+			ConflictSeverity::Collision
+		} else {
+			ConflictSeverity::Shadowing
+		}
+	}
+}
+
 pub(crate) fn push_problem(
 	problems: &mut Vec<KeymapBuildProblem>,
 	mode: Option<BindingMode>,
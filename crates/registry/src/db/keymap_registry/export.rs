@@ -0,0 +1,91 @@
+//! NUON export of the effective keymap.
+
+use std::fmt::Write;
+
+use xeno_invocation::CommandRoute;
+
+use super::snapshot::{CompiledBinding, CompiledBindingTarget, KeymapSnapshot, sequence_string};
+use crate::invocation::Invocation;
+
+impl KeymapSnapshot {
+	/// Serializes the base keymap (including user runtime binds) as a NUON config.
+	///
+	/// The output is a `{ keymap: { keys: { <mode>: { <keys>: <target> } } } }`
+	/// record with modes and key sequences sorted. Parsing it as a config and
+	/// building it over an empty preset yields the same bindings. Pushed layers
+	/// are not exported.
+	pub fn to_nuon(&self) -> String {
+		let mut modes: Vec<_> = self.matchers.iter().collect();
+		modes.sort_by_key(|(mode, _)| **mode);
+
+		let mut out = String::from("{\n\tkeymap: {\n\t\tkeys: {\n");
+		for (mode, matcher) in modes {
+			let mut entries: Vec<_> = matcher.values().into_iter().map(|b| (sequence_string(b.keys()), target_nuon(b))).collect();
+			if entries.is_empty() {
+				continue;
+			}
+			entries.sort();
+			let _ = writeln!(out, "\t\t\t{}: {{", mode.as_str());
+			for (keys, target) in entries {
+				let _ = writeln!(out, "\t\t\t\t{keys:?}: {target},");
+			}
+			out.push_str("\t\t\t},\n");
+		}
+		out.push_str("\t\t},\n\t},\n}\n");
+		out
+	}
+}
+
+/// Formats a binding target as a spec string when one can express it, and as
+/// a dispatch record otherwise.
+fn target_nuon(binding: &CompiledBinding) -> String {
+	match binding.target() {
+		CompiledBindingTarget::Action { count, extend, register, .. } => action_nuon(binding.name(), *count, *extend, *register, None),
+		CompiledBindingTarget::Invocation { inv } => match inv {
+			Invocation::Action { name, count, extend, register } => action_nuon(name, *count, *extend, *register, None),
+			Invocation::ActionWithChar {
+				name,
+				count,
+				extend,
+				register,
+				char_arg,
+			} => action_nuon(name, *count, *extend, *register, Some(*char_arg)),
+			Invocation::Command(command) => {
+				let kind = match command.route {
+					CommandRoute::Editor => "editor",
+					CommandRoute::Auto | CommandRoute::Registry => "command",
+				};
+				named_nuon(kind, &command.name, &command.args)
+			}
+			Invocation::Nu { name, args } => named_nuon("nu", name, args),
+		},
+	}
+}
+
+fn action_nuon(name: &str, count: usize, extend: bool, register: Option<char>, char_arg: Option<char>) -> String {
+	if count <= 1 && !extend && register.is_none() && char_arg.is_none() {
+		return format!("{:?}", format!("action:{name}"));
+	}
+	let mut out = format!("{{ type: \"dispatch\", kind: \"action\", name: {name:?}");
+	if count > 1 {
+		let _ = write!(out, ", count: {count}");
+	}
+	if extend {
+		out.push_str(", extend: true");
+	}
+	if let Some(register) = register {
+		let _ = write!(out, ", register: {:?}", register.to_string());
+	}
+	if let Some(ch) = char_arg {
+		let _ = write!(out, ", char: {:?}", ch.to_string());
+	}
+	out.push_str(" }");
+	out
+}
+
+fn named_nuon(kind: &str, name: &str, args: &[String]) -> String {
+	if args.is_empty() {
+		return format!("{:?}", format!("{kind}:{name}"));
+	}
+	format!("{{ type: \"dispatch\", kind: {kind:?}, name: {name:?}, args: {args:?} }}")
+}
@@ -0,0 +1,183 @@
+//! Pushed keymap layers and explicit user binds on top of a compiled snapshot.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use xeno_keymap_core::Matcher;
+use xeno_keymap_core::parser::parse_seq;
+
+use super::compiler::{action_binding, canonical_action_id, resolve_action_by_name};
+use super::diagnostics::{KeymapBuildProblem, KeymapConflict, KeymapProblemKind, push_problem};
+use super::precedence::{CandidatePrecedence, compare_candidates};
+use super::snapshot::{CompiledBinding, CompiledBindingTarget, KeymapSnapshot};
+use super::spec::{KeymapBindingSource, SlotKey};
+use crate::actions::{ActionEntry, BindingMode};
+use crate::core::ActionId;
+use crate::core::index::Snapshot;
+use crate::invocation::Invocation;
+
+/// One binding contributed by a pushed keymap layer.
+#[derive(Debug, Clone)]
+pub struct LayerBinding {
+	pub mode: BindingMode,
+	/// Key sequence, e.g. `"ctrl-n"` or `"g d"`.
+	pub keys: Arc<str>,
+	pub invocation: Invocation,
+}
+
+impl LayerBinding {
+	pub fn new(mode: BindingMode, keys: impl Into<Arc<str>>, invocation: Invocation) -> Self {
+		Self {
+			mode,
+			keys: keys.into(),
+			invocation,
+		}
+	}
+}
+
+/// Named set of bindings consulted before everything below it.
+#[derive(Debug)]
+pub(super) struct KeymapLayer {
+	pub(super) name: Arc<str>,
+	pub(super) matchers: HashMap<BindingMode, Matcher<CompiledBinding>>,
+}
+
+/// Winning user bind for one slot.
+#[derive(Debug, Clone)]
+pub(super) struct RuntimeBind {
+	priority: i16,
+	ordinal: usize,
+	target_desc: String,
+}
+
+impl KeymapSnapshot {
+	/// Pushes a named layer whose bindings shadow the layers and base keymap below it.
+	///
+	/// Invalid key sequences are recorded in [`Self::problems`] and skipped.
+	pub fn push_layer(&mut self, name: impl Into<Arc<str>>, bindings: Vec<LayerBinding>) {
+		let mut matchers: HashMap<BindingMode, Matcher<CompiledBinding>> = HashMap::new();
+		for binding in bindings {
+			let target_desc: Arc<str> = Arc::from(binding.invocation.describe());
+			let Ok(keys) = parse_seq(&binding.keys) else {
+				push_problem(
+					&mut self.problems,
+					Some(binding.mode),
+					&binding.keys,
+					&target_desc,
+					KeymapProblemKind::InvalidKeySequence,
+					"invalid key sequence in layer",
+				);
+				continue;
+			};
+			let name: Arc<str> = Arc::from(invocation_name(&binding.invocation));
+			let compiled = CompiledBinding::new(
+				CompiledBindingTarget::Invocation { inv: binding.invocation },
+				Arc::clone(&name),
+				target_desc,
+				name,
+				keys.clone(),
+			);
+			matchers.entry(binding.mode).or_default().add(keys, compiled);
+		}
+		self.layers.push(KeymapLayer { name: name.into(), matchers });
+	}
+
+	/// Removes the most recently pushed layer called `name`.
+	///
+	/// Returns `false` if no such layer is active.
+	pub fn pop_layer(&mut self, name: &str) -> bool {
+		let Some(index) = self.layers.iter().rposition(|layer| &*layer.name == name) else {
+			return false;
+		};
+		self.layers.remove(index);
+		true
+	}
+
+	/// Returns the names of active layers, bottom first.
+	pub fn layer_names(&self) -> impl Iterator<Item = &str> {
+		self.layers.iter().map(|layer| &*layer.name)
+	}
+
+	/// Binds `keys` in `mode` to `action` as an explicit user bind.
+	///
+	/// User binds replace compiled bindings of any source and priority. Between
+	/// user binds on the same sequence the lower `priority` wins, then the later
+	/// bind; the loser is recorded in [`Self::conflicts`].
+	pub fn bind_runtime(
+		&mut self,
+		actions: &Snapshot<ActionEntry, ActionId>,
+		mode: BindingMode,
+		keys: &str,
+		action: &str,
+		priority: i16,
+	) -> Result<(), KeymapBuildProblem> {
+		let problem = |kind, message: &str| KeymapBuildProblem {
+			mode: Some(mode),
+			keys: Arc::from(keys),
+			target: Arc::from(action),
+			kind,
+			message: Arc::from(message),
+		};
+		let parsed_keys = parse_seq(keys).map_err(|_| problem(KeymapProblemKind::InvalidKeySequence, "invalid key sequence"))?;
+		let id = resolve_action_by_name(actions, action).ok_or_else(|| problem(KeymapProblemKind::UnknownActionTarget, "unknown action target"))?;
+
+		let target_desc = canonical_action_id(actions, id);
+		let ordinal = self.next_runtime_ordinal;
+		self.next_runtime_ordinal += 1;
+		let slot = SlotKey {
+			mode,
+			sequence: Arc::from(keys),
+		};
+
+		if let Some(current) = self.runtime_binds.get(&slot) {
+			let incoming_wins = compare_candidates(
+				user_precedence(priority, ordinal, &target_desc),
+				user_precedence(current.priority, current.ordinal, &current.target_desc),
+			) == Ordering::Greater;
+			let incoming = (priority, target_desc.clone());
+			let existing = (current.priority, current.target_desc.clone());
+			let (kept, dropped) = if incoming_wins { (incoming, existing) } else { (existing, incoming) };
+			self.conflicts.push(KeymapConflict {
+				mode,
+				keys: Arc::clone(&slot.sequence),
+				kept_target: kept.1,
+				dropped_target: dropped.1,
+				kept_priority: kept.0,
+				dropped_priority: dropped.0,
+			});
+			if !incoming_wins {
+				return Ok(());
+			}
+		}
+
+		let binding = action_binding(actions, id, 1, false, None, &parsed_keys);
+		self.matchers.entry(mode).or_default().add(parsed_keys, binding);
+		self.runtime_binds.insert(
+			slot,
+			RuntimeBind {
+				priority,
+				ordinal,
+				target_desc,
+			},
+		);
+		Ok(())
+	}
+}
+
+fn user_precedence(priority: i16, ordinal: usize, target_desc: &str) -> CandidatePrecedence<'_> {
+	CandidatePrecedence {
+		source: KeymapBindingSource::RuntimeAction,
+		ordinal,
+		priority,
+		target_desc,
+		user_override: true,
+	}
+}
+
+fn invocation_name(inv: &Invocation) -> &str {
+	match inv {
+		Invocation::Action { name, .. } | Invocation::ActionWithChar { name, .. } | Invocation::Nu { name, .. } => name,
+		Invocation::Command(command) => &command.name,
+	}
+}
@@ -13,7 +13,8 @@
 //! * `compiler` resolves targets, applies precedence per `(mode, sequence)` slot, and emits
 //!   a `CompiledKeymap` artifact with diagnostics.
 //! * `snapshot` materializes trie matchers from compiled slots for fast lookup and continuation queries.
-//! * `runtime` caches a catalog-scoped immutable snapshot.
+//! * `runtime` caches an immutable snapshot per catalog version and actions generation.
+//! * `layers` stacks named binding layers and explicit user binds on top of a snapshot.
 //!
 //! # Key types
 //!
@@ -21,15 +22,20 @@
 //! |---|---|
 //! | `KeymapSpec` | Collected source candidates before resolution. |
 //! | `CompiledKeymap` | Compile artifact with resolved slots and diagnostics. |
-//! | `KeymapSnapshot` | Immutable runtime lookup index used by input dispatch. |
-//! | `KeymapSnapshotCache` | Snapshot cache keyed by immutable catalog version. |
+//! | `KeymapSnapshot` | Runtime lookup index used by input dispatch. |
+//! | `LayerBinding` | Invocation binding contributed by a pushed layer. |
+//! | `KeymapSnapshotCache` | Snapshot cache keyed by catalog version and actions generation. |
 //!
 //! # Invariants
 //!
 //! * Must resolve one deterministic winner per `(mode, sequence)` slot.
 //! * Must apply precedence in source order: override > preset > runtime-action > action-default.
+//! * Must let explicit user binds (`KeymapSnapshot::bind_runtime`) outrank every compiled source
+//!   through the `user_override` flag, never by source rank or numeric priority.
+//! * Must consult pushed layers last-pushed first before the base trie; unbound sequences fall through.
 //! * Must preserve unbind semantics (`None` override removes inherited bindings).
 //! * Must expose compile diagnostics without aborting snapshot construction.
+//! * Must report prefix shadowing and cross-priority slot collisions through `KeymapSnapshot::binding_conflicts`.
 //!
 //! # Data flow
 //!
@@ -40,12 +46,12 @@
 //!
 //! # Lifecycle
 //!
-//! * Build snapshot once from immutable action catalog data.
-//! * Reuse the same snapshot for the catalog lifetime.
+//! * Build snapshot from the actions registry snapshot at catalog init.
+//! * Reuse it until the actions registry publishes a new generation, then rebuild lazily.
 //!
 //! # Concurrency & ordering
 //!
-//! * Snapshot reads are immutable and lock-free.
+//! * Snapshot reads are immutable; the cache slot is guarded by a short mutex.
 //! * Compile ordering is deterministic through explicit precedence policy and sorted slot materialization.
 //!
 //! # Failure modes & recovery
@@ -67,15 +73,18 @@
 
 mod compiler;
 mod diagnostics;
+mod export;
+mod layers;
 mod precedence;
 mod runtime;
 mod snapshot;
 mod sources;
 mod spec;
 
-pub use diagnostics::KeymapBuildProblem;
+pub use diagnostics::{BindingConflict, BindingEntry, ConflictSeverity, KeymapBuildProblem};
+pub use layers::LayerBinding;
 pub use runtime::{KeymapSnapshotCache, get_keymap_snapshot};
-pub use snapshot::{CompiledBinding, CompiledBindingTarget, KeymapSnapshot, LookupOutcome};
+pub use snapshot::{CompiledBinding, CompiledBindingTarget, Continuation, KeymapSnapshot, LookupOutcome};
 pub use xeno_keymap_core::ContinuationKind;
 
 #[cfg(test)]
@@ -11,16 +11,27 @@ pub(crate) struct CandidatePrecedence<'a> {
 	pub ordinal: usize,
 	pub priority: i16,
 	pub target_desc: &'a str,
+	/// Explicit user bind made at runtime; outranks every source.
+	pub user_override: bool,
 }
 
 /// Compare two candidates and return ordering where `Greater` wins.
 ///
 /// Policy:
+/// * Explicit user binds beat everything else; between two of them, lower
+///   numeric priority wins, then the last writer.
 /// * Source precedence: Override > Preset > RuntimeAction > ActionDefault.
 /// * For ActionDefault/RuntimeAction, lower numeric priority wins.
 /// * For Preset/Override, last-writer ordinal wins.
 /// * Stable tie-break uses target description.
 pub(crate) fn compare_candidates(a: CandidatePrecedence<'_>, b: CandidatePrecedence<'_>) -> Ordering {
+	match (a.user_override, b.user_override) {
+		(true, false) => return Ordering::Greater,
+		(false, true) => return Ordering::Less,
+		(true, true) => return b.priority.cmp(&a.priority).then_with(|| a.ordinal.cmp(&b.ordinal)),
+		(false, false) => {}
+	}
+
 	let by_rank = a.source.rank().cmp(&b.source.rank());
 	if by_rank != Ordering::Equal {
 		return by_rank;
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use super::compiler::KeymapCompiler;
 use super::snapshot::KeymapSnapshot;
@@ -29,20 +29,42 @@ impl KeymapSnapshot {
 	}
 }
 
-/// Immutable keymap snapshot cache keyed by catalog version.
+/// Immutable keymap snapshot cache keyed by catalog version and actions generation.
 pub struct KeymapSnapshotCache {
 	catalog_version: u64,
-	snapshot: Arc<KeymapSnapshot>,
+	state: Mutex<(u64, Arc<KeymapSnapshot>)>,
 }
 
 impl KeymapSnapshotCache {
 	pub fn new(catalog_version: u64, snap: Arc<Snapshot<ActionEntry, ActionId>>) -> Self {
 		let snapshot = Arc::new(KeymapSnapshot::build(&snap));
-		Self { catalog_version, snapshot }
+		Self {
+			catalog_version,
+			state: Mutex::new((snap.generation, snapshot)),
+		}
 	}
 
+	/// Returns the cached snapshot without checking for staleness.
 	pub fn snapshot(&self) -> Arc<KeymapSnapshot> {
-		Arc::clone(&self.snapshot)
+		Arc::clone(&self.lock().1)
+	}
+
+	/// Returns the cached snapshot, rebuilding it first if `actions` is a newer generation.
+	pub fn snapshot_for(&self, actions: &Snapshot<ActionEntry, ActionId>) -> Arc<KeymapSnapshot> {
+		let mut state = self.lock();
+		if state.0 != actions.generation {
+			*state = (actions.generation, Arc::new(KeymapSnapshot::build(actions)));
+		}
+		Arc::clone(&state.1)
+	}
+
+	/// Returns the actions generation the cached snapshot was built from.
+	pub fn actions_generation(&self) -> u64 {
+		self.lock().0
+	}
+
+	fn lock(&self) -> std::sync::MutexGuard<'_, (u64, Arc<KeymapSnapshot>)> {
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
 	}
 
 	pub fn catalog_version(&self) -> u64 {
@@ -50,8 +72,8 @@ impl KeymapSnapshotCache {
 	}
 }
 
-/// Returns the keymap snapshot for the immutable actions registry catalog.
+/// Returns the keymap snapshot for the current actions registry generation.
 pub fn get_keymap_snapshot() -> Arc<KeymapSnapshot> {
 	let db = crate::db::get_catalog();
-	db.keymap.snapshot()
+	db.keymap.snapshot_for(&db.actions.snapshot())
 }
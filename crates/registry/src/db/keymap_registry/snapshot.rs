@@ -1,10 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
-use xeno_keymap_core::parser::Node;
-use xeno_keymap_core::{ContinuationEntry, MatchResult, Matcher};
+use xeno_keymap_core::parser::{Node, parse_seq};
+use xeno_keymap_core::{ContinuationEntry, ContinuationKind, MatchResult, Matcher};
 
-use super::diagnostics::{KeymapBuildProblem, KeymapConflict};
+use super::diagnostics::{BindingConflict, BindingEntry, KeymapBuildProblem, KeymapConflict};
+use super::layers::{KeymapLayer, RuntimeBind};
+use super::spec::SlotKey;
 use crate::actions::BindingMode;
 use crate::core::ActionId;
 use crate::invocation::Invocation;
@@ -75,14 +78,34 @@ impl CompiledBinding {
 /// Lookup result for key sequence matching.
 #[derive(Debug)]
 pub enum LookupOutcome<'a> {
-	/// Complete match.
+	/// Complete match with no longer bindings behind it.
 	Match(&'a CompiledBinding),
-	/// Prefix match awaiting more keys.
-	Pending { sticky: Option<&'a CompiledBinding> },
+	/// Unbound prefix of longer bindings, awaiting more keys.
+	Pending,
+	/// Bound sequence that is also a prefix of longer bindings.
+	///
+	/// Input waits for more keys and commits `exact` once the key sequence
+	/// timeout elapses (see [`KeymapSnapshot::resolve_pending`]).
+	AmbiguousMatch { exact: &'a CompiledBinding, longer_candidates: usize },
 	/// No match.
 	None,
 }
 
+/// Next-key choice at a pending prefix, for which-key style UIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Continuation {
+	/// Key to press next; the variant with the fewest modifiers.
+	pub key: Node,
+	/// Keys differing from `key` only in modifiers that reach the same target.
+	pub variants: Vec<Node>,
+	/// Bound action or invocation name, if the key completes a binding.
+	pub action_name: Option<String>,
+	/// Action description, or the prefix description for sub-prefixes.
+	pub description: String,
+	/// Whether more keys can follow.
+	pub is_prefix: bool,
+}
+
 #[derive(Debug, Clone)]
 struct PrefixEntry {
 	mode: BindingMode,
@@ -90,12 +113,18 @@ struct PrefixEntry {
 	description: Arc<str>,
 }
 
-/// Immutable runtime keymap snapshot used by input dispatch.
+/// Runtime keymap snapshot used by input dispatch.
+///
+/// The compiled base trie is immutable; pushed layers and user runtime binds
+/// are applied on top of it (see [`Self::push_layer`] and [`Self::bind_runtime`]).
 pub struct KeymapSnapshot {
-	matchers: HashMap<BindingMode, Matcher<CompiledBinding>>,
+	pub(super) matchers: HashMap<BindingMode, Matcher<CompiledBinding>>,
 	prefixes: Vec<PrefixEntry>,
-	conflicts: Vec<KeymapConflict>,
-	problems: Vec<KeymapBuildProblem>,
+	pub(super) conflicts: Vec<KeymapConflict>,
+	pub(super) problems: Vec<KeymapBuildProblem>,
+	pub(super) layers: Vec<KeymapLayer>,
+	pub(super) runtime_binds: HashMap<SlotKey, RuntimeBind>,
+	pub(super) next_runtime_ordinal: usize,
 }
 
 impl Default for KeymapSnapshot {
@@ -111,6 +140,9 @@ impl KeymapSnapshot {
 			prefixes: Vec::new(),
 			conflicts: Vec::new(),
 			problems: Vec::new(),
+			layers: Vec::new(),
+			runtime_binds: HashMap::new(),
+			next_runtime_ordinal: 0,
 		}
 	}
 
@@ -128,18 +160,39 @@ impl KeymapSnapshot {
 				.collect(),
 			conflicts,
 			problems,
+			layers: Vec::new(),
+			runtime_binds: HashMap::new(),
+			next_runtime_ordinal: 0,
 		}
 	}
 
+	/// Looks up `keys` in pushed layers, most recent first, then in the base keymap.
+	///
+	/// A layer that does not bind `keys` (or a prefix of them) falls through to
+	/// the layers below it.
 	pub fn lookup(&self, mode: BindingMode, keys: &[Node]) -> LookupOutcome<'_> {
-		let Some(matcher) = self.matchers.get(&mode) else {
-			return LookupOutcome::None;
-		};
-
-		match matcher.lookup(keys) {
-			MatchResult::Complete(entry) => LookupOutcome::Match(entry),
-			MatchResult::Partial { has_value } => LookupOutcome::Pending { sticky: has_value },
-			MatchResult::None => LookupOutcome::None,
+		self.mode_matchers(mode)
+			.map(|matcher| match matcher.lookup(keys) {
+				MatchResult::Complete(entry) => LookupOutcome::Match(entry),
+				MatchResult::Partial { has_value: None } => LookupOutcome::Pending,
+				MatchResult::Partial { has_value: Some(exact) } => LookupOutcome::AmbiguousMatch {
+					exact,
+					longer_candidates: matcher.count_below(keys),
+				},
+				MatchResult::None => LookupOutcome::None,
+			})
+			.find(|outcome| !matches!(outcome, LookupOutcome::None))
+			.unwrap_or(LookupOutcome::None)
+	}
+
+	/// Commits an ambiguous `keys` to its exact binding once `elapsed` reaches `timeout`.
+	///
+	/// Returns `None` while the timeout is still running, or when `keys` is not
+	/// a [`LookupOutcome::AmbiguousMatch`].
+	pub fn resolve_pending(&self, mode: BindingMode, keys: &[Node], elapsed: Duration, timeout: Duration) -> Option<&CompiledBinding> {
+		match self.lookup(mode, keys) {
+			LookupOutcome::AmbiguousMatch { exact, .. } if elapsed >= timeout => Some(exact),
+			_ => None,
 		}
 	}
 
@@ -148,17 +201,79 @@ impl KeymapSnapshot {
 	}
 
 	pub fn continuations_at(&self, mode: BindingMode, prefix: &[Node]) -> Vec<(&Node, Option<&CompiledBinding>)> {
-		let Some(matcher) = self.matchers.get(&mode) else {
-			return Vec::new();
-		};
-		matcher.continuations_at(prefix)
+		let mut seen = HashSet::new();
+		self.mode_matchers(mode)
+			.flat_map(|matcher| matcher.continuations_at(prefix))
+			.filter(|(key, _)| seen.insert(*key))
+			.collect()
 	}
 
 	pub fn continuations_with_kind(&self, mode: BindingMode, prefix: &[Node]) -> Vec<ContinuationEntry<'_, CompiledBinding>> {
-		let Some(matcher) = self.matchers.get(&mode) else {
-			return Vec::new();
-		};
-		matcher.continuations_with_kind(prefix)
+		let mut seen = HashSet::new();
+		self.mode_matchers(mode)
+			.flat_map(|matcher| matcher.continuations_with_kind(prefix))
+			.filter(|entry| seen.insert(entry.key))
+			.collect()
+	}
+
+	/// Returns the next-key choices after `pressed`, sorted by key.
+	///
+	/// Choices that differ only in modifiers and reach the same target are
+	/// grouped into one [`Continuation`], keyed by the least-modified variant.
+	pub fn continuations(&self, mode: BindingMode, pressed: &[Node]) -> Vec<Continuation> {
+		let mut entries = self.continuations_with_kind(mode, pressed);
+		entries.sort_by_cached_key(|entry| (unmodified_key(entry.key), entry.key.modifiers.count_ones(), entry.key.modifiers));
+
+		let pressed_str = sequence_string(pressed);
+		let mut out: Vec<Continuation> = Vec::new();
+		for entry in entries {
+			let is_prefix = entry.kind == ContinuationKind::Branch;
+			let action_name = entry.value.map(|binding| binding.name().to_string());
+			if let Some(group) = out
+				.iter_mut()
+				.find(|c| c.key.key == entry.key.key && c.is_prefix == is_prefix && c.action_name == action_name)
+			{
+				group.variants.push(entry.key.clone());
+				continue;
+			}
+
+			let prefix_desc = is_prefix
+				.then(|| {
+					let keys = if pressed_str.is_empty() {
+						entry.key.to_string()
+					} else {
+						format!("{pressed_str} {}", entry.key)
+					};
+					self.prefix_description(mode, &keys)
+				})
+				.flatten();
+			let description = match (prefix_desc, entry.value) {
+				(Some(desc), _) => desc.to_string(),
+				(None, Some(binding)) => [binding.description(), binding.short_desc(), binding.name()]
+					.into_iter()
+					.find(|text| !text.is_empty())
+					.unwrap_or_default()
+					.to_string(),
+				(None, None) => String::new(),
+			};
+			out.push(Continuation {
+				key: entry.key.clone(),
+				variants: Vec::new(),
+				action_name,
+				description,
+				is_prefix,
+			});
+		}
+		out
+	}
+
+	/// Matchers for `mode` in lookup order: layers top-down, then the base trie.
+	fn mode_matchers(&self, mode: BindingMode) -> impl Iterator<Item = &Matcher<CompiledBinding>> {
+		self.layers
+			.iter()
+			.rev()
+			.filter_map(move |layer| layer.matchers.get(&mode))
+			.chain(self.matchers.get(&mode))
 	}
 
 	pub fn conflicts(&self) -> &[KeymapConflict] {
@@ -168,4 +283,62 @@ impl KeymapSnapshot {
 	pub fn problems(&self) -> &[KeymapBuildProblem] {
 		&self.problems
 	}
+
+	/// Returns ambiguous binding pairs across all modes.
+	///
+	/// Walks each mode's trie for bindings that are also prefixes of longer ones
+	/// ([`ConflictSeverity::Shadowing`]), then adds same-sequence slot conflicts
+	/// between different priorities ([`ConflictSeverity::Collision`]). Results are
+	/// sorted by mode and key sequence.
+	///
+	/// [`ConflictSeverity::Shadowing`]: super::diagnostics::ConflictSeverity::Shadowing
+	/// [`ConflictSeverity::Collision`]: super::diagnostics::ConflictSeverity::Collision
+	pub fn binding_conflicts(&self) -> Vec<BindingConflict> {
+		let mut out = Vec::new();
+		for (&mode, matcher) in &self.matchers {
+			out.extend(matcher.shadowed_bindings().into_iter().map(|shadow| BindingConflict {
+				mode,
+				prefix: shadow.prefix,
+				shadowed: compiled_entry(shadow.shadowed),
+				shadowing: compiled_entry(shadow.shadowing),
+			}));
+		}
+		for conflict in self.conflicts.iter().filter(|c| c.kept_priority != c.dropped_priority) {
+			let Ok(keys) = parse_seq(&conflict.keys) else {
+				continue;
+			};
+			out.push(BindingConflict {
+				mode: conflict.mode,
+				prefix: keys.clone(),
+				shadowed: BindingEntry {
+					keys: keys.clone(),
+					target: conflict.dropped_target.clone(),
+					priority: Some(conflict.dropped_priority),
+				},
+				shadowing: BindingEntry {
+					keys,
+					target: conflict.kept_target.clone(),
+					priority: Some(conflict.kept_priority),
+				},
+			});
+		}
+		out.sort_by_cached_key(|c| (c.mode, sequence_string(&c.prefix), sequence_string(&c.shadowed.keys), c.shadowed.target.clone()));
+		out
+	}
+}
+
+fn compiled_entry(binding: &CompiledBinding) -> BindingEntry {
+	BindingEntry {
+		keys: binding.keys.clone(),
+		target: binding.name.to_string(),
+		priority: None,
+	}
+}
+
+fn unmodified_key(node: &Node) -> String {
+	Node::new(0, node.key).to_string()
+}
+
+pub(super) fn sequence_string(keys: &[Node]) -> String {
+	keys.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
 }
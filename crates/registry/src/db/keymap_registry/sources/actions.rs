@@ -7,7 +7,7 @@ use crate::db::keymap_registry::spec::{KeymapBindingSource, KeymapSpec, SlotKey,
 
 pub(crate) fn collect_action_defaults(actions: &Snapshot<ActionEntry, ActionId>, spec: &mut KeymapSpec, ordinal: &mut usize) {
 	let mut bindings = Vec::new();
-	for (idx, action_entry) in actions.table.iter().enumerate() {
+	for (idx, action_entry) in actions.entries() {
 		let action_id = ActionId::from_u32(idx as u32);
 		for binding in action_entry.bindings.iter() {
 			bindings.push((action_id, binding.clone()));
@@ -44,7 +44,7 @@ pub(crate) fn collect_action_defaults(actions: &Snapshot<ActionEntry, ActionId>,
 }
 
 pub(crate) fn collect_runtime_action_bindings(actions: &Snapshot<ActionEntry, ActionId>, spec: &mut KeymapSpec, ordinal: &mut usize) {
-	for (idx, action_entry) in actions.table.iter().enumerate() {
+	for (idx, action_entry) in actions.entries() {
 		if !matches!(action_entry.source(), RegistrySource::Runtime) {
 			continue;
 		}
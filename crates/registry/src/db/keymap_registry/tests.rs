@@ -204,6 +204,56 @@ fn which_key_labels_invocation() {
 	));
 }
 
+#[test]
+fn continuations_under_g_list_goto_motions() {
+	let actions = crate::db::ACTIONS.snapshot();
+	let index = KeymapSnapshot::build(&actions);
+	let g_key = parse_seq("g").expect("should parse");
+	let continuations = index.continuations(BindingMode::Normal, &g_key);
+
+	for (key, action, description) in [
+		("g", "document_start", "Goto file start"),
+		("e", "document_end", "Goto file end"),
+		("h", "goto_line_start", "Goto line start"),
+		("l", "goto_line_end", "Goto line end"),
+	] {
+		let cont = continuations
+			.iter()
+			.find(|c| c.key.to_string() == key)
+			.unwrap_or_else(|| panic!("should have '{key}' continuation under 'g'"));
+		assert_eq!(cont.action_name.as_deref(), Some(action));
+		assert_eq!(cont.description, description);
+		assert!(!cont.is_prefix);
+	}
+
+	let keys: Vec<String> = continuations.iter().map(|c| c.key.to_string()).collect();
+	let mut sorted = keys.clone();
+	sorted.sort();
+	assert_eq!(keys, sorted, "continuations should be sorted by key");
+}
+
+#[test]
+fn continuations_group_modifier_variants_of_same_target() {
+	let actions = crate::db::ACTIONS.snapshot();
+	let mut normal = HashMap::new();
+	normal.insert("g x".to_string(), Some(Invocation::action("document_start")));
+	normal.insert("g alt-x".to_string(), Some(Invocation::action("document_start")));
+	normal.insert("g ctrl-x".to_string(), Some(Invocation::action("document_end")));
+	let mut modes = HashMap::new();
+	modes.insert("normal".to_string(), normal);
+	let overrides = UnresolvedKeys { modes };
+
+	let index = KeymapSnapshot::build_with_overrides(&actions, Some(&overrides));
+	let g_key = parse_seq("g").expect("should parse");
+	let continuations = index.continuations(BindingMode::Normal, &g_key);
+
+	let x = continuations.iter().find(|c| c.key.to_string() == "x").expect("x continuation");
+	assert_eq!(x.variants.iter().map(ToString::to_string).collect::<Vec<_>>(), ["alt-x"]);
+	let ctrl_x = continuations.iter().find(|c| c.key.to_string() == "ctrl-x").expect("ctrl-x stays separate");
+	assert_eq!(ctrl_x.action_name.as_deref(), Some("document_end"));
+	assert!(!continuations.iter().any(|c| c.key.to_string() == "alt-x"));
+}
+
 #[test]
 fn invalid_override_produces_problem() {
 	use crate::db::keymap_registry::diagnostics::KeymapProblemKind;
@@ -309,3 +359,252 @@ fn preset_binding_precedes_action_default_binding() {
 	assert_eq!(resolved_id, target_id);
 	assert_ne!(resolved_id, base_id);
 }
+
+fn invocation_binding(name: &str, keys: &str) -> (Vec<xeno_keymap_core::parser::Node>, CompiledBinding) {
+	let keys = parse_seq(keys).expect("test key sequence should parse");
+	let binding = CompiledBinding::new(
+		CompiledBindingTarget::Invocation {
+			inv: Invocation::command(name, vec![]),
+		},
+		Arc::from(name),
+		Arc::from(name),
+		Arc::from(name),
+		keys.clone(),
+	);
+	(keys, binding)
+}
+
+#[test]
+fn binding_conflicts_report_shadowing_prefix() {
+	let matcher = [
+		invocation_binding("goto", "g"),
+		invocation_binding("definition", "g d"),
+		invocation_binding("line", "x"),
+	]
+	.into_iter()
+	.collect();
+	let mut matchers = HashMap::new();
+	matchers.insert(BindingMode::Normal, matcher);
+	let index = KeymapSnapshot::from_parts(matchers, Vec::new(), Vec::new(), Vec::new());
+
+	let conflicts = index.binding_conflicts();
+	assert_eq!(conflicts.len(), 1);
+	let conflict = &conflicts[0];
+	assert_eq!(conflict.severity(), ConflictSeverity::Shadowing);
+	assert_eq!(conflict.mode, BindingMode::Normal);
+	assert_eq!(conflict.prefix, parse_seq("g").unwrap());
+	assert_eq!(conflict.shadowing.target, "goto");
+	assert_eq!(conflict.shadowed.target, "definition");
+	assert_eq!(conflict.shadowed.keys, parse_seq("g d").unwrap());
+}
+
+#[test]
+fn binding_conflicts_report_cross_priority_collisions() {
+	let slot_conflict = |kept_priority, dropped_priority| super::diagnostics::KeymapConflict {
+		mode: BindingMode::Insert,
+		keys: Arc::from("ctrl-s"),
+		kept_target: "save".to_string(),
+		dropped_target: "search".to_string(),
+		kept_priority,
+		dropped_priority,
+	};
+	let index = KeymapSnapshot::from_parts(HashMap::new(), Vec::new(), vec![slot_conflict(10, 50), slot_conflict(50, 50)], Vec::new());
+
+	let conflicts = index.binding_conflicts();
+	assert_eq!(conflicts.len(), 1, "equal-priority slot conflicts are ordinary precedence");
+	let conflict = &conflicts[0];
+	assert_eq!(conflict.severity(), ConflictSeverity::Collision);
+	assert_eq!(conflict.prefix, parse_seq("ctrl-s").unwrap());
+	assert_eq!((conflict.shadowing.target.as_str(), conflict.shadowing.priority), ("save", Some(10)));
+	assert_eq!((conflict.shadowed.target.as_str(), conflict.shadowed.priority), ("search", Some(50)));
+}
+
+#[test]
+fn pushed_layers_shadow_base_until_popped() {
+	let actions = crate::db::ACTIONS.snapshot();
+	let (mode, key_seq, base_id, _target_id, _target_name) = sample_binding(&actions).expect("registry should contain at least one binding");
+	let keys = parse_seq(&key_seq).expect("key sequence should parse");
+	let matched_name = |index: &KeymapSnapshot| match index.lookup(mode, &keys) {
+		LookupOutcome::Match(entry) => entry.name().to_string(),
+		_ => panic!("expected a complete keybinding match"),
+	};
+
+	let mut index = KeymapSnapshot::build(&actions);
+	index.push_layer(
+		"picker",
+		vec![LayerBinding::new(mode, key_seq.as_str(), Invocation::editor_command("picker-next", vec![]))],
+	);
+	index.push_layer(
+		"prompt",
+		vec![LayerBinding::new(mode, key_seq.as_str(), Invocation::editor_command("prompt-accept", vec![]))],
+	);
+	assert_eq!(index.layer_names().collect::<Vec<_>>(), ["picker", "prompt"]);
+	assert_eq!(matched_name(&index), "prompt-accept");
+	assert!(
+		index.continuations_at(mode, &[]).iter().filter(|(key, _)| **key == keys[0]).count() == 1,
+		"shadowed base keys are listed once"
+	);
+
+	assert!(index.pop_layer("picker"));
+	assert_eq!(matched_name(&index), "prompt-accept");
+	assert!(index.pop_layer("prompt"));
+	assert_eq!(lookup_action_id(&index, mode, &key_seq), base_id);
+	assert!(!index.pop_layer("prompt"));
+}
+
+#[test]
+fn layer_falls_through_for_unbound_sequences() {
+	use crate::db::keymap_registry::diagnostics::KeymapProblemKind;
+
+	let actions = crate::db::ACTIONS.snapshot();
+	let (mode, key_seq, base_id, _target_id, _target_name) = sample_binding(&actions).expect("registry should contain at least one binding");
+
+	let mut index = KeymapSnapshot::build(&actions);
+	index.push_layer(
+		"picker",
+		vec![
+			LayerBinding::new(mode, "ctrl-alt-z", Invocation::editor_command("picker-next", vec![])),
+			LayerBinding::new(mode, "ctrl-", Invocation::editor_command("picker-prev", vec![])),
+		],
+	);
+	assert_eq!(lookup_action_id(&index, mode, &key_seq), base_id);
+	assert!(
+		index
+			.problems()
+			.iter()
+			.any(|p| p.kind == KeymapProblemKind::InvalidKeySequence && &*p.keys == "ctrl-")
+	);
+}
+
+#[test]
+fn user_bind_overrides_builtin_regardless_of_priority() {
+	use crate::db::keymap_registry::diagnostics::KeymapProblemKind;
+
+	let actions = crate::db::ACTIONS.snapshot();
+	let action_id = |name: &str| super::compiler::resolve_action_by_name(&actions, name).expect("action registered");
+	let mut index = KeymapSnapshot::build(&actions);
+	assert_eq!(lookup_action_id(&index, BindingMode::Normal, "x"), action_id("select_line"));
+
+	index
+		.bind_runtime(&actions, BindingMode::Normal, "x", "extend_line", i16::MAX)
+		.expect("user bind should apply");
+	assert_eq!(lookup_action_id(&index, BindingMode::Normal, "x"), action_id("extend_line"));
+
+	index
+		.bind_runtime(&actions, BindingMode::Normal, "x", "select_all", i16::MAX)
+		.expect("equal priority rebind should apply");
+	assert_eq!(lookup_action_id(&index, BindingMode::Normal, "x"), action_id("select_all"));
+
+	index
+		.bind_runtime(&actions, BindingMode::Normal, "x", "select_line", 0)
+		.expect("lower priority rebind should apply");
+	index
+		.bind_runtime(&actions, BindingMode::Normal, "x", "extend_line", 10)
+		.expect("losing bind is not an error");
+	assert_eq!(lookup_action_id(&index, BindingMode::Normal, "x"), action_id("select_line"));
+	let last = index.conflicts().last().expect("losing user bind is recorded");
+	assert_eq!((last.kept_priority, last.dropped_priority), (0, 10));
+
+	let err = index
+		.bind_runtime(&actions, BindingMode::Normal, "x", "does-not-exist", 0)
+		.expect_err("unknown action is rejected");
+	assert_eq!(err.kind, KeymapProblemKind::UnknownActionTarget);
+}
+
+fn goto_keymap() -> KeymapSnapshot {
+	let matcher = [
+		invocation_binding("goto", "g"),
+		invocation_binding("definition", "g d"),
+		invocation_binding("references", "g r"),
+		invocation_binding("line", "x"),
+		invocation_binding("window-split", "ctrl-w s"),
+	]
+	.into_iter()
+	.collect();
+	let mut matchers = HashMap::new();
+	matchers.insert(BindingMode::Normal, matcher);
+	KeymapSnapshot::from_parts(matchers, Vec::new(), Vec::new(), Vec::new())
+}
+
+#[test]
+fn lookup_distinguishes_exact_prefix_and_ambiguous_sequences() {
+	let index = goto_keymap();
+	let lookup = |keys: &str| index.lookup(BindingMode::Normal, &parse_seq(keys).unwrap());
+
+	assert!(matches!(lookup("x"), LookupOutcome::Match(entry) if entry.name() == "line"));
+	assert!(matches!(lookup("ctrl-w"), LookupOutcome::Pending));
+	assert!(matches!(
+		lookup("g"),
+		LookupOutcome::AmbiguousMatch { exact, longer_candidates: 2 } if exact.name() == "goto"
+	));
+	assert!(matches!(lookup("g d"), LookupOutcome::Match(entry) if entry.name() == "definition"));
+}
+
+#[test]
+fn resolve_pending_commits_ambiguous_match_after_timeout() {
+	use std::time::Duration;
+
+	let index = goto_keymap();
+	let timeout = Duration::from_millis(500);
+	let resolve = |keys: &str, elapsed| index.resolve_pending(BindingMode::Normal, &parse_seq(keys).unwrap(), elapsed, timeout);
+
+	assert!(resolve("g", Duration::from_millis(100)).is_none(), "timeout still running");
+	assert_eq!(resolve("g", timeout).map(CompiledBinding::name), Some("goto"));
+	assert!(resolve("ctrl-w", Duration::from_secs(5)).is_none(), "unbound prefixes never commit");
+	assert!(resolve("x", Duration::from_secs(5)).is_none(), "exact matches dispatch without waiting");
+}
+
+#[test]
+fn nuon_export_round_trips_through_config_parsing() {
+	let actions = crate::db::ACTIONS.snapshot();
+	let action_name = actions.interner.resolve(actions.table[0].name()).to_string();
+
+	let mut overrides = UnresolvedKeys::default();
+	let insert = overrides.modes.entry("insert".to_string()).or_default();
+	insert.insert(
+		"ctrl-r".to_string(),
+		Some(Invocation::Action {
+			name: action_name.clone(),
+			count: 3,
+			extend: true,
+			register: Some('a'),
+		}),
+	);
+	insert.insert(
+		"ctrl-n".to_string(),
+		Some(Invocation::nu("go", vec!["two words".to_string(), "say \"hi\"".to_string()])),
+	);
+	let space = overrides.modes.entry("space".to_string()).or_default();
+	space.insert("w".to_string(), Some(Invocation::command("write", vec![])));
+	space.insert("q".to_string(), Some(Invocation::editor_command("quit", vec!["force".to_string()])));
+	space.insert(
+		"f x".to_string(),
+		Some(Invocation::ActionWithChar {
+			name: action_name,
+			count: 1,
+			extend: false,
+			register: None,
+			char_arg: 'x',
+		}),
+	);
+
+	let preset = crate::keymaps::preset("vim").expect("vim preset should exist");
+	let index = KeymapSnapshot::build_with_preset(&actions, Some(&preset), Some(&overrides));
+	let exported = index.to_nuon();
+
+	let config = crate::config::nuon::parse_config_str(&exported).expect("exported keymap should parse");
+	let keys = config.keymap.and_then(|keymap| keymap.keys).expect("exported config should carry keys");
+	let binding_count: usize = keys.modes.values().map(HashMap::len).sum();
+	assert!(binding_count >= 20, "expected at least 20 bindings, got {binding_count}");
+	assert!(keys.modes.len() >= 3, "expected at least 3 modes, got {:?}", keys.modes.keys());
+
+	let empty = crate::keymaps::KeymapPreset {
+		name: Arc::from("empty"),
+		initial_mode: xeno_primitives::Mode::Normal,
+		behavior: crate::keymaps::KeymapBehavior::default(),
+		bindings: Vec::new(),
+		prefixes: Vec::new(),
+	};
+	let rebuilt = KeymapSnapshot::build_with_preset(&actions, Some(&empty), Some(&keys));
+	assert_eq!(rebuilt.to_nuon(), exported);
+}
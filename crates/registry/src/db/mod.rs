@@ -16,6 +16,7 @@ pub mod index;
 pub mod keymap_registry;
 
 use crate::actions::entry::ActionEntry;
+use crate::core::RegistrySet;
 #[cfg(feature = "keymap")]
 use crate::db::keymap_registry::KeymapSnapshotCache;
 use crate::domains::catalog::with_registry_domains;
@@ -63,6 +64,17 @@ pub enum CatalogLoadError {
 	BuiltinRegistration(#[from] crate::core::RegistryError),
 	#[error("invalid cross-domain references: {0:?}")]
 	InvalidCrossDomainReferences(Vec<String>),
+	#[error("registry construction order: {0}")]
+	ConstructionOrder(crate::core::RegistryError),
+}
+
+/// Registries that other catalog parts read during construction, built via [`RegistrySet`].
+#[derive(Default)]
+struct CoreRuntimes {
+	actions: Option<<crate::actions::Actions as crate::db::domain::DomainSpec>::Runtime>,
+	commands: Option<<crate::commands::Commands as crate::db::domain::DomainSpec>::Runtime>,
+	#[cfg(feature = "keymap")]
+	keymap: Option<KeymapSnapshotCache>,
 }
 
 impl RegistryCatalog {
@@ -74,16 +86,37 @@ impl RegistryCatalog {
 	}
 
 	fn from_indices(indices: builder::RegistryIndices) -> Result<Self, CatalogLoadError> {
-		let actions_reg = <crate::actions::Actions as crate::db::domain::DomainSpec>::into_runtime(indices.actions);
-
+		let mut core = CoreRuntimes::default();
+		let mut set = RegistrySet::new();
+		let actions = indices.actions;
+		set.add("actions", move |core: &mut CoreRuntimes| {
+			core.actions = Some(<crate::actions::Actions as crate::db::domain::DomainSpec>::into_runtime(actions));
+		});
+		let commands = indices.commands;
+		set.add("commands", move |core: &mut CoreRuntimes| {
+			core.commands = Some(<crate::commands::Commands as crate::db::domain::DomainSpec>::into_runtime(commands));
+		});
+		#[cfg(feature = "keymap")]
+		set.add("keymap", |core: &mut CoreRuntimes| {
+			let actions = core.actions.as_ref().expect("actions are built before the keymap");
+			core.keymap = Some(KeymapSnapshotCache::new(0, actions.snapshot()));
+		})
+		.depends_on("actions");
+		set.build(&mut core).map_err(CatalogLoadError::ConstructionOrder)?;
+
+		let actions_reg = core.actions.expect("actions registry is built");
+		let commands_reg = core.commands.expect("commands registry is built");
 		#[cfg(feature = "keymap")]
-		let keymap = KeymapSnapshotCache::new(0, actions_reg.snapshot());
+		let keymap = core.keymap.expect("keymap cache is built");
 
 		macro_rules! domain_runtime {
-			(actions, $marker:path, $indices:ident, $actions_reg:ident) => {
-				$actions_reg
+			(actions, $marker:path, $indices:ident) => {
+				actions_reg
 			};
-			($field:ident, $marker:path, $indices:ident, $actions_reg:ident) => {
+			(commands, $marker:path, $indices:ident) => {
+				commands_reg
+			};
+			($field:ident, $marker:path, $indices:ident) => {
 				<$marker as crate::db::domain::DomainSpec>::into_runtime($indices.$field)
 			};
 		}
@@ -101,7 +134,7 @@ impl RegistryCatalog {
 				)*
 			) => {
 				RegistryCatalog {
-					$( $(#[$attr])* $field: domain_runtime!($field, $marker, indices, actions_reg), )*
+					$( $(#[$attr])* $field: domain_runtime!($field, $marker, indices), )*
 					#[cfg(feature = "keymap")]
 					keymap,
 					version_hash: 0,
@@ -139,6 +172,11 @@ impl RegistryCatalog {
 		}
 	}
 
+	/// Explains how `name` resolves in every domain that knows it.
+	pub fn explain(&self, name: &str) -> Vec<NameExplanation> {
+		explain_catalog_name(self, name)
+	}
+
 	pub fn notifications_reg(&self) -> &RuntimeRegistry<crate::notifications::NotificationEntry, crate::notifications::NotificationId> {
 		&self.notifications
 	}
@@ -182,6 +220,57 @@ pub struct CatalogDiagnostics {
 	pub collisions: Vec<crate::core::Collision>,
 }
 
+/// Owned explanation of a name lookup within one catalog domain.
+#[derive(Debug, Clone)]
+pub struct NameExplanation {
+	/// Catalog domain field name (e.g. `actions`).
+	pub registry: &'static str,
+	/// Canonical id of the winning entry.
+	pub winner: Option<String>,
+	/// Canonical ids of effective entries that lost the name.
+	pub losers: Vec<String>,
+	/// Precedence reasoning, one line per decision.
+	pub reason: String,
+}
+
+macro_rules! define_catalog_explain_fn {
+	(
+		$(
+			$(#[$attr:meta])*
+			{
+				field: $field:ident,
+				global: $global:ident,
+				marker: $marker:path,
+				$(,)?
+			}
+		)*
+	) => {
+		fn explain_catalog_name(catalog: &RegistryCatalog, name: &str) -> Vec<NameExplanation> {
+			use crate::core::RegistryEntry;
+
+			let mut out = Vec::new();
+			$(
+				$(#[$attr])*
+				{
+					let guard = catalog.$field.snapshot_guard();
+					let snap = guard.snapshot();
+					if let Some(explanation) = snap.explain(name) {
+						out.push(NameExplanation {
+							registry: stringify!($field),
+							winner: explanation.winner.map(|entry| snap.interner.resolve(entry.id()).to_string()),
+							losers: explanation.losers.iter().map(|entry| snap.interner.resolve(entry.id()).to_string()).collect(),
+							reason: explanation.reason,
+						});
+					}
+				}
+			)*
+			out
+		}
+	};
+}
+
+with_registry_domains!(define_catalog_explain_fn);
+
 macro_rules! define_catalog_collision_fn {
 	(
 		$(
@@ -251,10 +340,12 @@ mod tests {
 			id: "test::language::invalid_lsp_ref",
 			name: "invalid_lsp_ref",
 			keys: &[],
+			categories: &[],
 			description: "test language with unknown lsp server",
 			priority: 0,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		scope: None,
 		grammar_name: None,
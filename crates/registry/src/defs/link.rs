@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::core::{LinkedMetaOwned, RegistrySource};
+use crate::core::{Deprecation, LinkedMetaOwned, RegistrySource};
 use crate::defs::spec::MetaCommonSpec;
 
 /// Builds `LinkedMetaOwned` from `MetaCommonSpec` with consistent defaults.
@@ -9,14 +9,24 @@ pub fn linked_meta_from_spec(common: &MetaCommonSpec) -> LinkedMetaOwned {
 		id: format!("xeno-registry::{}", common.name),
 		name: common.name.clone(),
 		keys: common.keys.clone(),
+		categories: common.categories.clone(),
 		description: common.description.clone(),
 		priority: common.priority,
 		source: RegistrySource::Crate(env!("CARGO_PKG_NAME")),
 		mutates_buffer: common.mutates_buffer,
+		deprecated: deprecation_from_spec(common),
 		short_desc: common.short_desc.clone().unwrap_or_else(|| common.description.clone()),
 	}
 }
 
+/// Converts the spec's `deprecated` record into an owned [`Deprecation`].
+pub fn deprecation_from_spec(common: &MetaCommonSpec) -> Option<Deprecation<String>> {
+	common.deprecated.as_ref().map(|d| Deprecation {
+		since: d.since.clone(),
+		replacement: d.replacement.clone(),
+	})
+}
+
 /// Links definition specs with handler statics using a name-based bijection.
 ///
 /// Panics if any spec entry has no matching handler, or vice versa.
@@ -1,17 +1,25 @@
 use crate::actions::{ActionEffects, ActionResult, PendingAction, PendingKind, action_handler, edit_op};
 
-action_handler!(delete, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::delete(true))));
+action_handler!(delete, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::delete(true).with_register(ctx.register)
+)));
 action_handler!(delete_no_yank, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::delete(false))));
-action_handler!(change, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::change(true))));
+action_handler!(change, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::change(true).with_register(ctx.register)
+)));
 action_handler!(change_no_yank, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::change(false))));
-action_handler!(yank, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::yank())));
-action_handler!(paste_after, |_ctx| ActionResult::Effects(ActionEffects::paste(false)));
-action_handler!(paste_before, |_ctx| ActionResult::Effects(ActionEffects::paste(true)));
+action_handler!(yank, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::yank().with_register(ctx.register)
+)));
+action_handler!(paste_after, |ctx| ActionResult::Effects(ActionEffects::paste(false, ctx.count, ctx.register)));
+action_handler!(paste_before, |ctx| ActionResult::Effects(ActionEffects::paste(true, ctx.count, ctx.register)));
 action_handler!(undo, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::undo())));
 action_handler!(redo, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::redo())));
 action_handler!(indent, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::indent())));
 action_handler!(deindent, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::deindent())));
-action_handler!(join_lines, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::join_lines())));
+action_handler!(join_lines, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::join_lines().with_count(ctx.count)
+)));
 action_handler!(delete_back, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::delete_back())));
 action_handler!(delete_forward, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::delete_forward())));
 action_handler!(delete_word_back, |_ctx| ActionResult::Effects(ActionEffects::edit_op(
@@ -20,8 +28,16 @@ action_handler!(delete_word_back, |_ctx| ActionResult::Effects(ActionEffects::ed
 action_handler!(delete_word_forward, |_ctx| ActionResult::Effects(ActionEffects::edit_op(
 	edit_op::delete_word_forward()
 )));
-action_handler!(paste_all_after, |_ctx| ActionResult::Effects(ActionEffects::paste(false)));
-action_handler!(paste_all_before, |_ctx| ActionResult::Effects(ActionEffects::paste(true)));
+action_handler!(paste_all_after, |ctx| ActionResult::Effects(ActionEffects::paste(
+	false,
+	ctx.count,
+	ctx.register
+)));
+action_handler!(paste_all_before, |ctx| ActionResult::Effects(ActionEffects::paste(
+	true,
+	ctx.count,
+	ctx.register
+)));
 action_handler!(to_lowercase, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::case_convert(
 	edit_op::CharMapKind::ToLowerCase
 ))));
@@ -31,8 +47,12 @@ action_handler!(to_uppercase, |_ctx| ActionResult::Effects(ActionEffects::edit_o
 action_handler!(swap_case, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::case_convert(
 	edit_op::CharMapKind::SwapCase
 ))));
-action_handler!(open_below, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::open_below())));
-action_handler!(open_above, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::open_above())));
+action_handler!(open_below, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::open_below().with_count(ctx.count)
+)));
+action_handler!(open_above, |ctx| ActionResult::Effects(ActionEffects::edit_op(
+	edit_op::open_above().with_count(ctx.count)
+)));
 
 action_handler!(replace_char, |ctx| match ctx.args.char {
 	Some(ch) => ActionResult::Effects(ActionEffects::edit_op(edit_op::replace_with_char(ch))),
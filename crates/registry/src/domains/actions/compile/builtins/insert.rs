@@ -35,3 +35,8 @@ action_handler!(insert_after, |ctx| {
 });
 
 action_handler!(insert_newline, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::insert_newline())));
+
+action_handler!(insert_char, |ctx| match ctx.args.char {
+	Some(ch) => ActionResult::Effects(ActionEffects::edit_op(edit_op::insert_char(ch))),
+	None => ActionResult::Effects(ActionEffects::ok()),
+});
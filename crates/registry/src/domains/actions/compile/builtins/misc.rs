@@ -1,7 +1,37 @@
-use crate::actions::{ActionEffects, ActionResult, ViewEffect, action_handler, edit_op};
+use crate::actions::{ActionEffects, ActionResult, AppEffect, ViewEffect, action_handler, edit_op};
 
 action_handler!(add_line_below, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::add_line_below())));
 action_handler!(add_line_above, |_ctx| ActionResult::Effects(ActionEffects::edit_op(edit_op::add_line_above())));
 action_handler!(use_selection_as_search, |_ctx| ActionResult::Effects(ViewEffect::UseSelectionAsSearch.into()));
 
 action_handler!(open_palette, |_ctx| ActionResult::Effects(crate::actions::UiEffect::OpenPalette.into()));
+
+action_handler!(repeat_last, |_ctx| ActionResult::Effects(crate::actions::AppEffect::RepeatLastAction.into()));
+
+action_handler!(toggle_notifications, |_ctx| ActionResult::Effects(
+	crate::actions::UiEffect::ToggleDoNotDisturb.into()
+));
+
+/// Register used by the macro actions when none is given.
+const DEFAULT_MACRO_REGISTER: char = 'q';
+
+action_handler!(record_macro, |ctx| ActionResult::Effects(
+	AppEffect::ToggleMacroRecording {
+		register: ctx.register.unwrap_or(DEFAULT_MACRO_REGISTER),
+	}
+	.into()
+));
+action_handler!(replay_macro, |ctx| ActionResult::Effects(
+	AppEffect::ReplayMacro {
+		register: Some(ctx.register.unwrap_or(DEFAULT_MACRO_REGISTER)),
+		count: ctx.count,
+	}
+	.into()
+));
+action_handler!(replay_last_macro, |ctx| ActionResult::Effects(
+	AppEffect::ReplayMacro {
+		register: None,
+		count: ctx.count,
+	}
+	.into()
+));
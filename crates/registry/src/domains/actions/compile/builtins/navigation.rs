@@ -51,3 +51,7 @@ action_handler!(move_bottom_screen, |ctx| {
 
 action_handler!(goto_next_hunk, |ctx| cursor_motion(ctx, motion_ids::NEXT_HUNK));
 action_handler!(goto_prev_hunk, |ctx| cursor_motion(ctx, motion_ids::PREV_HUNK));
+action_handler!(goto_next_sibling_node, |ctx| cursor_motion(ctx, motion_ids::NEXT_SIBLING_NODE));
+action_handler!(goto_prev_sibling_node, |ctx| cursor_motion(ctx, motion_ids::PREV_SIBLING_NODE));
+action_handler!(goto_parent_node, |ctx| cursor_motion(ctx, motion_ids::PARENT_NODE_START));
+action_handler!(goto_node_end, |ctx| cursor_motion(ctx, motion_ids::NODE_END));
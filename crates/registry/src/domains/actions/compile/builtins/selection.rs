@@ -1,6 +1,6 @@
-use xeno_primitives::Selection;
+use xeno_primitives::{Direction, Selection};
 
-use crate::actions::{ActionEffects, ActionResult, action_handler};
+use crate::actions::{ActionEffects, ActionResult, PendingAction, PendingKind, action_handler};
 
 action_handler!(collapse_selection, |ctx| {
 	let mut new_sel = ctx.selection.clone();
@@ -161,6 +161,21 @@ fn split_lines_impl(ctx: &crate::actions::ActionContext) -> ActionResult {
 	}
 }
 
+action_handler!(split_selection_on_regex, |_ctx| ActionResult::Effects(ActionEffects::pending(PendingAction {
+	kind: PendingKind::SplitRegex,
+	prompt: "split:".into(),
+})));
+
+action_handler!(add_cursor_below, |ctx| ActionResult::Effects(ActionEffects::add_cursor_vertical(
+	Direction::Forward,
+	ctx.count
+)));
+
+action_handler!(add_cursor_above, |ctx| ActionResult::Effects(ActionEffects::add_cursor_vertical(
+	Direction::Backward,
+	ctx.count
+)));
+
 action_handler!(duplicate_selections_down, handler: duplicate_selections_down_impl);
 
 fn duplicate_selections_down_impl(ctx: &crate::actions::ActionContext) -> ActionResult {
@@ -23,8 +23,8 @@ fn select_object_with_trigger(ctx: &crate::actions::ActionContext, selection_kin
 	new_sel.transform_mut(|r| {
 		let pos = r.head;
 		let result = match selection_kind {
-			ObjectSelectionKind::Inner => (obj.inner)(ctx.text, pos),
-			ObjectSelectionKind::Around => (obj.around)(ctx.text, pos),
+			ObjectSelectionKind::Inner => obj.select_inner(ctx.text, ctx.syntax, pos),
+			ObjectSelectionKind::Around => obj.select_around(ctx.text, ctx.syntax, pos),
 			ObjectSelectionKind::ToStart => select_to_boundary(ctx, &obj, pos, true),
 			ObjectSelectionKind::ToEnd => select_to_boundary(ctx, &obj, pos, false),
 		};
@@ -37,7 +37,7 @@ fn select_object_with_trigger(ctx: &crate::actions::ActionContext, selection_kin
 }
 
 fn select_to_boundary(ctx: &crate::actions::ActionContext, obj: &crate::textobj::TextObjectEntry, pos: usize, to_start: bool) -> Option<Range> {
-	let range = (obj.around)(ctx.text, pos)?;
+	let range = obj.select_around(ctx.text, ctx.syntax, pos)?;
 	if to_start {
 		Some(Range::new(pos, range.min()))
 	} else {
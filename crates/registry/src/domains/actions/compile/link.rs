@@ -14,15 +14,23 @@ pub type LinkedActionDef = LinkedDef<ActionPayload>;
 pub struct ActionPayload {
 	pub handler: ActionHandler,
 	pub bindings: Arc<[KeyBindingDef]>,
+	pub repeatable: bool,
+	pub keyboard_icon: Option<String>,
 }
 
 impl LinkedPayload<ActionEntry> for ActionPayload {
-	fn build_entry(&self, _ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, short_desc: Symbol) -> ActionEntry {
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.keyboard_icon.as_deref());
+	}
+
+	fn build_entry(&self, ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, short_desc: Symbol) -> ActionEntry {
 		ActionEntry {
 			meta,
 			short_desc,
 			handler: self.handler,
 			bindings: Arc::clone(&self.bindings),
+			repeatable: self.repeatable,
+			keyboard_icon: self.keyboard_icon.as_deref().map(|icon| ctx.intern(icon)),
 		}
 	}
 }
@@ -67,15 +75,19 @@ pub fn link_actions(spec: &ActionsSpec, handlers: impl Iterator<Item = &'static
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: common.mutates_buffer,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.short_desc.clone().unwrap_or_else(|| common.description.clone()),
 				},
 				payload: ActionPayload {
 					handler: handler.handler,
 					bindings: Arc::from(bindings.into_boxed_slice()),
+					repeatable: meta.repeatable,
+					keyboard_icon: meta.keyboard_icon.clone(),
 				},
 			}
 		},
@@ -22,6 +22,17 @@ pub struct ActionDef {
 	pub handler: ActionHandler,
 	/// Keybindings associated with the action.
 	pub bindings: &'static [KeyBindingDef],
+	/// Whether `repeat_last` may replay this action.
+	pub repeatable: bool,
+	/// Compact glyph (e.g. `"⌫"`) labelling the action on soft-keyboard overlays.
+	pub keyboard_icon: Option<&'static str>,
+}
+
+impl ActionDef {
+	/// Returns the keyboard icon, falling back to the action name.
+	pub fn icon_or_name(&self) -> &'static str {
+		self.keyboard_icon.unwrap_or(self.meta.name)
+	}
 }
 
 impl BuildEntry<ActionEntry> for ActionDef {
@@ -30,10 +41,12 @@ impl BuildEntry<ActionEntry> for ActionDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -41,7 +54,9 @@ impl BuildEntry<ActionEntry> for ActionDef {
 		self.short_desc
 	}
 
-	fn collect_payload_strings<'b>(&'b self, _collector: &mut crate::core::index::StringCollector<'_, 'b>) {}
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.keyboard_icon);
+	}
 
 	fn build(&self, ctx: &mut dyn crate::core::index::BuildCtx, key_pool: &mut Vec<Symbol>) -> ActionEntry {
 		let meta = crate::core::index::meta_build::build_meta(ctx, key_pool, self.meta_ref(), []);
@@ -51,6 +66,8 @@ impl BuildEntry<ActionEntry> for ActionDef {
 			short_desc: ctx.intern(self.short_desc),
 			handler: self.handler,
 			bindings: Arc::from(self.bindings),
+			repeatable: self.repeatable,
+			keyboard_icon: self.keyboard_icon.map(|icon| ctx.intern(icon)),
 		}
 	}
 }
@@ -15,6 +15,10 @@ pub struct ActionEntry {
 	pub handler: ActionHandler,
 	/// Keybindings associated with the action.
 	pub bindings: Arc<[KeyBindingDef]>,
+	/// Whether `repeat_last` may replay this action.
+	pub repeatable: bool,
+	/// Soft-keyboard glyph (interned), if the action has one.
+	pub keyboard_icon: Option<Symbol>,
 }
 
 crate::impl_registry_entry!(ActionEntry);
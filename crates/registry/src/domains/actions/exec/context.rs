@@ -5,6 +5,8 @@
 use ropey::RopeSlice;
 use xeno_primitives::{CharIdx, Selection};
 
+use crate::motions::SyntaxTree;
+
 /// Context passed to action handlers.
 ///
 /// Provides read-only access to buffer state needed for computing action results.
@@ -15,6 +17,8 @@ use xeno_primitives::{CharIdx, Selection};
 pub struct ActionContext<'a> {
 	/// Document text (read-only slice).
 	pub text: RopeSlice<'a>,
+	/// Parsed syntax tree of the document, when one is available.
+	pub syntax: Option<&'a dyn SyntaxTree>,
 	/// Current cursor position (char index).
 	pub cursor: CharIdx,
 	/// Current selection state.
@@ -29,6 +33,12 @@ pub struct ActionContext<'a> {
 	pub args: ActionArgs,
 }
 
+/// Action invocation captured while recording a macro.
+///
+/// Same shape as [`LastAction`]: the action is stored by ID with its resolved
+/// arguments, so replay does not go back through the keymap.
+pub type RecordedInvocation = LastAction;
+
 /// Additional arguments for actions requiring extra input.
 ///
 /// Used by pending actions that wait for user input (e.g., `f` waits for
@@ -40,3 +50,17 @@ pub struct ActionArgs {
 	/// String argument (e.g., for search patterns).
 	pub string: Option<String>,
 }
+
+/// Most recent repeatable action, replayed by `repeat_last`.
+///
+/// Captures the invocation arguments so the replay matches the original:
+/// count, extend, register, and any pending-action input.
+#[derive(Debug, Clone)]
+pub struct LastAction {
+	/// Action that ran.
+	pub id: crate::core::ActionId,
+	pub count: usize,
+	pub extend: bool,
+	pub register: Option<char>,
+	pub args: ActionArgs,
+}
@@ -51,6 +51,10 @@ pub struct EditOp {
 	pub transform: TextTransform,
 	/// Effects to apply after the edit.
 	pub post: Vec<PostEffect>,
+	/// Times to apply the selection op and transform; `0` applies once.
+	pub count: usize,
+	/// Register that [`PreEffect::Yank`] writes to; `None` is the default register.
+	pub register: Option<char>,
 }
 
 impl EditOp {
@@ -88,6 +92,20 @@ impl EditOp {
 		self
 	}
 
+	/// Sets how many times the selection op and transform are applied.
+	#[inline]
+	pub fn with_count(mut self, count: usize) -> Self {
+		self.count = count;
+		self
+	}
+
+	/// Sets the register yanked text goes to.
+	#[inline]
+	pub fn with_register(mut self, register: Option<char>) -> Self {
+		self.register = register;
+		self
+	}
+
 	/// Returns true if this operation modifies text.
 	#[inline]
 	pub fn modifies_text(&self) -> bool {
@@ -144,6 +162,88 @@ impl EditOp {
 		};
 		EditOrigin::EditOp { id }
 	}
+
+	/// Describes this operation in one line for undo history display, e.g.
+	/// `Yank selection; Delete selection; Enter insert mode`.
+	///
+	/// Operations carry no buffer positions, so the description names the
+	/// selection op instead of a line and column.
+	pub fn explain(&self) -> String {
+		self.explain_steps().join("; ")
+	}
+
+	/// Describes the pre-effects, the transform and the post-effects of this
+	/// operation, one step each, in execution order.
+	fn explain_steps(&self) -> Vec<String> {
+		let mut steps = Vec::new();
+		for pre in &self.pre {
+			match pre {
+				PreEffect::Yank => steps.push(match self.register {
+					Some(register) => format!("Yank {} to register '{register}'", self.selection.target()),
+					None => format!("Yank {}", self.selection.target()),
+				}),
+			}
+		}
+
+		if let Some(mut transform) = self.explain_transform() {
+			if self.count > 1 {
+				transform.push_str(&format!(" ({} times)", self.count));
+			}
+			steps.push(transform);
+		}
+
+		for post in &self.post {
+			match post {
+				PostEffect::SetMode(mode) => steps.push(format!("Enter {} mode", mode.name())),
+				PostEffect::MoveCursor(CursorAdjust::Up(lines)) => steps.push(format!("Move cursor up {lines} line{}", plural(*lines))),
+				PostEffect::MoveCursor(CursorAdjust::ToStart) => steps.push("Move cursor to start of edit".to_string()),
+				PostEffect::MoveCursor(CursorAdjust::ToEnd) => steps.push("Move cursor to end of edit".to_string()),
+				PostEffect::MoveCursor(CursorAdjust::Stay) => {}
+			}
+		}
+
+		if steps.is_empty() {
+			steps.push("No change".to_string());
+		}
+		steps
+	}
+
+	/// Describes the text transform, or `None` for [`TextTransform::None`].
+	fn explain_transform(&self) -> Option<String> {
+		let target = self.selection.target();
+		let explanation = match &self.transform {
+			TextTransform::None => return None,
+			TextTransform::Delete => format!("Delete {target}"),
+			TextTransform::Replace(text) => format!("Replace {target} with {}", quote(text)),
+			TextTransform::Insert(text) => format!("Insert {} {}", quote(text), self.selection.location()),
+			TextTransform::InsertNewlineWithIndent => format!("Insert indented newline {}", self.selection.location()),
+			TextTransform::MapChars(CharMapKind::ToLowerCase) => format!("Lowercase {target}"),
+			TextTransform::MapChars(CharMapKind::ToUpperCase) => format!("Uppercase {target}"),
+			TextTransform::MapChars(CharMapKind::SwapCase) => format!("Swap case of {target}"),
+			TextTransform::ReplaceEachChar(ch) => format!("Replace each char of {target} with '{}'", ch.escape_debug()),
+			TextTransform::Undo => "Undo last change".to_string(),
+			TextTransform::Redo => "Redo last undone change".to_string(),
+			TextTransform::Deindent { max_spaces } => format!("Deindent {target} by up to {max_spaces} space{}", plural(*max_spaces)),
+		};
+		Some(explanation)
+	}
+}
+
+/// Longest inserted or replacement text quoted in full by [`EditOp::explain`].
+const EXPLAIN_TEXT_LIMIT: usize = 24;
+
+/// Quotes `text` for an explanation, escaping control characters and
+/// truncating it past [`EXPLAIN_TEXT_LIMIT`] chars.
+fn quote(text: &str) -> String {
+	let mut quoted: String = text.chars().take(EXPLAIN_TEXT_LIMIT).flat_map(char::escape_debug).collect();
+	if text.chars().nth(EXPLAIN_TEXT_LIMIT).is_some() {
+		quoted.push('…');
+	}
+	format!("'{quoted}'")
+}
+
+fn plural(count: usize) -> &'static str {
+	if count == 1 { "" } else { "s" }
 }
 
 /// A compiled edit plan ready for execution.
@@ -162,6 +262,33 @@ pub struct EditPlan {
 	pub origin: EditOrigin,
 }
 
+impl EditPlan {
+	/// Returns how many times the selection op and transform run.
+	#[inline]
+	pub fn passes(&self) -> usize {
+		self.op.count.max(1)
+	}
+
+	/// Returns the undo policy for pass `pass` (0-based).
+	///
+	/// Recorded passes after the first merge into its undo group, so a counted
+	/// edit undoes in one step.
+	pub fn undo_policy_for_pass(&self, pass: usize) -> UndoPolicy {
+		match self.undo_policy {
+			UndoPolicy::Record if pass > 0 => UndoPolicy::MergeWithCurrentGroup,
+			policy => policy,
+		}
+	}
+
+	/// Describes each step of the plan in execution order: pre-effects, the
+	/// transform (with its pass count), then post-effects.
+	///
+	/// [`EditOp::explain`] joins these into one line.
+	pub fn explain(&self) -> Vec<String> {
+		self.op.explain_steps()
+	}
+}
+
 /// Effects to apply before the main edit transformation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreEffect {
@@ -215,6 +342,41 @@ pub enum SelectionOp {
 	PositionAfterCursor,
 }
 
+impl SelectionOp {
+	/// Names the text this op selects, for [`EditOp::explain`].
+	fn target(&self) -> String {
+		match self {
+			Self::None | Self::PositionAfterCursor => "selection".to_string(),
+			Self::Extend { direction, count } => {
+				let direction = match direction {
+					Direction::Forward => "forward",
+					Direction::Backward => "backward",
+				};
+				format!("{count} char{} {direction}", plural(*count))
+			}
+			Self::ToLineStart => "line start".to_string(),
+			Self::ToLineEnd => "line end".to_string(),
+			Self::ExpandToFullLines => "selected lines".to_string(),
+			Self::SelectCharBefore => "char before cursor".to_string(),
+			Self::SelectCharAfter => "char after cursor".to_string(),
+			Self::SelectWordBefore => "word before cursor".to_string(),
+			Self::SelectWordAfter => "word after cursor".to_string(),
+			Self::SelectToNextLineStart => "line break".to_string(),
+		}
+	}
+
+	/// Names where inserted text goes, for [`EditOp::explain`].
+	fn location(&self) -> String {
+		match self {
+			Self::None => "at cursor".to_string(),
+			Self::ToLineStart => "at line start".to_string(),
+			Self::ToLineEnd => "at line end".to_string(),
+			Self::PositionAfterCursor => "after cursor".to_string(),
+			other => format!("over {}", other.target()),
+		}
+	}
+}
+
 /// Text transformation applied to selection.
 ///
 /// These are the primitive text operations. Complex operations are built
@@ -412,6 +574,11 @@ pub fn delete_word_forward() -> EditOp {
 	EditOp::new().with_selection(SelectionOp::SelectWordAfter).with_transform(TextTransform::Delete)
 }
 
+/// Creates a single-character insertion at each cursor.
+pub fn insert_char(ch: char) -> EditOp {
+	EditOp::new().with_transform(TextTransform::Insert(ch.to_string()))
+}
+
 /// Creates a newline insertion with smart indentation.
 pub fn insert_newline() -> EditOp {
 	EditOp::new().with_transform(TextTransform::InsertNewlineWithIndent)
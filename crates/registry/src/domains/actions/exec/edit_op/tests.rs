@@ -42,3 +42,64 @@ fn test_char_map_swapcase() {
 	assert_eq!(lower, "a");
 	assert_eq!(upper, "A");
 }
+
+#[test]
+fn test_counted_plan_merges_later_passes_into_one_undo_group() {
+	let plan = join_lines().with_count(3).compile();
+	assert_eq!(plan.passes(), 3);
+	assert_eq!(plan.undo_policy_for_pass(0), UndoPolicy::Record);
+	assert_eq!(plan.undo_policy_for_pass(2), UndoPolicy::MergeWithCurrentGroup);
+
+	let uncounted = undo().compile();
+	assert_eq!(uncounted.passes(), 1);
+	assert_eq!(uncounted.undo_policy_for_pass(1), UndoPolicy::NoUndo);
+}
+
+#[test]
+fn test_explain_names_each_transform() {
+	let cases = [
+		(delete_back(), "Delete char before cursor"),
+		(delete_forward(), "Delete char after cursor"),
+		(delete_word_back(), "Delete word before cursor"),
+		(
+			delete(false).with_selection(SelectionOp::Extend {
+				direction: Direction::Forward,
+				count: 5,
+			}),
+			"Delete 5 chars forward",
+		),
+		(join_lines(), "Replace line break with ' '"),
+		(indent(), "Insert '    ' at line start"),
+		(add_line_below(), "Insert '\\n' at line end"),
+		(insert_newline(), "Insert indented newline at cursor"),
+		(case_convert(CharMapKind::ToLowerCase), "Lowercase selection"),
+		(case_convert(CharMapKind::ToUpperCase), "Uppercase selection"),
+		(case_convert(CharMapKind::SwapCase), "Swap case of selection"),
+		(replace_with_char('x'), "Replace each char of selection with 'x'"),
+		(undo(), "Undo last change"),
+		(redo(), "Redo last undone change"),
+		(deindent(), "Deindent selection by up to 4 spaces"),
+		(EditOp::new(), "No change"),
+	];
+	for (op, expected) in cases {
+		assert_eq!(op.explain(), expected, "{op:?}");
+	}
+}
+
+#[test]
+fn test_explain_includes_effects_count_and_register() {
+	let op = change(true).with_register(Some('a')).with_count(3);
+	assert_eq!(op.explain(), "Yank selection to register 'a'; Delete selection (3 times); Enter insert mode");
+
+	assert_eq!(
+		open_above().compile().explain(),
+		["Insert indented newline at line start", "Move cursor up 1 line", "Enter insert mode"]
+	);
+	assert_eq!(yank().compile().explain(), ["Yank selection"]);
+}
+
+#[test]
+fn test_explain_truncates_long_text() {
+	let op = EditOp::new().with_transform(TextTransform::Insert("a".repeat(40)));
+	assert_eq!(op.explain(), format!("Insert '{}…' at cursor", "a".repeat(24)));
+}
@@ -117,6 +117,9 @@ pub trait NotificationAccess {
 
 	/// Clears all visible notifications.
 	fn clear_notifications(&mut self);
+
+	/// Flips do-not-disturb, which suppresses notification popups while on.
+	fn toggle_do_not_disturb(&mut self);
 }
 
 /// Search operations (optional).
@@ -156,6 +159,18 @@ pub trait UndoAccess {
 	fn can_undo(&self) -> bool;
 	/// Returns true if redo is available.
 	fn can_redo(&self) -> bool;
+	/// Starts grouping text edits into one undo step.
+	///
+	/// Calls nest; only the outermost [`Self::end_batch`] closes the group.
+	fn begin_batch(&mut self);
+	/// Ends the group started by [`Self::begin_batch`].
+	///
+	/// Returns false when this call closes the outermost batch and one of its
+	/// edits was rejected; the batch's applied edits have then been rolled back
+	/// and the view, mode and registers restored to their pre-batch state.
+	fn end_batch(&mut self) -> bool;
+	/// Returns true when an edit in the open batch has been rejected.
+	fn batch_rejected(&self) -> bool;
 }
 
 /// Jump list operations.
@@ -173,16 +188,21 @@ pub trait JumpAccess {
 
 /// Macro recording/playback.
 ///
-/// Enables recording sequences of key events and replaying them.
+/// Macros store resolved invocations ([`RecordedInvocation`]) rather than raw
+/// keys, so playback is unaffected by keymap changes made after recording.
+///
+/// [`RecordedInvocation`]: crate::actions::RecordedInvocation
 pub trait MacroAccess {
-	/// Starts recording a macro.
-	fn record(&mut self);
+	/// Starts recording a macro into `register`.
+	fn record(&mut self, register: char);
 	/// Stops recording the current macro.
 	fn stop_recording(&mut self);
-	/// Plays the recorded macro.
-	fn play(&mut self);
+	/// Plays the macro stored in `register` `count` times.
+	fn play(&mut self, register: char, count: usize) -> super::HandleOutcome;
 	/// Returns true if currently recording a macro.
 	fn is_recording(&self) -> bool;
+	/// Returns the register of the most recently recorded or played macro.
+	fn last_register(&self) -> Option<char>;
 }
 
 /// Edit operations (optional).
@@ -199,10 +219,12 @@ pub trait EditAccess {
 	/// composable and processed by a single executor function.
 	fn execute_edit_op(&mut self, op: &crate::actions::edit_op::EditOp);
 
-	/// Pastes from the yank register.
+	/// Pastes from a yank register.
 	///
 	/// * `before`: If true, pastes before cursor; otherwise after
-	fn paste(&mut self, before: bool);
+	/// * `count`: Copies of the register content to paste
+	/// * `register`: Register to paste from; `None` is the default register
+	fn paste(&mut self, before: bool, count: usize, register: Option<char>);
 }
 
 /// Visual cursor motion (optional).
@@ -217,6 +239,12 @@ pub trait MotionAccess {
 	/// * `count`: Number of visual lines to move
 	/// * `extend`: If true, extends selection rather than moving
 	fn move_visual_vertical(&mut self, direction: Direction, count: usize, extend: bool);
+
+	/// Adds a cursor `count` lines past the outermost selection, keeping the
+	/// primary cursor's display column.
+	///
+	/// * `direction`: Forward for below, Backward for above
+	fn add_cursor_vertical(&mut self, direction: Direction, count: usize);
 }
 
 /// Motion dispatch via ID resolution.
@@ -263,6 +291,18 @@ pub trait ThemeAccess {
 	///
 	/// [`CommandError::Failed`]: crate::actions::CommandError::Failed
 	fn set_theme(&mut self, name: &str) -> Result<(), crate::actions::CommandError>;
+
+	/// Overrides the theme of one buffer, leaving the editor theme unchanged.
+	///
+	/// # Errors
+	///
+	/// Returns [`CommandError::Failed`] if the theme is not found.
+	///
+	/// [`CommandError::Failed`]: crate::actions::CommandError::Failed
+	fn set_buffer_theme(&mut self, buffer: crate::hooks::ViewId, name: &str) -> Result<(), crate::actions::CommandError>;
+
+	/// Removes the theme override of `buffer`, if any.
+	fn clear_buffer_theme(&mut self, buffer: crate::hooks::ViewId);
 }
 
 /// Errors that can occur during split operations.
@@ -64,6 +64,10 @@ impl<'a> NotificationAccess for EditorContext<'a> {
 	fn clear_notifications(&mut self) {
 		self.inner.clear_notifications();
 	}
+
+	fn toggle_do_not_disturb(&mut self) {
+		self.inner.toggle_do_not_disturb();
+	}
 }
 
 impl<'a> EditorContext<'a> {
@@ -192,6 +196,11 @@ impl<'a> EditorContext<'a> {
 		self.inner.is_readonly()
 	}
 
+	/// Replays the most recent repeatable action.
+	pub fn repeat_last_action(&mut self) -> HandleOutcome {
+		self.inner.repeat_last_action()
+	}
+
 	/// Emits a type-safe notification.
 	pub fn emit(&mut self, notification: impl Into<crate::notifications::Notification>) {
 		self.inner.emit(notification.into());
@@ -272,4 +281,11 @@ pub trait EditorCapabilities: CursorAccess + SelectionAccess + ModeAccess + Noti
 	fn is_readonly(&self) -> bool {
 		false
 	}
+
+	/// Replays the most recent repeatable action with its original arguments.
+	///
+	/// Editors without action history treat this as a no-op.
+	fn repeat_last_action(&mut self) -> HandleOutcome {
+		HandleOutcome::Handled
+	}
 }
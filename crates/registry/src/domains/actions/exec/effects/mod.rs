@@ -236,10 +236,16 @@ impl ActionEffects {
 		Self::from_effect(ViewEffect::VisualMove { direction, count, extend }.into())
 	}
 
-	/// Pastes from yank register.
+	/// Adds a cursor `count` lines below or above the outermost selection.
 	#[inline]
-	pub fn paste(before: bool) -> Self {
-		Self::from_effect(EditEffect::Paste { before }.into())
+	pub fn add_cursor_vertical(direction: Direction, count: usize) -> Self {
+		Self::from_effect(ViewEffect::AddCursorVertical { direction, count }.into())
+	}
+
+	/// Pastes `count` copies of `register` (the default register if `None`).
+	#[inline]
+	pub fn paste(before: bool, count: usize, register: Option<char>) -> Self {
+		Self::from_effect(EditEffect::Paste { before, count, register }.into())
 	}
 
 	/// Enters pending state for multi-key action.
@@ -349,6 +355,14 @@ pub enum ViewEffect {
 		extend: bool,
 	},
 
+	/// Add a cursor on a line below or above the outermost selection.
+	AddCursorVertical {
+		/// Direction to add in (Forward = below, Backward = above).
+		direction: Direction,
+		/// Number of lines past the outermost selection.
+		count: usize,
+	},
+
 	/// Search in direction.
 	Search {
 		/// Direction to search.
@@ -380,10 +394,14 @@ pub enum EditEffect {
 	/// are composable and processed by a single executor function.
 	EditOp(crate::actions::edit_op::EditOp),
 
-	/// Paste from yank register.
+	/// Paste from a yank register.
 	Paste {
 		/// Whether to paste before cursor (vs after).
 		before: bool,
+		/// Copies of the register content to paste.
+		count: usize,
+		/// Register to paste from; `None` is the default register.
+		register: Option<char>,
 	},
 }
 
@@ -407,6 +425,9 @@ pub enum UiEffect {
 
 	/// Force a redraw.
 	ForceRedraw,
+
+	/// Toggle do-not-disturb, which suppresses notification popups.
+	ToggleDoNotDisturb,
 }
 
 /// Application-level effects (mode, focus, lifecycle).
@@ -447,6 +468,23 @@ pub enum AppEffect {
 
 	/// Queue a deferred invocation for runtime pump execution.
 	QueueInvocation(DeferredInvocationRequest),
+
+	/// Replay the most recent repeatable action with its original arguments.
+	RepeatLastAction,
+
+	/// Start recording a macro into `register`, or stop the recording in progress.
+	ToggleMacroRecording {
+		/// Register to record into when starting.
+		register: char,
+	},
+
+	/// Replay a recorded macro.
+	ReplayMacro {
+		/// Register to replay; `None` replays the last recorded or played macro.
+		register: Option<char>,
+		/// Number of times to replay the macro.
+		count: usize,
+	},
 }
 
 /// Primitive state mutation.
@@ -30,8 +30,13 @@ fn test_nested_view_effect() {
 
 #[test]
 fn test_nested_edit_effect() {
-	let effect: Effect = EditEffect::Paste { before: true }.into();
-	assert!(matches!(effect, Effect::Edit(EditEffect::Paste { before: true })));
+	let effect: Effect = EditEffect::Paste {
+		before: true,
+		count: 1,
+		register: None,
+	}
+	.into();
+	assert!(matches!(effect, Effect::Edit(EditEffect::Paste { before: true, .. })));
 }
 
 #[test]
@@ -41,6 +41,18 @@ pub enum BindingMode {
 	Space,
 }
 
+impl BindingMode {
+	/// Returns the lowercase mode name used in keymap specs.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Normal => "normal",
+			Self::Insert => "insert",
+			Self::Match => "match",
+			Self::Space => "space",
+		}
+	}
+}
+
 impl From<Mode> for BindingMode {
 	fn from(mode: Mode) -> Self {
 		match mode {
@@ -18,16 +18,24 @@ pub enum ScreenPosition {
 
 /// Result of executing an action.
 ///
-/// All actions return `ActionResult::Effects(...)` containing composable
-/// primitive effects. The `apply_effects` function processes these effects
-/// to mutate editor state.
+/// Actions return `ActionResult::Effects(...)` containing composable
+/// primitive effects, or a [`ActionResult::Batch`] of such results. The
+/// editor's `dispatch_result` processes these to mutate editor state.
 #[derive(Debug, Clone)]
 pub enum ActionResult {
 	/// Apply a set of composable effects.
 	///
-	/// This is the sole variant - all editor state changes are expressed
-	/// as compositions of primitive [`Effect`](crate::actions::Effect) values.
+	/// All editor state changes are expressed as compositions of primitive
+	/// [`Effect`](crate::actions::Effect) values.
 	Effects(ActionEffects),
+	/// Apply each result in order, as one all-or-nothing unit.
+	///
+	/// Text edits made by the elements share a single undo step. If the buffer
+	/// rejects any of them, later elements are skipped, the edits already
+	/// applied by the batch are rolled back and nothing reaches the undo
+	/// history. Cursor, selection, mode and register changes made before the
+	/// rejection are reverted too, and a requested quit is dropped.
+	Batch(Vec<ActionResult>),
 }
 
 impl ActionResult {
@@ -37,6 +45,13 @@ impl ActionResult {
 	pub fn variant_name(&self) -> &'static str {
 		match self {
 			ActionResult::Effects(..) => "Effects",
+			ActionResult::Batch(..) => "Batch",
 		}
 	}
 }
+
+impl From<ActionEffects> for ActionResult {
+	fn from(effects: ActionEffects) -> Self {
+		ActionResult::Effects(effects)
+	}
+}
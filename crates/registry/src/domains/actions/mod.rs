@@ -37,7 +37,7 @@ pub mod spec;
 mod domain;
 #[path = "exec/editor_ctx/mod.rs"]
 pub mod editor_ctx;
-pub use context::{ActionArgs, ActionContext};
+pub use context::{ActionArgs, ActionContext, LastAction, RecordedInvocation};
 pub use def::{ActionDef, ActionHandler};
 pub use domain::Actions;
 pub use editor_ctx::{
@@ -105,7 +105,16 @@ pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
 /// Finds an action by name, key, or id.
 #[cfg(feature = "minimal")]
 pub fn find_action(name: &str) -> Option<ActionRef> {
-	ACTIONS.get(name)
+	crate::db::index::find_action(name)
+}
+
+/// Returns the keyboard icon of `action`, falling back to its name.
+#[cfg(feature = "minimal")]
+pub fn icon_or_name(action: &ActionRef) -> &str {
+	match action.keyboard_icon {
+		Some(icon) => action.resolve(icon),
+		None => action.name_str(),
+	}
 }
 
 /// Returns all registered actions (builtins + runtime), sorted by name.
@@ -1,12 +1,18 @@
 use std::path::PathBuf;
+use std::sync::LazyLock;
 
+use regex::Regex;
 use xeno_primitives::BoxFutureLocal;
 
 use crate::command_handler;
 use crate::commands::{CommandContext, CommandError, CommandOutcome};
 
-command_handler!(edit, handler: cmd_edit);
+/// Matches a `path:line` or `path:line:column` target (both 1-based).
+static POSITION_SUFFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?<path>.+?):(?<line>\d+)(?::(?<column>\d+))?$").expect("valid regex"));
 
+command_handler!(edit, handler: cmd_edit, user_data: &POSITION_SUFFIX);
+
+/// Opens `args[0]`, jumping to a trailing `:line[:column]` if one is given.
 fn cmd_edit<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
 		if ctx.args.is_empty() {
@@ -14,8 +20,39 @@ fn cmd_edit<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<Co
 				.queue_invocation(crate::actions::DeferredInvocationRequest::command("files".to_string(), Vec::new()));
 			return Ok(CommandOutcome::Ok);
 		}
-		let path = PathBuf::from(ctx.args[0]);
-		ctx.editor.goto_file(path, 0, 0).await?;
+		let (path, line, column) = parse_target(ctx.args[0], ctx.user_data::<LazyLock<Regex>>().map(LazyLock::force));
+		ctx.editor.goto_file(PathBuf::from(path), line, column).await?;
 		Ok(CommandOutcome::Ok)
 	})
 }
+
+/// Splits `target` into a path and a 0-based line and column.
+fn parse_target<'t>(target: &'t str, suffix: Option<&Regex>) -> (&'t str, usize, usize) {
+	let Some(caps) = suffix.and_then(|re| re.captures(target)) else {
+		return (target, 0, 0);
+	};
+	let number = |name| caps.name(name).and_then(|m| m.as_str().parse::<usize>().ok()).unwrap_or(1).saturating_sub(1);
+	(caps.name("path").map_or(target, |m| m.as_str()), number("line"), number("column"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::commands::builtins::test_fixtures::{MockEditor, run};
+
+	#[test]
+	fn edit_downcasts_its_regex_and_jumps_to_position() {
+		let user_data = CMD_HANDLER_edit.user_data;
+		let mut editor = MockEditor::default();
+		run(cmd_edit, &mut editor, &["src/main.rs:12:4"], user_data).expect("edit succeeds");
+		run(cmd_edit, &mut editor, &["notes.md:7"], user_data).expect("edit succeeds");
+		assert_eq!(editor.opened, [(PathBuf::from("src/main.rs"), 11, 3), (PathBuf::from("notes.md"), 6, 0)]);
+	}
+
+	#[test]
+	fn edit_without_user_data_opens_path_verbatim() {
+		let mut editor = MockEditor::default();
+		run(cmd_edit, &mut editor, &["src/main.rs:12"], None).expect("edit succeeds");
+		assert_eq!(editor.opened, [(PathBuf::from("src/main.rs:12"), 0, 0)]);
+	}
+}
@@ -0,0 +1,45 @@
+use xeno_primitives::BoxFutureLocal;
+
+use crate::command_handler;
+use crate::commands::{CommandContext, CommandError, CommandOutcome};
+use crate::notifications::keys;
+
+command_handler!(history, handler: cmd_history);
+
+/// Entries listed when `:history` is given no count.
+const DEFAULT_COUNT: usize = 20;
+
+fn cmd_history<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let count = match ctx.parsed.int(0) {
+			Some(count) => usize::try_from(count).map_err(|_| CommandError::InvalidArgument(format!("count must not be negative: {count}")))?,
+			None => DEFAULT_COUNT,
+		};
+
+		let recent = ctx.editor.recent_commands(count);
+		if recent.is_empty() {
+			ctx.emit(keys::info("command history is empty"));
+			return Ok(CommandOutcome::Ok);
+		}
+
+		let lines: Vec<String> = recent.iter().rev().enumerate().map(|(idx, entry)| format!("{:>4}  {entry}", idx + 1)).collect();
+		ctx.editor.open_info_popup(&lines.join("\n"), None);
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::commands::builtins::test_fixtures::{MockEditor, run_command};
+
+	#[test]
+	fn history_lists_recent_entries_oldest_first() {
+		let mut editor = MockEditor {
+			history: vec!["q".to_string(), "set wrap".to_string(), "w".to_string()],
+			..MockEditor::default()
+		};
+		run_command("history", &mut editor, &["2"]).expect("history succeeds");
+		assert_eq!(editor.popups, ["   1  set wrap\n   2  w"]);
+		assert!(run_command("history", &mut editor, &["many"]).is_err());
+	}
+}
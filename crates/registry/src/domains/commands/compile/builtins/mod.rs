@@ -1,13 +1,18 @@
 mod buffer;
 mod edit;
 mod help;
+mod history;
 mod quit;
 mod registry;
 mod set;
 mod snippet;
+mod tasks;
 mod theme;
 mod write;
 
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+
 use crate::db::builder::RegistryDbBuilder;
 
 pub fn register_builtins(builder: &mut RegistryDbBuilder) {
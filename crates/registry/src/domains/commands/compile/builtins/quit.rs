@@ -1,5 +1,7 @@
+use xeno_primitives::BoxFutureLocal;
+
 use crate::command_handler;
-use crate::commands::CommandOutcome;
+use crate::commands::{CommandContext, CommandError, CommandOutcome};
 
 command_handler!(quit, handler: |_ctx| {
 	Box::pin(async move {
@@ -7,8 +9,50 @@ command_handler!(quit, handler: |_ctx| {
 	})
 });
 
-command_handler!(force_quit, handler: |_ctx| {
+command_handler!(force_quit, handler: cmd_force_quit);
+
+/// Quits without saving, confirming first when the current buffer has unsaved changes.
+fn cmd_force_quit<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
+		if ctx.editor.is_modified() && !ctx.confirm("Discard unsaved changes and quit?").await {
+			return Ok(CommandOutcome::Ok);
+		}
 		Ok(CommandOutcome::ForceQuit)
 	})
-});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::commands::ConfirmationResult;
+	use crate::commands::builtins::test_fixtures::{MockEditor, run};
+
+	fn force_quit(editor: &mut MockEditor) -> CommandOutcome {
+		run(cmd_force_quit, editor, &[], None).expect("force_quit does not fail")
+	}
+
+	#[test]
+	fn force_quit_returns_early_when_confirmation_declined() {
+		let mut editor = MockEditor {
+			modified: true,
+			answer: ConfirmationResult::Decline,
+			..MockEditor::default()
+		};
+		assert_eq!(force_quit(&mut editor), CommandOutcome::Ok);
+		assert_eq!(editor.prompts.len(), 1);
+
+		editor.answer = ConfirmationResult::Accept;
+		assert_eq!(force_quit(&mut editor), CommandOutcome::ForceQuit);
+	}
+
+	#[test]
+	fn force_quit_skips_confirmation_for_unmodified_buffer() {
+		let mut editor = MockEditor {
+			modified: false,
+			answer: ConfirmationResult::Decline,
+			..MockEditor::default()
+		};
+		assert_eq!(force_quit(&mut editor), CommandOutcome::ForceQuit);
+		assert!(editor.prompts.is_empty());
+	}
+}
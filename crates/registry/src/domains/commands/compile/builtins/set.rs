@@ -1,7 +1,7 @@
 use xeno_primitives::BoxFutureLocal;
 
 use crate::command_handler;
-use crate::commands::{CommandContext, CommandError, CommandOutcome};
+use crate::commands::{ArgValue, CommandContext, CommandError, CommandOutcome};
 use crate::notifications::keys;
 
 command_handler!(set, handler: cmd_set);
@@ -10,11 +10,19 @@ command_handler!(setlocal, handler: cmd_setlocal);
 
 fn cmd_set<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
-		if ctx.args.is_empty() {
+		let parsed = ctx.parsed;
+		let Some(first) = parsed.str(0) else {
+			return Ok(CommandOutcome::Ok);
+		};
+		let values: Vec<&str> = parsed.rest().iter().filter_map(ArgValue::as_str).collect();
+
+		if let Some(key) = query_key(first, &values) {
+			let description = ctx.editor.describe_option(key)?;
+			ctx.emit(keys::info(format!("{key}={description}")));
 			return Ok(CommandOutcome::Ok);
 		}
 
-		let (key, value) = parse_set_args(ctx.args)?;
+		let (key, value) = parse_set_args(first, &values);
 		ctx.editor.set_option(&key, &value)?;
 		ctx.emit(keys::option_set(&key, &value));
 		Ok(CommandOutcome::Ok)
@@ -23,31 +31,71 @@ fn cmd_set<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<Com
 
 fn cmd_setlocal<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
-		if ctx.args.is_empty() {
+		let parsed = ctx.parsed;
+		let Some(first) = parsed.str(0) else {
+			return Ok(CommandOutcome::Ok);
+		};
+		let values: Vec<&str> = parsed.rest().iter().filter_map(ArgValue::as_str).collect();
+
+		if let Some(key) = query_key(first, &values) {
+			let description = ctx.editor.describe_option(key)?;
+			ctx.emit(keys::info(format!("{key}={description}")));
 			return Ok(CommandOutcome::Ok);
 		}
 
-		let (key, value) = parse_set_args(ctx.args)?;
+		let (key, value) = parse_set_args(first, &values);
 		ctx.editor.set_local_option(&key, &value)?;
 		ctx.emit(keys::option_set(&key, &value));
 		Ok(CommandOutcome::Ok)
 	})
 }
 
-fn parse_set_args(args: &[&str]) -> Result<(String, String), CommandError> {
-	let first = args[0];
+/// Returns the option key of a `:set key?` query.
+fn query_key<'a>(first: &'a str, values: &[&str]) -> Option<&'a str> {
+	first.strip_suffix('?').filter(|key| !key.is_empty() && values.is_empty())
+}
 
+fn parse_set_args(first: &str, values: &[&str]) -> (String, String) {
 	if let Some((key, value)) = first.split_once('=') {
-		return Ok((key.to_string(), value.to_string()));
+		return (key.to_string(), value.to_string());
 	}
 
-	if args.len() >= 2 {
-		return Ok((first.to_string(), args[1..].join(" ")));
+	if !values.is_empty() {
+		return (first.to_string(), values.join(" "));
 	}
 
 	if let Some(opt) = first.strip_prefix("no") {
-		Ok((opt.to_string(), "false".to_string()))
+		(opt.to_string(), "false".to_string())
 	} else {
-		Ok((first.to_string(), "true".to_string()))
+		(first.to_string(), "true".to_string())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::commands::builtins::test_fixtures::{MockEditor, run_command};
+
+	#[test]
+	fn set_joins_variadic_values_and_splits_assignments() {
+		let mut editor = MockEditor::default();
+		run_command("set", &mut editor, &["statusline-format", "{mode}", "{file}"]).expect("set succeeds");
+		run_command("setlocal", &mut editor, &["tab-width=2"]).expect("setlocal succeeds");
+		run_command("set", &mut editor, &["nowrap"]).expect("set succeeds");
+		assert_eq!(
+			editor.options,
+			[
+				("statusline-format".to_string(), "{mode} {file}".to_string()),
+				("tab-width".to_string(), "2".to_string()),
+				("wrap".to_string(), "false".to_string()),
+			]
+		);
+	}
+
+	#[test]
+	fn set_query_does_not_assign() {
+		let mut editor = MockEditor::default();
+		run_command("set", &mut editor, &["tab-width?"]).expect("query succeeds");
+		run_command("set", &mut editor, &[]).expect("bare set is a no-op");
+		assert!(editor.options.is_empty());
 	}
 }
@@ -0,0 +1,95 @@
+use xeno_primitives::BoxFutureLocal;
+
+use crate::command_handler;
+use crate::commands::{CommandContext, CommandError, CommandOutcome, TaskId};
+use crate::notifications::keys;
+
+command_handler!(tasks, handler: cmd_tasks);
+command_handler!(task_cancel, handler: cmd_task_cancel);
+
+fn cmd_tasks<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let tasks = ctx.editor.background_tasks();
+		if tasks.is_empty() {
+			ctx.emit(keys::info("no background tasks running"));
+			return Ok(CommandOutcome::Ok);
+		}
+
+		let lines: Vec<String> = tasks
+			.iter()
+			.map(|task| {
+				let mut line = format!("{:>4}  {}", task.id, task.name);
+				if let Some(progress) = task.progress {
+					line.push_str(&format!(" ({progress})"));
+				}
+				if task.cancelling {
+					line.push_str(" [cancelling]");
+				}
+				line
+			})
+			.collect();
+		ctx.editor.open_info_popup(&lines.join("\n"), None);
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+fn cmd_task_cancel<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let raw = ctx.parsed.int(0).ok_or(CommandError::MissingArgument("id"))?;
+		let id = u64::try_from(raw)
+			.map(TaskId)
+			.map_err(|_| CommandError::InvalidArgument(format!("invalid task id: {raw}")))?;
+		if !ctx.editor.cancel_background(id) {
+			return Err(CommandError::InvalidArgument(format!("no running task with id {id}")));
+		}
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::future::Future;
+	use std::task::{Context, Waker};
+
+	use crate::commands::TaskHandle;
+	use crate::commands::builtins::test_fixtures::{MockEditor, run_command};
+	use crate::commands::tasks::TaskStatus;
+
+	fn poll_spawned(editor: &mut MockEditor) {
+		for future in &mut editor.spawned {
+			let _ = future.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+		}
+	}
+
+	#[test]
+	fn tasks_lists_progress_and_task_cancel_stops_the_task() {
+		let mut editor = MockEditor::default();
+		let id = {
+			let mut ctx = crate::commands::CommandContext {
+				editor: &mut editor,
+				args: &[],
+				parsed: &Default::default(),
+				count: 1,
+				register: None,
+				range: None,
+				user_data: None,
+			};
+			ctx.spawn_background("index workspace", |task: TaskHandle| async move {
+				task.set_progress(2, 5);
+				std::future::pending::<()>().await;
+				Ok(())
+			})
+		};
+		poll_spawned(&mut editor);
+
+		run_command("tasks", &mut editor, &[]).expect("tasks succeeds");
+		assert_eq!(editor.popups, [format!("{id:>4}  index workspace (2/5)")]);
+
+		assert!(run_command("task_cancel", &mut editor, &["99"]).is_err(), "unknown ids are rejected");
+		run_command("task_cancel", &mut editor, &[&id.to_string()]).expect("task_cancel succeeds");
+		poll_spawned(&mut editor);
+		let finished = editor.tasks.drain_finished();
+		assert_eq!(finished.len(), 1);
+		assert_eq!(finished[0].status, TaskStatus::Cancelled);
+	}
+}
@@ -0,0 +1,175 @@
+//! Shared test helpers for builtin command handlers.
+
+use std::any::Any;
+use std::path::PathBuf;
+use std::task::{Context, Poll, Waker};
+
+use xeno_primitives::{BoxFutureLocal, BoxFutureSend};
+
+use crate::commands::def::CommandHandler;
+use crate::commands::{
+	BackgroundTask, CommandContext, CommandEditorOps, CommandError, CommandOutcome, ConfirmationResult, ParsedArgs, TaskId, TaskInfo, TaskRegistry,
+};
+use crate::notifications::Notification;
+
+/// Command host that records file opens and answers every confirmation with a
+/// pre-seeded result.
+pub(crate) struct MockEditor {
+	pub modified: bool,
+	pub readonly: bool,
+	pub answer: ConfirmationResult,
+	pub prompts: Vec<String>,
+	/// Reported by `confirmation_pending`; no prompt sets it.
+	pub confirmation_pending: bool,
+	/// Number of `save` calls.
+	pub saves: usize,
+	/// Notifications passed to `emit`.
+	pub emitted: Vec<Notification>,
+	/// `goto_file` calls as `(path, line, column)`.
+	pub opened: Vec<(PathBuf, usize, usize)>,
+	/// `set_option` and `set_local_option` calls as `(key, value)`.
+	pub options: Vec<(String, String)>,
+	/// Last theme passed to `set_theme`.
+	pub theme: Option<String>,
+	/// Command-line history, oldest first.
+	pub history: Vec<String>,
+	/// Contents passed to `open_info_popup`.
+	pub popups: Vec<String>,
+	/// Background tasks; their futures wait in `spawned` for the test to poll.
+	pub tasks: TaskRegistry,
+	pub spawned: Vec<BoxFutureSend<'static, ()>>,
+}
+
+impl Default for MockEditor {
+	fn default() -> Self {
+		Self {
+			modified: false,
+			readonly: false,
+			answer: ConfirmationResult::Accept,
+			prompts: Vec::new(),
+			confirmation_pending: false,
+			saves: 0,
+			emitted: Vec::new(),
+			opened: Vec::new(),
+			options: Vec::new(),
+			theme: None,
+			history: Vec::new(),
+			popups: Vec::new(),
+			tasks: TaskRegistry::new(),
+			spawned: Vec::new(),
+		}
+	}
+}
+
+impl CommandEditorOps for MockEditor {
+	fn emit(&mut self, notification: Notification) {
+		self.emitted.push(notification);
+	}
+	fn clear_notifications(&mut self) {}
+	fn is_modified(&self) -> bool {
+		self.modified
+	}
+	fn is_readonly(&self) -> bool {
+		self.readonly
+	}
+	fn set_readonly(&mut self, _readonly: bool) {}
+	fn save(&mut self) -> BoxFutureLocal<'_, Result<(), CommandError>> {
+		self.saves += 1;
+		Box::pin(async { Ok(()) })
+	}
+	fn save_as(&mut self, _path: PathBuf) -> BoxFutureLocal<'_, Result<(), CommandError>> {
+		Box::pin(async { Ok(()) })
+	}
+	fn set_theme(&mut self, name: &str) -> Result<(), CommandError> {
+		self.theme = Some(name.to_string());
+		Ok(())
+	}
+	fn set_option(&mut self, key: &str, value: &str) -> Result<(), CommandError> {
+		self.options.push((key.to_string(), value.to_string()));
+		Ok(())
+	}
+	fn set_local_option(&mut self, key: &str, value: &str) -> Result<(), CommandError> {
+		self.options.push((key.to_string(), value.to_string()));
+		Ok(())
+	}
+	fn describe_option(&self, _key: &str) -> Result<String, CommandError> {
+		Ok(String::new())
+	}
+	fn open_info_popup(&mut self, content: &str, _file_type: Option<&str>) {
+		self.popups.push(content.to_string());
+	}
+	fn close_all_info_popups(&mut self) {}
+	fn recent_commands(&self, limit: usize) -> Vec<String> {
+		self.history.iter().rev().take(limit).cloned().collect()
+	}
+	fn insert_snippet_body(&mut self, _body: &str) -> bool {
+		false
+	}
+	fn goto_file(&mut self, path: PathBuf, line: usize, column: usize) -> BoxFutureLocal<'_, Result<(), CommandError>> {
+		self.opened.push((path, line, column));
+		Box::pin(async { Ok(()) })
+	}
+	fn queue_invocation(&mut self, _request: crate::actions::DeferredInvocationRequest) {}
+	fn confirm(&mut self, prompt: &str) -> BoxFutureLocal<'_, ConfirmationResult> {
+		self.prompts.push(prompt.to_string());
+		let answer = self.answer;
+		Box::pin(async move { answer })
+	}
+	fn confirmation_pending(&self) -> bool {
+		self.confirmation_pending
+	}
+	fn spawn_background(&mut self, name: String, task: BackgroundTask) -> TaskId {
+		let (id, future) = self.tasks.start(name, task);
+		self.spawned.push(future);
+		id
+	}
+	fn background_tasks(&self) -> Vec<TaskInfo> {
+		self.tasks.list()
+	}
+	fn cancel_background(&mut self, id: TaskId) -> bool {
+		self.tasks.cancel(id)
+	}
+}
+
+/// Runs `handler` to completion against `editor`, without parsing `args`.
+///
+/// Panics if the handler's future does not resolve on first poll; every
+/// [`MockEditor`] future does.
+pub(crate) fn run(
+	handler: CommandHandler,
+	editor: &mut MockEditor,
+	args: &[&str],
+	user_data: Option<&'static (dyn Any + Send + Sync)>,
+) -> Result<CommandOutcome, CommandError> {
+	run_parsed(handler, editor, args, &ParsedArgs::default(), user_data)
+}
+
+/// Dispatches registered command `name` like the editor does: parses `args`
+/// against its arg spec, then runs the handler.
+pub(crate) fn run_command(name: &str, editor: &mut MockEditor, args: &[&str]) -> Result<CommandOutcome, CommandError> {
+	let command = crate::commands::find_command(name).expect("command should be registered");
+	let parsed = command.parse_args(args)?;
+	run_parsed(command.handler, editor, args, &parsed, command.user_data)
+}
+
+fn run_parsed(
+	handler: CommandHandler,
+	editor: &mut MockEditor,
+	args: &[&str],
+	parsed: &ParsedArgs,
+	user_data: Option<&'static (dyn Any + Send + Sync)>,
+) -> Result<CommandOutcome, CommandError> {
+	let mut ctx = CommandContext {
+		editor,
+		args,
+		parsed,
+		count: 1,
+		register: None,
+		range: None,
+		user_data,
+	};
+	match handler(&mut ctx).as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+		Poll::Ready(result) => result,
+		Poll::Pending => panic!("mock editor futures resolve immediately"),
+	}
+}
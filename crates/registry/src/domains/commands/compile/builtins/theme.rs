@@ -3,14 +3,64 @@ use xeno_primitives::BoxFutureLocal;
 use crate::command_handler;
 use crate::commands::{CommandContext, CommandError, CommandOutcome};
 use crate::notifications::keys;
+use crate::themes::{audit, get_theme};
 
 command_handler!(theme, handler: cmd_theme);
+command_handler!(theme_check, handler: cmd_theme_check);
 
 fn cmd_theme<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
 	Box::pin(async move {
-		let theme_name = ctx.args.first().ok_or(CommandError::MissingArgument("theme name"))?;
+		let parsed = ctx.parsed;
+		let theme_name = parsed.str(0).ok_or(CommandError::MissingArgument("theme name"))?;
 		ctx.editor.set_theme(theme_name)?;
 		ctx.emit(keys::theme_set(theme_name));
 		Ok(CommandOutcome::Ok)
 	})
 }
+
+/// `:theme-check <name>` reports color pairs of `name` with too little contrast.
+fn cmd_theme_check<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let parsed = ctx.parsed;
+		let name = parsed.str(0).ok_or(CommandError::MissingArgument("theme name"))?;
+		let theme = get_theme(name).ok_or_else(|| CommandError::InvalidArgument(format!("unknown theme: {name}")))?;
+		let issues = audit(&theme.colors);
+		if issues.is_empty() {
+			ctx.emit(keys::diagnostic_output(format!("{name}: no contrast issues")));
+		} else {
+			let lines: Vec<String> = issues.iter().map(ToString::to_string).collect();
+			ctx.emit(keys::diagnostic_warning(format!("{name}:\n{}", lines.join("\n"))));
+		}
+		Ok(CommandOutcome::Ok)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::commands::builtins::test_fixtures::{MockEditor, run_command};
+
+	#[test]
+	fn theme_arguments_are_validated_before_the_handler() {
+		let mut editor = MockEditor::default();
+		let err = run_command("theme", &mut editor, &[]).expect_err("theme name is required");
+		assert!(err.to_string().contains("missing required argument: theme"), "{err}");
+		assert!(run_command("theme", &mut editor, &["a", "b", "c"]).is_err());
+		assert_eq!(editor.theme, None);
+
+		run_command("theme", &mut editor, &["gruvbox"]).expect("theme succeeds");
+		assert_eq!(editor.theme.as_deref(), Some("gruvbox"));
+	}
+
+	#[test]
+	fn theme_named_check_is_set_and_theme_check_only_reports() {
+		let mut editor = MockEditor::default();
+		run_command("theme", &mut editor, &["check"]).expect("theme succeeds");
+		assert_eq!(editor.theme.as_deref(), Some("check"));
+
+		let mut editor = MockEditor::default();
+		run_command("theme_check", &mut editor, &[crate::themes::DEFAULT_THEME_ID]).expect("theme_check succeeds");
+		assert_eq!(editor.theme, None);
+		assert_eq!(editor.emitted.len(), 1);
+		assert!(run_command("theme_check", &mut editor, &["no-such-theme"]).is_err());
+	}
+}
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use super::spec::CommandsSpec;
 use crate::commands::def::CommandHandler;
 use crate::commands::entry::CommandEntry;
@@ -11,6 +13,8 @@ pub type LinkedCommandDef = LinkedDef<CommandPayload>;
 pub struct CommandPayload {
 	pub handler: CommandHandler,
 	pub palette: super::spec::CommandPaletteSpec,
+	pub user_data: Option<&'static (dyn Any + Send + Sync)>,
+	pub flags: u32,
 }
 
 impl LinkedPayload<CommandEntry> for CommandPayload {
@@ -19,7 +23,8 @@ impl LinkedPayload<CommandEntry> for CommandPayload {
 			meta,
 			palette: self.palette.clone(),
 			handler: self.handler,
-			user_data: None,
+			user_data: self.user_data,
+			flags: self.flags,
 		}
 	}
 }
@@ -40,15 +45,23 @@ pub fn link_commands(spec: &CommandsSpec, handlers: impl Iterator<Item = &'stati
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.name.clone(), // commands.rs used name as short_desc
 				},
 				payload: CommandPayload {
 					handler: handler.handler,
 					palette: meta.palette.clone(),
+					user_data: handler.user_data,
+					flags: if meta.accepts_range {
+						crate::commands::flags::ACCEPTS_RANGE
+					} else {
+						crate::commands::flags::NONE
+					},
 				},
 			}
 		},
@@ -17,7 +17,9 @@ pub struct CommandDef {
 	/// Async function that executes the command.
 	pub handler: CommandHandler,
 	/// Extension-specific data attached to the command.
-	pub user_data: Option<&'static (dyn Any + Sync)>,
+	pub user_data: Option<&'static (dyn Any + Send + Sync)>,
+	/// Behavior flags (see [`super::flags`]).
+	pub flags: u32,
 }
 
 impl BuildEntry<CommandEntry> for CommandDef {
@@ -26,10 +28,12 @@ impl BuildEntry<CommandEntry> for CommandDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -47,6 +51,7 @@ impl BuildEntry<CommandEntry> for CommandDef {
 			palette: CommandPaletteSpec::default(),
 			handler: self.handler,
 			user_data: self.user_data,
+			flags: self.flags,
 		}
 	}
 }
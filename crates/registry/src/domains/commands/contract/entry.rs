@@ -1,8 +1,9 @@
 use std::any::Any;
 
+use super::args::{ParsedArgs, parse_args};
 use super::def::CommandHandler;
 use super::spec::CommandPaletteSpec;
-use crate::core::RegistryMeta;
+use crate::core::{CommandError, RegistryMeta};
 
 /// Symbolized command entry stored in the registry snapshot.
 pub struct CommandEntry {
@@ -13,7 +14,9 @@ pub struct CommandEntry {
 	/// Async function that executes the command.
 	pub handler: CommandHandler,
 	/// Extension-specific data passed to handler.
-	pub user_data: Option<&'static (dyn Any + Sync)>,
+	pub user_data: Option<&'static (dyn Any + Send + Sync)>,
+	/// Behavior flags (see [`super::flags`]).
+	pub flags: u32,
 }
 
 impl CommandEntry {
@@ -21,6 +24,16 @@ impl CommandEntry {
 	pub fn palette(&self) -> &CommandPaletteSpec {
 		&self.palette
 	}
+
+	/// Returns whether the command honors a line range prefix.
+	pub fn accepts_range(&self) -> bool {
+		self.flags & super::flags::ACCEPTS_RANGE != 0
+	}
+
+	/// Validates raw arguments against the palette arg spec.
+	pub fn parse_args(&self, raw: &[&str]) -> Result<ParsedArgs, CommandError> {
+		parse_args(&self.palette.args, raw)
+	}
 }
 
 crate::impl_registry_entry!(CommandEntry);
@@ -0,0 +1,185 @@
+//! Argument parsing against a command's declared arg spec.
+//!
+//! The dispatch layer runs [`parse_args`] with the command's
+//! [`PaletteArgSpec`] list before calling the handler, so handlers read typed
+//! values from [`CommandContext::parsed`](super::CommandContext::parsed)
+//! instead of re-validating raw tokens.
+
+use std::path::{Path, PathBuf};
+
+use super::spec::{PaletteArgKind, PaletteArgSpec};
+use crate::core::CommandError;
+
+/// A single argument converted according to its [`PaletteArgKind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgValue {
+	/// [`PaletteArgKind::FilePath`] argument.
+	Path(PathBuf),
+	/// [`PaletteArgKind::Int`] argument.
+	Int(i64),
+	/// Any other kind, kept verbatim.
+	Str(String),
+}
+
+impl ArgValue {
+	/// Returns the textual form of a path or string argument.
+	pub fn as_str(&self) -> Option<&str> {
+		match self {
+			Self::Path(path) => path.to_str(),
+			Self::Str(text) => Some(text),
+			Self::Int(_) => None,
+		}
+	}
+}
+
+/// Command arguments validated against an arg spec.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedArgs {
+	positional: Vec<ArgValue>,
+	rest: Vec<ArgValue>,
+}
+
+impl ParsedArgs {
+	/// Returns the positional argument at `idx`, if it was given.
+	pub fn get(&self, idx: usize) -> Option<&ArgValue> {
+		self.positional.get(idx)
+	}
+
+	/// Returns the path argument at `idx`.
+	pub fn path(&self, idx: usize) -> Option<&Path> {
+		match self.get(idx)? {
+			ArgValue::Path(path) => Some(path),
+			_ => None,
+		}
+	}
+
+	/// Returns the integer argument at `idx`.
+	pub fn int(&self, idx: usize) -> Option<i64> {
+		match self.get(idx)? {
+			ArgValue::Int(value) => Some(*value),
+			_ => None,
+		}
+	}
+
+	/// Returns the string argument at `idx`.
+	pub fn str(&self, idx: usize) -> Option<&str> {
+		match self.get(idx)? {
+			ArgValue::Str(text) => Some(text),
+			_ => None,
+		}
+	}
+
+	/// Returns the values collected by the trailing variadic argument.
+	pub fn rest(&self) -> &[ArgValue] {
+		&self.rest
+	}
+}
+
+/// Validates `raw` tokens against `spec`.
+///
+/// Positional tokens are matched to spec entries in order; a trailing
+/// `variadic` entry collects every remaining token. Fails on a missing
+/// required argument, a token that does not convert to its kind, or surplus
+/// tokens when the spec has no variadic entry.
+pub fn parse_args(spec: &[PaletteArgSpec], raw: &[&str]) -> Result<ParsedArgs, CommandError> {
+	let mut parsed = ParsedArgs::default();
+	let mut tokens = raw.iter().copied();
+
+	for arg in spec {
+		if arg.variadic {
+			parsed.rest = tokens.by_ref().map(|token| convert(arg, token)).collect::<Result<_, _>>()?;
+			if arg.required && parsed.rest.is_empty() {
+				return Err(missing(arg));
+			}
+			continue;
+		}
+		match tokens.next() {
+			Some(token) => parsed.positional.push(convert(arg, token)?),
+			None if arg.required => return Err(missing(arg)),
+			None => break,
+		}
+	}
+
+	let surplus = tokens.count();
+	if surplus > 0 {
+		return Err(CommandError::InvalidArgument(format!(
+			"expected at most {} argument(s), got {}",
+			raw.len() - surplus,
+			raw.len()
+		)));
+	}
+	Ok(parsed)
+}
+
+fn convert(arg: &PaletteArgSpec, token: &str) -> Result<ArgValue, CommandError> {
+	match arg.kind {
+		PaletteArgKind::FilePath => Ok(ArgValue::Path(PathBuf::from(token))),
+		PaletteArgKind::Int => token
+			.parse()
+			.map(ArgValue::Int)
+			.map_err(|_| CommandError::InvalidArgument(format!("{}: expected an integer, got '{token}'", arg.name))),
+		_ => Ok(ArgValue::Str(token.to_string())),
+	}
+}
+
+fn missing(arg: &PaletteArgSpec) -> CommandError {
+	CommandError::InvalidArgument(format!("missing required argument: {}", arg.name))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn arg(name: &str, kind: PaletteArgKind, required: bool, variadic: bool) -> PaletteArgSpec {
+		PaletteArgSpec {
+			name: name.to_string(),
+			kind,
+			required,
+			variadic,
+		}
+	}
+
+	#[test]
+	fn missing_required_argument_is_rejected() {
+		let spec = [arg("theme", PaletteArgKind::ThemeName, true, false)];
+		let err = parse_args(&spec, &[]).expect_err("theme is required");
+		assert!(err.to_string().contains("missing required argument: theme"), "{err}");
+	}
+
+	#[test]
+	fn wrong_type_is_rejected() {
+		let spec = [
+			arg("path", PaletteArgKind::FilePath, true, false),
+			arg("line", PaletteArgKind::Int, false, false),
+		];
+		let parsed = parse_args(&spec, &["src/main.rs", "12"]).expect("valid args");
+		assert_eq!(parsed.path(0), Some(Path::new("src/main.rs")));
+		assert_eq!(parsed.int(1), Some(12));
+
+		let err = parse_args(&spec, &["src/main.rs", "twelve"]).expect_err("line must be an integer");
+		assert!(err.to_string().contains("line: expected an integer, got 'twelve'"), "{err}");
+	}
+
+	#[test]
+	fn variadic_argument_collects_rest() {
+		let spec = [
+			arg("key", PaletteArgKind::OptionKey, false, false),
+			arg("value", PaletteArgKind::OptionValue, false, true),
+		];
+		let parsed = parse_args(&spec, &["status-format", "a", "b"]).expect("valid args");
+		assert_eq!(parsed.str(0), Some("status-format"));
+		let rest: Vec<_> = parsed.rest().iter().filter_map(ArgValue::as_str).collect();
+		assert_eq!(rest, ["a", "b"]);
+
+		let required_rest = [arg("body", PaletteArgKind::SnippetRefOrBody, true, true)];
+		assert!(parse_args(&required_rest, &[]).is_err());
+	}
+
+	#[test]
+	fn surplus_arguments_are_rejected() {
+		let spec = [arg("path", PaletteArgKind::FilePath, false, false)];
+		assert!(parse_args(&spec, &[]).expect("optional").get(0).is_none());
+		let err = parse_args(&spec, &["a", "b"]).expect_err("one argument at most");
+		assert!(err.to_string().contains("expected at most 1 argument(s), got 2"), "{err}");
+	}
+}
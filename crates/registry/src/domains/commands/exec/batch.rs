@@ -0,0 +1,257 @@
+//! Synthetic commands that run other commands in sequence.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use xeno_primitives::BoxFutureLocal;
+
+use super::{CommandContext, CommandDef, CommandError, CommandOutcome, RegistryMetaStatic, RegistrySource, flags};
+
+/// Batches built so far, by batch name; read at run time and for cycle detection.
+static BATCHES: LazyLock<Mutex<HashMap<&'static str, Batch>>> = LazyLock::new(Default::default);
+
+struct Batch {
+	steps: Arc<[String]>,
+	/// User data shared by every definition of this batch name.
+	marker: &'static BatchName,
+}
+
+/// Name of a batch command, attached as its user data; the steps live in [`BATCHES`].
+#[derive(Debug)]
+struct BatchName(&'static str);
+
+impl CommandDef {
+	/// Builds a command named `name` that runs each command in `steps` without
+	/// arguments, in order.
+	///
+	/// The batch mutates the buffer if any step does, and each step is checked
+	/// against the current buffer's readonly flag before it runs. The batch
+	/// stops at the first step that fails, returns anything but
+	/// [`CommandOutcome::Ok`], or leaves a [`CommandContext::confirm`] prompt
+	/// pending; accepting such a prompt re-runs the whole batch. A run returns
+	/// [`CommandOutcome::Pipeline`] with one outcome per step that ran.
+	///
+	/// Building a batch again under the same name replaces its steps.
+	///
+	/// # Panics
+	///
+	/// Panics if `steps` names the batch itself, directly or through a
+	/// previously built batch.
+	pub fn batch(name: &'static str, steps: impl IntoIterator<Item = impl Into<String>>) -> CommandDef {
+		let steps: Arc<[String]> = steps.into_iter().map(Into::into).collect();
+		let mut batches = BATCHES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		if let Some(path) = find_cycle(&batches, name, &steps) {
+			panic!("batch command '{name}' is cyclic: {name} -> {}", path.join(" -> "));
+		}
+		let mutates_buffer = steps
+			.iter()
+			.any(|step| super::find_command(step).is_some_and(|command| command.mutates_buffer()));
+		// Leaked once per batch name, so redefining a batch allocates nothing permanent.
+		let marker = match batches.get(name) {
+			Some(batch) => batch.marker,
+			None => Box::leak(Box::new(BatchName(name))),
+		};
+		batches.insert(name, Batch { steps, marker });
+
+		CommandDef {
+			meta: RegistryMetaStatic {
+				id: name,
+				name,
+				keys: &[],
+				categories: &[],
+				description: "Run commands in sequence",
+				priority: 0,
+				source: RegistrySource::Runtime,
+				mutates_buffer,
+				deprecated: None,
+			},
+			handler: cmd_batch,
+			user_data: Some(marker),
+			flags: flags::NONE,
+		}
+	}
+}
+
+/// Returns the step path leading from `steps` back to `name`, if any.
+fn find_cycle(batches: &HashMap<&'static str, Batch>, name: &str, steps: &[String]) -> Option<Vec<String>> {
+	steps.iter().find_map(|step| {
+		if step == name {
+			return Some(vec![step.clone()]);
+		}
+		let nested = batches.get(step.as_str())?;
+		let mut path = find_cycle(batches, name, &nested.steps)?;
+		path.insert(0, step.clone());
+		Some(path)
+	})
+}
+
+/// Returns whether `outcome` lets a batch continue: `Ok`, or a pipeline of them.
+fn is_ok(outcome: &CommandOutcome) -> bool {
+	match outcome {
+		CommandOutcome::Ok => true,
+		CommandOutcome::Pipeline(steps) => steps.iter().all(is_ok),
+		CommandOutcome::Quit | CommandOutcome::ForceQuit => false,
+	}
+}
+
+fn cmd_batch<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+	Box::pin(async move {
+		let BatchName(name) = ctx.require_user_data::<BatchName>()?;
+		let steps = BATCHES
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+			.get(name)
+			.map(|batch| Arc::clone(&batch.steps))
+			.ok_or_else(|| CommandError::NotFound((*name).to_string()))?;
+		let mut outcomes = Vec::with_capacity(steps.len());
+		for step in steps.iter() {
+			let command = super::find_command(step).ok_or_else(|| CommandError::NotFound(step.clone()))?;
+			if command.mutates_buffer() && ctx.editor.is_readonly() {
+				return Err(CommandError::Failed(format!("'{step}' cannot run: buffer is readonly")));
+			}
+			let parsed = command.parse_args(&[])?;
+			let mut step_ctx = CommandContext {
+				editor: &mut *ctx.editor,
+				args: &[],
+				parsed: &parsed,
+				count: 1,
+				register: ctx.register,
+				range: None,
+				user_data: command.user_data,
+			};
+			let outcome = (command.handler)(&mut step_ctx).await?;
+			let stop = !is_ok(&outcome) || ctx.editor.confirmation_pending();
+			outcomes.push(outcome);
+			if stop {
+				break;
+			}
+		}
+		Ok(CommandOutcome::Pipeline(outcomes))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::commands::builtins::test_fixtures::{MockEditor, run};
+
+	#[test]
+	fn batch_runs_every_step_in_order() {
+		let def = CommandDef::batch("test_batch_write_next", ["write", "buffer_next"]);
+		let mut editor = MockEditor::default();
+
+		let outcome = run(def.handler, &mut editor, &[], def.user_data).expect("batch succeeds");
+
+		assert_eq!(outcome, CommandOutcome::Pipeline(vec![CommandOutcome::Ok, CommandOutcome::Ok]));
+		assert_eq!(editor.saves, 1, "write ran");
+		assert_eq!(editor.emitted.len(), 1, "buffer_next ran after write");
+		assert!(editor.emitted[0].message.contains("buffer-next"));
+	}
+
+	#[test]
+	fn batch_stops_at_the_first_failing_step() {
+		let def = CommandDef::batch("test_batch_failing", ["no_such_command", "write"]);
+		let mut editor = MockEditor::default();
+
+		let error = run(def.handler, &mut editor, &[], def.user_data).expect_err("unknown step fails");
+
+		assert!(matches!(error, CommandError::NotFound(ref name) if name == "no_such_command"));
+		assert_eq!(editor.saves, 0, "later steps do not run");
+	}
+
+	#[test]
+	fn batch_stops_after_a_step_that_does_not_return_ok() {
+		let def = CommandDef::batch("test_batch_quit_write", ["quit", "write"]);
+		let mut editor = MockEditor::default();
+
+		let outcome = run(def.handler, &mut editor, &[], def.user_data).expect("batch succeeds");
+
+		assert_eq!(outcome, CommandOutcome::Pipeline(vec![CommandOutcome::Quit]));
+		assert_eq!(editor.saves, 0, "steps after quit do not run");
+	}
+
+	#[test]
+	fn batch_stops_while_a_confirmation_is_pending() {
+		let def = CommandDef::batch("test_batch_pending", ["write", "write"]);
+		let mut editor = MockEditor {
+			confirmation_pending: true,
+			..MockEditor::default()
+		};
+
+		let outcome = run(def.handler, &mut editor, &[], def.user_data).expect("batch succeeds");
+
+		assert_eq!(outcome, CommandOutcome::Pipeline(vec![CommandOutcome::Ok]));
+		assert_eq!(editor.saves, 1);
+	}
+
+	fn mark_ran<'a>(ctx: &'a mut CommandContext<'a>) -> BoxFutureLocal<'a, Result<CommandOutcome, CommandError>> {
+		Box::pin(async move {
+			ctx.emit(crate::notifications::keys::not_implemented("batch-mutating-test"));
+			Ok(CommandOutcome::Ok)
+		})
+	}
+
+	static MUTATING: CommandDef = CommandDef {
+		meta: RegistryMetaStatic {
+			id: "registry::commands::batch_mutating_test",
+			name: "batch_mutating_test",
+			keys: &[],
+			categories: &[],
+			description: "mutating batch step",
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: true,
+			deprecated: None,
+		},
+		handler: mark_ran,
+		user_data: None,
+		flags: flags::NONE,
+	};
+
+	#[test]
+	fn batch_checks_readonly_before_each_mutating_step() {
+		crate::commands::COMMANDS.replace(Arc::new(crate::commands::CommandInput::Static(MUTATING.clone())));
+		let def = CommandDef::batch("test_batch_mutating", ["write", "batch_mutating_test"]);
+		assert!(def.meta.mutates_buffer, "a mutating step makes the batch mutating");
+
+		let mut editor = MockEditor {
+			readonly: true,
+			..MockEditor::default()
+		};
+		let result = run(def.handler, &mut editor, &[], def.user_data);
+		crate::commands::COMMANDS.remove_canonical(MUTATING.meta.id, |_| true);
+		let error = result.expect_err("readonly buffer refuses the mutating step");
+
+		assert!(matches!(error, CommandError::Failed(ref message) if message.contains("readonly")), "{error:?}");
+		assert_eq!(editor.saves, 1, "the non-mutating step before it ran");
+		assert!(editor.emitted.is_empty(), "the mutating step did not run");
+	}
+
+	#[test]
+	fn redefining_a_batch_replaces_its_steps_and_reuses_its_user_data() {
+		let first = CommandDef::batch("test_batch_redefined", ["write"]);
+		let second = CommandDef::batch("test_batch_redefined", ["buffer_next"]);
+		let (Some(first_data), Some(second_data)) = (first.user_data, second.user_data) else {
+			panic!("batches carry user data");
+		};
+		assert!(std::ptr::addr_eq(first_data, second_data));
+
+		let mut editor = MockEditor::default();
+		run(first.handler, &mut editor, &[], first.user_data).expect("batch succeeds");
+		assert_eq!(editor.saves, 0, "the latest steps run");
+		assert_eq!(editor.emitted.len(), 1);
+	}
+
+	#[test]
+	#[should_panic(expected = "test_batch_self -> test_batch_self")]
+	fn batch_naming_itself_panics() {
+		CommandDef::batch("test_batch_self", ["write", "test_batch_self"]);
+	}
+
+	#[test]
+	#[should_panic(expected = "test_batch_outer -> test_batch_inner -> test_batch_outer")]
+	fn batch_closing_a_cycle_through_an_earlier_batch_panics() {
+		CommandDef::batch("test_batch_inner", ["test_batch_outer"]);
+		CommandDef::batch("test_batch_outer", ["write", "test_batch_inner"]);
+	}
+}
@@ -4,9 +4,24 @@
 //! submits it via `inventory::submit!`. At startup, the linking step collects
 //! all submitted handlers and pairs them with NUON metadata by name.
 
+use std::any::Any;
+
 use super::def::CommandHandler;
 
-pub type CommandHandlerStatic = crate::core::HandlerStatic<CommandHandler>;
+/// Static registration payload for a command handler.
+///
+/// Like [`crate::core::HandlerStatic`], plus the user data handed to the
+/// handler through [`CommandContext::user_data`](super::CommandContext::user_data).
+pub struct CommandHandlerStatic {
+	/// Handler name (must match registry metadata name).
+	pub name: &'static str,
+	/// Crate that defined this handler.
+	pub crate_name: &'static str,
+	/// Handler function pointer.
+	pub handler: CommandHandler,
+	/// State shared by every invocation of the command.
+	pub user_data: Option<&'static (dyn Any + Send + Sync)>,
+}
 
 /// Static handler registration entry collected via `inventory`.
 /// Wrapper for `inventory::collect!`.
@@ -0,0 +1,201 @@
+//! Command-line history shared by the command palette and `:history`.
+//!
+//! The on-disk format is one entry per line, oldest first, with `\` and
+//! newlines escaped as `\\` and `\n`. Entries are appended as they are
+//! recorded; [`CommandHistory::save`] compacts the file down to the in-memory
+//! ring. Lines that are not valid UTF-8 or contain an unknown escape are
+//! skipped on load.
+
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Bounded command-line history, oldest entry first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandHistory {
+	entries: VecDeque<String>,
+	capacity: usize,
+}
+
+impl Default for CommandHistory {
+	fn default() -> Self {
+		Self::with_capacity(Self::DEFAULT_CAPACITY)
+	}
+}
+
+impl CommandHistory {
+	/// Number of entries kept when no capacity is given.
+	pub const DEFAULT_CAPACITY: usize = 1000;
+
+	/// Creates an empty history holding at most `capacity` entries.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			entries: VecDeque::new(),
+			capacity: capacity.max(1),
+		}
+	}
+
+	/// Records `entry`, evicting the oldest entry when full.
+	///
+	/// Blank entries and repeats of the most recent entry are ignored; returns
+	/// whether the entry was recorded.
+	pub fn push(&mut self, entry: impl Into<String>) -> bool {
+		let entry = entry.into();
+		if entry.trim().is_empty() || self.entries.back() == Some(&entry) {
+			return false;
+		}
+		if self.entries.len() == self.capacity {
+			self.entries.pop_front();
+		}
+		self.entries.push_back(entry);
+		true
+	}
+
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Iterates entries from newest to oldest.
+	pub fn iter_rev(&self) -> impl Iterator<Item = &str> {
+		self.entries.iter().rev().map(String::as_str)
+	}
+
+	/// Returns distinct entries starting with `prefix`, newest first.
+	///
+	/// An entry equal to `prefix` is not a match.
+	pub fn search_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+		let mut seen = HashSet::new();
+		self.iter_rev()
+			.filter(move |entry| entry.len() > prefix.len() && entry.starts_with(prefix))
+			.filter(move |entry| seen.insert(*entry))
+	}
+
+	/// Loads history from `path`, keeping the newest `capacity` entries.
+	///
+	/// A missing file yields an empty history.
+	pub fn load(path: &Path, capacity: usize) -> io::Result<Self> {
+		let mut history = Self::with_capacity(capacity);
+		let data = match fs::read(path) {
+			Ok(data) => data,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(history),
+			Err(error) => return Err(error),
+		};
+		for line in data.split(|&byte| byte == b'\n') {
+			if let Some(entry) = std::str::from_utf8(line).ok().and_then(unescape) {
+				history.push(entry);
+			}
+		}
+		Ok(history)
+	}
+
+	/// Rewrites `path` with exactly the in-memory entries.
+	///
+	/// Writes to a sibling temp file first so a failed save leaves the old
+	/// file intact.
+	pub fn save(&self, path: &Path) -> io::Result<()> {
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let mut out = String::new();
+		for entry in &self.entries {
+			out.push_str(&escape(entry));
+			out.push('\n');
+		}
+		let tmp = path.with_extension("tmp");
+		fs::write(&tmp, out)?;
+		fs::rename(&tmp, path)
+	}
+
+	/// Appends a single `entry` to the history file at `path`.
+	pub fn append(path: &Path, entry: &str) -> io::Result<()> {
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+		writeln!(file, "{}", escape(entry))
+	}
+}
+
+fn escape(entry: &str) -> String {
+	entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str) -> Option<String> {
+	let mut out = String::with_capacity(line.len());
+	let mut chars = line.chars();
+	while let Some(ch) = chars.next() {
+		if ch != '\\' {
+			out.push(ch);
+			continue;
+		}
+		match chars.next()? {
+			'\\' => out.push('\\'),
+			'n' => out.push('\n'),
+			_ => return None,
+		}
+	}
+	Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn push_skips_consecutive_repeats_and_evicts_oldest() {
+		let mut history = CommandHistory::with_capacity(3);
+		assert!(history.push("w"));
+		assert!(!history.push("w"));
+		assert!(!history.push("  "));
+		history.push("set wrap");
+		history.push("w");
+		history.push("q");
+		assert_eq!(history.iter_rev().collect::<Vec<_>>(), ["q", "w", "set wrap"]);
+	}
+
+	#[test]
+	fn search_prefix_returns_distinct_newest_first() {
+		let mut history = CommandHistory::default();
+		for entry in ["set wrap", "set tab-width 2", "w", "set wrap", "set"] {
+			history.push(entry);
+		}
+		assert_eq!(history.search_prefix("set").collect::<Vec<_>>(), ["set wrap", "set tab-width 2"]);
+		assert_eq!(history.search_prefix("x").count(), 0);
+	}
+
+	#[test]
+	fn persistence_round_trips_and_skips_malformed_lines() {
+		let dir = tempfile::tempdir().expect("temp dir");
+		let path = dir.path().join("state").join("command_history");
+
+		let mut history = CommandHistory::default();
+		history.push("snippet a\\b");
+		history.push("echo one\ntwo");
+		history.save(&path).expect("save");
+		CommandHistory::append(&path, "w").expect("append");
+
+		let mut raw = fs::read(&path).expect("read");
+		raw.extend_from_slice(b"bad \\x escape\n\xff\xfe\n");
+		fs::write(&path, raw).expect("write");
+
+		let loaded = CommandHistory::load(&path, CommandHistory::DEFAULT_CAPACITY).expect("load");
+		assert_eq!(loaded.iter_rev().collect::<Vec<_>>(), ["w", "echo one\ntwo", "snippet a\\b"]);
+
+		let capped = CommandHistory::load(&path, 2).expect("load");
+		assert_eq!(capped.iter_rev().collect::<Vec<_>>(), ["w", "echo one\ntwo"]);
+		capped.save(&path).expect("compact");
+		assert_eq!(CommandHistory::load(&path, 10).expect("reload"), CommandHistory { capacity: 10, ..capped });
+	}
+
+	#[test]
+	fn load_missing_file_is_empty() {
+		let dir = tempfile::tempdir().expect("temp dir");
+		let history = CommandHistory::load(&dir.path().join("absent"), 10).expect("load");
+		assert!(history.is_empty());
+	}
+}
@@ -2,9 +2,18 @@
 ///
 /// The metadata (description, aliases, etc.) comes from `commands.nuon`; this macro
 /// only provides the Rust handler and creates the inventory linkage.
+///
+/// The optional `user_data` is a `&'static` value the handler reads back with
+/// `ctx.user_data::<T>()`, for state such as a compiled regex.
 #[macro_export]
 macro_rules! command_handler {
 	($name:ident, handler: $handler:expr) => {
+		$crate::command_handler!(@static $name, $handler, None);
+	};
+	($name:ident, handler: $handler:expr, user_data: $data:expr) => {
+		$crate::command_handler!(@static $name, $handler, Some($data));
+	};
+	(@static $name:ident, $handler:expr, $data:expr) => {
 		paste::paste! {
 			#[allow(non_upper_case_globals)]
 			pub(crate) static [<CMD_HANDLER_ $name>]: $crate::commands::CommandHandlerStatic =
@@ -12,6 +21,7 @@ macro_rules! command_handler {
 					name: stringify!($name),
 					crate_name: env!("CARGO_PKG_NAME"),
 					handler: $handler,
+					user_data: $data,
 				};
 
 			inventory::submit!($crate::commands::CommandHandlerReg(&[<CMD_HANDLER_ $name>]));
@@ -0,0 +1,192 @@
+//! Ex-style line ranges prefixed to a command name (`:%d`, `:10,20>`).
+//!
+//! [`split_range`] peels the range off the command word; the dispatch layer
+//! rejects it unless the command sets [`flags::ACCEPTS_RANGE`](super::flags::ACCEPTS_RANGE)
+//! and hands it to the handler as [`CommandContext::range`](super::CommandContext::range).
+
+use std::fmt;
+
+use crate::core::CommandError;
+
+/// One end of a [`CommandRange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineAddr {
+	/// 1-based line number (`N`).
+	Line(usize),
+	/// Line of the primary cursor (`.`).
+	Current,
+	/// Last line of the buffer (`$`).
+	Last,
+}
+
+impl LineAddr {
+	/// Resolves to a 0-based line, given the 0-based cursor line and the buffer's line count.
+	pub fn resolve(self, current: usize, line_count: usize) -> Result<usize, CommandError> {
+		match self {
+			Self::Line(line) if (1..=line_count).contains(&line) => Ok(line - 1),
+			Self::Line(line) => Err(CommandError::InvalidArgument(format!("line {line} out of range (1-{line_count})"))),
+			Self::Current => Ok(current.min(line_count.saturating_sub(1))),
+			Self::Last => Ok(line_count.saturating_sub(1)),
+		}
+	}
+}
+
+impl fmt::Display for LineAddr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Line(line) => write!(f, "{line}"),
+			Self::Current => f.write_str("."),
+			Self::Last => f.write_str("$"),
+		}
+	}
+}
+
+/// Inclusive line range given before a command name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandRange {
+	pub start: LineAddr,
+	pub end: LineAddr,
+}
+
+impl CommandRange {
+	/// The whole buffer (`%`).
+	pub const WHOLE: Self = Self {
+		start: LineAddr::Line(1),
+		end: LineAddr::Last,
+	};
+
+	/// A range covering the single line `addr`.
+	pub fn line(addr: LineAddr) -> Self {
+		Self { start: addr, end: addr }
+	}
+
+	/// Resolves to inclusive 0-based `(first, last)` lines.
+	///
+	/// Fails if either end is out of bounds or the range runs backwards.
+	pub fn resolve(&self, current: usize, line_count: usize) -> Result<(usize, usize), CommandError> {
+		let first = self.start.resolve(current, line_count)?;
+		let last = self.end.resolve(current, line_count)?;
+		if first > last {
+			return Err(CommandError::InvalidArgument("backwards range".to_string()));
+		}
+		Ok((first, last))
+	}
+}
+
+/// Formats in the grammar [`split_range`] accepts, so a range can be
+/// re-prefixed to a resolved command name.
+impl fmt::Display for CommandRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if *self == Self::WHOLE {
+			f.write_str("%")
+		} else if self.start == self.end {
+			write!(f, "{}", self.start)
+		} else {
+			write!(f, "{},{}", self.start, self.end)
+		}
+	}
+}
+
+/// Splits a leading range off `word`, returning it with the remaining command name.
+///
+/// Accepts `%`, `N`, `.`, `$` and `A,B` of the last three. Words without a
+/// range prefix come back unchanged with `None`.
+pub fn split_range(word: &str) -> Result<(Option<CommandRange>, &str), CommandError> {
+	if let Some(rest) = word.strip_prefix('%') {
+		return Ok((Some(CommandRange::WHOLE), rest.trim_start()));
+	}
+	let Some((start, rest)) = split_addr(word)? else {
+		return Ok((None, word));
+	};
+	let Some(rest) = rest.strip_prefix(',') else {
+		return Ok((Some(CommandRange::line(start)), rest.trim_start()));
+	};
+	let Some((end, rest)) = split_addr(rest)? else {
+		return Err(CommandError::InvalidArgument(format!("expected a line address after ',' in '{word}'")));
+	};
+	Ok((Some(CommandRange { start, end }), rest.trim_start()))
+}
+
+fn split_addr(input: &str) -> Result<Option<(LineAddr, &str)>, CommandError> {
+	if let Some(rest) = input.strip_prefix('.') {
+		return Ok(Some((LineAddr::Current, rest)));
+	}
+	if let Some(rest) = input.strip_prefix('$') {
+		return Ok(Some((LineAddr::Last, rest)));
+	}
+	let digits = input.len() - input.trim_start_matches(|ch: char| ch.is_ascii_digit()).len();
+	if digits == 0 {
+		return Ok(None);
+	}
+	let line = input[..digits]
+		.parse()
+		.map_err(|_| CommandError::InvalidArgument(format!("line number '{}' is too large", &input[..digits])))?;
+	Ok(Some((LineAddr::Line(line), &input[digits..])))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn split(word: &str) -> (Option<CommandRange>, &str) {
+		split_range(word).expect("range should parse")
+	}
+
+	#[test]
+	fn split_range_parses_each_address_form() {
+		assert_eq!(split("%d"), (Some(CommandRange::WHOLE), "d"));
+		assert_eq!(split("12d"), (Some(CommandRange::line(LineAddr::Line(12))), "d"));
+		assert_eq!(split(".indent"), (Some(CommandRange::line(LineAddr::Current)), "indent"));
+		assert_eq!(split("$"), (Some(CommandRange::line(LineAddr::Last)), ""));
+		assert_eq!(
+			split("10,20 indent"),
+			(
+				Some(CommandRange {
+					start: LineAddr::Line(10),
+					end: LineAddr::Line(20),
+				}),
+				"indent"
+			)
+		);
+		assert_eq!(
+			split(".,$>"),
+			(
+				Some(CommandRange {
+					start: LineAddr::Current,
+					end: LineAddr::Last,
+				}),
+				">"
+			)
+		);
+		assert_eq!(split("write"), (None, "write"));
+	}
+
+	#[test]
+	fn display_round_trips_through_split_range() {
+		for word in ["%", "7", ".", "$", "3,$", ".,12"] {
+			let (range, rest) = split(word);
+			assert_eq!(rest, "");
+			assert_eq!(range.expect("range").to_string(), word);
+		}
+	}
+
+	#[test]
+	fn split_range_rejects_dangling_comma() {
+		assert!(matches!(split_range("3,d"), Err(CommandError::InvalidArgument(_))));
+		assert!(matches!(split_range("99999999999999999999999d"), Err(CommandError::InvalidArgument(_))));
+	}
+
+	#[test]
+	fn resolve_checks_bounds_and_direction() {
+		assert_eq!(CommandRange::WHOLE.resolve(3, 10).unwrap(), (0, 9));
+		assert_eq!(CommandRange::line(LineAddr::Current).resolve(3, 10).unwrap(), (3, 3));
+		let range = |start, end| CommandRange {
+			start: LineAddr::Line(start),
+			end: LineAddr::Line(end),
+		};
+		assert_eq!(range(2, 4).resolve(0, 10).unwrap(), (1, 3));
+		assert!(matches!(range(4, 2).resolve(0, 10), Err(CommandError::InvalidArgument(msg)) if msg == "backwards range"));
+		assert!(range(0, 2).resolve(0, 10).is_err());
+		assert!(range(2, 11).resolve(0, 10).is_err());
+	}
+}
@@ -0,0 +1,270 @@
+//! Background tasks spawned by commands.
+//!
+//! Long commands (workspace grep, large writes) hand their work to
+//! [`CommandContext::spawn_background`](super::CommandContext::spawn_background)
+//! instead of holding up the command loop. The editor keeps a [`TaskRegistry`]
+//! of running tasks, spawns the future returned by [`TaskRegistry::start`] on
+//! its runtime, and reports each [`FinishedTask`] as a notification once
+//! [`TaskRegistry::drain_finished`] yields it.
+//!
+//! Tasks receive a [`TaskHandle`] for progress reporting and cooperative
+//! cancellation; a cancelled task is also dropped at its next await point.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio_util::sync::CancellationToken;
+use xeno_primitives::BoxFutureSend;
+
+use crate::core::CommandError;
+
+/// Identifier of a background task, unique per [`TaskRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(pub u64);
+
+impl fmt::Display for TaskId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+/// Work units completed out of a known total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskProgress {
+	pub current: u64,
+	pub total: u64,
+}
+
+impl fmt::Display for TaskProgress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}/{}", self.current, self.total)
+	}
+}
+
+/// Task-side view of a background task: progress reporting and cancellation.
+#[derive(Clone, Default)]
+pub struct TaskHandle {
+	cancel: CancellationToken,
+	progress: Arc<Mutex<Option<TaskProgress>>>,
+}
+
+impl TaskHandle {
+	/// Token fired by `task-cancel`; long loops should check or select on it.
+	pub fn cancel_token(&self) -> &CancellationToken {
+		&self.cancel
+	}
+
+	/// Returns whether cancellation was requested.
+	pub fn is_cancelled(&self) -> bool {
+		self.cancel.is_cancelled()
+	}
+
+	/// Records progress shown by the `tasks` listing.
+	pub fn set_progress(&self, current: u64, total: u64) {
+		*self.progress.lock().unwrap_or_else(PoisonError::into_inner) = Some(TaskProgress { current, total });
+	}
+
+	fn progress(&self) -> Option<TaskProgress> {
+		*self.progress.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+/// Deferred body of a background task, started with its [`TaskHandle`].
+pub type BackgroundTask = Box<dyn FnOnce(TaskHandle) -> BoxFutureSend<'static, Result<(), CommandError>> + Send>;
+
+/// Boxes `body` as a [`BackgroundTask`].
+pub fn background_task<F, Fut>(body: F) -> BackgroundTask
+where
+	F: FnOnce(TaskHandle) -> Fut + Send + 'static,
+	Fut: Future<Output = Result<(), CommandError>> + Send + 'static,
+{
+	Box::new(move |handle: TaskHandle| -> BoxFutureSend<'static, Result<(), CommandError>> { Box::pin(body(handle)) })
+}
+
+/// Snapshot of a running task for listings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskInfo {
+	pub id: TaskId,
+	pub name: String,
+	pub progress: Option<TaskProgress>,
+	/// Cancellation was requested but the task has not stopped yet.
+	pub cancelling: bool,
+}
+
+/// How a background task ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskStatus {
+	Completed,
+	Failed(CommandError),
+	Cancelled,
+}
+
+/// A task that stopped since the last [`TaskRegistry::drain_finished`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinishedTask {
+	pub id: TaskId,
+	pub name: String,
+	pub status: TaskStatus,
+}
+
+struct RunningTask {
+	id: TaskId,
+	name: String,
+	handle: TaskHandle,
+}
+
+/// Running background tasks, in start order.
+#[derive(Default)]
+pub struct TaskRegistry {
+	next_id: u64,
+	running: Vec<RunningTask>,
+	/// Statuses posted by task futures as they stop, keyed by id.
+	finished: Arc<Mutex<Vec<(TaskId, TaskStatus)>>>,
+}
+
+impl TaskRegistry {
+	/// Creates an empty registry.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers `task` under `name`, returning its id and the future to spawn.
+	///
+	/// The future races the task against its cancellation token and posts the
+	/// outcome back to this registry; it never fails itself.
+	pub fn start(&mut self, name: String, task: BackgroundTask) -> (TaskId, BoxFutureSend<'static, ()>) {
+		self.next_id += 1;
+		let id = TaskId(self.next_id);
+		let handle = TaskHandle::default();
+		let cancel = handle.cancel.clone();
+		let body = task(handle.clone());
+		let finished = Arc::clone(&self.finished);
+		self.running.push(RunningTask { id, name, handle });
+
+		let future: BoxFutureSend<'static, ()> = Box::pin(async move {
+			let status = match cancel.run_until_cancelled(body).await {
+				Some(Ok(())) => TaskStatus::Completed,
+				Some(Err(error)) => TaskStatus::Failed(error),
+				None => TaskStatus::Cancelled,
+			};
+			finished.lock().unwrap_or_else(PoisonError::into_inner).push((id, status));
+		});
+		(id, future)
+	}
+
+	/// Lists running tasks, oldest first.
+	pub fn list(&self) -> Vec<TaskInfo> {
+		self.running
+			.iter()
+			.map(|task| TaskInfo {
+				id: task.id,
+				name: task.name.clone(),
+				progress: task.handle.progress(),
+				cancelling: task.handle.is_cancelled(),
+			})
+			.collect()
+	}
+
+	/// Requests cancellation of task `id`, returning `false` if it is not running.
+	///
+	/// The task stays listed until its future observes the cancellation.
+	pub fn cancel(&mut self, id: TaskId) -> bool {
+		let Some(task) = self.running.iter().find(|task| task.id == id) else {
+			return false;
+		};
+		task.handle.cancel.cancel();
+		true
+	}
+
+	/// Removes and returns tasks that stopped since the last call.
+	pub fn drain_finished(&mut self) -> Vec<FinishedTask> {
+		let posted = std::mem::take(&mut *self.finished.lock().unwrap_or_else(PoisonError::into_inner));
+		posted
+			.into_iter()
+			.filter_map(|(id, status)| {
+				let idx = self.running.iter().position(|task| task.id == id)?;
+				let task = self.running.remove(idx);
+				Some(FinishedTask { id, name: task.name, status })
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::task::{Context, Poll, Waker};
+
+	use super::*;
+
+	fn poll(future: &mut BoxFutureSend<'static, ()>) -> Poll<()> {
+		future.as_mut().poll(&mut Context::from_waker(Waker::noop()))
+	}
+
+	/// Reports one unit of progress, then never finishes on its own.
+	fn long_running() -> BackgroundTask {
+		background_task(|handle| async move {
+			handle.set_progress(1, 3);
+			std::future::pending::<()>().await;
+			Ok(())
+		})
+	}
+
+	#[test]
+	fn running_task_is_listed_with_progress() {
+		let mut tasks = TaskRegistry::new();
+		let (id, mut future) = tasks.start("grep".to_string(), long_running());
+		assert_eq!(tasks.list()[0].progress, None);
+
+		assert!(poll(&mut future).is_pending());
+		assert_eq!(
+			tasks.list(),
+			[TaskInfo {
+				id,
+				name: "grep".to_string(),
+				progress: Some(TaskProgress { current: 1, total: 3 }),
+				cancelling: false,
+			}]
+		);
+		assert!(tasks.drain_finished().is_empty());
+	}
+
+	#[test]
+	fn cancel_stops_task_and_reports_it() {
+		let mut tasks = TaskRegistry::new();
+		let (id, mut future) = tasks.start("grep".to_string(), long_running());
+		assert!(poll(&mut future).is_pending());
+
+		assert!(tasks.cancel(id));
+		assert!(tasks.list()[0].cancelling, "listed until the future observes the cancel");
+		assert!(poll(&mut future).is_ready());
+
+		let finished = tasks.drain_finished();
+		assert_eq!(finished.len(), 1);
+		assert_eq!(finished[0].status, TaskStatus::Cancelled);
+		assert!(tasks.list().is_empty());
+		assert!(!tasks.cancel(id), "finished tasks cannot be cancelled");
+	}
+
+	#[test]
+	fn completion_and_failure_are_reported() {
+		let mut tasks = TaskRegistry::new();
+		let (ok, mut ok_future) = tasks.start("ok".to_string(), background_task(|_| async { Ok(()) }));
+		let (failed, mut failed_future) = tasks.start(
+			"failed".to_string(),
+			background_task(|_| async { Err(CommandError::Failed("boom".to_string())) }),
+		);
+		assert_ne!(ok, failed);
+		assert!(poll(&mut failed_future).is_ready());
+		assert!(poll(&mut ok_future).is_ready());
+
+		let statuses: Vec<_> = tasks.drain_finished().into_iter().map(|task| (task.id, task.status)).collect();
+		assert_eq!(
+			statuses,
+			[
+				(failed, TaskStatus::Failed(CommandError::Failed("boom".to_string()))),
+				(ok, TaskStatus::Completed)
+			]
+		);
+	}
+}
@@ -7,6 +7,10 @@ use xeno_primitives::BoxFutureLocal;
 
 use crate::notifications::Notification;
 
+#[path = "exec/args.rs"]
+pub mod args;
+#[path = "exec/batch.rs"]
+mod batch;
 #[path = "compile/builtins/mod.rs"]
 pub mod builtins;
 #[path = "contract/def.rs"]
@@ -16,21 +20,31 @@ mod domain;
 pub mod entry;
 #[path = "exec/handler.rs"]
 pub mod handler;
+#[path = "exec/history.rs"]
+pub mod history;
 #[path = "compile/link.rs"]
 pub mod link;
 #[path = "compile/loader.rs"]
 pub mod loader;
 #[path = "exec/macros.rs"]
 mod macros;
+#[path = "exec/range.rs"]
+pub mod range;
 #[path = "contract/spec.rs"]
 pub mod spec;
+#[path = "exec/tasks.rs"]
+pub mod tasks;
 
+pub use args::{ArgValue, ParsedArgs, parse_args};
 pub use builtins::register_builtins;
 pub use def::{CommandDef, CommandHandler, CommandInput};
 pub use domain::Commands;
 pub use entry::CommandEntry;
 pub use handler::{CommandHandlerReg, CommandHandlerStatic};
+pub use history::CommandHistory;
+pub use range::{CommandRange, LineAddr, split_range};
 pub use spec::{CommandPaletteSpec, PaletteArgKind, PaletteArgSpec, PaletteCommitPolicy};
+pub use tasks::{BackgroundTask, TaskHandle, TaskId, TaskInfo, TaskRegistry, background_task};
 
 /// Registers compiled commands from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -55,7 +69,7 @@ pub type CommandRef = RegistryRef<CommandEntry, crate::core::CommandId>;
 pub type CommandResult = Result<(), CommandError>;
 
 /// Outcome of a successfully executed command.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CommandOutcome {
 	/// Command completed normally.
 	Ok,
@@ -63,6 +77,36 @@ pub enum CommandOutcome {
 	Quit,
 	/// Command requests immediate quit (no prompts).
 	ForceQuit,
+	/// Outcomes of each step of a [`CommandDef::batch`] command, in order.
+	Pipeline(Vec<CommandOutcome>),
+}
+
+impl CommandOutcome {
+	/// Collapses a pipeline into its most decisive step outcome: force-quit,
+	/// then quit, then ok. Other outcomes are returned unchanged.
+	pub fn collapse(self) -> Self {
+		match self {
+			Self::Pipeline(steps) => steps.into_iter().map(Self::collapse).max_by_key(Self::quit_rank).unwrap_or(Self::Ok),
+			outcome => outcome,
+		}
+	}
+
+	fn quit_rank(&self) -> u8 {
+		match self {
+			Self::ForceQuit => 2,
+			Self::Quit => 1,
+			Self::Ok | Self::Pipeline(_) => 0,
+		}
+	}
+}
+
+/// User answer to a [`CommandContext::confirm`] prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationResult {
+	/// The user approved the operation.
+	Accept,
+	/// The user rejected the operation; the command should stop without side effects.
+	Decline,
 }
 
 /// Editor operations available to commands.
@@ -87,10 +131,15 @@ pub trait CommandEditorOps {
 	fn set_option(&mut self, key: &str, value: &str) -> Result<(), CommandError>;
 	/// Sets a buffer-local option value by config key.
 	fn set_local_option(&mut self, key: &str, value: &str) -> Result<(), CommandError>;
+	/// Describes the effective value of option `key` for the current buffer and
+	/// where it came from, e.g. `2 (buffer-local, set at runtime)`.
+	fn describe_option(&self, key: &str) -> Result<String, CommandError>;
 	/// Opens an info popup with the given content and optional file type for syntax highlighting.
 	fn open_info_popup(&mut self, content: &str, file_type: Option<&str>);
 	/// Closes all open info popups.
 	fn close_all_info_popups(&mut self);
+	/// Returns up to `limit` command-line history entries, newest first.
+	fn recent_commands(&self, limit: usize) -> Vec<String>;
 	/// Inserts a snippet body at the current cursor/selection.
 	///
 	/// Returns `true` when insertion succeeds.
@@ -102,6 +151,20 @@ pub trait CommandEditorOps {
 	fn goto_file(&mut self, path: PathBuf, line: usize, column: usize) -> BoxFutureLocal<'_, Result<(), CommandError>>;
 	/// Queues an invocation request for execution on the editor runtime loop.
 	fn queue_invocation(&mut self, request: crate::actions::DeferredInvocationRequest);
+	/// Asks the user to confirm an operation described by `prompt`.
+	///
+	/// Editors that cannot wait for the answer inside the command may decline,
+	/// then re-run the command once the user accepts.
+	fn confirm(&mut self, prompt: &str) -> BoxFutureLocal<'_, ConfirmationResult>;
+	/// Returns whether a [`Self::confirm`] call declined to wait for an answer
+	/// that the editor will ask for once the command returns.
+	fn confirmation_pending(&self) -> bool;
+	/// Starts `task` in the background and tracks it under `name` until it finishes.
+	fn spawn_background(&mut self, name: String, task: BackgroundTask) -> TaskId;
+	/// Lists running background tasks, oldest first.
+	fn background_tasks(&self) -> Vec<TaskInfo>;
+	/// Requests cancellation of background task `id`; returns `false` if it is not running.
+	fn cancel_background(&mut self, id: TaskId) -> bool;
 }
 
 /// Context provided to command handlers.
@@ -110,12 +173,18 @@ pub struct CommandContext<'a> {
 	pub editor: &'a mut dyn CommandEditorOps,
 	/// Command arguments (space-separated tokens after command name).
 	pub args: &'a [&'a str],
+	/// `args` validated against the command's palette arg spec.
+	pub parsed: &'a ParsedArgs,
 	/// Numeric prefix count (e.g., `3:w` has count=3).
 	pub count: usize,
 	/// Register specified with command (e.g., `"a:w`).
 	pub register: Option<char>,
+	/// Line range prefixed to the command name (e.g., `:10,20indent`).
+	///
+	/// Only ever `Some` for commands that set [`flags::ACCEPTS_RANGE`].
+	pub range: Option<CommandRange>,
 	/// Extension-specific data attached to the command.
-	pub user_data: Option<&'static (dyn Any + Sync)>,
+	pub user_data: Option<&'static (dyn Any + Send + Sync)>,
 }
 
 impl<'a> CommandContext<'a> {
@@ -139,13 +208,37 @@ impl<'a> CommandContext<'a> {
 		self.editor.set_readonly(readonly);
 	}
 
+	/// Asks the user to confirm a destructive operation, resolving to `true` on accept.
+	///
+	/// Commands should return early without side effects when this resolves to `false`.
+	pub fn confirm(&mut self, prompt: &str) -> impl Future<Output = bool> {
+		let answer = self.editor.confirm(prompt);
+		async move { answer.await == ConfirmationResult::Accept }
+	}
+
+	/// Runs `body` in the background so the command can return immediately.
+	///
+	/// `body` receives a [`TaskHandle`] for progress and cancellation; the task
+	/// shows up in `:tasks` and its completion is reported as a notification.
+	pub fn spawn_background<F, Fut>(&mut self, name: impl Into<String>, body: F) -> TaskId
+	where
+		F: FnOnce(TaskHandle) -> Fut + Send + 'static,
+		Fut: Future<Output = Result<(), CommandError>> + Send + 'static,
+	{
+		self.editor.spawn_background(name.into(), background_task(body))
+	}
+
+	/// Downcasts the command's user data to `T`.
+	///
+	/// Returns `None` if the command has no user data or it is not a `T`.
+	pub fn user_data<T: Any>(&self) -> Option<&'static T> {
+		let any: &'static dyn Any = self.user_data?;
+		any.downcast_ref::<T>()
+	}
+
 	/// Extracts and downcasts user data to the expected type.
 	pub fn require_user_data<T: Any + Sync>(&self) -> Result<&'static T, CommandError> {
-		self.user_data
-			.and_then(|d| {
-				let any: &dyn Any = d;
-				any.downcast_ref::<T>()
-			})
+		self.user_data::<T>()
 			.ok_or_else(|| CommandError::Other(format!("Missing or invalid user data for command (expected {})", std::any::type_name::<T>())))
 	}
 }
@@ -154,6 +247,8 @@ impl<'a> CommandContext<'a> {
 pub mod flags {
 	/// No special flags.
 	pub const NONE: u32 = 0;
+	/// Command honors a line range prefix; without it, a range is rejected.
+	pub const ACCEPTS_RANGE: u32 = 1 << 0;
 }
 
 #[cfg(feature = "minimal")]
@@ -162,7 +257,7 @@ pub use crate::db::COMMANDS;
 /// Finds a command by name or key.
 #[cfg(feature = "minimal")]
 pub fn find_command(name: &str) -> Option<RegistryRef<CommandEntry, crate::core::CommandId>> {
-	COMMANDS.get(name)
+	crate::db::index::find_command(name)
 }
 
 /// Returns all registered commands (builtins + runtime), sorted by name.
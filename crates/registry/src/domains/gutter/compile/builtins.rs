@@ -1,6 +1,6 @@
 //! Built-in gutter column implementations.
 
-use crate::gutter::GutterCell;
+use crate::gutter::{GutterCell, LineChange};
 use crate::gutter_handler;
 
 gutter_handler!(line_numbers, |ctx| {
@@ -63,6 +63,32 @@ gutter_handler!(signs, |ctx| {
 	}
 });
 
+gutter_handler!(diagnostics, |ctx| {
+	if ctx.is_continuation {
+		return None;
+	}
+	let colors = &ctx.theme.colors.semantic;
+	match ctx.annotations.diagnostics.highest_severity() {
+		4 => Some(GutterCell::new("✘", Some(colors.error), false)),
+		3 => Some(GutterCell::new("▲", Some(colors.warning), false)),
+		2 => Some(GutterCell::new("●", Some(colors.info), false)),
+		1 => Some(GutterCell::new("◆", Some(colors.hint), false)),
+		_ => None,
+	}
+});
+
+gutter_handler!(git_signs, |ctx| {
+	if ctx.is_continuation {
+		return None;
+	}
+	let colors = &ctx.theme.colors.semantic;
+	match ctx.annotations.diff?.get(ctx.line_idx)? {
+		LineChange::Added => Some(GutterCell::new("▍", Some(colors.success), false)),
+		LineChange::Modified => Some(GutterCell::new("▍", Some(colors.warning), false)),
+		LineChange::RemovedAbove => Some(GutterCell::new("▔", Some(colors.error), false)),
+	}
+});
+
 pub fn register_builtins(builder: &mut crate::db::builder::RegistryDbBuilder) {
 	crate::gutter::register_compiled(builder);
 }
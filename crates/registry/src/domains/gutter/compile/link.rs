@@ -52,10 +52,12 @@ pub fn link_gutters(spec: &GuttersSpec, handlers: impl Iterator<Item = &'static
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.name.clone(),
 				},
 				payload: GutterPayload {
@@ -0,0 +1,128 @@
+//! Sparse per-line gutter cells from a single annotation source.
+
+use std::collections::BTreeMap;
+
+use super::GutterCell;
+
+/// How [`GutterAnnotationMap::merge`] settles a line annotated by both sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+	/// Keep only the primary source's cells.
+	PrimaryWins,
+	/// Keep only the secondary source's cells.
+	SecondaryWins,
+	/// Keep the primary source's cells followed by the secondary's.
+	Concatenate,
+}
+
+/// Gutter cells keyed by 0-based line, as produced by one source such as LSP
+/// diagnostics, blame or coverage.
+///
+/// Lines without cells are not stored, so iteration only visits annotated lines.
+#[derive(Debug, Clone, Default)]
+pub struct GutterAnnotationMap {
+	lines: BTreeMap<usize, Vec<GutterCell>>,
+}
+
+impl GutterAnnotationMap {
+	/// Creates an empty map.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends `cell` to the cells of `line`.
+	pub fn push(&mut self, line: usize, cell: GutterCell) {
+		self.lines.entry(line).or_default().push(cell);
+	}
+
+	/// Returns the cells of `line`, empty if it has none.
+	pub fn get(&self, line: usize) -> &[GutterCell] {
+		self.lines.get(&line).map_or(&[], Vec::as_slice)
+	}
+
+	/// Removes every cell of `line`.
+	pub fn clear_line(&mut self, line: usize) {
+		self.lines.remove(&line);
+	}
+
+	/// Returns the number of annotated lines.
+	pub fn len(&self) -> usize {
+		self.lines.len()
+	}
+
+	/// Returns `true` if no line is annotated.
+	pub fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+
+	/// Iterates annotated lines in ascending order, skipping lines without cells.
+	pub fn lines_with_annotations(&self) -> impl Iterator<Item = (usize, &[GutterCell])> {
+		self.lines.iter().map(|(&line, cells)| (line, cells.as_slice()))
+	}
+
+	/// Combines two sources; lines annotated by only one source keep its cells.
+	pub fn merge(primary: &Self, secondary: &Self, strategy: MergeStrategy) -> Self {
+		let mut lines = primary.lines.clone();
+		for (&line, cells) in &secondary.lines {
+			match lines.get_mut(&line) {
+				None => {
+					lines.insert(line, cells.clone());
+				}
+				Some(existing) => match strategy {
+					MergeStrategy::PrimaryWins => {}
+					MergeStrategy::SecondaryWins => *existing = cells.clone(),
+					MergeStrategy::Concatenate => existing.extend(cells.iter().cloned()),
+				},
+			}
+		}
+		Self { lines }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn source(cells: &[(usize, &str)]) -> GutterAnnotationMap {
+		let mut map = GutterAnnotationMap::new();
+		for &(line, text) in cells {
+			map.push(line, GutterCell::new(text, None, false));
+		}
+		map
+	}
+
+	fn texts(map: &GutterAnnotationMap) -> Vec<(usize, Vec<&str>)> {
+		map.lines_with_annotations()
+			.map(|(line, cells)| (line, cells.iter().map(|cell| cell.segments[0].text.as_str()).collect()))
+			.collect()
+	}
+
+	#[test]
+	fn merge_strategies_settle_shared_lines() {
+		let lsp = source(&[(1, "E"), (4, "W")]);
+		let blame = source(&[(4, "ab12"), (9, "cd34")]);
+
+		assert_eq!(
+			texts(&GutterAnnotationMap::merge(&lsp, &blame, MergeStrategy::PrimaryWins)),
+			[(1, vec!["E"]), (4, vec!["W"]), (9, vec!["cd34"])]
+		);
+		assert_eq!(
+			texts(&GutterAnnotationMap::merge(&lsp, &blame, MergeStrategy::SecondaryWins)),
+			[(1, vec!["E"]), (4, vec!["ab12"]), (9, vec!["cd34"])]
+		);
+		assert_eq!(
+			texts(&GutterAnnotationMap::merge(&lsp, &blame, MergeStrategy::Concatenate)),
+			[(1, vec!["E"]), (4, vec!["W", "ab12"]), (9, vec!["cd34"])]
+		);
+	}
+
+	#[test]
+	fn sparse_iteration_skips_cleared_lines() {
+		let mut map = source(&[(0, "a"), (2, "b"), (2, "c")]);
+		map.clear_line(0);
+		assert_eq!(texts(&map), [(2, vec!["b", "c"])]);
+		assert_eq!(map.len(), 1);
+		assert!(map.get(0).is_empty());
+		assert!(GutterAnnotationMap::merge(&GutterAnnotationMap::new(), &GutterAnnotationMap::new(), MergeStrategy::Concatenate).is_empty());
+	}
+}
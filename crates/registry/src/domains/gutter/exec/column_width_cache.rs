@@ -0,0 +1,151 @@
+//! Memoized gutter column widths for an unchanged buffer and viewport.
+
+use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use super::GutterWidthContext;
+
+thread_local! {
+	/// Per-thread cache used by [`super::total_width`] and [`super::column_widths`].
+	pub(super) static THREAD_CACHE: RefCell<GutterWidthCache> = RefCell::new(GutterWidthCache::default());
+}
+
+/// Widths of the enabled gutters, by name, for the context they were computed in.
+#[derive(Debug, Clone, Default)]
+pub struct GutterWidthCache {
+	/// Hash of the [`GutterWidthContext`] and gutter registry generation the widths
+	/// belong to; `0` before the first fill.
+	pub context_hash: u64,
+	/// Enabled gutter names and their widths, in registry order.
+	pub widths: Vec<(String, u16)>,
+}
+
+fn context_hash(context: &GutterWidthContext, generation: u64) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	context.hash(&mut hasher);
+	generation.hash(&mut hasher);
+	// Keep 0 free for the empty cache.
+	hasher.finish().max(1)
+}
+
+/// Returns the enabled gutter widths for `context`, recomputing them only when
+/// `context` or the set of registered gutters differs from the one `cache` was
+/// filled for.
+#[cfg(feature = "minimal")]
+pub fn column_widths_cached<'c>(context: &GutterWidthContext, cache: &'c mut GutterWidthCache) -> &'c [(String, u16)] {
+	cached_with(context, super::GUTTERS.generation(), cache, |context| {
+		super::enabled_gutters()
+			.into_iter()
+			.map(|gutter| (gutter.name_str().to_string(), super::column_width(&gutter, context)))
+			.collect()
+	})
+}
+
+fn cached_with<'c>(
+	context: &GutterWidthContext,
+	generation: u64,
+	cache: &'c mut GutterWidthCache,
+	compute: impl FnOnce(&GutterWidthContext) -> Vec<(String, u16)>,
+) -> &'c [(String, u16)] {
+	let hash = context_hash(context, generation);
+	if cache.context_hash != hash {
+		cache.widths = compute(context);
+		cache.context_hash = hash;
+	}
+	&cache.widths
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+	use crate::gutter::{GUTTERS, GutterDef, GutterInput, GutterWidth, RegistryMetaStatic, RegistrySource};
+
+	fn ctx(total_lines: usize, viewport_width: u16) -> GutterWidthContext {
+		GutterWidthContext { total_lines, viewport_width }
+	}
+
+	#[test]
+	fn hits_skip_the_width_computation() {
+		let mut cache = GutterWidthCache::default();
+		let mut calls = 0;
+		let mut compute = |context: &GutterWidthContext| {
+			calls += 1;
+			vec![("line_numbers".to_string(), context.total_lines.to_string().len() as u16)]
+		};
+
+		assert_eq!(cached_with(&ctx(120, 80), 0, &mut cache, &mut compute), [("line_numbers".to_string(), 3)]);
+		assert_eq!(cached_with(&ctx(120, 80), 0, &mut cache, &mut compute), [("line_numbers".to_string(), 3)]);
+		assert_eq!(calls, 1);
+	}
+
+	#[test]
+	fn changing_any_context_field_invalidates() {
+		let mut cache = GutterWidthCache::default();
+		let mut calls = 0;
+		let mut compute = |_: &GutterWidthContext| {
+			calls += 1;
+			Vec::new()
+		};
+
+		cached_with(&ctx(120, 80), 0, &mut cache, &mut compute);
+		cached_with(&ctx(12_000, 80), 0, &mut cache, &mut compute);
+		cached_with(&ctx(12_000, 100), 0, &mut cache, &mut compute);
+		cached_with(&ctx(12_000, 100), 0, &mut cache, &mut compute);
+		cached_with(&ctx(12_000, 100), 1, &mut cache, &mut compute);
+		assert_eq!(calls, 4);
+	}
+
+	fn no_cell(_: &crate::gutter::GutterLineContext) -> Option<crate::gutter::GutterCell> {
+		None
+	}
+
+	static RUNTIME_GUTTER: GutterDef = GutterDef {
+		meta: RegistryMetaStatic {
+			id: "registry::gutter::width_cache_test",
+			name: "width_cache_test",
+			keys: &[],
+			categories: &[],
+			description: "width cache test gutter",
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		default_enabled: true,
+		width: GutterWidth::Fixed(3),
+		render: no_cell,
+	};
+
+	fn uncached(context: &GutterWidthContext) -> Vec<(String, u16)> {
+		crate::gutter::enabled_gutters()
+			.into_iter()
+			.map(|gutter| (gutter.name_str().to_string(), crate::gutter::column_width(&gutter, context)))
+			.collect()
+	}
+
+	// Registration is global, so this one test covers both the plain and the
+	// registering case rather than racing a sibling test over the enabled set.
+	#[test]
+	fn registry_widths_match_uncached_widths() {
+		let context = ctx(12_345, 80);
+		let mut cache = GutterWidthCache::default();
+
+		assert_eq!(column_widths_cached(&context, &mut cache), uncached(&context).as_slice());
+		assert!(cache.widths.iter().any(|(name, width)| name == "line_numbers" && *width == 5));
+
+		GUTTERS.replace(Arc::new(GutterInput::Static(RUNTIME_GUTTER)));
+		let registered = column_widths_cached(&context, &mut cache).to_vec();
+		GUTTERS.remove_canonical(RUNTIME_GUTTER.meta.id, |_| true);
+
+		assert!(registered.contains(&("width_cache_test".to_string(), 3)), "{registered:?}");
+		assert_eq!(column_widths_cached(&context, &mut cache), uncached(&context).as_slice());
+
+		let columns: Vec<_> = crate::gutter::column_widths(&context)
+			.into_iter()
+			.map(|(width, gutter)| (gutter.name_str().to_string(), width))
+			.collect();
+		assert_eq!(columns, uncached(&context), "column widths served from the thread cache");
+	}
+}
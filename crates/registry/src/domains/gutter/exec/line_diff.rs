@@ -0,0 +1,207 @@
+//! Line-level diff of a document against a base revision, for change signs.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use ropey::Rope;
+
+/// How a line differs from the base revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineChange {
+	/// The line is not in the base.
+	Added,
+	/// The line replaces a base line.
+	Modified,
+	/// Base lines were deleted directly above this line.
+	RemovedAbove,
+}
+
+/// Changed lines of a document keyed by 0-based line; unchanged lines are not stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineDiff {
+	lines: BTreeMap<usize, LineChange>,
+}
+
+impl LineDiff {
+	/// Returns the change on `line`, if any.
+	pub fn get(&self, line: usize) -> Option<LineChange> {
+		self.lines.get(&line).copied()
+	}
+
+	/// Returns the number of changed lines.
+	pub fn len(&self) -> usize {
+		self.lines.len()
+	}
+
+	/// Returns `true` if the document matches its base.
+	pub fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+
+	/// Iterates changed lines in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = (usize, LineChange)> + '_ {
+		self.lines.iter().map(|(&line, &change)| (line, change))
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+	Equal,
+	Delete,
+	Insert,
+}
+
+/// Diffs the lines of `new` against `old` with Myers' algorithm.
+///
+/// Within a hunk, new lines pair up with deleted lines as [`LineChange::Modified`]
+/// and the surplus is [`LineChange::Added`]. A pure deletion marks the line
+/// after it as [`LineChange::RemovedAbove`], or the last line when the deletion
+/// is at the end of the document. Lines compare without their line endings, so
+/// dropping the final newline does not mark the last line as modified.
+pub fn compute_line_diff(old: &Rope, new: &Rope) -> LineDiff {
+	let old_lines: Vec<Cow<str>> = old.lines().map(Cow::from).collect();
+	let new_lines: Vec<Cow<str>> = new.lines().map(Cow::from).collect();
+	let old_lines: Vec<&str> = old_lines.iter().map(|line| line.trim_end_matches(['\n', '\r'])).collect();
+	let new_lines: Vec<&str> = new_lines.iter().map(|line| line.trim_end_matches(['\n', '\r'])).collect();
+
+	let prefix = old_lines.iter().zip(&new_lines).take_while(|(a, b)| a == b).count();
+	let suffix = old_lines[prefix..]
+		.iter()
+		.rev()
+		.zip(new_lines[prefix..].iter().rev())
+		.take_while(|(a, b)| a == b)
+		.count();
+	let ops = myers(&old_lines[prefix..old_lines.len() - suffix], &new_lines[prefix..new_lines.len() - suffix]);
+
+	let mut diff = LineDiff::default();
+	let mut line = prefix;
+	let mut ops = ops.into_iter().peekable();
+	while let Some(op) = ops.next() {
+		if op == Op::Equal {
+			line += 1;
+			continue;
+		}
+		let (mut deleted, mut inserted) = (0, 0);
+		let mut op = Some(op);
+		while let Some(current) = op.filter(|&op| op != Op::Equal) {
+			match current {
+				Op::Delete => deleted += 1,
+				_ => inserted += 1,
+			}
+			op = ops.next_if(|&op| op != Op::Equal);
+		}
+		for offset in 0..inserted {
+			let change = if offset < deleted { LineChange::Modified } else { LineChange::Added };
+			diff.lines.insert(line + offset, change);
+		}
+		line += inserted;
+		if inserted == 0 && !new_lines.is_empty() {
+			diff.lines.entry(line.min(new_lines.len() - 1)).or_insert(LineChange::RemovedAbove);
+		}
+	}
+	diff
+}
+
+/// Returns the shortest edit script turning `a` into `b`.
+fn myers<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Op> {
+	let (n, m) = (a.len() as isize, b.len() as isize);
+	let max = n + m;
+	let index = |k: isize| (k + max) as usize;
+	let mut v = vec![0isize; 2 * max as usize + 2];
+	let mut trace = Vec::new();
+
+	'search: for d in 0..=max {
+		trace.push(v.clone());
+		for k in (-d..=d).step_by(2) {
+			let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+				v[index(k + 1)]
+			} else {
+				v[index(k - 1)] + 1
+			};
+			let mut y = x - k;
+			while x < n && y < m && a[x as usize] == b[y as usize] {
+				x += 1;
+				y += 1;
+			}
+			v[index(k)] = x;
+			if x >= n && y >= m {
+				break 'search;
+			}
+		}
+	}
+
+	let mut ops = Vec::with_capacity((n + m) as usize);
+	let (mut x, mut y) = (n, m);
+	for (d, v) in trace.iter().enumerate().rev() {
+		let d = d as isize;
+		let k = x - y;
+		let prev_k = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+			k + 1
+		} else {
+			k - 1
+		};
+		let prev_x = v[index(prev_k)];
+		let prev_y = prev_x - prev_k;
+		while x > prev_x && y > prev_y {
+			ops.push(Op::Equal);
+			x -= 1;
+			y -= 1;
+		}
+		if d > 0 {
+			ops.push(if x == prev_x { Op::Insert } else { Op::Delete });
+		}
+		x = prev_x;
+		y = prev_y;
+	}
+	ops.reverse();
+	ops
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn diff(old: &str, new: &str) -> Vec<(usize, LineChange)> {
+		compute_line_diff(&Rope::from(old), &Rope::from(new)).iter().collect()
+	}
+
+	#[test]
+	fn identical_documents_have_no_changes() {
+		assert!(compute_line_diff(&Rope::from("a\nb\n"), &Rope::from("a\nb\n")).is_empty());
+	}
+
+	#[test]
+	fn insertion_hunks_mark_added_lines() {
+		assert_eq!(diff("a\nb\n", "a\nx\ny\nb\n"), [(1, LineChange::Added), (2, LineChange::Added)]);
+		assert_eq!(diff("a\n", "x\na\n"), [(0, LineChange::Added)]);
+		assert_eq!(diff("", "x\n"), [(0, LineChange::Added)]);
+	}
+
+	#[test]
+	fn deletion_hunks_mark_the_following_line() {
+		assert_eq!(diff("a\nb\nc\nd\n", "a\nd\n"), [(1, LineChange::RemovedAbove)]);
+		assert_eq!(diff("a\nb\n", "b\n"), [(0, LineChange::RemovedAbove)]);
+		assert_eq!(diff("a\nb", "a"), [(0, LineChange::RemovedAbove)], "deletion at the end marks the last line");
+	}
+
+	#[test]
+	fn modification_hunks_pair_old_and_new_lines() {
+		assert_eq!(diff("a\nb\nc\n", "a\nB\nc\n"), [(1, LineChange::Modified)]);
+		assert_eq!(
+			diff("a\nb\nc\n", "a\nB\nB2\nc\n"),
+			[(1, LineChange::Modified), (2, LineChange::Added)],
+			"surplus new lines are additions"
+		);
+		assert_eq!(diff("a\nb\nc\nd\n", "a\nX\nd\n"), [(1, LineChange::Modified)], "surplus deletions are absorbed");
+	}
+
+	#[test]
+	fn separate_hunks_are_reported_independently() {
+		let old = "1\n2\n3\n4\n5\n6\n7\n";
+		let new = "1\nnew\n2\n3\n5\n6\nsix\n";
+		assert_eq!(
+			diff(old, new),
+			[(1, LineChange::Added), (4, LineChange::RemovedAbove), (6, LineChange::Modified)]
+		);
+	}
+}
@@ -7,11 +7,17 @@ use ropey::RopeSlice;
 pub use crate::themes::Color;
 pub use crate::themes::theme::ThemeDef as Theme;
 
+#[path = "exec/annotations.rs"]
+mod annotations;
 #[path = "compile/builtins.rs"]
 pub mod builtins;
+#[path = "exec/column_width_cache.rs"]
+pub mod column_width_cache;
 mod domain;
 #[path = "exec/handler.rs"]
 pub mod handler;
+#[path = "exec/line_diff.rs"]
+mod line_diff;
 #[path = "compile/link.rs"]
 pub mod link;
 #[path = "compile/loader.rs"]
@@ -21,9 +27,14 @@ mod macros;
 #[path = "contract/spec.rs"]
 pub mod spec;
 
+pub use annotations::{GutterAnnotationMap, MergeStrategy};
 pub use builtins::register_builtins;
+pub use column_width_cache::GutterWidthCache;
+#[cfg(feature = "minimal")]
+pub use column_width_cache::column_widths_cached;
 pub use domain::Gutters;
 pub use handler::{GutterHandlerReg, GutterHandlerStatic};
+pub use line_diff::{LineChange, LineDiff, compute_line_diff};
 
 /// Registers compiled gutters from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -54,11 +65,11 @@ pub struct GutterLineContext<'a> {
 	pub is_continuation: bool,
 	pub line_text: RopeSlice<'a>,
 	pub path: Option<&'a Path>,
-	pub annotations: &'a GutterAnnotations,
+	pub annotations: &'a GutterAnnotations<'a>,
 	pub theme: &'a Theme,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GutterWidthContext {
 	pub total_lines: usize,
 	pub viewport_width: u16,
@@ -94,12 +105,62 @@ pub enum GutterWidth {
 	Dynamic(fn(&GutterWidthContext) -> u16),
 }
 
+/// Diagnostics on one line, counted by severity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiagnosticCounts {
+	pub errors: u16,
+	pub warnings: u16,
+	pub info: u16,
+	pub hints: u16,
+}
+
+impl DiagnosticCounts {
+	/// Counts one diagnostic of gutter `severity` (4 = error down to 1 = hint).
+	pub fn add(&mut self, severity: u8) {
+		let count = match severity {
+			4 => &mut self.errors,
+			3 => &mut self.warnings,
+			2 => &mut self.info,
+			1 => &mut self.hints,
+			_ => return,
+		};
+		*count = count.saturating_add(1);
+	}
+
+	/// Returns the highest gutter severity present, or 0 for none.
+	pub fn highest_severity(&self) -> u8 {
+		[(self.errors, 4), (self.warnings, 3), (self.info, 2), (self.hints, 1)]
+			.into_iter()
+			.find_map(|(count, severity)| (count > 0).then_some(severity))
+			.unwrap_or(0)
+	}
+
+	pub fn total(&self) -> u32 {
+		[self.errors, self.warnings, self.info, self.hints].into_iter().map(u32::from).sum()
+	}
+}
+
+impl FromIterator<u8> for DiagnosticCounts {
+	fn from_iter<I: IntoIterator<Item = u8>>(severities: I) -> Self {
+		let mut counts = Self::default();
+		for severity in severities {
+			counts.add(severity);
+		}
+		counts
+	}
+}
+
 #[derive(Debug, Clone, Default)]
-pub struct GutterAnnotations {
+pub struct GutterAnnotations<'a> {
+	/// Highest diagnostic severity on the line (4 = error down to 1 = hint, 0 = none).
 	pub diagnostic_severity: u8,
+	/// Diagnostics on the line by severity.
+	pub diagnostics: DiagnosticCounts,
 	pub sign: Option<char>,
 	pub diff_old_line: Option<u32>,
 	pub diff_new_line: Option<u32>,
+	/// Changes of the whole document against its diff base, if one is tracked.
+	pub diff: Option<&'a LineDiff>,
 }
 
 #[derive(Clone, Copy)]
@@ -135,10 +196,12 @@ impl BuildEntry<GutterEntry> for GutterDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -188,13 +251,32 @@ pub fn column_width(gutter: &GutterEntry, ctx: &GutterWidthContext) -> u16 {
 	}
 }
 
+/// Returns the width of all enabled gutters plus the separator, reusing this
+/// thread's widths while `ctx` is unchanged.
 #[cfg(feature = "minimal")]
 pub fn total_width(ctx: &GutterWidthContext) -> u16 {
-	let width: u16 = enabled_gutters().iter().map(|g| column_width(g, ctx)).sum();
+	let width: u16 = column_width_cache::THREAD_CACHE.with_borrow_mut(|cache| column_widths_cached(ctx, cache).iter().map(|(_, width)| width).sum());
 	if width > 0 { width + 1 } else { 0 }
 }
 
+/// Returns each enabled gutter with its width, reusing this thread's widths
+/// while `ctx` is unchanged.
 #[cfg(feature = "minimal")]
 pub fn column_widths(ctx: &GutterWidthContext) -> Vec<(u16, RegistryRef<GutterEntry, GutterId>)> {
-	enabled_gutters().into_iter().map(|g| (column_width(&g, ctx), g)).collect()
+	column_width_cache::THREAD_CACHE.with_borrow_mut(|cache| {
+		let widths = column_widths_cached(ctx, cache);
+		enabled_gutters()
+			.into_iter()
+			.map(|g| {
+				let width = widths
+					.iter()
+					.find(|(name, _)| name == g.name_str())
+					.map_or_else(|| column_width(&g, ctx), |(_, width)| *width);
+				(width, g)
+			})
+			.collect()
+	})
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use ropey::Rope;
+
+use super::*;
+use crate::themes::DEFAULT_THEME;
+
+fn render(name: &str, annotations: &GutterAnnotations, is_continuation: bool) -> Option<GutterCell> {
+	let gutter = find(name).expect("gutter registered");
+	let text = Rope::from("let x = 1;\n");
+	let ctx = GutterLineContext {
+		line_idx: 0,
+		total_lines: 1,
+		cursor_line: 0,
+		is_cursor_line: true,
+		is_continuation,
+		line_text: text.line(0),
+		path: None,
+		annotations,
+		theme: &DEFAULT_THEME,
+	};
+	(gutter.render)(&ctx)
+}
+
+fn diagnostics(severities: &[u8]) -> GutterAnnotations {
+	GutterAnnotations {
+		diagnostics: severities.iter().copied().collect(),
+		..Default::default()
+	}
+}
+
+#[test]
+fn diagnostics_gutter_is_two_cells_wide() {
+	let gutter = find("diagnostics").expect("diagnostics gutter registered");
+	assert!(!gutter.default_enabled, "signs already show diagnostics by default");
+	for total_lines in [1, 100, 1_000_000] {
+		let ctx = GutterWidthContext {
+			total_lines,
+			viewport_width: 80,
+		};
+		assert_eq!(column_width(&gutter, &ctx), 2);
+	}
+}
+
+#[test]
+fn diagnostics_gutter_shows_highest_severity() {
+	let colors = &DEFAULT_THEME.colors.semantic;
+
+	let cell = render("diagnostics", &diagnostics(&[3, 4, 3, 1]), false).expect("error line");
+	assert_eq!(cell.segments[0].text, "✘", "error beats warning");
+	assert_eq!(cell.segments[0].fg, Some(colors.error));
+
+	let cell = render("diagnostics", &diagnostics(&[1, 3]), false).expect("warning line");
+	assert_eq!(cell.segments[0].text, "▲");
+	assert_eq!(cell.segments[0].fg, Some(colors.warning));
+
+	assert!(render("diagnostics", &diagnostics(&[]), false).is_none());
+	assert!(render("diagnostics", &diagnostics(&[4]), true).is_none(), "wrapped rows stay blank");
+}
+
+#[test]
+fn diagnostic_counts_aggregate_by_severity() {
+	let counts: DiagnosticCounts = [4, 3, 3, 2, 9].into_iter().collect();
+	assert_eq!(
+		counts,
+		DiagnosticCounts {
+			errors: 1,
+			warnings: 2,
+			info: 1,
+			hints: 0,
+		}
+	);
+	assert_eq!(counts.total(), 4, "unknown severities are ignored");
+	assert_eq!(counts.highest_severity(), 4);
+	assert_eq!(DiagnosticCounts::default().highest_severity(), 0);
+}
+
+#[test]
+fn git_signs_gutter_marks_changed_lines() {
+	let colors = &DEFAULT_THEME.colors.semantic;
+	let gutter = find("git_signs").expect("git_signs gutter registered");
+	let text = Rope::from("a\nB\nnew\nd\n");
+	let diff = compute_line_diff(&Rope::from("a\nb\nc\nd\ne\n"), &text);
+	let annotations = GutterAnnotations {
+		diff: Some(&diff),
+		..Default::default()
+	};
+	let cell = |line_idx: usize, is_continuation: bool| {
+		let ctx = GutterLineContext {
+			line_idx,
+			total_lines: text.len_lines(),
+			cursor_line: 0,
+			is_cursor_line: false,
+			is_continuation,
+			line_text: text.line(line_idx),
+			path: None,
+			annotations: &annotations,
+			theme: &DEFAULT_THEME,
+		};
+		(gutter.render)(&ctx).map(|cell| (cell.segments[0].text.clone(), cell.segments[0].fg))
+	};
+
+	assert_eq!(cell(0, false), None);
+	assert_eq!(cell(1, false), Some(("▍".to_string(), Some(colors.warning))));
+	assert_eq!(cell(2, false), Some(("▍".to_string(), Some(colors.warning))), "replaces `c`");
+	assert_eq!(cell(3, false), None);
+	assert_eq!(
+		cell(4, false),
+		Some(("▔".to_string(), Some(colors.error))),
+		"`e` was removed above the final line"
+	);
+	assert_eq!(cell(1, true), None, "wrapped rows stay blank");
+
+	let added = compute_line_diff(&Rope::from(""), &Rope::from("let x = 1;\n"));
+	let annotations = GutterAnnotations {
+		diff: Some(&added),
+		..Default::default()
+	};
+	let cell = render("git_signs", &annotations, false).expect("added line");
+	assert_eq!(cell.segments[0].fg, Some(colors.success));
+	assert!(render("git_signs", &GutterAnnotations::default(), false).is_none(), "no diff base, no signs");
+}
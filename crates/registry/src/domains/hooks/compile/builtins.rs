@@ -6,16 +6,17 @@ use ropey::RopeSlice;
 use xeno_primitives::Mode;
 
 use crate::hook_handler;
+use crate::hooks::{HookPhase, tags};
 
-hook_handler!(log_buffer_open, BufferOpen, |path: &Path, text: &RopeSlice, file_type: &Option<&str>| {
+hook_handler!(log_buffer_open, BufferOpen, tags: [tags::LOGGING], phase: HookPhase::Post, |path: &Path, text: &RopeSlice, file_type: &Option<&str>| {
 	tracing::info!("Buffer opened: path={:?} type={:?} size={}", path, file_type, text.len_chars());
 });
 
-hook_handler!(log_mode_change, ModeChange, |old_mode: &Mode, new_mode: &Mode| {
+hook_handler!(log_mode_change, ModeChange, tags: [tags::LOGGING], phase: HookPhase::Post, |old_mode: &Mode, new_mode: &Mode| {
 	tracing::info!("Mode changed: {:?} -> {:?}", old_mode, new_mode);
 });
 
-hook_handler!(log_option_change, OptionChanged, |key: &str, scope: &str| {
+hook_handler!(log_option_change, OptionChanged, tags: [tags::LOGGING], phase: HookPhase::Post, |key: &str, scope: &str| {
 	tracing::info!("Option changed: key={} scope={}", key, scope);
 });
 
@@ -2,7 +2,7 @@ use super::spec::HooksSpec;
 use crate::HookEvent;
 use crate::core::{LinkedDef, LinkedMetaOwned, LinkedPayload, RegistryMeta, RegistrySource, Symbol};
 use crate::hooks::handler::HookHandlerStatic;
-use crate::hooks::{HookEntry, HookHandler, HookMutability, HookPriority};
+use crate::hooks::{HookEntry, HookFilter, HookHandler, HookMutability, HookPhase, HookPriority, HookSchedule};
 
 pub type LinkedHookDef = LinkedDef<HookPayload>;
 
@@ -12,16 +12,29 @@ pub struct HookPayload {
 	pub mutability: HookMutability,
 	pub execution_priority: HookPriority,
 	pub handler: HookHandler,
+	pub filter: Option<HookFilter>,
+	pub schedule: HookSchedule,
+	pub phase: HookPhase,
+	pub tags: Vec<String>,
 }
 
 impl LinkedPayload<HookEntry> for HookPayload {
-	fn build_entry(&self, _ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> HookEntry {
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.tags.iter().map(String::as_str));
+	}
+
+	fn build_entry(&self, ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> HookEntry {
 		HookEntry {
 			meta,
 			event: self.event,
 			mutability: self.mutability,
 			execution_priority: self.execution_priority,
-			handler: self.handler,
+			handler: self.handler.clone(),
+			filter: self.filter,
+			schedule: self.schedule,
+			phase: self.phase,
+			tags: self.tags.iter().map(|tag| ctx.intern(tag)).collect::<Vec<_>>().into(),
+			schedule_state: Default::default(),
 		}
 	}
 }
@@ -52,17 +65,23 @@ pub fn link_hooks(spec: &HooksSpec, handlers: impl Iterator<Item = &'static Hook
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.short_desc.clone().unwrap_or_else(|| common.description.clone()),
 				},
 				payload: HookPayload {
 					event: handler.handler.event,
 					mutability: handler.handler.mutability,
 					execution_priority: handler.handler.execution_priority,
-					handler: handler.handler.handler,
+					handler: handler.handler.handler.clone(),
+					filter: handler.handler.filter,
+					schedule: handler.handler.schedule,
+					phase: handler.handler.phase,
+					tags: handler.handler.tags.iter().map(|tag| tag.to_string()).collect(),
 				},
 			}
 		},
@@ -1,6 +1,11 @@
-//! Hook type definitions: HookDef, HookAction, HookResult.
+//! Hook type definitions: HookDef, HookAction, HookResult, HookFilter, HookSchedule, HookPhase, tags.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 use super::context::{HookContext, MutableHookContext};
+use super::schedule::ScheduleState;
 use crate::HookEvent;
 use crate::core::index::{BuildEntry, RegistryMetaRef, StrListRef};
 pub use crate::core::{RegistryEntry, RegistryMeta, RegistryMetaStatic, Symbol};
@@ -12,11 +17,42 @@ pub enum HookPriority {
 	Background,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Coarse ordering stage for hooks of one event.
+///
+/// Emit functions run every `Pre` hook before any `Main` hook and every `Main`
+/// hook before any `Post` hook; registry priority only orders hooks within a
+/// phase. A `Post` hook therefore observes the effects of all `Main` hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum HookPhase {
+	/// Guards and setup that must run first.
+	Pre,
+	#[default]
+	Main,
+	/// Observers of the final state.
+	Post,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum HookResult {
 	#[default]
 	Continue,
-	Cancel,
+	/// Stops the operation; `reason` is shown to the user when present.
+	Cancel { reason: Option<String> },
+}
+
+impl HookResult {
+	/// Returns true for [`HookResult::Cancel`].
+	pub fn is_cancel(&self) -> bool {
+		matches!(self, Self::Cancel { .. })
+	}
+
+	/// Returns the cancel reason, if any.
+	pub fn reason(&self) -> Option<&str> {
+		match self {
+			Self::Cancel { reason } => reason.as_deref(),
+			Self::Continue => None,
+		}
+	}
 }
 
 pub type HookFuture = xeno_primitives::BoxFutureStatic<HookResult>;
@@ -31,8 +67,10 @@ impl HookAction {
 		HookAction::Done(HookResult::Continue)
 	}
 
-	pub fn cancel() -> Self {
-		HookAction::Done(HookResult::Cancel)
+	pub fn cancel(reason: Option<&str>) -> Self {
+		HookAction::Done(HookResult::Cancel {
+			reason: reason.map(str::to_owned),
+		})
 	}
 }
 
@@ -54,20 +92,103 @@ pub enum HookMutability {
 	Mutable,
 }
 
-#[derive(Clone, Copy)]
+/// Closure handler for hooks registered at runtime.
+pub type DynHookHandler = Arc<dyn Fn(&HookContext) -> HookAction + Send + Sync>;
+
+#[derive(Clone)]
 pub enum HookHandler {
 	Immutable(fn(&HookContext) -> HookAction),
 	Mutable(fn(&mut MutableHookContext) -> HookAction),
+	/// Immutable closure supplied through [`register_runtime`](crate::hooks::register_runtime).
+	Dynamic(DynHookHandler),
+}
+
+impl HookHandler {
+	/// Returns the callable for immutable emit paths, or `None` for mutable handlers.
+	pub fn immutable(&self) -> Option<&(dyn Fn(&HookContext) -> HookAction + Send + Sync)> {
+		match self {
+			Self::Immutable(handler) => Some(handler),
+			Self::Dynamic(handler) => Some(handler.as_ref()),
+			Self::Mutable(_) => None,
+		}
+	}
+}
+
+/// Declarative event filter evaluated before a hook handler runs.
+///
+/// Constraints are ANDed: a hook with both a glob and file types only runs when
+/// the event's path matches the glob and its file type is listed. Events that
+/// carry no path (or no file type) never satisfy the corresponding constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HookFilter {
+	/// Glob matched against the file name, or the full path when it contains `/`.
+	pub path_glob: Option<&'static str>,
+	/// Accepted file types; empty accepts any.
+	pub file_types: &'static [&'static str],
+}
+
+impl HookFilter {
+	/// Returns true if an event with `path` and `file_type` passes this filter.
+	pub fn matches(&self, path: Option<&Path>, file_type: Option<&str>) -> bool {
+		if let Some(pattern) = self.path_glob {
+			let Some(path) = path else {
+				return false;
+			};
+			let filename = path.file_name().and_then(|name| name.to_str());
+			if !crate::domains::shared::matchers::glob_matches(pattern, &path.to_string_lossy(), filename) {
+				return false;
+			}
+		}
+		self.file_types.is_empty() || file_type.is_some_and(|ft| self.file_types.contains(&ft))
+	}
+}
+
+/// How often a hook may run, enforced by the emit functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HookSchedule {
+	/// Runs for every matching event.
+	#[default]
+	Always,
+	/// Runs for the first matching event only.
+	Once,
+	/// Runs at most once per `window` for each buffer path (events without a path share one slot).
+	///
+	/// With `trailing`, the last event skipped inside a window is replayed once the
+	/// window expires. Only [`emit_sync_with`](crate::hooks::emit_sync_with) can
+	/// schedule that replay; other emit paths just skip, but still refresh the
+	/// payload of an already scheduled replay.
+	Debounce { window: Duration, trailing: bool },
+}
+
+/// Standard hook tags for documentation grouping and [`find_hooks_by_tag`](crate::hooks::find_hooks_by_tag).
+///
+/// Hooks may carry other tags; these are the ones built-in hooks use.
+pub mod tags {
+	pub const LSP: &str = "lsp";
+	pub const GIT: &str = "git";
+	pub const FORMATTING: &str = "formatting";
+	pub const INDEXING: &str = "indexing";
+	pub const DIAGNOSTIC: &str = "diagnostic";
+	pub const LOGGING: &str = "logging";
+
+	/// All standard tags.
+	pub const STANDARD: &[&str] = &[LSP, GIT, FORMATTING, INDEXING, DIAGNOSTIC, LOGGING];
 }
 
 /// A hook that responds to editor events (static input).
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct HookDef {
 	pub meta: RegistryMetaStatic,
 	pub event: HookEvent,
 	pub mutability: HookMutability,
 	pub execution_priority: HookPriority,
 	pub handler: HookHandler,
+	/// Skips the handler for events that do not match.
+	pub filter: Option<HookFilter>,
+	pub schedule: HookSchedule,
+	pub phase: HookPhase,
+	/// Grouping tags; see [`tags`].
+	pub tags: &'static [&'static str],
 }
 
 impl std::fmt::Debug for HookDef {
@@ -77,6 +198,10 @@ impl std::fmt::Debug for HookDef {
 			.field("event", &self.event)
 			.field("mutability", &self.mutability)
 			.field("execution_priority", &self.execution_priority)
+			.field("filter", &self.filter)
+			.field("schedule", &self.schedule)
+			.field("phase", &self.phase)
+			.field("tags", &self.tags)
 			.finish()
 	}
 }
@@ -88,6 +213,19 @@ pub struct HookEntry {
 	pub mutability: HookMutability,
 	pub execution_priority: HookPriority,
 	pub handler: HookHandler,
+	pub filter: Option<HookFilter>,
+	pub schedule: HookSchedule,
+	pub phase: HookPhase,
+	pub tags: Arc<[Symbol]>,
+	/// Once/debounce bookkeeping; fresh for every built entry.
+	pub(super) schedule_state: ScheduleState,
+}
+
+impl HookEntry {
+	/// Returns true if this hook should run for an event with `path` and `file_type`.
+	pub fn accepts(&self, path: Option<&Path>, file_type: Option<&str>) -> bool {
+		self.filter.is_none_or(|filter| filter.matches(path, file_type))
+	}
 }
 
 crate::impl_registry_entry!(HookEntry);
@@ -98,10 +236,12 @@ impl BuildEntry<HookEntry> for HookDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -109,7 +249,9 @@ impl BuildEntry<HookEntry> for HookDef {
 		self.meta.name
 	}
 
-	fn collect_payload_strings<'b>(&'b self, _collector: &mut crate::core::index::StringCollector<'_, 'b>) {}
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.tags.iter().copied());
+	}
 
 	fn build(&self, ctx: &mut dyn crate::core::index::BuildCtx, key_pool: &mut Vec<Symbol>) -> HookEntry {
 		let meta = crate::core::index::meta_build::build_meta(ctx, key_pool, self.meta_ref(), []);
@@ -119,7 +261,12 @@ impl BuildEntry<HookEntry> for HookDef {
 			event: self.event,
 			mutability: self.mutability,
 			execution_priority: self.execution_priority,
-			handler: self.handler,
+			handler: self.handler.clone(),
+			filter: self.filter,
+			schedule: self.schedule,
+			phase: self.phase,
+			tags: ctx.intern_slice(self.tags),
+			schedule_state: ScheduleState::default(),
 		}
 	}
 }
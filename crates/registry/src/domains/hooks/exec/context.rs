@@ -5,6 +5,7 @@ use std::path::Path;
 use xeno_primitives::Rope;
 pub use xeno_primitives::ViewId;
 
+use super::types::HookFuture;
 use crate::{HookEvent, HookEventData, OwnedHookContext};
 
 /// Identifier for a window in hook payloads.
@@ -60,6 +61,21 @@ impl<'a> HookContext<'a> {
 	pub fn to_owned(&self) -> OwnedHookContext {
 		self.data.to_owned()
 	}
+
+	/// Queues `fut` to run after the current sync phase, at the running hook's priority.
+	///
+	/// Only takes effect inside [`emit_sync_with`](super::emit_sync_with); elsewhere
+	/// the future is dropped with a warning and `false` is returned.
+	pub fn spawn(&self, fut: HookFuture) -> bool {
+		let queued = super::spawn::spawn(fut);
+		if !queued {
+			tracing::warn!(
+				event = self.event().as_str(),
+				"HookContext::spawn called outside emit_sync_with; dropping future"
+			);
+		}
+		queued
+	}
 }
 
 /// Mutable context passed to mutable hook handlers.
@@ -1,39 +1,80 @@
 //! Hook emission functions for triggering hooks on events.
+//!
+//! Hooks run phase by phase ([`HookPhase`](super::HookPhase)), and by registry
+//! priority within a phase. Hooks whose [`HookFilter`](super::HookFilter) rejects the event's path or
+//! file type are skipped before their handler runs, as are runs suppressed by
+//! the hook's [`HookSchedule`](super::HookSchedule) (see [`super::schedule`]).
+//! Every handler call is timed through [`super::stats`].
+
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 use tracing::warn;
 
 use super::context::{HookContext, MutableHookContext};
-use super::hooks_for_event;
+use super::schedule::{Admission, admit, trailing_run};
+use super::spawn::{self, SharedHookScheduler};
+use super::stats::{HookRun, record};
 use super::types::{HookAction, HookFuture, HookHandler, HookMutability, HookPriority, HookResult};
+use super::{HooksRef, hooks_for_event_sorted};
+
+/// Result of the `*_with_outcome` emit functions, naming the hook that cancelled.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EmitOutcome {
+	pub result: HookResult,
+	/// Canonical ID of the hook that cancelled, if any.
+	pub cancelled_by: Option<String>,
+}
+
+impl EmitOutcome {
+	/// Returns true if a hook cancelled the event.
+	pub fn is_cancelled(&self) -> bool {
+		self.result.is_cancel()
+	}
+
+	/// Returns the reason given by the cancelling hook, if any.
+	pub fn reason(&self) -> Option<&str> {
+		self.result.reason()
+	}
+}
 
 /// Emit an event to all registered hooks.
 ///
-/// Hooks are executed in priority order (lower priority runs first).
+/// Hooks are executed phase by phase, in priority order within each phase
+/// (lower priority runs first).
 /// Sync hooks complete immediately; async hooks are awaited in sequence.
 ///
 /// Returns [`HookResult::Cancel`] if any hook cancels, otherwise [`HookResult::Continue`].
 pub async fn emit(ctx: &HookContext<'_>) -> HookResult {
-	let event = ctx.event();
-	let mut matching = hooks_for_event(event);
-	matching.sort_by_key(|h| h.meta.priority);
+	emit_with_outcome(ctx).await.result
+}
 
-	for hook in matching {
-		if hook.mutability != HookMutability::Immutable {
+/// Like [`emit`], but also reports which hook cancelled.
+pub async fn emit_with_outcome(ctx: &HookContext<'_>) -> EmitOutcome {
+	for hook in hooks_for_event_sorted(ctx.event()) {
+		if hook.mutability != HookMutability::Immutable || !hook.accepts(ctx.data.path(), ctx.data.file_type()) || !admit_immutable(&hook, ctx, None) {
 			continue;
 		}
-		let handler = match hook.handler {
-			HookHandler::Immutable(handler) => handler,
-			HookHandler::Mutable(_) => continue,
+		let Some(handler) = hook.handler.immutable() else {
+			continue;
 		};
+		let start = Instant::now();
 		let result = match handler(ctx) {
-			HookAction::Done(result) => result,
-			HookAction::Async(fut) => fut.await,
+			HookAction::Done(result) => {
+				record(&hook, HookRun::Sync, start.elapsed());
+				result
+			}
+			HookAction::Async(fut) => {
+				let result = fut.await;
+				record(&hook, HookRun::Async, start.elapsed());
+				result
+			}
 		};
-		if result == HookResult::Cancel {
-			return HookResult::Cancel;
+		if result.is_cancel() {
+			return cancelled(&hook, result);
 		}
 	}
-	HookResult::Continue
+	EmitOutcome::default()
 }
 
 /// Emit an event synchronously, ignoring any async hooks.
@@ -41,22 +82,25 @@ pub async fn emit(ctx: &HookContext<'_>) -> HookResult {
 /// This is useful in contexts where async is not available. Async hooks
 /// will log a warning and be skipped.
 pub fn emit_sync(ctx: &HookContext<'_>) -> HookResult {
-	let event = ctx.event();
-	let mut matching = hooks_for_event(event);
-	matching.sort_by_key(|h| h.meta.priority);
+	emit_sync_with_outcome(ctx).result
+}
 
-	for hook in matching {
-		if hook.mutability != HookMutability::Immutable {
+/// Like [`emit_sync`], but also reports which hook cancelled.
+pub fn emit_sync_with_outcome(ctx: &HookContext<'_>) -> EmitOutcome {
+	for hook in hooks_for_event_sorted(ctx.event()) {
+		if hook.mutability != HookMutability::Immutable || !hook.accepts(ctx.data.path(), ctx.data.file_type()) || !admit_immutable(&hook, ctx, None) {
 			continue;
 		}
-		let handler = match hook.handler {
-			HookHandler::Immutable(handler) => handler,
-			HookHandler::Mutable(_) => continue,
+		let Some(handler) = hook.handler.immutable() else {
+			continue;
 		};
-		match handler(ctx) {
+		let start = Instant::now();
+		let action = handler(ctx);
+		record(&hook, HookRun::Sync, start.elapsed());
+		match action {
 			HookAction::Done(result) => {
-				if result == HookResult::Cancel {
-					return HookResult::Cancel;
+				if result.is_cancel() {
+					return cancelled(&hook, result);
 				}
 			}
 			HookAction::Async(_) => {
@@ -64,34 +108,45 @@ pub fn emit_sync(ctx: &HookContext<'_>) -> HookResult {
 			}
 		}
 	}
-	HookResult::Continue
+	EmitOutcome::default()
 }
 
 /// Emit a mutable event to all registered mutable hooks.
 ///
 /// Returns [`HookResult::Cancel`] if any hook cancels, otherwise [`HookResult::Continue`].
 pub async fn emit_mutable(ctx: &mut MutableHookContext<'_>) -> HookResult {
-	let event = ctx.event;
-	let mut matching = hooks_for_event(event);
-	matching.sort_by_key(|h| h.meta.priority);
+	emit_mutable_with_outcome(ctx).await.result
+}
 
-	for hook in matching {
-		if hook.mutability != HookMutability::Mutable {
+/// Like [`emit_mutable`], but also reports which hook cancelled.
+pub async fn emit_mutable_with_outcome(ctx: &mut MutableHookContext<'_>) -> EmitOutcome {
+	for hook in hooks_for_event_sorted(ctx.event) {
+		if hook.mutability != HookMutability::Mutable
+			|| !hook.accepts(ctx.path, ctx.file_type)
+			|| !matches!(admit(&hook, ctx.path, || None, false), Admission::Run)
+		{
 			continue;
 		}
-		let handler = match hook.handler {
-			HookHandler::Mutable(handler) => handler,
-			HookHandler::Immutable(_) => continue,
+		let HookHandler::Mutable(handler) = hook.handler else {
+			continue;
 		};
+		let start = Instant::now();
 		let result = match handler(ctx) {
-			HookAction::Done(result) => result,
-			HookAction::Async(fut) => fut.await,
+			HookAction::Done(result) => {
+				record(&hook, HookRun::Sync, start.elapsed());
+				result
+			}
+			HookAction::Async(fut) => {
+				let result = fut.await;
+				record(&hook, HookRun::Async, start.elapsed());
+				result
+			}
 		};
-		if result == HookResult::Cancel {
-			return HookResult::Cancel;
+		if result.is_cancel() {
+			return cancelled(&hook, result);
 		}
 	}
-	HookResult::Continue
+	EmitOutcome::default()
 }
 
 /// Trait for scheduling async hook futures.
@@ -105,32 +160,56 @@ pub trait HookScheduler {
 	/// The `priority` indicates whether this hook is interactive (must complete) or
 	/// background (can be dropped under backlog).
 	fn schedule(&mut self, fut: HookFuture, priority: HookPriority);
+
+	/// Queue an async hook future to start once the delay has elapsed.
+	///
+	/// Used for trailing-edge debounce replays, so the replay observes the end of
+	/// the window. Schedulers without timers can rely on the default, which
+	/// ignores the delay and queues the future through [`Self::schedule`].
+	fn schedule_after(&mut self, _delay: Duration, fut: HookFuture, priority: HookPriority) {
+		self.schedule(fut, priority);
+	}
 }
 
 /// Emit an event synchronously, scheduling async hooks for later execution.
 ///
 /// Sync hooks run immediately and can cancel the operation. Async hooks are
 /// queued via the provided scheduler and will run later (they cannot cancel
-/// since the operation has already proceeded).
+/// since the operation has already proceeded). Futures queued through
+/// [`HookContext::spawn`] reach `scheduler` after every sync hook has run.
 ///
 /// Returns [`HookResult::Cancel`] if any sync hook cancels, otherwise [`HookResult::Continue`].
 pub fn emit_sync_with<S: HookScheduler>(ctx: &HookContext<'_>, scheduler: &mut S) -> HookResult {
-	let event = ctx.event();
-	let mut matching = hooks_for_event(event);
-	matching.sort_by_key(|h| h.meta.priority);
+	emit_scheduled_with_outcome(ctx, scheduler).result
+}
 
-	for hook in matching {
-		if hook.mutability != HookMutability::Immutable {
+/// Like [`emit_sync_with`], but also reports which hook cancelled.
+pub fn emit_scheduled_with_outcome<S: HookScheduler>(ctx: &HookContext<'_>, scheduler: &mut S) -> EmitOutcome {
+	let spawned = SharedHookScheduler::new();
+	let outcome = spawn::scoped(&spawned, || run_sync_with(ctx, scheduler));
+	spawned.drain_into(scheduler);
+	outcome
+}
+
+fn run_sync_with<S: HookScheduler>(ctx: &HookContext<'_>, scheduler: &mut S) -> EmitOutcome {
+	for hook in hooks_for_event_sorted(ctx.event()) {
+		if hook.mutability != HookMutability::Immutable
+			|| !hook.accepts(ctx.data.path(), ctx.data.file_type())
+			|| !admit_immutable(&hook, ctx, Some(&mut *scheduler))
+		{
 			continue;
 		}
-		let handler = match hook.handler {
-			HookHandler::Immutable(handler) => handler,
-			HookHandler::Mutable(_) => continue,
+		let Some(handler) = hook.handler.immutable() else {
+			continue;
 		};
-		match handler(ctx) {
+		spawn::set_priority(hook.execution_priority);
+		let start = Instant::now();
+		let action = handler(ctx);
+		record(&hook, HookRun::Sync, start.elapsed());
+		match action {
 			HookAction::Done(result) => {
-				if result == HookResult::Cancel {
-					return HookResult::Cancel;
+				if result.is_cancel() {
+					return cancelled(&hook, result);
 				}
 			}
 			HookAction::Async(fut) => {
@@ -138,5 +217,323 @@ pub fn emit_sync_with<S: HookScheduler>(ctx: &HookContext<'_>, scheduler: &mut S
 			}
 		}
 	}
-	HookResult::Continue
+	EmitOutcome::default()
+}
+
+/// Builds the outcome for `hook` cancelling with `result`.
+fn cancelled(hook: &HooksRef, result: HookResult) -> EmitOutcome {
+	EmitOutcome {
+		result,
+		cancelled_by: Some(hook.id_str().to_string()),
+	}
+}
+
+/// Applies `hook`'s schedule to an immutable event, queueing any trailing replay on `scheduler`.
+///
+/// Returns false when the event is skipped.
+fn admit_immutable(hook: &HooksRef, ctx: &HookContext<'_>, scheduler: Option<&mut dyn HookScheduler>) -> bool {
+	let path = ctx.data.path();
+	match admit(hook, path, || Some(ctx.to_owned()), scheduler.is_some()) {
+		Admission::Run => true,
+		Admission::Skip => false,
+		Admission::Trailing { delay } => {
+			if let Some(scheduler) = scheduler {
+				scheduler.schedule_after(delay, trailing_run(hook.clone(), path.map(Path::to_path_buf)), hook.execution_priority);
+			}
+			false
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+	use std::path::Path;
+	use std::sync::Arc;
+
+	use xeno_primitives::Rope;
+
+	use super::*;
+	use crate::core::{RegistryMetaStatic, RegistrySource};
+	use crate::hooks::{HookDef, HookFilter, HookInput, HookPhase, HookSchedule, find_hooks_in_phase};
+	use crate::{HookEvent, HookEventData};
+
+	thread_local! {
+		static RUNS: Cell<[usize; 5]> = const { Cell::new([0; 5]) };
+	}
+
+	fn bump(slot: usize) -> HookAction {
+		RUNS.with(|runs| {
+			let mut counts = runs.get();
+			counts[slot] += 1;
+			runs.set(counts);
+		});
+		HookAction::done()
+	}
+
+	fn runs() -> [usize; 5] {
+		RUNS.with(|runs| runs.replace([0; 5]))
+	}
+
+	fn glob_only(_: &HookContext) -> HookAction {
+		bump(0)
+	}
+	fn file_type_only(_: &HookContext) -> HookAction {
+		bump(1)
+	}
+	fn combined(_: &HookContext) -> HookAction {
+		bump(2)
+	}
+	fn quit_filtered(_: &HookContext) -> HookAction {
+		bump(3)
+	}
+	fn quit_unfiltered(_: &HookContext) -> HookAction {
+		bump(4)
+	}
+
+	const fn hook(id: &'static str, event: HookEvent, handler: fn(&HookContext) -> HookAction, filter: Option<HookFilter>) -> HookDef {
+		HookDef {
+			meta: RegistryMetaStatic {
+				id,
+				name: id,
+				keys: &[],
+				categories: &[],
+				description: "hook filter test",
+				priority: 0,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+			event,
+			mutability: HookMutability::Immutable,
+			execution_priority: HookPriority::Interactive,
+			handler: HookHandler::Immutable(handler),
+			filter,
+			schedule: HookSchedule::Always,
+			phase: HookPhase::Main,
+			tags: &[],
+		}
+	}
+
+	static HOOKS: [HookDef; 5] = [
+		hook(
+			"registry::hooks::filter_glob",
+			HookEvent::BufferOpen,
+			glob_only,
+			Some(crate::__hook_filter!(glob: "*.rs")),
+		),
+		hook(
+			"registry::hooks::filter_file_type",
+			HookEvent::BufferOpen,
+			file_type_only,
+			Some(crate::__hook_filter!(file_types: ["rust", "toml"])),
+		),
+		hook(
+			"registry::hooks::filter_combined",
+			HookEvent::BufferOpen,
+			combined,
+			Some(crate::__hook_filter!(glob: "*.rs", file_types: ["rust"])),
+		),
+		hook(
+			"registry::hooks::filter_quit",
+			HookEvent::EditorQuit,
+			quit_filtered,
+			Some(crate::__hook_filter!(glob: "*", file_types: ["rust"])),
+		),
+		hook("registry::hooks::filter_quit_unfiltered", HookEvent::EditorQuit, quit_unfiltered, None),
+	];
+
+	fn register() {
+		for def in &HOOKS {
+			crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(def.clone())));
+		}
+	}
+
+	fn open(path: &str, file_type: Option<&str>) -> [usize; 5] {
+		let text = Rope::from("fn main() {}");
+		let ctx = HookContext::new(HookEventData::BufferOpen {
+			path: Path::new(path),
+			text: text.slice(..),
+			file_type,
+		});
+		runs();
+		assert_eq!(emit_sync(&ctx), HookResult::Continue);
+		runs()
+	}
+
+	#[test]
+	fn path_glob_filter_matches_file_name() {
+		register();
+		assert_eq!(open("src/main.rs", None)[0], 1);
+		assert_eq!(open("README.md", Some("rust"))[0], 0);
+	}
+
+	#[test]
+	fn file_type_filter_requires_listed_type() {
+		register();
+		assert_eq!(open("Cargo.toml", Some("toml"))[1], 1);
+		assert_eq!(open("main.py", Some("python"))[1], 0);
+		assert_eq!(open("src/main.rs", None)[1], 0);
+	}
+
+	#[test]
+	fn combined_filter_requires_both() {
+		register();
+		assert_eq!(open("src/main.rs", Some("rust"))[2], 1);
+		assert_eq!(open("src/main.rs", None)[2], 0);
+		assert_eq!(open("notes.txt", Some("rust"))[2], 0);
+	}
+
+	#[test]
+	fn filters_skip_events_without_path() {
+		register();
+		runs();
+		assert_eq!(emit_sync(&HookContext::new(HookEventData::EditorQuit)), HookResult::Continue);
+		assert_eq!(runs()[3..], [0, 1]);
+	}
+
+	fn readonly_guard(_: &HookContext) -> HookAction {
+		HookAction::cancel(Some("file is readonly"))
+	}
+
+	static READONLY_GUARD: HookDef = hook(
+		"registry::hooks::readonly_guard",
+		HookEvent::BufferWritePre,
+		readonly_guard,
+		Some(crate::__hook_filter!(glob: "*.lock")),
+	);
+
+	#[test]
+	fn cancel_reports_hook_and_reason() {
+		crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(READONLY_GUARD.clone())));
+		let text = Rope::from("locked");
+		let write = |path: &'static str| {
+			let ctx = HookContext::new(HookEventData::BufferWritePre {
+				path: Path::new(path),
+				text: text.slice(..),
+			});
+			let mut fut = std::pin::pin!(emit_with_outcome(&ctx));
+			match fut.as_mut().poll(&mut std::task::Context::from_waker(std::task::Waker::noop())) {
+				std::task::Poll::Ready(outcome) => outcome,
+				std::task::Poll::Pending => panic!("sync hooks complete on first poll"),
+			}
+		};
+
+		let outcome = write("Cargo.lock");
+		assert!(outcome.is_cancelled());
+		assert_eq!(outcome.cancelled_by.as_deref(), Some(READONLY_GUARD.meta.id));
+		assert_eq!(outcome.reason(), Some("file is readonly"));
+		assert_eq!(write("src/main.rs"), EmitOutcome::default());
+
+		let ctx = HookContext::new(HookEventData::BufferWritePre {
+			path: Path::new("Cargo.lock"),
+			text: text.slice(..),
+		});
+		assert_eq!(emit_sync(&ctx).reason(), Some("file is readonly"));
+		let expected = EmitOutcome {
+			result: HookResult::Cancel {
+				reason: Some("file is readonly".into()),
+			},
+			cancelled_by: Some(READONLY_GUARD.meta.id.to_string()),
+		};
+		assert_eq!(emit_sync_with_outcome(&ctx), expected);
+		assert_eq!(emit_scheduled_with_outcome(&ctx, &mut SharedHookScheduler::new()), expected);
+	}
+
+	thread_local! {
+		static POST_SAW_MAIN: Cell<Option<bool>> = const { Cell::new(None) };
+	}
+
+	fn main_append(ctx: &mut MutableHookContext) -> HookAction {
+		if let Some(text) = ctx.text.as_deref_mut() {
+			text.insert(text.len_chars(), " main");
+		}
+		HookAction::done()
+	}
+
+	fn post_observe(ctx: &mut MutableHookContext) -> HookAction {
+		let saw = ctx.text.as_deref().is_some_and(|text| text.to_string().ends_with(" main"));
+		POST_SAW_MAIN.with(|cell| cell.set(Some(saw)));
+		HookAction::done()
+	}
+
+	const fn phased(id: &'static str, handler: fn(&mut MutableHookContext) -> HookAction, priority: i16, phase: HookPhase) -> HookDef {
+		HookDef {
+			meta: RegistryMetaStatic {
+				id,
+				name: id,
+				keys: &[],
+				categories: &[],
+				description: "hook phase test",
+				priority,
+				source: RegistrySource::Runtime,
+				mutates_buffer: true,
+				deprecated: None,
+			},
+			event: HookEvent::BufferClose,
+			mutability: HookMutability::Mutable,
+			execution_priority: HookPriority::Interactive,
+			handler: HookHandler::Mutable(handler),
+			filter: None,
+			schedule: HookSchedule::Always,
+			phase,
+			tags: &[],
+		}
+	}
+
+	/// Priorities alone would run the post hook first.
+	static PHASED: [HookDef; 2] = [
+		phased("registry::hooks::phase_post", post_observe, -1000, HookPhase::Post),
+		phased("registry::hooks::phase_main", main_append, 1000, HookPhase::Main),
+	];
+
+	#[test]
+	fn post_hook_observes_main_mutation() {
+		for def in &PHASED {
+			crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(def.clone())));
+		}
+
+		for _ in 0..3 {
+			let mut text = Rope::from("text");
+			let mut ctx = MutableHookContext {
+				event: HookEvent::BufferClose,
+				text: Some(&mut text),
+				path: None,
+				file_type: None,
+			};
+			let poll = std::pin::pin!(emit_mutable(&mut ctx)).poll(&mut std::task::Context::from_waker(std::task::Waker::noop()));
+			assert_eq!(poll, std::task::Poll::Ready(HookResult::Continue), "sync hooks complete on first poll");
+			assert_eq!(POST_SAW_MAIN.with(Cell::take), Some(true));
+			assert_eq!(text.to_string(), "text main");
+		}
+	}
+
+	#[test]
+	fn find_hooks_in_phase_filters_by_phase() {
+		for def in &PHASED {
+			crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(def.clone())));
+		}
+		let ids = |phase| {
+			find_hooks_in_phase(HookEvent::BufferClose, phase)
+				.iter()
+				.map(|h| h.id_str().to_string())
+				.collect::<Vec<_>>()
+		};
+		assert!(ids(HookPhase::Post).contains(&PHASED[0].meta.id.to_string()));
+		assert!(ids(HookPhase::Main).contains(&PHASED[1].meta.id.to_string()));
+		assert!(ids(HookPhase::Pre).is_empty());
+		assert!(
+			find_hooks_in_phase(HookEvent::ModeChange, HookPhase::Post)
+				.iter()
+				.any(|h| h.name_str() == "log_mode_change")
+		);
+	}
+
+	#[test]
+	fn filter_macro_defaults() {
+		let empty = crate::__hook_filter!();
+		assert_eq!(empty, HookFilter::default());
+		assert!(empty.matches(None, None));
+		assert_eq!(crate::__hook_filter!(file_types: ["rust",]).path_glob, None);
+	}
 }
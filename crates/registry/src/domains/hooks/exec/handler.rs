@@ -1,13 +1,13 @@
 //! Hook handler static registration via `inventory`.
 
-use super::types::{HookHandler, HookMutability, HookPriority};
+use super::types::{HookFilter, HookHandler, HookMutability, HookPhase, HookPriority, HookSchedule};
 use crate::HookEvent;
 
 /// Handler configuration carried by the static registration.
 ///
-/// Contains the executable logic and structural properties (event, mutability, priority)
-/// that are code-dependent and cannot be expressed in NUON.
-#[derive(Clone, Copy)]
+/// Contains the executable logic and structural properties (event, mutability, priority,
+/// filter, schedule, phase, tags) that are code-dependent and cannot be expressed in NUON.
+#[derive(Clone)]
 pub struct HookHandlerConfig {
 	/// Event this hook listens to.
 	pub event: HookEvent,
@@ -17,6 +17,14 @@ pub struct HookHandlerConfig {
 	pub execution_priority: HookPriority,
 	/// Handler function.
 	pub handler: HookHandler,
+	/// Event filter checked before the handler runs.
+	pub filter: Option<HookFilter>,
+	/// Run frequency limit.
+	pub schedule: HookSchedule,
+	/// Ordering stage relative to other hooks for the event.
+	pub phase: HookPhase,
+	/// Grouping tags; see [`tags`](crate::hooks::tags).
+	pub tags: &'static [&'static str],
 }
 
 pub type HookHandlerStatic = crate::core::HandlerStatic<HookHandlerConfig>;
@@ -1,6 +1,7 @@
 //! Hook registration macros.
 //!
-//! `hook_handler!` for registering event lifecycle observers via NUON metadata.
+//! `hook_handler!` for registering event lifecycle observers via NUON metadata,
+//! with optional declarative event filters and run schedules.
 //!
 //! Note: The `__hook_extract!` and `__async_hook_extract!` macros are generated
 //! by `xeno_macros::define_events!` in `lib.rs`.
@@ -30,15 +31,44 @@ macro_rules! __hook_param_expr {
 ///
 /// Metadata comes from `hooks.nuon`; this macro provides the handler function
 /// and creates the inventory linkage.
+///
+/// Optional settings go before the closure, in this order:
+/// * `tags: [tags::LSP, tags::DIAGNOSTIC],` groups the hook for documentation and
+///   [`find_hooks_by_tag`](crate::hooks::find_hooks_by_tag); see
+///   [`tags`](crate::hooks::tags) for the standard ones.
+/// * `filter: { glob: "*.rs", file_types: ["rust"] },` skips the handler for
+///   events whose path or file type do not match (see
+///   [`HookFilter`](crate::hooks::HookFilter)); either key may be omitted.
+/// * `schedule: HookSchedule::Once,` limits how often the handler runs (see
+///   [`HookSchedule`](crate::hooks::HookSchedule)); defaults to `Always`.
+/// * `phase: HookPhase::Post,` picks the ordering stage (see
+///   [`HookPhase`](crate::hooks::HookPhase)); defaults to `Main`.
 #[macro_export]
 macro_rules! hook_handler {
-	($name:ident, $event:ident, |$($param:ident : $ty:ty),*| $body:expr) => {
-		$crate::hook_handler!($name, $event, |ctx| {
-			__hook_extract!($event, ctx, $($param : $ty),*);
-			$body
-		});
+	($name:ident, $event:ident, $(tags: [$($tag:expr),* $(,)?],)? $(filter: { $($filter:tt)* },)? $(schedule: $schedule:expr,)? $(phase: $phase:expr,)? |$($param:ident : $ty:ty),*| $body:expr) => {
+		$crate::hook_handler!(
+			@register $name, $event,
+			&[$($($tag),*)?],
+			$crate::__hook_handler_filter!($({ $($filter)* })?),
+			$crate::__hook_handler_schedule!($($schedule)?),
+			$crate::__hook_handler_phase!($($phase)?),
+			|ctx| {
+				__hook_extract!($event, ctx, $($param : $ty),*);
+				$body
+			}
+		);
 	};
-	($name:ident, $event:ident, |$ctx:ident| $body:expr) => {
+	($name:ident, $event:ident, $(tags: [$($tag:expr),* $(,)?],)? $(filter: { $($filter:tt)* },)? $(schedule: $schedule:expr,)? $(phase: $phase:expr,)? |$ctx:ident| $body:expr) => {
+		$crate::hook_handler!(
+			@register $name, $event,
+			&[$($($tag),*)?],
+			$crate::__hook_handler_filter!($({ $($filter)* })?),
+			$crate::__hook_handler_schedule!($($schedule)?),
+			$crate::__hook_handler_phase!($($phase)?),
+			|$ctx| $body
+		);
+	};
+	(@register $name:ident, $event:ident, $tags:expr, $filter:expr, $schedule:expr, $phase:expr, |$ctx:ident| $body:expr) => {
 		paste::paste! {
 			fn [<hook_handler_ $name>]($ctx: &$crate::hooks::HookContext) -> $crate::hooks::HookAction {
 				let result = { $body };
@@ -55,6 +85,10 @@ macro_rules! hook_handler {
 						mutability: $crate::hooks::HookMutability::Immutable,
 						execution_priority: $crate::hooks::HookPriority::Interactive,
 						handler: $crate::hooks::HookHandler::Immutable([<hook_handler_ $name>]),
+						filter: $filter,
+						schedule: $schedule,
+						phase: $phase,
+						tags: $tags,
 					},
 				};
 
@@ -62,3 +96,57 @@ macro_rules! hook_handler {
 		}
 	};
 }
+
+/// Expands an optional `hook_handler!` filter block to `Option<HookFilter>`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_handler_filter {
+	() => {
+		::core::option::Option::None
+	};
+	({ $($filter:tt)* }) => {
+		::core::option::Option::Some($crate::__hook_filter!($($filter)*))
+	};
+}
+
+/// Expands an optional `hook_handler!` schedule, defaulting to `HookSchedule::Always`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_handler_schedule {
+	() => {
+		$crate::hooks::HookSchedule::Always
+	};
+	($schedule:expr) => {
+		$schedule
+	};
+}
+
+/// Expands an optional `hook_handler!` phase, defaulting to `HookPhase::Main`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_handler_phase {
+	() => {
+		$crate::hooks::HookPhase::Main
+	};
+	($phase:expr) => {
+		$phase
+	};
+}
+
+/// Builds a [`HookFilter`](crate::hooks::HookFilter) from `hook_handler!` filter syntax.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_filter {
+	($(glob: $glob:literal)? $(,)? $(file_types: [$($file_type:literal),* $(,)?])? $(,)?) => {
+		$crate::hooks::HookFilter {
+			path_glob: $crate::__hook_filter!(@glob $($glob)?),
+			file_types: &[$($($file_type),*)?],
+		}
+	};
+	(@glob) => {
+		::core::option::Option::None
+	};
+	(@glob $glob:literal) => {
+		::core::option::Option::Some($glob)
+	};
+}
@@ -0,0 +1,318 @@
+//! Once and debounce enforcement for [`HookSchedule`].
+//!
+//! Bookkeeping lives on each [`HookEntry`](super::HookEntry), so replacing a hook
+//! in the registry re-arms it. Debounce windows are tracked per buffer path.
+//! A trailing-edge replay is handed to the caller's
+//! [`HookScheduler::schedule_after`](super::HookScheduler::schedule_after) to
+//! start when the window expires, and runs the handler with the latest skipped
+//! payload.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+
+use super::HooksRef;
+use super::context::HookContext;
+use super::stats::{HookRun, record};
+use super::types::{HookAction, HookFuture, HookResult, HookSchedule};
+use crate::OwnedHookContext;
+
+/// Per-entry schedule bookkeeping.
+#[derive(Default)]
+pub(crate) struct ScheduleState {
+	fired: AtomicBool,
+	debounce: Mutex<FxHashMap<Option<PathBuf>, DebounceSlot>>,
+}
+
+struct DebounceSlot {
+	last_run: Instant,
+	/// Latest payload skipped inside the window.
+	pending: Option<OwnedHookContext>,
+	/// A trailing replay was handed to a scheduler and has not run yet.
+	trailing_scheduled: bool,
+}
+
+/// Outcome of [`admit`].
+pub(super) enum Admission {
+	Run,
+	Skip,
+	/// Skipped; the caller should schedule [`trailing_run`] after `delay`.
+	Trailing {
+		delay: Duration,
+	},
+}
+
+/// Applies `hook`'s schedule to an event at `path`.
+///
+/// `pending` captures the payload for a trailing replay and may return `None`
+/// when the context cannot be owned. [`Admission::Trailing`] is only returned
+/// when `can_schedule` is set and no replay is already queued for the slot.
+pub(super) fn admit(hook: &HooksRef, path: Option<&Path>, pending: impl FnOnce() -> Option<OwnedHookContext>, can_schedule: bool) -> Admission {
+	let state = &hook.schedule_state;
+	let (window, trailing) = match hook.schedule {
+		HookSchedule::Always => return Admission::Run,
+		HookSchedule::Once if state.fired.swap(true, Ordering::AcqRel) => return Admission::Skip,
+		HookSchedule::Once => return Admission::Run,
+		HookSchedule::Debounce { window, trailing } => (window, trailing),
+	};
+
+	let now = Instant::now();
+	let mut slots = lock(&state.debounce);
+	let key = path.map(Path::to_path_buf);
+	let Some(slot) = slots.get_mut(&key) else {
+		slots.insert(
+			key,
+			DebounceSlot {
+				last_run: now,
+				pending: None,
+				trailing_scheduled: false,
+			},
+		);
+		return Admission::Run;
+	};
+
+	let elapsed = now.duration_since(slot.last_run);
+	if elapsed >= window {
+		slot.last_run = now;
+		slot.pending = None;
+		return Admission::Run;
+	}
+	if !trailing {
+		return Admission::Skip;
+	}
+	let Some(owned) = pending() else {
+		return Admission::Skip;
+	};
+	slot.pending = Some(owned);
+	if slot.trailing_scheduled || !can_schedule {
+		return Admission::Skip;
+	}
+	slot.trailing_scheduled = true;
+	Admission::Trailing { delay: window - elapsed }
+}
+
+/// Replays the latest payload skipped in `hook`'s debounce slot for `path`.
+///
+/// Does nothing if a newer leading-edge run already consumed the slot.
+pub(super) fn trailing_run(hook: HooksRef, path: Option<PathBuf>) -> HookFuture {
+	Box::pin(async move {
+		let pending = {
+			let mut slots = lock(&hook.schedule_state.debounce);
+			let Some(slot) = slots.get_mut(&path) else {
+				return HookResult::Continue;
+			};
+			slot.trailing_scheduled = false;
+			let pending = slot.pending.take();
+			if pending.is_some() {
+				slot.last_run = Instant::now();
+			}
+			pending
+		};
+		let (Some(owned), Some(handler)) = (pending, hook.handler.immutable()) else {
+			return HookResult::Continue;
+		};
+
+		let start = Instant::now();
+		let result = match owned.with_event_data(|data| handler(&HookContext::new(data))) {
+			HookAction::Done(result) => result,
+			HookAction::Async(fut) => fut.await,
+		};
+		record(&hook, HookRun::Async, start.elapsed());
+		result
+	})
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+	mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+	use std::sync::Arc;
+	use std::task::{Context, Poll, Wake, Waker};
+
+	use xeno_primitives::Rope;
+
+	use super::*;
+	use crate::core::{RegistryMetaStatic, RegistrySource};
+	use crate::hooks::{HookDef, HookHandler, HookInput, HookMutability, HookPhase, HookPriority, HookScheduler, emit_sync, emit_sync_with};
+	use crate::{HookEvent, HookEventData};
+
+	thread_local! {
+		static SEEN: RefCell<Vec<(&'static str, u64)>> = const { RefCell::new(Vec::new()) };
+	}
+
+	fn seen() -> Vec<(&'static str, u64)> {
+		SEEN.with(|seen| seen.take())
+	}
+
+	fn record_once(_: &HookContext) -> HookAction {
+		SEEN.with(|seen| seen.borrow_mut().push(("once", 0)));
+		HookAction::done()
+	}
+
+	fn record_change(tag: &'static str, ctx: &HookContext) -> HookAction {
+		if let HookEventData::BufferChange { path, version, .. } = &ctx.data
+			&& path.starts_with("schedule-test")
+		{
+			SEEN.with(|seen| seen.borrow_mut().push((tag, *version)));
+		}
+		HookAction::done()
+	}
+
+	fn record_leading(ctx: &HookContext) -> HookAction {
+		record_change("leading", ctx)
+	}
+
+	fn record_trailing(ctx: &HookContext) -> HookAction {
+		record_change("trailing", ctx)
+	}
+
+	const fn hook(id: &'static str, event: HookEvent, handler: fn(&HookContext) -> HookAction, schedule: HookSchedule) -> HookDef {
+		HookDef {
+			meta: RegistryMetaStatic {
+				id,
+				name: id,
+				keys: &[],
+				categories: &[],
+				description: "hook schedule test",
+				priority: 0,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+			event,
+			mutability: HookMutability::Immutable,
+			execution_priority: HookPriority::Interactive,
+			handler: HookHandler::Immutable(handler),
+			filter: None,
+			schedule,
+			phase: HookPhase::Main,
+			tags: &[],
+		}
+	}
+
+	static ONCE: HookDef = hook("registry::hooks::schedule_once", HookEvent::EditorStart, record_once, HookSchedule::Once);
+	static LEADING: HookDef = hook(
+		"registry::hooks::schedule_leading",
+		HookEvent::BufferChange,
+		record_leading,
+		HookSchedule::Debounce {
+			window: Duration::from_secs(3600),
+			trailing: false,
+		},
+	);
+	static TRAILING: HookDef = hook(
+		"registry::hooks::schedule_trailing",
+		HookEvent::BufferChange,
+		record_trailing,
+		HookSchedule::Debounce {
+			window: Duration::from_millis(30),
+			trailing: true,
+		},
+	);
+
+	/// Unregisters the hook on drop, so it does not fire for other tests' events.
+	struct Registered(&'static HookDef);
+
+	impl Drop for Registered {
+		fn drop(&mut self) {
+			crate::db::HOOKS.inner.remove_canonical(self.0.meta.id, |_| true);
+		}
+	}
+
+	#[must_use = "the hook is unregistered when the guard drops"]
+	fn register(def: &'static HookDef) -> Registered {
+		crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(def.clone())));
+		Registered(def)
+	}
+
+	fn change(path: &str, version: u64) -> HookContext<'static> {
+		let path: &'static Path = Box::leak(PathBuf::from(path).into_boxed_path());
+		let text: &'static Rope = Box::leak(Box::new(Rope::from("text")));
+		HookContext::new(HookEventData::BufferChange {
+			path,
+			text: text.slice(..),
+			file_type: None,
+			version,
+		})
+	}
+
+	#[derive(Default)]
+	struct Queue(Vec<(Duration, HookFuture)>);
+
+	impl HookScheduler for Queue {
+		fn schedule(&mut self, fut: HookFuture, _priority: HookPriority) {
+			self.0.push((Duration::ZERO, fut));
+		}
+
+		fn schedule_after(&mut self, delay: Duration, fut: HookFuture, _priority: HookPriority) {
+			self.0.push((delay, fut));
+		}
+	}
+
+	struct ThreadWaker(std::thread::Thread);
+
+	impl Wake for ThreadWaker {
+		fn wake(self: Arc<Self>) {
+			self.0.unpark();
+		}
+	}
+
+	fn block_on(mut fut: HookFuture) -> HookResult {
+		let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+		let mut cx = Context::from_waker(&waker);
+		loop {
+			if let Poll::Ready(result) = fut.as_mut().poll(&mut cx) {
+				return result;
+			}
+			std::thread::park();
+		}
+	}
+
+	#[test]
+	fn once_runs_for_first_event_only() {
+		let _registered = register(&ONCE);
+		seen();
+		for _ in 0..3 {
+			assert_eq!(emit_sync(&HookContext::new(HookEventData::EditorStart)), HookResult::Continue);
+		}
+		assert_eq!(seen(), [("once", 0)]);
+	}
+
+	#[test]
+	fn debounce_skips_within_window_per_path() {
+		let _registered = register(&LEADING);
+		seen();
+		for version in 1..=3 {
+			emit_sync(&change("schedule-test/leading-a.rs", version));
+		}
+		emit_sync(&change("schedule-test/leading-b.rs", 4));
+		let leading: Vec<_> = seen().into_iter().filter(|(tag, _)| *tag == "leading").collect();
+		assert_eq!(leading, [("leading", 1), ("leading", 4)]);
+	}
+
+	#[test]
+	fn debounce_trailing_replays_latest_skipped_event() {
+		let _registered = register(&TRAILING);
+		seen();
+		let mut queue = Queue::default();
+		for version in 1..=3 {
+			emit_sync_with(&change("schedule-test/trailing.rs", version), &mut queue);
+		}
+		let trailing = |seen: Vec<(&'static str, u64)>| seen.into_iter().filter(|(tag, _)| *tag == "trailing").collect::<Vec<_>>();
+		assert_eq!(trailing(seen()), [("trailing", 1)], "only the leading edge runs inside the window");
+		assert_eq!(queue.0.len(), 1, "one trailing replay per window");
+
+		for (delay, fut) in std::mem::take(&mut queue.0) {
+			assert!(delay > Duration::ZERO && delay <= Duration::from_millis(30), "replay waits out the window");
+			std::thread::sleep(delay);
+			assert_eq!(block_on(fut), HookResult::Continue);
+		}
+		assert_eq!(trailing(seen()), [("trailing", 3)], "replay uses the latest payload");
+	}
+}
@@ -0,0 +1,223 @@
+//! Sub-future spawning from inside hook handlers.
+//!
+//! Async hook futures are `'static`, so they cannot borrow the caller's
+//! [`HookScheduler`]. [`emit_sync_with`](super::emit_sync_with) instead installs
+//! a thread-local [`SharedHookScheduler`] for the duration of its sync phase;
+//! [`HookContext::spawn`](super::HookContext::spawn) posts to it, and the queued
+//! futures are handed to the caller's scheduler once every sync hook has run.
+
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use super::emit::HookScheduler;
+use super::types::{HookFuture, HookPriority};
+
+thread_local! {
+	static ACTIVE: RefCell<Option<(SharedHookScheduler, HookPriority)>> = const { RefCell::new(None) };
+}
+
+/// Cloneable hook scheduler that buffers futures until drained.
+#[derive(Clone, Default)]
+pub struct SharedHookScheduler {
+	queue: Arc<Mutex<Vec<(Duration, HookFuture, HookPriority)>>>,
+}
+
+impl SharedHookScheduler {
+	/// Creates an empty shared scheduler.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of queued futures.
+	pub fn len(&self) -> usize {
+		self.lock().len()
+	}
+
+	/// Returns `true` if nothing is queued.
+	pub fn is_empty(&self) -> bool {
+		self.lock().is_empty()
+	}
+
+	/// Moves every queued future into `scheduler`, in queue order.
+	pub fn drain_into<S: HookScheduler + ?Sized>(&self, scheduler: &mut S) {
+		let queued = std::mem::take(&mut *self.lock());
+		for (delay, fut, priority) in queued {
+			if delay.is_zero() {
+				scheduler.schedule(fut, priority);
+			} else {
+				scheduler.schedule_after(delay, fut, priority);
+			}
+		}
+	}
+
+	fn lock(&self) -> MutexGuard<'_, Vec<(Duration, HookFuture, HookPriority)>> {
+		self.queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+impl HookScheduler for SharedHookScheduler {
+	fn schedule(&mut self, fut: HookFuture, priority: HookPriority) {
+		self.lock().push((Duration::ZERO, fut, priority));
+	}
+
+	fn schedule_after(&mut self, delay: Duration, fut: HookFuture, priority: HookPriority) {
+		self.lock().push((delay, fut, priority));
+	}
+}
+
+/// Runs `f` with `spawned` installed as this thread's spawn target.
+///
+/// Restores the previous target afterwards, so nested emits queue into their
+/// own scheduler.
+pub(super) fn scoped<R>(spawned: &SharedHookScheduler, f: impl FnOnce() -> R) -> R {
+	struct Restore(Option<(SharedHookScheduler, HookPriority)>);
+
+	impl Drop for Restore {
+		fn drop(&mut self) {
+			ACTIVE.with(|active| *active.borrow_mut() = self.0.take());
+		}
+	}
+
+	let previous = ACTIVE.with(|active| active.borrow_mut().replace((spawned.clone(), HookPriority::Interactive)));
+	let _restore = Restore(previous);
+	f()
+}
+
+/// Sets the priority given to futures spawned by the hook about to run.
+pub(super) fn set_priority(priority: HookPriority) {
+	ACTIVE.with(|active| {
+		if let Some((_, current)) = active.borrow_mut().as_mut() {
+			*current = priority;
+		}
+	});
+}
+
+/// Queues `fut` on the active spawn target.
+///
+/// Returns `false`, dropping `fut`, when no [`emit_sync_with`](super::emit_sync_with)
+/// sync phase is running on this thread.
+pub(super) fn spawn(fut: HookFuture) -> bool {
+	ACTIVE.with(|active| match active.borrow_mut().as_mut() {
+		Some((scheduler, priority)) => {
+			let priority = *priority;
+			scheduler.schedule(fut, priority);
+			true
+		}
+		None => false,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+	use std::sync::Arc;
+	use std::task::{Context, Poll, Waker};
+
+	use super::*;
+	use crate::core::{RegistryMetaStatic, RegistrySource};
+	use crate::hooks::{HookAction, HookContext, HookDef, HookHandler, HookInput, HookMutability, HookPhase, HookResult, HookSchedule, emit_sync_with};
+	use crate::{HookEvent, HookEventData};
+
+	thread_local! {
+		static SEEN: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+	}
+
+	fn push(tag: &'static str) {
+		SEEN.with(|seen| seen.borrow_mut().push(tag));
+	}
+
+	fn spawner(ctx: &HookContext) -> HookAction {
+		push("spawner");
+		assert!(ctx.spawn(Box::pin(async {
+			HookResult::Cancel {
+				reason: Some("from spawned".into()),
+			}
+		})));
+		HookAction::done()
+	}
+
+	fn later(_: &HookContext) -> HookAction {
+		push("later");
+		HookAction::done()
+	}
+
+	const fn hook(id: &'static str, handler: fn(&HookContext) -> HookAction, priority: i16, execution_priority: HookPriority) -> HookDef {
+		HookDef {
+			meta: RegistryMetaStatic {
+				id,
+				name: id,
+				keys: &[],
+				categories: &[],
+				description: "hook spawn test",
+				priority,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+			event: HookEvent::WindowFocusChanged,
+			mutability: HookMutability::Immutable,
+			execution_priority,
+			handler: HookHandler::Immutable(handler),
+			filter: None,
+			schedule: HookSchedule::Always,
+			phase: HookPhase::Main,
+			tags: &[],
+		}
+	}
+
+	static HOOKS: [HookDef; 2] = [
+		hook("registry::hooks::spawn_spawner", spawner, -10, HookPriority::Background),
+		hook("registry::hooks::spawn_later", later, 10, HookPriority::Interactive),
+	];
+
+	#[derive(Default)]
+	struct Queue(Vec<(HookFuture, HookPriority)>);
+
+	impl HookScheduler for Queue {
+		fn schedule(&mut self, fut: HookFuture, priority: HookPriority) {
+			push("scheduled");
+			self.0.push((fut, priority));
+		}
+	}
+
+	/// Unregisters the test hooks on drop, so they do not fire for other tests' events.
+	struct Registered;
+
+	impl Drop for Registered {
+		fn drop(&mut self) {
+			for def in &HOOKS {
+				crate::db::HOOKS.inner.remove_canonical(def.meta.id, |_| true);
+			}
+		}
+	}
+
+	#[test]
+	fn spawned_future_reaches_scheduler_after_sync_phase() {
+		for def in &HOOKS {
+			crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(def.clone())));
+		}
+		let _registered = Registered;
+		SEEN.with(|seen| seen.take());
+
+		let mut queue = Queue::default();
+		let ctx = HookContext::new(HookEventData::WindowFocusChanged {
+			window_id: crate::hooks::WindowId(1),
+			focused: true,
+		});
+		assert_eq!(emit_sync_with(&ctx, &mut queue), HookResult::Continue);
+		assert_eq!(SEEN.with(|seen| seen.take()), ["spawner", "later", "scheduled"]);
+
+		let [(mut fut, priority)] = <[_; 1]>::try_from(queue.0).unwrap_or_else(|_| panic!("one spawned future"));
+		assert_eq!(priority, HookPriority::Background, "spawned futures inherit the hook's priority");
+		let poll = fut.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+		assert_eq!(
+			poll,
+			Poll::Ready(HookResult::Cancel {
+				reason: Some("from spawned".into())
+			})
+		);
+
+		assert!(!ctx.spawn(Box::pin(async { HookResult::Continue })), "no spawn target outside emit_sync_with");
+	}
+}
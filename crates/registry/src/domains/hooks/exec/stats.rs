@@ -0,0 +1,271 @@
+//! Per-hook execution timing and slow-hook reporting.
+//!
+//! Emission paths time every handler call. Sync runs longer than the slow-hook
+//! threshold (default 5ms, see [`set_slow_hook_threshold`]) log a warning. When
+//! collection is enabled via [`set_stats_enabled`], each run is also pushed into
+//! a fixed-size lock-free ring buffer that [`stats`] aggregates on demand, so the
+//! numbers describe the most recent [`RING_CAPACITY`] runs across all hooks.
+//!
+//! Collection is off by default; a disabled recorder costs one relaxed load.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering, fence};
+use std::time::Duration;
+
+use rustc_hash::FxHashMap;
+use tracing::warn;
+
+use super::HooksRef;
+use crate::HookEvent;
+use crate::core::RegistryEntry;
+
+/// Number of most recent hook runs retained for [`stats`].
+pub const RING_CAPACITY: usize = 1024;
+
+const DEFAULT_SLOW_THRESHOLD: Duration = Duration::from_millis(5);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SLOW_THRESHOLD_NANOS: AtomicU64 = AtomicU64::new(DEFAULT_SLOW_THRESHOLD.as_nanos() as u64);
+static RING: Ring = Ring::new();
+
+/// How a timed hook run blocked its caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum HookRun {
+	/// Handler call only; the emitting thread was blocked for the whole run.
+	Sync,
+	/// Handler call plus awaiting the returned future.
+	Async,
+}
+
+/// Timing summary for one hook over the retained window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookStat {
+	/// Canonical hook ID.
+	pub id: String,
+	/// Event the hook is attached to.
+	pub event: HookEvent,
+	/// Number of retained runs.
+	pub count: usize,
+	/// Runs that awaited an async action.
+	pub async_count: usize,
+	pub mean: Duration,
+	/// 95th percentile (nearest rank).
+	pub p95: Duration,
+	pub max: Duration,
+}
+
+/// Enables or disables collection into the stats ring buffer.
+///
+/// Slow sync hooks are reported regardless of this setting.
+pub fn set_stats_enabled(enabled: bool) {
+	ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether stats collection is enabled.
+pub fn stats_enabled() -> bool {
+	ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sets the wall time above which a single sync hook run logs a warning.
+pub fn set_slow_hook_threshold(threshold: Duration) {
+	SLOW_THRESHOLD_NANOS.store(duration_nanos(threshold), Ordering::Relaxed);
+}
+
+/// Returns per-hook timing stats, slowest p95 first.
+///
+/// Only hooks present in the current [`HOOKS`](crate::db::HOOKS) snapshot are
+/// reported.
+pub fn stats() -> Vec<HookStat> {
+	let mut samples: FxHashMap<u32, (Vec<u64>, usize)> = FxHashMap::default();
+	RING.for_each(|hook, run, nanos| {
+		let (durations, async_count) = samples.entry(hook).or_default();
+		durations.push(nanos);
+		if run == HookRun::Async {
+			*async_count += 1;
+		}
+	});
+
+	let snap = crate::db::HOOKS.snapshot_guard();
+	let mut out: Vec<HookStat> = snap
+		.iter_refs()
+		.filter_map(|hook| {
+			let (mut durations, async_count) = samples.remove(&hook.id().as_u32())?;
+			durations.sort_unstable();
+			let count = durations.len();
+			let total: u64 = durations.iter().sum();
+			let p95_rank = (count * 95).div_ceil(100).max(1);
+			Some(HookStat {
+				id: hook.id_str().to_string(),
+				event: hook.event,
+				count,
+				async_count,
+				mean: Duration::from_nanos(total / count as u64),
+				p95: Duration::from_nanos(durations[p95_rank - 1]),
+				max: Duration::from_nanos(durations[count - 1]),
+			})
+		})
+		.collect();
+	out.sort_by(|a, b| b.p95.cmp(&a.p95).then_with(|| a.id.cmp(&b.id)));
+	out
+}
+
+/// Records one hook run, warning when a sync run exceeds the slow-hook threshold.
+pub(super) fn record(hook: &HooksRef, run: HookRun, elapsed: Duration) {
+	let nanos = duration_nanos(elapsed);
+	if run == HookRun::Sync && nanos > SLOW_THRESHOLD_NANOS.load(Ordering::Relaxed) {
+		warn!(
+			hook = hook.id_str(),
+			event = hook.event.as_str(),
+			elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+			"slow sync hook"
+		);
+	}
+	if ENABLED.load(Ordering::Relaxed) {
+		RING.push(hook.id().as_u32(), run, nanos);
+	}
+}
+
+fn duration_nanos(duration: Duration) -> u64 {
+	u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// One ring slot guarded by a sequence lock.
+///
+/// `seq` is `0` when never written, odd while a writer is mid-update, and even
+/// once the record for push number `seq / 2 - 1` is complete.
+struct Slot {
+	seq: AtomicU64,
+	/// Hook symbol in the high 32 bits, [`HookRun::Async`] flag in bit 0.
+	hook: AtomicU64,
+	nanos: AtomicU64,
+}
+
+impl Slot {
+	const fn new() -> Self {
+		Self {
+			seq: AtomicU64::new(0),
+			hook: AtomicU64::new(0),
+			nanos: AtomicU64::new(0),
+		}
+	}
+}
+
+struct Ring {
+	head: AtomicUsize,
+	slots: [Slot; RING_CAPACITY],
+}
+
+impl Ring {
+	const fn new() -> Self {
+		Self {
+			head: AtomicUsize::new(0),
+			slots: [const { Slot::new() }; RING_CAPACITY],
+		}
+	}
+
+	fn push(&self, hook: u32, run: HookRun, nanos: u64) {
+		let n = self.head.fetch_add(1, Ordering::Relaxed);
+		let slot = &self.slots[n % RING_CAPACITY];
+		let seq = (n as u64 + 1) * 2;
+
+		slot.seq.store(seq - 1, Ordering::Relaxed);
+		fence(Ordering::Release);
+		slot.hook.store((u64::from(hook) << 32) | u64::from(run == HookRun::Async), Ordering::Relaxed);
+		slot.nanos.store(nanos, Ordering::Relaxed);
+		slot.seq.store(seq, Ordering::Release);
+	}
+
+	/// Visits every complete record; slots being overwritten concurrently are skipped.
+	fn for_each(&self, mut f: impl FnMut(u32, HookRun, u64)) {
+		for slot in &self.slots {
+			let before = slot.seq.load(Ordering::Acquire);
+			if before == 0 || before % 2 == 1 {
+				continue;
+			}
+			let hook = slot.hook.load(Ordering::Relaxed);
+			let nanos = slot.nanos.load(Ordering::Relaxed);
+			fence(Ordering::Acquire);
+			if slot.seq.load(Ordering::Relaxed) != before {
+				continue;
+			}
+			let run = if hook & 1 == 1 { HookRun::Async } else { HookRun::Sync };
+			f((hook >> 32) as u32, run, nanos);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+	use std::time::Duration;
+
+	use super::*;
+	use crate::core::{RegistryMetaStatic, RegistrySource};
+	use crate::hooks::{
+		HookAction, HookContext, HookDef, HookHandler, HookInput, HookMutability, HookPhase, HookPriority, HookResult, HookSchedule, emit_sync,
+	};
+
+	fn slow_hook(_ctx: &HookContext) -> HookAction {
+		std::thread::sleep(Duration::from_millis(8));
+		HookAction::Done(HookResult::Continue)
+	}
+
+	static SLOW_HOOK: HookDef = HookDef {
+		meta: RegistryMetaStatic {
+			id: "registry::hooks::stats_slow_test",
+			name: "stats_slow_test",
+			keys: &[],
+			categories: &[],
+			description: "deliberately slow hook for stats tests",
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		event: HookEvent::EditorQuit,
+		mutability: HookMutability::Immutable,
+		execution_priority: HookPriority::Interactive,
+		handler: HookHandler::Immutable(slow_hook),
+		filter: None,
+		schedule: HookSchedule::Always,
+		phase: HookPhase::Main,
+		tags: &[],
+	};
+
+	#[test]
+	fn slow_hook_appears_in_stats() {
+		crate::db::HOOKS.inner.replace(Arc::new(HookInput::Static(SLOW_HOOK.clone())));
+		set_stats_enabled(true);
+
+		let ctx = HookContext::new(crate::HookEventData::EditorQuit);
+		for _ in 0..3 {
+			assert_eq!(emit_sync(&ctx), HookResult::Continue);
+		}
+
+		let stat = stats()
+			.into_iter()
+			.find(|stat| stat.id == SLOW_HOOK.meta.id)
+			.expect("slow hook should have a stat entry");
+		assert_eq!(stat.event, HookEvent::EditorQuit);
+		assert!(stat.count >= 3);
+		assert_eq!(stat.async_count, 0);
+		assert!(stat.max >= Duration::from_millis(8));
+		assert!(stat.mean >= Duration::from_millis(8) && stat.mean <= stat.max);
+		assert!(stat.p95 <= stat.max);
+	}
+
+	#[test]
+	fn ring_skips_unwritten_slots_and_keeps_latest_records() {
+		let ring = Ring::new();
+		let mut seen = Vec::new();
+		ring.for_each(|hook, run, nanos| seen.push((hook, run, nanos)));
+		assert!(seen.is_empty());
+
+		for n in 0..(RING_CAPACITY as u64 + 2) {
+			ring.push(7, if n % 2 == 0 { HookRun::Sync } else { HookRun::Async }, n);
+		}
+		ring.for_each(|hook, run, nanos| seen.push((hook, run, nanos)));
+		assert_eq!(seen.len(), RING_CAPACITY);
+		assert!(seen.iter().all(|&(hook, _, nanos)| hook == 7 && nanos >= 2), "oldest records are overwritten");
+		assert!(seen.iter().all(|&(_, run, nanos)| (run == HookRun::Async) == (nanos % 2 == 1)));
+	}
+}
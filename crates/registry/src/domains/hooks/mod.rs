@@ -19,14 +19,23 @@ pub mod loader;
 mod macros;
 #[path = "runtime/query.rs"]
 pub mod query;
+#[path = "runtime/register.rs"]
+mod register;
+#[path = "exec/schedule.rs"]
+mod schedule;
+#[path = "exec/spawn.rs"]
+mod spawn;
 #[path = "contract/spec.rs"]
 pub mod spec;
+#[path = "exec/stats.rs"]
+mod stats;
 #[path = "contract/types.rs"]
 mod types;
 
 pub use builtins::register_builtins;
 pub use domain::Hooks;
-pub use query::HooksRegistry;
+pub use query::{HooksRegistry, SortedHooks};
+pub use register::OwnedHookDef;
 
 /// Registers compiled hooks from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -41,9 +50,17 @@ pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
 }
 
 pub use context::{Bool, HookContext, MutableHookContext, OptionViewId, SplitDirection, Str, ViewId, WindowId, WindowKind};
-pub use emit::{HookScheduler, emit, emit_mutable, emit_sync, emit_sync_with};
+pub use emit::{
+	EmitOutcome, HookScheduler, emit, emit_mutable, emit_mutable_with_outcome, emit_scheduled_with_outcome, emit_sync, emit_sync_with, emit_sync_with_outcome,
+	emit_with_outcome,
+};
 pub use handler::{HookHandlerReg, HookHandlerStatic};
-pub use types::{HookAction, HookDef, HookEntry, HookFuture, HookHandler, HookInput, HookMutability, HookPriority, HookResult};
+pub use spawn::SharedHookScheduler;
+pub use stats::{HookStat, RING_CAPACITY, set_slow_hook_threshold, set_stats_enabled, stats, stats_enabled};
+pub use types::{
+	DynHookHandler, HookAction, HookDef, HookEntry, HookFilter, HookFuture, HookHandler, HookInput, HookMutability, HookPhase, HookPriority, HookResult,
+	HookSchedule, tags,
+};
 pub use xeno_primitives::Mode;
 
 #[cfg(feature = "minimal")]
@@ -63,8 +80,50 @@ pub fn find_hooks(event: crate::HookEvent) -> Vec<HooksRef> {
 	hooks_for_event(event)
 }
 
+/// Returns the hooks for `event` by phase, then priority; see [`HooksRegistry::for_event_sorted`].
+#[cfg(feature = "minimal")]
+pub fn hooks_for_event_sorted(event: crate::HookEvent) -> impl Iterator<Item = HooksRef> {
+	HOOKS.for_event_sorted(event)
+}
+
+/// Returns the hooks for `event` in `phase`, in the order emit runs them.
+#[cfg(feature = "minimal")]
+pub fn find_hooks_in_phase(event: crate::HookEvent, phase: HookPhase) -> Vec<HooksRef> {
+	hooks_for_event_sorted(event).filter(|hook| hook.phase == phase).collect()
+}
+
+/// Returns the hooks carrying `tag`; see [`tags`] for the standard ones.
+#[cfg(feature = "minimal")]
+pub fn find_hooks_by_tag(tag: &str) -> Vec<HooksRef> {
+	HOOKS.by_tag(tag)
+}
+
+/// Returns every tag used by a registered hook, sorted and without duplicates.
+#[cfg(feature = "minimal")]
+pub fn all_hook_tags() -> Vec<String> {
+	HOOKS.tags()
+}
+
+/// Registers a hook at runtime; see [`HooksRegistry::register_runtime`].
+#[cfg(feature = "minimal")]
+pub fn register_runtime(def: OwnedHookDef) -> crate::core::InsertAction {
+	HOOKS.register_runtime(def)
+}
+
+/// Removes a runtime hook; see [`HooksRegistry::unregister`].
+#[cfg(feature = "minimal")]
+pub fn unregister(id: &str) -> bool {
+	HOOKS.unregister(id)
+}
+
 /// Returns all registered hooks.
 #[cfg(feature = "minimal")]
 pub fn all_hooks() -> Vec<HooksRef> {
 	HOOKS.snapshot_guard().iter_refs().collect()
 }
+
+/// Returns all registered hooks by phase, then priority, sorted once per registry generation.
+#[cfg(feature = "minimal")]
+pub fn all_hooks_sorted_by_priority() -> std::sync::Arc<SortedHooks> {
+	HOOKS.sorted_by_priority()
+}
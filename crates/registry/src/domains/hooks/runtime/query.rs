@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+
 use crate::HookEvent;
-use crate::core::index::RegistryRef;
+use crate::core::index::{RegistryRef, Snapshot};
 use crate::core::{DenseId, HookId, RegistryIndex, RuntimeRegistry};
 use crate::hooks::HookEntry;
 
@@ -8,12 +12,53 @@ pub type HooksRef = RegistryRef<HookEntry, HookId>;
 
 pub struct HooksRegistry {
 	pub(super) inner: RuntimeRegistry<HookEntry, HookId>,
+	/// Execution order of the most recently sorted snapshot; see [`Self::sorted_by_priority`].
+	sorted: ArcSwapOption<SortedHooks>,
+}
+
+/// Hooks of one registry snapshot in execution order: stable-sorted by phase,
+/// then by priority within a phase (lower runs first).
+///
+/// Ties keep registration order. Holding this pins the snapshot it was sorted from.
+pub struct SortedHooks {
+	snap: Arc<Snapshot<HookEntry, HookId>>,
+	order: Box<[HookId]>,
+}
+
+impl SortedHooks {
+	fn new(snap: Arc<Snapshot<HookEntry, HookId>>) -> Self {
+		let mut order: Vec<HookId> = snap.entries().map(|(idx, _)| HookId::from_u32(idx as u32)).collect();
+		order.sort_by_key(|id| {
+			let entry = &snap.table[id.as_u32() as usize];
+			(entry.phase, entry.meta.priority)
+		});
+		Self {
+			snap,
+			order: order.into_boxed_slice(),
+		}
+	}
+
+	/// Returns an iterator over the hooks in execution order.
+	pub fn iter(&self) -> impl Iterator<Item = HooksRef> + '_ {
+		self.order.iter().map(|&id| RegistryRef { snap: self.snap.clone(), id })
+	}
+
+	/// Returns the number of hooks.
+	pub fn len(&self) -> usize {
+		self.order.len()
+	}
+
+	/// Returns true if no hooks are registered.
+	pub fn is_empty(&self) -> bool {
+		self.order.is_empty()
+	}
 }
 
 impl HooksRegistry {
 	pub fn new(builtins: RegistryIndex<HookEntry, HookId>) -> Self {
 		Self {
 			inner: RuntimeRegistry::new("hooks", builtins),
+			sorted: ArcSwapOption::empty(),
 		}
 	}
 
@@ -32,7 +77,7 @@ impl HooksRegistry {
 	pub fn for_event(&self, event: HookEvent) -> Vec<HooksRef> {
 		let snap = self.inner.snapshot();
 		let mut refs = Vec::new();
-		for (idx, entry) in snap.table.iter().enumerate() {
+		for (idx, entry) in snap.entries() {
 			if entry.event == event {
 				refs.push(RegistryRef {
 					snap: snap.clone(),
@@ -43,6 +88,62 @@ impl HooksRegistry {
 		refs
 	}
 
+	/// Returns the hooks carrying `tag`, in registration order.
+	pub fn by_tag(&self, tag: &str) -> Vec<HooksRef> {
+		let snap = self.inner.snapshot();
+		let Some(sym) = snap.interner.get(tag) else {
+			return Vec::new();
+		};
+		let mut refs = Vec::new();
+		for (idx, entry) in snap.entries() {
+			if entry.tags.contains(&sym) {
+				refs.push(RegistryRef {
+					snap: snap.clone(),
+					id: HookId::from_u32(idx as u32),
+				});
+			}
+		}
+		refs
+	}
+
+	/// Returns every tag used by a registered hook, sorted and without duplicates.
+	pub fn tags(&self) -> Vec<String> {
+		let snap = self.inner.snapshot();
+		let mut tags: Vec<String> = snap
+			.entries()
+			.flat_map(|(_, entry)| entry.tags.iter())
+			.map(|&sym| snap.interner.resolve(sym).to_string())
+			.collect();
+		tags.sort_unstable();
+		tags.dedup();
+		tags
+	}
+
+	/// Returns all hooks of the current snapshot by phase, then priority.
+	///
+	/// The order is sorted once per snapshot generation and shared, so repeated
+	/// calls between registry mutations neither re-sort nor allocate.
+	pub fn sorted_by_priority(&self) -> Arc<SortedHooks> {
+		let snap = self.inner.snapshot();
+		if let Some(sorted) = self.sorted.load_full()
+			&& sorted.snap.generation == snap.generation
+		{
+			return sorted;
+		}
+		let sorted = Arc::new(SortedHooks::new(snap));
+		self.sorted.store(Some(Arc::clone(&sorted)));
+		sorted
+	}
+
+	/// Returns the hooks for `event` in execution order, filtered from [`Self::sorted_by_priority`].
+	pub fn for_event_sorted(&self, event: HookEvent) -> impl Iterator<Item = HooksRef> + use<> {
+		let sorted = self.sorted_by_priority();
+		(0..sorted.len()).filter_map(move |pos| {
+			let id = sorted.order[pos];
+			(sorted.snap.table[id.as_u32() as usize].event == event).then(|| RegistryRef { snap: sorted.snap.clone(), id })
+		})
+	}
+
 	pub fn len(&self) -> usize {
 		self.inner.len()
 	}
@@ -51,9 +152,13 @@ impl HooksRegistry {
 		self.inner.is_empty()
 	}
 
-	pub fn collisions(&self) -> &[crate::core::Collision] {
+	pub fn collisions(&self) -> std::sync::Arc<[crate::core::Collision]> {
 		self.inner.collisions()
 	}
+
+	pub fn all_deprecated(&self) -> impl Iterator<Item = HooksRef> {
+		self.inner.all_deprecated()
+	}
 }
 
 #[cfg(test)]
@@ -61,7 +166,7 @@ mod tests {
 	use super::*;
 	use crate::core::index::RegistryBuilder;
 	use crate::core::{RegistryMetaStatic, RegistrySource};
-	use crate::hooks::{HookAction, HookContext, HookDef, HookHandler, HookInput, HookMutability, HookPriority, HookResult};
+	use crate::hooks::{HookAction, HookContext, HookDef, HookHandler, HookInput, HookMutability, HookPhase, HookPriority, HookResult, HookSchedule};
 
 	fn test_hook(_ctx: &HookContext) -> HookAction {
 		HookAction::Done(HookResult::Continue)
@@ -72,25 +177,99 @@ mod tests {
 			id: "registry::hooks::runtime_test",
 			name: "runtime_test",
 			keys: &[],
+			categories: &[],
 			description: "runtime hook test",
 			priority: 0,
 			source: RegistrySource::Runtime,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		event: crate::HookEvent::EditorTick,
 		mutability: HookMutability::Immutable,
 		execution_priority: HookPriority::Interactive,
 		handler: HookHandler::Immutable(test_hook),
+		filter: None,
+		schedule: HookSchedule::Always,
+		phase: HookPhase::Main,
+		tags: &[],
 	};
 
 	#[test]
 	fn runtime_registration_is_visible_in_event_lookup() {
 		let mut builder: RegistryBuilder<HookInput, HookEntry, HookId> = RegistryBuilder::new("hooks-test");
-		builder.push(std::sync::Arc::new(HookInput::Static(RUNTIME_HOOK)));
+		builder.push(std::sync::Arc::new(HookInput::Static(RUNTIME_HOOK.clone())));
 		let registry = HooksRegistry::new(builder.build());
 
 		let hooks = registry.for_event(crate::HookEvent::EditorTick);
 		assert_eq!(hooks.len(), 1);
 		assert_eq!(hooks[0].id_str(), RUNTIME_HOOK.meta.id);
 	}
+
+	fn prioritized(id: &'static str, event: crate::HookEvent, priority: i16) -> HookDef {
+		let mut def = RUNTIME_HOOK.clone();
+		def.meta.id = id;
+		def.meta.name = id;
+		def.meta.priority = priority;
+		def.event = event;
+		def
+	}
+
+	#[test]
+	fn sorted_by_priority_orders_phase_first_and_event_filter_keeps_order() {
+		use crate::HookEvent::{EditorTick, FocusLost};
+
+		let mut post_early = prioritized("post_early", EditorTick, -10);
+		post_early.phase = HookPhase::Post;
+		let mut pre_late = prioritized("pre_late", EditorTick, 10);
+		pre_late.phase = HookPhase::Pre;
+
+		let mut builder: RegistryBuilder<HookInput, HookEntry, HookId> = RegistryBuilder::new("hooks-test");
+		for def in [
+			prioritized("late", EditorTick, 10),
+			prioritized("focus_early", FocusLost, -5),
+			prioritized("tie_first", EditorTick, 0),
+			prioritized("early", EditorTick, -5),
+			prioritized("tie_second", EditorTick, 0),
+			post_early,
+			pre_late,
+		] {
+			builder.push(std::sync::Arc::new(HookInput::Static(def)));
+		}
+		let registry = HooksRegistry::new(builder.build());
+
+		let sorted = registry.sorted_by_priority();
+		let keys: Vec<(HookPhase, i16)> = sorted.iter().map(|hook| (hook.phase, hook.meta.priority)).collect();
+		assert!(keys.windows(2).all(|pair| pair[0] <= pair[1]), "{keys:?}");
+		assert!(Arc::ptr_eq(&sorted, &registry.sorted_by_priority()), "order is cached per generation");
+
+		let ticks: Vec<String> = registry.for_event_sorted(EditorTick).map(|hook| hook.name_str().to_string()).collect();
+		assert_eq!(ticks, ["pre_late", "early", "tie_first", "tie_second", "late", "post_early"]);
+	}
+
+	fn tagged(id: &'static str, tags: &'static [&'static str]) -> HookDef {
+		let mut def = prioritized(id, crate::HookEvent::EditorTick, 0);
+		def.tags = tags;
+		def
+	}
+
+	#[test]
+	fn tag_lookup_returns_only_tagged_hooks() {
+		use crate::hooks::tags::{DIAGNOSTIC, FORMATTING, LSP};
+
+		let mut builder: RegistryBuilder<HookInput, HookEntry, HookId> = RegistryBuilder::new("hooks-test");
+		for def in [
+			tagged("lsp_sync", &[LSP]),
+			tagged("format_on_save", &[FORMATTING, LSP]),
+			tagged("lint", &[DIAGNOSTIC]),
+			tagged("untagged", &[]),
+		] {
+			builder.push(std::sync::Arc::new(HookInput::Static(def)));
+		}
+		let registry = HooksRegistry::new(builder.build());
+
+		let lsp: Vec<String> = registry.by_tag(LSP).iter().map(|hook| hook.name_str().to_string()).collect();
+		assert_eq!(lsp, ["lsp_sync", "format_on_save"]);
+		assert!(registry.by_tag("git").is_empty());
+		assert_eq!(registry.tags(), [DIAGNOSTIC, FORMATTING, LSP]);
+	}
 }
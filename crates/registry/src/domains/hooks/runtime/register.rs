@@ -0,0 +1,170 @@
+//! Runtime hook registration for config and plugins.
+//!
+//! Runtime hooks are linked into the same [`HooksRegistry`] snapshot as compiled
+//! hooks, so emission orders them together by priority. Emit paths iterate refs
+//! that pin their snapshot, which keeps [`HooksRegistry::unregister`] safe while
+//! an emit is in flight: the removal only affects later emits.
+
+use std::sync::Arc;
+
+use super::link::{HookPayload, LinkedHookDef};
+use super::{HookAction, HookContext, HookFilter, HookHandler, HookInput, HookMutability, HookPhase, HookPriority, HookSchedule, HooksRegistry};
+use crate::HookEvent;
+use crate::core::{InsertAction, LinkedMetaOwned, RegistryEntry, RegistrySource};
+
+/// Immutable hook definition supplied at runtime.
+pub struct OwnedHookDef {
+	/// Canonical ID; registering an existing ID replaces that hook.
+	pub id: String,
+	pub description: String,
+	/// Dispatch order among hooks in the same phase (lower runs first).
+	pub priority: i16,
+	pub event: HookEvent,
+	pub execution_priority: HookPriority,
+	pub filter: Option<HookFilter>,
+	pub schedule: HookSchedule,
+	pub phase: HookPhase,
+	/// Grouping tags; see [`tags`](crate::hooks::tags).
+	pub tags: Vec<String>,
+	pub handler: Box<dyn Fn(&HookContext) -> HookAction + Send + Sync>,
+}
+
+impl OwnedHookDef {
+	/// Creates an interactive, unfiltered, unscheduled, untagged `Main` hook at priority 0.
+	pub fn new(id: impl Into<String>, event: HookEvent, handler: impl Fn(&HookContext) -> HookAction + Send + Sync + 'static) -> Self {
+		Self {
+			id: id.into(),
+			description: String::new(),
+			priority: 0,
+			event,
+			execution_priority: HookPriority::Interactive,
+			filter: None,
+			schedule: HookSchedule::Always,
+			phase: HookPhase::Main,
+			tags: Vec::new(),
+			handler: Box::new(handler),
+		}
+	}
+
+	fn into_linked(self) -> LinkedHookDef {
+		LinkedHookDef {
+			meta: LinkedMetaOwned {
+				name: self.id.clone(),
+				id: self.id,
+				keys: Vec::new(),
+				categories: Vec::new(),
+				short_desc: self.description.clone(),
+				description: self.description,
+				priority: self.priority,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+			payload: HookPayload {
+				event: self.event,
+				mutability: HookMutability::Immutable,
+				execution_priority: self.execution_priority,
+				handler: HookHandler::Dynamic(Arc::from(self.handler)),
+				filter: self.filter,
+				schedule: self.schedule,
+				phase: self.phase,
+				tags: self.tags,
+			},
+		}
+	}
+}
+
+impl HooksRegistry {
+	/// Registers `def`, replacing any hook with the same canonical ID.
+	///
+	/// A higher-priority existing hook is kept, as for any runtime replacement.
+	pub fn register_runtime(&self, def: OwnedHookDef) -> InsertAction {
+		self.inner.replace(Arc::new(HookInput::Linked(def.into_linked()))).action
+	}
+
+	/// Removes the runtime hook with canonical ID `id`.
+	///
+	/// Returns false if no such hook exists or it was not registered at runtime.
+	pub fn unregister(&self, id: &str) -> bool {
+		self.inner.remove_canonical(id, |hook| hook.source() == RegistrySource::Runtime).is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	use super::*;
+	use crate::HookEventData;
+	use crate::hooks::{HookResult, Mode, all_hooks, emit_sync, register_runtime, unregister};
+
+	fn mode_change() -> HookContext<'static> {
+		HookContext::new(HookEventData::ModeChange {
+			old_mode: Mode::Normal,
+			new_mode: Mode::Insert,
+		})
+	}
+
+	#[test]
+	fn runtime_hook_fires_cancels_and_unregisters() {
+		const ID: &str = "registry::hooks::runtime_register_test";
+		let calls = Arc::new(AtomicUsize::new(0));
+
+		let counter = Arc::clone(&calls);
+		let def = OwnedHookDef::new(ID, HookEvent::ModeChange, move |_| {
+			counter.fetch_add(1, Ordering::Relaxed);
+			HookAction::done()
+		});
+		assert_eq!(register_runtime(def), InsertAction::InsertedNew);
+		assert_eq!(emit_sync(&mode_change()), HookResult::Continue);
+		assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+		let counter = Arc::clone(&calls);
+		let def = OwnedHookDef::new(ID, HookEvent::ModeChange, move |_| {
+			counter.fetch_add(1, Ordering::Relaxed);
+			HookAction::cancel(None)
+		});
+		assert_eq!(register_runtime(def), InsertAction::ReplacedExisting);
+		assert_eq!(emit_sync(&mode_change()), HookResult::Cancel { reason: None });
+		assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+		assert!(unregister(ID));
+		assert!(!unregister(ID), "second unregister finds nothing");
+		assert_eq!(emit_sync(&mode_change()), HookResult::Continue);
+		assert_eq!(calls.load(Ordering::Relaxed), 2);
+	}
+
+	#[test]
+	fn unregister_during_emit_affects_later_emits_only() {
+		const FIRST: &str = "registry::hooks::runtime_unregister_first";
+		const SECOND: &str = "registry::hooks::runtime_unregister_second";
+		let second_calls = Arc::new(AtomicUsize::new(0));
+
+		let mut first = OwnedHookDef::new(FIRST, HookEvent::FocusLost, |_| {
+			unregister(SECOND);
+			HookAction::done()
+		});
+		first.priority = -100;
+		register_runtime(first);
+		let counter = Arc::clone(&second_calls);
+		let mut second = OwnedHookDef::new(SECOND, HookEvent::FocusLost, move |_| {
+			counter.fetch_add(1, Ordering::Relaxed);
+			HookAction::done()
+		});
+		second.priority = 100;
+		register_runtime(second);
+
+		emit_sync(&HookContext::new(HookEventData::FocusLost));
+		assert_eq!(second_calls.load(Ordering::Relaxed), 1, "the in-flight emit still sees the removed hook");
+		emit_sync(&HookContext::new(HookEventData::FocusLost));
+		assert_eq!(second_calls.load(Ordering::Relaxed), 1);
+		assert!(unregister(FIRST));
+	}
+
+	#[test]
+	fn unregister_ignores_compiled_hooks() {
+		if let Some(builtin) = all_hooks().into_iter().find(|hook| hook.source() != RegistrySource::Runtime) {
+			assert!(!unregister(builtin.id_str()));
+		}
+	}
+}
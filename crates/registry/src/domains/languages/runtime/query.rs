@@ -50,7 +50,7 @@ impl LanguagesRegistry {
 	pub fn globs(&self) -> Vec<(String, LanguageId)> {
 		let snap = self.inner.snapshot();
 		let mut out = Vec::new();
-		for (idx, entry) in snap.table.iter().enumerate() {
+		for (idx, entry) in snap.entries() {
 			let id = LanguageId::from_u32(idx as u32);
 			for &sym in entry.globs.iter() {
 				out.push((snap.interner.resolve(sym).to_string(), id));
@@ -104,15 +104,19 @@ impl LanguagesRegistry {
 		self.inner.is_empty()
 	}
 
-	pub fn collisions(&self) -> &[crate::core::Collision] {
+	pub fn collisions(&self) -> std::sync::Arc<[crate::core::Collision]> {
 		self.inner.collisions()
 	}
+
+	pub fn all_deprecated(&self) -> impl Iterator<Item = LanguageRef> {
+		self.inner.all_deprecated()
+	}
 }
 
 fn best_match(snap: &Arc<Snapshot<LanguageEntry, LanguageId>>, matches: impl Fn(&LanguageEntry) -> bool) -> Option<LanguageRef> {
 	let mut winner: Option<(LanguageId, crate::core::Party)> = None;
 
-	for (idx, entry) in snap.table.iter().enumerate() {
+	for (idx, entry) in snap.entries() {
 		if !matches(entry) {
 			continue;
 		}
@@ -138,10 +142,12 @@ mod tests {
 			id: "registry::languages::builtin",
 			name: "builtin",
 			keys: &[],
+			categories: &[],
 			description: "builtin language",
 			priority: 0,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		scope: None,
 		grammar_name: None,
@@ -162,10 +168,12 @@ mod tests {
 			id: "registry::languages::runtime",
 			name: "runtime",
 			keys: &[],
+			categories: &[],
 			description: "runtime language",
 			priority: 0,
 			source: RegistrySource::Runtime,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		scope: None,
 		grammar_name: None,
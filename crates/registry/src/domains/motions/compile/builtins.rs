@@ -106,6 +106,38 @@ motion_handler!(prev_hunk, |text, range, count, extend| {
 	movement::move_to_diff_change(text, range, Direction::Backward, count, extend)
 });
 
+motion_handler!(
+	next_sibling_node,
+	syntax | ctx,
+	range,
+	count,
+	extend | { movement::move_to_sibling_node(ctx, range, Direction::Forward, count, extend) }
+);
+
+motion_handler!(
+	prev_sibling_node,
+	syntax | ctx,
+	range,
+	count,
+	extend | { movement::move_to_sibling_node(ctx, range, Direction::Backward, count, extend) }
+);
+
+motion_handler!(
+	parent_node_start,
+	syntax | ctx,
+	range,
+	count,
+	extend | { movement::move_to_parent_node_start(ctx, range, count, extend) }
+);
+
+motion_handler!(
+	node_end,
+	syntax | ctx,
+	range,
+	count,
+	extend | movement::move_to_node_end(ctx, range, count, extend)
+);
+
 pub fn register_builtins(builder: &mut crate::db::builder::RegistryDbBuilder) {
 	crate::motions::register_compiled(builder);
 }
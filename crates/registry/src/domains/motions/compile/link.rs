@@ -8,11 +8,20 @@ pub type LinkedMotionDef = LinkedDef<MotionPayload>;
 #[derive(Clone)]
 pub struct MotionPayload {
 	pub handler: MotionHandler,
+	pub inverse: Option<String>,
 }
 
 impl LinkedPayload<MotionEntry> for MotionPayload {
-	fn build_entry(&self, _ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> MotionEntry {
-		MotionEntry { meta, handler: self.handler }
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.inverse.as_deref());
+	}
+
+	fn build_entry(&self, ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> MotionEntry {
+		MotionEntry {
+			meta,
+			handler: self.handler,
+			inverse: self.inverse.as_deref().map(|name| ctx.intern(name)),
+		}
 	}
 }
 
@@ -31,13 +40,18 @@ pub fn link_motions(spec: &MotionsSpec, handlers: impl Iterator<Item = &'static
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.name.clone(),
 				},
-				payload: MotionPayload { handler: handler.handler },
+				payload: MotionPayload {
+					handler: handler.handler,
+					inverse: meta.inverse.clone(),
+				},
 			}
 		},
 		"motion",
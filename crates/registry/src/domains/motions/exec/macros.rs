@@ -2,13 +2,22 @@
 ///
 /// The metadata (description, aliases, etc.) comes from `motions.nuon`; this macro
 /// only provides the Rust handler and creates the inventory linkage.
+///
+/// The `syntax` form receives a [`SyntaxContext`](crate::motions::SyntaxContext)
+/// instead of the text and only runs once the document has a syntax tree.
 #[macro_export]
 macro_rules! motion_handler {
 	($name:ident, |$text:ident, $range:ident, $count:ident, $extend:ident| $body:expr) => {
+		$crate::motion_handler!(@register $name, Text, $text: ropey::RopeSlice<'_>, $range, $count, $extend, $body);
+	};
+	($name:ident, syntax |$ctx:ident, $range:ident, $count:ident, $extend:ident| $body:expr) => {
+		$crate::motion_handler!(@register $name, Syntax, $ctx: $crate::motions::SyntaxContext<'_>, $range, $count, $extend, $body);
+	};
+	(@register $name:ident, $kind:ident, $input:ident: $input_ty:ty, $range:ident, $count:ident, $extend:ident, $body:expr) => {
 		paste::paste! {
 			#[allow(unused_variables, non_snake_case)]
 			fn [<motion_handler_ $name>](
-				$text: ropey::RopeSlice,
+				$input: $input_ty,
 				$range: xeno_primitives::Range,
 				$count: usize,
 				$extend: bool,
@@ -21,7 +30,7 @@ macro_rules! motion_handler {
 				$crate::motions::MotionHandlerStatic {
 					name: stringify!($name),
 					crate_name: env!("CARGO_PKG_NAME"),
-					handler: [<motion_handler_ $name>],
+					handler: $crate::motions::MotionHandler::$kind([<motion_handler_ $name>]),
 				};
 
 			inventory::submit!($crate::motions::MotionHandlerReg(&[<MOTION_HANDLER_ $name>]));
@@ -11,6 +11,7 @@ mod line;
 mod objects;
 mod paragraph;
 mod search;
+mod syntax;
 mod vertical;
 mod word;
 
@@ -22,6 +23,7 @@ pub use line::*;
 pub use objects::*;
 pub use paragraph::*;
 pub use search::*;
+pub use syntax::*;
 pub use vertical::*;
 pub use word::*;
 use xeno_primitives::{CharIdx, Range};
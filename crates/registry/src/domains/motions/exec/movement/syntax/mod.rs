@@ -0,0 +1,114 @@
+//! Syntax-aware movement over named tree-sitter nodes.
+//!
+//! The registry does not parse; the editor exposes its parsed tree through
+//! [`SyntaxTree`] and passes it in a [`SyntaxContext`]. Nodes are plain byte
+//! spans so motions can be tested against fixture trees.
+
+use ropey::RopeSlice;
+use xeno_primitives::{CharIdx, Direction, Range};
+
+use super::make_range;
+
+/// Byte span of a named syntax node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxNode {
+	pub start: u32,
+	pub end: u32,
+}
+
+/// Named-node navigation over a parsed document.
+///
+/// Nodes are identified by span; when nested nodes share a span,
+/// implementations answer for the innermost one.
+pub trait SyntaxTree {
+	/// Returns the smallest named node containing `start..end`.
+	fn named_node_at(&self, start: u32, end: u32) -> Option<SyntaxNode>;
+	/// Returns the nearest named ancestor whose span is larger than `node`'s.
+	fn parent(&self, node: SyntaxNode) -> Option<SyntaxNode>;
+	/// Returns the next named sibling of `node`.
+	fn next_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode>;
+	/// Returns the previous named sibling of `node`.
+	fn prev_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode>;
+	/// Returns the spans the language's text object query captures as
+	/// `capture` (e.g. `function.inside`) that contain `start..end`, in any order.
+	///
+	/// Languages without a text object query capture nothing.
+	fn captures_containing(&self, text: RopeSlice<'_>, capture: &str, start: u32, end: u32) -> Vec<SyntaxNode> {
+		let _ = (text, capture, start, end);
+		Vec::new()
+	}
+}
+
+/// Input to syntax-aware motions: the document text and its parsed tree.
+#[derive(Clone, Copy)]
+pub struct SyntaxContext<'a> {
+	pub text: RopeSlice<'a>,
+	pub tree: &'a dyn SyntaxTree,
+}
+
+impl SyntaxContext<'_> {
+	fn node_at(&self, pos: CharIdx) -> Option<SyntaxNode> {
+		let byte = self.to_byte(pos);
+		self.tree.named_node_at(byte, byte)
+	}
+
+	/// Iterates `node` and its ancestors, innermost first.
+	fn ancestors(&self, node: SyntaxNode) -> impl Iterator<Item = SyntaxNode> + '_ {
+		std::iter::successors(Some(node), |node| self.tree.parent(*node))
+	}
+
+	pub(crate) fn to_byte(&self, pos: CharIdx) -> u32 {
+		self.text.char_to_byte(pos.min(self.text.len_chars())) as u32
+	}
+
+	pub(crate) fn to_char(&self, byte: u32) -> CharIdx {
+		self.text.byte_to_char((byte as usize).min(self.text.len_bytes()))
+	}
+}
+
+/// Moves to the start of the next or previous named sibling of the node at the cursor.
+///
+/// When that node has no sibling in `direction`, the nearest ancestor that does is used.
+pub fn move_to_sibling_node(ctx: SyntaxContext, range: Range, direction: Direction, count: usize, extend: bool) -> Range {
+	let mut pos = range.head;
+	for _ in 0..count {
+		let Some(node) = ctx.node_at(pos) else { break };
+		let sibling = ctx.ancestors(node).find_map(|node| match direction {
+			Direction::Forward => ctx.tree.next_sibling(node),
+			Direction::Backward => ctx.tree.prev_sibling(node),
+		});
+		let Some(sibling) = sibling else { break };
+		pos = ctx.to_char(sibling.start);
+	}
+	make_range(range, pos, extend)
+}
+
+/// Moves to the start of the nearest enclosing named node that begins before the cursor.
+pub fn move_to_parent_node_start(ctx: SyntaxContext, range: Range, count: usize, extend: bool) -> Range {
+	let mut pos = range.head;
+	for _ in 0..count {
+		let byte = ctx.to_byte(pos);
+		let Some(node) = ctx.node_at(pos) else { break };
+		let Some(parent) = ctx.ancestors(node).find(|node| node.start < byte) else {
+			break;
+		};
+		pos = ctx.to_char(parent.start);
+	}
+	make_range(range, pos, extend)
+}
+
+/// Moves to the last character of the innermost named node that ends after the cursor.
+pub fn move_to_node_end(ctx: SyntaxContext, range: Range, count: usize, extend: bool) -> Range {
+	let mut pos = range.head;
+	for _ in 0..count {
+		let Some(node) = ctx.node_at(pos) else { break };
+		let Some(last) = ctx.ancestors(node).map(|node| ctx.to_char(node.end).saturating_sub(1)).find(|&last| last > pos) else {
+			break;
+		};
+		pos = last;
+	}
+	make_range(range, pos, extend)
+}
+
+#[cfg(test)]
+mod tests;
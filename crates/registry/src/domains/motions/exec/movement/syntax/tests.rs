@@ -0,0 +1,157 @@
+use ropey::Rope;
+
+use super::*;
+use crate::motions::MotionHandler;
+
+/// ```text
+/// fn a() {
+///     let x = 1;
+///     x
+/// }
+/// fn b() {}
+/// ```
+const SRC: &str = "fn a() {\n    let x = 1;\n    x\n}\nfn b() {}\n";
+
+/// Named nodes of [`SRC`] as a Rust grammar parses them, in pre-order.
+const NODES: &[(&str, u32, u32)] = &[
+	("source_file", 0, 42),
+	("function_item", 0, 31),
+	("identifier", 3, 4),
+	("block", 7, 31),
+	("let_declaration", 13, 23),
+	("identifier", 17, 18),
+	("integer_literal", 21, 22),
+	("identifier", 28, 29),
+	("function_item", 32, 41),
+	("identifier", 35, 36),
+	("block", 39, 41),
+];
+
+/// Pre-order node list; a node's parent is the closest earlier node containing it.
+struct FixtureTree {
+	nodes: Vec<SyntaxNode>,
+}
+
+impl FixtureTree {
+	fn rust() -> Self {
+		Self {
+			nodes: NODES.iter().map(|&(_, start, end)| SyntaxNode { start, end }).collect(),
+		}
+	}
+
+	fn index(&self, node: SyntaxNode) -> Option<usize> {
+		self.nodes.iter().rposition(|n| *n == node)
+	}
+
+	fn parent_index(&self, idx: usize) -> Option<usize> {
+		let node = self.nodes[idx];
+		self.nodes[..idx].iter().rposition(|n| n.start <= node.start && node.end <= n.end)
+	}
+
+	fn sibling_indices(&self, node: SyntaxNode) -> (usize, impl Iterator<Item = usize> + '_) {
+		let idx = self.index(node).expect("fixture node");
+		let parent = self.parent_index(idx);
+		(idx, (0..self.nodes.len()).filter(move |&i| self.parent_index(i) == parent))
+	}
+}
+
+impl SyntaxTree for FixtureTree {
+	fn named_node_at(&self, start: u32, end: u32) -> Option<SyntaxNode> {
+		self.nodes.iter().rev().find(|n| n.start <= start && start < n.end && end <= n.end).copied()
+	}
+
+	fn parent(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		self.parent_index(self.index(node)?).map(|idx| self.nodes[idx])
+	}
+
+	fn next_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		let (idx, mut siblings) = self.sibling_indices(node);
+		siblings.find(|&i| i > idx).map(|i| self.nodes[i])
+	}
+
+	fn prev_sibling(&self, node: SyntaxNode) -> Option<SyntaxNode> {
+		let (idx, siblings) = self.sibling_indices(node);
+		siblings.filter(|&i| i < idx).last().map(|i| self.nodes[i])
+	}
+}
+
+struct Fixture {
+	text: Rope,
+	tree: FixtureTree,
+}
+
+impl Fixture {
+	fn new() -> Self {
+		Self {
+			text: Rope::from(SRC),
+			tree: FixtureTree::rust(),
+		}
+	}
+
+	fn ctx(&self) -> SyntaxContext<'_> {
+		SyntaxContext {
+			text: self.text.slice(..),
+			tree: &self.tree,
+		}
+	}
+
+	fn sibling(&self, from: usize, direction: Direction, count: usize) -> usize {
+		move_to_sibling_node(self.ctx(), Range::point(from), direction, count, false).head
+	}
+
+	fn parent(&self, from: usize, count: usize) -> usize {
+		move_to_parent_node_start(self.ctx(), Range::point(from), count, false).head
+	}
+
+	fn node_end(&self, from: usize) -> usize {
+		move_to_node_end(self.ctx(), Range::point(from), 1, false).head
+	}
+}
+
+#[test]
+fn fixture_spans_match_source() {
+	assert_eq!(&SRC[13..23], "let x = 1;");
+	assert_eq!(&SRC[32..41], "fn b() {}");
+}
+
+#[test]
+fn sibling_motion_moves_between_named_siblings() {
+	let fx = Fixture::new();
+	assert_eq!(fx.sibling(17, Direction::Forward, 1), 21, "let pattern to value");
+	assert_eq!(fx.sibling(21, Direction::Backward, 1), 17, "value to let pattern");
+	assert_eq!(fx.sibling(28, Direction::Backward, 1), 13, "tail expression to let statement");
+}
+
+#[test]
+fn sibling_motion_climbs_to_ancestor_with_sibling() {
+	let fx = Fixture::new();
+	assert_eq!(fx.sibling(28, Direction::Forward, 1), 32, "last statement climbs to the next function");
+	assert_eq!(fx.sibling(32, Direction::Forward, 1), 32, "last top-level item stays put");
+	assert_eq!(fx.sibling(0, Direction::Forward, 2), 32);
+}
+
+#[test]
+fn parent_motion_moves_to_enclosing_starts() {
+	let fx = Fixture::new();
+	assert_eq!(fx.parent(21, 1), 13, "literal to its let statement");
+	assert_eq!(fx.parent(21, 2), 7, "then to the enclosing block");
+	assert_eq!(fx.parent(21, 3), 0, "then to the function");
+	assert_eq!(fx.parent(0, 1), 0, "nothing encloses the first item's start");
+}
+
+#[test]
+fn node_end_motion_moves_to_last_char() {
+	let fx = Fixture::new();
+	assert_eq!(fx.node_end(13), 22, "let statement ends at its semicolon");
+	assert_eq!(fx.node_end(22), 30, "already at the end climbs to the block");
+}
+
+#[test]
+fn syntax_handlers_are_noops_without_a_tree() {
+	let handler = MotionHandler::Syntax(move_to_node_end);
+	let fx = Fixture::new();
+	let text = fx.text.slice(..);
+
+	assert_eq!(handler.apply(text, None, Range::point(13), 1, false), Range::point(13));
+	assert_eq!(handler.apply(text, Some(&fx.tree), Range::point(13), 1, false).head, 22);
+}
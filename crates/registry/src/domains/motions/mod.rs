@@ -30,6 +30,7 @@ pub mod spec;
 pub use builtins::register_builtins;
 pub use domain::Motions;
 pub use handler::{MotionHandlerReg, MotionHandlerStatic};
+pub use movement::{SyntaxContext, SyntaxNode, SyntaxTree};
 
 /// Registers compiled motions from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -52,8 +53,49 @@ pub mod flags {
 	pub const NONE: u32 = 0;
 }
 
-/// Handler signature for motion primitives.
-pub type MotionHandler = fn(RopeSlice, Range, usize, bool) -> Range;
+/// Signature of motions that only need the document text.
+pub type TextMotionFn = fn(RopeSlice, Range, usize, bool) -> Range;
+
+/// Signature of motions that navigate the parsed syntax tree.
+pub type SyntaxMotionFn = fn(SyntaxContext<'_>, Range, usize, bool) -> Range;
+
+/// Document state a dispatch site must supply to run a motion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+	/// Document text only.
+	Text,
+	/// Document text plus the parsed syntax tree, when one exists.
+	Syntax,
+}
+
+/// Handler for a motion primitive.
+#[derive(Clone, Copy)]
+pub enum MotionHandler {
+	Text(TextMotionFn),
+	Syntax(SyntaxMotionFn),
+}
+
+impl MotionHandler {
+	/// Returns what the handler needs from the dispatch site.
+	pub fn capability(self) -> Capability {
+		match self {
+			Self::Text(_) => Capability::Text,
+			Self::Syntax(_) => Capability::Syntax,
+		}
+	}
+
+	/// Applies the motion to `range`.
+	///
+	/// Syntax motions leave `range` unchanged when `tree` is `None`, so callers
+	/// can dispatch uniformly before a document is parsed.
+	pub fn apply(self, text: RopeSlice, tree: Option<&dyn SyntaxTree>, range: Range, count: usize, extend: bool) -> Range {
+		match (self, tree) {
+			(Self::Text(handler), _) => handler(text, range, count, extend),
+			(Self::Syntax(handler), Some(tree)) => handler(SyntaxContext { text, tree }, range, count, extend),
+			(Self::Syntax(_), None) => range,
+		}
+	}
+}
 
 /// Definition of a motion primitive (static input for builder).
 #[derive(Clone)]
@@ -62,6 +104,8 @@ pub struct MotionDef {
 	pub meta: RegistryMetaStatic,
 	/// Function that implements the motion logic.
 	pub handler: MotionHandler,
+	/// Name of the motion that reverses this one, if paired.
+	pub inverse: Option<&'static str>,
 }
 
 /// Symbolized motion entry stored in the registry snapshot.
@@ -70,6 +114,8 @@ pub struct MotionEntry {
 	pub meta: RegistryMeta,
 	/// Function that implements the motion logic.
 	pub handler: MotionHandler,
+	/// Name of the motion that reverses this one; resolve with [`inverse_def`].
+	pub inverse: Option<Symbol>,
 }
 
 crate::impl_registry_entry!(MotionEntry);
@@ -80,10 +126,12 @@ impl BuildEntry<MotionEntry> for MotionDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -91,12 +139,18 @@ impl BuildEntry<MotionEntry> for MotionDef {
 		self.meta.name
 	}
 
-	fn collect_payload_strings<'b>(&'b self, _collector: &mut crate::core::index::StringCollector<'_, 'b>) {}
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.inverse);
+	}
 
 	fn build(&self, ctx: &mut dyn BuildCtx, key_pool: &mut Vec<Symbol>) -> MotionEntry {
 		let meta = crate::core::index::meta_build::build_meta(ctx, key_pool, self.meta_ref(), []);
 
-		MotionEntry { meta, handler: self.handler }
+		MotionEntry {
+			meta,
+			handler: self.handler,
+			inverse: self.inverse.map(|name| ctx.intern(name)),
+		}
 	}
 }
 
@@ -112,6 +166,12 @@ pub fn find(name: &str) -> Option<MotionRef> {
 	MOTIONS.get(name)
 }
 
+/// Resolves the motion that reverses `motion`, if it is paired.
+#[cfg(feature = "minimal")]
+pub fn inverse_def(motion: &MotionRef) -> Option<MotionRef> {
+	MOTIONS.get(motion.resolve(motion.inverse?))
+}
+
 /// Returns all registered motions, sorted by name.
 #[cfg(feature = "minimal")]
 pub fn all() -> Vec<MotionRef> {
@@ -37,6 +37,12 @@ notif!(readonly_enabled, "Read-only enabled");
 notif!(readonly_disabled, "Read-only disabled");
 notif!(nothing_to_undo, "Nothing to undo");
 notif!(nothing_to_redo, "Nothing to redo");
+notif!(macro_empty(register: char), format!("No macro recorded in register '{}'", register));
+notif!(no_last_macro, "No macro recorded yet");
+notif!(
+	macro_recursion_limit(depth: usize),
+	format!("Macro replay stopped: nested deeper than {} levels", depth)
+);
 notif!(undo, "Undo");
 notif!(redo, "Redo");
 notif!(no_selection, "No selection");
@@ -66,13 +72,19 @@ notif!(no_matches_to_split, "No matches found to split on");
 notif!(yanked_chars(count: usize), format!("Yanked {} chars", count));
 notif!(yanked_lines(count: usize), format!("Yanked {} lines", count));
 notif!(deleted_chars(count: usize), format!("Deleted {} chars", count));
-notif!(file_saved(path: &Path), format!("Saved {}", path.display()));
+notif!(file_saved(path: &Path), format!("Saved {}", path.display()), group: "file_saved");
 notif!(file_not_found(path: &Path), format!("File not found: {}", path.display()));
 notif!(file_load_error(err: &str), format!("Failed to load file: {}", err));
 notif!(file_save_error(err: &str), format!("Failed to save: {}", err));
 notif!(buffer_closed(name: &str), format!("Closed {}", name));
 notif!(option_set(key: &str, value: &str), format!("{}={}", key, value));
 notif!(unhandled_result(variant: &str), format!("Unhandled action result: {}", variant));
+notif!(task_completed(name: &str), format!("Task '{}' finished", name));
+notif!(
+	task_failed(name: &str, err: impl core::fmt::Display),
+	format!("Task '{}' failed: {}", name, err)
+);
+notif!(task_cancelled(name: &str), format!("Task '{}' cancelled", name));
 
 notif!(info(msg: impl Into<String>), msg);
 notif!(warn(msg: impl Into<String>), msg);
@@ -4,6 +4,8 @@
 ///
 /// The metadata (level, auto-dismiss, etc.) comes from `notifications.nuon`.
 /// This macro creates a `NotificationKey` typed handle and optionally a builder function.
+/// A trailing `group: expr` makes the builder put every notification it creates
+/// in that group, so repeats replace each other on screen.
 #[macro_export]
 macro_rules! notif {
 	// Static message: notif!(name, "message")
@@ -18,6 +20,27 @@ macro_rules! notif {
 		}
 	};
 
+	// Grouped: notif!(name(arg: Type, ...), format_expr, group: key_expr)
+	($name:ident ( $($arg:ident : $ty:ty),* $(,)? ), $fmt:expr, group: $group:expr) => {
+		paste::paste! {
+			/// Const key for pattern matching and introspection.
+			pub const [<$name:upper>]: $crate::notifications::NotificationKey =
+				$crate::notifications::NotificationKey::new(
+					concat!("xeno-registry::", stringify!($name)),
+					""
+				);
+
+			/// Builder function for parameterized notification.
+			pub fn $name($($arg: $ty),*) -> $crate::notifications::Notification {
+				$crate::notifications::Notification::new_pending(
+					concat!("xeno-registry::", stringify!($name)),
+					$fmt
+				)
+				.with_group_key($group)
+			}
+		}
+	};
+
 	// Parameterized: notif!(name(arg: Type, ...), format_expr)
 	($name:ident ( $($arg:ident : $ty:ty),* $(,)? ), $fmt:expr) => {
 		paste::paste! {
@@ -24,6 +24,8 @@ pub mod keys;
 pub mod link;
 #[path = "compile/loader.rs"]
 pub mod loader;
+#[path = "runtime/queue.rs"]
+pub mod queue;
 #[path = "contract/spec.rs"]
 pub mod spec;
 
@@ -31,6 +33,7 @@ pub use builtins::register_builtins;
 pub use def::{LinkedNotificationDef, NotificationDef, NotificationInput};
 pub use domain::Notifications;
 pub use entry::NotificationEntry;
+pub use queue::{NotificationQueue, PushResult};
 
 pub use crate::core::NotificationId;
 
@@ -63,6 +66,33 @@ pub enum Level {
 	Success,
 }
 
+impl Level {
+	/// Returns the rank used for threshold filtering, lowest first; `Success`
+	/// ranks with `Info`.
+	pub const fn severity(self) -> u8 {
+		match self {
+			Self::Debug => 0,
+			Self::Info | Self::Success => 1,
+			Self::Warn => 2,
+			Self::Error => 3,
+		}
+	}
+
+	/// Parses a `notifications-min-level` value into the lowest
+	/// [`Self::severity`] still shown, or `None` for `off`.
+	///
+	/// Unknown values show every level.
+	pub fn min_severity(threshold: &str) -> Option<u8> {
+		match threshold {
+			"off" => None,
+			"error" => Some(Self::Error.severity()),
+			"warning" => Some(Self::Warn.severity()),
+			"info" => Some(Self::Info.severity()),
+			_ => Some(Self::Debug.severity()),
+		}
+	}
+}
+
 /// Controls automatic dismissal of notifications.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AutoDismiss {
@@ -94,6 +124,9 @@ pub struct Notification {
 	pub auto_dismiss: Option<AutoDismiss>,
 	/// The formatted message content.
 	pub message: String,
+	/// Group this notification belongs to; a newer notification in the same
+	/// group replaces it on screen instead of stacking below it.
+	pub group_key: Option<String>,
 }
 
 impl Notification {
@@ -104,6 +137,7 @@ impl Notification {
 			level: Some(level),
 			auto_dismiss: Some(auto_dismiss),
 			message: message.into(),
+			group_key: None,
 		}
 	}
 
@@ -114,9 +148,17 @@ impl Notification {
 			level: None,
 			auto_dismiss: None,
 			message: message.into(),
+			group_key: None,
 		}
 	}
 
+	/// Puts this notification in group `key`, replacing any notification of
+	/// that group still on screen.
+	pub fn with_group_key(mut self, key: impl Into<String>) -> Self {
+		self.group_key = Some(key.into());
+		self
+	}
+
 	/// Returns the notification level, or Info if not yet resolved.
 	pub fn level(&self) -> Level {
 		if self.level.is_none() {
@@ -176,6 +218,14 @@ impl core::fmt::Debug for NotificationKey {
 pub trait IntoNotification {
 	/// Converts this value into a notification.
 	fn into_notification(self) -> Notification;
+
+	/// Converts this value into a notification in group `key`.
+	fn into_grouped_notification(self, key: impl Into<String>) -> Notification
+	where
+		Self: Sized,
+	{
+		self.into_notification().with_group_key(key)
+	}
 }
 
 impl IntoNotification for Notification {
@@ -0,0 +1,207 @@
+//! Bounded notification queue with deduplication and per-type rate limiting.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+
+use super::Notification;
+
+/// Default window in which an identical notification is suppressed.
+pub const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_secs(1);
+
+/// Outcome of [`NotificationQueue::push`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushResult {
+	/// The notification was queued.
+	Queued,
+	/// An identical notification was queued within the dedup window.
+	Deduplicated,
+	/// A notification of the same type was queued within the rate limit.
+	RateLimited,
+}
+
+/// FIFO of pending notifications that filters repeats before they reach the UI.
+///
+/// Duplicates are notifications with the same ID and message. Rate limiting is
+/// keyed by notification ID alone. Both look at when a notification was last
+/// queued, not drained, so draining does not reset either window. A queued
+/// notification with a group key replaces the pending one of the same group in
+/// place. When full, pushing evicts the oldest pending notification.
+#[derive(Debug)]
+pub struct NotificationQueue {
+	max_capacity: usize,
+	dedup_window: Option<Duration>,
+	rate_limit: Option<Duration>,
+	pending: VecDeque<Notification>,
+	last_message: FxHashMap<(Arc<str>, String), Instant>,
+	last_type: FxHashMap<Arc<str>, Instant>,
+}
+
+impl NotificationQueue {
+	/// Creates a queue holding up to `max_capacity` notifications, with no filtering.
+	pub fn new(max_capacity: usize) -> Self {
+		Self {
+			max_capacity: max_capacity.max(1),
+			dedup_window: None,
+			rate_limit: None,
+			pending: VecDeque::new(),
+			last_message: FxHashMap::default(),
+			last_type: FxHashMap::default(),
+		}
+	}
+
+	/// Enables or disables deduplication within [`DEFAULT_DEDUP_WINDOW`].
+	pub fn with_dedup(self, dedup: bool) -> Self {
+		self.with_dedup_window(dedup.then_some(DEFAULT_DEDUP_WINDOW))
+	}
+
+	/// Sets the deduplication window; `None` disables deduplication.
+	pub fn with_dedup_window(mut self, window: Option<Duration>) -> Self {
+		self.dedup_window = window;
+		self
+	}
+
+	/// Sets the minimum interval between notifications with the same ID.
+	pub fn with_rate_limit(mut self, rate_limit: Option<Duration>) -> Self {
+		self.rate_limit = rate_limit;
+		self
+	}
+
+	/// Queues `notification` unless it is a duplicate or rate limited.
+	pub fn push(&mut self, notification: Notification) -> PushResult {
+		self.push_at(notification, Instant::now())
+	}
+
+	/// Like [`Self::push`], with an explicit arrival time.
+	pub fn push_at(&mut self, notification: Notification, now: Instant) -> PushResult {
+		let message_key = (Arc::clone(&notification.id), notification.message.clone());
+		if let Some(window) = self.dedup_window {
+			self.last_message.retain(|_, queued| now.saturating_duration_since(*queued) < window);
+			if self.last_message.contains_key(&message_key) {
+				return PushResult::Deduplicated;
+			}
+		}
+		if let Some(limit) = self.rate_limit
+			&& let Some(queued) = self.last_type.get(&notification.id)
+			&& now.saturating_duration_since(*queued) < limit
+		{
+			return PushResult::RateLimited;
+		}
+
+		if self.dedup_window.is_some() {
+			self.last_message.insert(message_key, now);
+		}
+		if self.rate_limit.is_some() {
+			self.last_type.insert(Arc::clone(&notification.id), now);
+		}
+		if let Some(key) = &notification.group_key
+			&& let Some(pending) = self.pending.iter_mut().find(|pending| pending.group_key.as_ref() == Some(key))
+		{
+			*pending = notification;
+			return PushResult::Queued;
+		}
+		if self.pending.len() == self.max_capacity {
+			self.pending.pop_front();
+		}
+		self.pending.push_back(notification);
+		PushResult::Queued
+	}
+
+	/// Removes and returns every pending notification, oldest first.
+	pub fn drain(&mut self) -> Vec<Notification> {
+		self.pending.drain(..).collect()
+	}
+
+	/// Drops every pending notification, keeping the dedup and rate-limit windows.
+	pub fn clear(&mut self) {
+		self.pending.clear();
+	}
+
+	/// Returns the number of pending notifications.
+	pub fn len(&self) -> usize {
+		self.pending.len()
+	}
+
+	/// Returns `true` if nothing is pending.
+	pub fn is_empty(&self) -> bool {
+		self.pending.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::notifications::{AutoDismiss, Level};
+
+	fn info(id: &str, message: &str) -> Notification {
+		Notification::new(id, Level::Info, AutoDismiss::DEFAULT, message)
+	}
+
+	fn messages(queue: &mut NotificationQueue) -> Vec<String> {
+		queue.drain().into_iter().map(|n| n.message).collect()
+	}
+
+	#[test]
+	fn dedup_suppresses_identical_messages_within_one_second() {
+		let mut queue = NotificationQueue::new(8).with_dedup(true);
+		let start = Instant::now();
+
+		assert_eq!(queue.push_at(info("test::saved", "saved"), start), PushResult::Queued);
+		assert_eq!(
+			queue.push_at(info("test::saved", "saved"), start + Duration::from_millis(500)),
+			PushResult::Deduplicated
+		);
+		assert_eq!(
+			queue.push_at(info("test::saved", "saved as"), start + Duration::from_millis(500)),
+			PushResult::Queued
+		);
+		assert_eq!(queue.drain().len(), 2);
+		assert_eq!(
+			queue.push_at(info("test::saved", "saved"), start + Duration::from_millis(900)),
+			PushResult::Deduplicated,
+			"draining does not reset the window"
+		);
+		assert_eq!(queue.push_at(info("test::saved", "saved"), start + Duration::from_secs(1)), PushResult::Queued);
+		assert_eq!(messages(&mut queue), ["saved"]);
+	}
+
+	#[test]
+	fn rate_limit_applies_per_notification_id() {
+		let mut queue = NotificationQueue::new(8).with_rate_limit(Some(Duration::from_millis(200)));
+		let start = Instant::now();
+
+		assert_eq!(queue.push_at(info("test::lsp", "one"), start), PushResult::Queued);
+		assert_eq!(
+			queue.push_at(info("test::lsp", "two"), start + Duration::from_millis(100)),
+			PushResult::RateLimited
+		);
+		assert_eq!(
+			queue.push_at(info("test::other", "three"), start + Duration::from_millis(100)),
+			PushResult::Queued
+		);
+		assert_eq!(queue.push_at(info("test::lsp", "four"), start + Duration::from_millis(200)), PushResult::Queued);
+		assert_eq!(messages(&mut queue), ["one", "three", "four"]);
+	}
+
+	#[test]
+	fn grouped_notification_replaces_pending_one_in_place() {
+		let mut queue = NotificationQueue::new(2);
+		queue.push(info("test::progress", "10%").with_group_key("lsp"));
+		queue.push(info("test::other", "saved"));
+		assert_eq!(queue.push(info("test::progress", "50%").with_group_key("lsp")), PushResult::Queued);
+		assert_eq!(messages(&mut queue), ["50%", "saved"]);
+	}
+
+	#[test]
+	fn overflow_evicts_oldest_pending() {
+		let mut queue = NotificationQueue::new(2);
+		for message in ["a", "b", "c"] {
+			assert_eq!(queue.push(info("test::overflow", message)), PushResult::Queued);
+		}
+		assert_eq!(queue.len(), 2);
+		assert_eq!(messages(&mut queue), ["b", "c"]);
+		assert!(queue.is_empty());
+	}
+}
@@ -12,9 +12,24 @@ pub const TAB_WIDTH: TypedOptionKey<i64> = TypedOptionKey::new("xeno-registry::t
 /// Number of lines to scroll.
 pub const SCROLL_LINES: TypedOptionKey<i64> = TypedOptionKey::new("xeno-registry::scroll_lines");
 
+/// Multiplier applied to mouse scroll distance.
+pub const SCROLL_MULTIPLIER: TypedOptionKey<f64> = TypedOptionKey::new("xeno-registry::scroll_multiplier");
+
 /// Minimum number of lines to keep above/below the cursor.
 pub const SCROLL_MARGIN: TypedOptionKey<i64> = TypedOptionKey::new("xeno-registry::scroll_margin");
 
+/// Line ending style for new lines (`lf`, `crlf`, or `auto` to follow the file).
+pub const LINE_ENDING: TypedOptionKey<String> = TypedOptionKey::new("xeno-registry::line_ending");
+
+/// Milliseconds to wait for more keys before committing a bound prefix (e.g. `g` with `g g` also bound).
+pub const KEYSEQ_TIMEOUT: TypedOptionKey<i64> = TypedOptionKey::new("xeno-registry::keyseq_timeout");
+
+/// Lowest notification level shown as a popup (`hint`, `info`, `warning`, `error`, or `off`).
+pub const NOTIFICATIONS_MIN_LEVEL: TypedOptionKey<String> = TypedOptionKey::new("xeno-registry::notifications_min_level");
+
+/// File and buffer icon set (`nerd-font`, `ascii`, or `none`).
+pub const ICONS: TypedOptionKey<String> = TypedOptionKey::new("xeno-registry::icons");
+
 /// Active color theme name.
 pub const THEME: TypedOptionKey<String> = TypedOptionKey::new("xeno-registry::theme");
 
@@ -23,6 +38,11 @@ pub const DEFAULT_THEME_ID: TypedOptionKey<String> = TypedOptionKey::new("xeno-r
 
 // Register standard validators
 crate::option_validator!(positive_int, super::validators::positive_int);
+crate::option_validator!(scroll_multiplier, super::validators::scroll_multiplier);
+crate::option_validator!(tab_width, super::validators::tab_width);
+crate::option_validator!(line_ending, super::validators::line_ending);
+crate::option_validator!(notification_level, super::validators::notification_level);
+crate::option_validator!(icon_style, super::validators::icon_style);
 
 pub fn register_builtins(builder: &mut RegistryDbBuilder) {
 	crate::options::register_compiled(builder);
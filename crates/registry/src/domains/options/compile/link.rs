@@ -13,6 +13,7 @@ pub struct OptionPayload {
 	pub default: OptionDefault,
 	pub scope: OptionScope,
 	pub validator: Option<OptionValidator>,
+	pub choices: Option<Vec<String>>,
 }
 
 impl LinkedPayload<OptionEntry> for OptionPayload {
@@ -20,6 +21,10 @@ impl LinkedPayload<OptionEntry> for OptionPayload {
 		collector.push(self.key.as_str());
 	}
 
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.extend(self.choices.iter().flatten().map(String::as_str));
+	}
+
 	fn build_entry(&self, ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> OptionEntry {
 		OptionEntry {
 			meta,
@@ -28,6 +33,10 @@ impl LinkedPayload<OptionEntry> for OptionPayload {
 			default: self.default.clone(),
 			scope: self.scope,
 			validator: self.validator,
+			choices: self
+				.choices
+				.as_ref()
+				.map(|choices| choices.iter().map(|choice| ctx.intern(choice)).collect::<Vec<_>>().into()),
 		}
 	}
 }
@@ -44,6 +53,7 @@ pub fn link_options(spec: &OptionsSpec, validators: impl Iterator<Item = &'stati
 		let default = match value_type {
 			OptionType::Bool => OptionDefault::Value(OptionValue::Bool(parse_boolish(&meta.default))),
 			OptionType::Int => OptionDefault::Value(OptionValue::Int(parse_i64(&meta.default, "int default"))),
+			OptionType::Float => OptionDefault::Value(OptionValue::Float(parse_f64(&meta.default, "float default"))),
 			OptionType::String => OptionDefault::Value(OptionValue::String(meta.default.clone())),
 		};
 
@@ -62,6 +72,7 @@ pub fn link_options(spec: &OptionsSpec, validators: impl Iterator<Item = &'stati
 				default,
 				scope,
 				validator,
+				choices: meta.choices.clone(),
 			},
 		});
 	}
@@ -73,6 +84,7 @@ fn parse_option_type(s: &str) -> OptionType {
 	match s {
 		"bool" => OptionType::Bool,
 		"int" => OptionType::Int,
+		"float" => OptionType::Float,
 		"string" => OptionType::String,
 		other => panic!("unknown option value-type: {}", other),
 	}
@@ -97,3 +109,7 @@ fn parse_boolish(s: &str) -> bool {
 fn parse_i64(s: &str, field: &'static str) -> i64 {
 	s.parse::<i64>().unwrap_or_else(|_| panic!("invalid {field}: '{s}'"))
 }
+
+fn parse_f64(s: &str, field: &'static str) -> f64 {
+	s.parse::<f64>().unwrap_or_else(|_| panic!("invalid {field}: '{s}'"))
+}
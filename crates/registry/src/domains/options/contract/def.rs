@@ -19,6 +19,8 @@ pub struct OptionDef {
 	pub default: OptionDefault,
 	pub scope: OptionScope,
 	pub validator: Option<OptionValidator>,
+	/// Allowed values for a string option; `None` accepts any string.
+	pub choices: Option<&'static [&'static str]>,
 }
 
 /// Handle to an option definition.
@@ -40,10 +42,12 @@ impl BuildEntry<OptionEntry> for OptionDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -53,9 +57,12 @@ impl BuildEntry<OptionEntry> for OptionDef {
 
 	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
 		collector.push(self.key);
+		collector.extend(self.choices.into_iter().flatten().copied());
 	}
 
 	fn build(&self, ctx: &mut dyn crate::core::index::BuildCtx, key_pool: &mut Vec<Symbol>) -> OptionEntry {
+		use crate::core::index::BuildCtxExt;
+
 		let meta = crate::core::index::meta_build::build_meta(ctx, key_pool, self.meta_ref(), [self.key]);
 
 		OptionEntry {
@@ -65,6 +72,7 @@ impl BuildEntry<OptionEntry> for OptionDef {
 			default: self.default.clone(),
 			scope: self.scope,
 			validator: self.validator,
+			choices: self.choices.map(|choices| ctx.intern_slice(choices)),
 		}
 	}
 }
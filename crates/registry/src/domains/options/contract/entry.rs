@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use super::def::{OptionScope, OptionValidator};
 use crate::core::{OptionDefault, OptionType, RegistryMeta, Symbol};
 
@@ -9,6 +11,8 @@ pub struct OptionEntry {
 	pub default: OptionDefault,
 	pub scope: OptionScope,
 	pub validator: Option<OptionValidator>,
+	/// Allowed values for a string option; `None` accepts any string.
+	pub choices: Option<Arc<[Symbol]>>,
 }
 
 crate::impl_registry_entry!(OptionEntry);
@@ -32,7 +32,9 @@ pub use domain::Options;
 pub use entry::OptionEntry;
 pub use query::{OptionsRef, OptionsRegistry};
 pub use resolver::OptionResolver;
-pub use store::OptionStore;
+pub use store::{
+	FiletypeOptions, OptionChange, OptionDiff, OptionLayer, OptionOrigin, OptionSnapshot, OptionSource, OptionStore, OptionTransaction, OptionWatch,
+};
 pub use typed_keys::TypedOptionKey;
 
 /// Registers compiled options from the embedded spec.
@@ -49,7 +51,10 @@ pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
 
 /// Typed handles for built-in options.
 pub mod option_keys {
-	pub use crate::options::builtins::{CURSORLINE, DEFAULT_THEME_ID, SCROLL_LINES, SCROLL_MARGIN, TAB_WIDTH, THEME};
+	pub use crate::options::builtins::{
+		CURSORLINE, DEFAULT_THEME_ID, ICONS, KEYSEQ_TIMEOUT, LINE_ENDING, NOTIFICATIONS_MIN_LEVEL, SCROLL_LINES, SCROLL_MARGIN, SCROLL_MULTIPLIER, TAB_WIDTH,
+		THEME,
+	};
 }
 
 // Re-exports for convenience.
@@ -118,6 +123,14 @@ pub fn validate_ref(opt: &OptionsRef, value: &OptionValue) -> Result<(), OptionE
 			got: value.type_name(),
 		});
 	}
+	if let (Some(choices), OptionValue::String(s)) = (choices(opt), value)
+		&& !choices.contains(&s.as_str())
+	{
+		return Err(OptionError::InvalidValue {
+			option: opt.name_str().to_string(),
+			reason: format!("'{s}' is not one of: {}", choices.join(", ")),
+		});
+	}
 	if let Some(validator) = opt.validator {
 		validator(value).map_err(|reason| OptionError::InvalidValue {
 			option: opt.name_str().to_string(),
@@ -127,6 +140,11 @@ pub fn validate_ref(opt: &OptionsRef, value: &OptionValue) -> Result<(), OptionE
 	Ok(())
 }
 
+/// Returns the allowed values of a choice option, in declaration order.
+pub fn choices(opt: &OptionsRef) -> Option<Vec<&str>> {
+	opt.choices.as_ref().map(|choices| choices.iter().map(|&sym| opt.resolve(sym)).collect())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OptionError {
 	UnknownOption(String),
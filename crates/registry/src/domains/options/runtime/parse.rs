@@ -24,6 +24,7 @@ pub fn parse_value_for_type(value: &str, ty: OptionType) -> Result<OptionValue,
 	match ty {
 		OptionType::Bool => parse_bool(value).map(OptionValue::Bool),
 		OptionType::Int => parse_int(value).map(OptionValue::Int),
+		OptionType::Float => parse_float(value).map(OptionValue::Float),
 		OptionType::String => Ok(OptionValue::String(value.to_string())),
 	}
 }
@@ -42,6 +43,15 @@ pub fn parse_int(value: &str) -> Result<i64, String> {
 	value.parse::<i64>().map_err(|_| format!("invalid integer: '{value}'"))
 }
 
+/// Parse a float value; integer literals such as `2` are accepted.
+pub fn parse_float(value: &str) -> Result<f64, String> {
+	value
+		.parse::<f64>()
+		.ok()
+		.filter(|v| v.is_finite())
+		.ok_or_else(|| format!("invalid float: '{value}'"))
+}
+
 /// Suggests a similar option key using fuzzy matching.
 pub fn suggest_option(key: &str) -> Option<String> {
 	if let Some(msg) = deprecated_option_message(key) {
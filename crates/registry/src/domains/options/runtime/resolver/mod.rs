@@ -7,11 +7,12 @@
 //! # Resolution Order
 //!
 //! 1. Buffer-local override (set via `:setlocal`)
-//! 2. Language-specific config (from `language "rust" { }` block)
-//! 3. Global config (from `options { }` block)
-//! 4. Compile-time default (from `#[derive_option]` macro)
+//! 2. Filetype-specific config (from `filetype "markdown" { }` block)
+//! 3. Language-specific config (from `language "rust" { }` block)
+//! 4. Global config (from `options { }` block)
+//! 5. Compile-time default (from `#[derive_option]` macro)
 
-use crate::options::{OptionStore, OptionValue, OptionsRef};
+use crate::options::{OptionLayer, OptionOrigin, OptionStore, OptionValue, OptionsRef};
 
 #[cfg(test)]
 mod tests;
@@ -20,6 +21,7 @@ mod tests;
 #[derive(Default)]
 pub struct OptionResolver<'a> {
 	buffer_local: Option<&'a OptionStore>,
+	filetype: Option<&'a OptionStore>,
 	language: Option<&'a OptionStore>,
 	global: Option<&'a OptionStore>,
 }
@@ -36,6 +38,12 @@ impl<'a> OptionResolver<'a> {
 		self
 	}
 
+	/// Adds a filetype-specific store, consulted between buffer and language.
+	pub fn with_filetype(mut self, store: &'a OptionStore) -> Self {
+		self.filetype = Some(store);
+		self
+	}
+
 	/// Adds a language-specific store.
 	pub fn with_language(mut self, store: &'a OptionStore) -> Self {
 		self.language = Some(store);
@@ -50,22 +58,34 @@ impl<'a> OptionResolver<'a> {
 
 	/// Resolves an option through the hierarchy.
 	pub fn resolve(&self, opt: &OptionsRef) -> OptionValue {
-		if let Some(store) = self.buffer_local
-			&& let Some(v) = store.get(opt.dense_id())
-		{
-			return v.clone();
-		}
-		if let Some(store) = self.language
-			&& let Some(v) = store.get(opt.dense_id())
-		{
-			return v.clone();
+		match self.lookup(opt) {
+			Some((_, _, value)) => value.clone(),
+			None => opt.default.to_value(),
 		}
-		if let Some(store) = self.global
-			&& let Some(v) = store.get(opt.dense_id())
-		{
-			return v.clone();
+	}
+
+	/// Resolves an option through the hierarchy, reporting which layer supplied
+	/// the value and where that layer's store loaded it from.
+	pub fn resolve_with_origin(&self, opt: &OptionsRef) -> (OptionValue, OptionOrigin) {
+		match self.lookup(opt) {
+			Some((layer, store, value)) => (value.clone(), OptionOrigin::new(layer, store.source(opt.dense_id()))),
+			None => (opt.default.to_value(), OptionOrigin::new(OptionLayer::Default, None)),
 		}
-		opt.default.to_value()
+	}
+
+	/// Returns the most specific layer that sets `opt`, with its store and value.
+	fn lookup(&self, opt: &OptionsRef) -> Option<(OptionLayer, &'a OptionStore, &'a OptionValue)> {
+		[
+			(OptionLayer::Buffer, self.buffer_local),
+			(OptionLayer::Filetype, self.filetype),
+			(OptionLayer::Language, self.language),
+			(OptionLayer::Global, self.global),
+		]
+		.into_iter()
+		.find_map(|(layer, store)| {
+			let store = store?;
+			Some((layer, store, store.get(opt.dense_id())?))
+		})
 	}
 
 	/// Resolves an integer option through the hierarchy.
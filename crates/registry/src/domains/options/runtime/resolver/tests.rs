@@ -1,5 +1,6 @@
 use super::*;
 use crate::options::option_keys as keys;
+use crate::options::{FiletypeOptions, OptionLayer, OptionSource};
 
 #[test]
 fn test_resolve_default() {
@@ -103,3 +104,70 @@ fn test_type_mismatch_falls_back_to_default() {
 	// Should fall back to default (4) since type doesn't match
 	assert_eq!(resolver.resolve_int(&tab_width), 4);
 }
+
+#[test]
+fn test_resolve_filetype_between_buffer_and_language() {
+	let options = &crate::db::OPTIONS;
+	let tab_width = options.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+
+	let mut global = OptionStore::new();
+	global.set(tab_width.clone(), OptionValue::Int(8));
+	let mut language = OptionStore::new();
+	language.set(tab_width.clone(), OptionValue::Int(6));
+	let mut filetypes = FiletypeOptions::new();
+	filetypes.set_filetype("markdown", tab_width.clone(), OptionValue::Int(2));
+	let filetype = filetypes.get("markdown").unwrap();
+	let mut buffer = OptionStore::new();
+	buffer.set(tab_width.clone(), OptionValue::Int(3));
+
+	let full = OptionResolver::new()
+		.with_global(&global)
+		.with_language(&language)
+		.with_filetype(filetype)
+		.with_buffer(&buffer);
+	assert_eq!(full.resolve_int(&tab_width), 3);
+
+	let no_buffer = OptionResolver::new().with_global(&global).with_language(&language).with_filetype(filetype);
+	assert_eq!(no_buffer.resolve_int(&tab_width), 2);
+
+	let no_filetype = OptionResolver::new().with_global(&global).with_language(&language);
+	assert_eq!(no_filetype.resolve_int(&tab_width), 6);
+
+	assert_eq!(OptionResolver::new().with_global(&global).resolve_int(&tab_width), 8);
+	assert_eq!(OptionResolver::new().resolve_int(&tab_width), 4);
+	assert!(filetypes.get("rust").is_none());
+}
+
+#[test]
+fn test_resolve_with_origin_reports_each_layer() {
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let config = |line| OptionSource {
+		file: Some("config.nuon".into()),
+		line,
+	};
+
+	let (value, origin) = OptionResolver::new().resolve_with_origin(&tab_width);
+	assert_eq!((value, origin.layer), (OptionValue::Int(4), OptionLayer::Default));
+	assert_eq!(origin.to_string(), "default");
+
+	let mut global = OptionStore::new();
+	global.set_with_source(tab_width.clone(), OptionValue::Int(4), config(Some(12)));
+	let resolver = OptionResolver::new().with_global(&global);
+	assert_eq!(resolver.resolve_with_origin(&tab_width).1.to_string(), "global, config.nuon:12");
+
+	let mut language = OptionStore::new();
+	language.set_with_source(tab_width.clone(), OptionValue::Int(8), config(None));
+	let resolver = resolver.with_language(&language);
+	assert_eq!(resolver.resolve_with_origin(&tab_width).1.to_string(), "language, config.nuon");
+
+	let mut filetype = OptionStore::new();
+	filetype.set_with_source(tab_width.clone(), OptionValue::Int(3), config(Some(20)));
+	let resolver = resolver.with_filetype(&filetype);
+	assert_eq!(resolver.resolve_with_origin(&tab_width).1.to_string(), "filetype, config.nuon:20");
+
+	let mut buffer = OptionStore::new();
+	buffer.set(tab_width.clone(), OptionValue::Int(2));
+	let (value, origin) = resolver.with_buffer(&buffer).resolve_with_origin(&tab_width);
+	assert_eq!(value, OptionValue::Int(2));
+	assert_eq!(origin.to_string(), "buffer-local, set at runtime");
+}
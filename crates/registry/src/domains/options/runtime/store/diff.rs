@@ -0,0 +1,42 @@
+//! Value differences between two [`OptionStore`]s, for config reloads.
+
+use super::{OptionChange, OptionStore};
+use crate::core::OptionId;
+
+/// An option whose effective value differs between two stores, as returned by
+/// [`OptionStore::diff`].
+///
+/// `old` and `new` fall back to the option default where a store leaves the
+/// option unset, so a removed key reports its default as `new`.
+pub type OptionDiff = OptionChange;
+
+impl OptionStore {
+	/// Compares every option set in this store or `other`, returning one
+	/// [`OptionDiff`] per option whose effective value changes from `self`
+	/// (old) to `other` (new), in registry order.
+	///
+	/// Setting an option to its default, or unsetting one that held its
+	/// default, is not a change.
+	pub fn diff(&self, other: &OptionStore) -> Vec<OptionDiff> {
+		let len = self.values.len().max(other.values.len());
+		(0..len)
+			.filter_map(|idx| {
+				let id = OptionId::from_u32(idx as u32);
+				let (old, new) = (self.get(id), other.get(id));
+				if old.is_none() && new.is_none() {
+					return None;
+				}
+				let opt = crate::db::OPTIONS.get_by_id(id)?;
+				let default = opt.default.to_value();
+				let old = old.cloned().unwrap_or_else(|| default.clone());
+				let new = new.cloned().unwrap_or(default);
+				(old != new).then(|| OptionDiff {
+					key: opt.resolve(opt.key).to_string(),
+					scope: opt.scope,
+					old,
+					new,
+				})
+			})
+			.collect()
+	}
+}
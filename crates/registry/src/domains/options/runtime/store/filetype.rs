@@ -0,0 +1,53 @@
+//! Per-filetype option stores.
+
+use std::collections::HashMap;
+
+use super::OptionStore;
+use crate::options::{OptionValue, OptionsRef};
+
+/// Option overrides keyed by filetype name.
+///
+/// Unlike language stores, filetype names are free-form: they need not match a
+/// language with a grammar, so `markdown` or `txt` overrides work for any buffer
+/// whose filetype resolves to that name.
+#[derive(Debug, Clone, Default)]
+pub struct FiletypeOptions {
+	stores: HashMap<String, OptionStore>,
+}
+
+impl FiletypeOptions {
+	/// Creates an empty set of filetype stores.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets `opt` to `value` for filetype `ft`.
+	pub fn set_filetype(&mut self, ft: &str, opt: OptionsRef, value: OptionValue) {
+		self.stores.entry(ft.to_string()).or_default().set(opt, value);
+	}
+
+	/// Merges `store` into the overrides for filetype `ft`, with `store` taking precedence.
+	pub fn merge(&mut self, ft: &str, store: &OptionStore) {
+		self.stores.entry(ft.to_string()).or_default().merge(store);
+	}
+
+	/// Returns the store for filetype `ft`, if any override was set.
+	pub fn get(&self, ft: &str) -> Option<&OptionStore> {
+		self.stores.get(ft)
+	}
+
+	/// Returns the store for filetype `ft`, creating an empty one if needed.
+	pub fn get_or_insert(&mut self, ft: &str) -> &mut OptionStore {
+		self.stores.entry(ft.to_string()).or_default()
+	}
+
+	/// Returns the names of filetypes with a store.
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.stores.keys().map(String::as_str)
+	}
+
+	/// Returns `true` if no filetype has overrides.
+	pub fn is_empty(&self) -> bool {
+		self.stores.is_empty()
+	}
+}
@@ -0,0 +1,80 @@
+//! `OptionChanged` hook emission from store writes.
+
+use std::fmt;
+
+use super::OptionStore;
+use crate::HookEventData;
+use crate::hooks::{HookContext, SharedHookScheduler, emit_sync_with};
+
+/// Hook emission attached to a store by [`OptionStore::emit_hooks`].
+#[derive(Clone)]
+pub(super) struct OptionHooks {
+	/// Scope reported in the event (`global`, `buffer`, `language`, `filetype`).
+	scope: &'static str,
+	/// Receives async hook futures; the store's owner drains it.
+	scheduler: SharedHookScheduler,
+	/// Set while a batch applies its writes; the batch emits once afterwards.
+	deferred: bool,
+}
+
+impl fmt::Debug for OptionHooks {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("OptionHooks")
+			.field("scope", &self.scope)
+			.field("deferred", &self.deferred)
+			.finish()
+	}
+}
+
+impl OptionHooks {
+	fn emit(&self, key: &str) {
+		emit_sync_with(
+			&HookContext::new(HookEventData::OptionChanged { key, scope: self.scope }),
+			&mut self.scheduler.clone(),
+		);
+	}
+}
+
+impl OptionStore {
+	/// Fires an `OptionChanged` hook with `scope` after every write that changes
+	/// an effective value in this store.
+	///
+	/// Sync hooks run inside the write; async hook futures are queued on
+	/// `scheduler` for the store's owner to drain. Batches ([`Self::merge`],
+	/// [`Self::restore`], transaction commits) fire once per changed option after
+	/// the whole batch is applied. Clones of the store do not inherit this.
+	pub fn emit_hooks(&mut self, scope: &'static str, scheduler: SharedHookScheduler) {
+		self.hooks = Some(OptionHooks {
+			scope,
+			scheduler,
+			deferred: false,
+		});
+	}
+
+	/// Fires the hook for config key `key` unless no hooks are attached or a batch is applying.
+	pub(super) fn emit_changed(&self, key: &str) {
+		if let Some(hooks) = &self.hooks
+			&& !hooks.deferred
+		{
+			hooks.emit(key);
+		}
+	}
+
+	/// Runs `f` as one batch, firing hooks for the options whose effective value
+	/// differs afterwards rather than for each write.
+	pub(super) fn batch<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+		let Some(hooks) = self.hooks.as_mut().filter(|hooks| !hooks.deferred) else {
+			return f(self);
+		};
+		hooks.deferred = true;
+		let before = self.clone();
+		let result = f(self);
+		let hooks = self.hooks.as_mut().expect("hooks stay attached during a batch");
+		hooks.deferred = false;
+		let hooks = hooks.clone();
+		for change in before.diff(self) {
+			hooks.emit(&change.key);
+		}
+		result
+	}
+}
@@ -2,19 +2,98 @@
 //!
 //! The [`OptionStore`] provides a container for option values that can be used
 //! for global configuration, per-language settings, or buffer-local overrides.
+//! [`FiletypeOptions`] groups stores keyed by free-form filetype name.
 //! Multiple stores can be combined using the [`OptionResolver`](crate::options::OptionResolver)
 //! to implement layered configuration.
+//!
+//! Subsystems that react to settings (LSP, syntax, formatters) subscribe with
+//! [`OptionStore::watch`] instead of polling; every write to a watched option is
+//! pushed through a `tokio::sync::watch` channel. [`OptionStore::subscribe`]
+//! instead delivers every [`OptionChange`] in the store, with old and new values.
+//!
+//! [`OptionStore::diff`] compares two stores, e.g. before and after a config
+//! reload, so only the options that changed need handling.
+//!
+//! [`OptionStore::snapshot`] and [`OptionStore::restore`] revert a batch of
+//! changes; [`OptionStore::with_overrides`] scopes overrides to a closure.
+//! [`OptionStore::transaction`] stages a batch of writes and applies all of them
+//! or none.
+//!
+//! [`OptionStore::emit_hooks`] makes a store fire the `OptionChanged` hook
+//! itself, so every write path (`:set`, transactions, config reloads) reaches
+//! Nu and registry hooks.
+//!
+//! Values loaded from config carry an [`OptionSource`] naming their file and
+//! line; [`OptionResolver::resolve_with_origin`](crate::options::OptionResolver::resolve_with_origin)
+//! combines it with the layer that supplied the value.
 
-use crate::core::{DenseId, OptionId};
-use crate::options::{OptionError, OptionValue, OptionsRef, OptionsRegistry};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+use tokio::sync::{broadcast, watch};
+
+use crate::core::{DenseId, FromOptionValue, OptionId};
+use crate::options::{OptionError, OptionScope, OptionValue, OptionsRef, OptionsRegistry, TypedOptionKey};
+
+/// Changes buffered per [`OptionStore::subscribe`] receiver before it lags.
+const CHANGE_CAPACITY: usize = 64;
+
+/// A value change in an [`OptionStore`], delivered by [`OptionStore::subscribe`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionChange {
+	/// Config key of the changed option (e.g., `tab-width`).
+	pub key: String,
+	/// Declared scope of the changed option.
+	pub scope: OptionScope,
+	/// Value before the change; the option default when it was unset.
+	pub old: OptionValue,
+	/// Value after the change; the option default when it was removed.
+	pub new: OptionValue,
+}
 
+mod diff;
+mod filetype;
+mod hooks;
+mod origin;
+mod snapshot;
 #[cfg(test)]
 mod tests;
+mod transaction;
+mod watcher;
+
+pub use diff::OptionDiff;
+pub use filetype::FiletypeOptions;
+pub use origin::{OptionLayer, OptionOrigin, OptionSource};
+pub use snapshot::OptionSnapshot;
+pub use transaction::OptionTransaction;
+pub use watcher::OptionWatch;
 
 /// Runtime storage for option values using dense-ID indexing.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Default)]
 pub struct OptionStore {
 	values: Vec<Option<OptionValue>>,
+	/// Sources of config-loaded values, keyed by dense index; runtime writes have none.
+	sources: HashMap<usize, OptionSource>,
+	/// Change senders keyed by canonical option ID, created on first [`Self::watch`].
+	watchers: Mutex<HashMap<&'static str, watch::Sender<OptionValue>>>,
+	/// Change broadcast, created on first [`Self::subscribe`].
+	changes: Option<broadcast::Sender<OptionChange>>,
+	/// `OptionChanged` hook emission, attached by [`Self::emit_hooks`].
+	hooks: Option<hooks::OptionHooks>,
+}
+
+/// Clones values and sources only; watchers, subscribers and hooks stay with the original store.
+impl Clone for OptionStore {
+	fn clone(&self) -> Self {
+		Self {
+			values: self.values.clone(),
+			sources: self.sources.clone(),
+			watchers: Mutex::default(),
+			changes: None,
+			hooks: None,
+		}
+	}
 }
 
 impl OptionStore {
@@ -25,7 +104,13 @@ impl OptionStore {
 
 	/// Creates a store sized for the given registry.
 	pub fn with_capacity(reg: &OptionsRegistry) -> Self {
-		Self { values: vec![None; reg.len()] }
+		Self {
+			values: vec![None; reg.len()],
+			sources: HashMap::new(),
+			watchers: Mutex::default(),
+			changes: None,
+			hooks: None,
+		}
 	}
 
 	fn ensure_len(&mut self, id: OptionId) {
@@ -35,11 +120,101 @@ impl OptionStore {
 		}
 	}
 
-	/// Sets an option value by reference.
+	/// Sets an option value by reference, notifying watchers and subscribers of that option.
+	///
+	/// The value is recorded as set at runtime, clearing any previous source.
 	pub fn set(&mut self, opt: OptionsRef, value: OptionValue) {
 		let id = opt.dense_id();
 		self.ensure_len(id);
+		let changed = self.notify(&opt, &value);
 		self.values[id.as_u32() as usize] = Some(value);
+		self.sources.remove(&(id.as_u32() as usize));
+		if changed {
+			self.emit_changed(opt.resolve(opt.key));
+		}
+	}
+
+	/// Like [`Self::set`], recording where the value was loaded from.
+	pub fn set_with_source(&mut self, opt: OptionsRef, value: OptionValue, source: OptionSource) {
+		let idx = opt.dense_id().as_u32() as usize;
+		self.set(opt, value);
+		self.sources.insert(idx, source);
+	}
+
+	/// Returns where the value of `id` was loaded from, or `None` if unset or set at runtime.
+	pub fn source(&self, id: OptionId) -> Option<&OptionSource> {
+		self.get(id)?;
+		self.sources.get(&(id.as_u32() as usize))
+	}
+
+	/// Attributes every value in this store to config file `path`.
+	///
+	/// Lines already recorded are kept.
+	pub fn set_source_file(&mut self, path: &Path) {
+		for (idx, value) in self.values.iter().enumerate() {
+			if value.is_some() {
+				self.sources.entry(idx).or_default().file = Some(path.to_path_buf());
+			}
+		}
+	}
+
+	/// Subscribes to changes of `key` in this store.
+	///
+	/// The receiver starts at the store's current value (or the option default when
+	/// unset) and observes every later [`Self::set`], [`Self::set_by_key`],
+	/// [`Self::remove`], and [`Self::merge`] touching `key`. Only this store is
+	/// watched; layering across stores remains the [`OptionResolver`](crate::options::OptionResolver)'s job.
+	pub fn watch<T: FromOptionValue>(&self, key: TypedOptionKey<T>) -> OptionWatch<T> {
+		let opt = crate::db::OPTIONS.get_key(&key.untyped()).expect("typed option key missing from registry");
+		let default = opt.default.to_value();
+		let mut watchers = self.watchers.lock().unwrap_or_else(PoisonError::into_inner);
+		let sender = watchers.entry(key.canonical_id()).or_insert_with(|| {
+			let current = self.get(opt.dense_id()).cloned().unwrap_or_else(|| default.clone());
+			watch::Sender::new(current)
+		});
+		OptionWatch::new(sender.subscribe(), default)
+	}
+
+	/// Subscribes to every value change in this store.
+	///
+	/// Each write that changes an option's effective value in this store sends one
+	/// [`OptionChange`]; rewriting the current value sends nothing. Writes rejected by
+	/// validation never reach the store and send nothing either.
+	pub fn subscribe(&mut self) -> broadcast::Receiver<OptionChange> {
+		self.changes.get_or_insert_with(|| broadcast::Sender::new(CHANGE_CAPACITY)).subscribe()
+	}
+
+	/// Reports a write of `value` to `opt` before it is stored, returning
+	/// whether it changes the store's effective value.
+	///
+	/// Pushes `value` to the watcher of `opt` when it differs from the last value
+	/// sent, and broadcasts an [`OptionChange`] when it differs from the stored value.
+	fn notify(&mut self, opt: &OptionsRef, value: &OptionValue) -> bool {
+		let old = self.get(opt.dense_id()).cloned().unwrap_or_else(|| opt.default.to_value());
+		let changed = &old != value;
+		if changed
+			&& let Some(changes) = &self.changes
+			&& changes.receiver_count() > 0
+		{
+			let _ = changes.send(OptionChange {
+				key: opt.resolve(opt.key).to_string(),
+				scope: opt.scope,
+				old,
+				new: value.clone(),
+			});
+		}
+
+		let watchers = self.watchers.get_mut().unwrap_or_else(PoisonError::into_inner);
+		if let Some(sender) = watchers.get(opt.id_str()) {
+			sender.send_if_modified(|current| {
+				if current == value {
+					return false;
+				}
+				*current = value.clone();
+				true
+			});
+		}
+		changed
 	}
 
 	/// Sets an option value by config key.
@@ -72,19 +247,38 @@ impl OptionStore {
 		self.get(id).and_then(|v| v.as_str())
 	}
 
-	/// Removes an option from the store.
+	/// Removes an option from the store; watchers fall back to the option default.
 	pub fn remove(&mut self, opt: OptionsRef) -> Option<OptionValue> {
-		self.values.get_mut(opt.dense_id().as_u32() as usize)?.take()
+		self.get(opt.dense_id())?;
+		let changed = self.notify(&opt, &opt.default.to_value());
+		self.sources.remove(&(opt.dense_id().as_u32() as usize));
+		let removed = self.values[opt.dense_id().as_u32() as usize].take();
+		if changed {
+			self.emit_changed(opt.resolve(opt.key));
+		}
+		removed
 	}
 
-	/// Merges another store into this one.
+	/// Merges another store into this one, along with the sources of its values.
 	pub fn merge(&mut self, other: &OptionStore) {
+		self.batch(|store| store.merge_values(other));
+	}
+
+	fn merge_values(&mut self, other: &OptionStore) {
 		if other.values.len() > self.values.len() {
 			self.values.resize_with(other.values.len(), || None);
 		}
+		let watched = !self.watchers.get_mut().unwrap_or_else(PoisonError::into_inner).is_empty() || self.changes.is_some();
 		for (i, v) in other.values.iter().enumerate() {
 			if let Some(v) = v {
+				if watched && let Some(opt) = crate::db::OPTIONS.get_by_id(OptionId::from_u32(i as u32)) {
+					self.notify(&opt, v);
+				}
 				self.values[i] = Some(v.clone());
+				match other.sources.get(&i) {
+					Some(source) => self.sources.insert(i, source.clone()),
+					None => self.sources.remove(&i),
+				};
 			}
 		}
 	}
@@ -0,0 +1,87 @@
+//! Provenance of stored and resolved option values.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Where a value in an [`OptionStore`](super::OptionStore) was written from.
+///
+/// Values without a source were set at runtime (`:set`, scripts, transactions).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptionSource {
+	/// Config file the value was loaded from.
+	pub file: Option<PathBuf>,
+	/// 1-based line of the value in `file`, when it could be located.
+	pub line: Option<usize>,
+}
+
+impl OptionSource {
+	/// Creates a source at `line` of an as yet unknown file.
+	pub fn at_line(line: usize) -> Self {
+		Self { file: None, line: Some(line) }
+	}
+}
+
+/// Resolution layer that supplied an option value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionLayer {
+	/// Buffer-local override.
+	Buffer,
+	/// Filetype-specific config.
+	Filetype,
+	/// Language-specific config.
+	Language,
+	/// Global config.
+	Global,
+	/// Compile-time default.
+	Default,
+}
+
+impl OptionLayer {
+	/// Returns the human-readable layer name.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Buffer => "buffer-local",
+			Self::Filetype => "filetype",
+			Self::Language => "language",
+			Self::Global => "global",
+			Self::Default => "default",
+		}
+	}
+}
+
+/// Provenance of a resolved option value, from
+/// [`OptionResolver::resolve_with_origin`](crate::options::OptionResolver::resolve_with_origin).
+///
+/// Displays as `buffer-local, set at runtime` or `global, config.nuon:12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionOrigin {
+	pub layer: OptionLayer,
+	/// Config file the value was loaded from; `None` when set at runtime or defaulted.
+	pub file: Option<PathBuf>,
+	/// 1-based line in `file`, when known.
+	pub line: Option<usize>,
+}
+
+impl OptionOrigin {
+	/// Combines `layer` with the source recorded by its store.
+	pub fn new(layer: OptionLayer, source: Option<&OptionSource>) -> Self {
+		let source = source.cloned().unwrap_or_default();
+		Self {
+			layer,
+			file: source.file,
+			line: source.line,
+		}
+	}
+}
+
+impl fmt::Display for OptionOrigin {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.layer.as_str())?;
+		match (&self.file, self.line) {
+			_ if self.layer == OptionLayer::Default => Ok(()),
+			(Some(file), Some(line)) => write!(f, ", {}:{line}", file.display()),
+			(Some(file), None) => write!(f, ", {}", file.display()),
+			(None, _) => f.write_str(", set at runtime"),
+		}
+	}
+}
@@ -0,0 +1,111 @@
+//! Point-in-time copies of an [`OptionStore`] for transactional changes.
+
+use std::collections::HashMap;
+
+use super::{OptionSource, OptionStore};
+use crate::options::{OptionError, OptionValue};
+
+/// Values set in an [`OptionStore`] and their sources, keyed by canonical option ID.
+///
+/// Keying by canonical ID rather than dense ID keeps a snapshot valid across
+/// registry changes that shift dense IDs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionSnapshot {
+	values: HashMap<String, OptionValue>,
+	sources: HashMap<String, OptionSource>,
+}
+
+impl OptionSnapshot {
+	/// Returns the captured value for canonical option ID `id`.
+	pub fn get(&self, id: &str) -> Option<&OptionValue> {
+		self.values.get(id)
+	}
+
+	/// Returns the number of captured values.
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Returns `true` if no values were captured.
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+}
+
+/// Restores the store to its snapshot when dropped, including during unwinding.
+struct RestoreGuard<'a> {
+	store: &'a mut OptionStore,
+	snapshot: Option<OptionSnapshot>,
+}
+
+impl Drop for RestoreGuard<'_> {
+	fn drop(&mut self) {
+		if let Some(snapshot) = self.snapshot.take() {
+			self.store.restore(snapshot);
+		}
+	}
+}
+
+impl OptionStore {
+	/// Captures every value currently set in this store.
+	///
+	/// Values whose dense ID no longer resolves in the options registry are skipped.
+	pub fn snapshot(&self) -> OptionSnapshot {
+		let mut snapshot = OptionSnapshot::default();
+		for (id, value) in self.iter() {
+			let Some(opt) = crate::db::OPTIONS.get_by_id(id) else {
+				continue;
+			};
+			if let Some(source) = self.source(id) {
+				snapshot.sources.insert(opt.id_str().to_string(), source.clone());
+			}
+			snapshot.values.insert(opt.id_str().to_string(), value.clone());
+		}
+		snapshot
+	}
+
+	/// Replaces the contents of this store with `snapshot`.
+	///
+	/// Options set now but absent from the snapshot are removed, and watchers see
+	/// every resulting change. Snapshot entries for options no longer registered
+	/// are dropped.
+	pub fn restore(&mut self, snapshot: OptionSnapshot) {
+		self.batch(|store| store.restore_values(snapshot));
+	}
+
+	fn restore_values(&mut self, mut snapshot: OptionSnapshot) {
+		let set: Vec<_> = self.iter().filter_map(|(id, _)| crate::db::OPTIONS.get_by_id(id)).collect();
+		for opt in set {
+			if !snapshot.values.contains_key(opt.id_str()) {
+				self.remove(opt);
+			}
+		}
+		for (id, value) in snapshot.values {
+			let Some(opt) = crate::db::OPTIONS.get(&id) else {
+				continue;
+			};
+			match snapshot.sources.remove(&id) {
+				Some(source) => self.set_with_source(opt, value, source),
+				None => self.set(opt, value),
+			}
+		}
+	}
+
+	/// Applies `overrides` by config key, runs `f`, then restores the previous contents.
+	///
+	/// The restore also runs if an override is rejected or `f` panics.
+	pub fn with_overrides<R, F>(&mut self, overrides: &[(&str, OptionValue)], f: F) -> Result<R, OptionError>
+	where
+		F: FnOnce(&mut OptionStore) -> R,
+	{
+		let snapshot = self.snapshot();
+		let guard = RestoreGuard {
+			store: self,
+			snapshot: Some(snapshot),
+		};
+		for (key, value) in overrides {
+			guard.store.set_by_key(&crate::db::OPTIONS, key, value.clone())?;
+		}
+		Ok(f(guard.store))
+	}
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::options::option_keys as keys;
+use crate::options::{OptionDef, OptionDefault, OptionInput, OptionType, RegistryMetaStatic, RegistrySource};
 
 #[test]
 fn test_set_and_get() {
@@ -95,3 +96,366 @@ fn test_string_option() {
 
 	assert_eq!(store.get_string(theme.dense_id()), Some("monokai"));
 }
+
+#[test]
+fn test_watch_receives_set_without_polling() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let mut watcher = store.watch(keys::TAB_WIDTH);
+	assert_eq!(watcher.get_and_update(), 4);
+	assert!(!watcher.has_changed());
+
+	store.set(tab_width.clone(), OptionValue::Int(2));
+	assert!(watcher.has_changed());
+	assert_eq!(watcher.get_and_update(), 2);
+
+	store.set(tab_width.clone(), OptionValue::Int(2));
+	assert!(!watcher.has_changed(), "rewriting the same value must not notify");
+
+	store.remove(tab_width);
+	assert!(watcher.has_changed());
+	assert_eq!(watcher.get(), 4, "removal falls back to the default");
+}
+
+#[test]
+fn test_watch_starts_at_current_value_and_sees_merge() {
+	let mut store = OptionStore::new();
+	store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(8)).unwrap();
+	let mut watcher = store.watch(keys::TAB_WIDTH);
+	assert_eq!(watcher.get_and_update(), 8);
+
+	let mut overlay = OptionStore::new();
+	overlay.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(3)).unwrap();
+	store.merge(&overlay);
+	assert!(watcher.has_changed());
+	assert_eq!(watcher.get_and_update(), 3);
+}
+
+#[test]
+fn test_subscribe_sees_one_change_per_write_with_old_and_new() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let mut changes = store.subscribe();
+
+	store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(2)).unwrap();
+	store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(2)).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+	store.remove(tab_width);
+
+	let change = |old, new| OptionChange {
+		key: "tab-width".to_string(),
+		scope: OptionScope::Buffer,
+		old: OptionValue::Int(old),
+		new: OptionValue::Int(new),
+	};
+	assert_eq!(changes.try_recv().unwrap(), change(4, 2));
+	assert_eq!(changes.try_recv().unwrap(), change(2, 8), "rewriting the same value must not notify");
+	assert_eq!(changes.try_recv().unwrap(), change(8, 4));
+	assert!(changes.try_recv().is_err());
+}
+
+#[test]
+fn test_subscribe_skips_writes_rejected_by_validation() {
+	let mut store = OptionStore::new();
+	let mut changes = store.subscribe();
+
+	assert!(store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(0)).is_err());
+	assert!(store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::String("wide".into())).is_err());
+	assert!(changes.try_recv().is_err());
+}
+
+#[test]
+fn test_snapshot_restore_reverts_sets_and_removals() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let theme = crate::db::OPTIONS.get_key(&keys::THEME.untyped()).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+
+	let snapshot = store.snapshot();
+	assert_eq!(snapshot.len(), 1);
+	assert_eq!(snapshot.get(tab_width.id_str()), Some(&OptionValue::Int(8)));
+
+	store.set(tab_width.clone(), OptionValue::Int(2));
+	store.set(theme.clone(), OptionValue::String("restore-test".to_string()));
+	let mut watcher = store.watch(keys::THEME);
+	watcher.get_and_update();
+
+	store.restore(snapshot);
+	assert_eq!(store.get_int(tab_width.dense_id()), Some(8));
+	assert_eq!(store.get(theme.dense_id()), None, "options set after the snapshot are removed");
+	assert!(watcher.has_changed(), "restore notifies watchers");
+}
+
+#[test]
+fn test_transaction_with_invalid_write_applies_nothing() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let theme = crate::db::OPTIONS.get_key(&keys::THEME.untyped()).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+	let mut changes = store.subscribe();
+
+	let mut tx = store.transaction();
+	tx.set("theme", OptionValue::String("gruvbox".to_string()))
+		.set("tab-width", OptionValue::Int(0))
+		.set_buffer("scroll-lines", OptionValue::Int(2))
+		.remove("no-such-option");
+	let errors = tx.commit().unwrap_err();
+
+	assert_eq!(errors.len(), 3, "every rejection is reported: {errors:?}");
+	assert!(matches!(&errors[0], OptionError::InvalidValue { option, .. } if option == "tab_width"));
+	assert!(matches!(&errors[1], OptionError::InvalidValue { option, .. } if option == "scroll_lines"));
+	assert!(matches!(&errors[2], OptionError::UnknownOption(key) if key == "no-such-option"));
+	assert_eq!(store.get_int(tab_width.dense_id()), Some(8));
+	assert_eq!(store.get(theme.dense_id()), None);
+	assert!(changes.try_recv().is_err());
+}
+
+#[test]
+fn test_transaction_commit_notifies_effective_changes_once() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let theme = crate::db::OPTIONS.get_key(&keys::THEME.untyped()).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+	let mut changes = store.subscribe();
+
+	let mut tx = store.transaction();
+	tx.set_buffer("tab-width", OptionValue::Int(2))
+		.set("theme", OptionValue::String("gruvbox".to_string()))
+		.set_buffer("tab-width", OptionValue::Int(8))
+		.set("scroll-lines", OptionValue::Int(1));
+	tx.commit().unwrap();
+
+	assert_eq!(store.get_int(tab_width.dense_id()), Some(8));
+	assert_eq!(store.get_string(theme.dense_id()), Some("gruvbox"));
+	assert_eq!(store.len(), 3);
+	let change = changes.try_recv().unwrap();
+	assert_eq!(change.key, "theme");
+	assert_eq!(change.new, OptionValue::String("gruvbox".to_string()));
+	assert!(changes.try_recv().is_err(), "superseded and unchanged writes send nothing");
+}
+
+#[test]
+fn test_transaction_replace_with_keeps_subscribers_and_sources() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let theme = crate::db::OPTIONS.get_key(&keys::THEME.untyped()).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+	store.set(theme.clone(), OptionValue::String("gruvbox".to_string()));
+	let watch = store.watch(keys::TAB_WIDTH);
+	let mut changes = store.subscribe();
+
+	let mut loaded = OptionStore::new();
+	loaded.set_with_source(tab_width.clone(), OptionValue::Int(2), OptionSource::at_line(3));
+	let mut tx = store.transaction();
+	tx.replace_with(&loaded);
+	tx.commit().unwrap();
+
+	assert_eq!(store.len(), 1);
+	assert_eq!(store.source(tab_width.dense_id()), Some(&OptionSource::at_line(3)));
+	assert_eq!(watch.get(), 2);
+	let mut keys: Vec<_> = std::iter::from_fn(|| changes.try_recv().ok()).map(|change| change.key).collect();
+	keys.sort();
+	assert_eq!(keys, ["tab-width", "theme"]);
+}
+
+#[test]
+fn test_with_overrides_restores_after_closure() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+
+	let seen = store
+		.with_overrides(&[("tab-width", OptionValue::Int(2))], |store| store.get_int(tab_width.dense_id()))
+		.unwrap();
+	assert_eq!(seen, Some(2));
+	assert_eq!(store.get_int(tab_width.dense_id()), Some(8));
+
+	let result = store.with_overrides(&[("tab-width", OptionValue::Int(3)), ("unknown-option", OptionValue::Int(1))], |_| ());
+	assert!(matches!(result, Err(OptionError::UnknownOption(_))));
+	assert_eq!(store.get_int(tab_width.dense_id()), Some(8), "a rejected override still restores");
+}
+
+#[test]
+fn test_with_overrides_restores_when_closure_panics() {
+	let mut store = OptionStore::new();
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+
+	let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		store
+			.with_overrides(&[("tab-width", OptionValue::Int(2))], |_| panic!("override body failed"))
+			.ok();
+	}));
+	assert!(result.is_err());
+	assert_eq!(store.get(tab_width.dense_id()), None, "the guard restores during unwinding");
+}
+
+static LINE_NUMBERS: OptionDef = OptionDef {
+	meta: RegistryMetaStatic {
+		id: "registry::options::test_line_numbers",
+		name: "test_line_numbers",
+		keys: &[],
+		categories: &[],
+		description: "choice option for store tests",
+		priority: 0,
+		source: RegistrySource::Runtime,
+		mutates_buffer: false,
+		deprecated: None,
+	},
+	key: "test-line-numbers",
+	value_type: OptionType::String,
+	default: OptionDefault::Value(OptionValue::String(String::new())),
+	scope: OptionScope::Buffer,
+	validator: None,
+	choices: Some(&["absolute", "relative", "none"]),
+};
+
+#[test]
+fn test_set_by_key_enforces_choices() {
+	let options = &crate::db::OPTIONS;
+	options.replace(std::sync::Arc::new(OptionInput::Static(LINE_NUMBERS.clone())));
+	let opt = options.get("test-line-numbers").unwrap();
+	assert_eq!(crate::options::choices(&opt), Some(vec!["absolute", "relative", "none"]));
+
+	let mut store = OptionStore::new();
+	store.set_by_key(options, "test-line-numbers", OptionValue::String("relative".into())).unwrap();
+	assert_eq!(store.get_string(opt.dense_id()), Some("relative"));
+
+	let err = store
+		.set_by_key(options, "test-line-numbers", OptionValue::String("relativ".into()))
+		.unwrap_err();
+	assert_eq!(
+		err,
+		OptionError::InvalidValue {
+			option: "test_line_numbers".into(),
+			reason: "'relativ' is not one of: absolute, relative, none".into(),
+		}
+	);
+	assert_eq!(store.get_string(opt.dense_id()), Some("relative"));
+
+	let theme = options.get("theme").unwrap();
+	assert_eq!(crate::options::choices(&theme), None);
+	store.set_by_key(options, "theme", OptionValue::String("anything-goes".into())).unwrap();
+}
+
+#[test]
+fn test_set_by_key_runs_builtin_validators() {
+	let options = &crate::db::OPTIONS;
+	let mut store = OptionStore::new();
+	store.set_by_key(options, "tab-width", OptionValue::Int(32)).unwrap();
+	assert!(store.set_by_key(options, "tab-width", OptionValue::Int(33)).is_err());
+
+	let line_ending = options.get_key(&keys::LINE_ENDING.untyped()).unwrap();
+	store.set_by_key(options, "line-ending", OptionValue::String("crlf".into())).unwrap();
+	assert!(store.set_by_key(options, "line-ending", OptionValue::String("cr".into())).is_err());
+	assert_eq!(store.get_string(line_ending.dense_id()), Some("crlf"));
+}
+
+#[test]
+fn test_diff_reports_changed_added_and_removed_options() {
+	let options = &crate::db::OPTIONS;
+	let mut old = OptionStore::new();
+	old.set_by_key(options, "tab-width", OptionValue::Int(2)).unwrap();
+	old.set_by_key(options, "theme", OptionValue::String("gruvbox".to_string())).unwrap();
+	old.set_by_key(options, "scroll-lines", OptionValue::Int(5)).unwrap();
+	old.set_by_key(options, "cursorline", OptionValue::Bool(true)).unwrap();
+
+	let mut new = OptionStore::new();
+	new.set_by_key(options, "tab-width", OptionValue::Int(8)).unwrap();
+	new.set_by_key(options, "theme", OptionValue::String("gruvbox".to_string())).unwrap();
+	new.set_by_key(options, "line-ending", OptionValue::String("crlf".to_string())).unwrap();
+
+	let mut diff = old.diff(&new);
+	diff.sort_by(|a, b| a.key.cmp(&b.key));
+
+	assert_eq!(
+		diff,
+		[
+			OptionDiff {
+				key: "line-ending".to_string(),
+				scope: OptionScope::Buffer,
+				old: OptionValue::String("auto".to_string()),
+				new: OptionValue::String("crlf".to_string()),
+			},
+			OptionDiff {
+				key: "scroll-lines".to_string(),
+				scope: OptionScope::Global,
+				old: OptionValue::Int(5),
+				new: OptionValue::Int(1),
+			},
+			OptionDiff {
+				key: "tab-width".to_string(),
+				scope: OptionScope::Buffer,
+				old: OptionValue::Int(2),
+				new: OptionValue::Int(8),
+			},
+		],
+		"cursorline is removed but was already at its default"
+	);
+	assert!(new.diff(&new.clone()).is_empty());
+}
+
+thread_local! {
+	static OPTION_HOOK_RUNS: std::cell::RefCell<Vec<(String, String)>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn record_option_changed(ctx: &crate::hooks::HookContext) -> crate::hooks::HookAction {
+	if let crate::HookEventData::OptionChanged { key, scope } = &ctx.data {
+		OPTION_HOOK_RUNS.with(|runs| runs.borrow_mut().push((key.to_string(), scope.to_string())));
+	}
+	crate::hooks::HookAction::done()
+}
+
+static OPTION_CHANGED_RECORDER: crate::hooks::HookDef = crate::hooks::HookDef {
+	meta: RegistryMetaStatic {
+		id: "registry::options::test_option_changed_recorder",
+		name: "test_option_changed_recorder",
+		keys: &[],
+		categories: &[],
+		description: "records OptionChanged events from option stores",
+		priority: 0,
+		source: RegistrySource::Runtime,
+		mutates_buffer: false,
+		deprecated: None,
+	},
+	event: crate::HookEvent::OptionChanged,
+	mutability: crate::hooks::HookMutability::Immutable,
+	execution_priority: crate::hooks::HookPriority::Interactive,
+	handler: crate::hooks::HookHandler::Immutable(record_option_changed),
+	filter: None,
+	schedule: crate::hooks::HookSchedule::Always,
+	phase: crate::hooks::HookPhase::Main,
+	tags: &[],
+};
+
+fn option_hook_runs() -> Vec<(String, String)> {
+	OPTION_HOOK_RUNS.with(|runs| runs.take())
+}
+
+#[test]
+fn test_emit_hooks_fires_option_changed_for_every_write_path() {
+	crate::db::HOOKS
+		.inner
+		.replace(std::sync::Arc::new(crate::hooks::HookInput::Static(OPTION_CHANGED_RECORDER.clone())));
+	let tab_width = crate::db::OPTIONS.get_key(&keys::TAB_WIDTH.untyped()).unwrap();
+	let mut store = OptionStore::new();
+	store.set(tab_width.clone(), OptionValue::Int(8));
+	assert!(option_hook_runs().is_empty(), "stores without hooks attached stay silent");
+
+	store.emit_hooks("language", crate::hooks::SharedHookScheduler::new());
+	store.set_by_key(&crate::db::OPTIONS, "tab-width", OptionValue::Int(2)).unwrap();
+	store.set(tab_width.clone(), OptionValue::Int(2));
+	assert_eq!(option_hook_runs(), [("tab-width".to_string(), "language".to_string())]);
+
+	let mut tx = store.transaction();
+	tx.set_buffer("tab-width", OptionValue::Int(3))
+		.set("theme", OptionValue::String("gruvbox".to_string()))
+		.set_buffer("tab-width", OptionValue::Int(2));
+	tx.commit().unwrap();
+	assert_eq!(option_hook_runs(), [("theme".to_string(), "language".to_string())]);
+
+	let snapshot = store.snapshot();
+	store.remove(tab_width.clone());
+	assert_eq!(option_hook_runs(), [("tab-width".to_string(), "language".to_string())]);
+	store.restore(snapshot);
+	assert_eq!(option_hook_runs(), [("tab-width".to_string(), "language".to_string())]);
+}
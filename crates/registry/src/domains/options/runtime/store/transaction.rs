@@ -0,0 +1,172 @@
+//! All-or-nothing batches of option writes.
+
+use super::{OptionSource, OptionStore};
+use crate::options::{OptionError, OptionScope, OptionValue, OptionsRef};
+
+/// A staged write, applied on [`OptionTransaction::commit`].
+enum Staged {
+	Set {
+		key: String,
+		value: OptionValue,
+		buffer: bool,
+		source: Option<OptionSource>,
+	},
+	Remove {
+		key: String,
+	},
+}
+
+/// A validated write: the value and its source, or `None` for a removal.
+type Write = Option<(OptionValue, Option<OptionSource>)>;
+
+/// Batch of option writes staged against an [`OptionStore`].
+///
+/// Nothing reaches the store until [`Self::commit`], which validates every
+/// staged write first and applies none of them if any is rejected. Dropping
+/// the transaction discards it.
+#[must_use = "staged writes are discarded unless the transaction is committed"]
+pub struct OptionTransaction<'a> {
+	store: &'a mut OptionStore,
+	staged: Vec<Staged>,
+}
+
+impl OptionTransaction<'_> {
+	/// Stages setting config key `key` to `value`.
+	pub fn set(&mut self, key: impl Into<String>, value: OptionValue) -> &mut Self {
+		self.staged.push(Staged::Set {
+			key: key.into(),
+			value,
+			buffer: false,
+			source: None,
+		});
+		self
+	}
+
+	/// Stages setting `key` in a buffer-local or language store.
+	///
+	/// Commit rejects the write when `key` names a global option, matching
+	/// `:setlocal` and language config blocks.
+	pub fn set_buffer(&mut self, key: impl Into<String>, value: OptionValue) -> &mut Self {
+		self.staged.push(Staged::Set {
+			key: key.into(),
+			value,
+			buffer: true,
+			source: None,
+		});
+		self
+	}
+
+	/// Stages removing `key` from the store.
+	pub fn remove(&mut self, key: impl Into<String>) -> &mut Self {
+		self.staged.push(Staged::Remove { key: key.into() });
+		self
+	}
+
+	/// Stages replacing the store's contents with `loaded`, keeping the source of
+	/// each loaded value.
+	///
+	/// Options set in the store but not in `loaded` are removed, so the commit
+	/// leaves the store equal to `loaded`, as a config reload needs. Options no
+	/// longer registered are skipped.
+	pub fn replace_with(&mut self, loaded: &OptionStore) -> &mut Self {
+		for (id, _) in self.store.iter() {
+			if loaded.get(id).is_none()
+				&& let Some(opt) = crate::db::OPTIONS.get_by_id(id)
+			{
+				self.staged.push(Staged::Remove {
+					key: opt.resolve(opt.key).to_string(),
+				});
+			}
+		}
+		for (id, value) in loaded.iter() {
+			let Some(opt) = crate::db::OPTIONS.get_by_id(id) else {
+				continue;
+			};
+			self.staged.push(Staged::Set {
+				key: opt.resolve(opt.key).to_string(),
+				value: value.clone(),
+				buffer: false,
+				source: loaded.source(id).cloned(),
+			});
+		}
+		self
+	}
+
+	/// Returns the number of staged writes.
+	pub fn len(&self) -> usize {
+		self.staged.len()
+	}
+
+	/// Returns `true` if nothing is staged.
+	pub fn is_empty(&self) -> bool {
+		self.staged.is_empty()
+	}
+
+	/// Validates every staged write, then applies them all in staging order.
+	///
+	/// On failure the store is untouched and every rejection is returned. Later
+	/// writes to the same option supersede earlier ones, so watchers and
+	/// subscribers only see options whose value differs after the commit, and
+	/// attached hooks fire once per such option after every write is applied.
+	pub fn commit(self) -> Result<(), Vec<OptionError>> {
+		let mut errors = Vec::new();
+		let mut resolved: Vec<(OptionsRef, Write)> = Vec::with_capacity(self.staged.len());
+		for staged in self.staged {
+			match resolve(staged) {
+				Ok((opt, write)) => {
+					resolved.retain(|(prev, _)| prev.dense_id() != opt.dense_id());
+					resolved.push((opt, write));
+				}
+				Err(err) => errors.push(err),
+			}
+		}
+		if !errors.is_empty() {
+			return Err(errors);
+		}
+
+		self.store.batch(|store| {
+			for (opt, write) in resolved {
+				match write {
+					Some((value, Some(source))) => store.set_with_source(opt, value, source),
+					Some((value, None)) => store.set(opt, value),
+					None => {
+						store.remove(opt);
+					}
+				}
+			}
+		});
+		Ok(())
+	}
+}
+
+/// Looks up and validates one staged write.
+fn resolve(staged: Staged) -> Result<(OptionsRef, Write), OptionError> {
+	let (key, write, buffer) = match staged {
+		Staged::Set { key, value, buffer, source } => (key, Some((value, source)), buffer),
+		Staged::Remove { key } => (key, None, false),
+	};
+	let opt = crate::db::OPTIONS.get(&key).ok_or(OptionError::UnknownOption(key))?;
+	if buffer && opt.scope == OptionScope::Global {
+		return Err(OptionError::InvalidValue {
+			option: opt.name_str().to_string(),
+			reason: "global options cannot be set per buffer or language".to_string(),
+		});
+	}
+	if let Some((value, _)) = &write {
+		crate::options::validate_ref(&opt, value)?;
+	}
+	Ok((opt, write))
+}
+
+impl OptionStore {
+	/// Starts a transaction that stages writes to this store.
+	///
+	/// Pair with [`Self::snapshot`] to undo a committed batch later, such as a
+	/// config reload that turns out to be unwanted.
+	pub fn transaction(&mut self) -> OptionTransaction<'_> {
+		OptionTransaction {
+			store: self,
+			staged: Vec::new(),
+		}
+	}
+}
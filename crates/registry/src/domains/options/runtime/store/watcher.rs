@@ -0,0 +1,60 @@
+//! Typed receiver for option change notifications.
+
+use std::marker::PhantomData;
+
+use tokio::sync::watch;
+
+use crate::core::FromOptionValue;
+use crate::options::OptionValue;
+
+/// Typed view over a store's change channel for one option.
+///
+/// Values that fail to convert to `T` read as the option default, mirroring
+/// typed option access elsewhere.
+#[derive(Debug, Clone)]
+pub struct OptionWatch<T: FromOptionValue> {
+	rx: watch::Receiver<OptionValue>,
+	default: OptionValue,
+	_marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromOptionValue> OptionWatch<T> {
+	pub(super) fn new(rx: watch::Receiver<OptionValue>, default: OptionValue) -> Self {
+		Self {
+			rx,
+			default,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Returns the latest value without marking it seen.
+	pub fn get(&self) -> T {
+		self.convert(&self.rx.borrow())
+	}
+
+	/// Returns the latest value and marks it seen.
+	pub fn get_and_update(&mut self) -> T {
+		let value = self.rx.borrow_and_update().clone();
+		self.convert(&value)
+	}
+
+	/// Returns `true` if a value was published since it was last seen.
+	///
+	/// Returns `false` once the store has been dropped.
+	pub fn has_changed(&self) -> bool {
+		self.rx.has_changed().unwrap_or(false)
+	}
+
+	/// Waits for the next change.
+	///
+	/// Fails once the store has been dropped.
+	pub async fn changed(&mut self) -> Result<(), watch::error::RecvError> {
+		self.rx.changed().await
+	}
+
+	fn convert(&self, value: &OptionValue) -> T {
+		T::from_option(value)
+			.or_else(|| T::from_option(&self.default))
+			.expect("option type mismatch with registered default")
+	}
+}
@@ -2,7 +2,7 @@
 //!
 //! These functions can be used as validators in [`OptionDef`](crate::options::OptionDef).
 
-use crate::options::OptionValue;
+use crate::options::{OptionValidator, OptionValue};
 
 /// Validates that an integer is positive (>= 1).
 pub fn positive_int(value: &OptionValue) -> Result<(), String> {
@@ -13,5 +13,77 @@ pub fn positive_int(value: &OptionValue) -> Result<(), String> {
 	}
 }
 
+/// Returns a validator accepting integers within `min..=max`.
+///
+/// Option validators are plain function pointers, so options wrap the returned
+/// closure in a named validator with fixed bounds (see [`tab_width`]).
+pub fn range_int(min: i64, max: i64) -> impl Fn(&OptionValue) -> Result<(), String> {
+	move |value| match value {
+		OptionValue::Int(n) if (min..=max).contains(n) => Ok(()),
+		OptionValue::Int(n) => Err(format!("must be between {min} and {max}, got {n}")),
+		_ => Err("expected integer".to_string()),
+	}
+}
+
+/// Returns a validator accepting strings from a fixed set of `choices`.
+pub fn one_of_str(choices: &'static [&'static str]) -> impl Fn(&OptionValue) -> Result<(), String> {
+	move |value| match value {
+		OptionValue::String(s) if choices.contains(&s.as_str()) => Ok(()),
+		OptionValue::String(s) => Err(format!("'{s}' is not one of: {}", choices.join(", "))),
+		_ => Err("expected string".to_string()),
+	}
+}
+
+/// Returns a validator that runs `validators` in order, failing with the first error.
+pub fn all_of(validators: Vec<OptionValidator>) -> impl Fn(&OptionValue) -> Result<(), String> {
+	move |value| validators.iter().try_for_each(|validator| validator(value))
+}
+
+/// Validates a tab width in `1..=32`.
+pub fn tab_width(value: &OptionValue) -> Result<(), String> {
+	range_int(1, 32)(value)
+}
+
+/// Line ending styles accepted by the `line-ending` option.
+pub const LINE_ENDINGS: &[&str] = &["lf", "crlf", "auto"];
+
+/// Validates a `line-ending` value against [`LINE_ENDINGS`].
+pub fn line_ending(value: &OptionValue) -> Result<(), String> {
+	one_of_str(LINE_ENDINGS)(value)
+}
+
+/// Levels accepted by the `notifications-min-level` option, lowest first.
+pub const NOTIFICATION_LEVELS: &[&str] = &["hint", "info", "warning", "error", "off"];
+
+/// Validates a `notifications-min-level` value against [`NOTIFICATION_LEVELS`].
+pub fn notification_level(value: &OptionValue) -> Result<(), String> {
+	one_of_str(NOTIFICATION_LEVELS)(value)
+}
+
+/// Icon sets accepted by the `icons` option.
+pub const ICON_STYLES: &[&str] = &["nerd-font", "ascii", "none"];
+
+/// Validates an `icons` value against [`ICON_STYLES`].
+pub fn icon_style(value: &OptionValue) -> Result<(), String> {
+	one_of_str(ICON_STYLES)(value)
+}
+
+/// Validates that a float lies within `min..=max`.
+///
+/// Option validators are plain function pointers, so ranged options wrap this
+/// in a named validator with fixed bounds (see [`scroll_multiplier`]).
+pub fn in_range_f64(value: &OptionValue, min: f64, max: f64) -> Result<(), String> {
+	match value {
+		OptionValue::Float(v) if (min..=max).contains(v) => Ok(()),
+		OptionValue::Float(v) => Err(format!("must be between {min} and {max}, got {v}")),
+		_ => Err("expected float".to_string()),
+	}
+}
+
+/// Validates a scroll multiplier in `0.1..=10.0`.
+pub fn scroll_multiplier(value: &OptionValue) -> Result<(), String> {
+	in_range_f64(value, 0.1, 10.0)
+}
+
 #[cfg(test)]
 mod tests;
@@ -8,3 +8,49 @@ fn test_positive_int() {
 	assert!(positive_int(&OptionValue::Int(-1)).is_err());
 	assert!(positive_int(&OptionValue::String("foo".into())).is_err());
 }
+
+#[test]
+fn test_in_range_f64() {
+	assert!(in_range_f64(&OptionValue::Float(0.5), 0.0, 1.0).is_ok());
+	assert!(in_range_f64(&OptionValue::Float(1.0), 0.0, 1.0).is_ok());
+	assert!(in_range_f64(&OptionValue::Float(1.5), 0.0, 1.0).is_err());
+	assert!(in_range_f64(&OptionValue::Float(f64::NAN), 0.0, 1.0).is_err());
+	assert!(in_range_f64(&OptionValue::Int(1), 0.0, 1.0).is_err());
+}
+
+#[test]
+fn range_int_accepts_bounds_and_rejects_neighbours() {
+	let validate = range_int(1, 32);
+	assert!(validate(&OptionValue::Int(0)).is_err());
+	assert!(validate(&OptionValue::Int(1)).is_ok());
+	assert!(validate(&OptionValue::Int(32)).is_ok());
+	assert_eq!(validate(&OptionValue::Int(33)), Err("must be between 1 and 32, got 33".to_string()));
+	assert!(validate(&OptionValue::String("4".into())).is_err());
+
+	let validate = range_int(i64::MIN, i64::MAX);
+	assert!(validate(&OptionValue::Int(i64::MIN)).is_ok());
+	assert!(validate(&OptionValue::Int(i64::MAX)).is_ok());
+}
+
+#[test]
+fn one_of_str_accepts_listed_choices_only() {
+	assert!(line_ending(&OptionValue::String("crlf".into())).is_ok());
+	assert_eq!(
+		line_ending(&OptionValue::String("CRLF".into())),
+		Err("'CRLF' is not one of: lf, crlf, auto".to_string())
+	);
+	assert!(line_ending(&OptionValue::Int(1)).is_err());
+	assert!(notification_level(&OptionValue::String("off".into())).is_ok());
+	assert!(notification_level(&OptionValue::String("warn".into())).is_err());
+	assert!(icon_style(&OptionValue::String("ascii".into())).is_ok());
+	assert!(icon_style(&OptionValue::String("emoji".into())).is_err());
+}
+
+#[test]
+fn all_of_reports_first_failure() {
+	let validate = all_of(vec![positive_int, tab_width]);
+	assert!(validate(&OptionValue::Int(8)).is_ok());
+	assert_eq!(validate(&OptionValue::Int(0)), Err("must be at least 1, got 0".to_string()));
+	assert_eq!(validate(&OptionValue::Int(64)), Err("must be between 1 and 32, got 64".to_string()));
+	assert!(all_of(Vec::new())(&OptionValue::Bool(true)).is_ok());
+}
@@ -41,10 +41,12 @@ mod tests {
 			id: "test::language::invalid_lsp_ref",
 			name: "invalid_lsp_ref",
 			keys: &[],
+			categories: &[],
 			description: "test language with unknown lsp server",
 			priority: 0,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		scope: None,
 		grammar_name: None,
@@ -1,21 +1,28 @@
 //! Built-in statusline segment implementations.
 
 use crate::segment_handler;
-use crate::statusline::{RenderedSegment, SegmentStyle};
+use crate::statusline::{RenderedSegment, SegmentAction, SegmentStyle};
 
 segment_handler!(mode, |ctx| {
-	Some(RenderedSegment {
-		text: format!(" {} ", ctx.mode_name.to_uppercase()),
-		style: SegmentStyle::Mode,
-	})
+	Some(RenderedSegment::new(format!(" {} ", ctx.mode_name.to_uppercase()), SegmentStyle::Mode))
+});
+
+segment_handler!(recording, |ctx| {
+	ctx.recording_macro
+		.map(|register| RenderedSegment::new(format!(" @{} ", register), SegmentStyle::Warning))
+});
+
+segment_handler!(dnd, |ctx| {
+	if ctx.do_not_disturb {
+		Some(RenderedSegment::new(" DND ", SegmentStyle::Dim))
+	} else {
+		None
+	}
 });
 
 segment_handler!(count, |ctx| {
 	if ctx.count > 0 {
-		Some(RenderedSegment {
-			text: format!(" {} ", ctx.count),
-			style: SegmentStyle::Inverted,
-		})
+		Some(RenderedSegment::new(format!(" {} ", ctx.count), SegmentStyle::Inverted))
 	} else {
 		None
 	}
@@ -23,35 +30,24 @@ segment_handler!(count, |ctx| {
 
 segment_handler!(file, |ctx| {
 	let modified = if ctx.modified { " [+]" } else { "" };
-	Some(RenderedSegment {
-		text: format!(" {} {}{} ", ctx.file_icon, ctx.file_label, modified),
-		style: SegmentStyle::Normal,
-	})
+	let text = format!(" {} {}{} ", ctx.file_icon, ctx.file_label, modified);
+	Some(RenderedSegment::new(text, SegmentStyle::Normal).with_click(SegmentAction::command("files", Vec::new())))
 });
 
 segment_handler!(readonly, |ctx| {
 	if ctx.readonly {
-		Some(RenderedSegment {
-			text: " [RO] ".to_string(),
-			style: SegmentStyle::Warning,
-		})
+		Some(RenderedSegment::new(" [RO] ", SegmentStyle::Warning))
 	} else {
 		None
 	}
 });
 
 segment_handler!(filetype, |ctx| {
-	ctx.file_type.map(|ft| RenderedSegment {
-		text: format!(" {} ", ft),
-		style: SegmentStyle::Dim,
-	})
+	ctx.file_type.map(|ft| RenderedSegment::new(format!(" {} ", ft), SegmentStyle::Dim))
 });
 
 segment_handler!(position, |ctx| {
-	Some(RenderedSegment {
-		text: format!(" {}:{} ", ctx.line, ctx.col),
-		style: SegmentStyle::Normal,
-	})
+	Some(RenderedSegment::new(format!(" {}:{} ", ctx.line, ctx.col), SegmentStyle::Normal))
 });
 
 segment_handler!(progress, |ctx| {
@@ -60,10 +56,7 @@ segment_handler!(progress, |ctx| {
 	} else {
 		100
 	};
-	Some(RenderedSegment {
-		text: format!(" {}% ", pct),
-		style: SegmentStyle::Dim,
-	})
+	Some(RenderedSegment::new(format!(" {}% ", pct), SegmentStyle::Dim))
 });
 
 pub fn register_builtins(builder: &mut crate::db::builder::RegistryDbBuilder) {
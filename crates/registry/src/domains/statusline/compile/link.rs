@@ -9,6 +9,8 @@ pub type LinkedStatuslineDef = LinkedDef<StatuslinePayload>;
 pub struct StatuslinePayload {
 	pub position: SegmentPosition,
 	pub default_enabled: bool,
+	pub min_width: Option<u16>,
+	pub shrink_priority: i16,
 	pub render: StatuslineRenderHandler,
 }
 
@@ -18,6 +20,8 @@ impl LinkedPayload<StatuslineEntry> for StatuslinePayload {
 			meta,
 			position: self.position,
 			default_enabled: self.default_enabled,
+			min_width: self.min_width,
+			shrink_priority: self.shrink_priority,
 			render: self.render,
 		}
 	}
@@ -47,15 +51,19 @@ pub fn link_statusline(spec: &StatuslineSpec, handlers: impl Iterator<Item = &'s
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.name.clone(),
 				},
 				payload: StatuslinePayload {
 					position: parse_position(&meta.position, &common.name),
 					default_enabled: true,
+					min_width: meta.min_width,
+					shrink_priority: meta.shrink_priority.unwrap_or(common.priority),
 					render: handler.handler,
 				},
 			}
@@ -22,6 +22,7 @@ pub use crate::core::{
 	StatuslineId, Symbol, SymbolList,
 };
 pub use crate::segment_handler;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Registers compiled statusline segments from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -42,6 +43,7 @@ pub enum SegmentPosition {
 	Right,
 }
 
+#[derive(Clone, Copy)]
 pub struct StatuslineContext<'a> {
 	pub mode_name: &'a str,
 	pub path: Option<&'a str>,
@@ -58,12 +60,119 @@ pub struct StatuslineContext<'a> {
 	pub buffer_count: usize,
 	pub sync_role: Option<&'a str>,
 	pub sync_status: Option<&'a str>,
+	/// Register of the macro being recorded, if any.
+	pub recording_macro: Option<char>,
+	/// Whether do-not-disturb is suppressing notification popups.
+	pub do_not_disturb: bool,
+	/// Width of the whole statusline in cells; `u16::MAX` when unbounded.
+	pub total_width: u16,
+	/// Width already taken by segments rendered before the current one.
+	pub consumed_width: u16,
+}
+
+impl StatuslineContext<'_> {
+	/// Returns the width left for the segment being rendered.
+	pub fn remaining_width(&self) -> u16 {
+		self.total_width.saturating_sub(self.consumed_width)
+	}
+
+	/// Cuts `text` to fit [`Self::remaining_width`], ending it with `…` when cut.
+	pub fn truncate_to_width(&self, text: &str) -> String {
+		truncate_to_width(text, self.remaining_width())
+	}
+}
+
+/// Cuts `text` to at most `width` cells at a grapheme boundary, ending it with
+/// `…` when cut.
+pub fn truncate_to_width(text: &str, width: u16) -> String {
+	let budget = usize::from(width);
+	if text.width() <= budget {
+		return text.to_string();
+	}
+	if budget == 0 {
+		return String::new();
+	}
+	let slice = ropey::RopeSlice::from(text);
+	let mut out = String::new();
+	let mut used = 0;
+	let mut start = 0;
+	while start < slice.len_chars() {
+		let end = xeno_primitives::next_grapheme_boundary(slice, start);
+		let grapheme = slice.slice(start..end);
+		let w: usize = grapheme.chars().map(|ch| ch.width().unwrap_or(0)).sum();
+		if used + w + 1 > budget {
+			break;
+		}
+		out.extend(grapheme.chars());
+		used += w;
+		start = end;
+	}
+	out.push('…');
+	out
 }
 
 #[derive(Debug, Clone)]
 pub struct RenderedSegment {
 	pub text: String,
 	pub style: SegmentStyle,
+	/// Display width of `text` in cells, set by [`render_segments`].
+	pub width: u16,
+	/// Invocation to run when the segment is clicked.
+	pub on_click: Option<SegmentAction>,
+}
+
+impl RenderedSegment {
+	pub fn new(text: impl Into<String>, style: SegmentStyle) -> Self {
+		let text = text.into();
+		Self {
+			width: text_width(&text),
+			text,
+			style,
+			on_click: None,
+		}
+	}
+
+	/// Runs `action` when the segment is clicked.
+	pub fn with_click(mut self, action: SegmentAction) -> Self {
+		self.on_click = Some(action);
+		self
+	}
+}
+
+/// Command invoked by clicking a statusline segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentAction {
+	/// Command name, resolved like a typed command.
+	pub name: String,
+	pub args: Vec<String>,
+}
+
+impl SegmentAction {
+	pub fn command(name: impl Into<String>, args: Vec<String>) -> Self {
+		Self { name: name.into(), args }
+	}
+
+	pub fn to_invocation(&self) -> xeno_invocation::Invocation {
+		xeno_invocation::Invocation::command(self.name.clone(), self.args.clone())
+	}
+}
+
+/// Returns the click action of the segment covering `column` in `rendered`,
+/// laid out left to right from column 0 as [`render_segments`] returned it.
+pub fn hit_test(rendered: &[RenderedSegment], column: u16) -> Option<&SegmentAction> {
+	let mut start = 0u16;
+	for segment in rendered {
+		let end = start.saturating_add(segment.width);
+		if column < end {
+			return segment.on_click.as_ref();
+		}
+		start = end;
+	}
+	None
+}
+
+fn text_width(text: &str) -> u16 {
+	u16::try_from(text.width()).unwrap_or(u16::MAX)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -83,6 +192,11 @@ pub struct StatuslineSegmentDef {
 	pub meta: RegistryMetaStatic,
 	pub position: SegmentPosition,
 	pub default_enabled: bool,
+	/// Narrowest width the segment may be truncated to on overflow; without
+	/// one the segment is dropped whole.
+	pub min_width: Option<u16>,
+	/// Overflow order: segments with the lowest value shrink first.
+	pub shrink_priority: i16,
 	pub render: fn(&StatuslineContext) -> Option<RenderedSegment>,
 }
 
@@ -100,6 +214,8 @@ pub struct StatuslineEntry {
 	pub meta: RegistryMeta,
 	pub position: SegmentPosition,
 	pub default_enabled: bool,
+	pub min_width: Option<u16>,
+	pub shrink_priority: i16,
 	pub render: fn(&StatuslineContext) -> Option<RenderedSegment>,
 }
 
@@ -111,10 +227,12 @@ impl BuildEntry<StatuslineEntry> for StatuslineSegmentDef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -131,6 +249,8 @@ impl BuildEntry<StatuslineEntry> for StatuslineSegmentDef {
 			meta,
 			position: self.position,
 			default_enabled: self.default_enabled,
+			min_width: self.min_width,
+			shrink_priority: self.shrink_priority,
 			render: self.render,
 		}
 	}
@@ -151,11 +271,81 @@ pub fn segments_for_position(position: SegmentPosition) -> Vec<RegistryRef<Statu
 		.collect()
 }
 
+/// Layout of one segment as seen by [`render_segments`].
+#[derive(Clone, Copy)]
+pub struct SegmentLayout {
+	pub min_width: Option<u16>,
+	pub shrink_priority: i16,
+	pub render: handler::StatuslineRenderHandler,
+}
+
+impl From<&StatuslineEntry> for SegmentLayout {
+	fn from(entry: &StatuslineEntry) -> Self {
+		Self {
+			min_width: entry.min_width,
+			shrink_priority: entry.shrink_priority,
+			render: entry.render,
+		}
+	}
+}
+
+/// Renders the enabled segments at `position`, highest priority first, fitted
+/// into `available_width` cells.
 #[cfg(feature = "minimal")]
-pub fn render_position(position: SegmentPosition, ctx: &StatuslineContext) -> Vec<RenderedSegment> {
+pub fn render_position(ctx: &StatuslineContext, position: SegmentPosition, available_width: u16) -> Vec<RenderedSegment> {
 	let mut segments = segments_for_position(position);
 	segments.sort_by(|a, b| b.meta().priority.cmp(&a.meta().priority));
-	segments.into_iter().filter_map(|seg| (seg.render)(ctx)).collect()
+	render_segments(segments.iter().map(|seg| SegmentLayout::from(&**seg)), ctx, available_width)
+}
+
+/// Renders `segments` in order and fits them into `available_width` cells.
+///
+/// Each segment sees the width taken by the ones before it. While the total
+/// overflows, the segment with the lowest shrink priority (the later one on
+/// ties) is truncated if that fits without going below its `min_width`, and
+/// dropped otherwise.
+pub fn render_segments(segments: impl IntoIterator<Item = SegmentLayout>, ctx: &StatuslineContext, available_width: u16) -> Vec<RenderedSegment> {
+	let mut ctx = StatuslineContext {
+		total_width: available_width,
+		consumed_width: 0,
+		..*ctx
+	};
+	let mut rendered = Vec::new();
+	for layout in segments {
+		let Some(mut segment) = (layout.render)(&ctx) else {
+			continue;
+		};
+		segment.width = text_width(&segment.text);
+		ctx.consumed_width = ctx.consumed_width.saturating_add(segment.width);
+		rendered.push((layout, segment));
+	}
+
+	let mut total: u32 = rendered.iter().map(|(_, seg)| u32::from(seg.width)).sum();
+	while total > u32::from(available_width) {
+		let Some(idx) = rendered
+			.iter()
+			.enumerate()
+			.min_by_key(|(idx, (layout, _))| (layout.shrink_priority, std::cmp::Reverse(*idx)))
+			.map(|(idx, _)| idx)
+		else {
+			break;
+		};
+		let (layout, segment) = &mut rendered[idx];
+		let overflow = total - u32::from(available_width);
+		let target = u32::from(segment.width).saturating_sub(overflow);
+		total -= u32::from(segment.width);
+		match layout.min_width {
+			Some(min) if target >= u32::from(min.max(1)) => {
+				segment.text = truncate_to_width(&segment.text, target as u16);
+				segment.width = text_width(&segment.text);
+				total += u32::from(segment.width);
+			}
+			_ => {
+				rendered.remove(idx);
+			}
+		}
+	}
+	rendered.into_iter().map(|(_, segment)| segment).collect()
 }
 
 #[cfg(feature = "minimal")]
@@ -167,3 +357,6 @@ pub fn find_segment(name: &str) -> Option<RegistryRef<StatuslineEntry, Statuslin
 pub fn all_segments() -> Vec<RegistryRef<StatuslineEntry, StatuslineId>> {
 	STATUSLINE_SEGMENTS.snapshot_guard().iter_refs().collect()
 }
+
+#[cfg(test)]
+mod tests;
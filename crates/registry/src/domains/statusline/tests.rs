@@ -0,0 +1,141 @@
+use super::*;
+
+fn ctx(total_width: u16) -> StatuslineContext<'static> {
+	StatuslineContext {
+		mode_name: "normal",
+		path: None,
+		file_icon: "",
+		file_label: "scratch",
+		modified: false,
+		readonly: false,
+		line: 1,
+		col: 1,
+		count: 0,
+		total_lines: 1,
+		file_type: None,
+		buffer_index: 1,
+		buffer_count: 1,
+		sync_role: None,
+		sync_status: None,
+		recording_macro: None,
+		do_not_disturb: false,
+		total_width,
+		consumed_width: 0,
+	}
+}
+
+fn segment(text: &str) -> Option<RenderedSegment> {
+	Some(RenderedSegment::new(text, SegmentStyle::Normal))
+}
+
+fn layout(min_width: Option<u16>, shrink_priority: i16, render: handler::StatuslineRenderHandler) -> SegmentLayout {
+	SegmentLayout {
+		min_width,
+		shrink_priority,
+		render,
+	}
+}
+
+fn texts(segments: &[RenderedSegment]) -> Vec<&str> {
+	segments.iter().map(|s| s.text.as_str()).collect()
+}
+
+#[test]
+fn lowest_shrink_priority_segments_are_dropped_first() {
+	let layout = [
+		layout(None, 100, |_| segment(" NORMAL ")),
+		layout(None, 80, |_| segment(" main.rs ")),
+		layout(None, 90, |_| segment(" 12:4 ")),
+		layout(None, 50, |_| segment(" rust ")),
+		layout(None, 60, |_| segment(" 42% ")),
+	];
+	let render = |width| render_segments(layout, &ctx(u16::MAX), width);
+
+	assert_eq!(texts(&render(u16::MAX)), [" NORMAL ", " main.rs ", " 12:4 ", " rust ", " 42% "]);
+	assert_eq!(texts(&render(30)), [" NORMAL ", " main.rs ", " 12:4 ", " 42% "]);
+	assert_eq!(texts(&render(25)), [" NORMAL ", " main.rs ", " 12:4 "]);
+	assert_eq!(texts(&render(16)), [" NORMAL ", " 12:4 "]);
+	assert_eq!(texts(&render(10)), [" NORMAL "]);
+	assert!(render(4).is_empty());
+}
+
+#[test]
+fn overflow_drops_truncates_or_fits() {
+	let layout = [
+		layout(None, 100, |_| segment(" NORMAL ")),
+		layout(Some(8), 50, |_| segment(" src/editor/main.rs ")),
+		layout(None, 10, |_| segment(" 12:4 ")),
+	];
+	let render = |width| render_segments(layout, &ctx(u16::MAX), width);
+	let widths = |segments: &[RenderedSegment]| segments.iter().map(|s| s.width).collect::<Vec<_>>();
+
+	let fit = render(34);
+	assert_eq!(texts(&fit), [" NORMAL ", " src/editor/main.rs ", " 12:4 "]);
+	assert_eq!(widths(&fit), [8, 20, 6]);
+
+	assert_eq!(texts(&render(30)), [" NORMAL ", " src/editor/main.rs "], "no min width drops whole");
+
+	let truncated = render(20);
+	assert_eq!(texts(&truncated), [" NORMAL ", " src/editor…"]);
+	assert_eq!(widths(&truncated), [8, 12]);
+
+	assert_eq!(texts(&render(14)), [" NORMAL "], "truncating below the min width drops");
+}
+
+#[test]
+fn segments_see_consumed_width_and_truncate() {
+	let layout = [
+		layout(None, 0, |_| segment("0123456789")),
+		layout(None, 0, |ctx| segment(&ctx.truncate_to_width("/very/long/path.rs"))),
+	];
+
+	let rendered = render_segments(layout, &ctx(u16::MAX), 16);
+	assert_eq!(texts(&rendered), ["0123456789", "/very…"]);
+
+	let mut full = ctx(16);
+	full.consumed_width = 20;
+	assert_eq!(full.remaining_width(), 0);
+	assert_eq!(full.truncate_to_width("abc"), "");
+	assert_eq!(ctx(3).truncate_to_width("abc"), "abc");
+}
+
+#[test]
+fn truncation_keeps_graphemes_whole() {
+	assert_eq!(truncate_to_width("e\u{301}e\u{301}e\u{301}", 3), "e\u{301}e\u{301}e\u{301}");
+	assert_eq!(truncate_to_width("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+	assert_eq!(truncate_to_width("日本語", 4), "日…");
+}
+
+#[test]
+fn hit_test_maps_columns_to_segment_actions() {
+	let open = SegmentAction::command("files", Vec::new());
+	let palette = SegmentAction::command("open_palette", vec!["buffers".to_string()]);
+	let rendered = [
+		RenderedSegment::new(" NORMAL ", SegmentStyle::Mode).with_click(palette.clone()),
+		RenderedSegment::new("   ", SegmentStyle::Normal),
+		RenderedSegment::new(" main.rs ", SegmentStyle::Normal).with_click(open.clone()),
+		RenderedSegment::new("", SegmentStyle::Normal).with_click(palette.clone()),
+	];
+
+	assert_eq!(hit_test(&rendered, 0), Some(&palette));
+	assert_eq!(hit_test(&rendered, 7), Some(&palette), "last cell of the first segment");
+	assert_eq!(hit_test(&rendered, 8), None, "spacer gap");
+	assert_eq!(hit_test(&rendered, 10), None);
+	assert_eq!(hit_test(&rendered, 11), Some(&open), "first cell after the gap");
+	assert_eq!(hit_test(&rendered, 19), Some(&open));
+	assert_eq!(hit_test(&rendered, 20), None, "past the end; empty segments take no cells");
+}
+
+#[test]
+fn hit_test_follows_truncated_layout() {
+	let layout = [
+		layout(None, 100, |_| segment(" NORMAL ")),
+		layout(Some(8), 50, |_| {
+			Some(RenderedSegment::new(" src/editor/main.rs ", SegmentStyle::Normal).with_click(SegmentAction::command("files", Vec::new())))
+		}),
+	];
+	let rendered = render_segments(layout, &ctx(u16::MAX), 20);
+	assert_eq!(rendered[1].width, 12);
+	assert!(hit_test(&rendered, 19).is_some());
+	assert!(hit_test(&rendered, 20).is_none());
+}
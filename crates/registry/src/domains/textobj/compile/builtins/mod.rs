@@ -2,6 +2,7 @@
 
 pub mod brackets;
 pub mod quotes;
+pub mod syntax;
 pub mod word;
 
 pub fn register_builtins(builder: &mut crate::db::builder::RegistryDbBuilder) {
@@ -0,0 +1,31 @@
+//! Text objects over the language's text object query captures.
+//!
+//! Without a tree these select nothing, except `comment`, which falls back to
+//! runs of line comments.
+
+use crate::text_object_handler;
+use crate::textobj::syntax::select_line_comment;
+
+text_object_handler!(function, {
+	syntax: "function",
+	inner: |_text, _pos| None,
+	around: |_text, _pos| None,
+});
+
+text_object_handler!(class, {
+	syntax: "class",
+	inner: |_text, _pos| None,
+	around: |_text, _pos| None,
+});
+
+text_object_handler!(parameter, {
+	syntax: "parameter",
+	inner: |_text, _pos| None,
+	around: |_text, _pos| None,
+});
+
+text_object_handler!(comment, {
+	syntax: "comment",
+	inner: |text, pos| select_line_comment(text, pos, true),
+	around: |text, pos| select_line_comment(text, pos, false),
+});
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use super::spec::TextObjectsSpec;
 use crate::core::{LinkedDef, LinkedMetaOwned, LinkedPayload, RegistryMeta, RegistrySource, Symbol};
 use crate::textobj::handler::TextObjectHandlerStatic;
-use crate::textobj::{TextObjectEntry, TextObjectHandler};
+use crate::textobj::{TextObjectEntry, TextObjectHandler, TextObjectKind};
 
 pub type LinkedTextObjectDef = LinkedDef<TextObjectPayload>;
 
@@ -11,16 +11,24 @@ pub type LinkedTextObjectDef = LinkedDef<TextObjectPayload>;
 pub struct TextObjectPayload {
 	pub trigger: char,
 	pub alt_triggers: Vec<char>,
+	pub description_long: String,
+	pub kind: TextObjectKind,
 	pub inner: TextObjectHandler,
 	pub around: TextObjectHandler,
 }
 
 impl LinkedPayload<TextObjectEntry> for TextObjectPayload {
-	fn build_entry(&self, _ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> TextObjectEntry {
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.push(&self.description_long);
+	}
+
+	fn build_entry(&self, ctx: &mut dyn crate::core::index::BuildCtx, meta: RegistryMeta, _short_desc: Symbol) -> TextObjectEntry {
 		TextObjectEntry {
 			meta,
 			trigger: self.trigger,
 			alt_triggers: Arc::from(self.alt_triggers.as_slice()),
+			description_long: ctx.intern(&self.description_long),
+			kind: self.kind,
 			inner: self.inner,
 			around: self.around,
 		}
@@ -52,15 +60,19 @@ pub fn link_text_objects(spec: &TextObjectsSpec, handlers: impl Iterator<Item =
 					id,
 					name: common.name.clone(),
 					keys: common.keys.clone(),
+					categories: common.categories.clone(),
 					description: common.description.clone(),
 					priority: common.priority,
 					source: RegistrySource::Crate(handler.crate_name),
 					mutates_buffer: false,
+					deprecated: crate::defs::link::deprecation_from_spec(common),
 					short_desc: common.short_desc.clone().unwrap_or_else(|| common.description.clone()),
 				},
 				payload: TextObjectPayload {
 					trigger,
 					alt_triggers,
+					description_long: meta.description_long.clone(),
+					kind: handler.handler.kind,
 					inner: handler.handler.inner,
 					around: handler.handler.around,
 				},
@@ -1,6 +1,6 @@
 //! Text object handler static registration via `inventory`.
 
-use super::TextObjectHandler;
+use super::{TextObjectHandler, TextObjectKind};
 
 /// Handler configuration carrying inner/around selection functions.
 #[derive(Clone, Copy)]
@@ -9,6 +9,9 @@ pub struct TextObjectHandlers {
 	pub inner: TextObjectHandler,
 	/// Around selection handler.
 	pub around: TextObjectHandler,
+	/// [`TextObjectKind::Custom`], or [`TextObjectKind::Syntax`] with the
+	/// handlers as fallback.
+	pub kind: TextObjectKind,
 }
 
 pub type TextObjectHandlerStatic = crate::core::HandlerStatic<TextObjectHandlers>;
@@ -4,11 +4,37 @@
 ///
 /// The metadata (description, trigger, etc.) comes from `text_objects.nuon`; this macro
 /// only provides the Rust handlers and creates the inventory linkage.
+///
+/// With a leading `syntax: "name"`, the object selects the `@name.inside` /
+/// `@name.around` captures of the language's text object query, and the
+/// handlers only run when no tree is available or nothing is captured.
 #[macro_export]
 macro_rules! text_object_handler {
 	($name:ident, {
 		inner: |$ti_text:ident, $ti_pos:ident| $inner_body:expr,
 		around: |$ta_text:ident, $ta_pos:ident| $around_body:expr $(,)?
+	}) => {
+		$crate::text_object_handler!(@register $name, $crate::textobj::TextObjectKind::Custom, {
+			inner: |$ti_text, $ti_pos| $inner_body,
+			around: |$ta_text, $ta_pos| $around_body,
+		});
+	};
+	($name:ident, {
+		syntax: $capture:literal,
+		inner: |$ti_text:ident, $ti_pos:ident| $inner_body:expr,
+		around: |$ta_text:ident, $ta_pos:ident| $around_body:expr $(,)?
+	}) => {
+		$crate::text_object_handler!(@register $name, $crate::textobj::TextObjectKind::Syntax {
+			inside: concat!($capture, ".inside"),
+			around: concat!($capture, ".around"),
+		}, {
+			inner: |$ti_text, $ti_pos| $inner_body,
+			around: |$ta_text, $ta_pos| $around_body,
+		});
+	};
+	(@register $name:ident, $kind:expr, {
+		inner: |$ti_text:ident, $ti_pos:ident| $inner_body:expr,
+		around: |$ta_text:ident, $ta_pos:ident| $around_body:expr $(,)?
 	}) => {
 		paste::paste! {
 			#[allow(unused_variables)]
@@ -35,6 +61,7 @@ macro_rules! text_object_handler {
 					handler: $crate::textobj::handler::TextObjectHandlers {
 						inner: [<textobj_inner_ $name>],
 						around: [<textobj_around_ $name>],
+						kind: $kind,
 					},
 				};
 
@@ -0,0 +1,171 @@
+//! Selection for [`TextObjectKind::Syntax`](super::TextObjectKind::Syntax) objects.
+//!
+//! Syntax objects select spans captured by the language's text object query
+//! (`@function.inside`, `@function.around`, ...), reported by the editor's
+//! parsed tree through [`SyntaxTree::captures_containing`](crate::motions::SyntaxTree::captures_containing).
+
+use std::borrow::Cow;
+
+use ropey::RopeSlice;
+use xeno_primitives::Range;
+
+use crate::motions::SyntaxContext;
+
+/// Line-comment prefixes recognized by [`select_line_comment`], longest first.
+const LINE_COMMENT_PREFIXES: &[&str] = &["///", "//!", "//", "--", "#", ";"];
+
+/// Selects the innermost span captured as `capture` that covers the character at `pos`.
+pub fn select_capture(ctx: SyntaxContext, pos: usize, capture: &str) -> Option<Range> {
+	let byte = ctx.to_byte(pos);
+	let span = ctx
+		.tree
+		.captures_containing(ctx.text, capture, byte, byte)
+		.into_iter()
+		.filter(|span| span.start <= byte && byte < span.end)
+		.min_by_key(|span| span.end - span.start)?;
+	Some(Range::from_exclusive(ctx.to_char(span.start), ctx.to_char(span.end)))
+}
+
+/// Selects the run of adjacent lines sharing the cursor line's comment prefix.
+///
+/// Fallback for the comment object when no tree is available. Around covers
+/// the whole lines including the final newline; inner runs from after the
+/// first prefix (and one space) to the last non-whitespace character.
+pub fn select_line_comment(text: RopeSlice, pos: usize, inner: bool) -> Option<Range> {
+	let line = text.char_to_line(pos.min(text.len_chars()));
+	let prefix = comment_prefix(text.line(line))?;
+	let is_comment = |line: usize| comment_prefix(text.line(line)) == Some(prefix);
+
+	let mut first = line;
+	while first > 0 && is_comment(first - 1) {
+		first -= 1;
+	}
+	let mut last = line;
+	while last + 1 < text.len_lines() && is_comment(last + 1) {
+		last += 1;
+	}
+
+	let (start, end) = if inner {
+		let first_line = text.line(first);
+		let indent = first_line.chars().take_while(|c| c.is_whitespace()).count();
+		let mut start = text.line_to_char(first) + indent + prefix.len();
+		if text.get_char(start) == Some(' ') {
+			start += 1;
+		}
+		let last_line: Cow<str> = text.line(last).into();
+		(start, text.line_to_char(last) + last_line.trim_end().chars().count())
+	} else {
+		let end = if last + 1 < text.len_lines() {
+			text.line_to_char(last + 1)
+		} else {
+			text.len_chars()
+		};
+		(text.line_to_char(first), end)
+	};
+	(start < end).then(|| Range::from_exclusive(start, end))
+}
+
+fn comment_prefix(line: RopeSlice) -> Option<&'static str> {
+	let line: Cow<str> = line.into();
+	let trimmed = line.trim_start();
+	LINE_COMMENT_PREFIXES.iter().copied().find(|prefix| trimmed.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+	use ropey::Rope;
+
+	use super::*;
+	use crate::motions::{SyntaxNode, SyntaxTree};
+
+	/// ```text
+	/// fn outer() {
+	///     fn inner(a: u8) {
+	///         a
+	///     }
+	/// }
+	/// ```
+	const SRC: &str = "fn outer() {\n    fn inner(a: u8) {\n        a\n    }\n}\n";
+
+	/// Captures of [`SRC`] as a Rust text object query reports them.
+	const CAPTURES: &[(&str, u32, u32)] = &[
+		("function.around", 0, 52),
+		("function.inside", 11, 52),
+		("function.around", 17, 50),
+		("function.inside", 33, 50),
+		("parameter.inside", 26, 31),
+	];
+
+	struct FixtureTree;
+
+	impl SyntaxTree for FixtureTree {
+		fn named_node_at(&self, _start: u32, _end: u32) -> Option<SyntaxNode> {
+			None
+		}
+
+		fn parent(&self, _node: SyntaxNode) -> Option<SyntaxNode> {
+			None
+		}
+
+		fn next_sibling(&self, _node: SyntaxNode) -> Option<SyntaxNode> {
+			None
+		}
+
+		fn prev_sibling(&self, _node: SyntaxNode) -> Option<SyntaxNode> {
+			None
+		}
+
+		fn captures_containing(&self, _text: RopeSlice<'_>, capture: &str, start: u32, end: u32) -> Vec<SyntaxNode> {
+			CAPTURES
+				.iter()
+				.filter(|&&(name, s, e)| name == capture && s <= start && end <= e)
+				.map(|&(_, start, end)| SyntaxNode { start, end })
+				.collect()
+		}
+	}
+
+	fn select(pos: usize, capture: &str) -> Option<String> {
+		let text = Rope::from(SRC);
+		let ctx = SyntaxContext {
+			text: text.slice(..),
+			tree: &FixtureTree,
+		};
+		select_capture(ctx, pos, capture).map(|range| text.slice(range.min()..=range.max()).to_string())
+	}
+
+	#[test]
+	fn fixture_spans_match_source() {
+		assert_eq!(&SRC[17..50], "fn inner(a: u8) {\n        a\n    }");
+		assert_eq!(&SRC[26..31], "a: u8");
+	}
+
+	#[test]
+	fn nested_functions_select_the_innermost() {
+		assert_eq!(select(43, "function.around").as_deref(), Some(&SRC[17..50]), "body of inner");
+		assert_eq!(select(43, "function.inside").as_deref(), Some(&SRC[33..50]));
+		assert_eq!(select(3, "function.around").as_deref(), Some(&SRC[0..52]), "name of outer");
+		assert_eq!(select(51, "function.inside").as_deref(), Some(&SRC[11..52]), "closing brace of outer");
+	}
+
+	#[test]
+	fn uncaptured_positions_select_nothing() {
+		assert_eq!(select(52, "function.around"), None, "trailing newline");
+		assert_eq!(select(43, "class.around"), None);
+		assert_eq!(select(28, "parameter.inside").as_deref(), Some("a: u8"));
+	}
+
+	#[test]
+	fn line_comment_fallback_spans_adjacent_comment_lines() {
+		let text = Rope::from("let a = 1;\n    // first\n    // second\n# other\n");
+		let slice = text.slice(..);
+		let show = |range: Range| slice.slice(range.min()..=range.max()).to_string();
+
+		assert_eq!(select_line_comment(slice, 30, true).map(show).as_deref(), Some("first\n    // second"));
+		assert_eq!(
+			select_line_comment(slice, 15, false).map(show).as_deref(),
+			Some("    // first\n    // second\n")
+		);
+		assert_eq!(select_line_comment(slice, 42, true).map(show).as_deref(), Some("other"), "prefixes do not mix");
+		assert_eq!(select_line_comment(slice, 3, true), None, "code line");
+	}
+}
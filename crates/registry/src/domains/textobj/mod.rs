@@ -5,6 +5,8 @@ use std::sync::Arc;
 use ropey::RopeSlice;
 use xeno_primitives::Range;
 
+use crate::motions::{SyntaxContext, SyntaxTree};
+
 #[path = "compile/builtins/mod.rs"]
 pub mod builtins;
 mod domain;
@@ -18,13 +20,18 @@ pub mod loader;
 mod macros;
 #[path = "runtime/query.rs"]
 pub mod query;
+#[path = "runtime/register.rs"]
+mod register;
 #[path = "contract/spec.rs"]
 pub mod spec;
+#[path = "exec/syntax.rs"]
+pub mod syntax;
 
 pub use builtins::register_builtins;
 pub use domain::TextObjects;
 pub use handler::{TextObjectHandlerReg, TextObjectHandlerStatic};
 pub use query::{TextObjectRef, TextObjectRegistry};
+pub use register::OwnedTextObjectDef;
 
 /// Registers compiled text objects from the embedded spec.
 pub fn register_compiled(db: &mut crate::db::builder::RegistryDbBuilder) {
@@ -48,13 +55,44 @@ pub use crate::text_object_handler;
 
 pub type TextObjectHandler = fn(RopeSlice, usize) -> Option<Range>;
 
+/// How a text object computes its selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextObjectKind {
+	/// Selection is computed by the `inner` / `around` handlers.
+	Custom,
+	/// Balanced pair with distinct delimiters, e.g. `(` and `)`.
+	BracketPair { open: char, close: char },
+	/// Same character opens and closes, e.g. `"`.
+	Symmetric { delimiter: char },
+	/// Spans captured by the language's text object query, e.g. `function.inside`
+	/// and `function.around`. The `inner` / `around` handlers serve as the
+	/// fallback when no tree exists or nothing is captured at the cursor.
+	Syntax { inside: &'static str, around: &'static str },
+}
+
+impl TextObjectKind {
+	/// Returns the kind selecting between `open` and `close`; equal delimiters are symmetric.
+	pub const fn pair(open: char, close: char) -> Self {
+		if open == close {
+			Self::Symmetric { delimiter: open }
+		} else {
+			Self::BracketPair { open, close }
+		}
+	}
+}
+
 /// Definition of a text object (static input).
 #[derive(Clone, Copy)]
 pub struct TextObjectDef {
 	pub meta: RegistryMetaStatic,
 	pub trigger: char,
 	pub alt_triggers: &'static [char],
+	/// Extended help text; empty when only the one-line description applies.
+	pub description_long: &'static str,
+	pub kind: TextObjectKind,
+	/// Inner selection handler; only called for [`TextObjectKind::Custom`].
 	pub inner: TextObjectHandler,
+	/// Around selection handler; only called for [`TextObjectKind::Custom`].
 	pub around: TextObjectHandler,
 }
 
@@ -66,33 +104,97 @@ impl TextObjectDef {
 			meta,
 			trigger,
 			alt_triggers,
+			description_long: "",
+			kind: TextObjectKind::Custom,
 			inner,
 			around,
 		}
 	}
 }
 
+/// Declares a text object selecting between `open` and `close` without custom handlers.
+///
+/// Equal `open` and `close` characters produce a [`TextObjectKind::Symmetric`] object.
+pub const fn text_object_bracket_pair(meta: RegistryMetaStatic, trigger: char, open: char, close: char) -> TextObjectDef {
+	TextObjectDef {
+		meta,
+		trigger,
+		alt_triggers: &[],
+		description_long: "",
+		kind: TextObjectKind::pair(open, close),
+		inner: no_selection,
+		around: no_selection,
+	}
+}
+
+/// Declares a text object selecting between two occurrences of `delimiter`.
+pub const fn text_object_symmetric(meta: RegistryMetaStatic, trigger: char, delimiter: char) -> TextObjectDef {
+	text_object_bracket_pair(meta, trigger, delimiter, delimiter)
+}
+
+/// Placeholder handler for parameterized kinds, which never dispatch through handlers.
+fn no_selection(_text: RopeSlice, _pos: usize) -> Option<Range> {
+	None
+}
+
 /// Symbolized text object entry.
 pub struct TextObjectEntry {
 	pub meta: RegistryMeta,
 	pub trigger: char,
 	pub alt_triggers: Arc<[char]>,
+	/// Extended help text (interned); resolves to `""` when absent.
+	pub description_long: Symbol,
+	pub kind: TextObjectKind,
 	pub inner: TextObjectHandler,
 	pub around: TextObjectHandler,
 }
 
 crate::impl_registry_entry!(TextObjectEntry);
 
+impl TextObjectEntry {
+	/// Selects the object's contents around `pos`, excluding delimiters.
+	///
+	/// `tree` is only consulted by [`TextObjectKind::Syntax`] objects.
+	pub fn select_inner(&self, text: RopeSlice, tree: Option<&dyn SyntaxTree>, pos: usize) -> Option<Range> {
+		self.select(text, tree, pos, true)
+	}
+
+	/// Selects the object around `pos`, including delimiters.
+	///
+	/// `tree` is only consulted by [`TextObjectKind::Syntax`] objects.
+	pub fn select_around(&self, text: RopeSlice, tree: Option<&dyn SyntaxTree>, pos: usize) -> Option<Range> {
+		self.select(text, tree, pos, false)
+	}
+
+	fn select(&self, text: RopeSlice, tree: Option<&dyn SyntaxTree>, pos: usize, inner: bool) -> Option<Range> {
+		let fallback = || if inner { (self.inner)(text, pos) } else { (self.around)(text, pos) };
+		let (open, close) = match self.kind {
+			TextObjectKind::Custom => return fallback(),
+			TextObjectKind::Syntax { inside, around } => {
+				let capture = if inner { inside } else { around };
+				return tree
+					.and_then(|tree| syntax::select_capture(SyntaxContext { text, tree }, pos, capture))
+					.or_else(fallback);
+			}
+			TextObjectKind::BracketPair { open, close } => (open, close),
+			TextObjectKind::Symmetric { delimiter } => (delimiter, delimiter),
+		};
+		crate::motions::movement::select_surround_object(text, Range::point(pos), open, close, inner)
+	}
+}
+
 impl BuildEntry<TextObjectEntry> for TextObjectDef {
 	fn meta_ref(&self) -> RegistryMetaRef<'_> {
 		RegistryMetaRef {
 			id: self.meta.id,
 			name: self.meta.name,
 			keys: StrListRef::Static(self.meta.keys),
+			categories: StrListRef::Static(self.meta.categories),
 			description: self.meta.description,
 			priority: self.meta.priority,
 			source: self.meta.source,
 			mutates_buffer: self.meta.mutates_buffer,
+			deprecated: self.meta.deprecated,
 		}
 	}
 
@@ -100,7 +202,9 @@ impl BuildEntry<TextObjectEntry> for TextObjectDef {
 		self.meta.name
 	}
 
-	fn collect_payload_strings<'b>(&'b self, _collector: &mut crate::core::index::StringCollector<'_, 'b>) {}
+	fn collect_payload_strings<'b>(&'b self, collector: &mut crate::core::index::StringCollector<'_, 'b>) {
+		collector.push(self.description_long);
+	}
 
 	fn build(&self, ctx: &mut dyn BuildCtx, key_pool: &mut Vec<Symbol>) -> TextObjectEntry {
 		let meta = crate::core::index::meta_build::build_meta(ctx, key_pool, self.meta_ref(), []);
@@ -109,6 +213,8 @@ impl BuildEntry<TextObjectEntry> for TextObjectDef {
 			meta,
 			trigger: self.trigger,
 			alt_triggers: self.alt_triggers.into(),
+			description_long: ctx.intern(self.description_long),
+			kind: self.kind,
 			inner: self.inner,
 			around: self.around,
 		}
@@ -127,6 +233,18 @@ pub fn find_by_trigger(trigger: char) -> Option<TextObjectRef> {
 	TEXT_OBJECTS.by_trigger(trigger)
 }
 
+/// Registers a text object at runtime; see [`TextObjectRegistry::register_runtime`].
+#[cfg(feature = "minimal")]
+pub fn register_runtime(def: OwnedTextObjectDef) -> crate::core::InsertAction {
+	TEXT_OBJECTS.register_runtime(def)
+}
+
+/// Removes a runtime text object; see [`TextObjectRegistry::unregister`].
+#[cfg(feature = "minimal")]
+pub fn unregister(id: &str) -> bool {
+	TEXT_OBJECTS.unregister(id)
+}
+
 #[cfg(feature = "minimal")]
 pub fn find(name: &str) -> Option<TextObjectRef> {
 	TEXT_OBJECTS.get(name)
@@ -136,3 +254,33 @@ pub fn find(name: &str) -> Option<TextObjectRef> {
 pub fn all() -> Vec<TextObjectRef> {
 	TEXT_OBJECTS.snapshot_guard().iter_refs().collect()
 }
+
+/// Formats help for the text object bound to `trigger`.
+///
+/// Includes the name, triggers, short and long descriptions, and an
+/// inner/around example table. Returns `None` for unbound triggers.
+#[cfg(feature = "minimal")]
+pub fn text_object_help(trigger: char) -> Option<String> {
+	use std::fmt::Write;
+
+	let obj = find_by_trigger(trigger)?;
+	let triggers: Vec<String> = std::iter::once(obj.trigger).chain(obj.alt_triggers.iter().copied()).map(String::from).collect();
+	let mut help = format!("{}: {}\ntriggers: {}\n", obj.name_str(), obj.description_str(), triggers.join(" "));
+
+	let long = obj.resolve(obj.description_long);
+	if !long.is_empty() {
+		let _ = write!(help, "\n{}\n", long.trim_end());
+	}
+
+	let (inner, around) = match obj.kind {
+		TextObjectKind::Custom => ("object contents".to_string(), "contents plus surrounding whitespace".to_string()),
+		TextObjectKind::BracketPair { open, close } => (format!("{open}[text]{close}"), format!("[{open}text{close}]")),
+		TextObjectKind::Symmetric { delimiter: d } => (format!("{d}[text]{d}"), format!("[{d}text{d}]")),
+		TextObjectKind::Syntax { inside, around } => (format!("'@{inside}' capture"), format!("'@{around}' capture")),
+	};
+	let _ = write!(
+		help,
+		"\n| select | example |\n|--------|---------|\n| inner  | {inner} |\n| around | {around} |\n"
+	);
+	Some(help)
+}
@@ -26,7 +26,7 @@ impl TextObjectRegistry {
 		let snap = self.inner.snapshot();
 		let mut winner: Option<(TextObjectId, crate::core::Party)> = None;
 
-		for (idx, entry) in snap.table.iter().enumerate() {
+		for (idx, entry) in snap.entries() {
 			if entry.trigger != trigger && !entry.alt_triggers.contains(&trigger) {
 				continue;
 			}
@@ -55,9 +55,13 @@ impl TextObjectRegistry {
 		self.inner.is_empty()
 	}
 
-	pub fn collisions(&self) -> &[crate::core::Collision] {
+	pub fn collisions(&self) -> std::sync::Arc<[crate::core::Collision]> {
 		self.inner.collisions()
 	}
+
+	pub fn all_deprecated(&self) -> impl Iterator<Item = TextObjectRef> {
+		self.inner.all_deprecated()
+	}
 }
 
 #[cfg(test)]
@@ -65,7 +69,7 @@ mod tests {
 	use super::*;
 	use crate::core::index::RegistryBuilder;
 	use crate::core::{RegistryMetaStatic, RegistrySource};
-	use crate::textobj::{TextObjectDef, TextObjectEntry, TextObjectInput};
+	use crate::textobj::{TextObjectDef, TextObjectEntry, TextObjectInput, TextObjectKind, text_object_bracket_pair};
 
 	fn test_inner(_text: ropey::RopeSlice, _pos: usize) -> Option<xeno_primitives::Range> {
 		None
@@ -80,13 +84,17 @@ mod tests {
 			id: "registry::textobj::builtin",
 			name: "builtin",
 			keys: &[],
+			categories: &[],
 			description: "builtin text object",
 			priority: 0,
 			source: RegistrySource::Builtin,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		trigger: 'x',
 		alt_triggers: &[],
+		description_long: "",
+		kind: TextObjectKind::Custom,
 		inner: test_inner,
 		around: test_around,
 	};
@@ -96,13 +104,17 @@ mod tests {
 			id: "registry::textobj::runtime",
 			name: "runtime",
 			keys: &[],
+			categories: &[],
 			description: "runtime text object",
 			priority: 0,
 			source: RegistrySource::Runtime,
 			mutates_buffer: false,
+			deprecated: None,
 		},
 		trigger: 'x',
 		alt_triggers: &[],
+		description_long: "",
+		kind: TextObjectKind::Custom,
 		inner: test_inner,
 		around: test_around,
 	};
@@ -118,4 +130,48 @@ mod tests {
 		let resolved = registry.by_trigger('x').expect("trigger should resolve");
 		assert_eq!(resolved.id_str(), RUNTIME_TEXT_OBJECT.meta.id);
 	}
+
+	static QUOTE_PAIR: TextObjectDef = text_object_bracket_pair(
+		RegistryMetaStatic {
+			id: "registry::textobj::plugin_quotes",
+			name: "plugin_quotes",
+			keys: &[],
+			categories: &[],
+			description: "plugin quotes",
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		'"',
+		'"',
+		'"',
+	);
+
+	#[test]
+	fn bracket_pair_selects_inside_quotes() {
+		let mut builder: RegistryBuilder<TextObjectInput, TextObjectEntry, TextObjectId> = RegistryBuilder::new("textobj-test");
+		builder.push(std::sync::Arc::new(TextObjectInput::Static(QUOTE_PAIR)));
+		let registry = TextObjectRegistry::new(builder.build());
+
+		let obj = registry.by_trigger('"').expect("quote trigger should resolve");
+		assert_eq!(obj.kind, TextObjectKind::Symmetric { delimiter: '"' });
+
+		let rope = ropey::Rope::from_str(r#"say "hello" now"#);
+		let inner = obj.select_inner(rope.slice(..), None, 7).expect("ci\" should select");
+		assert_eq!((inner.min(), inner.max()), (5, 9));
+		let around = obj.select_around(rope.slice(..), None, 7).expect("ca\" should select");
+		assert_eq!((around.min(), around.max()), (4, 10));
+	}
+
+	#[cfg(feature = "minimal")]
+	#[test]
+	fn help_lists_triggers_and_long_description() {
+		let help = crate::textobj::text_object_help('w').expect("builtin word object");
+		assert!(help.starts_with("word: "));
+		assert!(help.contains("triggers: w"));
+		assert!(help.contains("A run of word characters"));
+		assert!(help.contains("| inner ") && help.contains("| around "));
+		assert!(crate::textobj::text_object_help('\u{1}').is_none());
+	}
 }
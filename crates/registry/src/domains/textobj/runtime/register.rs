@@ -0,0 +1,159 @@
+//! Runtime text object registration for config-defined delimiter pairs.
+//!
+//! Runtime objects join the same [`TextObjectRegistry`] snapshot as compiled
+//! ones, so [`TextObjectRegistry::by_trigger`] resolves a shared trigger by the
+//! usual precedence: higher priority wins, then the runtime source.
+
+use std::sync::Arc;
+
+use super::link::{LinkedTextObjectDef, TextObjectPayload};
+use super::{TextObjectInput, TextObjectKind, TextObjectRegistry, no_selection};
+use crate::core::{InsertAction, LinkedMetaOwned, RegistryEntry, RegistrySource};
+
+/// Delimiter-pair text object supplied at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedTextObjectDef {
+	/// Canonical ID; registering an existing ID replaces that object.
+	pub id: String,
+	pub description: String,
+	/// Precedence against objects sharing a trigger (higher wins).
+	pub priority: i16,
+	pub trigger: char,
+	pub alt_triggers: Vec<char>,
+	pub open: char,
+	pub close: char,
+}
+
+impl OwnedTextObjectDef {
+	/// Creates a priority-0 object selecting between `open` and `close`.
+	///
+	/// Equal delimiters select symmetrically, like quotes.
+	pub fn pair(id: impl Into<String>, trigger: char, open: char, close: char) -> Self {
+		Self {
+			id: id.into(),
+			description: String::new(),
+			priority: 0,
+			trigger,
+			alt_triggers: Vec::new(),
+			open,
+			close,
+		}
+	}
+
+	fn into_linked(self) -> LinkedTextObjectDef {
+		let description = if self.description.is_empty() {
+			format!("{} … {}", self.open, self.close)
+		} else {
+			self.description
+		};
+		LinkedTextObjectDef {
+			meta: LinkedMetaOwned {
+				name: self.id.clone(),
+				id: self.id,
+				keys: Vec::new(),
+				categories: Vec::new(),
+				short_desc: description.clone(),
+				description,
+				priority: self.priority,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+			},
+			payload: TextObjectPayload {
+				trigger: self.trigger,
+				alt_triggers: self.alt_triggers,
+				description_long: String::new(),
+				kind: TextObjectKind::pair(self.open, self.close),
+				inner: no_selection,
+				around: no_selection,
+			},
+		}
+	}
+}
+
+impl TextObjectRegistry {
+	/// Registers `def`, replacing any text object with the same canonical ID.
+	///
+	/// A higher-priority existing object is kept, as for any runtime replacement.
+	pub fn register_runtime(&self, def: OwnedTextObjectDef) -> InsertAction {
+		self.inner.replace(Arc::new(TextObjectInput::Linked(def.into_linked()))).action
+	}
+
+	/// Removes the runtime text object with canonical ID `id`.
+	///
+	/// Returns false if no such object exists or it was not registered at runtime.
+	pub fn unregister(&self, id: &str) -> bool {
+		self.inner.remove_canonical(id, |obj| obj.source() == RegistrySource::Runtime).is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ropey::Rope;
+
+	use super::*;
+	use crate::core::index::RegistryBuilder;
+	use crate::core::{RegistryMetaStatic, TextObjectId};
+	use crate::textobj::{TextObjectDef, TextObjectEntry, text_object_bracket_pair};
+
+	static BUILTIN_PARENS: TextObjectDef = text_object_bracket_pair(
+		RegistryMetaStatic {
+			id: "registry::textobj::builtin_parens",
+			name: "builtin_parens",
+			keys: &[],
+			categories: &[],
+			description: "builtin parens",
+			priority: 0,
+			source: RegistrySource::Builtin,
+			mutates_buffer: false,
+			deprecated: None,
+		},
+		'(',
+		'(',
+		')',
+	);
+
+	fn registry() -> TextObjectRegistry {
+		let mut builder: RegistryBuilder<TextObjectInput, TextObjectEntry, TextObjectId> = RegistryBuilder::new("textobj-test");
+		builder.push(Arc::new(TextObjectInput::Static(BUILTIN_PARENS)));
+		TextObjectRegistry::new(builder.build())
+	}
+
+	#[test]
+	fn multibyte_pair_selects_inner_and_around() {
+		let registry = registry();
+		assert_eq!(
+			registry.register_runtime(OwnedTextObjectDef::pair("guillemet", '«', '«', '»')),
+			InsertAction::InsertedNew
+		);
+
+		let obj = registry.by_trigger('«').expect("runtime trigger resolves");
+		assert_eq!(obj.kind, TextObjectKind::BracketPair { open: '«', close: '»' });
+
+		let text = Rope::from("dit «bonjour» ici");
+		let slice = text.slice(..);
+		let inner = obj.select_inner(slice, None, 7).expect("inside the pair");
+		assert_eq!(slice.slice(inner.min()..=inner.max()).to_string(), "bonjour");
+		let around = obj.select_around(slice, None, 7).expect("inside the pair");
+		assert_eq!(slice.slice(around.min()..=around.max()).to_string(), "«bonjour»");
+	}
+
+	#[test]
+	fn trigger_collisions_follow_priority_then_source() {
+		let registry = registry();
+
+		let mut lower = OwnedTextObjectDef::pair("low_parens", '(', '[', ']');
+		lower.priority = -1;
+		registry.register_runtime(lower);
+		assert_eq!(registry.by_trigger('(').unwrap().id_str(), BUILTIN_PARENS.meta.id, "lower priority loses");
+
+		registry.register_runtime(OwnedTextObjectDef::pair("dollars", '(', '$', '$'));
+		let resolved = registry.by_trigger('(').unwrap();
+		assert_eq!(resolved.id_str(), "dollars", "runtime wins the priority tie");
+		assert_eq!(resolved.kind, TextObjectKind::Symmetric { delimiter: '$' });
+
+		assert!(registry.unregister("dollars"));
+		assert!(!registry.unregister(BUILTIN_PARENS.meta.id), "compiled objects stay");
+		assert_eq!(registry.by_trigger('(').unwrap().id_str(), BUILTIN_PARENS.meta.id);
+	}
+}
@@ -179,7 +179,7 @@ pub(crate) fn parse_modifiers(s: &str, theme_name: &str, scope: &str) -> Modifie
 	modifiers
 }
 
-fn set_syntax_style(styles: &mut SyntaxStyles, scope: &str, style: SyntaxStyle) {
+pub(crate) fn set_syntax_style(styles: &mut SyntaxStyles, scope: &str, style: SyntaxStyle) {
 	match scope {
 		"attribute" => styles.attribute = style,
 		"tag" => styles.tag = style,
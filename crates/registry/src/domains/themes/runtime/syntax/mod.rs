@@ -57,6 +57,25 @@ impl SyntaxStyle {
 		self
 	}
 
+	/// Blends this style with `other` (`alpha = 1.0` yields `self`, `0.0` yields `other`).
+	///
+	/// Colors present on both sides are mixed with [`Color::blend`]. A color or
+	/// modifier set present on only one side switches over at the midpoint: `other`
+	/// wins when `alpha < 0.5`, `self` from `0.5` up.
+	pub fn blend_with(self, other: SyntaxStyle, alpha: f32) -> Self {
+		let self_dominant = alpha >= 0.5;
+		let blend = |a: Option<Color>, b: Option<Color>| match (a, b) {
+			(Some(a), Some(b)) => Some(a.blend(b, alpha)),
+			_ if self_dominant => a,
+			_ => b,
+		};
+		Self {
+			fg: blend(self.fg, other.fg),
+			bg: blend(self.bg, other.bg),
+			modifiers: if self_dominant { self.modifiers } else { other.modifiers },
+		}
+	}
+
 	/// Convert to abstract Style.
 	pub fn to_style(self) -> Style {
 		let mut style = Style::new().add_modifier(self.modifiers);
@@ -327,6 +346,89 @@ impl SyntaxStyles {
 		}
 	}
 
+	/// Blends every capture with the matching capture in `other`.
+	///
+	/// See [`SyntaxStyle::blend_with`] for the per-style rules; stepping `alpha`
+	/// from `0.0` to `1.0` crossfades from `other` to `self`.
+	pub fn blend_with(&self, other: &SyntaxStyles, alpha: f32) -> Self {
+		Self {
+			attribute: self.attribute.blend_with(other.attribute, alpha),
+			tag: self.tag.blend_with(other.tag, alpha),
+			namespace: self.namespace.blend_with(other.namespace, alpha),
+			comment: self.comment.blend_with(other.comment, alpha),
+			comment_line: self.comment_line.blend_with(other.comment_line, alpha),
+			comment_block: self.comment_block.blend_with(other.comment_block, alpha),
+			comment_block_documentation: self.comment_block_documentation.blend_with(other.comment_block_documentation, alpha),
+			constant: self.constant.blend_with(other.constant, alpha),
+			constant_builtin: self.constant_builtin.blend_with(other.constant_builtin, alpha),
+			constant_builtin_boolean: self.constant_builtin_boolean.blend_with(other.constant_builtin_boolean, alpha),
+			constant_character: self.constant_character.blend_with(other.constant_character, alpha),
+			constant_character_escape: self.constant_character_escape.blend_with(other.constant_character_escape, alpha),
+			constant_numeric: self.constant_numeric.blend_with(other.constant_numeric, alpha),
+			constant_numeric_integer: self.constant_numeric_integer.blend_with(other.constant_numeric_integer, alpha),
+			constant_numeric_float: self.constant_numeric_float.blend_with(other.constant_numeric_float, alpha),
+			constructor: self.constructor.blend_with(other.constructor, alpha),
+			function: self.function.blend_with(other.function, alpha),
+			function_builtin: self.function_builtin.blend_with(other.function_builtin, alpha),
+			function_method: self.function_method.blend_with(other.function_method, alpha),
+			function_macro: self.function_macro.blend_with(other.function_macro, alpha),
+			function_special: self.function_special.blend_with(other.function_special, alpha),
+			keyword: self.keyword.blend_with(other.keyword, alpha),
+			keyword_control: self.keyword_control.blend_with(other.keyword_control, alpha),
+			keyword_control_conditional: self.keyword_control_conditional.blend_with(other.keyword_control_conditional, alpha),
+			keyword_control_repeat: self.keyword_control_repeat.blend_with(other.keyword_control_repeat, alpha),
+			keyword_control_import: self.keyword_control_import.blend_with(other.keyword_control_import, alpha),
+			keyword_control_return: self.keyword_control_return.blend_with(other.keyword_control_return, alpha),
+			keyword_control_exception: self.keyword_control_exception.blend_with(other.keyword_control_exception, alpha),
+			keyword_operator: self.keyword_operator.blend_with(other.keyword_operator, alpha),
+			keyword_directive: self.keyword_directive.blend_with(other.keyword_directive, alpha),
+			keyword_function: self.keyword_function.blend_with(other.keyword_function, alpha),
+			keyword_storage: self.keyword_storage.blend_with(other.keyword_storage, alpha),
+			keyword_storage_type: self.keyword_storage_type.blend_with(other.keyword_storage_type, alpha),
+			keyword_storage_modifier: self.keyword_storage_modifier.blend_with(other.keyword_storage_modifier, alpha),
+			label: self.label.blend_with(other.label, alpha),
+			operator: self.operator.blend_with(other.operator, alpha),
+			punctuation: self.punctuation.blend_with(other.punctuation, alpha),
+			punctuation_bracket: self.punctuation_bracket.blend_with(other.punctuation_bracket, alpha),
+			punctuation_delimiter: self.punctuation_delimiter.blend_with(other.punctuation_delimiter, alpha),
+			punctuation_special: self.punctuation_special.blend_with(other.punctuation_special, alpha),
+			string: self.string.blend_with(other.string, alpha),
+			string_regexp: self.string_regexp.blend_with(other.string_regexp, alpha),
+			string_special: self.string_special.blend_with(other.string_special, alpha),
+			string_special_path: self.string_special_path.blend_with(other.string_special_path, alpha),
+			string_special_url: self.string_special_url.blend_with(other.string_special_url, alpha),
+			string_special_symbol: self.string_special_symbol.blend_with(other.string_special_symbol, alpha),
+			r#type: self.r#type.blend_with(other.r#type, alpha),
+			type_builtin: self.type_builtin.blend_with(other.type_builtin, alpha),
+			type_parameter: self.type_parameter.blend_with(other.type_parameter, alpha),
+			type_enum_variant: self.type_enum_variant.blend_with(other.type_enum_variant, alpha),
+			variable: self.variable.blend_with(other.variable, alpha),
+			variable_builtin: self.variable_builtin.blend_with(other.variable_builtin, alpha),
+			variable_parameter: self.variable_parameter.blend_with(other.variable_parameter, alpha),
+			variable_other: self.variable_other.blend_with(other.variable_other, alpha),
+			variable_other_member: self.variable_other_member.blend_with(other.variable_other_member, alpha),
+			markup_heading: self.markup_heading.blend_with(other.markup_heading, alpha),
+			markup_heading_1: self.markup_heading_1.blend_with(other.markup_heading_1, alpha),
+			markup_heading_2: self.markup_heading_2.blend_with(other.markup_heading_2, alpha),
+			markup_heading_3: self.markup_heading_3.blend_with(other.markup_heading_3, alpha),
+			markup_bold: self.markup_bold.blend_with(other.markup_bold, alpha),
+			markup_italic: self.markup_italic.blend_with(other.markup_italic, alpha),
+			markup_strikethrough: self.markup_strikethrough.blend_with(other.markup_strikethrough, alpha),
+			markup_link: self.markup_link.blend_with(other.markup_link, alpha),
+			markup_link_url: self.markup_link_url.blend_with(other.markup_link_url, alpha),
+			markup_link_text: self.markup_link_text.blend_with(other.markup_link_text, alpha),
+			markup_quote: self.markup_quote.blend_with(other.markup_quote, alpha),
+			markup_raw: self.markup_raw.blend_with(other.markup_raw, alpha),
+			markup_raw_inline: self.markup_raw_inline.blend_with(other.markup_raw_inline, alpha),
+			markup_raw_block: self.markup_raw_block.blend_with(other.markup_raw_block, alpha),
+			markup_list: self.markup_list.blend_with(other.markup_list, alpha),
+			diff_plus: self.diff_plus.blend_with(other.diff_plus, alpha),
+			diff_minus: self.diff_minus.blend_with(other.diff_minus, alpha),
+			diff_delta: self.diff_delta.blend_with(other.diff_delta, alpha),
+			special: self.special.blend_with(other.special, alpha),
+		}
+	}
+
 	/// Resolve a scope name to a style with hierarchical fallback.
 	///
 	/// Given "keyword.control.import", tries in order:
@@ -335,25 +437,31 @@ impl SyntaxStyles {
 	/// 3. keyword
 	/// 4. Default style
 	pub fn resolve(&self, scope: &str) -> Style {
+		self.resolve_scope(scope).map_or_else(Style::new, SyntaxStyle::to_style)
+	}
+
+	/// Like [`Self::resolve`], but returns the nearest styled ancestor itself.
+	///
+	/// Returns `None` when neither `scope` nor any of its ancestors carries a
+	/// color or modifier, including scopes with an unknown root.
+	///
+	/// The highlight pipeline already resolves each recognized scope once per
+	/// theme into `HighlightStyles`, so this walk is not on the per-span path.
+	pub fn resolve_scope(&self, scope: &str) -> Option<SyntaxStyle> {
 		let mut current = scope;
 		loop {
 			if let Some(style) = self.get_by_scope(current)
 				&& (style.fg.is_some() || style.bg.is_some() || !style.modifiers.is_empty())
 			{
-				return style.to_style();
+				return Some(style);
 			}
 
-			match current.rfind('.') {
-				Some(idx) => current = &current[..idx],
-				None => break,
-			}
+			current = &current[..current.rfind('.')?];
 		}
-
-		Style::new()
 	}
 
 	/// Get style by exact scope name (with dots converted to underscores).
-	fn get_by_scope(&self, scope: &str) -> Option<SyntaxStyle> {
+	pub(crate) fn get_by_scope(&self, scope: &str) -> Option<SyntaxStyle> {
 		Some(match scope {
 			"attribute" => self.attribute,
 			"tag" => self.tag,
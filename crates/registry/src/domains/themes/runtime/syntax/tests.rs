@@ -28,6 +28,20 @@ fn test_resolve_partial_hierarchy() {
 	assert_eq!(resolved.fg, Some(Color::Blue));
 }
 
+#[test]
+fn test_resolve_scope_walks_ancestors() {
+	let mut styles = SyntaxStyles::minimal();
+	styles.function = SyntaxStyle::fg(Color::Yellow);
+	styles.string_special = SyntaxStyle::fg(Color::Green);
+
+	assert_eq!(styles.resolve_scope("function.method").and_then(|s| s.fg), Some(Color::Yellow));
+	assert_eq!(styles.resolve_scope("function.method.builtin").and_then(|s| s.fg), Some(Color::Yellow));
+	assert_eq!(styles.resolve_scope("string.special.url").and_then(|s| s.fg), Some(Color::Green));
+	assert!(styles.resolve_scope("string").is_none(), "unstyled known scopes do not resolve");
+	assert!(styles.resolve_scope("nonexistent.scope").is_none());
+	assert_eq!(styles.resolve("nonexistent.scope"), Style::new());
+}
+
 #[test]
 fn test_scope_names_complete() {
 	let names = SyntaxStyles::scope_names();
@@ -35,3 +49,40 @@ fn test_scope_names_complete() {
 	assert!(names.contains(&"function.macro"));
 	assert!(names.contains(&"variable.other.member"));
 }
+
+#[test]
+fn test_blend_with_modifier_crossover() {
+	let bold = SyntaxStyle::fg_mod(Color::Rgb(200, 0, 0), Modifier::BOLD);
+	let plain = SyntaxStyle::fg(Color::Rgb(0, 0, 100));
+
+	let mid = bold.blend_with(plain, 0.5);
+	assert_eq!(mid.fg, Some(Color::Rgb(100, 0, 50)));
+	assert!(mid.modifiers.contains(Modifier::BOLD), "self wins at the midpoint");
+
+	let below = bold.blend_with(plain, 0.49);
+	assert!(below.modifiers.is_empty(), "other wins below the midpoint");
+	assert!(plain.blend_with(bold, 0.49).modifiers.contains(Modifier::BOLD));
+
+	assert_eq!(bold.blend_with(plain, 1.0).fg, bold.fg);
+	assert_eq!(bold.blend_with(plain, 0.0).fg, plain.fg);
+}
+
+#[test]
+fn test_blend_with_one_sided_color_and_styles() {
+	let with_bg = SyntaxStyle::fg(Color::Rgb(10, 10, 10)).with_bg(Color::Rgb(40, 40, 40));
+	let no_bg = SyntaxStyle::fg(Color::Rgb(30, 30, 30));
+	assert_eq!(with_bg.blend_with(no_bg, 0.5).bg, Some(Color::Rgb(40, 40, 40)));
+	assert_eq!(with_bg.blend_with(no_bg, 0.2).bg, None);
+
+	let mut from = SyntaxStyles::minimal();
+	from.keyword = SyntaxStyle::fg_mod(Color::Rgb(200, 0, 0), Modifier::BOLD);
+	let mut to = SyntaxStyles::minimal();
+	to.keyword = SyntaxStyle::fg(Color::Rgb(0, 0, 100));
+	to.string = SyntaxStyle::fg(Color::Rgb(0, 100, 0));
+
+	let blended = from.blend_with(&to, 0.5);
+	assert_eq!(blended.keyword.fg, Some(Color::Rgb(100, 0, 50)));
+	assert!(blended.keyword.modifiers.contains(Modifier::BOLD));
+	assert_eq!(blended.string.fg, None);
+	assert_eq!(from.blend_with(&to, 0.0).string.fg, Some(Color::Rgb(0, 100, 0)));
+}
@@ -0,0 +1,181 @@
+//! WCAG contrast checks for theme color pairs.
+
+use std::fmt;
+
+use xeno_primitives::Color;
+
+use super::types::ThemeColors;
+
+/// Minimum contrast ratio for normal text against its background (WCAG AA).
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// A color pair whose contrast falls below its minimum ratio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityIssue {
+	/// Foreground and background names, e.g. `("ui.fg", "ui.bg")`.
+	pub pair: (&'static str, &'static str),
+	/// Computed WCAG contrast ratio.
+	pub ratio: f64,
+	/// Ratio the pair was expected to reach.
+	pub minimum: f64,
+}
+
+impl fmt::Display for AccessibilityIssue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (fg, bg) = self.pair;
+		write!(f, "{fg}/{bg}: contrast {:.2}:1 (minimum {:.1}:1)", self.ratio, self.minimum)
+	}
+}
+
+/// WCAG 2.1 relative luminance of `color`.
+fn relative_luminance(color: Color) -> f64 {
+	let (r, g, b) = color.to_rgb();
+	let linear = |c: u8| {
+		let c = f64::from(c) / 255.0;
+		if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+	};
+	0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+impl ThemeColors {
+	/// Computes the WCAG 2.1 contrast ratio of `fg` against `bg`, from `1.0`
+	/// (identical luminance) to `21.0` (black on white).
+	pub fn wcag_contrast_ratio(fg: Color, bg: Color) -> f64 {
+		let (fg, bg) = (relative_luminance(fg), relative_luminance(bg));
+		let (lighter, darker) = if fg > bg { (fg, bg) } else { (bg, fg) };
+		(lighter + 0.05) / (darker + 0.05)
+	}
+
+	/// Returns every UI foreground/background pair whose contrast is below
+	/// `min_ratio`; WCAG AA asks for `4.5` for body text.
+	///
+	/// Only pairs where both colors are [`Color::Rgb`] are checked: reset, named
+	/// and indexed colors are rendered from the terminal's own palette, so their
+	/// actual contrast is unknown.
+	pub fn audit_accessibility(&self, min_ratio: f64) -> Vec<AccessibilityIssue> {
+		let (ui, mode, popup, sem) = (&self.ui, &self.mode, &self.popup, &self.semantic);
+		let pairs = [
+			(("ui.fg", "ui.bg"), ui.fg, ui.bg),
+			(("ui.gutter_fg", "ui.bg"), ui.gutter_fg, ui.bg),
+			(("ui.message_fg", "ui.bg"), ui.message_fg, ui.bg),
+			(("ui.command_input_fg", "ui.bg"), ui.command_input_fg, ui.bg),
+			(("ui.cursor_fg", "ui.cursor_bg"), ui.cursor_fg, ui.cursor_bg),
+			(("ui.selection_fg", "ui.selection_bg"), ui.selection_fg, ui.selection_bg),
+			(("mode.normal.fg", "mode.normal.bg"), mode.normal.fg, mode.normal.bg),
+			(("mode.insert.fg", "mode.insert.bg"), mode.insert.fg, mode.insert.bg),
+			(("mode.prefix.fg", "mode.prefix.bg"), mode.prefix.fg, mode.prefix.bg),
+			(("mode.command.fg", "mode.command.bg"), mode.command.fg, mode.command.bg),
+			(("popup.fg", "popup.bg"), popup.fg, popup.bg),
+			(("popup.title", "popup.bg"), popup.title, popup.bg),
+			(("semantic.error", "popup.bg"), sem.error, popup.bg),
+			(("semantic.warning", "popup.bg"), sem.warning, popup.bg),
+			(("semantic.success", "popup.bg"), sem.success, popup.bg),
+			(("semantic.info", "popup.bg"), sem.info, popup.bg),
+			(("semantic.hint", "popup.bg"), sem.hint, popup.bg),
+			(("semantic.dim", "popup.bg"), sem.dim, popup.bg),
+			(("semantic.link", "popup.bg"), sem.link, popup.bg),
+			(("semantic.match_hl", "popup.bg"), sem.match_hl, popup.bg),
+			(("semantic.accent", "popup.bg"), sem.accent, popup.bg),
+		];
+
+		pairs
+			.into_iter()
+			.filter(|&(_, fg, bg)| matches!((fg, bg), (Color::Rgb(..), Color::Rgb(..))))
+			.filter_map(|(pair, fg, bg)| {
+				let ratio = Self::wcag_contrast_ratio(fg, bg);
+				(ratio < min_ratio).then_some(AccessibilityIssue {
+					pair,
+					ratio,
+					minimum: min_ratio,
+				})
+			})
+			.collect()
+	}
+
+	/// Returns `true` if no UI color pair falls below `min_ratio`; see
+	/// [`Self::audit_accessibility`].
+	pub fn is_accessible(&self, min_ratio: f64) -> bool {
+		self.audit_accessibility(min_ratio).is_empty()
+	}
+}
+
+/// Checks the key foreground/background pairs of `colors` against
+/// [`MIN_TEXT_CONTRAST`]; see [`ThemeColors::audit_accessibility`].
+pub fn audit(colors: &ThemeColors) -> Vec<AccessibilityIssue> {
+	colors.audit_accessibility(MIN_TEXT_CONTRAST)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::themes::get_theme;
+
+	fn default_colors() -> ThemeColors {
+		let theme = get_theme(crate::themes::DEFAULT_THEME_ID).expect("default theme registered");
+		theme.colors
+	}
+
+	#[test]
+	fn low_contrast_pairs_are_reported_with_ratio() {
+		let mut colors = default_colors();
+		colors.ui.bg = Color::Rgb(0x30, 0x30, 0x30);
+		colors.ui.fg = Color::Rgb(0x38, 0x38, 0x38);
+		colors.popup.bg = Color::Rgb(0x20, 0x20, 0x20);
+		colors.semantic.error = Color::Rgb(0x28, 0x20, 0x20);
+
+		let issues = audit(&colors);
+		let fg = issues.iter().find(|issue| issue.pair == ("ui.fg", "ui.bg")).expect("fg/bg flagged");
+		assert!(fg.ratio < 1.2, "ratio {}", fg.ratio);
+		assert_eq!(fg.minimum, MIN_TEXT_CONTRAST);
+		assert!(issues.iter().any(|issue| issue.pair == ("semantic.error", "popup.bg")));
+		assert!(fg.to_string().starts_with("ui.fg/ui.bg: contrast 1."));
+	}
+
+	#[test]
+	fn default_theme_audit_only_checks_rgb_pairs() {
+		let mut colors = crate::themes::DEFAULT_THEME.colors;
+		let ratio = ThemeColors::wcag_contrast_ratio(colors.semantic.error, colors.popup.bg);
+		assert!(ratio.is_finite() && ratio >= 1.0, "{ratio}");
+		assert!(colors.is_accessible(f64::INFINITY), "the default theme uses terminal palette colors");
+
+		colors.semantic.error = Color::Rgb(0x40, 0x10, 0x10);
+		let issues = colors.audit_accessibility(MIN_TEXT_CONTRAST);
+		let error = issues
+			.iter()
+			.find(|issue| issue.pair == ("semantic.error", "popup.bg"))
+			.expect("dark red on near-black popup flagged");
+		assert!(error.ratio < 4.5 && error.minimum == 4.5, "{error}");
+		assert!(colors.is_accessible(1.0));
+	}
+
+	#[test]
+	fn wcag_contrast_ratio_spans_one_to_twenty_one() {
+		let white = Color::Rgb(0xff, 0xff, 0xff);
+		let black = Color::Rgb(0, 0, 0);
+		assert!((ThemeColors::wcag_contrast_ratio(white, black) - 21.0).abs() < 1e-9);
+		assert_eq!(ThemeColors::wcag_contrast_ratio(black, white), ThemeColors::wcag_contrast_ratio(white, black));
+		assert_eq!(ThemeColors::wcag_contrast_ratio(white, white), 1.0);
+		let gray = ThemeColors::wcag_contrast_ratio(Color::Rgb(0x76, 0x76, 0x76), white);
+		assert!((4.5..4.6).contains(&gray), "#767676 on white is the AA threshold, got {gray}");
+	}
+
+	#[test]
+	fn pairs_with_terminal_palette_colors_are_skipped() {
+		let mut colors = default_colors();
+		colors.ui.fg = Color::Reset;
+		colors.ui.bg = Color::Rgb(0, 0, 0);
+		colors.ui.cursor_fg = Color::Black;
+		colors.ui.cursor_bg = Color::Rgb(0, 0, 0);
+		colors.ui.selection_fg = Color::Indexed(0);
+		colors.ui.selection_bg = Color::Rgb(0, 0, 0);
+		colors.popup.fg = Color::Rgb(0, 0, 0);
+		colors.popup.bg = Color::Rgb(0, 0, 0);
+
+		let issues = audit(&colors);
+		for pair in [("ui.fg", "ui.bg"), ("ui.cursor_fg", "ui.cursor_bg"), ("ui.selection_fg", "ui.selection_bg")] {
+			assert!(!issues.iter().any(|issue| issue.pair == pair), "{pair:?} uses a palette color");
+		}
+		let popup = issues.iter().find(|issue| issue.pair == ("popup.fg", "popup.bg")).expect("RGB pair checked");
+		assert_eq!(popup.ratio, 1.0);
+	}
+}
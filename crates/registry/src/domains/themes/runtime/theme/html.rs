@@ -0,0 +1,196 @@
+//! CSS and HTML export of theme syntax styles for web documentation.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use xeno_primitives::{Color, Modifier};
+
+use super::super::syntax::{SyntaxStyle, SyntaxStyles};
+use super::types::{ThemeColors, ThemeDef};
+
+/// Class prefix used by [`ThemeDef::to_html_snippet`].
+pub const HTML_CLASS_PREFIX: &str = "xeno-";
+
+/// Returns `color` as a CSS hex color, or `None` for [`Color::Reset`].
+fn css_color(color: Color) -> Option<String> {
+	if color == Color::Reset {
+		return None;
+	}
+	let (r, g, b) = color.to_rgb();
+	Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// Turns a capture name such as `keyword.control` into a class name.
+fn css_class_name(capture: &str) -> String {
+	capture.replace('.', "-")
+}
+
+fn escape_html(text: &str, out: &mut String) {
+	for ch in text.chars() {
+		match ch {
+			'&' => out.push_str("&amp;"),
+			'<' => out.push_str("&lt;"),
+			'>' => out.push_str("&gt;"),
+			'"' => out.push_str("&quot;"),
+			_ => out.push(ch),
+		}
+	}
+}
+
+impl SyntaxStyle {
+	/// Returns the CSS declarations for this style, e.g.
+	/// `color: #f92672; font-weight: bold`.
+	///
+	/// Reset colors and modifiers without a CSS counterpart are left out.
+	pub fn to_css_declarations(&self) -> String {
+		let mut decls = Vec::new();
+		if let Some(fg) = self.fg.and_then(css_color) {
+			decls.push(format!("color: {fg}"));
+		}
+		if let Some(bg) = self.bg.and_then(css_color) {
+			decls.push(format!("background-color: {bg}"));
+		}
+		if self.modifiers.contains(Modifier::BOLD) {
+			decls.push("font-weight: bold".to_string());
+		}
+		if self.modifiers.contains(Modifier::ITALIC) {
+			decls.push("font-style: italic".to_string());
+		}
+		if self.modifiers.contains(Modifier::DIM) {
+			decls.push("opacity: 0.7".to_string());
+		}
+		let decorations: Vec<_> = [(Modifier::UNDERLINED, "underline"), (Modifier::CROSSED_OUT, "line-through")]
+			.into_iter()
+			.filter(|(modifier, _)| self.modifiers.contains(*modifier))
+			.map(|(_, decoration)| decoration)
+			.collect();
+		if !decorations.is_empty() {
+			decls.push(format!("text-decoration: {}", decorations.join(" ")));
+		}
+		decls.join("; ")
+	}
+
+	/// Returns a CSS rule applying this style to the class named after
+	/// `capture`, with dots replaced by dashes: `keyword.control` becomes
+	/// `.keyword-control { ... }`.
+	pub fn to_css_class(&self, capture: &str) -> String {
+		match self.to_css_declarations() {
+			decls if decls.is_empty() => format!(".{} {{}}", css_class_name(capture)),
+			decls => format!(".{} {{ {decls} }}", css_class_name(capture)),
+		}
+	}
+}
+
+impl ThemeColors {
+	/// Returns a stylesheet with one rule per styled syntax capture, each
+	/// class named `{class_prefix}{capture}`, plus a `{class_prefix}code` rule
+	/// for the editor foreground and background.
+	///
+	/// Captures without a style of their own inherit their nearest styled
+	/// parent, as in [`SyntaxStyles::resolve_scope`].
+	pub fn to_css_stylesheet(&self, class_prefix: &str) -> String {
+		let base = SyntaxStyle {
+			fg: Some(self.ui.fg),
+			bg: Some(self.ui.bg),
+			modifiers: Modifier::empty(),
+		};
+		let mut css = base.to_css_class(&format!("{class_prefix}code"));
+		css.push('\n');
+		for &capture in SyntaxStyles::scope_names() {
+			if let Some(style) = self.syntax.resolve_scope(capture) {
+				css.push_str(&style.to_css_class(&format!("{class_prefix}{capture}")));
+				css.push('\n');
+			}
+		}
+		css
+	}
+}
+
+impl ThemeDef {
+	/// Renders `code` as a self-contained HTML snippet styled with this theme.
+	///
+	/// Without captures the code is only escaped; see
+	/// [`Self::to_html_snippet_with_captures`] for highlighted output.
+	pub fn to_html_snippet(&self, code: &str, language: &str) -> String {
+		self.to_html_snippet_with_captures(code, language, &[])
+	}
+
+	/// Renders pre-tokenized `code` as a self-contained HTML snippet.
+	///
+	/// Each capture is a byte range of `code` and the capture name a
+	/// highlighter assigned to it. Captures must be sorted; ones that overlap
+	/// an earlier capture or do not fall on char boundaries are ignored.
+	pub fn to_html_snippet_with_captures(&self, code: &str, language: &str, captures: &[(Range<usize>, &str)]) -> String {
+		let mut html = format!(
+			"<style>\n{}</style>\n<pre class=\"{HTML_CLASS_PREFIX}code\"><code class=\"language-",
+			self.colors.to_css_stylesheet(HTML_CLASS_PREFIX)
+		);
+		escape_html(language, &mut html);
+		html.push_str("\">");
+
+		let mut pos = 0;
+		for (range, capture) in captures {
+			let Some(text) = code.get(range.clone()).filter(|_| range.start >= pos) else {
+				continue;
+			};
+			escape_html(&code[pos..range.start], &mut html);
+			let _ = write!(html, "<span class=\"{HTML_CLASS_PREFIX}{}\">", css_class_name(capture));
+			escape_html(text, &mut html);
+			html.push_str("</span>");
+			pos = range.end;
+		}
+		escape_html(&code[pos..], &mut html);
+
+		html.push_str("</code></pre>\n");
+		html
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::themes::{DEFAULT_THEME, get_theme};
+
+	#[test]
+	fn default_theme_keyword_style_exports_as_css() {
+		let theme = get_theme(crate::themes::DEFAULT_THEME_ID).expect("default theme registered");
+		let keyword = theme.colors.syntax.keyword;
+
+		assert_eq!(keyword.to_css_declarations(), "color: #f92672");
+		assert_eq!(keyword.to_css_class("hl-keyword"), ".hl-keyword { color: #f92672 }");
+
+		let css = theme.colors.to_css_stylesheet("hl-");
+		assert!(css.lines().any(|rule| rule == ".hl-keyword { color: #f92672 }"), "{css}");
+		assert!(css.starts_with(".hl-code { color: #f8f8f2; background-color: #272822 }\n"), "{css}");
+	}
+
+	#[test]
+	fn modifiers_map_to_css_properties() {
+		let style = SyntaxStyle::fg_mod(Color::Rgb(0xfb, 0x49, 0x34), Modifier::BOLD | Modifier::UNDERLINED | Modifier::CROSSED_OUT);
+
+		assert_eq!(
+			style.to_css_class("keyword.control"),
+			".keyword-control { color: #fb4934; font-weight: bold; text-decoration: underline line-through }"
+		);
+		assert_eq!(SyntaxStyle::fg(Color::Reset).to_css_class("variable"), ".variable {}");
+	}
+
+	#[test]
+	fn html_snippet_wraps_captures_and_escapes_text() {
+		let mut theme = DEFAULT_THEME;
+		theme.colors.syntax.keyword = SyntaxStyle::fg(Color::Red);
+		let code = "if a < b {}";
+
+		let html = theme.to_html_snippet_with_captures(code, "rust", &[(0..2, "keyword.control"), (1..4, "variable"), (5..6, "operator")]);
+
+		assert!(html.contains(".xeno-keyword-control { color: #800000 }"), "{html}");
+		assert!(html.ends_with(
+			"<pre class=\"xeno-code\"><code class=\"language-rust\"><span class=\"xeno-keyword-control\">if</span> a <span class=\"xeno-operator\">&lt;</span> b {}</code></pre>\n"
+		));
+		assert!(
+			theme
+				.to_html_snippet(code, "rust")
+				.contains("<code class=\"language-rust\">if a &lt; b {}</code>")
+		);
+	}
+}
@@ -1,9 +1,19 @@
 //! Theme entry types and operations.
 
+mod audit;
+#[cfg(feature = "html-export")]
+mod html;
 mod mod_types;
 mod ops;
+mod overrides;
+mod patch;
 mod types;
 
+pub use audit::*;
+#[cfg(feature = "html-export")]
+pub use html::*;
 pub use mod_types::*;
 pub use ops::*;
+pub use overrides::*;
+pub use patch::*;
 pub use types::*;
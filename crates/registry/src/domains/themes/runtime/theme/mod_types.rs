@@ -10,10 +10,12 @@ pub static DEFAULT_THEME: ThemeDef = ThemeDef {
 		id: "default",
 		name: "default",
 		keys: &[],
+		categories: &[],
 		description: "",
 		priority: 0,
 		source: RegistrySource::Builtin,
 		mutates_buffer: false,
+		deprecated: None,
 	},
 	variant: crate::themes::ThemeVariant::Dark,
 	colors: ThemeColors {
@@ -1,6 +1,8 @@
 use xeno_primitives::Color;
 
-use super::mod_types::THEMES;
+use super::mod_types::{THEMES, get_theme};
+use super::types::{ThemeEntry, ThemeVariant};
+use crate::core::{RegistryRef, ThemeId};
 
 /// Blend two colors with the given alpha (0.0 = bg, 1.0 = fg).
 #[inline]
@@ -33,3 +35,100 @@ pub fn suggest_theme(name: &str) -> Option<String> {
 
 	if best_score > 0.8 { best_match } else { None }
 }
+
+/// Finds the counterpart of theme `name` with the given `variant`.
+///
+/// Pairs follow a naming convention: with `base` being the theme name minus any
+/// `-dark`/`-light` (or `_dark`/`_light`) suffix, the light counterpart is
+/// `base-light`, `base_light` or `base` itself, and likewise for dark. Falls
+/// back to `name` when no registered counterpart has `variant`, and returns
+/// `None` only when `name` is not registered.
+pub fn get_theme_for_variant(name: &str, variant: ThemeVariant) -> Option<RegistryRef<ThemeEntry, ThemeId>> {
+	let theme = get_theme(name)?;
+	if theme.variant == variant {
+		return Some(theme);
+	}
+
+	let theme_name = theme.name_str();
+	let base = ["-dark", "-light", "_dark", "_light"]
+		.iter()
+		.find_map(|suffix| theme_name.strip_suffix(suffix))
+		.unwrap_or(theme_name);
+	let suffix = match variant {
+		ThemeVariant::Dark => "dark",
+		ThemeVariant::Light => "light",
+	};
+	let counterpart = [format!("{base}-{suffix}"), format!("{base}_{suffix}"), base.to_string()]
+		.iter()
+		.filter_map(|candidate| get_theme(candidate))
+		.find(|candidate| candidate.variant == variant);
+	Some(counterpart.unwrap_or(theme))
+}
+
+/// Returns every registered theme with `variant`, sorted by name.
+pub fn all_by_variant(variant: ThemeVariant) -> Vec<RegistryRef<ThemeEntry, ThemeId>> {
+	let mut themes: Vec<_> = THEMES.snapshot_guard().iter_refs().filter(|theme| theme.variant == variant).collect();
+	themes.sort_by(|a, b| a.name_str().cmp(b.name_str()));
+	themes
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+	use crate::core::{LinkedDef, LinkedMetaOwned, RegistrySource};
+	use crate::themes::{DEFAULT_THEME, ThemeInput, ThemePayload};
+
+	fn register(name: &str, variant: ThemeVariant) {
+		let def = LinkedDef {
+			meta: LinkedMetaOwned {
+				id: format!("test::{name}"),
+				name: name.to_string(),
+				keys: Vec::new(),
+				categories: Vec::new(),
+				description: String::new(),
+				priority: 0,
+				source: RegistrySource::Runtime,
+				mutates_buffer: false,
+				deprecated: None,
+				short_desc: name.to_string(),
+			},
+			payload: ThemePayload {
+				variant,
+				colors: DEFAULT_THEME.colors,
+			},
+		};
+		THEMES.replace(Arc::new(ThemeInput::Linked(def)));
+	}
+
+	#[test]
+	fn variant_pairs_resolve_by_suffix() {
+		register("pairtest", ThemeVariant::Dark);
+		register("pairtest-light", ThemeVariant::Light);
+		register("pairtest_dim_dark", ThemeVariant::Dark);
+		register("pairtest_dim", ThemeVariant::Light);
+
+		let name = |theme: Option<RegistryRef<ThemeEntry, ThemeId>>| theme.map(|theme| theme.name_str().to_string());
+		assert_eq!(name(get_theme_for_variant("pairtest", ThemeVariant::Light)).as_deref(), Some("pairtest-light"));
+		assert_eq!(name(get_theme_for_variant("pairtest-light", ThemeVariant::Dark)).as_deref(), Some("pairtest"));
+		assert_eq!(name(get_theme_for_variant("pairtest", ThemeVariant::Dark)).as_deref(), Some("pairtest"));
+		assert_eq!(
+			name(get_theme_for_variant("pairtest_dim_dark", ThemeVariant::Light)).as_deref(),
+			Some("pairtest_dim")
+		);
+
+		let light = all_by_variant(ThemeVariant::Light);
+		assert!(light.iter().all(|theme| theme.variant == ThemeVariant::Light));
+		assert!(light.iter().any(|theme| theme.name_str() == "pairtest-light"));
+		assert!(!light.iter().any(|theme| theme.name_str() == "pairtest"));
+	}
+
+	#[test]
+	fn unpaired_theme_falls_back_to_itself() {
+		register("pairtest-lonely", ThemeVariant::Dark);
+		let theme = get_theme_for_variant("pairtest-lonely", ThemeVariant::Light).expect("registered theme");
+		assert_eq!(theme.name_str(), "pairtest-lonely");
+		assert!(get_theme_for_variant("pairtest-missing", ThemeVariant::Light).is_none());
+	}
+}
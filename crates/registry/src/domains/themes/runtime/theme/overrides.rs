@@ -0,0 +1,78 @@
+//! Per-buffer theme overrides layered over the global theme.
+
+use rustc_hash::FxHashMap;
+use xeno_primitives::ViewId;
+
+use super::types::ThemeDef;
+
+/// Themes that replace the global theme for individual buffers, such as the
+/// preview pane of a theme picker.
+///
+/// Render contexts resolve their theme through [`Self::resolve`]: a buffer
+/// override if one is set, the global theme otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct ThemeOverrides {
+	by_buffer: FxHashMap<ViewId, ThemeDef>,
+}
+
+impl ThemeOverrides {
+	/// Creates an empty set of overrides.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Overrides the theme of `buffer`, returning the previous override.
+	pub fn set(&mut self, buffer: ViewId, theme: ThemeDef) -> Option<ThemeDef> {
+		self.by_buffer.insert(buffer, theme)
+	}
+
+	/// Removes the override of `buffer`, returning it if one was set.
+	pub fn clear(&mut self, buffer: ViewId) -> Option<ThemeDef> {
+		self.by_buffer.remove(&buffer)
+	}
+
+	/// Returns the override of `buffer`, if any.
+	pub fn get(&self, buffer: ViewId) -> Option<&ThemeDef> {
+		self.by_buffer.get(&buffer)
+	}
+
+	/// Returns the effective theme of `buffer`: its override, else `global`.
+	pub fn resolve<'a>(&'a self, buffer: ViewId, global: &'a ThemeDef) -> &'a ThemeDef {
+		self.by_buffer.get(&buffer).unwrap_or(global)
+	}
+
+	/// Returns `true` if no buffer has an override.
+	pub fn is_empty(&self) -> bool {
+		self.by_buffer.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::RegistryMetaStatic;
+	use crate::themes::DEFAULT_THEME;
+
+	fn preview_theme() -> ThemeDef {
+		ThemeDef {
+			meta: RegistryMetaStatic::minimal("test::preview", "preview", ""),
+			..DEFAULT_THEME
+		}
+	}
+
+	#[test]
+	fn buffer_override_wins_over_global_until_cleared() {
+		let mut overrides = ThemeOverrides::new();
+		let (preview, other) = (ViewId(1), ViewId(2));
+		assert_eq!(overrides.resolve(preview, &DEFAULT_THEME).meta.name, DEFAULT_THEME.meta.name);
+
+		assert!(overrides.set(preview, preview_theme()).is_none());
+		assert_eq!(overrides.resolve(preview, &DEFAULT_THEME).meta.name, "preview");
+		assert_eq!(overrides.resolve(other, &DEFAULT_THEME).meta.name, DEFAULT_THEME.meta.name);
+
+		assert_eq!(overrides.clear(preview).map(|theme| theme.meta.name), Some("preview"));
+		assert_eq!(overrides.resolve(preview, &DEFAULT_THEME).meta.name, DEFAULT_THEME.meta.name);
+		assert!(overrides.clear(preview).is_none());
+		assert!(overrides.is_empty());
+	}
+}
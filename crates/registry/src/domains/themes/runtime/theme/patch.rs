@@ -0,0 +1,346 @@
+//! Partial themes layered over a parent via `inherits`.
+//!
+//! A [`ThemePatch`] only records the colors a theme file spells out. Resolving
+//! it copies the parent's [`ThemeColors`] and overwrites just those fields, so
+//! a child of gruvbox that sets `ui.bg` keeps every other gruvbox color.
+
+use std::collections::HashMap;
+
+use xeno_primitives::Color;
+
+use super::super::syntax::SyntaxStyle;
+use super::types::{LinkedThemeDef, ModeColors, PopupColors, SemanticColors, ThemeColors, ThemePayload, ThemeVariant, UiColors};
+use crate::core::{LinkedDef, LinkedMetaOwned};
+
+macro_rules! color_patch {
+	($(#[$doc:meta])* $name:ident => $target:ty { $($field:ident = $key:literal => $($path:ident).+;)* }) => {
+		$(#[$doc])*
+		#[derive(Clone, Copy, Debug, Default)]
+		pub struct $name {
+			$(pub $field: Option<Color>,)*
+		}
+
+		impl $name {
+			/// Sets the field spelled `key` in theme files; returns `false` for unknown keys.
+			pub fn set(&mut self, key: &str, color: Color) -> bool {
+				match key {
+					$($key => self.$field = Some(color),)*
+					_ => return false,
+				}
+				true
+			}
+
+			/// Overwrites each field of `target` that this patch provides.
+			pub fn apply(&self, target: &mut $target) {
+				$(if let Some(color) = self.$field {
+					target.$($path).+ = color;
+				})*
+			}
+		}
+	};
+}
+
+color_patch! {
+	/// Overrides for [`UiColors`].
+	UiColorsPatch => UiColors {
+		bg = "bg" => bg;
+		fg = "fg" => fg;
+		nontext_bg = "nontext-bg" => nontext_bg;
+		gutter_fg = "gutter-fg" => gutter_fg;
+		cursor_bg = "cursor-bg" => cursor_bg;
+		cursor_fg = "cursor-fg" => cursor_fg;
+		cursorline_bg = "cursorline-bg" => cursorline_bg;
+		selection_bg = "selection-bg" => selection_bg;
+		selection_fg = "selection-fg" => selection_fg;
+		message_fg = "message-fg" => message_fg;
+		command_input_fg = "command-input-fg" => command_input_fg;
+	}
+}
+
+color_patch! {
+	/// Overrides for [`ModeColors`].
+	ModeColorsPatch => ModeColors {
+		normal_bg = "normal-bg" => normal.bg;
+		normal_fg = "normal-fg" => normal.fg;
+		insert_bg = "insert-bg" => insert.bg;
+		insert_fg = "insert-fg" => insert.fg;
+		prefix_bg = "prefix-bg" => prefix.bg;
+		prefix_fg = "prefix-fg" => prefix.fg;
+		command_bg = "command-bg" => command.bg;
+		command_fg = "command-fg" => command.fg;
+	}
+}
+
+color_patch! {
+	/// Overrides for [`SemanticColors`].
+	SemanticColorsPatch => SemanticColors {
+		error = "error" => error;
+		warning = "warning" => warning;
+		success = "success" => success;
+		info = "info" => info;
+		hint = "hint" => hint;
+		dim = "dim" => dim;
+		link = "link" => link;
+		match_hl = "match" => match_hl;
+		accent = "accent" => accent;
+	}
+}
+
+color_patch! {
+	/// Overrides for [`PopupColors`].
+	PopupColorsPatch => PopupColors {
+		bg = "bg" => bg;
+		fg = "fg" => fg;
+		border = "border" => border;
+		title = "title" => title;
+	}
+}
+
+/// Overrides for [`ThemeColors`].
+#[derive(Clone, Debug, Default)]
+pub struct ThemeColorsPatch {
+	pub ui: UiColorsPatch,
+	pub mode: ModeColorsPatch,
+	pub semantic: SemanticColorsPatch,
+	pub popup: PopupColorsPatch,
+	/// Syntax scopes to replace, in file order.
+	pub syntax: Vec<(String, SyntaxStyle)>,
+}
+
+impl ThemeColorsPatch {
+	/// Overwrites each color of `target` that this patch provides.
+	pub fn apply(&self, target: &mut ThemeColors) {
+		self.ui.apply(&mut target.ui);
+		self.mode.apply(&mut target.mode);
+		self.semantic.apply(&mut target.semantic);
+		self.popup.apply(&mut target.popup);
+		for (scope, style) in &self.syntax {
+			crate::themes::link::set_syntax_style(&mut target.syntax, scope, *style);
+		}
+	}
+}
+
+/// A theme that only lists its differences from the theme it inherits.
+#[derive(Clone)]
+pub struct ThemePatch {
+	pub meta: LinkedMetaOwned,
+	/// Name, ID or key of the parent theme.
+	pub inherits: String,
+	/// Variant override; the parent's variant when `None`.
+	pub variant: Option<ThemeVariant>,
+	pub colors: ThemeColorsPatch,
+}
+
+/// A theme loaded at runtime, before inheritance is resolved.
+#[derive(Clone)]
+pub enum RuntimeTheme {
+	/// A theme that defines every color itself.
+	Complete(Box<LinkedThemeDef>),
+	/// A theme that inherits from another.
+	Patch(Box<ThemePatch>),
+}
+
+impl RuntimeTheme {
+	/// Returns the theme's metadata.
+	pub fn meta(&self) -> &LinkedMetaOwned {
+		match self {
+			Self::Complete(def) => &def.meta,
+			Self::Patch(patch) => &patch.meta,
+		}
+	}
+}
+
+/// Error resolving a [`ThemePatch`] parent.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ThemeInheritError {
+	/// The named parent is neither loaded alongside the theme nor registered.
+	#[error("theme '{theme}' inherits unknown theme '{parent}'")]
+	MissingParent { theme: String, parent: String },
+	/// Following `inherits` leads back to a theme already on the chain.
+	#[error("theme inheritance cycle: {}", .0.join(" -> "))]
+	Cycle(Vec<String>),
+}
+
+impl ThemeInheritError {
+	/// Returns the name of the theme that failed to resolve.
+	pub fn theme(&self) -> &str {
+		match self {
+			Self::MissingParent { theme, .. } => theme,
+			Self::Cycle(chain) => &chain[0],
+		}
+	}
+}
+
+/// Resolves every [`RuntimeTheme::Patch`] against its parent.
+///
+/// Parents are looked up among `themes` first, matching name, ID or key, then
+/// in the theme registry. A patch never resolves to itself, so a runtime
+/// `gruvbox` that inherits `gruvbox` extends the builtin. Themes whose chain is
+/// cyclic or ends at a missing parent are dropped, and each root cause is
+/// reported once.
+pub fn resolve_theme_inheritance(themes: Vec<RuntimeTheme>) -> (Vec<LinkedThemeDef>, Vec<ThemeInheritError>) {
+	let mut resolver = Resolver {
+		themes: &themes,
+		resolved: HashMap::new(),
+		chain: Vec::new(),
+	};
+	let mut resolved = Vec::with_capacity(themes.len());
+	let mut errors = Vec::new();
+	for idx in 0..themes.len() {
+		match resolver.resolve(idx) {
+			Ok(def) => resolved.push(def),
+			Err(err) => {
+				if !errors.contains(&err) {
+					errors.push(err);
+				}
+			}
+		}
+	}
+	(resolved, errors)
+}
+
+struct Resolver<'a> {
+	themes: &'a [RuntimeTheme],
+	resolved: HashMap<usize, Result<LinkedThemeDef, ThemeInheritError>>,
+	/// Indices of the patches currently being resolved, outermost first.
+	chain: Vec<usize>,
+}
+
+impl Resolver<'_> {
+	fn resolve(&mut self, idx: usize) -> Result<LinkedThemeDef, ThemeInheritError> {
+		if let Some(done) = self.resolved.get(&idx) {
+			return done.clone();
+		}
+		let patch = match &self.themes[idx] {
+			RuntimeTheme::Complete(def) => return Ok(def.as_ref().clone()),
+			RuntimeTheme::Patch(patch) => patch,
+		};
+		if let Some(pos) = self.chain.iter().position(|&open| open == idx) {
+			let mut cycle: Vec<String> = self.chain[pos..].iter().map(|&i| self.themes[i].meta().name.clone()).collect();
+			cycle.push(patch.meta.name.clone());
+			return Err(ThemeInheritError::Cycle(cycle));
+		}
+
+		self.chain.push(idx);
+		let parent = self.parent(idx, patch);
+		self.chain.pop();
+
+		let result = parent.map(|(variant, mut colors)| {
+			patch.colors.apply(&mut colors);
+			LinkedDef {
+				meta: patch.meta.clone(),
+				payload: ThemePayload {
+					variant: patch.variant.unwrap_or(variant),
+					colors,
+				},
+			}
+		});
+		self.resolved.insert(idx, result.clone());
+		result
+	}
+
+	fn parent(&mut self, idx: usize, patch: &ThemePatch) -> Result<(ThemeVariant, ThemeColors), ThemeInheritError> {
+		let name = patch.inherits.as_str();
+		let sibling = self.themes.iter().enumerate().position(|(i, theme)| {
+			let meta = theme.meta();
+			i != idx && (meta.name == name || meta.id == name || meta.keys.iter().any(|key| key == name))
+		});
+		if let Some(sibling) = sibling {
+			let def = self.resolve(sibling)?;
+			return Ok((def.payload.variant, def.payload.colors));
+		}
+		match super::get_theme(name) {
+			Some(theme) => Ok((theme.variant, theme.colors)),
+			None => Err(ThemeInheritError::MissingParent {
+				theme: patch.meta.name.clone(),
+				parent: name.to_string(),
+			}),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::RegistrySource;
+	use crate::themes::DEFAULT_THEME;
+
+	fn meta(name: &str) -> LinkedMetaOwned {
+		LinkedMetaOwned {
+			id: format!("xeno-registry::{name}"),
+			name: name.to_string(),
+			keys: Vec::new(),
+			categories: Vec::new(),
+			description: String::new(),
+			priority: 0,
+			source: RegistrySource::Runtime,
+			mutates_buffer: false,
+			deprecated: None,
+			short_desc: name.to_string(),
+		}
+	}
+
+	fn complete(name: &str) -> RuntimeTheme {
+		RuntimeTheme::Complete(Box::new(LinkedDef {
+			meta: meta(name),
+			payload: ThemePayload {
+				variant: ThemeVariant::Light,
+				colors: DEFAULT_THEME.colors,
+			},
+		}))
+	}
+
+	fn patch(name: &str, inherits: &str, colors: ThemeColorsPatch) -> RuntimeTheme {
+		RuntimeTheme::Patch(Box::new(ThemePatch {
+			meta: meta(name),
+			inherits: inherits.to_string(),
+			variant: None,
+			colors,
+		}))
+	}
+
+	#[test]
+	fn child_overrides_only_provided_colors() {
+		let mut colors = ThemeColorsPatch::default();
+		assert!(colors.ui.set("bg", Color::Rgb(1, 2, 3)));
+		assert!(!colors.ui.set("background", Color::Red));
+		colors.syntax.push(("keyword".to_string(), SyntaxStyle::fg(Color::Magenta)));
+
+		let (themes, errors) = resolve_theme_inheritance(vec![patch("child", "base", colors), complete("base")]);
+		assert!(errors.is_empty(), "{errors:?}");
+		let child = themes.iter().find(|def| def.meta.name == "child").expect("child resolves");
+		let parent = DEFAULT_THEME.colors;
+
+		assert_eq!(child.payload.variant, ThemeVariant::Light, "variant is inherited");
+		assert_eq!(child.payload.colors.ui.bg, Color::Rgb(1, 2, 3));
+		assert_eq!(child.payload.colors.ui.fg, parent.ui.fg);
+		assert_eq!(child.payload.colors.ui.selection_bg, parent.ui.selection_bg);
+		assert_eq!(child.payload.colors.popup.bg, parent.popup.bg);
+		assert_eq!(child.payload.colors.syntax.keyword.fg, Some(Color::Magenta));
+		assert_eq!(child.payload.colors.syntax.string.fg, parent.syntax.string.fg);
+	}
+
+	#[test]
+	fn cycles_and_missing_parents_are_reported() {
+		let themes = vec![
+			patch("a", "b", ThemeColorsPatch::default()),
+			patch("b", "a", ThemeColorsPatch::default()),
+			patch("orphan", "nowhere", ThemeColorsPatch::default()),
+			patch("grandchild", "a", ThemeColorsPatch::default()),
+		];
+		let (resolved, errors) = resolve_theme_inheritance(themes);
+
+		assert!(resolved.is_empty(), "descendants of a cycle are dropped with it");
+		assert_eq!(
+			errors,
+			[
+				ThemeInheritError::Cycle(vec!["a".into(), "b".into(), "a".into()]),
+				ThemeInheritError::MissingParent {
+					theme: "orphan".into(),
+					parent: "nowhere".into()
+				},
+			]
+		);
+		assert_eq!(errors[0].to_string(), "theme inheritance cycle: a -> b -> a");
+	}
+}
@@ -0,0 +1,188 @@
+//! JSON export of registry contents for external tooling.
+//!
+//! [`export_all`] walks the live catalog and emits one document that docs and
+//! shell-completion generators can consume without linking the editor. The
+//! document shape is versioned by [`EXPORT_SCHEMA_VERSION`]; entries within a
+//! registry are sorted by canonical ID so output is stable across builds.
+//!
+//! ```text
+//! {
+//!   "schema_version": 1,
+//!   "catalog_version": <u64>,
+//!   "registries": {
+//!     "actions": [{ id, name, aliases, description, categories, priority, source,
+//!                   mutates_buffer, deprecated, repeatable, bindings: [{ mode, keys, priority }] }],
+//!     "commands": [...], "options": [...], "motions": [...],
+//!     "text_objects": [...], "hooks": [...], "themes": [...]
+//!   }
+//! }
+//! ```
+
+use serde_json::{Map, Value, json};
+
+use crate::core::index::SnapshotGuard;
+use crate::core::{DenseId, RegistryEntry, RegistryRef};
+
+/// Version of the exported document shape; bumped when fields change meaning or disappear.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Exports actions, commands, options, motions, text objects, hooks, and themes as JSON.
+pub fn export_all() -> Value {
+	let catalog = crate::db::get_catalog();
+
+	let mut registries = Map::new();
+	registries.insert("actions".into(), export_registry(catalog.actions.snapshot_guard(), action_fields));
+	registries.insert("commands".into(), export_registry(catalog.commands.snapshot_guard(), no_fields));
+	registries.insert("options".into(), export_registry(catalog.options.snapshot_guard(), no_fields));
+	registries.insert("motions".into(), export_registry(catalog.motions.snapshot_guard(), no_fields));
+	registries.insert("text_objects".into(), export_registry(catalog.text_objects.snapshot_guard(), no_fields));
+	registries.insert("hooks".into(), export_registry(catalog.hooks.snapshot_guard(), no_fields));
+	registries.insert("themes".into(), export_registry(catalog.themes.snapshot_guard(), no_fields));
+
+	json!({
+		"schema_version": EXPORT_SCHEMA_VERSION,
+		"catalog_version": catalog.version_hash(),
+		"registries": registries,
+	})
+}
+
+/// Exports every entry of one registry, sorted by canonical ID.
+///
+/// `extra` adds domain-specific fields on top of the shared metadata.
+fn export_registry<T, Id, F>(guard: SnapshotGuard<T, Id>, extra: F) -> Value
+where
+	T: RegistryEntry + Send + Sync + 'static,
+	Id: DenseId,
+	F: Fn(&RegistryRef<T, Id>, &mut Map<String, Value>),
+{
+	let mut entries: Vec<(String, Value)> = guard
+		.iter_refs()
+		.map(|entry| {
+			let mut fields = meta_fields(&entry);
+			extra(&entry, &mut fields);
+			(entry.id_str().to_string(), Value::Object(fields))
+		})
+		.collect();
+	entries.sort_by(|a, b| a.0.cmp(&b.0));
+	Value::Array(entries.into_iter().map(|(_, value)| value).collect())
+}
+
+fn meta_fields<T, Id>(entry: &RegistryRef<T, Id>) -> Map<String, Value>
+where
+	T: RegistryEntry + Send + Sync + 'static,
+	Id: DenseId,
+{
+	let deprecated = entry.deprecated_resolved().map(|d| {
+		json!({
+			"since": d.since,
+			"replacement": d.replacement,
+		})
+	});
+
+	let mut fields = Map::new();
+	fields.insert("id".into(), entry.id_str().into());
+	fields.insert("name".into(), entry.name_str().into());
+	fields.insert("aliases".into(), entry.keys_resolved().into());
+	fields.insert("description".into(), entry.description_str().into());
+	fields.insert("categories".into(), entry.categories_resolved().into());
+	fields.insert("priority".into(), entry.priority().into());
+	fields.insert("source".into(), entry.source().to_string().into());
+	fields.insert("mutates_buffer".into(), entry.mutates_buffer().into());
+	fields.insert("deprecated".into(), deprecated.unwrap_or(Value::Null));
+	fields
+}
+
+fn no_fields<T, Id>(_: &RegistryRef<T, Id>, _: &mut Map<String, Value>)
+where
+	T: RegistryEntry + Send + Sync + 'static,
+	Id: DenseId,
+{
+}
+
+fn action_fields(entry: &RegistryRef<crate::actions::ActionEntry, crate::core::ActionId>, fields: &mut Map<String, Value>) {
+	let bindings: Vec<Value> = entry
+		.bindings
+		.iter()
+		.map(|binding| {
+			json!({
+				"mode": binding.mode.as_str(),
+				"keys": &*binding.keys,
+				"priority": binding.priority,
+			})
+		})
+		.collect();
+	fields.insert("bindings".into(), bindings.into());
+	fields.insert("repeatable".into(), entry.repeatable.into());
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::Value;
+
+	use super::{EXPORT_SCHEMA_VERSION, export_all};
+
+	const REGISTRIES: &[&str] = &["actions", "commands", "options", "motions", "text_objects", "hooks", "themes"];
+	const ENTRY_FIELDS: &[&str] = &[
+		"aliases",
+		"categories",
+		"deprecated",
+		"description",
+		"id",
+		"mutates_buffer",
+		"name",
+		"priority",
+		"source",
+	];
+
+	fn keys(value: &Value) -> Vec<&str> {
+		value.as_object().expect("expected a JSON object").keys().map(String::as_str).collect()
+	}
+
+	/// Asserts the document shape only, so adding registry items never breaks it.
+	#[test]
+	fn export_schema_shape() {
+		let doc = export_all();
+		assert_eq!(keys(&doc), ["catalog_version", "registries", "schema_version"]);
+		assert_eq!(doc["schema_version"], EXPORT_SCHEMA_VERSION);
+		assert!(doc["catalog_version"].is_u64());
+
+		let mut registries = keys(&doc["registries"]);
+		registries.sort_unstable();
+		let mut expected = REGISTRIES.to_vec();
+		expected.sort_unstable();
+		assert_eq!(registries, expected);
+
+		for name in REGISTRIES {
+			let entries = doc["registries"][name].as_array().expect("registry exports an array");
+			for entry in entries {
+				let mut expected = ENTRY_FIELDS.to_vec();
+				if *name == "actions" {
+					expected.extend(["bindings", "repeatable"]);
+				}
+				expected.sort_unstable();
+				assert_eq!(keys(entry), expected, "{name} entry {}", entry["id"]);
+				assert!(entry["id"].is_string() && entry["name"].is_string() && entry["description"].is_string());
+				assert!(entry["aliases"].is_array() && entry["categories"].is_array());
+				assert!(entry["priority"].is_i64() && entry["source"].is_string() && entry["mutates_buffer"].is_boolean());
+				assert!(entry["deprecated"].is_null() || entry["deprecated"]["since"].is_string());
+			}
+		}
+
+		let actions = doc["registries"]["actions"].as_array().unwrap();
+		let binding = actions
+			.iter()
+			.flat_map(|action| action["bindings"].as_array().unwrap())
+			.next()
+			.expect("builtin actions carry keybindings");
+		assert_eq!(keys(binding), ["keys", "mode", "priority"]);
+	}
+
+	#[test]
+	fn export_is_sorted_by_id() {
+		let doc = export_all();
+		for name in REGISTRIES {
+			let ids: Vec<&str> = doc["registries"][name].as_array().unwrap().iter().map(|e| e["id"].as_str().unwrap()).collect();
+			assert!(ids.is_sorted(), "{name} export should be sorted by id");
+		}
+	}
+}
@@ -1,4 +1,5 @@
 #![recursion_limit = "8192"]
+#![cfg_attr(test, allow(unused_crate_dependencies))]
 //! Registry-first organization extensions.
 //!
 //! The registry crate owns both:
@@ -9,6 +10,8 @@
 pub mod config;
 pub mod core;
 pub mod defs;
+#[cfg(feature = "export")]
+pub mod export;
 pub mod invocation;
 pub mod schema;
 
@@ -159,13 +162,20 @@ xeno_macros::define_events! {
 		/// Name of the result variant returned by the action.
 		result_variant: Str,
 	},
-	/// An option value was changed via :set or :setlocal.
+	/// An option value changed in an option store (`:set`, `:setlocal`, config reload).
 	OptionChanged => "option:changed" {
 		/// The config key of the changed option (e.g., "tab-width").
 		key: Str,
-		/// The scope of the change: "global" or "buffer".
+		/// The store that changed: "global", "buffer", "language" or "filetype".
 		scope: Str,
 	},
+	/// A command asked for confirmation before a destructive operation.
+	///
+	/// A hook that cancels this event declines the operation.
+	ConfirmRequest => "command:confirm" {
+		/// Question shown to the user.
+		prompt: Str,
+	},
 	/// LSP diagnostics were updated for a document.
 	DiagnosticsUpdated => "lsp:diagnostics" {
 		/// Filesystem path of the document with updated diagnostics.
@@ -203,7 +213,8 @@ pub use db::index::{
 };
 #[cfg(feature = "keymap")]
 pub use db::keymap_registry::{
-	CompiledBinding, CompiledBindingTarget, ContinuationKind, KeymapBuildProblem, KeymapSnapshot, KeymapSnapshotCache, LookupOutcome, get_keymap_snapshot,
+	BindingConflict, BindingEntry, CompiledBinding, CompiledBindingTarget, ConflictSeverity, Continuation, ContinuationKind, KeymapBuildProblem,
+	KeymapSnapshot, KeymapSnapshotCache, LayerBinding, LookupOutcome, get_keymap_snapshot,
 };
 #[cfg(feature = "minimal")]
 pub use db::{ACTIONS, COMMANDS, GUTTERS, HOOKS, LANGUAGES, LSP_SERVERS, MOTIONS, NOTIFICATIONS, OPTIONS, STATUSLINE_SEGMENTS, TEXT_OBJECTS, THEMES};
@@ -234,6 +245,8 @@ pub use domains::statusline;
 pub use domains::textobj;
 #[cfg(feature = "themes")]
 pub use domains::themes;
+#[cfg(feature = "export")]
+pub use export::export_all;
 #[cfg(feature = "options")]
 pub use xeno_macros::derive_option;
 
@@ -16,6 +16,16 @@ pub struct ActionSpec {
 	pub bindings: Vec<KeyBindingSpec>,
 	#[serde(default)]
 	pub group: Option<String>,
+	/// Whether `repeat_last` may replay this action. Navigation-only actions opt out.
+	#[serde(default = "default_true")]
+	pub repeatable: bool,
+	/// Compact glyph labelling the action on soft-keyboard and touch overlays.
+	#[serde(default)]
+	pub keyboard_icon: Option<String>,
+}
+
+fn default_true() -> bool {
+	true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
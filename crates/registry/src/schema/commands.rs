@@ -22,6 +22,7 @@ pub enum PaletteArgKind {
 	SnippetRefOrBody,
 	OptionKey,
 	OptionValue,
+	Int,
 	BufferRef,
 	CommandName,
 	FreeText,
@@ -49,6 +50,9 @@ pub struct CommandPaletteSpec {
 pub struct CommandSpec {
 	pub common: MetaCommonSpec,
 	pub palette: CommandPaletteSpec,
+	/// Whether the command honors an ex-style line range prefix (`:%cmd`).
+	#[serde(default)]
+	pub accepts_range: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,7 +10,44 @@ pub struct MetaCommonSpec {
 	#[serde(default)]
 	pub keys: Vec<String>,
 	#[serde(default)]
+	pub categories: Vec<String>,
+	#[serde(default)]
 	pub priority: i16,
 	#[serde(default)]
 	pub mutates_buffer: bool,
+	#[serde(default)]
+	pub deprecated: Option<DeprecationSpec>,
+}
+
+/// Deprecation record for `common.deprecated`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecationSpec {
+	/// Release the item was deprecated in.
+	pub since: String,
+	/// Name of the item that supersedes this one.
+	#[serde(default)]
+	pub replacement: Option<String>,
+}
+
+/// Well-known grouping categories for registry metadata.
+///
+/// Categories group entries for UI surfaces such as the command palette. NUON
+/// specs list them under `common.categories` and are validated against `ALL`
+/// at build time; at runtime they are interned into
+/// `RegistryMeta::categories`.
+pub mod categories {
+	pub const EDITING: &str = "editing";
+	pub const MOTION: &str = "motion";
+	pub const SELECTION: &str = "selection";
+	pub const FILE: &str = "file";
+	pub const WINDOW: &str = "window";
+	pub const SEARCH: &str = "search";
+	pub const MODE: &str = "mode";
+	pub const VIEW: &str = "view";
+	pub const BUFFER: &str = "buffer";
+	pub const HELP: &str = "help";
+	pub const CONFIG: &str = "config";
+
+	/// Every accepted category, used for build-time validation.
+	pub const ALL: &[&str] = &[EDITING, MOTION, SELECTION, FILE, WINDOW, SEARCH, MODE, VIEW, BUFFER, HELP, CONFIG];
 }
@@ -9,6 +9,9 @@ use super::meta::MetaCommonSpec;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MotionSpec {
 	pub common: MetaCommonSpec,
+	/// Name of the motion that reverses this one (`next_word_start` ↔ `prev_word_start`).
+	#[serde(default)]
+	pub inverse: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
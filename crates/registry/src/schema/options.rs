@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use super::meta::MetaCommonSpec;
 
-pub const VALID_TYPES: &[&str] = &["bool", "int", "string"];
+pub const VALID_TYPES: &[&str] = &["bool", "int", "float", "string"];
 pub const VALID_SCOPES: &[&str] = &["buffer", "global"];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +24,9 @@ pub struct OptionSpec {
 	/// Optional validator name.
 	#[serde(default)]
 	pub validator: Option<String>,
+	/// Allowed values for a `"string"` option; any string is accepted when absent.
+	#[serde(default)]
+	pub choices: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +12,12 @@ pub const VALID_POSITIONS: &[&str] = &["left", "right", "center"];
 pub struct StatuslineSegmentSpec {
 	pub common: MetaCommonSpec,
 	pub position: String,
+	/// Narrowest width the segment may be truncated to on overflow.
+	#[serde(default)]
+	pub min_width: Option<u16>,
+	/// Overflow order, lowest shrinking first; defaults to the segment priority.
+	#[serde(default)]
+	pub shrink_priority: Option<i16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
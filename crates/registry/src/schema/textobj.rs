@@ -12,6 +12,9 @@ pub struct TextObjectSpec {
 	pub trigger: String,
 	#[serde(default)]
 	pub alt_triggers: Vec<String>,
+	/// Extended help text shown by `text_object_help`.
+	#[serde(default)]
+	pub description_long: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
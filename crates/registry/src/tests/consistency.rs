@@ -76,6 +76,28 @@ fn motions_consistency() {
 	}
 }
 
+#[test]
+#[cfg(feature = "minimal")]
+fn motion_inverses_are_bidirectional() {
+	let mut paired = 0;
+	for motion in crate::motions::all() {
+		let Some(name) = motion.inverse.map(|sym| motion.resolve(sym)) else {
+			continue;
+		};
+		let inverse = crate::motions::inverse_def(&motion).unwrap_or_else(|| panic!("motion '{}' names unknown inverse '{name}'", motion.name_str()));
+		let back = crate::motions::inverse_def(&inverse).map(|m| m.name_str().to_string());
+		assert_eq!(
+			back.as_deref(),
+			Some(motion.name_str()),
+			"motion '{}' has inverse '{}', which does not point back",
+			motion.name_str(),
+			inverse.name_str()
+		);
+		paired += 1;
+	}
+	assert!(paired > 0, "expected paired motions");
+}
+
 // textobj
 #[test]
 #[cfg(feature = "textobj")]
@@ -122,6 +144,32 @@ fn hooks_consistency() {
 	}
 }
 
+#[test]
+#[cfg(feature = "hooks")]
+fn builtin_hooks_use_standard_tags() {
+	use crate::hooks::tags::STANDARD;
+
+	let handlers: Vec<_> = inventory::iter::<crate::hooks::HookHandlerReg>.into_iter().map(|r| r.0).collect();
+	for handler in &handlers {
+		assert!(!handler.handler.tags.is_empty(), "built-in hook '{}' has no tags", handler.name);
+		for tag in handler.handler.tags {
+			assert!(STANDARD.contains(tag), "built-in hook '{}' uses non-standard tag '{tag}'", handler.name);
+		}
+	}
+
+	let tags = crate::hooks::all_hook_tags();
+	assert_eq!(tags.iter().collect::<HashSet<_>>().len(), tags.len(), "{tags:?}");
+	let logging = crate::hooks::find_hooks_by_tag(crate::hooks::tags::LOGGING);
+	assert!(!logging.is_empty(), "built-in logging hooks are tagged");
+	for handler in handlers.iter().filter(|h| h.handler.tags.contains(&crate::hooks::tags::LOGGING)) {
+		assert!(
+			logging.iter().any(|hook| hook.name_str() == handler.name),
+			"'{}' missing from tag lookup",
+			handler.name
+		);
+	}
+}
+
 // statusline
 #[test]
 #[cfg(feature = "statusline")]
@@ -170,3 +218,60 @@ fn gutter_consistency() {
 		assert!(spec_names.contains(handler.name), "Handler '{}' has no Spec entry", handler.name);
 	}
 }
+
+#[test]
+#[cfg(feature = "actions")]
+fn actions_motion_category_covers_movement_groups() {
+	use crate::core::{RegistryEntry, categories};
+
+	let spec = crate::actions::loader::load_actions_spec();
+	let expected: HashSet<&str> = spec
+		.actions
+		.iter()
+		.filter(|a| matches!(a.group.as_deref(), Some("navigation" | "find")))
+		.map(|a| a.common.name.as_str())
+		.collect();
+	assert!(!expected.is_empty());
+
+	let mut builder = crate::db::builder::RegistryDbBuilder::new();
+	crate::db::builtins::register_all(&mut builder).expect("builtins should register");
+	let actions = builder.build().actions;
+	let got: HashSet<&str> = actions.by_category(categories::MOTION).map(|a| actions.interner.resolve(a.name())).collect();
+
+	assert_eq!(got, expected);
+}
+
+#[test]
+#[cfg(feature = "actions")]
+fn actions_movement_groups_are_not_repeatable() {
+	let spec = crate::actions::loader::load_actions_spec();
+	for action in &spec.actions {
+		let non_editing = matches!(
+			action.group.as_deref(),
+			Some("navigation" | "scrolling" | "modes" | "find" | "search" | "window")
+		);
+		if non_editing || matches!(action.common.name.as_str(), "repeat_last" | "undo" | "redo" | "insert_mode") {
+			assert!(!action.repeatable, "action '{}' should not be repeatable", action.common.name);
+		}
+	}
+	let delete = spec.actions.iter().find(|a| a.common.name == "delete").expect("delete action in spec");
+	assert!(delete.repeatable, "edits should be repeatable by default");
+}
+
+#[test]
+#[cfg(feature = "actions")]
+fn actions_keyboard_icon_falls_back_to_name() {
+	let delete_back = crate::actions::find_action("delete_back").expect("delete_back registered");
+	assert_eq!(crate::actions::icon_or_name(&delete_back), "⌫");
+
+	let spec = crate::actions::loader::load_actions_spec();
+	assert_eq!(spec.actions.iter().filter(|a| a.keyboard_icon.is_some()).count(), 20);
+	for action in &spec.actions {
+		if let Some(icon) = &action.keyboard_icon {
+			assert_eq!(icon.chars().count(), 1, "action '{}' icon should be a single glyph", action.common.name);
+		}
+	}
+
+	let split = crate::actions::find_action("split_horizontal").expect("split_horizontal registered");
+	assert_eq!(crate::actions::icon_or_name(&split), "split_horizontal");
+}
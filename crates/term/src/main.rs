@@ -64,6 +64,7 @@ async fn main() -> anyhow::Result<()> {
 	editor.kick_theme_load();
 	editor.kick_lsp_catalog_load();
 	editor.apply_loaded_config(user_config);
+	editor.load_command_history();
 
 	if let Some(theme_name) = cli.theme {
 		editor.set_configured_theme_name(theme_name);
@@ -256,6 +257,7 @@ async fn run_editor_normal() -> anyhow::Result<()> {
 	editor.kick_theme_load();
 	editor.kick_lsp_catalog_load();
 	editor.apply_loaded_config(user_config);
+	editor.load_command_history();
 
 	run_editor(editor).await?;
 	Ok(())
@@ -61,8 +61,21 @@ impl ToastManager {
 	/// Adds a toast and returns its ID.
 	///
 	/// If a toast with identical content and anchor already exists (and is not
-	/// exiting), increments its stack count and resets the dismiss timer.
+	/// exiting), increments its stack count and resets the dismiss timer. A toast
+	/// with a group key instead replaces the live toast of that group, stacking
+	/// only when the content is unchanged.
 	pub fn push(&mut self, toast: Toast) -> u64 {
+		if let Some(key) = &toast.group_key
+			&& let Some((&id, state)) = self.states.iter_mut().find(|(_, s)| s.can_stack() && s.toast.group_key.as_ref() == Some(key))
+		{
+			if state.toast.content == toast.content {
+				state.increment_stack();
+			} else {
+				state.replace(toast);
+			}
+			return id;
+		}
+
 		if let Some((&id, state)) = self
 			.states
 			.iter_mut()
@@ -147,3 +160,48 @@ impl ToastManager {
 		self.states.iter().max_by_key(|(_, s)| s.created_at).map(|(&id, _)| id)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn contents(manager: &ToastManager) -> Vec<(&str, u32)> {
+		let mut states: Vec<_> = manager.states.values().collect();
+		states.sort_by_key(|s| s.created_at);
+		states.iter().map(|s| (s.toast.content.as_str(), s.stack_count)).collect()
+	}
+
+	#[test]
+	fn same_group_replaces_the_live_toast() {
+		let mut manager = ToastManager::new();
+		let first = manager.push(Toast::new("indexing 10%").group_key("lsp"));
+		manager.tick(Duration::from_secs(3));
+		let second = manager.push(Toast::new("indexing 50%").group_key("lsp"));
+
+		assert_eq!(first, second);
+		assert_eq!(contents(&manager), [("indexing 50%", 1)]);
+
+		manager.tick(Duration::from_secs(3));
+		assert!(manager.states.values().all(ToastState::can_stack), "replacing resets the dismiss timer");
+	}
+
+	#[test]
+	fn same_group_with_identical_text_counts_repeats() {
+		let mut manager = ToastManager::new();
+		manager.push(Toast::new("Saved a.rs").group_key("file_saved"));
+		manager.push(Toast::new("Saved a.rs").group_key("file_saved"));
+
+		assert_eq!(contents(&manager), [("Saved a.rs", 2)]);
+	}
+
+	#[test]
+	fn distinct_groups_stack() {
+		let mut manager = ToastManager::new();
+		manager.push(Toast::new("indexing").group_key("lsp"));
+		manager.push(Toast::new("building").group_key("cargo"));
+		manager.push(Toast::new("indexing").group_key("cargo"));
+
+		assert_eq!(manager.len(), 2);
+		assert_eq!(contents(&manager), [("indexing", 1), ("indexing", 1)]);
+	}
+}
@@ -122,6 +122,14 @@ impl ToastState {
 		self.remaining_dwell = self.original_dwell;
 	}
 
+	/// Swaps in `toast` as the new content of this toast group and resets the
+	/// stack count and dwell timer.
+	pub(super) fn replace(&mut self, toast: Toast) {
+		self.toast = toast;
+		self.stack_count = 1;
+		self.remaining_dwell = self.original_dwell;
+	}
+
 	/// Returns true if this toast can be stacked with another having the same content.
 	pub(super) fn can_stack(&self) -> bool {
 		!matches!(self.phase, AnimationPhase::Exiting | AnimationPhase::Finished)
@@ -78,6 +78,8 @@ pub struct Toast {
 	pub border_type: BorderType,
 	/// Whether to apply fade effect during slide/expand animations.
 	pub fade_effect: bool,
+	/// Group key; a newer toast in the same group replaces this one.
+	pub group_key: Option<String>,
 }
 
 impl Default for Toast {
@@ -103,6 +105,7 @@ impl Default for Toast {
 			title_style: Style::default(),
 			border_type: BorderType::Stripe,
 			fade_effect: false,
+			group_key: None,
 		}
 	}
 }
@@ -218,6 +221,13 @@ impl Toast {
 		self
 	}
 
+	/// Sets the group key, so a newer toast in the group replaces this one.
+	#[must_use]
+	pub fn group_key(mut self, key: impl Into<String>) -> Self {
+		self.group_key = Some(key.into());
+		self
+	}
+
 	/// Returns the width needed for the icon column, if an icon is present.
 	pub fn icon_column_width(&self) -> u16 {
 		if self.icon.is_some() { ICON_COLUMN_WIDTH } else { 0 }